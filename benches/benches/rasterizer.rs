@@ -115,6 +115,29 @@ fn blend_background(c: &mut Criterion) {
     });
 }
 
+fn fill_background_4k(c: &mut Criterion) {
+    let mut g = c.benchmark_group("rasterizer::fill_background_4k");
+    let (w, h) = (3840, 2160);
+
+    g.bench_function("rgb", |b| {
+        let mut buffer = vec![0; (w * h * 3) as usize];
+        b.iter(|| {
+            let root = BitMapBackend::with_buffer(&mut buffer, (w, h)).into_drawing_area();
+            root.fill(&RGBColor(250, 240, 230)).unwrap();
+        })
+    });
+
+    g.bench_function("bgrx", |b| {
+        let mut buffer = vec![0; (w * h * 4) as usize];
+        b.iter(|| {
+            let root = BitMapBackend::<BGRXPixel>::with_buffer_and_format(&mut buffer, (w, h))
+                .unwrap()
+                .into_drawing_area();
+            root.fill(&RGBColor(250, 240, 230)).unwrap();
+        })
+    });
+}
+
 fn fill_circle(c: &mut Criterion) {
     let mut g = c.benchmark_group("rasterizer::fill_circle");
 
@@ -200,5 +223,6 @@ criterion_group! {
         fill_background,
         fill_circle,
         fill_background_red,
+        fill_background_4k,
         fill_hexagon,
 }
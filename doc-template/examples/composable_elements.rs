@@ -5,7 +5,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     root.fill(&RGBColor(240, 200, 200))?;
 
-    let root = root.apply_coord_spec(RangedCoord::<RangedCoordf32, RangedCoordf32>::new(
+    let root = root.apply_coord_spec(RangedCoord::cartesian_2d(
         0f32..1f32,
         0f32..1f32,
         (0..640, 0..480),
@@ -22,36 +22,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .into_drawing_area();
 
     for i in 0..8 {
-        root.fill(&WHITE)?;
-
-        let mut chart = ChartBuilder::on(&root)
-            .caption(
-                format!("Koch's Snowflake (n_iter = {})", i),
-                ("sans-serif", 50),
-            )
-            .build_ranged(-2.0..2.0, -1.5..1.5)?;
-
-        let mut snowflake_vertices = {
-            let mut current: Vec<(f64, f64)> = vec![
-                (0.0, 1.0),
-                ((3.0f64).sqrt() / 2.0, -0.5),
-                (-(3.0f64).sqrt() / 2.0, -0.5),
-            ];
-            for _ in 0..i {
-                current = snowflake_iter(&current[..]);
-            }
-            current
-        };
-
-        chart.draw_series(std::iter::once(Polygon::new(
-            snowflake_vertices.clone(),
-            &RED.mix(0.2),
-        )))?;
-
-        snowflake_vertices.push(snowflake_vertices[0]);
-        chart.draw_series(std::iter::once(PathElement::new(snowflake_vertices, &RED)))?;
-
-        root.present()?;
+        root.draw_frame(&WHITE, |root| {
+            let mut chart = ChartBuilder::on(root)
+                .caption(
+                    format!("Koch's Snowflake (n_iter = {})", i),
+                    ("sans-serif", 50),
+                )
+                .build_ranged(-2.0..2.0, -1.5..1.5)?;
+
+            let mut snowflake_vertices = {
+                let mut current: Vec<(f64, f64)> = vec![
+                    (0.0, 1.0),
+                    ((3.0f64).sqrt() / 2.0, -0.5),
+                    (-(3.0f64).sqrt() / 2.0, -0.5),
+                ];
+                for _ in 0..i {
+                    current = snowflake_iter(&current[..]);
+                }
+                current
+            };
+
+            chart.draw_series(std::iter::once(Polygon::new(
+                snowflake_vertices.clone(),
+                &RED.mix(0.2),
+            )))?;
+
+            snowflake_vertices.push(snowflake_vertices[0]);
+            chart.draw_series(std::iter::once(PathElement::new(snowflake_vertices, &RED)))?;
+
+            Ok(())
+        })?;
     }
 
     Ok(())
@@ -72,7 +72,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut chart = ChartBuilder::on(&upper)
         .x_label_area_size(40)
         .y_label_area_size(80)
-        .caption("Ping Boxplot", ("sans-serif", 20).into_font())
+        .caption("Ping Boxplot", ("sans-serif", 20))
         .build_ranged(
             values_range.start - 1.0..values_range.end + 1.0,
             category.range(),
@@ -123,7 +123,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut chart = ChartBuilder::on(&left)
         .x_label_area_size(40)
         .y_label_area_size(40)
-        .caption("Vertical Boxplot", ("sans-serif", 20).into_font())
+        .caption("Vertical Boxplot", ("sans-serif", 20))
         .build_ranged(
             category_ab.clone(),
             values_range.start - 10.0..values_range.end + 10.0,
@@ -138,7 +138,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut chart = ChartBuilder::on(&right)
         .x_label_area_size(40)
         .y_label_area_size(40)
-        .caption("Horizontal Boxplot", ("sans-serif", 20).into_font())
+        .caption("Horizontal Boxplot", ("sans-serif", 20))
         .build_ranged(-30f32..90f32, 0..3)?;
 
     chart.configure_mesh().line_style_2(&WHITE).draw()?;
@@ -6,14 +6,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     root_area.fill(&WHITE)?;
 
-    let root_area = root_area.titled("Image Title", ("sans-serif", 60).into_font())?;
+    let root_area = root_area.titled("Image Title", ("sans-serif", 60))?;
 
     let (upper, lower) = root_area.split_vertically(512);
 
     let mut cc = ChartBuilder::on(&upper)
         .margin(5)
         .set_all_label_area_size(50)
-        .caption("Sine and Cosine", ("sans-serif", 40).into_font())
+        .caption("Sine and Cosine", ("sans-serif", 40))
         .build_ranged(-3.4f32..3.4f32, -1.2f32..1.2f32)?;
 
     cc.configure_mesh()
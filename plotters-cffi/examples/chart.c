@@ -0,0 +1,34 @@
+/* cc examples/chart.c -Iinclude -Ltarget/release -lplotters_cffi -o chart */
+#include <math.h>
+#include <stdio.h>
+
+#include "plotters.h"
+
+int main(void) {
+    double xs[100], ys[100];
+    for (int i = 0; i < 100; i++) {
+        xs[i] = i / 10.0;
+        ys[i] = sin(xs[i]);
+    }
+
+    plotters_backend *backend = plotters_bitmap_backend("chart.png", 640, 480);
+    if (!backend) {
+        fprintf(stderr, "%s\n", plotters_last_error());
+        return 1;
+    }
+    plotters_area *root = plotters_backend_area(backend);
+    plotters_area_fill(root, 0xffffff);
+
+    plotters_chart *chart = plotters_chart_new(root, "sin(x)", 0.0, 10.0, -1.2, 1.2);
+    plotters_chart_line(chart, xs, ys, 100, 0xff0000, "sin(x)");
+    plotters_chart_scatter(chart, xs, ys, 100, 2, 0x0000ff, NULL);
+    plotters_chart_legend(chart);
+
+    if (plotters_backend_present(backend) != 0) {
+        fprintf(stderr, "%s\n", plotters_last_error());
+    }
+    plotters_chart_free(chart);
+    plotters_area_free(root);
+    plotters_backend_free(backend);
+    return 0;
+}
@@ -0,0 +1,62 @@
+/*
+ * The C interface of Plotters.
+ *
+ * The handles are opaque and each of them is released with its own `_free` function. The
+ * functions returning a handle return NULL on failure, the functions returning an `int` return
+ * 0 on success and -1 on failure, and `plotters_last_error` tells what went wrong.
+ *
+ * The colors are written as 0xRRGGBB.
+ */
+#ifndef PLOTTERS_H
+#define PLOTTERS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct plotters_backend plotters_backend;
+typedef struct plotters_area plotters_area;
+typedef struct plotters_chart plotters_chart;
+
+/* The message of the last error on the calling thread, or NULL. It's valid until the next call. */
+const char *plotters_last_error(void);
+
+/* Create a backend writing a bitmap file, the format is picked by the extension of the path */
+plotters_backend *plotters_bitmap_backend(const char *path, uint32_t width, uint32_t height);
+/* Create a backend writing an SVG file */
+plotters_backend *plotters_svg_backend(const char *path, uint32_t width, uint32_t height);
+/* Write the image, otherwise it's written once the backend and all the areas and the charts on it are freed */
+int plotters_backend_present(plotters_backend *backend);
+void plotters_backend_free(plotters_backend *backend);
+
+/* The drawing area of the whole backend */
+plotters_area *plotters_backend_area(const plotters_backend *backend);
+/* Split the area into a grid of `rows` x `cols` and get the `index`-th cell, row by row */
+plotters_area *plotters_area_split(const plotters_area *area, uint32_t rows, uint32_t cols, uint32_t index);
+int plotters_area_fill(const plotters_area *area, uint32_t color);
+void plotters_area_free(plotters_area *area);
+
+/* Create a chart with the axes and the mesh on an area, `caption` can be NULL */
+plotters_chart *plotters_chart_new(const plotters_area *area, const char *caption,
+                                   double x_min, double x_max, double y_min, double y_max);
+/* Draw the points `(xs[i], ys[i])` connected with a line, `label` can be NULL */
+int plotters_chart_line(plotters_chart *chart, const double *xs, const double *ys, size_t len,
+                        uint32_t color, const char *label);
+/* Draw the points `(xs[i], ys[i])` as circles of the radius `size`, `label` can be NULL */
+int plotters_chart_scatter(plotters_chart *chart, const double *xs, const double *ys, size_t len,
+                           uint32_t size, uint32_t color, const char *label);
+/* Draw the histogram of the values with `bins` bins of the same width across the X range */
+int plotters_chart_histogram(plotters_chart *chart, const double *values, size_t len, size_t bins,
+                             uint32_t color, const char *label);
+/* Draw the legend of the labeled series */
+int plotters_chart_legend(plotters_chart *chart);
+void plotters_chart_free(plotters_chart *chart);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
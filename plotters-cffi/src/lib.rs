@@ -0,0 +1,510 @@
+/*!
+  The C interface of Plotters, so the C and C++ applications can render the charts with it.
+
+  The declarations are in `include/plotters.h`. The backends, the drawing areas and the charts
+  are opaque handles, the series are drawn with the simple functions taking the arrays of the
+  values, and the image is written by `plotters_backend_present`, or once all the handles on
+  the backend are freed.
+
+  All the functions taking the pointers are unsafe, the pointers must either be null or valid
+  for the duration of the call, and each handle must be released only once.
+*/
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::slice;
+
+use plotters::chart::SeriesAnno;
+use plotters::coord::{RangedCoord, RangedCoordf64, Shift};
+use plotters::error::PlottersError;
+use plotters::prelude::*;
+
+type Coord = RangedCoord<RangedCoordf64, RangedCoordf64>;
+
+enum Area {
+    Bitmap(DrawingArea<BitMapBackend<'static>, Shift>),
+    Svg(DrawingArea<SVGBackend<'static>, Shift>),
+}
+
+enum Chart {
+    Bitmap(ChartContext<'static, BitMapBackend<'static>, Coord>),
+    Svg(ChartContext<'static, SVGBackend<'static>, Coord>),
+}
+
+/// The backend handle, which holds the root drawing area
+pub struct PlottersBackend(Area);
+
+/// The drawing area handle
+pub struct PlottersArea(Area);
+
+/// The chart handle
+pub struct PlottersChart(Chart);
+
+macro_rules! with_area {
+    ($area:expr, $a:ident => $body:expr) => {
+        match $area {
+            Area::Bitmap($a) => $body,
+            Area::Svg($a) => $body,
+        }
+    };
+}
+
+macro_rules! with_chart {
+    ($chart:expr, $c:ident => $body:expr) => {
+        match $chart {
+            Chart::Bitmap($c) => $body,
+            Chart::Svg($c) => $body,
+        }
+    };
+}
+
+impl Clone for Area {
+    fn clone(&self) -> Self {
+        match self {
+            Area::Bitmap(a) => Area::Bitmap(a.clone()),
+            Area::Svg(a) => Area::Svg(a.clone()),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_error<E: ToString>(error: E) {
+    let message = CString::new(error.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+// Convert the result into the status code, the error is kept for `plotters_last_error`
+fn status<E: Into<PlottersError>>(result: Result<(), E>) -> c_int {
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            set_error(e.into());
+            -1
+        }
+    }
+}
+
+fn color_of(color: u32) -> RGBColor {
+    RGBColor((color >> 16) as u8, (color >> 8) as u8, color as u8)
+}
+
+unsafe fn str_of<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+unsafe fn slice_of<'a>(values: *const f64, len: usize) -> &'a [f64] {
+    if values.is_null() {
+        return &[];
+    }
+    slice::from_raw_parts(values, len)
+}
+
+// The backends borrow the path until they are dropped, which may be after the backend handle
+// is freed, so the path is leaked
+unsafe fn path_of(path: *const c_char) -> Option<&'static Path> {
+    match str_of(path) {
+        Some(path) => Some(Box::leak(PathBuf::from(path).into_boxed_path())),
+        None => {
+            set_error("The path is null or not UTF-8");
+            None
+        }
+    }
+}
+
+fn into_handle<T>(value: T) -> *mut T {
+    Box::into_raw(Box::new(value))
+}
+
+unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Get the message of the last error on the calling thread, or null if nothing failed. The
+/// message is valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn plotters_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+/// Create a backend writing a bitmap file, the format is picked by the extension of the path
+///
+/// # Safety
+/// `path` must be null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_bitmap_backend(
+    path: *const c_char,
+    width: u32,
+    height: u32,
+) -> *mut PlottersBackend {
+    match path_of(path) {
+        Some(path) => into_handle(PlottersBackend(Area::Bitmap(
+            BitMapBackend::new(path, (width, height)).into_drawing_area(),
+        ))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Create a backend writing an SVG file
+///
+/// # Safety
+/// `path` must be null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_svg_backend(
+    path: *const c_char,
+    width: u32,
+    height: u32,
+) -> *mut PlottersBackend {
+    match path_of(path) {
+        Some(path) => into_handle(PlottersBackend(Area::Svg(
+            SVGBackend::new(path, (width, height)).into_drawing_area(),
+        ))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Write the image of the backend
+///
+/// # Safety
+/// `backend` must be a valid backend handle
+#[no_mangle]
+pub unsafe extern "C" fn plotters_backend_present(backend: *mut PlottersBackend) -> c_int {
+    with_area!(&(*backend).0, a => status(a.present()))
+}
+
+/// Free the backend handle
+///
+/// # Safety
+/// `backend` must be null or a valid backend handle, which isn't used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn plotters_backend_free(backend: *mut PlottersBackend) {
+    free_handle(backend)
+}
+
+/// Get the drawing area of the whole backend
+///
+/// # Safety
+/// `backend` must be a valid backend handle
+#[no_mangle]
+pub unsafe extern "C" fn plotters_backend_area(
+    backend: *const PlottersBackend,
+) -> *mut PlottersArea {
+    into_handle(PlottersArea((*backend).0.clone()))
+}
+
+/// Split the area into a grid and get the `index`-th cell, the cells are counted row by row
+///
+/// # Safety
+/// `area` must be a valid area handle
+#[no_mangle]
+pub unsafe extern "C" fn plotters_area_split(
+    area: *const PlottersArea,
+    rows: u32,
+    cols: u32,
+    index: u32,
+) -> *mut PlottersArea {
+    if index >= rows.saturating_mul(cols) {
+        set_error("The index of the cell is out of the grid");
+        return ptr::null_mut();
+    }
+    let grid = (rows as usize, cols as usize);
+    let cell = match &(*area).0 {
+        Area::Bitmap(a) => Area::Bitmap(a.split_evenly(grid).swap_remove(index as usize)),
+        Area::Svg(a) => Area::Svg(a.split_evenly(grid).swap_remove(index as usize)),
+    };
+    into_handle(PlottersArea(cell))
+}
+
+/// Fill the area with the color
+///
+/// # Safety
+/// `area` must be a valid area handle
+#[no_mangle]
+pub unsafe extern "C" fn plotters_area_fill(area: *const PlottersArea, color: u32) -> c_int {
+    with_area!(&(*area).0, a => status(a.fill(&color_of(color))))
+}
+
+/// Free the area handle
+///
+/// # Safety
+/// `area` must be null or a valid area handle, which isn't used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn plotters_area_free(area: *mut PlottersArea) {
+    free_handle(area)
+}
+
+fn build_chart<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: Option<&str>,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+) -> Result<ChartContext<'static, DB, Coord>, PlottersError>
+where
+    DB::ErrorType: 'static,
+{
+    let mut builder = ChartBuilder::on(area);
+    builder
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40);
+    if let Some(caption) = caption {
+        builder.caption(caption, ("sans-serif", 20));
+    }
+    let mut chart = builder.build_ranged(x_range.0..x_range.1, y_range.0..y_range.1)?;
+    chart.configure_mesh().draw()?;
+    // The label areas are drawn already, the handle only keeps the plotting area
+    Ok(chart.into_chart_state().restore(area))
+}
+
+/// Create a chart with the axes and the mesh on the area
+///
+/// # Safety
+/// `area` must be a valid area handle and `caption` must be null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_new(
+    area: *const PlottersArea,
+    caption: *const c_char,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+) -> *mut PlottersChart {
+    let caption = str_of(caption);
+    let (x_range, y_range) = ((x_min, x_max), (y_min, y_max));
+    let chart = match &(*area).0 {
+        Area::Bitmap(a) => build_chart(a, caption, x_range, y_range).map(Chart::Bitmap),
+        Area::Svg(a) => build_chart(a, caption, x_range, y_range).map(Chart::Svg),
+    };
+    match chart {
+        Ok(chart) => into_handle(PlottersChart(chart)),
+        Err(e) => {
+            set_error(e);
+            ptr::null_mut()
+        }
+    }
+}
+
+fn add_legend<DB: DrawingBackend>(
+    anno: &mut SeriesAnno<'static, DB>,
+    label: Option<&str>,
+    color: RGBColor,
+) {
+    if let Some(label) = label {
+        let RGBColor(r, g, b) = color;
+        anno.label(label).legend(move |(x, y)| {
+            PathElement::new(vec![(x, y), (x + 20, y)], RGBColor(r, g, b).stroke_width(2))
+        });
+    }
+}
+
+/// Draw the points connected with a line
+///
+/// # Safety
+/// `chart` must be a valid chart handle, `xs` and `ys` must point to `len` values and `label`
+/// must be null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_line(
+    chart: *mut PlottersChart,
+    xs: *const f64,
+    ys: *const f64,
+    len: usize,
+    color: u32,
+    label: *const c_char,
+) -> c_int {
+    let points = slice_of(xs, len)
+        .iter()
+        .copied()
+        .zip(slice_of(ys, len).iter().copied());
+    let label = str_of(label);
+    with_chart!(&mut (*chart).0, c => status(
+        c.draw_series(LineSeries::new(points, &color_of(color)))
+            .map(|anno| add_legend(anno, label, color_of(color)))
+    ))
+}
+
+/// Draw the points as circles
+///
+/// # Safety
+/// `chart` must be a valid chart handle, `xs` and `ys` must point to `len` values and `label`
+/// must be null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_scatter(
+    chart: *mut PlottersChart,
+    xs: *const f64,
+    ys: *const f64,
+    len: usize,
+    size: u32,
+    color: u32,
+    label: *const c_char,
+) -> c_int {
+    let points = slice_of(xs, len)
+        .iter()
+        .copied()
+        .zip(slice_of(ys, len).iter().copied());
+    let label = str_of(label);
+    with_chart!(&mut (*chart).0, c => status(
+        c.draw_series(points.map(|p| Circle::new(p, size, color_of(color).filled())))
+            .map(|anno| add_legend(anno, label, color_of(color)))
+    ))
+}
+
+// The number of the values in each of the bins of the same width across the range
+fn bin_counts(values: &[f64], (start, end): (f64, f64), bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    let width = (end - start) / bins as f64;
+    for value in values {
+        let idx = ((value - start) / width).floor();
+        if idx >= 0.0 && idx < bins as f64 {
+            counts[idx as usize] += 1;
+        } else if *value == end {
+            counts[bins - 1] += 1;
+        }
+    }
+    counts
+}
+
+fn draw_histogram<DB: DrawingBackend>(
+    chart: &mut ChartContext<'static, DB, Coord>,
+    values: &[f64],
+    bins: usize,
+    color: RGBColor,
+    label: Option<&str>,
+) -> Result<(), PlottersError>
+where
+    DB::ErrorType: 'static,
+{
+    let range = chart.x_range();
+    let width = (range.end - range.start) / bins as f64;
+    let counts = bin_counts(values, (range.start, range.end), bins);
+    let style = color.filled();
+    let anno = chart.draw_series(counts.into_iter().enumerate().map(|(idx, count)| {
+        let x = range.start + width * idx as f64;
+        Rectangle::new([(x, 0.0), (x + width, count as f64)], style.clone())
+    }))?;
+    add_legend(anno, label, color);
+    Ok(())
+}
+
+/// Draw the histogram of the values, with `bins` bins of the same width across the X range
+///
+/// # Safety
+/// `chart` must be a valid chart handle, `values` must point to `len` values and `label` must be
+/// null or a valid C string
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_histogram(
+    chart: *mut PlottersChart,
+    values: *const f64,
+    len: usize,
+    bins: usize,
+    color: u32,
+    label: *const c_char,
+) -> c_int {
+    if bins == 0 {
+        set_error("The number of bins is zero");
+        return -1;
+    }
+    let values = slice_of(values, len);
+    let label = str_of(label);
+    with_chart!(&mut (*chart).0, c => status(
+        draw_histogram(c, values, bins, color_of(color), label)
+    ))
+}
+
+/// Draw the legend of the labeled series
+///
+/// # Safety
+/// `chart` must be a valid chart handle
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_legend(chart: *mut PlottersChart) -> c_int {
+    with_chart!(&mut (*chart).0, c => status(
+        c.configure_series_labels()
+            .background_style(&WHITE.mix(0.8))
+            .border_style(&BLACK)
+            .draw()
+    ))
+}
+
+/// Free the chart handle
+///
+/// # Safety
+/// `chart` must be null or a valid chart handle, which isn't used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn plotters_chart_free(chart: *mut PlottersChart) {
+    free_handle(chart)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bin_counts() {
+        let values = [0.0, 0.5, 1.0, 2.5, 3.0, -1.0, 4.0];
+        assert_eq!(bin_counts(&values, (0.0, 3.0), 3), [2, 1, 2]);
+    }
+
+    #[test]
+    fn test_c_interface() {
+        let path = std::env::temp_dir().join(format!("plotters-cffi-{}.svg", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let caption = CString::new("Caption").unwrap();
+        let label = CString::new("Series").unwrap();
+        let xs = [0.0, 1.0, 2.0];
+        let ys = [1.0, 3.0, 2.0];
+        unsafe {
+            let backend = plotters_svg_backend(c_path.as_ptr(), 400, 300);
+            let root = plotters_backend_area(backend);
+            assert_eq!(plotters_area_fill(root, 0xffffff), 0);
+
+            assert!(plotters_area_split(root, 1, 2, 2).is_null());
+            assert!(!plotters_last_error().is_null());
+            let left = plotters_area_split(root, 1, 2, 0);
+
+            let chart = plotters_chart_new(left, caption.as_ptr(), 0.0, 2.0, 0.0, 4.0);
+            assert!(!chart.is_null());
+            assert_eq!(
+                plotters_chart_line(chart, xs.as_ptr(), ys.as_ptr(), 3, 0xff0000, label.as_ptr()),
+                0
+            );
+            assert_eq!(
+                plotters_chart_scatter(
+                    chart,
+                    xs.as_ptr(),
+                    ys.as_ptr(),
+                    3,
+                    2,
+                    0x00ff00,
+                    ptr::null()
+                ),
+                0
+            );
+            assert_eq!(
+                plotters_chart_histogram(chart, ys.as_ptr(), 3, 2, 0x0000ff, ptr::null()),
+                0
+            );
+            assert_eq!(plotters_chart_legend(chart), 0);
+
+            // An empty range is a layout error
+            assert!(plotters_chart_new(left, ptr::null(), 1.0, 1.0, 0.0, 1.0).is_null());
+
+            assert_eq!(plotters_backend_present(backend), 0);
+            plotters_chart_free(chart);
+            plotters_area_free(left);
+            plotters_area_free(root);
+            plotters_backend_free(backend);
+        }
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(svg.contains("Caption") && svg.contains("Series"));
+        assert!(svg.contains("#FF0000") || svg.contains("#ff0000"));
+    }
+}
@@ -0,0 +1,183 @@
+/*!
+  The Python bindings of Plotters, which expose the quick plots of `plotters::quick` and
+  render them to the files, the SVG strings or the RGB pixels.
+
+  ```python
+  import plotters
+
+  xs = [x / 10 for x in range(100)]
+  plot = plotters.line(xs, [x * x for x in xs]).title("x^2").label("square")
+  plot.save("square.png")
+  svg = plot.to_svg()
+  ```
+
+  The plots are shown as SVG images in the Jupyter notebooks.
+*/
+use std::mem;
+
+use plotters::error::PlottersError as RenderError;
+use plotters::prelude::*;
+use plotters::quick::QuickPlot;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+create_exception!(
+    plotters,
+    PlottersError,
+    PyException,
+    "The error of rendering a plot"
+);
+
+fn py_error(error: RenderError) -> PyErr {
+    PlottersError::new_err(error.to_string())
+}
+
+/// A plot, the methods setting its properties return the plot itself so they can be chained
+#[pyclass(name = "Plot", module = "plotters")]
+struct Plot {
+    inner: QuickPlot,
+    size: (u32, u32),
+}
+
+impl Plot {
+    fn new() -> Self {
+        Self {
+            inner: QuickPlot::new(),
+            size: (640, 480),
+        }
+    }
+
+    fn update(&mut self, f: impl FnOnce(QuickPlot) -> QuickPlot) {
+        self.inner = f(mem::take(&mut self.inner));
+    }
+
+    fn render_svg(&self) -> Result<String, RenderError> {
+        let mut buf = String::new();
+        {
+            let root = SVGBackend::with_string(&mut buf, self.size).into_drawing_area();
+            root.fill(&WHITE)?;
+            self.inner.draw_on(&root)?;
+            root.present()?;
+        }
+        Ok(buf)
+    }
+
+    fn render_rgb(&self) -> Result<Vec<u8>, RenderError> {
+        let (w, h) = self.size;
+        let mut buf = vec![0; w as usize * h as usize * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buf, self.size).into_drawing_area();
+            root.fill(&WHITE)?;
+            self.inner.draw_on(&root)?;
+            root.present()?;
+        }
+        Ok(buf)
+    }
+}
+
+#[pymethods]
+impl Plot {
+    /// Add a line series
+    fn line<'py>(mut slf: PyRefMut<'py, Self>, xs: Vec<f64>, ys: Vec<f64>) -> PyRefMut<'py, Self> {
+        slf.update(|p| p.line(&xs, &ys));
+        slf
+    }
+
+    /// Add a scatter series
+    fn scatter<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+    ) -> PyRefMut<'py, Self> {
+        slf.update(|p| p.scatter(&xs, &ys));
+        slf
+    }
+
+    /// Set the label of the last series added, which is shown in the legend
+    fn label(mut slf: PyRefMut<'_, Self>, label: String) -> PyRefMut<'_, Self> {
+        slf.update(|p| p.label(label));
+        slf
+    }
+
+    /// Set the caption of the plot
+    fn title(mut slf: PyRefMut<'_, Self>, title: String) -> PyRefMut<'_, Self> {
+        slf.update(|p| p.title(title));
+        slf
+    }
+
+    /// Set the description of the X axis
+    fn x_label(mut slf: PyRefMut<'_, Self>, label: String) -> PyRefMut<'_, Self> {
+        slf.update(|p| p.x_label(label));
+        slf
+    }
+
+    /// Set the description of the Y axis
+    fn y_label(mut slf: PyRefMut<'_, Self>, label: String) -> PyRefMut<'_, Self> {
+        slf.update(|p| p.y_label(label));
+        slf
+    }
+
+    /// Set the size of the image in pixels, the default is 640x480
+    fn size(mut slf: PyRefMut<'_, Self>, width: u32, height: u32) -> PyRefMut<'_, Self> {
+        slf.size = (width, height);
+        slf.update(|p| p.size(width, height));
+        slf
+    }
+
+    /// Save the plot to a file, the `.svg` files are SVG images and the others are bitmaps in
+    /// the format of the extension
+    fn save(&self, py: Python<'_>, path: String) -> PyResult<()> {
+        py.allow_threads(|| self.inner.save(&path))
+            .map_err(py_error)
+    }
+
+    /// Render the plot as an SVG document
+    fn to_svg(&self, py: Python<'_>) -> PyResult<String> {
+        py.allow_threads(|| self.render_svg()).map_err(py_error)
+    }
+
+    /// Render the plot as the RGB pixels, row by row
+    fn to_rgb<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let pixels = py.allow_threads(|| self.render_rgb()).map_err(py_error)?;
+        Ok(PyBytes::new(py, &pixels))
+    }
+
+    fn _repr_svg_(&self, py: Python<'_>) -> PyResult<String> {
+        self.to_svg(py)
+    }
+}
+
+/// Create an empty plot
+#[pyfunction]
+fn plot() -> Plot {
+    Plot::new()
+}
+
+/// Plot the points `(xs[i], ys[i])` connected with a line
+#[pyfunction]
+fn line(xs: Vec<f64>, ys: Vec<f64>) -> Plot {
+    let mut plot = Plot::new();
+    plot.update(|p| p.line(&xs, &ys));
+    plot
+}
+
+/// Plot the points `(xs[i], ys[i])` as circles
+#[pyfunction]
+fn scatter(xs: Vec<f64>, ys: Vec<f64>) -> Plot {
+    let mut plot = Plot::new();
+    plot.update(|p| p.scatter(&xs, &ys));
+    plot
+}
+
+#[pymodule]
+#[pyo3(name = "plotters")]
+fn plotters_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Plot>()?;
+    m.add_function(wrap_pyfunction!(plot, m)?)?;
+    m.add_function(wrap_pyfunction!(line, m)?)?;
+    m.add_function(wrap_pyfunction!(scatter, m)?)?;
+    m.add("PlottersError", m.py().get_type::<PlottersError>())?;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+import os
+import tempfile
+import unittest
+
+import plotters
+
+
+class TestPlot(unittest.TestCase):
+    def test_svg(self):
+        plot = plotters.line([0, 1, 2], [1.0, 3.0, 2.0]).title("Quick").label("first")
+        plot = plot.scatter([0.5], [2.5]).x_label("x").size(400, 300)
+        svg = plot.to_svg()
+        self.assertTrue(svg.startswith("<svg"))
+        self.assertIn('width="400"', svg)
+        self.assertIn("Quick", svg)
+        self.assertEqual(plot._repr_svg_(), svg)
+
+    def test_rgb(self):
+        pixels = plotters.scatter([0, 1], [0, 1]).size(160, 120).to_rgb()
+        self.assertEqual(len(pixels), 160 * 120 * 3)
+        # The background is white
+        self.assertEqual(pixels[:3], b"\xff\xff\xff")
+
+    def test_save(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "plot.png")
+            plotters.line([0, 1], [0, 1]).save(path)
+            with open(path, "rb") as f:
+                self.assertEqual(f.read(4), b"\x89PNG")
+
+    def test_error(self):
+        # An empty range can't be drawn
+        with self.assertRaises(plotters.PlottersError):
+            plotters.line([1, 1], [1, 1]).to_svg()
+
+
+if __name__ == "__main__":
+    unittest.main()
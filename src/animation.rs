@@ -0,0 +1,344 @@
+/*!
+  The keyframe animations of the charts.
+
+  The parameters of a chart, such as the ranges of the axes, the data of the series or the
+  opacity of an element, are given at some key times with `Keyframes`, and the values between
+  them are interpolated with `Interpolate`. `Animation` samples the keyframes at a frame rate
+  and draws each frame with a closure, presenting the drawing area after each frame. So it can
+  feed the GIF backend, which writes a frame on each `present`, or a real-time backend.
+
+  ```rust,no_run
+  use plotters::animation::{Animation, Easing, Keyframes};
+  use plotters::prelude::*;
+
+  // The upper bound of the X axis and the phase of the wave
+  let keyframes = Keyframes::new((1.0, 0.0))
+      .key(1.0, (3.0, 0.0))
+      .key_with_easing(2.0, (3.0, 6.28), Easing::EaseInOut);
+
+  let root = BitMapBackend::gif("wave.gif", (300, 200), 40)
+      .unwrap()
+      .into_drawing_area();
+  Animation::new(keyframes, 25.0)
+      .render(&root, |area, &(x_max, phase)| {
+          area.fill(&WHITE)?;
+          let mut chart = ChartBuilder::on(area).build_ranged(0.0..x_max, -1.0..1.0)?;
+          chart.draw_series(LineSeries::new(
+              (0..=100).map(|i| i as f64 * x_max / 100.0).map(|x| (x, (x * 3.0 + phase).sin())),
+              &RED,
+          ))?;
+          Ok(())
+      })
+      .unwrap();
+  ```
+*/
+use core::ops::Range;
+
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::RGBAColor;
+
+/// The values which can be interpolated between two keyframes
+pub trait Interpolate: Clone {
+    /// Get the value at `t` between the value, when `t` is 0, and the other value, when `t`
+    /// is 1
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+macro_rules! impl_interpolate_float {
+    ($($t:ty),*) => {$(
+        impl Interpolate for $t {
+            fn interpolate(&self, other: &Self, t: f64) -> Self {
+                *self + (*other - *self) * t as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_interpolate_int {
+    ($($t:ty),*) => {$(
+        impl Interpolate for $t {
+            fn interpolate(&self, other: &Self, t: f64) -> Self {
+                let value = *self as f64 + (*other as f64 - *self as f64) * t;
+                (value + 0.5).floor() as $t
+            }
+        }
+    )*};
+}
+
+impl_interpolate_float!(f32, f64);
+impl_interpolate_int!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+impl<T: Interpolate> Interpolate for Range<T> {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.start.interpolate(&other.start, t)..self.end.interpolate(&other.end, t)
+    }
+}
+
+/// The elements are interpolated pairwise, the elements at the end which only one of the
+/// values has are kept until `t` reaches 1, so the data points can be added or removed
+impl<T: Interpolate> Interpolate for Vec<T> {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        let rest = if t < 1.0 { self } else { other };
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.interpolate(b, t))
+            .chain(rest.iter().skip(self.len().min(other.len())).cloned())
+            .collect()
+    }
+}
+
+impl<T: Interpolate> Interpolate for Option<T> {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, t)),
+            _ if t < 1.0 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+impl Interpolate for RGBAColor {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        RGBAColor(
+            self.0.interpolate(&other.0, t),
+            self.1.interpolate(&other.1, t),
+            self.2.interpolate(&other.2, t),
+            self.3.interpolate(&other.3, t),
+        )
+    }
+}
+
+macro_rules! impl_interpolate_tuple {
+    ($($name:ident $idx:tt),*) => {
+        impl<$($name: Interpolate),*> Interpolate for ($($name,)*) {
+            fn interpolate(&self, other: &Self, t: f64) -> Self {
+                ($(self.$idx.interpolate(&other.$idx, t),)*)
+            }
+        }
+    };
+}
+
+impl_interpolate_tuple!(A 0);
+impl_interpolate_tuple!(A 0, B 1);
+impl_interpolate_tuple!(A 0, B 1, C 2);
+impl_interpolate_tuple!(A 0, B 1, C 2, D 3);
+impl_interpolate_tuple!(A 0, B 1, C 2, D 3, E 4);
+
+/// How the values are interpolated from a keyframe to the next one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// At a constant speed
+    #[default]
+    Linear,
+    /// Start slowly and speed up
+    EaseIn,
+    /// Start quickly and slow down
+    EaseOut,
+    /// Start and end slowly
+    EaseInOut,
+    /// Keep the value of the previous keyframe, and jump to the next one
+    Step,
+}
+
+impl Easing {
+    /// Map the linear progress between two keyframes, in `[0, 1]`, to the eased progress
+    pub fn apply(self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::Step if t < 1.0 => 0.0,
+            Easing::Step => 1.0,
+        }
+    }
+}
+
+/// The values of the parameters at the key times, the time is in seconds
+#[derive(Clone, Debug)]
+pub struct Keyframes<T> {
+    // The time, the value and the easing from the previous keyframe, sorted by the time
+    frames: Vec<(f64, T, Easing)>,
+}
+
+impl<T: Interpolate> Keyframes<T> {
+    /// Create the keyframes with the initial value at time 0
+    pub fn new(initial: T) -> Self {
+        Self {
+            frames: vec![(0.0, initial, Easing::Linear)],
+        }
+    }
+
+    /// Add a keyframe which is reached linearly from the previous one
+    /// - `time`: The time of the keyframe, the keyframes added at the same time as an earlier
+    ///   one replace it
+    /// - `value`: The value at the time
+    pub fn key(self, time: f64, value: T) -> Self {
+        self.key_with_easing(time, value, Easing::Linear)
+    }
+
+    /// Add a keyframe which is reached with the easing from the previous one
+    pub fn key_with_easing(mut self, time: f64, value: T, easing: Easing) -> Self {
+        let time = time.max(0.0);
+        let idx = self.frames.partition_point(|(t, _, _)| *t < time);
+        match self.frames.get_mut(idx) {
+            Some(frame) if frame.0 == time => *frame = (time, value, easing),
+            _ => self.frames.insert(idx, (time, value, easing)),
+        }
+        self
+    }
+
+    /// The time of the last keyframe
+    pub fn duration(&self) -> f64 {
+        self.frames.last().map_or(0.0, |frame| frame.0)
+    }
+
+    /// Get the value at the time, the values before the first keyframe and after the last
+    /// keyframe are the values of these keyframes
+    pub fn value_at(&self, time: f64) -> T {
+        let idx = self.frames.partition_point(|(t, _, _)| *t <= time);
+        if idx == 0 {
+            return self.frames[0].1.clone();
+        }
+        let (from_time, from, _) = &self.frames[idx - 1];
+        match self.frames.get(idx) {
+            Some((to_time, to, easing)) => {
+                let t = (time - from_time) / (to_time - from_time);
+                from.interpolate(to, easing.apply(t))
+            }
+            None => from.clone(),
+        }
+    }
+}
+
+/// A frame of an animation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame<T> {
+    /// The index of the frame
+    pub index: usize,
+    /// The time of the frame in seconds
+    pub time: f64,
+    /// The value of the parameters at the time
+    pub value: T,
+}
+
+/// The keyframes sampled at a frame rate
+#[derive(Clone, Debug)]
+pub struct Animation<T> {
+    keyframes: Keyframes<T>,
+    fps: f64,
+}
+
+impl<T: Interpolate> Animation<T> {
+    /// Create an animation
+    /// - `keyframes`: The values of the parameters at the key times
+    /// - `fps`: The number of frames per second, which should match the frame delay of the GIF
+    ///   backend, that is `1000 / fps` milliseconds
+    pub fn new(keyframes: Keyframes<T>, fps: f64) -> Self {
+        Self {
+            keyframes,
+            fps: fps.max(f64::MIN_POSITIVE),
+        }
+    }
+
+    /// The number of frames, the first and the last frames are at the first and the last
+    /// keyframes
+    pub fn num_frames(&self) -> usize {
+        (self.keyframes.duration() * self.fps).round() as usize + 1
+    }
+
+    /// Iterate over the frames
+    pub fn frames(&self) -> impl Iterator<Item = Frame<T>> + '_ {
+        (0..self.num_frames()).map(move |index| {
+            let time = index as f64 / self.fps;
+            Frame {
+                index,
+                time,
+                value: self.keyframes.value_at(time),
+            }
+        })
+    }
+
+    /// Draw each frame on the drawing area with the closure, and present the drawing area
+    /// after each frame
+    /// - `area`: The drawing area, usually the root area of a GIF or real-time backend
+    /// - `draw`: The closure drawing a frame with the values of the parameters
+    pub fn render<DB, F>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        mut draw: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend,
+        F: FnMut(&DrawingArea<DB, Shift>, &T) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        for frame in self.frames() {
+            draw(area, &frame.value)?;
+            area.present()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(1.0.interpolate(&3.0, 0.25), 1.5);
+        assert_eq!(0i32.interpolate(&10, 0.26), 3);
+        assert_eq!((0.0..1.0).interpolate(&(1.0..3.0), 0.5), 0.5..2.0);
+        assert_eq!((1u8, 2.0).interpolate(&(3, 4.0), 0.5), (2, 3.0));
+        assert_eq!(vec![0.0, 1.0].interpolate(&vec![1.0], 0.5), vec![0.5, 1.0]);
+        assert_eq!(vec![0.0, 1.0].interpolate(&vec![1.0], 1.0), vec![1.0]);
+        assert_eq!(vec![0.0].interpolate(&vec![1.0, 2.0], 1.0), vec![1.0, 2.0]);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::Step.apply(0.9), 0.0);
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let keyframes = Keyframes::new(0.0)
+            .key(2.0, 4.0)
+            .key(1.0, 1.0)
+            .key_with_easing(3.0, 0.0, Easing::Step)
+            .key(2.0, 2.0);
+        assert_eq!(keyframes.duration(), 3.0);
+        assert_eq!(keyframes.value_at(-1.0), 0.0);
+        assert_eq!(keyframes.value_at(0.5), 0.5);
+        assert_eq!(keyframes.value_at(1.5), 1.5);
+        assert_eq!(keyframes.value_at(2.5), 2.0);
+        assert_eq!(keyframes.value_at(3.0), 0.0);
+        assert_eq!(keyframes.value_at(10.0), 0.0);
+
+        let animation = Animation::new(keyframes, 2.0);
+        assert_eq!(animation.num_frames(), 7);
+        let values: Vec<_> = animation.frames().map(|frame| frame.value).collect();
+        assert_eq!(values, [0.0, 0.5, 1.0, 1.5, 2.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_render_animation() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 5);
+                assert!(b.num_present_call >= 5);
+            });
+        });
+        let keyframes = Keyframes::new(10).key(1.0, 50);
+        let mut xs = vec![];
+        Animation::new(keyframes, 4.0)
+            .render(&drawing_area, |area, &x| {
+                xs.push(x);
+                area.draw(&Circle::new((x, 50), 5, &RED))
+            })
+            .unwrap();
+        assert_eq!(xs, [10, 20, 30, 40, 50]);
+    }
+}
@@ -1,9 +1,50 @@
 use super::context::ChartContext;
 
-use crate::coord::{AsRangedCoord, RangedCoord, Shift};
-use crate::drawing::backend::DrawingBackend;
+use crate::coord::{AsRangedCoord, Ranged, RangedCoord, Shift};
+use crate::drawing::backend::{DrawingBackend, RenderEvent, RenderPhase};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::style::{
+    AsRelative, Color, FontDesc, FontStyle, IntoTextStyle, SizeDesc, TextStyle, Theme, BLACK,
+};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::fmt::Debug;
+
+// The error of a layout step which leaves no room for the chart
+fn layout_error<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    step: &'static str,
+) -> DrawingAreaErrorKind<DB::ErrorType> {
+    area.in_step(step)(DrawingAreaErrorKind::LayoutError)
+}
+
+// Check if the area left for the chart is neither empty nor inverted
+fn check_area<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    step: &'static str,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let (x, y) = area.get_pixel_range();
+    if x.start < x.end && y.start < y.end {
+        Ok(())
+    } else {
+        Err(layout_error(area, step))
+    }
+}
+
+// Check if the range of an axis covers more than a single value, an empty range or a range
+// with NaN or infinite bounds maps its both ends to the same pixel
+fn check_range<R: Ranged, DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    spec: &R,
+    step: &'static str,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let range = spec.range();
+    if spec.map(&range.start, (0, 1 << 16)) == spec.map(&range.end, (0, 1 << 16)) {
+        return Err(layout_error(area, step));
+    }
+    Ok(())
+}
 
 /// The enum used to specify the position of label area.
 /// This is used when we configure the label area size with the API `set_label_area_size`
@@ -20,10 +61,12 @@ pub enum LabelAreaPosition {
 /// allows the high-level charting API being used on the drawing area.
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
+    label_area_set: [bool; 4],
     overlap_plotting_area: [bool; 4],
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: [u32; 4],
+    theme: Option<Theme>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -33,13 +76,25 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     pub fn on(root: &'a DrawingArea<DB, Shift>) -> Self {
         Self {
             label_area_size: [0; 4],
+            label_area_set: [false; 4],
             root_area: root,
             title: None,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            theme: None,
         }
     }
 
+    /// Set the theme of the chart. The drawing area is filled with the background color of the
+    /// theme, the caption in the default black color is drawn in the text color of the theme,
+    /// and the mesh and the series labels of the chart use the theme for the styles which are
+    /// not set explicitly.
+    /// - `theme`: The theme to use
+    pub fn theme(&mut self, theme: &Theme) -> &mut Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
     /// Set the margin size of the chart (applied for top, bottom, left and right at the same time)
     /// - `size`: The size of the chart margin.
     pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
@@ -130,6 +185,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     ) -> &mut Self {
         let size = size.in_pixels(self.root_area);
         self.label_area_size[pos as usize] = size.abs() as u32;
+        self.label_area_set[pos as usize] = true;
         self.overlap_plotting_area[pos as usize] = size < 0;
         self
     }
@@ -151,10 +207,27 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     }
 
     /// Build the chart with a 2D Cartesian coordinate system. The function will returns a chart
-    /// context, where data series can be rendered on.
+    /// context, where data series can be rendered on. The types of the axes are inferred from
+    /// the ranges, such as `RangedCoordf32` for `-1f32..1f32`, so they never need to be named.
     /// - `x_spec`: The specification of X axis
     /// - `y_spec`: The specification of Y axis
-    /// - Returns: A chart context
+    /// - Returns: A chart context, or a `DrawingAreaErrorKind::LayoutError` if the margins, the
+    ///   caption and the label areas leave no room for the plotting area, or a range covers a
+    ///   single value only, such as `1.0..1.0`, the `ErrorContext` of the error tells the step
+    ///   which failed when the drawing area attaches it, see `DrawingArea::attach_error_context`
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (100, 100)).into_drawing_area();
+    /// let error = ChartBuilder::on(&root)
+    ///     .set_label_area_size(LabelAreaPosition::Left, 120)
+    ///     .build_ranged(0..10, 0..10)
+    ///     .err()
+    ///     .unwrap();
+    /// assert!(matches!(error, DrawingAreaErrorKind::LayoutError));
+    /// ```
     #[allow(clippy::type_complexity)]
     pub fn build_ranged<X: AsRangedCoord, Y: AsRangedCoord>(
         &mut self,
@@ -164,10 +237,108 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
+        self.build_coord(x_spec.into(), y_spec.into())
+    }
+
+    /// Build the chart like `build_ranged`, but the label areas on the left and the bottom
+    /// which are not set explicitly are sized to fit the default tick labels of the axes, so
+    /// long labels or large fonts are not clipped. The labels are measured with the default
+    /// label font and formatter of `configure_mesh`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (400, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_ranged_fitted(0.0..1.0, 0.0..5_000_000.0)
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged_fitted<X: AsRangedCoord, Y: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X::Value: Debug,
+        Y::Value: Debug,
+    {
+        let (x_spec, y_spec): (X::CoordDescType, Y::CoordDescType) = (x_spec.into(), y_spec.into());
+
+        // The mesh scales the label font and the ticks with the plotting area and caps them,
+        // the plotting area is smaller than this, so the fitted areas are never too small
+        let (w, h) = self.root_area.dim_in_pixel();
+        let dim = (
+            w.saturating_sub(self.margin[2] + self.margin[3]),
+            h.saturating_sub(self.margin[0] + self.margin[1]),
+        );
+        let family = self
+            .theme
+            .as_ref()
+            .map_or_else(|| Theme::default().font_family, |theme| theme.font_family);
+        let font = FontDesc::new(
+            family,
+            f64::from((12i32).percent().max(12).in_pixels(&dim)),
+            FontStyle::Normal,
+        );
+        let tick_size = (5u32).percent().max(5).in_pixels(&dim) as u32;
+
+        let mut fit = |pos: LabelAreaPosition, labels: Vec<String>, vertical: bool| {
+            if self.label_area_set[pos as usize] {
+                return Ok(());
+            }
+            let mut size = 0;
+            for label in labels.iter() {
+                let (lw, lh) = self.root_area.estimate_text_size(label, &font)?;
+                size = size.max(if vertical { lw } else { lh });
+            }
+            // The labels are drawn twice the tick size away from the axis
+            self.label_area_size[pos as usize] = size + tick_size * 3;
+            Ok(())
+        };
+
+        let format = |v: &dyn Debug| format!("{:?}", v);
+        fit(
+            LabelAreaPosition::Bottom,
+            x_spec.key_points(10).iter().map(|v| format(v)).collect(),
+            false,
+        )?;
+        fit(
+            LabelAreaPosition::Left,
+            y_spec.key_points(10).iter().map(|v| format(v)).collect(),
+            true,
+        )?;
+
+        self.build_coord(x_spec, y_spec)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn build_coord<X: Ranged, Y: Ranged>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+    ) -> Result<ChartContext<'a, DB, RangedCoord<X, Y>>, DrawingAreaErrorKind<DB::ErrorType>> {
+        check_range(self.root_area, &x_spec, "x range")?;
+        check_range(self.root_area, &y_spec, "y range")?;
+        check_area(self.root_area, "drawing area")?;
+
+        self.root_area
+            .render_event(RenderEvent::PhaseStart(RenderPhase::Layout));
         let mut label_areas = [None, None, None, None];
 
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
+        if let Some(theme) = &self.theme {
+            drawing_area
+                .fill(&theme.background)
+                .map_err(drawing_area.in_step("background"))?;
+        }
+
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
             drawing_area = drawing_area.margin(
                 self.margin[0] as i32,
@@ -175,11 +346,20 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 self.margin[2] as i32,
                 self.margin[3] as i32,
             );
+            check_area(&drawing_area, "margins")?;
         }
 
         let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
             let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
+            let mut style = style.clone();
+            if let Some(theme) = &self.theme {
+                if style.color == BLACK.to_rgba() {
+                    style.color = theme.text_color.clone();
+                }
+            }
+            let in_step = drawing_area.in_step("caption");
+            drawing_area = drawing_area.titled(title, style).map_err(in_step)?;
+            check_area(&drawing_area, "caption")?;
             let (current_dx, current_dy) = drawing_area.get_base_pixel();
             (current_dx - origin_dx, current_dy - origin_dy)
         } else {
@@ -204,6 +384,13 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             actual_drawing_area_pos[idx] += split_point;
         }
 
+        // The label areas larger than the space left would invert the plotting area
+        if actual_drawing_area_pos[0] >= actual_drawing_area_pos[1]
+            || actual_drawing_area_pos[2] >= actual_drawing_area_pos[3]
+        {
+            return Err(layout_error(&drawing_area, "label areas"));
+        }
+
         let mut split: Vec<_> = drawing_area
             .split_by_breakpoints(
                 &actual_drawing_area_pos[2..4],
@@ -213,13 +400,13 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             .map(Some)
             .collect();
 
-        std::mem::swap(&mut drawing_area, split[4].as_mut().unwrap());
+        core::mem::swap(&mut drawing_area, split[4].as_mut().unwrap());
 
         for (src_idx, dst_idx) in [1, 7, 3, 5].iter().zip(0..4) {
             if !self.overlap_plotting_area[dst_idx] {
                 let (h, w) = split[*src_idx].as_ref().unwrap().dim_in_pixel();
                 if h > 0 && w > 0 {
-                    std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
+                    core::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
                 }
             } else if self.label_area_size[dst_idx] != 0 {
                 let size = self.label_area_size[dst_idx] as i32;
@@ -251,11 +438,13 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         let mut x_label_area = [None, None];
         let mut y_label_area = [None, None];
 
-        std::mem::swap(&mut x_label_area[0], &mut label_areas[0]);
-        std::mem::swap(&mut x_label_area[1], &mut label_areas[1]);
-        std::mem::swap(&mut y_label_area[0], &mut label_areas[2]);
-        std::mem::swap(&mut y_label_area[1], &mut label_areas[3]);
+        core::mem::swap(&mut x_label_area[0], &mut label_areas[0]);
+        core::mem::swap(&mut x_label_area[1], &mut label_areas[1]);
+        core::mem::swap(&mut y_label_area[0], &mut label_areas[2]);
+        core::mem::swap(&mut y_label_area[1], &mut label_areas[3]);
 
+        self.root_area
+            .render_event(RenderEvent::PhaseEnd(RenderPhase::Layout));
         Ok(ChartContext {
             x_label_area,
             y_label_area,
@@ -265,10 +454,12 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            hit_index: None,
             drawing_area_pos: (
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
             ),
+            theme: self.theme.clone().unwrap_or_default(),
         })
     }
 }
@@ -303,6 +494,32 @@ mod test {
         assert_eq!(chart.label_area_size[3], 200);
     }
 
+    #[test]
+    fn test_build_ranged_fitted() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let font = FontDesc::new(FontFamily::SansSerif, 12.0, FontStyle::Normal);
+        let (label_width, label_height) =
+            drawing_area.estimate_text_size("4500000.0", &font).unwrap();
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged_fitted(0.0..1.0, 0.0..5_000_000.0)
+            .unwrap();
+        let (w, _) = chart.y_label_area[0].as_ref().unwrap().dim_in_pixel();
+        let (_, h) = chart.x_label_area[1].as_ref().unwrap().dim_in_pixel();
+        // The labels are 12 pixels high and the ticks are 5 pixels long
+        assert_eq!(w, label_width + 15);
+        assert!(h >= label_height + 15);
+
+        // The explicit sizes are kept
+        let chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(20)
+            .build_ranged_fitted(0.0..1.0, 0.0..5_000_000.0)
+            .unwrap();
+        let (w, _) = chart.y_label_area[0].as_ref().unwrap().dim_in_pixel();
+        assert_eq!(w, 20);
+        assert!(chart.y_label_area[1].is_none());
+    }
+
     #[test]
     fn test_margin_configure() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -342,4 +559,65 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_build_ranged_validation() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {}).attach_error_context(true);
+        let step = |chart: &mut ChartBuilder<_>, x: std::ops::Range<f64>| {
+            let error = chart.build_ranged(x, 0.0..1.0).err().unwrap();
+            assert!(matches!(
+                error.root_cause(),
+                DrawingAreaErrorKind::LayoutError
+            ));
+            error.context().unwrap().step.unwrap()
+        };
+
+        let mut chart = ChartBuilder::on(&drawing_area);
+        assert_eq!(step(&mut chart, 1.0..1.0), "x range");
+        assert_eq!(step(&mut chart, 0.0..f64::NAN), "x range");
+        chart.margin(100);
+        assert_eq!(step(&mut chart, 0.0..1.0), "margins");
+
+        let mut chart = ChartBuilder::on(&drawing_area);
+        chart.caption("Caption", ("sans-serif", 300));
+        assert_eq!(step(&mut chart, 0.0..1.0), "caption");
+
+        let mut chart = ChartBuilder::on(&drawing_area);
+        chart.x_label_area_size(150).top_x_label_area_size(50);
+        assert_eq!(step(&mut chart, 0.0..1.0), "label areas");
+        // The label areas overlapping the plotting area don't take its room
+        chart.overlap_plotting_area[0] = true;
+        assert!(chart.build_ranged(0.0..1.0, 0.0..1.0).is_ok());
+    }
+
+    #[test]
+    fn test_theme() {
+        let theme = Theme::dark();
+        let background = theme.background.clone();
+        let text_color = theme.text_color.clone();
+        let axis_color = theme.axis_color.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert_eq!(c, background);
+                assert!(filled);
+                assert_eq!((ul, br), ((0, 0), (199, 199)));
+            });
+            m.check_draw_text(move |c, _, _, _, _| {
+                // The caption and the labels
+                assert_eq!(c, text_color);
+            });
+            m.check_draw_path(move |c, _, _| {
+                assert_eq!(c, axis_color);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .theme(&theme)
+            .caption("caption", ("sans-serif", 10))
+            .set_all_label_area_size(20)
+            .build_ranged(0..10, 0..10)
+            .unwrap();
+        chart.configure_mesh().disable_mesh().draw().unwrap();
+        assert_eq!(chart.theme.background, theme.background);
+    }
 }
@@ -0,0 +1,248 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::Rectangle;
+use crate::style::{Color, ColorMap, FontDesc, LinearColorMap, RGBColor, TextStyle, BLACK};
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The helper that draws a calendar heatmap, a.k.a. the GitHub contribution graph.
+///
+/// Each day is a cell, the columns are weeks starting from Sunday and the rows are the days
+/// of the week. The values of the same day are summed up, and the cell color is taken from the
+/// color map, which goes from the low to the high color by default. Days without any value are
+/// filled with the empty color.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use plotters::prelude::*;
+///
+/// let day = |d| NaiveDate::from_ymd_opt(2020, 1, d).unwrap();
+/// let calendar = CalendarHeatmap::new(vec![(day(1), 3.0), (day(14), 1.0)]);
+/// // 2020-01-01 is a Wednesday in the first week
+/// assert_eq!(calendar.cell_position(day(1)), Some((0, 3)));
+/// assert_eq!(calendar.week_count(), 3);
+/// ```
+pub struct CalendarHeatmap {
+    values: Vec<(NaiveDate, f64)>,
+    first_day: Option<NaiveDate>,
+    last_day: Option<NaiveDate>,
+    cell_size: u32,
+    color_map: Box<dyn ColorMap>,
+    empty_color: RGBColor,
+    font: FontDesc<'static>,
+}
+
+impl CalendarHeatmap {
+    /// Create a new calendar heatmap
+    /// - `data`: The iterator of `(date, value)` pairs, non-finite values are ignored
+    /// - **returns** The newly created chart helper
+    pub fn new<I: IntoIterator<Item = (NaiveDate, f64)>>(data: I) -> Self {
+        let mut values: Vec<(NaiveDate, f64)> = vec![];
+        let mut data: Vec<_> = data.into_iter().filter(|(_, v)| v.is_finite()).collect();
+        data.sort_by_key(|(date, _)| *date);
+        for (date, value) in data {
+            match values.last_mut() {
+                Some(last) if last.0 == date => last.1 += value,
+                _ => values.push((date, value)),
+            }
+        }
+
+        let first_day = values.first().map(|(date, _)| *date);
+        let last_day = values.last().map(|(date, _)| *date);
+        Self {
+            values,
+            first_day,
+            last_day,
+            cell_size: 12,
+            color_map: Box::new(LinearColorMap::new(&[
+                RGBColor(198, 228, 139),
+                RGBColor(25, 97, 39),
+            ])),
+            empty_color: RGBColor(235, 237, 240),
+            font: ("sans-serif", 10).into(),
+        }
+    }
+
+    /// Set the range of dates to draw, by default it's the range of the data
+    pub fn date_range(mut self, first_day: NaiveDate, last_day: NaiveDate) -> Self {
+        if first_day <= last_day {
+            self.first_day = Some(first_day);
+            self.last_day = Some(last_day);
+        }
+        self
+    }
+
+    /// Set the size of each cell in pixels
+    pub fn cell_size(mut self, size: u32) -> Self {
+        self.cell_size = size.max(2);
+        self
+    }
+
+    /// Set the colors used for the smallest and the largest value
+    pub fn colors(self, low: RGBColor, high: RGBColor) -> Self {
+        self.color_map(LinearColorMap::new(&[low, high]))
+    }
+
+    /// Set the color map of the cells, the values from the smallest to the largest are mapped
+    /// to the color map
+    pub fn color_map<M: ColorMap + 'static>(mut self, color_map: M) -> Self {
+        self.color_map = Box::new(color_map);
+        self
+    }
+
+    /// Set the color of the days without any value
+    pub fn empty_color(mut self, color: RGBColor) -> Self {
+        self.empty_color = color;
+        self
+    }
+
+    /// Set the font used by the month and weekday labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    fn first_sunday(&self) -> Option<NaiveDate> {
+        let first_day = self.first_day?;
+        Some(first_day - Duration::days(i64::from(first_day.weekday().num_days_from_sunday())))
+    }
+
+    /// Get the number of week columns
+    pub fn week_count(&self) -> usize {
+        match (self.first_sunday(), self.last_day) {
+            (Some(begin), Some(end)) => (end - begin).num_days() as usize / 7 + 1,
+            _ => 0,
+        }
+    }
+
+    /// Get the `(week, weekday)` position of the cell for the date, Sunday is weekday 0
+    /// - `date`: The date to query
+    /// - **returns** The position, or `None` if the date is out of the date range
+    pub fn cell_position(&self, date: NaiveDate) -> Option<(usize, usize)> {
+        let begin = self.first_sunday()?;
+        if date < self.first_day? || date > self.last_day? {
+            return None;
+        }
+        let days = (date - begin).num_days() as usize;
+        Some((days / 7, days % 7))
+    }
+
+    fn cell_color(&self, value: f64, range: (f64, f64)) -> RGBColor {
+        let t = if range.1 > range.0 {
+            ((value - range.0) / (range.1 - range.0)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        self.color_map.get_color(t)
+    }
+
+    /// Draw the calendar heatmap on the drawing area, the month labels are drawn above the
+    /// cells and the weekday labels are drawn on the left
+    /// - `area`: The drawing area to draw on
+    /// - **returns** The result of the drawing
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (begin, first_day, last_day) =
+            match (self.first_sunday(), self.first_day, self.last_day) {
+                (Some(begin), Some(first_day), Some(last_day)) => (begin, first_day, last_day),
+                _ => return Ok(()),
+            };
+
+        let size = self.cell_size as i32;
+        let left = area
+            .estimate_text_size("Wed", &self.font)
+            .map(|(w, _)| w as i32 + 4)
+            .unwrap_or(30);
+        let top = area
+            .estimate_text_size("Jan", &self.font)
+            .map(|(_, h)| h as i32 + 4)
+            .unwrap_or(15);
+        let text_style = TextStyle::from(self.font.clone()).color(&BLACK);
+
+        for (row, name) in [(1, "Mon"), (3, "Wed"), (5, "Fri")].iter() {
+            area.draw_text(name, &text_style, (0, top + row * size))?;
+        }
+
+        let min = self.values.iter().map(|(_, v)| *v).fold(f64::NAN, f64::min);
+        let max = self.values.iter().map(|(_, v)| *v).fold(f64::NAN, f64::max);
+
+        let mut values = self.values.iter().peekable();
+        let mut date = first_day;
+        while date <= last_day {
+            let days = (date - begin).num_days() as i32;
+            let (col, row) = (days / 7, days % 7);
+            let ul = (left + col * size, top + row * size);
+            let br = (ul.0 + size, ul.1 + size);
+
+            while let Some((d, _)) = values.peek() {
+                if *d >= date {
+                    break;
+                }
+                values.next();
+            }
+            let color = match values.peek() {
+                Some((d, v)) if *d == date => self.cell_color(*v, (min, max)).to_rgba(),
+                _ => self.empty_color.to_rgba(),
+            };
+
+            let mut cell = Rectangle::new([ul, br], color.filled());
+            cell.set_margin(1, 1, 1, 1);
+            area.draw(&cell)?;
+
+            if date.day() == 1 || date == first_day {
+                // The month label goes to the first week which starts in the month
+                let label_col = if row == 0 || date == first_day {
+                    col
+                } else {
+                    col + 1
+                };
+                area.draw_text(
+                    MONTH_NAMES[date.month0() as usize],
+                    &text_style,
+                    (left + label_col * size, 0),
+                )?;
+            }
+
+            date += Duration::days(1);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_calendar_heatmap() {
+        let day = |d| NaiveDate::from_ymd_opt(2020, 3, d).unwrap();
+        let calendar = CalendarHeatmap::new(vec![
+            (day(3), 1.0),
+            (day(3), 2.0),
+            (day(20), 5.0),
+            (day(10), f64::NAN),
+        ]);
+        assert_eq!(calendar.week_count(), 3);
+        assert_eq!(calendar.cell_position(day(20)), Some((2, 5)));
+        assert_eq!(calendar.cell_position(day(2)), None);
+        assert_eq!(
+            calendar.cell_color(3.0, (3.0, 5.0)).to_rgba(),
+            RGBColor(198, 228, 139).to_rgba()
+        );
+
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 18);
+            });
+        });
+        calendar.draw(&drawing_area).expect("Drawing Error");
+    }
+}
@@ -0,0 +1,241 @@
+use super::{ChartBuilder, LabelFormat};
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::Rectangle;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{Color, ColorMap, FontDesc, ShapeStyle, BLACK};
+use core::ops::Range;
+
+/// The colorbar of a color mapped plot, such as a heat map, a hexbin or a color mapped
+/// scatter plot. It's a strip of the gradient of the color map with an axis of the values the
+/// colors stand for, which is drawn on an area beside the chart.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut buffer, (640, 480)).into_drawing_area();
+///     let (plot_area, bar_area) = root.split_horizontally(560);
+///     let mut chart = ChartBuilder::on(&plot_area)
+///         .margin(10)
+///         .build_ranged(0..10, 0..10)
+///         .unwrap();
+///     chart
+///         .draw_series((0..10).flat_map(|x| {
+///             (0..10).map(move |y| {
+///                 let value = (x * y) as f64;
+///                 Rectangle::new(
+///                     [(x, y), (x + 1, y + 1)],
+///                     Viridis.get_color_normalized(value, 0.0, 81.0).filled(),
+///                 )
+///             })
+///         }))
+///         .unwrap();
+///     Colorbar::new(Viridis, 0.0..81.0)
+///         .caption("x * y")
+///         .draw(&bar_area)
+///         .unwrap();
+/// }
+/// assert!(buffer.contains("x * y"));
+/// ```
+pub struct Colorbar<M: ColorMap> {
+    color_map: M,
+    range: Range<f64>,
+    horizontal: bool,
+    caption: Option<String>,
+    labels: usize,
+    label_format: Option<LabelFormat>,
+    label_area_size: u32,
+    margin: u32,
+    border_style: ShapeStyle,
+    font: FontDesc<'static>,
+}
+
+impl<M: ColorMap> Colorbar<M> {
+    /// Create a new vertical colorbar
+    /// - `color_map`: The color map of the plot
+    /// - `range`: The values mapped to the start and the end of the color map
+    /// - **returns** The newly created colorbar
+    pub fn new(color_map: M, range: Range<f64>) -> Self {
+        Self {
+            color_map,
+            range,
+            horizontal: false,
+            caption: None,
+            labels: 10,
+            label_format: None,
+            label_area_size: 40,
+            margin: 10,
+            border_style: (&BLACK).into(),
+            font: ("sans-serif", 12).into(),
+        }
+    }
+
+    /// Draw the colorbar horizontally with the labels below it, for the area above or below
+    /// the chart
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Set the description of the values, which is drawn along the axis
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Set the maximum number of the labels of the axis
+    pub fn labels(mut self, labels: usize) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Format the labels with one of the built-in formats, see `LabelFormat`
+    pub fn label_format(mut self, format: LabelFormat) -> Self {
+        self.label_format = Some(format);
+        self
+    }
+
+    /// Set the size of the area of the labels and the caption, in pixels
+    pub fn label_area_size(mut self, size: u32) -> Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /// Set the margin around the colorbar in pixels, which should be the margin of the chart
+    /// so the colorbar is as long as the plotting area of the chart
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the style of the border of the strip
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the font of the labels and the caption
+    pub fn font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    // The value and the color of each of the steps of the strip
+    fn steps(&self, count: u32) -> impl Iterator<Item = (f64, f64, ShapeStyle)> + '_ {
+        let count = count.max(1);
+        let Range { start, end } = self.range;
+        (0..count).map(move |idx| {
+            let value = |idx: u32| start + (end - start) * f64::from(idx) / f64::from(count);
+            let ratio = (f64::from(idx) + 0.5) / f64::from(count);
+            (
+                value(idx),
+                value(idx + 1),
+                self.color_map.get_color(ratio).filled(),
+            )
+        })
+    }
+
+    /// Draw the colorbar on the drawing area
+    /// - `area`: The area beside the chart the colorbar is drawn on
+    /// - **returns** The result of the drawing
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut builder = ChartBuilder::on(area);
+        builder.margin(self.margin);
+        let range = self.range.clone();
+
+        if self.horizontal {
+            builder.x_label_area_size(self.label_area_size);
+            let mut chart = builder.build_ranged(range.clone(), 0.0..1.0)?;
+            let count = chart.plotting_area().dim_in_pixel().0;
+            chart.draw_series(
+                self.steps(count)
+                    .map(|(v0, v1, style)| Rectangle::new([(v0, 0.0), (v1, 1.0)], style)),
+            )?;
+            chart.draw_series(core::iter::once(Rectangle::new(
+                [(range.start, 0.0), (range.end, 1.0)],
+                self.border_style.clone(),
+            )))?;
+
+            let mut mesh = chart.configure_mesh();
+            mesh.disable_mesh()
+                .x_labels(self.labels)
+                .label_style(self.font.clone());
+            if let Some(format) = self.label_format {
+                mesh.x_label_format(format);
+            }
+            if let Some(caption) = self.caption.as_ref() {
+                mesh.x_desc(caption.as_str());
+            }
+            mesh.draw()
+        } else {
+            builder.right_y_label_area_size(self.label_area_size);
+            let mut chart = builder.build_ranged(0.0..1.0, range.clone())?;
+            let count = chart.plotting_area().dim_in_pixel().1;
+            chart.draw_series(
+                self.steps(count)
+                    .map(|(v0, v1, style)| Rectangle::new([(0.0, v0), (1.0, v1)], style)),
+            )?;
+            chart.draw_series(core::iter::once(Rectangle::new(
+                [(0.0, range.start), (1.0, range.end)],
+                self.border_style.clone(),
+            )))?;
+
+            let mut mesh = chart.configure_mesh();
+            mesh.disable_mesh()
+                .y_labels(self.labels)
+                .label_style(self.font.clone());
+            if let Some(format) = self.label_format {
+                mesh.y_label_format(format);
+            }
+            if let Some(caption) = self.caption.as_ref() {
+                mesh.y_desc(caption.as_str());
+            }
+            mesh.draw()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colorbar() {
+        let colorbar = Colorbar::new(LinearColorMap::new(&[BLUE, RED]), 0.0..10.0);
+        let steps: Vec<_> = colorbar.steps(4).collect();
+        assert_eq!(steps.len(), 4);
+        assert_eq!((steps[0].0, steps[0].1), (0.0, 2.5));
+        assert_eq!(steps[3].1, 10.0);
+        assert_eq!(
+            steps[0].2.color,
+            LinearColorMap::new(&[BLUE, RED]).get_color(0.125).to_rgba()
+        );
+
+        let drawing_area = create_mocked_drawing_area(80, 200, |m| {
+            m.drop_check(|b| {
+                // A step for each pixel row of the strip and the border
+                assert_eq!(b.num_draw_rect_call, 181);
+                assert!(b.num_draw_text_call >= 5);
+            });
+        });
+        colorbar.draw(&drawing_area).expect("Drawing error");
+
+        let drawing_area = create_mocked_drawing_area(200, 80, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 181);
+            });
+        });
+        Colorbar::new(Viridis, 0.0..1.0)
+            .horizontal()
+            .label_format(LabelFormat::Percent)
+            .draw(&drawing_area)
+            .expect("Drawing error");
+    }
+}
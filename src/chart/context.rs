@@ -1,21 +1,31 @@
-use std::borrow::Borrow;
-use std::fmt::Debug;
-use std::marker::PhantomData;
-use std::ops::Range;
-use std::sync::Arc;
+use alloc::borrow::Borrow;
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Range;
 
 use super::dual_coord::DualCoordChartContext;
-use super::mesh::MeshStyle;
+use super::hit::{self, HitIndex, SeriesHit};
+use super::mesh::{GridLine, GridLineStyleFn, LabelOverlap, MeshStyle};
 use super::series::SeriesLabelStyle;
 
 use crate::coord::{
     AsRangedCoord, CoordTranslate, MeshLine, Ranged, RangedCoord, ReverseCoordTranslate, Shift,
 };
-use crate::drawing::backend::{BackendCoord, DrawingBackend};
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{Drawable, DynElement, IntoDynElement, PathElement, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, RenderEvent, RenderPhase};
+#[cfg(feature = "rayon")]
+use crate::drawing::RecordingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, SharedClippedBackend};
+use crate::element::{
+    Drawable, DynElement, HSpan, IntoDynElement, MultiLineText, PathElement, PointCollection,
+    Tooltip, VSpan,
+};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+#[cfg(feature = "trend_line_series")]
+use crate::series::TrendLineSeries;
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{AsRelative, FontTransform, ShapeStyle, SizeDesc, TextStyle};
+use crate::style::{AsRelative, FontTransform, RGBColor, ShapeStyle, SizeDesc, TextStyle, Theme};
 
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
@@ -24,6 +34,8 @@ use crate::style::{AsRelative, FontTransform, ShapeStyle, SizeDesc, TextStyle};
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a>>,
+    // The area the series is drawn on, which is told the label of the series
+    area: DrawingArea<DB, Shift>,
     phantom_data: PhantomData<DB>,
 }
 
@@ -38,10 +50,11 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func.as_ref().map(|x| x.borrow())
     }
 
-    fn new() -> Self {
+    fn new(area: DrawingArea<DB, Shift>) -> Self {
         Self {
             label: None,
             draw_func: None,
+            area,
             phantom_data: PhantomData,
         }
     }
@@ -49,7 +62,9 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
     /// Set the series label
     /// - `label`: The string would be use as label for current series
     pub fn label<L: Into<String>>(&mut self, label: L) -> &mut Self {
-        self.label = Some(label.into());
+        let label = label.into();
+        self.area.set_group_label(&label);
+        self.label = Some(label);
         self
     }
 
@@ -75,6 +90,8 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(super) drawing_area_pos: (i32, i32),
+    pub(super) theme: Theme,
+    pub(super) hit_index: Option<HitIndex>,
 }
 
 /// A chart context state - This is the data that is needed to reconstruct the chart context
@@ -87,6 +104,8 @@ pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
     coord: CT,
+    theme: Theme,
+    hit_index: Option<HitIndex>,
 }
 
 impl<'a, CT: CoordTranslate + Clone> Clone for ChartState<CT> {
@@ -95,6 +114,8 @@ impl<'a, CT: CoordTranslate + Clone> Clone for ChartState<CT> {
             drawing_area_size: self.drawing_area_size,
             drawing_area_pos: self.drawing_area_pos,
             coord: self.coord.clone(),
+            theme: self.theme.clone(),
+            hit_index: self.hit_index.clone(),
         }
     }
 }
@@ -105,6 +126,8 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>>
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
             coord: chart.drawing_area.into_coord_spec(),
+            theme: chart.theme,
+            hit_index: chart.hit_index,
         }
     }
 }
@@ -125,6 +148,8 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
             coord: Arc::new(self.drawing_area.into_coord_spec()),
+            theme: self.theme,
+            hit_index: self.hit_index,
         }
     }
 }
@@ -139,6 +164,8 @@ where
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
             coord: chart.drawing_area.as_coord_spec().clone(),
+            theme: chart.theme.clone(),
+            hit_index: chart.hit_index.clone(),
         }
     }
 }
@@ -168,10 +195,20 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            theme: self.theme,
+            hit_index: self.hit_index,
         }
     }
 }
 
+impl<CT: ReverseCoordTranslate> ChartState<CT> {
+    /// Find the data point nearest to a pixel of the chart which has been drawn, see
+    /// `ChartContext::hit_test`
+    pub fn hit_test(&self, pixel: BackendCoord, max_distance: u32) -> Option<SeriesHit<CT::From>> {
+        hit::hit_test(self.hit_index.as_ref(), &self.coord, pixel, max_distance)
+    }
+}
+
 impl<
         'a,
         DB: DrawingBackend,
@@ -225,14 +262,23 @@ impl<
             draw_y_mesh: true,
             draw_x_axis: true,
             draw_y_axis: true,
+            draw_x_fine_mesh: true,
+            draw_y_fine_mesh: true,
+            draw_x_ticks: true,
+            draw_y_ticks: true,
+            draw_x_labels: true,
+            draw_y_labels: true,
             n_x_labels: 10,
             n_y_labels: 10,
             line_style_1: None,
             line_style_2: None,
             x_label_style: None,
             y_label_style: None,
-            format_x: &|x| format!("{:?}", x),
-            format_y: &|y| format!("{:?}", y),
+            format_x: None,
+            format_y: None,
+            line_style_fn: None,
+            origin_axes: None,
+            label_overlap: LabelOverlap::default(),
             target: Some(self),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -254,12 +300,72 @@ impl<'a, DB: DrawingBackend + 'a, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
+
+    /// Get the color of the next series from the palette of the chart theme. The color advances
+    /// each time a series is drawn, so the series drawn with it are told apart without picking
+    /// the colors manually:
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10).unwrap();
+    /// for k in 1..4 {
+    ///     let data = (0..10).map(|x| (x, x * k / 3));
+    ///     chart
+    ///         .draw_series(LineSeries::new(data, &chart.next_color()))
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn next_color(&self) -> RGBColor {
+        self.theme.series_color(self.series_anno.len())
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
     }
+
+    /// Index the positions of the data points of the series drawn after it, so the nearest
+    /// point to a pixel can be found with `hit_test`, such as the point under the cursor in a
+    /// GUI. The index is kept by the chart state, the series drawn with `draw_series_par` are
+    /// not indexed.
+    pub fn enable_hit_testing(&mut self) -> &mut Self {
+        if self.hit_index.is_none() {
+            self.hit_index = Some(HitIndex::default());
+        }
+        self
+    }
+
+    // Start drawing a series, which is a phase of the rendering and a group of the shapes
+    fn begin_series(&self) {
+        self.drawing_area
+            .render_event(RenderEvent::PhaseStart(RenderPhase::Series));
+        self.drawing_area
+            .begin_group(&format!("Series {}", self.series_anno.len() + 1));
+    }
+
+    fn end_series(&self) {
+        self.drawing_area.end_group();
+        self.drawing_area
+            .render_event(RenderEvent::PhaseEnd(RenderPhase::Series));
+    }
+
+    // Add the points of an element of the series which is being drawn to the hit index
+    fn index_hits<'b, E>(&mut self, element: &'b E)
+    where
+        &'b E: PointCollection<'b, CT::From>,
+    {
+        if let Some(index) = self.hit_index.as_mut() {
+            let series = self.series_anno.len();
+            let coord = self.drawing_area.as_coord_spec();
+            for point in element.point_iter() {
+                index.insert(series, coord.translate(point.borrow()));
+            }
+        }
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -268,45 +374,118 @@ impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT>
         let coord_spec = self.drawing_area.into_coord_spec();
         move |coord| coord_spec.reverse_translate(coord)
     }
+
+    /// Find the data point nearest to a pixel, within `max_distance` pixels, among the series
+    /// drawn after `enable_hit_testing`. The value of the point is mapped back from its
+    /// position, so it's as accurate as the pixels are.
+    /// - `pixel`: The position in the pixels of the backend, such as the position of the cursor
+    /// - `max_distance`: The maximum distance between the pixel and the point
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..100).unwrap();
+    /// chart.enable_hit_testing();
+    /// chart
+    ///     .draw_series((0..10).map(|x| Circle::new((x, x * x), 3, &RED)))
+    ///     .unwrap();
+    ///
+    /// let pixel = chart.backend_coord(&(3, 9));
+    /// let hit = chart.hit_test((pixel.0 + 2, pixel.1 - 1), 5).unwrap();
+    /// assert_eq!((hit.series, hit.index, hit.value), (0, 3, (3, 9)));
+    /// ```
+    pub fn hit_test(&self, pixel: BackendCoord, max_distance: u32) -> Option<SeriesHit<CT::From>> {
+        hit::hit_test(
+            self.hit_index.as_ref(),
+            self.drawing_area.as_coord_spec(),
+            pixel,
+            max_distance,
+        )
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Arc<RangedCoord<X, Y>>> {
-    pub(super) fn draw_series_impl<E, R, S>(
+    // Draw the elements of a series with the function drawing an element on the drawing area
+    fn draw_elements<E, R, S, F>(
         &mut self,
         series: S,
+        draw: F,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
-        E: Drawable<DB>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
+        F: Fn(
+            &DrawingArea<DB, Arc<RangedCoord<X, Y>>>,
+            &E,
+        ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
     {
+        self.begin_series();
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            self.index_hits(element.borrow());
+            draw(&self.drawing_area, element.borrow())
+                .map_err(self.drawing_area.in_step("series"))?;
         }
+        self.end_series();
         Ok(())
     }
 
+    pub(super) fn draw_series_impl<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<SharedClippedBackend<DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_elements(series, |area, element| area.draw_shared_clipped(element))
+    }
+
     pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
         let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
+        self.series_anno
+            .push(SeriesAnno::new(self.drawing_area.strip_coord_spec()));
         &mut self.series_anno[idx]
     }
 
-    /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements
+    /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements.
+    /// The elements are clipped by the plotting area, so the parts of the series out of the
+    /// ranges of the axes are cut at the border instead of being drawn over the axes, the
+    /// pixels the ends of the ranges are mapped to are kept.
     pub fn draw_series<E, R, S>(
         &mut self,
         series: S,
     ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
-        E: Drawable<DB>,
+        E: Drawable<SharedClippedBackend<DB>>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /// Draw a data series without clipping it, the points out of the plotting area are moved
+    /// onto its border. This is for the elements which are only drawable on the backend of the
+    /// chart, see `draw_series`.
+    pub fn draw_series_unclipped<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_elements(series, |area, element| area.draw(element))?;
+        Ok(self.alloc_series_anno())
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
@@ -326,36 +505,118 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         self.drawing_area.map_coordinate(coord)
     }
 
-    pub(super) fn draw_series_impl<E, R, S>(
+    /// Create a vertical band which highlights the x range across the full height of the
+    /// plotting area
+    /// - `x`: The range of the x axis to highlight
+    /// - `style`: The style of the band, usually a translucent fill
+    pub fn vspan<S: Into<ShapeStyle>>(
+        &self,
+        x: Range<X::ValueType>,
+        style: S,
+    ) -> VSpan<X::ValueType, Y::ValueType>
+    where
+        X::ValueType: Clone,
+        Y::ValueType: Clone,
+    {
+        VSpan::new(x, self.y_range(), style)
+    }
+
+    /// Create a horizontal band which highlights the y range across the full width of the
+    /// plotting area
+    /// - `y`: The range of the y axis to highlight
+    /// - `style`: The style of the band, usually a translucent fill
+    pub fn hspan<S: Into<ShapeStyle>>(
+        &self,
+        y: Range<Y::ValueType>,
+        style: S,
+    ) -> HSpan<X::ValueType, Y::ValueType>
+    where
+        X::ValueType: Clone,
+        Y::ValueType: Clone,
+    {
+        HSpan::new(self.x_range(), y, style)
+    }
+
+    /// Create a trend line of the points, which is the least-squares polynomial fit drawn
+    /// across the full range of the x axis, see `TrendLineSeries`
+    /// - `iter`: The data points
+    /// - `degree`: The degree of the fitted polynomial, 1 for a linear fit
+    /// - `style`: The style of the trend line
+    /// - **returns**: The series, drawable on the backend `B` the series is drawn on, which is
+    ///   inferred by `draw_series` or `draw_series_unclipped`
+    #[cfg(feature = "trend_line_series")]
+    pub fn trend_line<B, I, S>(
+        &self,
+        iter: I,
+        degree: usize,
+        style: S,
+    ) -> TrendLineSeries<B, X::ValueType, Y::ValueType>
+    where
+        B: DrawingBackend,
+        I: IntoIterator<Item = (X::ValueType, Y::ValueType)>,
+        S: Into<ShapeStyle>,
+        X::ValueType: num_traits::NumCast,
+        Y::ValueType: num_traits::NumCast,
+    {
+        TrendLineSeries::polynomial(iter, degree, style).x_range(self.x_range())
+    }
+
+    // Draw the elements of a series with the function drawing an element on the drawing area
+    fn draw_elements<E, R, S, F>(
         &mut self,
         series: S,
+        draw: F,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
-        E: Drawable<DB>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
+        F: Fn(
+            &DrawingArea<DB, RangedCoord<X, Y>>,
+            &E,
+        ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
     {
+        self.begin_series();
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            self.index_hits(element.borrow());
+            draw(&self.drawing_area, element.borrow())
+                .map_err(self.drawing_area.in_step("series"))?;
         }
+        self.end_series();
         Ok(())
     }
 
+    pub(super) fn draw_series_impl<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<SharedClippedBackend<DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_elements(series, |area, element| area.draw_shared_clipped(element))
+    }
+
     pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
         let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
+        self.series_anno
+            .push(SeriesAnno::new(self.drawing_area.strip_coord_spec()));
         &mut self.series_anno[idx]
     }
 
-    /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements
+    /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements.
+    /// The elements are clipped by the plotting area, so the parts of the series out of the
+    /// ranges of the axes are cut at the border instead of being drawn over the axes, the
+    /// pixels the ends of the ranges are mapped to are kept.
     pub fn draw_series<E, R, S>(
         &mut self,
         series: S,
     ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
-        E: Drawable<DB>,
+        E: Drawable<SharedClippedBackend<DB>>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
@@ -363,6 +624,146 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         Ok(self.alloc_series_anno())
     }
 
+    /// Draw a data series without clipping it, the points out of the plotting area are moved
+    /// onto its border. This is for the elements which are only drawable on the backend of the
+    /// chart, see `draw_series`.
+    pub fn draw_series_unclipped<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_elements(series, |area, element| area.draw(element))?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series, each element has the tooltip of its first data point, formatted as
+    /// `(x, y)`. The tooltips are shown when hovering over the elements on the backends
+    /// supporting them, such as `SVGBackend::interactive`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// {
+    ///     let root = SVGBackend::with_string(&mut buf, (300, 200))
+    ///         .interactive()
+    ///         .into_drawing_area();
+    ///     let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..100).unwrap();
+    ///     chart
+    ///         .draw_series_with_tooltips((0..10).map(|x| Circle::new((x, x * x), 3, RED.filled())))
+    ///         .unwrap();
+    /// }
+    /// assert!(buf.contains("data-tooltip=\"(3, 9)\""));
+    /// ```
+    pub fn draw_series_with_tooltips<E, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<SharedClippedBackend<DB>>,
+        S: IntoIterator<Item = E>,
+        X::ValueType: Clone + Debug,
+        Y::ValueType: Clone + Debug,
+    {
+        let series = series.into_iter().map(|element| {
+            let text = match element.point_iter().into_iter().next() {
+                Some(point) => {
+                    let (x, y) = point.borrow();
+                    format!("({:?}, {:?})", x, y)
+                }
+                None => String::new(),
+            };
+            Tooltip::new(element, text)
+        });
+        self.draw_series_impl::<Tooltip<_, E>, _, _>(series)?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series in chunks, the drawing area is presented after every `chunk_size`
+    /// elements. The elements are drawn as they're pulled from the series, so a lazy or
+    /// unbounded series, such as `ChunkedLineSeries` or a stream, is never collected, and a
+    /// real-time backend shows the progress as the chunks are flushed.
+    /// - `series`: The series to draw
+    /// - `chunk_size`: The number of elements between two flushes
+    pub fn draw_series_chunked<E, R, S>(
+        &mut self,
+        series: S,
+        chunk_size: usize,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<SharedClippedBackend<DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let chunk_size = chunk_size.max(1);
+        self.begin_series();
+        let mut pending = 0;
+        for element in series {
+            self.index_hits(element.borrow());
+            self.drawing_area
+                .draw_shared_clipped(element.borrow())
+                .map_err(self.drawing_area.in_step("series"))?;
+            pending += 1;
+            if pending == chunk_size {
+                self.drawing_area.present()?;
+                pending = 0;
+            }
+        }
+        if pending > 0 {
+            self.drawing_area.present()?;
+        }
+        self.end_series();
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series in parallel with rayon, for the series with a huge number of
+    /// elements. The elements are mapped and drawn on the thread pool, and composited in the
+    /// order of the series, see `DrawingArea::draw_par`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_ranged(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series_par((0..10000).into_par_iter().map(|i| {
+    ///         let x = i as f64 / 10000.0;
+    ///         Circle::new((x, x * x), 1, &BLUE)
+    ///     }))
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn draw_series_par<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<RecordingBackend<DB>>,
+        R: Borrow<E> + Send,
+        S: rayon::iter::IntoParallelIterator<Item = R>,
+        X: Sync,
+        Y: Sync,
+    {
+        self.begin_series();
+        self.drawing_area
+            .draw_par(series)
+            .map_err(self.drawing_area.in_step("series"))?;
+        self.end_series();
+        Ok(self.alloc_series_anno())
+    }
+
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
@@ -371,39 +772,43 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         (r, c): (usize, usize),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
+        line_style_fn: Option<GridLineStyleFn<X::ValueType, Y::ValueType>>,
         mut fmt_label: FmtLabel,
     ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
-        self.drawing_area.draw_mesh(
-            |b, l| {
-                let draw;
-                match l {
-                    MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
-                            x_labels.push((x, label_text));
-                        }
-                        draw = x_mesh;
+        let mut styled_lines = vec![];
+        let coord = self.drawing_area.as_coord_spec();
+        self.drawing_area.draw_mesh_batched(
+            |l| match *l {
+                MeshLine::XMesh((x, _), _, _) => {
+                    if let Some(label_text) = fmt_label(coord, l) {
+                        x_labels.push((x, label_text));
                     }
-                    MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
-                            y_labels.push((y, label_text));
-                        }
-                        draw = y_mesh;
+                    x_mesh && select_line(l, mesh_line_style, line_style_fn, &mut styled_lines)
+                }
+                MeshLine::YMesh((_, y), _, _) => {
+                    if let Some(label_text) = fmt_label(coord, l) {
+                        y_labels.push((y, label_text));
                     }
-                };
-                if draw {
-                    l.draw(b, mesh_line_style)
-                } else {
-                    Ok(())
+                    y_mesh && select_line(l, mesh_line_style, line_style_fn, &mut styled_lines)
                 }
             },
+            mesh_line_style,
             r,
             c,
         )?;
+
+        // The lines styled by the style function are drawn over the others
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        for (from, to, style) in styled_lines {
+            let points = vec![(from.0 - x0, from.1 - y0), (to.0 - x0, to.1 - y0)];
+            area.draw(&PathElement::new(points, style))?;
+        }
         Ok((x_labels, y_labels))
     }
 
@@ -488,6 +893,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
         tick_size: i32,
+        tick_marks: bool,
+        overlap: LabelOverlap,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -505,6 +912,22 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
          * and tick mark drawing */
         let axis_range = self.draw_axis(area, axis_style, orientation, tick_size < 0)?;
 
+        /* The labels which would overlap are thinned out, or rotated for the X axis */
+        let horizontal = orientation.0 == 0;
+        let rotated_style = label_style.transform(FontTransform::Rotate270);
+        let (step, rotated) = match overlap {
+            LabelOverlap::Allow => (1, false),
+            LabelOverlap::Thin => (label_step(labels, label_style, horizontal), false),
+            LabelOverlap::Rotate => match label_step(labels, label_style, horizontal) {
+                // The multi-line labels are never rotated
+                step if step > 1 && horizontal && !labels.iter().any(|(_, t)| t.contains('\n')) => {
+                    (label_step(labels, &rotated_style, horizontal), true)
+                }
+                step => (step, false),
+            },
+        };
+        let label_style = if rotated { &rotated_style } else { label_style };
+
         /* To make the right label area looks nice, it's a little bit tricky, since for a that is
          * very long, we actually prefer left alignment instead of right alignment.
          * Otherwise, the right alignment looks better. So we estimate the max and min label width
@@ -526,7 +949,15 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             })
             .collect();
 
-        let min_width = *label_width.iter().min().unwrap_or(&1).max(&1);
+        // The empty labels are the ticks left unlabeled, which don't affect the alignment
+        let min_width = *label_width
+            .iter()
+            .zip(labels)
+            .filter(|(_, (_, text))| !text.is_empty())
+            .map(|(w, _)| w)
+            .min()
+            .unwrap_or(&1)
+            .max(&1);
         let max_width = *label_width
             .iter()
             .filter(|&&x| x < min_width * 2)
@@ -535,7 +966,15 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         let right_align_width = (min_width * 2).min(max_width);
 
         /* Then we need to draw the tick mark and the label */
+        let mut nth = 0;
         for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+            // The ticks of the labels thinned out are kept unlabeled
+            let thinned = !t.is_empty() && {
+                nth += 1;
+                (nth - 1) % step != 0
+            };
+            let t = if thinned { "" } else { t.as_str() };
+
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -598,10 +1037,23 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (cx, cy + label_offset)
             };
 
+            // The rotated labels are read from the bottom, and end at the axis
+            let (h_pos, v_pos) = match v_pos {
+                VPos::Top if rotated => (HPos::Right, VPos::Center),
+                VPos::Bottom if rotated => (HPos::Left, VPos::Center),
+                v_pos => (h_pos, v_pos),
+            };
+
             let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(&t, label_style, (text_x, text_y))?;
+            if t.contains('\n') {
+                // The labels with multiple levels, such as the two-level date labels
+                let text = MultiLineText::from_str(t, (text_x, text_y), label_style, 0);
+                area.draw(&text)?;
+            } else if !t.is_empty() {
+                area.draw_text(&t, label_style, (text_x, text_y))?;
+            }
 
-            if tick_size != 0 {
+            if tick_size != 0 && tick_marks {
                 if let Some(style) = axis_style {
                     let xmax = tw as i32 - 1;
                     let ymax = th as i32 - 1;
@@ -669,6 +1121,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         &mut self,
         (r, c): (usize, usize),
         mesh_line_style: &ShapeStyle,
+        line_style_fn: Option<GridLineStyleFn<X::ValueType, Y::ValueType>>,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
         fmt_label: FmtLabel,
@@ -684,14 +1137,26 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         y_desc: Option<String>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        (x_ticks, y_ticks): (bool, bool),
+        label_overlap: LabelOverlap,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (x_labels, y_labels) = self
+            .draw_mesh_lines(
+                (r, c),
+                (x_mesh, y_mesh),
+                mesh_line_style,
+                line_style_fn,
+                fmt_label,
+            )
+            .map_err(self.drawing_area.in_step("mesh"))?;
 
         for idx in 0..2 {
+            let x_group = self.x_label_area[idx].is_some()
+                && (x_axis || !x_labels.is_empty() || x_desc.is_some());
+            self.begin_axis(x_group, "X axis", x_desc.as_ref());
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
@@ -701,8 +1166,15 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 x_tick_size[idx],
-            )?;
-
+                x_ticks,
+                label_overlap,
+            )
+            .map_err(self.drawing_area.in_step("axis labels"))?;
+            self.end_axis(x_group);
+
+            let y_group = self.y_label_area[idx].is_some()
+                && (y_axis || !y_labels.is_empty() || y_desc.is_some());
+            self.begin_axis(y_group, "Y axis", y_desc.as_ref());
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
@@ -712,12 +1184,141 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 y_tick_size[idx],
-            )?;
+                y_ticks,
+                label_overlap,
+            )
+            .map_err(self.drawing_area.in_step("axis labels"))?;
+            self.end_axis(y_group);
+        }
+
+        Ok(())
+    }
+
+    // Draw the axes crossing at the origin inside the plotting area, with the arrows at the
+    // positive ends and the labels along the axes. The origin is clamped into the plotting area.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(super) fn draw_origin_axes(
+        &self,
+        origin: BackendCoord,
+        (x_labels, y_labels): (&[(i32, String)], &[(i32, String)]),
+        (x_axis, y_axis): (bool, bool),
+        (x_tick_size, y_tick_size): (i32, i32),
+        axis_style: &ShapeStyle,
+        (x_label_style, y_label_style): (&TextStyle, &TextStyle),
+        axis_desc_style: &TextStyle,
+        (x_desc, y_desc): (Option<&String>, Option<&String>),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let (xr, yr) = (
+            self.drawing_area.get_x_axis_pixel_range(),
+            self.drawing_area.get_y_axis_pixel_range(),
+        );
+        let (left, right) = (xr.start.min(xr.end) - x0, xr.start.max(xr.end) - x0 - 1);
+        let (top, bottom) = (yr.start.min(yr.end) - y0, yr.start.max(yr.end) - y0 - 1);
+        let ox = (origin.0 - x0).max(left).min(right);
+        let oy = (origin.1 - y0).max(top).min(bottom);
+        let arrow = 8;
+        let gap = x_tick_size.abs().max(y_tick_size.abs()) + 2;
+
+        let draw_label = |text: &str, style: &TextStyle, pos: BackendCoord| {
+            if text.contains('\n') {
+                area.draw(&MultiLineText::from_str(text, pos, style, 0))
+            } else if !text.is_empty() {
+                area.draw_text(text, style, pos)
+            } else {
+                Ok(())
+            }
+        };
+
+        if x_axis {
+            self.begin_axis(true, "X axis", x_desc);
+            area.draw(&PathElement::new(
+                vec![(left, oy), (right, oy)],
+                axis_style.clone(),
+            ))?;
+            area.draw(&PathElement::new(
+                vec![
+                    (right - arrow, oy - arrow / 2),
+                    (right, oy),
+                    (right - arrow, oy + arrow / 2),
+                ],
+                axis_style.clone(),
+            ))?;
+            let style = x_label_style.pos(Pos::new(HPos::Center, VPos::Top));
+            for (p, text) in x_labels {
+                let px = *p - x0;
+                // The labels at the crossing and under the arrow are left out
+                if ((px - ox).abs() <= 1 && y_axis) || px < left || px > right - arrow {
+                    continue;
+                }
+                if x_tick_size != 0 {
+                    let tick = vec![(px, oy - x_tick_size.abs()), (px, oy + x_tick_size.abs())];
+                    area.draw(&PathElement::new(tick, axis_style.clone()))?;
+                }
+                draw_label(text, &style, (px, oy + gap))?;
+            }
+            if let Some(desc) = x_desc {
+                let style = axis_desc_style.pos(Pos::new(HPos::Right, VPos::Bottom));
+                area.draw_text(desc, &style, (right, oy - gap))?;
+            }
+            self.end_axis(true);
+        }
+
+        if y_axis {
+            self.begin_axis(true, "Y axis", y_desc);
+            area.draw(&PathElement::new(
+                vec![(ox, bottom), (ox, top)],
+                axis_style.clone(),
+            ))?;
+            area.draw(&PathElement::new(
+                vec![
+                    (ox - arrow / 2, top + arrow),
+                    (ox, top),
+                    (ox + arrow / 2, top + arrow),
+                ],
+                axis_style.clone(),
+            ))?;
+            let style = y_label_style.pos(Pos::new(HPos::Right, VPos::Center));
+            for (p, text) in y_labels {
+                let py = *p - y0;
+                if ((py - oy).abs() <= 1 && x_axis) || py < top + arrow || py > bottom {
+                    continue;
+                }
+                if y_tick_size != 0 {
+                    let tick = vec![(ox - y_tick_size.abs(), py), (ox + y_tick_size.abs(), py)];
+                    area.draw(&PathElement::new(tick, axis_style.clone()))?;
+                }
+                draw_label(text, &style, (ox - gap, py))?;
+            }
+            if let Some(desc) = y_desc {
+                let style = axis_desc_style.pos(Pos::new(HPos::Left, VPos::Top));
+                area.draw_text(desc, &style, (ox + gap, top))?;
+            }
+            self.end_axis(true);
         }
 
         Ok(())
     }
 
+    // Group the shapes of an axis, the group is labeled with the description of the axis if
+    // there's one
+    fn begin_axis(&self, group: bool, name: &str, desc: Option<&String>) {
+        if group {
+            let label = match desc {
+                Some(desc) => format!("{}: {}", name, desc),
+                None => name.to_string(),
+            };
+            self.drawing_area.begin_group(&label);
+        }
+    }
+
+    fn end_axis(&self, group: bool) {
+        if group {
+            self.drawing_area.end_group();
+        }
+    }
+
     /// Convert this chart context into a dual axis chart context
     ///
     /// - `x_coord`: The coordinate spec for the X axis
@@ -741,6 +1342,56 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
     }
 }
 
+// The minimal distance between the boxes of the adjacent labels
+const LABEL_GAP: i32 = 4;
+
+// Find the smallest step of the labels kept, so that the labels kept don't overlap, the empty
+// labels are skipped
+fn label_step(labels: &[(i32, String)], style: &TextStyle, horizontal: bool) -> usize {
+    let extents: Vec<_> = labels
+        .iter()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(p, text)| {
+            let (w, h) = text.split('\n').fold((0, 0), |(w, h), line| {
+                let (lw, lh) = style.font.box_size(line).unwrap_or((0, 0));
+                (w.max(lw as i32), h + lh as i32)
+            });
+            (*p, if horizontal { w } else { h })
+        })
+        .collect();
+    (1..extents.len().max(1))
+        .find(|&step| {
+            extents
+                .iter()
+                .step_by(step)
+                .zip(extents.iter().step_by(step).skip(1))
+                .all(|((p0, e0), (p1, e1))| (p1 - p0).abs() >= (e0 + e1) / 2 + LABEL_GAP)
+        })
+        .unwrap_or_else(|| extents.len().max(1))
+}
+
+// Decide if the mesh line is drawn in the batch with the default style. When there's a style
+// function, the lines are drawn separately with the styles it returns, or skipped.
+fn select_line<X: Ranged, Y: Ranged>(
+    line: &MeshLine<X, Y>,
+    default_style: &ShapeStyle,
+    line_style_fn: Option<GridLineStyleFn<X::ValueType, Y::ValueType>>,
+    styled_lines: &mut Vec<(BackendCoord, BackendCoord, ShapeStyle)>,
+) -> bool {
+    let style_fn = match line_style_fn {
+        Some(style_fn) => style_fn,
+        None => return true,
+    };
+    let (from, to, grid_line) = match *line {
+        MeshLine::XMesh(from, to, v) => (from, to, GridLine::X(v)),
+        MeshLine::YMesh(from, to, v) => (from, to, GridLine::Y(v)),
+    };
+    if let Some(style) = style_fn(grid_line, default_style) {
+        styled_lines.push((from, to, style));
+    }
+    false
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -789,4 +1440,441 @@ mod test {
             .draw()
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_series_color_cycling() {
+        let theme = Theme::light();
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart")
+            .set_secondary_coord(0..10, 0..10);
+
+        assert_eq!(
+            chart.next_color().to_rgba(),
+            theme.series_color(0).to_rgba()
+        );
+        let color = chart.next_color();
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &color)))
+            .expect("Drawing error");
+        assert_eq!(
+            chart.next_color().to_rgba(),
+            theme.series_color(1).to_rgba()
+        );
+        // The secondary series share the color cycle
+        let color = chart.next_color();
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((5, 5), 5, &color)))
+            .expect("Drawing error");
+        assert_eq!(
+            chart.next_color().to_rgba(),
+            theme.series_color(2).to_rgba()
+        );
+    }
+
+    #[test]
+    fn test_spans() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, RED.mix(0.2));
+                assert!(filled);
+                // The band covers the full range of the y axis
+                assert_eq!((ul, br), ((40, -1), (80, 199)));
+            });
+            // The outline of the band is cut at the bottom of the plotting area
+            let mut outline = vec![
+                vec![(0, 199), (0, 99)],
+                vec![(0, 99), (200, 99), (200, 199)],
+            ];
+            m.check_draw_path(move |_, _, path| {
+                assert_eq!(Some(path), outline.pop());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let vspan = chart.vspan(2..4, RED.mix(0.2).filled());
+        chart
+            .draw_series(std::iter::once(vspan))
+            .expect("Drawing error");
+        // The part of the band out of the y axis is clipped
+        let hspan = chart.hspan(-5..5, &BLUE);
+        chart
+            .draw_series(std::iter::once(hspan))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_draw_series_unclipped() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                // The point out of the ranges is moved onto the border
+                assert_eq!(path, vec![(0, 199), (200, 0)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series_unclipped(std::iter::once(PathElement::new(
+                vec![(0, 0), (20, 10)],
+                &RED,
+            )))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_draw_series_clipping() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                // The line is cut at the end of the x axis
+                assert_eq!(path[0], (0, 199));
+                assert_eq!(*path.last().unwrap(), (200, 99));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (100, 99));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                // The marker out of the ranges isn't drawn
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0, 0), (20, 10)],
+                &RED,
+            )))
+            .expect("Drawing error");
+        chart
+            .draw_series(
+                vec![(5, 5), (20, 20)]
+                    .into_iter()
+                    .map(|p| Circle::new(p, 3, &BLUE)),
+            )
+            .expect("Drawing error");
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_hit_testing() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100, 0..100)
+            .unwrap();
+        // The series drawn before enabling hit testing aren't indexed
+        chart
+            .draw_series(std::iter::once(Circle::new((50, 50), 3, &RED)))
+            .unwrap();
+        assert_eq!(chart.hit_test(chart.backend_coord(&(50, 50)), 5), None);
+
+        chart.enable_hit_testing();
+        chart
+            .draw_series(LineSeries::new((0..10).map(|x| (x * 10, 20)), &BLUE))
+            .unwrap();
+        chart
+            .draw_series((0..10).map(|x| Circle::new((x * 10, 80), 3, &RED)))
+            .unwrap();
+
+        let hit = chart.hit_test(chart.backend_coord(&(40, 20)), 5).unwrap();
+        assert_eq!((hit.series, hit.index, hit.value), (1, 4, (40, 20)));
+        assert_eq!(hit.distance, 0.0);
+
+        // The chart state keeps the index for the chart which has been drawn
+        let state = chart.into_chart_state();
+        let pixel = state.hit_test((0, 0), 1000).unwrap().pixel;
+        let hit = state.hit_test((pixel.0 + 3, pixel.1 + 3), 5).unwrap();
+        assert_eq!((hit.series, hit.index, hit.pixel), (2, 0, pixel));
+        assert_eq!(state.hit_test((pixel.0 + 10, pixel.1), 5), None);
+    }
+
+    #[test]
+    fn test_default_float_labels() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_ranged(0.0..1.0, 0..10)
+            .expect("Create chart");
+        chart.configure_mesh().draw().expect("Draw mesh");
+        drop(chart);
+        drop(drawing_area);
+
+        // The labels have the precision of the tick spacing, without the rounding noise
+        let labels = labels.borrow();
+        assert!(!labels.is_empty());
+        assert!(labels.iter().all(|l| l.len() == 3));
+        assert!(labels.contains(&"0.3".to_string()));
+    }
+
+    #[test]
+    fn test_builtin_label_formats() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(0.0..1.0, 0..5000)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_label_format(LabelFormat::Percent)
+            .y_label_format(LabelFormat::SI("B"))
+            .draw()
+            .expect("Draw mesh");
+        drop(chart);
+        drop(drawing_area);
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"50%".to_string()));
+        assert!(labels.contains(&"2kB".to_string()));
+    }
+
+    #[test]
+    fn test_mesh_toggles() {
+        fn draw_mesh(
+            configure: fn(&mut super::MeshStyle<RangedCoordi32, RangedCoordi32, MockedBackend>),
+            check: fn(&MockedBackend),
+        ) {
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.drop_check(check);
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_ranged(0..10, 0..10)
+                .expect("Create chart");
+            let mut mesh = chart.configure_mesh();
+            configure(&mut mesh);
+            mesh.draw().expect("Draw mesh");
+        }
+
+        // The tick marks are left out, the axis lines and the labels are drawn
+        draw_mesh(
+            |m| {
+                m.disable_mesh().disable_tick_marks();
+            },
+            |b| {
+                assert_eq!(b.num_draw_line_call, 0);
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_text_call, 21);
+            },
+        );
+        // The tick marks are drawn without the labels
+        draw_mesh(
+            |m| {
+                m.disable_mesh().disable_x_labels();
+            },
+            |b| {
+                assert_eq!(b.num_draw_path_call, 23);
+                assert_eq!(b.num_draw_text_call, 10);
+            },
+        );
+        // Only the coarse grid of the X axis is drawn
+        draw_mesh(
+            |m| {
+                m.disable_y_mesh().disable_fine_mesh().disable_axes();
+            },
+            |b| {
+                assert_eq!(b.num_draw_line_call, 11);
+                assert_eq!(b.num_draw_path_call, 0);
+            },
+        );
+    }
+
+    #[test]
+    fn test_axes_through_origin() {
+        let texts = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let paths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let (collected_texts, collected_paths) = (texts.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                collected_texts.borrow_mut().push((pos, text.to_string()));
+            });
+            m.check_draw_path(move |_, _, path| {
+                collected_paths.borrow_mut().push(path);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(-5..5, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .axes_through_origin()
+            .x_desc("x")
+            .draw()
+            .expect("Draw mesh");
+        drop(chart);
+        drop(drawing_area);
+
+        let (texts, paths) = (texts.borrow(), paths.borrow());
+        // The X axis is at the bottom since 0 is the lower bound of Y, and Y axis is in the
+        // middle
+        assert!(paths.contains(&vec![(20, 179), (199, 179)]));
+        assert!(paths.contains(&vec![(110, 179), (110, 0)]));
+        // The labels are along the axes, the ones at the crossing are left out
+        let x_labels: Vec<_> = texts.iter().filter(|(pos, _)| pos.1 > 179).collect();
+        let y_labels: Vec<_> = texts
+            .iter()
+            .filter(|(pos, _)| pos.0 < 110 && pos.1 < 179)
+            .collect();
+        assert!(x_labels.iter().any(|(_, t)| t == "-5") && x_labels.iter().any(|(_, t)| t == "4"));
+        assert!(y_labels.iter().any(|(_, t)| t == "5"));
+        assert!(texts.iter().all(|(_, t)| t != "0"));
+        assert!(texts.iter().any(|(_, t)| t == "x"));
+        assert_eq!(x_labels.len() + y_labels.len() + 1, texts.len());
+    }
+
+    #[test]
+    fn test_label_overlap() {
+        fn x_labels(overlap: LabelOverlap) -> Vec<i32> {
+            let positions = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+            let collected = positions.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_text(move |_, _, _, pos, text| {
+                    if text.ends_with("seconds") {
+                        collected.borrow_mut().push(pos.0);
+                    }
+                });
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_ranged(0..100, 0..100)
+                .expect("Create chart");
+            let fmt = |v: &i32| format!("{} seconds", v);
+            chart
+                .configure_mesh()
+                .x_label_formatter(&fmt)
+                .label_overlap(overlap)
+                .draw()
+                .expect("Draw mesh");
+            drop(chart);
+            drop(drawing_area);
+            let positions = positions.borrow().clone();
+            positions
+        }
+
+        let all = x_labels(LabelOverlap::Allow);
+        assert_eq!(all.len(), 11);
+        // Every Nth label is drawn
+        let thinned = x_labels(LabelOverlap::Thin);
+        assert!(thinned.len() > 1 && thinned.len() < all.len());
+        let step = all.iter().position(|x| *x == thinned[1]).unwrap();
+        assert!(thinned
+            .iter()
+            .zip(all.iter().step_by(step))
+            .all(|(a, b)| a == b));
+        // The rotated labels take less room along the axis
+        let rotated = x_labels(LabelOverlap::Rotate);
+        assert!(rotated.len() > thinned.len());
+    }
+
+    #[test]
+    fn test_mesh_line_style_fn() {
+        let colors = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let collected = colors.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |c, _, path| {
+                collected.borrow_mut().push((c, path[0].1));
+            });
+            m.drop_check(|b| {
+                // All the lines are drawn with the styles of the function
+                assert_eq!(b.num_draw_line_call, 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let style_fn = |line: GridLine<i32, i32>, style: &ShapeStyle| match line {
+            GridLine::Y(5) => Some(RED.stroke_width(3)),
+            GridLine::Y(_) => Some(style.clone()),
+            GridLine::X(_) => None,
+        };
+        chart
+            .configure_mesh()
+            .line_style_fn(&style_fn)
+            .draw()
+            .expect("Draw mesh");
+        drop(chart);
+        drop(drawing_area);
+
+        let colors = colors.borrow();
+        assert!(!colors.is_empty());
+        // The line at 5 is in both the fine and the coarse grid
+        let emphasized: Vec<_> = colors.iter().filter(|(c, _)| *c == RED.to_rgba()).collect();
+        assert_eq!(emphasized.len(), 2);
+        assert!(emphasized.iter().all(|(_, y)| (99..=100).contains(y)));
+    }
+
+    #[test]
+    fn test_label_formatter_with_context() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let fmt = |ctx: &LabelContext<i32>| {
+            assert_eq!(ctx.ticks[ctx.index], *ctx.value);
+            assert_eq!(*ctx.range, 0..10);
+            if ctx.index % 2 == 1 {
+                None
+            } else if ctx.next().is_none() {
+                Some(format!("last {}", ctx.value))
+            } else {
+                Some(format!("{} of {}", ctx.index, ctx.ticks.len()))
+            }
+        };
+        chart
+            .configure_mesh()
+            .x_label_formatter_with_context(&fmt)
+            .draw()
+            .expect("Draw mesh");
+        drop(chart);
+        drop(drawing_area);
+
+        let labels = labels.borrow();
+        let x_labels: Vec<_> = labels.iter().filter(|l| l.contains(' ')).collect();
+        assert_eq!(
+            x_labels,
+            ["0 of 11", "2 of 11", "4 of 11", "6 of 11", "8 of 11", "last 10"]
+        );
+    }
 }
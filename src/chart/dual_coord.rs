@@ -1,8 +1,8 @@
 /// The dual coordinate system support
-use std::borrow::{Borrow, BorrowMut};
-use std::fmt::Debug;
-use std::ops::{Deref, DerefMut};
-use std::sync::Arc;
+use alloc::borrow::{Borrow, BorrowMut};
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::ops::{Deref, DerefMut};
 
 use super::context::{ChartContext, ChartState, SeriesAnno};
 use super::mesh::SecondaryMeshStyle;
@@ -11,6 +11,7 @@ use crate::coord::{CoordTranslate, Ranged, RangedCoord, ReverseCoordTranslate, S
 use crate::drawing::backend::{BackendCoord, DrawingBackend};
 use crate::drawing::DrawingArea;
 use crate::drawing::DrawingAreaErrorKind;
+use crate::drawing::SharedClippedBackend;
 use crate::element::{Drawable, PointCollection};
 
 /// The chart context that has two coordinate system attached
@@ -102,8 +103,9 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
         let mut secondary_x_label_area = [None, None];
         let mut secondary_y_label_area = [None, None];
 
-        std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
-        std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        core::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
+        core::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        let theme = primary.theme.clone();
 
         Self {
             primary,
@@ -112,7 +114,9 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
+                hit_index: None,
                 drawing_area_pos: (0, 0),
+                theme,
             },
         }
     }
@@ -188,7 +192,7 @@ where
     ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (SX::ValueType, SY::ValueType)>,
-        E: Drawable<DB>,
+        E: Drawable<SharedClippedBackend<DB>>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
@@ -0,0 +1,261 @@
+use core::fmt::Debug;
+
+use super::{ChartBuilder, ChartContext};
+use crate::coord::{AsRangedCoord, RangedCoord, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::SizeDesc;
+
+// A size which is resolved when the grid is drawn, since a relative size depends on the area
+type LazySize = Box<dyn Fn(&(u32, u32)) -> i32>;
+
+fn lazy_size<S: SizeDesc + 'static>(size: S) -> LazySize {
+    Box::new(move |dim| size.in_pixels(dim))
+}
+
+/// The helper that draws small multiples: the data is grouped by a key, and each group is
+/// drawn as a sub-chart of a grid. All the sub-charts share the same axes and label areas,
+/// so they are directly comparable, and only the sub-charts on the left column and the
+/// bottom row have tick labels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![("a", (1.0, 2.0)), ("b", (2.0, 1.0)), ("a", (3.0, 4.0)), ("c", (2.5, 3.0))];
+///
+/// let mut buffer = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut buffer, (640, 480)).into_drawing_area();
+///     FacetGrid::wrap(data, 2)
+///         .caption("Small multiples")
+///         .draw(&root, 0.0..5.0, 0.0..5.0, |chart, _, points| {
+///             chart.draw_series(points.iter().map(|p| Circle::new(*p, 3, RED.filled())))?;
+///             Ok(())
+///         })
+///         .unwrap();
+/// }
+/// assert!(buffer.contains("Small multiples"));
+/// ```
+pub struct FacetGrid<T> {
+    // The title, the position and the data of each facet
+    facets: Vec<(String, (usize, usize), Vec<T>)>,
+    size: (usize, usize),
+    caption: Option<String>,
+    title_size: LazySize,
+    label_area_size: LazySize,
+    margin: LazySize,
+}
+
+// Group the values by their keys, in the order the keys first appear
+fn group_by_key<K: PartialEq, T, I: IntoIterator<Item = (K, T)>>(data: I) -> Vec<(K, Vec<T>)> {
+    let mut groups: Vec<(K, Vec<T>)> = vec![];
+    for (key, value) in data {
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, values)) => values.push(value),
+            None => groups.push((key, vec![value])),
+        }
+    }
+    groups
+}
+
+fn index_of<K: PartialEq>(keys: &mut Vec<K>, key: K) -> usize {
+    if let Some(idx) = keys.iter().position(|k| *k == key) {
+        return idx;
+    }
+    keys.push(key);
+    keys.len() - 1
+}
+
+impl<T> FacetGrid<T> {
+    fn with_facets(facets: Vec<(String, (usize, usize), Vec<T>)>, size: (usize, usize)) -> Self {
+        Self {
+            facets,
+            size,
+            caption: None,
+            title_size: lazy_size(15),
+            label_area_size: lazy_size(30),
+            margin: lazy_size(5),
+        }
+    }
+
+    /// Create a facet grid with one facet for each key, the facets are laid out in rows of
+    /// `cols` facets in the order the keys first appear
+    /// - `data`: The iterator of `(key, value)` pairs
+    /// - `cols`: The number of facets in each row
+    /// - **returns** The newly created facet grid
+    pub fn wrap<K: PartialEq + ToString, I: IntoIterator<Item = (K, T)>>(
+        data: I,
+        cols: usize,
+    ) -> Self {
+        let cols = cols.max(1);
+        let groups = group_by_key(data);
+        let rows = groups.len().div_ceil(cols);
+        let facets = groups
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (key, values))| (key.to_string(), (idx / cols, idx % cols), values))
+            .collect();
+        Self::with_facets(facets, (rows, cols))
+    }
+
+    /// Create a facet grid keyed by two keys, the first key selects the row and the second
+    /// key selects the column of the facet. The facets are titled `row / column`.
+    /// - `data`: The iterator of `((row_key, column_key), value)` pairs
+    /// - **returns** The newly created facet grid
+    pub fn grid<R, C, I>(data: I) -> Self
+    where
+        R: PartialEq + ToString,
+        C: PartialEq + ToString,
+        I: IntoIterator<Item = ((R, C), T)>,
+    {
+        let (mut rows, mut cols) = (vec![], vec![]);
+        let facets = group_by_key(data)
+            .into_iter()
+            .map(|((r, c), values)| {
+                let title = format!("{} / {}", r.to_string(), c.to_string());
+                let pos = (index_of(&mut rows, r), index_of(&mut cols, c));
+                (title, pos, values)
+            })
+            .collect();
+        Self::with_facets(facets, (rows.len(), cols.len()))
+    }
+
+    /// Set the caption of the whole figure
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Set the font size of the facet titles, a relative size is relative to the whole figure
+    pub fn title_size<S: SizeDesc + 'static>(mut self, size: S) -> Self {
+        self.title_size = lazy_size(size);
+        self
+    }
+
+    /// Set the size of the label areas on the left and the bottom of each facet, a relative
+    /// size is relative to the facet
+    pub fn label_area_size<S: SizeDesc + 'static>(mut self, size: S) -> Self {
+        self.label_area_size = lazy_size(size);
+        self
+    }
+
+    /// Set the margin around each facet, a relative size is relative to the facet
+    pub fn margin<S: SizeDesc + 'static>(mut self, size: S) -> Self {
+        self.margin = lazy_size(size);
+        self
+    }
+
+    /// Get the number of rows and columns of the grid
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Draw the facets on the drawing area
+    /// - `area`: The drawing area to draw on
+    /// - `x_spec`: The shared specification of the X axis
+    /// - `y_spec`: The shared specification of the Y axis
+    /// - `draw_func`: The function that draws a facet, it's called with the chart context,
+    ///   the title and the values of the facet
+    /// - **returns** The result of the drawing
+    pub fn draw<DB, X, Y, DrawFunc>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        x_spec: X,
+        y_spec: Y,
+        mut draw_func: DrawFunc,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend,
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord + Clone,
+        X::Value: Debug,
+        Y::Value: Debug,
+        DrawFunc: FnMut(
+            &mut ChartContext<DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+            &str,
+            &[T],
+        ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let title_size = (self.title_size)(&area.dim_in_pixel()).max(0);
+        let area = match self.caption.as_ref() {
+            Some(caption) => area.titled(caption, ("sans-serif", title_size * 2))?,
+            None => area.clone(),
+        };
+        let (rows, cols) = self.size;
+        if rows == 0 {
+            return Ok(());
+        }
+        let cells = area.split_evenly((rows, cols));
+        let no_label = |_: &X::Value| String::new();
+        let no_y_label = |_: &Y::Value| String::new();
+
+        for (title, (row, col), values) in self.facets.iter() {
+            let cell = &cells[row * cols + col];
+            let dim = cell.dim_in_pixel();
+            let label_area_size = (self.label_area_size)(&dim);
+            let mut chart = ChartBuilder::on(cell)
+                .margin((self.margin)(&dim))
+                .caption(title, ("sans-serif", title_size))
+                .x_label_area_size(label_area_size)
+                .y_label_area_size(label_area_size)
+                .build_ranged(x_spec.clone(), y_spec.clone())?;
+
+            // The tick labels are only drawn once for each column and each row
+            let below = self
+                .facets
+                .iter()
+                .any(|(_, pos, _)| *pos == (row + 1, *col));
+            let mut mesh = chart.configure_mesh();
+            if below {
+                mesh.x_label_formatter(&no_label);
+            }
+            if *col > 0 {
+                mesh.y_label_formatter(&no_y_label);
+            }
+            mesh.draw()?;
+
+            draw_func(&mut chart, title, values)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_facet_grid_layout() {
+        let grid = FacetGrid::wrap(vec![("a", 1), ("b", 2), ("c", 3), ("a", 4)], 2);
+        assert_eq!(grid.size(), (2, 2));
+
+        let grid = FacetGrid::grid(vec![(("x", 1), 1), (("y", 2), 2), (("x", 2), 3)]);
+        assert_eq!(grid.size(), (2, 2));
+        assert_eq!(grid.facets[2].0, "x / 2");
+        assert_eq!(grid.facets[2].1, (0, 1));
+    }
+
+    #[test]
+    fn test_facet_grid_draw() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |_| {});
+        let mut drawn = vec![];
+        FacetGrid::wrap(vec![("a", 1), ("b", 2), ("c", 3), ("a", 4)], 2)
+            .draw(&drawing_area, 0..10, 0..10, |chart, title, values| {
+                let (x0, y0) = chart.plotting_area().get_base_pixel();
+                drawn.push((title.to_string(), values.to_vec(), x0 >= 200, y0 >= 200));
+                Ok(())
+            })
+            .expect("Drawing error");
+        assert_eq!(
+            drawn,
+            vec![
+                ("a".to_string(), vec![1, 4], false, false),
+                ("b".to_string(), vec![2], true, false),
+                ("c".to_string(), vec![3], false, true),
+            ]
+        );
+    }
+}
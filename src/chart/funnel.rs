@@ -0,0 +1,190 @@
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::Polygon;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, Palette, Palette99, TextStyle, BLACK};
+
+/// A stage produced by the funnel layout
+#[derive(Clone, Debug)]
+pub struct FunnelSegment {
+    /// The label of the stage
+    pub label: String,
+    /// The value of the stage
+    pub value: f64,
+    /// The value of the stage relative to the first stage
+    pub ratio: f64,
+    /// The corners of the trapezoid, in the order of upper left, upper right, bottom right
+    /// and bottom left
+    pub corners: [(f64, f64); 4],
+}
+
+/// The funnel chart, which renders ordered stages, for example the steps of a conversion
+/// pipeline, as stacked trapezoids. The top edge of each stage is as wide as its value and
+/// the bottom edge narrows to the value of the next stage.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let funnel = Funnel::new(vec![("visits", 1000.0), ("signups", 400.0), ("orders", 100.0)])
+///     .gap(0.0);
+/// let segments = funnel.layout((200.0, 300.0));
+/// assert_eq!(segments.len(), 3);
+/// assert_eq!(segments[0].corners[0], (0.0, 0.0));
+/// assert_eq!(segments[0].corners[2], (140.0, 100.0));
+/// assert_eq!(segments[2].ratio, 0.1);
+/// ```
+pub struct Funnel {
+    stages: Vec<(String, f64)>,
+    gap: f64,
+    font: FontDesc<'static>,
+}
+
+impl Funnel {
+    /// Create a new funnel chart from the stages, in the order they are drawn from top to
+    /// bottom
+    /// - `stages`: The label and the value of each stage
+    pub fn new<S: Into<String>, I: IntoIterator<Item = (S, f64)>>(stages: I) -> Self {
+        Self {
+            stages: stages
+                .into_iter()
+                .map(|(label, value)| (label.into(), value.max(0.0)))
+                .collect(),
+            gap: 2.0,
+            font: ("sans-serif", 15).into(),
+        }
+    }
+
+    /// Set the vertical gap between two stages, in pixels
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.max(0.0);
+        self
+    }
+
+    /// Set the font used by the labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Compute the layout of the stages in a rectangle of the given size. The stages are
+    /// centered horizontally and the widest stage takes the full width.
+    /// - `size`: The size of the rectangle
+    /// - **returns** The list of laid out stages
+    pub fn layout(&self, (w, h): (f64, f64)) -> Vec<FunnelSegment> {
+        let n = self.stages.len();
+        let max = self.stages.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        if n == 0 || max <= 0.0 {
+            return vec![];
+        }
+
+        let first = self.stages[0].1;
+        let height = ((h - self.gap * (n - 1) as f64) / n as f64).max(0.0);
+        let half_width = |value: f64| value / max * w / 2.0;
+
+        self.stages
+            .iter()
+            .enumerate()
+            .map(|(idx, (label, value))| {
+                let next = self.stages.get(idx + 1).map_or(*value, |(_, v)| *v);
+                let top = idx as f64 * (height + self.gap);
+                let bottom = top + height;
+                let (top_half, bottom_half) = (half_width(*value), half_width(next));
+                FunnelSegment {
+                    label: label.clone(),
+                    value: *value,
+                    ratio: if first > 0.0 { value / first } else { 0.0 },
+                    corners: [
+                        (w / 2.0 - top_half, top),
+                        (w / 2.0 + top_half, top),
+                        (w / 2.0 + bottom_half, bottom),
+                        (w / 2.0 - bottom_half, bottom),
+                    ],
+                }
+            })
+            .collect()
+    }
+
+    /// Draw the funnel chart on the drawing area. Each stage is labeled with its value and
+    /// its percentage of the first stage.
+    /// - `area`: The drawing area to draw on
+    /// - **returns** The result of the drawing
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (w, h) = area.dim_in_pixel();
+        let text_style = TextStyle::from(self.font.clone())
+            .color(&BLACK)
+            .pos(Pos::new(HPos::Center, VPos::Center));
+
+        for (idx, segment) in self.layout((f64::from(w), f64::from(h))).iter().enumerate() {
+            let corners: Vec<_> = segment
+                .corners
+                .iter()
+                .map(|(x, y)| (x.round() as i32, y.round() as i32))
+                .collect();
+            area.draw(&Polygon::new(corners, Palette99::pick(idx).filled()))?;
+
+            let [ul, _, _, br] = segment.corners;
+            let center = (f64::from(w) / 2.0, (ul.1 + br.1) / 2.0);
+            let label = format!(
+                "{}: {} ({:.1}%)",
+                segment.label,
+                segment.value,
+                segment.ratio * 100.0
+            );
+            area.draw_text(
+                &label,
+                &text_style,
+                (center.0.round() as i32, center.1.round() as i32),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_funnel_layout() {
+        let funnel = Funnel::new(vec![("a", 50.0), ("b", 100.0), ("c", 25.0)]);
+        let segments = funnel.layout((400.0, 304.0));
+        assert_eq!(segments.len(), 3);
+
+        // The widest stage takes the full width, even if it's not the first one
+        assert_eq!(segments[1].corners[0], (0.0, 102.0));
+        assert_eq!(segments[1].corners[1], (400.0, 102.0));
+        assert_eq!(segments[0].corners[2], (400.0, 100.0));
+        assert_eq!(segments[2].corners[3], (150.0, 304.0));
+        assert_eq!(segments[1].ratio, 2.0);
+
+        assert!(Funnel::new(Vec::<(&str, f64)>::new())
+            .layout((100.0, 100.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_funnel_draw() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "visits: 10 (100.0%)");
+                assert_eq!(pos, (100, 25));
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "orders: 5 (50.0%)");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        Funnel::new(vec![("visits", 10.0), ("orders", 5.0)])
+            .draw(&drawing_area)
+            .expect("Drawing Error");
+    }
+}
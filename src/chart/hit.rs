@@ -0,0 +1,124 @@
+use alloc::collections::BTreeMap;
+
+use crate::coord::ReverseCoordTranslate;
+use crate::drawing::backend::BackendCoord;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+// The size of the cells of the spatial index in pixels
+const CELL_SIZE: i32 = 16;
+
+/// The data point nearest to a pixel, found by `ChartContext::hit_test`
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesHit<Coord> {
+    /// The index of the series, in the order the series are drawn, which is also the index of
+    /// the series in the legend
+    pub series: usize,
+    /// The index of the point in the series
+    pub index: usize,
+    /// The position of the point in pixels
+    pub pixel: BackendCoord,
+    /// The distance between the point and the pixel tested, in pixels
+    pub distance: f64,
+    /// The value of the point, mapped back from its position
+    pub value: Coord,
+}
+
+/// The spatial index of the positions of the points drawn, which is a grid of cells
+/// containing the points in them
+#[derive(Clone, Default)]
+pub(crate) struct HitIndex {
+    // The position, the series and the index in the series of each point
+    points: Vec<(BackendCoord, usize, usize)>,
+    cells: BTreeMap<(i32, i32), Vec<usize>>,
+    // The number of points in each series
+    series_len: Vec<usize>,
+}
+
+fn cell_of((x, y): BackendCoord) -> (i32, i32) {
+    (x.div_euclid(CELL_SIZE), y.div_euclid(CELL_SIZE))
+}
+
+impl HitIndex {
+    pub(crate) fn insert(&mut self, series: usize, pixel: BackendCoord) {
+        if self.series_len.len() <= series {
+            self.series_len.resize(series + 1, 0);
+        }
+        let index = self.series_len[series];
+        self.series_len[series] += 1;
+        self.cells
+            .entry(cell_of(pixel))
+            .or_default()
+            .push(self.points.len());
+        self.points.push((pixel, series, index));
+    }
+
+    // Find the point nearest to the pixel within the distance, the earlier one wins a tie
+    pub(crate) fn nearest(
+        &self,
+        pixel: BackendCoord,
+        max_distance: u32,
+    ) -> Option<(BackendCoord, usize, usize, f64)> {
+        let (cx, cy) = cell_of(pixel);
+        let reach = max_distance.div_ceil(CELL_SIZE as u32) as i32;
+        let mut best: Option<(usize, f64)> = None;
+        for x in cx - reach..=cx + reach {
+            for y in cy - reach..=cy + reach {
+                for &idx in self.cells.get(&(x, y)).into_iter().flatten() {
+                    let ((px, py), _, _) = self.points[idx];
+                    let (dx, dy) = ((px - pixel.0) as f64, (py - pixel.1) as f64);
+                    let distance = (dx * dx + dy * dy).sqrt();
+                    if distance > max_distance as f64 {
+                        continue;
+                    }
+                    if best.is_none_or(|(best_idx, best_distance)| {
+                        distance < best_distance || (distance == best_distance && idx < best_idx)
+                    }) {
+                        best = Some((idx, distance));
+                    }
+                }
+            }
+        }
+        best.map(|(idx, distance)| {
+            let (pixel, series, index) = self.points[idx];
+            (pixel, series, index, distance)
+        })
+    }
+}
+
+// Find the point nearest to the pixel and map it back to the value with the coordinate
+pub(crate) fn hit_test<CT: ReverseCoordTranslate>(
+    index: Option<&HitIndex>,
+    coord: &CT,
+    pixel: BackendCoord,
+    max_distance: u32,
+) -> Option<SeriesHit<CT::From>> {
+    let (pixel, series, index, distance) = index?.nearest(pixel, max_distance)?;
+    Some(SeriesHit {
+        series,
+        index,
+        pixel,
+        distance,
+        value: coord.reverse_translate(pixel)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hit_index() {
+        let mut index = HitIndex::default();
+        index.insert(0, (10, 10));
+        index.insert(0, (100, 100));
+        index.insert(1, (-20, 30));
+
+        assert_eq!(index.nearest((12, 10), 5), Some(((10, 10), 0, 0, 2.0)));
+        assert_eq!(index.nearest((97, 96), 5), Some(((100, 100), 0, 1, 5.0)));
+        assert_eq!(index.nearest((-21, 30), 5), Some(((-20, 30), 1, 0, 1.0)));
+        assert_eq!(index.nearest((50, 50), 20), None);
+        // The points in the cells far away are found with a larger distance
+        assert_eq!(index.nearest((50, 50), 100).map(|hit| hit.2), Some(0));
+    }
+}
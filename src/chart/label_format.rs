@@ -0,0 +1,220 @@
+use core::ops::Range;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+// The metric prefixes from 10^-24 to 10^24
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+const BYTE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// The built-in formats of the axis labels, which are used with
+/// `MeshStyle::x_label_format` and `MeshStyle::y_label_format` instead of writing the
+/// formatter functions.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// assert_eq!(LabelFormat::SI("").format(1234.0), "1.23k");
+/// assert_eq!(LabelFormat::SI("Hz").format(0.0035), "3.5mHz");
+/// assert_eq!(LabelFormat::Bytes.format(1536.0), "1.5KiB");
+/// assert_eq!(LabelFormat::Currency("$").format(-1234.5), "-$1,234.50");
+/// assert_eq!(LabelFormat::Percent.format(0.125), "12.5%");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelFormat {
+    /// The value with a metric prefix and the unit, such as `1.2k` and `3.4MHz`, the unit can
+    /// be empty
+    SI(&'static str),
+    /// The number of bytes with a binary prefix, such as `512B` and `1.5MiB`
+    Bytes,
+    /// The amount of money with the symbol of the currency, the thousands separators and two
+    /// decimal digits, such as `$1,234.50`
+    Currency(&'static str),
+    /// The fraction as the percentage, such as `25%` for `0.25`
+    Percent,
+}
+
+// Format the number with about 3 significant digits, without the trailing zeros
+fn format_number(value: f64) -> String {
+    let digits = match value.abs() {
+        v if v >= 100.0 => 0,
+        v if v >= 10.0 => 1,
+        _ => 2,
+    };
+    let text = format!("{:.*}", digits, value);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text[..]
+    };
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// Scale the value down by the base until it's less than the base, the rounding of the number
+// may carry it over to the next unit
+fn scale(value: f64, base: f64, max_units: usize) -> (f64, usize) {
+    let (mut value, mut unit) = (value, 0);
+    while unit + 1 < max_units && format_number(value.abs()).parse::<f64>().unwrap_or(0.0) >= base {
+        value /= base;
+        unit += 1;
+    }
+    (value, unit)
+}
+
+fn format_si(value: f64, unit: &str) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}{}", format_number(value), unit);
+    }
+    // Start from the smallest prefix the value is at least 1 of
+    let power = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+    let (scaled, carry) = scale(
+        value / 1000f64.powi(power),
+        1000.0,
+        (8 - power) as usize + 1,
+    );
+    let prefix = SI_PREFIXES[(power + 8) as usize + carry];
+    format!("{}{}{}", format_number(scaled), prefix, unit)
+}
+
+fn format_currency(value: f64, symbol: &str) -> String {
+    let text = format!("{:.2}", value.abs());
+    let (integer, fraction) = text.split_at(text.len() - 3);
+    let mut grouped = String::new();
+    for (idx, digit) in integer.chars().enumerate() {
+        if idx > 0 && (integer.len() - idx) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let sign = if value < 0.0 && text.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        "-"
+    } else {
+        ""
+    };
+    format!("{}{}{}{}", sign, symbol, grouped, fraction)
+}
+
+impl LabelFormat {
+    /// Format the value of a label
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            LabelFormat::SI(unit) => format_si(value, unit),
+            LabelFormat::Bytes => {
+                let (scaled, unit) = scale(value, 1024.0, BYTE_UNITS.len());
+                format!("{}{}", format_number(scaled), BYTE_UNITS[unit])
+            }
+            LabelFormat::Currency(symbol) => format_currency(value, symbol),
+            LabelFormat::Percent => format!("{}%", format_number(value * 100.0)),
+        }
+    }
+}
+
+/// The context of an axis label, which is passed to the formatters set with
+/// `MeshStyle::x_label_formatter_with_context`, so the label can depend on the other ticks,
+/// such as to show the year only on the first tick of each year, or hide every other label.
+pub struct LabelContext<'a, T> {
+    /// The value of the tick
+    pub value: &'a T,
+    /// The index of the tick in `ticks`
+    pub index: usize,
+    /// All the ticks of the axis, in the order they are drawn
+    pub ticks: &'a [T],
+    /// The range of the axis
+    pub range: &'a Range<T>,
+}
+
+impl<'a, T> LabelContext<'a, T> {
+    /// The tick before this one, if there's one
+    pub fn prev(&self) -> Option<&'a T> {
+        self.index
+            .checked_sub(1)
+            .and_then(|idx| self.ticks.get(idx))
+    }
+
+    /// The tick after this one, if there's one
+    pub fn next(&self) -> Option<&'a T> {
+        self.ticks.get(self.index + 1)
+    }
+}
+
+// The formatter of the labels of an axis, which is set with the mesh style
+pub(crate) enum LabelFormatter<'b, T> {
+    Func(&'b dyn Fn(&T) -> String),
+    Builtin(LabelFormat, fn(&T) -> f64),
+    WithContext(&'b dyn Fn(&LabelContext<T>) -> Option<String>),
+}
+
+impl<'b, T> Clone for LabelFormatter<'b, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'b, T> Copy for LabelFormatter<'b, T> {}
+
+impl<'b, T> LabelFormatter<'b, T> {
+    pub(crate) fn format(&self, context: &LabelContext<T>) -> Option<String> {
+        match self {
+            LabelFormatter::Func(fmt) => Some(fmt(context.value)),
+            LabelFormatter::Builtin(format, value) => Some(format.format(value(context.value))),
+            LabelFormatter::WithContext(fmt) => fmt(context),
+        }
+    }
+
+    // Whether the formatter uses the ticks other than the one it formats
+    pub(crate) fn needs_ticks(&self) -> bool {
+        matches!(self, LabelFormatter::WithContext(_))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_si_format() {
+        let si = LabelFormat::SI("");
+        assert_eq!(si.format(0.0), "0");
+        assert_eq!(si.format(5.0), "5");
+        assert_eq!(si.format(999.0), "999");
+        assert_eq!(si.format(1200.0), "1.2k");
+        assert_eq!(si.format(-3_400_000.0), "-3.4M");
+        assert_eq!(si.format(12_345_678.0), "12.3M");
+        assert_eq!(si.format(0.25), "250m");
+        assert_eq!(si.format(2.5e-6), "2.5µ");
+        // The rounding carries over to the next prefix
+        assert_eq!(si.format(999_999.0), "1M");
+        assert_eq!(LabelFormat::SI("W").format(1500.0), "1.5kW");
+    }
+
+    #[test]
+    fn test_bytes_format() {
+        assert_eq!(LabelFormat::Bytes.format(0.0), "0B");
+        assert_eq!(LabelFormat::Bytes.format(512.0), "512B");
+        assert_eq!(LabelFormat::Bytes.format(1024.0), "1KiB");
+        assert_eq!(LabelFormat::Bytes.format(3.5 * 1024.0 * 1024.0), "3.5MiB");
+        assert_eq!(LabelFormat::Bytes.format(1023.9), "1KiB");
+    }
+
+    #[test]
+    fn test_currency_and_percent_format() {
+        let usd = LabelFormat::Currency("$");
+        assert_eq!(usd.format(0.0), "$0.00");
+        assert_eq!(usd.format(999.999), "$1,000.00");
+        assert_eq!(usd.format(1_234_567.8), "$1,234,567.80");
+        assert_eq!(usd.format(-12.0), "-$12.00");
+        assert_eq!(usd.format(-0.001), "$0.00");
+        assert_eq!(LabelFormat::Currency("€").format(100.0), "€100.00");
+
+        assert_eq!(LabelFormat::Percent.format(0.25), "25%");
+        assert_eq!(LabelFormat::Percent.format(1.0), "100%");
+        assert_eq!(LabelFormat::Percent.format(-0.0312), "-3.12%");
+    }
+}
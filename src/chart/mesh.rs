@@ -1,16 +1,45 @@
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use core::fmt::Debug;
+use core::marker::PhantomData;
 
 use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
-use crate::coord::{MeshLine, Ranged, RangedCoord};
-use crate::drawing::backend::DrawingBackend;
+use super::label_format::{LabelContext, LabelFormat, LabelFormatter};
+use crate::coord::{CoordTranslate, MeshLine, Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, RenderEvent, RenderPhase};
 use crate::drawing::DrawingAreaErrorKind;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, FontDesc, FontStyle, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle,
 };
 
+/// A line of the grid, which is given to the style function set with
+/// `MeshStyle::line_style_fn`
+pub enum GridLine<'v, X, Y> {
+    /// The vertical line at the value of the X axis
+    X(&'v X),
+    /// The horizontal line at the value of the Y axis
+    Y(&'v Y),
+}
+
+// The function deciding the style of each grid line, it's given the default style of the line
+// and returns the style the line is drawn with, or `None` to skip the line
+pub(super) type GridLineStyleFn<'b, X, Y> =
+    &'b dyn Fn(GridLine<X, Y>, &ShapeStyle) -> Option<ShapeStyle>;
+
+/// How the axis labels which would overlap are drawn, see `MeshStyle::label_overlap`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LabelOverlap {
+    /// Draw only every Nth label, so the labels drawn don't overlap
+    #[default]
+    Thin,
+    /// Rotate the labels of the X axis to read from the bottom, and thin them out if they
+    /// still overlap. The labels of the Y axis are thinned out.
+    Rotate,
+    /// Draw all the labels, even if they overlap
+    Allow,
+}
+
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     style: MeshStyle<'a, 'b, X, Y, DB>,
@@ -72,6 +101,27 @@ where
         self
     }
 
+    /// Format the X labels with one of the built-in formats, see `LabelFormat`
+    /// - `format`: The format of the labels
+    pub fn x_label_format(&mut self, format: LabelFormat) -> &mut Self
+    where
+        X::ValueType: Into<f64> + Copy,
+    {
+        self.style.x_label_format(format);
+        self
+    }
+
+    /// Set the formatter function for the X label text, which is given the context of the
+    /// label, see `MeshStyle::x_label_formatter_with_context`
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter_with_context(
+        &mut self,
+        fmt: &'b dyn Fn(&LabelContext<X::ValueType>) -> Option<String>,
+    ) -> &mut Self {
+        self.style.x_label_formatter_with_context(fmt);
+        self
+    }
+
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
@@ -79,6 +129,27 @@ where
         self
     }
 
+    /// Format the Y labels with one of the built-in formats, see `LabelFormat`
+    /// - `format`: The format of the labels
+    pub fn y_label_format(&mut self, format: LabelFormat) -> &mut Self
+    where
+        Y::ValueType: Into<f64> + Copy,
+    {
+        self.style.y_label_format(format);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which is given the context of the
+    /// label, see `MeshStyle::y_label_formatter_with_context`
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_with_context(
+        &mut self,
+        fmt: &'b dyn Fn(&LabelContext<Y::ValueType>) -> Option<String>,
+    ) -> &mut Self {
+        self.style.y_label_formatter_with_context(fmt);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -101,6 +172,36 @@ where
         self
     }
 
+    /// Disable the tick marks of the secondary X axis
+    pub fn disable_x_tick_marks(&mut self) -> &mut Self {
+        self.style.disable_x_tick_marks();
+        self
+    }
+
+    /// Disable the tick marks of the secondary Y axis
+    pub fn disable_y_tick_marks(&mut self) -> &mut Self {
+        self.style.disable_y_tick_marks();
+        self
+    }
+
+    /// Disable the labels of the secondary X axis
+    pub fn disable_x_labels(&mut self) -> &mut Self {
+        self.style.disable_x_labels();
+        self
+    }
+
+    /// Disable the labels of the secondary Y axis
+    pub fn disable_y_labels(&mut self) -> &mut Self {
+        self.style.disable_y_labels();
+        self
+    }
+
+    /// Set how the labels which would overlap are drawn, see `MeshStyle::label_overlap`
+    pub fn label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.style.label_overlap(overlap);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -137,6 +238,7 @@ where
 }
 
 /// The struct that is used for tracking the configuration of a mesh of any chart
+#[allow(clippy::type_complexity)]
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB>
 where
     DB: DrawingBackend,
@@ -146,6 +248,12 @@ where
     pub(super) draw_y_mesh: bool,
     pub(super) draw_x_axis: bool,
     pub(super) draw_y_axis: bool,
+    pub(super) draw_x_fine_mesh: bool,
+    pub(super) draw_y_fine_mesh: bool,
+    pub(super) draw_x_ticks: bool,
+    pub(super) draw_y_ticks: bool,
+    pub(super) draw_x_labels: bool,
+    pub(super) draw_y_labels: bool,
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
     pub(super) n_x_labels: usize,
@@ -158,8 +266,12 @@ where
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    pub(super) format_x: Option<LabelFormatter<'b, X::ValueType>>,
+    pub(super) format_y: Option<LabelFormatter<'b, Y::ValueType>>,
+    pub(super) line_style_fn: Option<GridLineStyleFn<'b, X::ValueType, Y::ValueType>>,
+    // Where the axes cross when they are drawn through the origin
+    pub(super) origin_axes: Option<fn(&RangedCoord<X, Y>) -> BackendCoord>,
+    pub(super) label_overlap: LabelOverlap,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, RangedCoord<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
@@ -250,6 +362,86 @@ where
         self.disable_x_axis().disable_y_axis()
     }
 
+    /// Disable the fine grid for the x axis, the coarse grid is still drawn
+    pub fn disable_x_fine_mesh(&mut self) -> &mut Self {
+        self.draw_x_fine_mesh = false;
+        self
+    }
+
+    /// Disable the fine grid for the y axis, the coarse grid is still drawn
+    pub fn disable_y_fine_mesh(&mut self) -> &mut Self {
+        self.draw_y_fine_mesh = false;
+        self
+    }
+
+    /// Disable the fine grids, so only the grid lines at the labels are drawn
+    pub fn disable_fine_mesh(&mut self) -> &mut Self {
+        self.disable_x_fine_mesh().disable_y_fine_mesh()
+    }
+
+    /// Disable the tick marks of the X axis, the labels and the axis line are still drawn
+    pub fn disable_x_tick_marks(&mut self) -> &mut Self {
+        self.draw_x_ticks = false;
+        self
+    }
+
+    /// Disable the tick marks of the Y axis, the labels and the axis line are still drawn
+    pub fn disable_y_tick_marks(&mut self) -> &mut Self {
+        self.draw_y_ticks = false;
+        self
+    }
+
+    /// Disable the tick marks of all axes
+    pub fn disable_tick_marks(&mut self) -> &mut Self {
+        self.disable_x_tick_marks().disable_y_tick_marks()
+    }
+
+    /// Disable the labels of the X axis, the grid and the tick marks are still drawn
+    pub fn disable_x_labels(&mut self) -> &mut Self {
+        self.draw_x_labels = false;
+        self
+    }
+
+    /// Disable the labels of the Y axis, the grid and the tick marks are still drawn
+    pub fn disable_y_labels(&mut self) -> &mut Self {
+        self.draw_y_labels = false;
+        self
+    }
+
+    /// Disable the labels of all axes
+    pub fn disable_labels(&mut self) -> &mut Self {
+        self.disable_x_labels().disable_y_labels()
+    }
+
+    /// Draw the axes as the arrowed lines crossing at the origin inside the plot, with the
+    /// labels along the axes instead of at the border of the plot. When the origin is out of
+    /// the plot, the axes are drawn at the nearest edges.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_ranged(-5.0..5.0, -5.0..5.0)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .axes_through_origin()
+    ///     .x_desc("x")
+    ///     .y_desc("y")
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    pub fn axes_through_origin(&mut self) -> &mut Self
+    where
+        X::ValueType: Default,
+        Y::ValueType: Default,
+    {
+        self.origin_axes = Some(|coord| coord.translate(&(Default::default(), Default::default())));
+        self
+    }
+
     /// Set the style definition for the axis
     /// - `style`: The style for the axis
     pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -284,6 +476,51 @@ where
         self
     }
 
+    /// Set the function deciding the style of each grid line, which is given the line with its
+    /// value and the style it would be drawn with, and returns the style to draw it with, or
+    /// `None` to skip the line.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_ranged(-10..10, -10..10)
+    ///     .unwrap();
+    /// // Emphasize the zero lines and drop the vertical lines at the odd values
+    /// let style_fn = |line: GridLine<i32, i32>, style: &ShapeStyle| match line {
+    ///     GridLine::X(0) | GridLine::Y(0) => Some(BLACK.stroke_width(2)),
+    ///     GridLine::X(x) if x % 2 != 0 => None,
+    ///     _ => Some(style.clone()),
+    /// };
+    /// chart
+    ///     .configure_mesh()
+    ///     .line_style_fn(&style_fn)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    /// - `style_fn`: The style function
+    #[allow(clippy::type_complexity)]
+    pub fn line_style_fn(
+        &mut self,
+        style_fn: &'b dyn Fn(
+            GridLine<X::ValueType, Y::ValueType>,
+            &ShapeStyle,
+        ) -> Option<ShapeStyle>,
+    ) -> &mut Self {
+        self.line_style_fn = Some(style_fn);
+        self
+    }
+
+    /// Set how the labels which would overlap are drawn, based on the sizes of the label
+    /// text. By default, only every Nth label is drawn, so the labels don't overlap.
+    /// - `overlap`: How the overlapping labels are drawn
+    pub fn label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.label_overlap = overlap;
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -310,14 +547,80 @@ where
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = fmt;
+        self.format_x = Some(LabelFormatter::Func(fmt));
+        self
+    }
+
+    /// Format the X labels with one of the built-in formats, such as the SI prefixes or the
+    /// percentages, see `LabelFormat`
+    /// - `format`: The format of the labels
+    pub fn x_label_format(&mut self, format: LabelFormat) -> &mut Self
+    where
+        X::ValueType: Into<f64> + Copy,
+    {
+        self.format_x = Some(LabelFormatter::Builtin(format, |v| (*v).into()));
+        self
+    }
+
+    /// Set the formatter function for the X label text, which is given the context of the
+    /// label. The formatter is given the index of the tick, all the ticks of the axis and the range of
+    /// the axis besides the value, and the label is hidden if it returns `None`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(20)
+    ///     .build_ranged(0..100, 0..100)
+    ///     .unwrap();
+    /// // Show every other label, and the unit on the last one
+    /// let fmt = |ctx: &LabelContext<i32>| match ctx.index {
+    ///     idx if idx % 2 == 1 => None,
+    ///     idx if idx + 1 == ctx.ticks.len() => Some(format!("{}s", ctx.value)),
+    ///     _ => Some(ctx.value.to_string()),
+    /// };
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_label_formatter_with_context(&fmt)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter_with_context(
+        &mut self,
+        fmt: &'b dyn Fn(&LabelContext<X::ValueType>) -> Option<String>,
+    ) -> &mut Self {
+        self.format_x = Some(LabelFormatter::WithContext(fmt));
         self
     }
 
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = fmt;
+        self.format_y = Some(LabelFormatter::Func(fmt));
+        self
+    }
+
+    /// Format the Y labels with one of the built-in formats, see `LabelFormat`
+    /// - `format`: The format of the labels
+    pub fn y_label_format(&mut self, format: LabelFormat) -> &mut Self
+    where
+        Y::ValueType: Into<f64> + Copy,
+    {
+        self.format_y = Some(LabelFormatter::Builtin(format, |v| (*v).into()));
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which is given the context of the
+    /// label. See `x_label_formatter_with_context`.
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_with_context(
+        &mut self,
+        fmt: &'b dyn Fn(&LabelContext<Y::ValueType>) -> Option<String>,
+    ) -> &mut Self {
+        self.format_y = Some(LabelFormatter::WithContext(fmt));
         self
     }
 
@@ -343,19 +646,29 @@ where
     }
 
     /// Draw the configured mesh on the target plot
-    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
+    {
         let mut target = None;
-        std::mem::swap(&mut target, &mut self.target);
+        core::mem::swap(&mut target, &mut self.target);
         let target = target.unwrap();
-
-        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
-        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
-        let default_axis_color = RGBColor(0, 0, 0);
+        target
+            .drawing_area
+            .render_event(RenderEvent::PhaseStart(RenderPhase::Mesh));
+        target.drawing_area.begin_group("Axes");
+
+        let theme = &target.theme;
+        let default_mesh_color_1 = theme.bold_mesh_color.clone();
+        let default_mesh_color_2 = theme.light_mesh_color.clone();
+        let default_axis_color = theme.axis_color.clone();
         let default_label_font = FontDesc::new(
-            FontFamily::SansSerif,
+            theme.font_family,
             f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
             FontStyle::Normal,
-        );
+        )
+        .color(&theme.text_color);
 
         let mesh_style_1 = self
             .line_style_1
@@ -373,12 +686,9 @@ where
         let x_label_style = self
             .x_label_style
             .clone()
-            .unwrap_or_else(|| default_label_font.clone().into());
+            .unwrap_or_else(|| default_label_font.clone());
 
-        let y_label_style = self
-            .y_label_style
-            .clone()
-            .unwrap_or_else(|| default_label_font.into());
+        let y_label_style = self.y_label_style.clone().unwrap_or(default_label_font);
 
         let axis_desc_style = self
             .axis_desc_style
@@ -388,44 +698,137 @@ where
         target.draw_mesh(
             (self.n_y_labels * 10, self.n_x_labels * 10),
             &mesh_style_2,
+            self.line_style_fn,
             &x_label_style,
             &y_label_style,
-            |_| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            |_, _| None,
+            self.draw_x_mesh && self.draw_x_fine_mesh,
+            self.draw_y_mesh && self.draw_y_fine_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
             false,
             &axis_style,
             &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
+            None,
+            None,
             self.x_tick_size,
             self.y_tick_size,
+            (self.draw_x_ticks, self.draw_y_ticks),
+            self.label_overlap,
         )?;
 
+        // The ticks of the axes are only listed for the formatters using them, the labels are
+        // formatted in the order of the ticks
+        let coord = target.drawing_area.as_coord_spec();
+        let (x_range, y_range) = (coord.x_spec().range(), coord.y_spec().range());
+        let x_ticks = match self.format_x {
+            Some(fmt) if fmt.needs_ticks() => coord.x_spec().key_points(self.n_x_labels),
+            _ => vec![],
+        };
+        let y_ticks = match self.format_y {
+            Some(fmt) if fmt.needs_ticks() => coord.y_spec().key_points(self.n_y_labels),
+            _ => vec![],
+        };
+        let (mut x_index, mut y_index) = (0, 0);
+        let origin = self.origin_axes;
+        let (mut x_labels, mut y_labels) = (vec![], vec![]);
+
         target.draw_mesh(
             (self.n_y_labels, self.n_x_labels),
             &mesh_style_1,
+            self.line_style_fn,
             &x_label_style,
             &y_label_style,
-            |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+            |coord, m| {
+                let label = match m {
+                    // The ticks without the labels are given the empty labels
+                    MeshLine::XMesh(..) if !self.draw_x_labels => Some(String::new()),
+                    MeshLine::YMesh(..) if !self.draw_y_labels => Some(String::new()),
+                    MeshLine::XMesh(_, _, v) => {
+                        x_index += 1;
+                        match self.format_x {
+                            Some(fmt) => fmt.format(&LabelContext {
+                                value: v,
+                                index: x_index - 1,
+                                ticks: &x_ticks,
+                                range: &x_range,
+                            }),
+                            None => Some(
+                                coord
+                                    .x_spec()
+                                    .format_key_point(v, self.n_x_labels)
+                                    .unwrap_or_else(|| format!("{:?}", v)),
+                            ),
+                        }
+                    }
+                    MeshLine::YMesh(_, _, v) => {
+                        y_index += 1;
+                        match self.format_y {
+                            Some(fmt) => fmt.format(&LabelContext {
+                                value: v,
+                                index: y_index - 1,
+                                ticks: &y_ticks,
+                                range: &y_range,
+                            }),
+                            None => Some(
+                                coord
+                                    .y_spec()
+                                    .format_key_point(v, self.n_y_labels)
+                                    .unwrap_or_else(|| format!("{:?}", v)),
+                            ),
+                        }
+                    }
+                };
+                // The labels are drawn along the axes through the origin instead of the label
+                // areas
+                if origin.is_none() {
+                    return label;
+                }
+                match (m, label) {
+                    (MeshLine::XMesh((x, _), _, _), Some(label)) => x_labels.push((*x, label)),
+                    (MeshLine::YMesh((_, y), _, _), Some(label)) => y_labels.push((*y, label)),
+                    _ => {}
+                }
+                None
             },
             self.draw_x_mesh,
             self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
-            self.draw_x_axis,
-            self.draw_y_axis,
+            self.draw_x_axis && origin.is_none(),
+            self.draw_y_axis && origin.is_none(),
             &axis_style,
             &axis_desc_style,
-            None,
-            None,
+            self.x_desc.clone().filter(|_| origin.is_none()),
+            self.y_desc.clone().filter(|_| origin.is_none()),
             self.x_tick_size,
             self.y_tick_size,
-        )
+            (self.draw_x_ticks, self.draw_y_ticks),
+            self.label_overlap,
+        )?;
+
+        if let Some(origin) = origin {
+            let origin = origin(target.drawing_area.as_coord_spec());
+            let tick_size = |draw: bool, size: [i32; 2]| if draw { size[1].abs() } else { 0 };
+            target.draw_origin_axes(
+                origin,
+                (&x_labels, &y_labels),
+                (self.draw_x_axis, self.draw_y_axis),
+                (
+                    tick_size(self.draw_x_ticks, self.x_tick_size),
+                    tick_size(self.draw_y_ticks, self.y_tick_size),
+                ),
+                &axis_style,
+                (&x_label_style, &y_label_style),
+                &axis_desc_style,
+                (self.x_desc.as_ref(), self.y_desc.as_ref()),
+            )?;
+        }
+        target.drawing_area.end_group();
+        target
+            .drawing_area
+            .render_event(RenderEvent::PhaseEnd(RenderPhase::Mesh));
+        Ok(())
     }
 }
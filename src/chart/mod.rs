@@ -13,13 +13,31 @@ detailed description for each struct.
 */
 
 mod builder;
+#[cfg(feature = "chrono")]
+mod calendar;
+mod colorbar;
 mod context;
 mod dual_coord;
+mod facet;
+mod funnel;
+mod hit;
+mod label_format;
 mod mesh;
+mod pareto;
 mod series;
+mod treemap;
 
 pub use builder::{ChartBuilder, LabelAreaPosition};
+#[cfg(feature = "chrono")]
+pub use calendar::CalendarHeatmap;
+pub use colorbar::Colorbar;
 pub use context::{ChartContext, ChartState, SeriesAnno};
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::MeshStyle;
-pub use series::{SeriesLabelPosition, SeriesLabelStyle};
+pub use facet::FacetGrid;
+pub use funnel::{Funnel, FunnelSegment};
+pub use hit::SeriesHit;
+pub use label_format::{LabelContext, LabelFormat};
+pub use mesh::{GridLine, LabelOverlap, MeshStyle};
+pub use pareto::ParetoChart;
+pub use series::{SeriesLabelPosition, SeriesLabelStyle, SharedLegend};
+pub use treemap::{Treemap, TreemapCell, TreemapNode};
@@ -0,0 +1,190 @@
+use super::ChartBuilder;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::{Circle, PathElement, Rectangle};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{Color, ShapeStyle, BLUE, RED};
+
+/// The helper that draws a Pareto chart: the categories are sorted by their values in
+/// descending order and drawn as bars, and the cumulative percentage is drawn as a line
+/// on the secondary y axis.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut buffer, (640, 480)).into_drawing_area();
+///     ParetoChart::new(vec![("Scratch", 12.0), ("Dent", 35.0), ("Crack", 4.0)])
+///         .caption("Defects")
+///         .draw(&root)
+///         .unwrap();
+/// }
+/// assert!(buffer.contains("Dent"));
+/// ```
+pub struct ParetoChart {
+    data: Vec<(String, f64)>,
+    caption: Option<String>,
+    y_desc: Option<String>,
+    bar_style: ShapeStyle,
+    line_style: ShapeStyle,
+}
+
+impl ParetoChart {
+    /// Create a new Pareto chart from the categories and their values
+    /// - `data`: The iterator of `(category, value)` pairs, the order doesn't matter
+    /// - **returns** The newly created chart helper
+    pub fn new<S: Into<String>, I: IntoIterator<Item = (S, f64)>>(data: I) -> Self {
+        let mut data: Vec<_> = data
+            .into_iter()
+            .map(|(name, value)| (name.into(), value))
+            .filter(|(_, value)| value.is_finite())
+            .collect();
+        data.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        Self {
+            data,
+            caption: None,
+            y_desc: None,
+            bar_style: BLUE.mix(0.6).filled(),
+            line_style: RED.stroke_width(2),
+        }
+    }
+
+    /// Set the caption of the chart
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Set the description of the primary y axis
+    pub fn y_desc<S: Into<String>>(mut self, desc: S) -> Self {
+        self.y_desc = Some(desc.into());
+        self
+    }
+
+    /// Set the style of the bars
+    pub fn bar_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.bar_style = style.into();
+        self
+    }
+
+    /// Set the style of the cumulative percentage line
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Get the cumulative percentage of each category, in the order they are drawn
+    pub fn cumulative_percentage(&self) -> Vec<f64> {
+        let total: f64 = self.data.iter().map(|(_, v)| v).sum();
+        let mut acc = 0.0;
+        self.data
+            .iter()
+            .map(|(_, v)| {
+                acc += v;
+                if total == 0.0 {
+                    0.0
+                } else {
+                    acc * 100.0 / total
+                }
+            })
+            .collect()
+    }
+
+    /// Draw the chart on the drawing area
+    /// - `area`: The drawing area to draw on
+    /// - **returns** The result of the drawing
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let n = self.data.len();
+        let max = self.data.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        let max = if max > 0.0 { max * 1.05 } else { 1.0 };
+
+        let mut builder = ChartBuilder::on(area);
+        builder
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(50)
+            .right_y_label_area_size(50);
+        if let Some(caption) = self.caption.as_ref() {
+            builder.caption(caption, ("sans-serif", 30));
+        }
+
+        let mut chart = builder
+            .build_ranged(-0.5..(n as f64 - 0.5).max(0.5), 0.0..max)?
+            .set_secondary_coord(-0.5..(n as f64 - 0.5).max(0.5), 0.0..100.0);
+
+        let label_func = |x: &f64| {
+            let idx = x.round();
+            if (x - idx).abs() < 1e-6 && idx >= 0.0 && (idx as usize) < n {
+                self.data[idx as usize].0.clone()
+            } else {
+                "".to_string()
+            }
+        };
+        let percent_func = |y: &f64| format!("{:.0}%", y);
+
+        let mut mesh = chart.configure_mesh();
+        mesh.disable_x_mesh()
+            .x_labels(n.max(1))
+            .x_label_formatter(&label_func);
+        if let Some(desc) = self.y_desc.as_ref() {
+            mesh.y_desc(desc.as_str());
+        }
+        mesh.draw()?;
+
+        chart
+            .configure_secondary_axes()
+            .y_label_formatter(&percent_func)
+            .draw()?;
+
+        chart.draw_series(self.data.iter().enumerate().map(|(idx, (_, v))| {
+            let x = idx as f64;
+            Rectangle::new([(x - 0.4, *v), (x + 0.4, 0.0)], self.bar_style.clone())
+        }))?;
+
+        let points: Vec<_> = self
+            .cumulative_percentage()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, pct)| (idx as f64, pct))
+            .collect();
+
+        chart.draw_secondary_series(core::iter::once(PathElement::new(
+            points.clone(),
+            self.line_style.clone(),
+        )))?;
+        chart.draw_secondary_series(
+            points
+                .into_iter()
+                .map(|p| Circle::new(p, 3, self.line_style.filled())),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pareto_chart() {
+        let chart = ParetoChart::new(vec![("a", 1.0), ("b", 3.0), ("c", 6.0)]);
+        assert_eq!(chart.cumulative_percentage(), vec![60.0, 90.0, 100.0]);
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                // The marker at 100% is cut at the top of the plotting area
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        chart.draw(&drawing_area).expect("Drawing Error");
+    }
+}
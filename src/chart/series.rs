@@ -1,9 +1,13 @@
-use super::ChartContext;
-use crate::coord::CoordTranslate;
+use super::{ChartContext, SeriesAnno};
+use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
-use crate::drawing::{DrawingAreaErrorKind, DrawingBackend};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
 use crate::element::{EmptyElement, IntoDynElement, MultiLineText, Rectangle};
-use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{
+    Color, IntoFont, IntoTextStyle, RGBAColor, ShapeStyle, SizeDesc, TextStyle, BLACK, TRANSPARENT,
+};
 
 /// Describes where we want to put the series label
 pub enum SeriesLabelPosition {
@@ -118,74 +122,293 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
 
     /// Draw the series label area
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let in_step = self.target.plotting_area().in_step("series labels");
+        self.draw_labels().map_err(in_step)
+    }
+
+    fn draw_labels(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let drawing_area = self.target.plotting_area().strip_coord_spec();
 
         // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
         // resolved
         let default_font = ("sans-serif", 12).into_font();
-        let default_style: TextStyle = default_font.into();
+        let default_style = default_font.color(&self.target.theme.text_color);
 
         let font = {
             let mut temp = None;
-            std::mem::swap(&mut self.label_font, &mut temp);
+            core::mem::swap(&mut self.label_font, &mut temp);
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        draw_legend(
+            &drawing_area,
+            self.target.series_anno.iter(),
+            &font,
+            &self.position,
+            (self.legend_area_size, self.margin),
+            (&self.background, &self.border_style),
+        )
+    }
+}
+
+// Draw the labels and the legend elements of the series in a box on the drawing area
+fn draw_legend<'a, 'c, DB: DrawingBackend + 'a>(
+    drawing_area: &DrawingArea<DB, Shift>,
+    series: impl Iterator<Item = &'c SeriesAnno<'a, DB>>,
+    font: &TextStyle,
+    position: &SeriesLabelPosition,
+    (legend_area_size, margin): (u32, u32),
+    (background, border_style): (&ShapeStyle, &ShapeStyle),
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    'a: 'c,
+{
+    let mut label_element = MultiLineText::<_, &str>::new((0, 0), font);
+    let mut funcs = vec![];
+
+    for anno in series {
+        let label_text = anno.get_label();
+        let draw_func = anno.get_draw_func();
+
+        if label_text == "" && draw_func.is_none() {
+            continue;
+        }
+
+        funcs.push(draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()));
+        label_element.push_line(label_text);
+    }
+
+    let (mut w, mut h) = label_element
+        .estimate_dimension()
+        .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
+
+    let margin = margin as i32;
+
+    w += legend_area_size as i32 + margin * 2;
+    h += margin * 2;
+
+    let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        for anno in self.target.series_anno.iter() {
-            let label_text = anno.get_label();
-            let draw_func = anno.get_draw_func();
+    let (label_x, label_y) = position.layout_label_area((w, h), (area_w, area_h));
 
-            if label_text == "" && draw_func.is_none() {
+    label_element.relocate((label_x + legend_area_size as i32 + margin, label_y + margin));
+
+    drawing_area.draw(&Rectangle::new(
+        [(label_x, label_y), (label_x + w, label_y + h)],
+        background.filled(),
+    ))?;
+    drawing_area.draw(&Rectangle::new(
+        [(label_x, label_y), (label_x + w, label_y + h)],
+        border_style.clone(),
+    ))?;
+    drawing_area.draw(&label_element)?;
+
+    for (((_, y0), (_, y1)), make_elem) in label_element
+        .compute_line_layout()
+        .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
+        .into_iter()
+        .zip(funcs.into_iter())
+    {
+        let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
+        drawing_area.draw(&legend_element)?;
+    }
+
+    Ok(())
+}
+
+/// The legend shared by several charts, such as the facets or the stacked panels of a figure.
+/// The series labels are collected from the charts, and drawn in one legend on a dedicated
+/// area instead of a legend in each chart. The series labeled the same in several charts are
+/// listed once.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (400, 300)).into_drawing_area();
+/// let (panels, legend_area) = root.split_horizontally(320);
+/// let panels = panels.split_evenly((2, 1));
+///
+/// let mut legend = SharedLegend::new();
+/// for (idx, panel) in panels.iter().enumerate() {
+///     let mut chart = ChartBuilder::on(panel)
+///         .build_ranged(0.0..1.0, 0.0..1.0)
+///         .unwrap();
+///     chart
+///         .draw_series(LineSeries::new(vec![(0.0, 0.1), (1.0, 0.9)], &RED))
+///         .unwrap()
+///         .label("signal")
+///         .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+///     chart
+///         .draw_series(LineSeries::new(vec![(0.0, 0.5), (1.0, 0.5)], &BLUE))
+///         .unwrap()
+///         .label(format!("baseline {}", idx))
+///         .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+///     legend.collect(&mut chart);
+/// }
+/// // The labels are "signal", "baseline 0" and "baseline 1"
+/// legend.draw(&legend_area).unwrap();
+/// ```
+pub struct SharedLegend<'a, DB: DrawingBackend> {
+    series: Vec<SeriesAnno<'a, DB>>,
+    position: SeriesLabelPosition,
+    legend_area_size: u32,
+    border_style: ShapeStyle,
+    background: ShapeStyle,
+    label_font: Option<TextStyle<'a>>,
+    margin: u32,
+    text_color: RGBAColor,
+}
+
+impl<'a, DB: DrawingBackend + 'a> Default for SharedLegend<'a, DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a> SharedLegend<'a, DB> {
+    /// Create an empty shared legend
+    pub fn new() -> Self {
+        Self {
+            series: vec![],
+            position: SeriesLabelPosition::UpperLeft,
+            legend_area_size: 30,
+            border_style: (&TRANSPARENT).into(),
+            background: (&TRANSPARENT).into(),
+            label_font: None,
+            margin: 10,
+            text_color: BLACK.to_rgba(),
+        }
+    }
+
+    /// Move the series labels of a chart to the legend, so the chart doesn't have them anymore.
+    /// The labels already in the legend are skipped, and the text color of the theme of the
+    /// first chart is used for the labels.
+    ///
+    /// - `chart`: The chart to collect the labels from
+    pub fn collect<CT: CoordTranslate>(
+        &mut self,
+        chart: &mut ChartContext<'a, DB, CT>,
+    ) -> &mut Self {
+        if self.series.is_empty() {
+            self.text_color = chart.theme.text_color.clone();
+        }
+        for anno in core::mem::take(&mut chart.series_anno) {
+            let label = anno.get_label();
+            if !label.is_empty() && self.series.iter().any(|other| other.get_label() == label) {
                 continue;
             }
-
-            funcs.push(
-                draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
-            );
-            label_element.push_line(label_text);
+            self.series.push(anno);
         }
+        self
+    }
+
+    /// Set the position of the legend in the area it's drawn on, the default is the upper left
+    /// - `pos`: The positioning style
+    pub fn position(&mut self, pos: SeriesLabelPosition) -> &mut Self {
+        self.position = pos;
+        self
+    }
+
+    /// Set the margin of the legend in pixels
+    /// - `value`: The margin
+    pub fn margin(&mut self, value: u32) -> &mut Self {
+        self.margin = value;
+        self
+    }
+
+    /// Set the size of the area of the legend elements in pixels
+    /// - `size`: The size of the legend elements
+    pub fn legend_area_size(&mut self, size: u32) -> &mut Self {
+        self.legend_area_size = size;
+        self
+    }
+
+    /// Set the style of the border of the legend
+    /// - `style`: The style of the border
+    pub fn border_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the background style of the legend
+    /// - `style`: The style of the background
+    pub fn background_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.background = style.into();
+        self
+    }
 
-        let (mut w, mut h) = label_element
-            .estimate_dimension()
-            .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
-
-        let margin = self.margin as i32;
-
-        w += self.legend_area_size as i32 + margin * 2;
-        h += margin * 2;
-
-        let (area_w, area_h) = drawing_area.dim_in_pixel();
-
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
-
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
-
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.background.filled(),
-        ))?;
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.border_style.clone(),
-        ))?;
-        drawing_area.draw(&label_element)?;
-
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+    /// Set the font of the labels
+    /// - `font`: The font
+    pub fn label_font<F: IntoTextStyle<'a>>(&mut self, font: F) -> &mut Self {
+        self.label_font = Some(font.into_text_style(&(0, 0)));
+        self
+    }
+
+    /// Draw the legend on a drawing area
+    /// - `area`: The area the legend is drawn on
+    /// - **returns** The result of the drawing
+    pub fn draw(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let default_style = ("sans-serif", 12).into_font().color(&self.text_color);
+        let font = self.label_font.as_ref().unwrap_or(&default_style);
+        draw_legend(
+            area,
+            self.series.iter(),
+            font,
+            &self.position,
+            (self.legend_area_size, self.margin),
+            (&self.background, &self.border_style),
+        )
+        .map_err(area.in_step("series labels"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_shared_legend() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let collected = texts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+        let (panels, legend_area) = drawing_area.split_horizontally(200);
+        let panels = panels.split_evenly((2, 1));
+
+        let mut legend = SharedLegend::new();
+        for (idx, panel) in panels.iter().enumerate() {
+            let mut chart = ChartBuilder::on(panel)
+                .build_ranged(0..10, 0..10)
+                .expect("Create chart");
+            for label in &["shared", if idx == 0 { "first" } else { "second" }] {
+                chart
+                    .draw_series(std::iter::once(Circle::new((5, 5), 3, &RED)))
+                    .expect("Drawing error")
+                    .label(*label)
+                    .legend(|p| Circle::new(p, 3, &RED));
+            }
+            // The series without a label aren't listed
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 5), 3, &RED)))
+                .expect("Drawing error");
+            legend.collect(&mut chart);
+            // The labels are moved out of the chart
+            chart
+                .configure_series_labels()
+                .draw()
+                .expect("Drawing error");
         }
+        legend.draw(&legend_area).expect("Drawing error");
 
-        Ok(())
+        assert_eq!(*texts.borrow(), vec!["shared", "first", "second"]);
     }
 }
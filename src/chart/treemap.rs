@@ -0,0 +1,336 @@
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::Rectangle;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{Color, FontDesc, Palette, Palette99, RGBAColor, TextStyle, BLACK, WHITE};
+
+/// A node of the weighted tree rendered by `Treemap`
+#[derive(Clone, Debug)]
+pub struct TreemapNode {
+    label: String,
+    value: f64,
+    children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    /// Create a leaf node
+    /// - `label`: The label of the node
+    /// - `value`: The weight of the node, which decides the area of its rectangle
+    pub fn leaf<S: Into<String>>(label: S, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value: if value.is_finite() {
+                value.max(0.0)
+            } else {
+                0.0
+            },
+            children: vec![],
+        }
+    }
+
+    /// Create an inner node, whose weight is the sum of the weights of its children
+    /// - `label`: The label of the node
+    /// - `children`: The child nodes
+    pub fn node<S: Into<String>>(label: S, children: Vec<TreemapNode>) -> Self {
+        let value = children.iter().map(TreemapNode::weight).sum();
+        Self {
+            label: label.into(),
+            value,
+            children,
+        }
+    }
+
+    /// Get the weight of the node
+    pub fn weight(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the label of the node
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// A rectangle produced by the treemap layout
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreemapCell {
+    /// The label of the node
+    pub label: String,
+    /// The depth of the node, the top level nodes have depth 0
+    pub depth: usize,
+    /// The index of the top level node this node belongs to
+    pub branch: usize,
+    /// Whether the node is a leaf node
+    pub is_leaf: bool,
+    /// The upper left corner of the rectangle
+    pub upper_left: (f64, f64),
+    /// The bottom right corner of the rectangle
+    pub bottom_right: (f64, f64),
+}
+
+/// The squarified treemap, which lays out a weighted tree as nested rectangles whose
+/// areas are proportional to the weights, while keeping the aspect ratio close to 1.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let treemap = Treemap::new(vec![
+///     TreemapNode::leaf("a", 6.0),
+///     TreemapNode::node("b", vec![TreemapNode::leaf("b1", 2.0), TreemapNode::leaf("b2", 2.0)]),
+/// ]);
+/// let cells = treemap.layout((100.0, 100.0));
+/// assert_eq!(cells.len(), 4);
+/// assert_eq!(cells[0].upper_left, (0.0, 0.0));
+/// assert_eq!(cells[0].bottom_right, (60.0, 100.0));
+/// ```
+pub struct Treemap {
+    roots: Vec<TreemapNode>,
+    padding: f64,
+    font: FontDesc<'static>,
+}
+
+impl Treemap {
+    /// Create a new treemap from the top level nodes
+    pub fn new(roots: Vec<TreemapNode>) -> Self {
+        Self {
+            roots,
+            padding: 0.0,
+            font: ("sans-serif", 12).into(),
+        }
+    }
+
+    /// Set the padding between an inner node and its children, in pixels
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// Set the font used by the labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Compute the layout of all the nodes in a rectangle of the given size, parents are
+    /// listed before their children
+    /// - `size`: The size of the rectangle
+    /// - **returns** The list of laid out rectangles
+    pub fn layout(&self, size: (f64, f64)) -> Vec<TreemapCell> {
+        let mut cells = vec![];
+        let nodes: Vec<_> = self.roots.iter().enumerate().collect();
+        self.layout_nodes(&nodes, ((0.0, 0.0), size), 0, &mut cells);
+        cells
+    }
+
+    fn layout_nodes(
+        &self,
+        nodes: &[(usize, &TreemapNode)],
+        rect: ((f64, f64), (f64, f64)),
+        depth: usize,
+        cells: &mut Vec<TreemapCell>,
+    ) {
+        let mut nodes: Vec<_> = nodes.iter().filter(|(_, n)| n.weight() > 0.0).collect();
+        nodes.sort_by(|a, b| b.1.weight().partial_cmp(&a.1.weight()).unwrap());
+
+        let total: f64 = nodes.iter().map(|(_, n)| n.weight()).sum();
+        let ((x0, y0), (x1, y1)) = rect;
+        let area = (x1 - x0) * (y1 - y0);
+        if total <= 0.0 || area <= 0.0 {
+            return;
+        }
+        let scale = area / total;
+
+        let mut rect = rect;
+        let mut row: Vec<&(usize, &TreemapNode)> = vec![];
+        let mut idx = 0;
+        while idx < nodes.len() {
+            let ((x0, y0), (x1, y1)) = rect;
+            let side = (x1 - x0).min(y1 - y0);
+            let worst = |row: &[&(usize, &TreemapNode)]| {
+                let areas: Vec<_> = row.iter().map(|(_, n)| n.weight() * scale).collect();
+                let sum: f64 = areas.iter().sum();
+                let max = areas.iter().copied().fold(0.0, f64::max);
+                let min = areas.iter().copied().fold(f64::INFINITY, f64::min);
+                (side * side * max / (sum * sum)).max(sum * sum / (side * side * min))
+            };
+
+            let mut candidate = row.clone();
+            candidate.push(nodes[idx]);
+            if row.is_empty() || worst(&candidate) <= worst(&row) {
+                row = candidate;
+                idx += 1;
+                continue;
+            }
+
+            rect = self.layout_row(&row, rect, scale, depth, cells);
+            row.clear();
+        }
+        if !row.is_empty() {
+            self.layout_row(&row, rect, scale, depth, cells);
+        }
+    }
+
+    // Lay out a row along the shorter side of the rectangle and returns the remaining space
+    fn layout_row(
+        &self,
+        row: &[&(usize, &TreemapNode)],
+        rect: ((f64, f64), (f64, f64)),
+        scale: f64,
+        depth: usize,
+        cells: &mut Vec<TreemapCell>,
+    ) -> ((f64, f64), (f64, f64)) {
+        let ((x0, y0), (x1, y1)) = rect;
+        let sum: f64 = row.iter().map(|(_, n)| n.weight() * scale).sum();
+        let horizontal = (x1 - x0) >= (y1 - y0);
+
+        let thickness = if horizontal {
+            sum / (y1 - y0)
+        } else {
+            sum / (x1 - x0)
+        };
+
+        let mut offset = if horizontal { y0 } else { x0 };
+        for (branch, node) in row.iter() {
+            let length = node.weight() * scale / thickness;
+            let (upper_left, bottom_right) = if horizontal {
+                ((x0, offset), (x0 + thickness, offset + length))
+            } else {
+                ((offset, y0), (offset + length, y0 + thickness))
+            };
+            offset += length;
+
+            cells.push(TreemapCell {
+                label: node.label.clone(),
+                depth,
+                branch: *branch,
+                is_leaf: node.children.is_empty(),
+                upper_left,
+                bottom_right,
+            });
+
+            if !node.children.is_empty() {
+                // The padding of a small node is limited so the inner rectangle isn't inverted
+                let p = self
+                    .padding
+                    .min((bottom_right.0 - upper_left.0) / 2.0)
+                    .min((bottom_right.1 - upper_left.1) / 2.0);
+                let inner = (
+                    (upper_left.0 + p, upper_left.1 + p),
+                    (bottom_right.0 - p, bottom_right.1 - p),
+                );
+                let children: Vec<_> = node.children.iter().map(|c| (*branch, c)).collect();
+                self.layout_nodes(&children, inner, depth + 1, cells);
+            }
+        }
+
+        if horizontal {
+            ((x0 + thickness, y0), (x1, y1))
+        } else {
+            ((x0, y0 + thickness), (x1, y1))
+        }
+    }
+
+    /// Draw the treemap on the drawing area. The nodes are colored by the top level node they
+    /// belong to, and the label of a leaf node is drawn only if it fits in the rectangle.
+    /// - `area`: The drawing area to draw on
+    /// - **returns** The result of the drawing
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (w, h) = area.dim_in_pixel();
+        let text_style = TextStyle::from(self.font.clone()).color(&BLACK);
+        for cell in self.layout((f64::from(w), f64::from(h))) {
+            let ul = (
+                cell.upper_left.0.round() as i32,
+                cell.upper_left.1.round() as i32,
+            );
+            let br = (
+                cell.bottom_right.0.round() as i32,
+                cell.bottom_right.1.round() as i32,
+            );
+            let color: RGBAColor = Palette99::pick(cell.branch)
+                .mix(1.0 - 0.15 * cell.depth.min(4) as f64)
+                .to_rgba();
+            area.draw(&Rectangle::new([ul, br], color.filled()))?;
+            area.draw(&Rectangle::new([ul, br], &WHITE))?;
+
+            if cell.is_leaf {
+                if let Ok((tw, th)) = area.estimate_text_size(&cell.label, &self.font) {
+                    if tw as i32 + 4 <= br.0 - ul.0 && th as i32 + 4 <= br.1 - ul.1 {
+                        area.draw_text(&cell.label, &text_style, (ul.0 + 2, ul.1 + 2))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_treemap_layout() {
+        let treemap = Treemap::new(vec![
+            TreemapNode::leaf("a", 6.0),
+            TreemapNode::leaf("b", 6.0),
+            TreemapNode::leaf("c", 4.0),
+            TreemapNode::leaf("d", 3.0),
+            TreemapNode::leaf("e", 2.0),
+            TreemapNode::leaf("f", 2.0),
+            TreemapNode::leaf("g", 1.0),
+            TreemapNode::leaf("zero", 0.0),
+        ]);
+        let cells = treemap.layout((600.0, 400.0));
+        assert_eq!(cells.len(), 7);
+
+        let area: f64 = cells
+            .iter()
+            .map(|c| (c.bottom_right.0 - c.upper_left.0) * (c.bottom_right.1 - c.upper_left.1))
+            .sum();
+        assert!((area - 240000.0).abs() < 1e-6);
+
+        // This is the example from the squarified treemap paper, the first row contains a and b
+        assert_eq!(cells[0].bottom_right.0, cells[1].bottom_right.0);
+        assert!((cells[0].bottom_right.0 - 300.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_padding_larger_than_cell() {
+        let treemap = Treemap::new(vec![
+            TreemapNode::node("a", vec![TreemapNode::leaf("a1", 1.0)]),
+            TreemapNode::node("b", vec![TreemapNode::leaf("b1", 1.0)]),
+        ])
+        .padding(60.0);
+        let cells = treemap.layout((100.0, 100.0));
+        // The nodes are too small for their children, which aren't laid out
+        assert_eq!(cells.len(), 2);
+        for cell in cells {
+            assert!(cell.upper_left.0 <= cell.bottom_right.0);
+            assert!(cell.upper_left.1 <= cell.bottom_right.1);
+        }
+    }
+
+    #[test]
+    fn test_treemap_draw() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 10);
+            });
+        });
+        Treemap::new(vec![
+            TreemapNode::node("x", vec![TreemapNode::leaf("x1", 1.0)]),
+            TreemapNode::node(
+                "y",
+                vec![TreemapNode::leaf("y1", 1.0), TreemapNode::leaf("y2", 1.0)],
+            ),
+        ])
+        .padding(2.0)
+        .draw(&drawing_area)
+        .expect("Drawing Error");
+    }
+}
@@ -1,8 +1,10 @@
-use std::fmt;
-use std::ops::Range;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use core::fmt;
+use core::ops::Range;
 
-use super::{AsRangedCoord, DiscreteRanged, Ranged};
+use super::{map_ratio, AsRangedCoord, DiscreteRanged, Ranged};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 /// The category coordinate
 pub struct Category<T: PartialEq> {
@@ -22,16 +24,16 @@ impl<T: PartialEq> Clone for Category<T> {
     }
 }
 
-impl<T: PartialEq> std::cmp::PartialEq for Category<T> {
+impl<T: PartialEq> core::cmp::PartialEq for Category<T> {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name && self.elements == other.elements && self.idx == other.idx
     }
 }
 
-impl<T: std::hash::Hash + Eq> std::hash::Hash for Category<T> {
+impl<T: core::hash::Hash + Eq> core::hash::Hash for Category<T> {
     fn hash<H>(&self, state: &mut H)
     where
-        H: std::hash::Hasher,
+        H: core::hash::Hasher,
     {
         self.name.hash(state);
         self.idx.hash(state);
@@ -39,7 +41,7 @@ impl<T: std::hash::Hash + Eq> std::hash::Hash for Category<T> {
     }
 }
 
-impl<T: Eq> std::cmp::Eq for Category<T> {}
+impl<T: Eq> core::cmp::Eq for Category<T> {}
 
 impl<T: PartialEq + fmt::Display> fmt::Debug for Category<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -175,7 +177,7 @@ impl<T: PartialEq> Ranged for Category<T> {
         // Add margins to spans as edge values are not applicable to category
         let total_span = (self.len() + 1) as f64;
         let value_span = f64::from(value.idx + 1);
-        (f64::from(limit.1 - limit.0) * value_span / total_span) as i32 + limit.0
+        map_ratio(value_span / total_span, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
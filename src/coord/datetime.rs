@@ -1,8 +1,11 @@
 /// The datetime coordinates
-use chrono::{Date, DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike};
-use std::ops::Range;
+use chrono::{
+    Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+};
+use core::ops::Range;
 
-use super::{AsRangedCoord, DiscreteRanged, Ranged};
+use super::period::{compute_period_per_point, format_tick, CalendarFields, TimeUnit, NS_PER_DAY};
+use super::{map_ratio, AsRangedCoord, DiscreteRanged, Ranged};
 
 /// The trait that describe some time value
 pub trait TimeValue: Eq {
@@ -26,8 +29,7 @@ pub trait TimeValue: Eq {
         // First, lets try the nanoseconds precision
         if let Some(total_ns) = total_span.num_nanoseconds() {
             if let Some(value_ns) = value_span.num_nanoseconds() {
-                return (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64) as i32
-                    + limit.0;
+                return map_ratio(value_ns as f64 / total_ns as f64, limit);
             }
         }
 
@@ -36,7 +38,7 @@ pub trait TimeValue: Eq {
         let total_days = total_span.num_days() as f64;
         let value_days = value_span.num_days() as f64;
 
-        (f64::from(limit.1 - limit.0) * value_days / total_days) as i32 + limit.0
+        map_ratio(value_days / total_days, limit)
     }
 }
 
@@ -83,13 +85,46 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
     }
 }
 
-/// The ranged coordinate for date
+// The calendar fields of a date, or of the date of a time
+fn date_fields<D: Datelike>(date: &D) -> CalendarFields {
+    CalendarFields {
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+    }
+}
+
+fn date_time_fields<D: Datelike + Timelike>(time: &D) -> CalendarFields {
+    CalendarFields {
+        hour: time.hour(),
+        minute: time.minute(),
+        second: time.second(),
+        millisecond: time.nanosecond() / 1_000_000,
+        ..date_fields(time)
+    }
+}
+
+/// The ranged coordinate for date. The labels are formatted like `Mar 3`, with the year if
+/// the range spans multiple years.
 #[derive(Clone)]
-pub struct RangedDate<Z: TimeZone>(Date<Z>, Date<Z>);
+pub struct RangedDate<Z: TimeZone>(Date<Z>, Date<Z>, bool);
 
 impl<Z: TimeZone> From<Range<Date<Z>>> for RangedDate<Z> {
     fn from(range: Range<Date<Z>>) -> Self {
-        Self(range.start, range.end)
+        Self(range.start, range.end, false)
+    }
+}
+
+impl<Z: TimeZone> RangedDate<Z> {
+    /// Show the labels in two levels, the days on the first line, and the months on the
+    /// second line, which is only shown on the first tick and the first tick of each month
+    pub fn two_level(mut self) -> Self {
+        self.2 = true;
+        self
     }
 }
 
@@ -132,6 +167,17 @@ impl<Z: TimeZone> Ranged for RangedDate<Z> {
 
         ret
     }
+
+    fn format_key_point(&self, value: &Date<Z>, max_points: usize) -> Option<String> {
+        Some(format_tick(
+            value,
+            &self.key_points(max_points),
+            TimeUnit::Day,
+            self.0.year() != self.1.year(),
+            self.2,
+            date_fields,
+        ))
+    }
 }
 
 impl<Z: TimeZone> DiscreteRanged for RangedDate<Z> {
@@ -156,8 +202,18 @@ impl<Z: TimeZone> AsRangedCoord for Range<Date<Z>> {
 /// Note: since month doesn't have a constant duration.
 /// We can't use a simple granularity to describe it. Thus we have
 /// this axis decorator to make it yield monthly key-points.
+/// The labels are formatted like `Mar 2021`, or `2021` for the yearly key points.
 #[derive(Clone)]
-pub struct Monthly<T: TimeValue>(Range<T>);
+pub struct Monthly<T: TimeValue>(Range<T>, bool);
+
+impl<T: TimeValue> Monthly<T> {
+    /// Show the labels in two levels, the months on the first line, and the years on the
+    /// second line, which is only shown on the first tick and the first tick of each year
+    pub fn two_level(mut self) -> Self {
+        self.1 = true;
+        self
+    }
+}
 
 impl<T: TimeValue + Clone> AsRangedCoord for Monthly<T> {
     type CoordDescType = Monthly<T>;
@@ -264,6 +320,23 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
             self.0.start.timezone(),
         )
     }
+
+    fn format_key_point(&self, value: &T, max_points: usize) -> Option<String> {
+        let ticks = self.key_points(max_points);
+        let unit = match &ticks[..] {
+            [first, second, ..] => {
+                let (first, second) = (first.date_floor(), second.date_floor());
+                TimeUnit::of_month_spacing(
+                    (second.year() - first.year()) * 12 + second.month() as i32
+                        - first.month() as i32,
+                )
+            }
+            _ => TimeUnit::Month,
+        };
+        Some(format_tick(value, &ticks, unit, true, self.1, |v| {
+            date_fields(&v.date_floor())
+        }))
+    }
 }
 
 impl<T: TimeValue + Clone> DiscreteRanged for Monthly<T> {
@@ -293,6 +366,7 @@ impl<T: TimeValue + Clone> DiscreteRanged for Monthly<T> {
 }
 
 /// Indicate the coord has a yearly granularity.
+/// The labels are the years.
 #[derive(Clone)]
 pub struct Yearly<T: TimeValue>(Range<T>);
 
@@ -377,6 +451,10 @@ impl<T: TimeValue + Clone> Ranged for Yearly<T> {
             self.0.start.timezone(),
         )
     }
+
+    fn format_key_point(&self, value: &T, _max_points: usize) -> Option<String> {
+        Some(format!("{}", value.date_floor().year()))
+    }
 }
 
 impl<T: TimeValue + Clone> DiscreteRanged for Yearly<T> {
@@ -403,7 +481,7 @@ pub trait IntoYearly<T: TimeValue> {
 
 impl<T: TimeValue> IntoMonthly<T> for Range<T> {
     fn monthly(self) -> Monthly<T> {
-        Monthly(self)
+        Monthly(self, false)
     }
 }
 
@@ -413,9 +491,10 @@ impl<T: TimeValue> IntoYearly<T> for Range<T> {
     }
 }
 
-/// The ranged coordinate for the date and time
+/// The ranged coordinate for the date and time. The labels are formatted by the spacing of
+/// the key points, like `14:05` for the minutes and the hours, and `Mar 3` for the days.
 #[derive(Clone)]
-pub struct RangedDateTime<Z: TimeZone>(DateTime<Z>, DateTime<Z>);
+pub struct RangedDateTime<Z: TimeZone>(DateTime<Z>, DateTime<Z>, bool);
 
 impl<Z: TimeZone> AsRangedCoord for Range<DateTime<Z>> {
     type CoordDescType = RangedDateTime<Z>;
@@ -424,7 +503,47 @@ impl<Z: TimeZone> AsRangedCoord for Range<DateTime<Z>> {
 
 impl<Z: TimeZone> From<Range<DateTime<Z>>> for RangedDateTime<Z> {
     fn from(range: Range<DateTime<Z>>) -> Self {
-        Self(range.start, range.end)
+        Self(range.start, range.end, false)
+    }
+}
+
+impl<Z: TimeZone> RangedDateTime<Z> {
+    /// Show the labels in two levels, the times on the first line, and the dates on the
+    /// second line, which is only shown on the first tick and the first tick of each day. If
+    /// the key points are days, they are shown on top of the months.
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (600, 200)).into_drawing_area();
+    /// let (start, end) = (Utc.ymd(2021, 3, 3).and_hms(0, 0, 0), Utc.ymd(2021, 3, 5).and_hms(0, 0, 0));
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(40)
+    ///     .build_ranged(RangedDateTime::from(start..end).two_level(), 0.0..1.0)
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    /// ```
+    pub fn two_level(mut self) -> Self {
+        self.2 = true;
+        self
+    }
+
+    /// Show the date and time in another timezone, see `RangedDateTimeIn`
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use plotters::prelude::*;
+    ///
+    /// let (start, end) = (Utc.ymd(2021, 3, 3).and_hms(0, 0, 0), Utc.ymd(2021, 3, 5).and_hms(0, 0, 0));
+    /// // The ticks are at the midnight of UTC+8
+    /// let coord = RangedDateTime::from(start..end).in_timezone(FixedOffset::east(8 * 3600));
+    /// assert_eq!(coord.key_points(3)[0], Utc.ymd(2021, 3, 3).and_hms(16, 0, 0));
+    /// assert_eq!(coord.format_key_point(&coord.key_points(3)[0], 3).unwrap(), "Mar 4");
+    /// ```
+    pub fn in_timezone<D: TimeZone>(self, tz: D) -> RangedDateTimeIn<Z, D> {
+        RangedDateTimeIn(self.0, self.1, self.2, tz)
     }
 }
 
@@ -440,50 +559,150 @@ impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let total_span = self.1.clone() - self.0.clone();
+        date_time_key_points(&self.0, &self.1, &self.0.timezone(), max_points)
+    }
 
-        if let Some(total_ns) = total_span.num_nanoseconds() {
-            if let Some(actual_ns_per_point) =
-                compute_period_per_point(total_ns as u64, max_points, true)
-            {
-                let start_time_ns = u64::from(self.0.time().num_seconds_from_midnight())
-                    * 1_000_000_000
-                    + u64::from(self.0.time().nanosecond());
-
-                let mut start_time = self
-                    .0
-                    .date_floor()
-                    .and_time(
-                        NaiveTime::from_hms(0, 0, 0)
-                            + Duration::nanoseconds(if start_time_ns % actual_ns_per_point > 0 {
-                                start_time_ns
-                                    + (actual_ns_per_point - start_time_ns % actual_ns_per_point)
-                            } else {
-                                start_time_ns
-                            } as i64),
-                    )
-                    .unwrap();
+    fn format_key_point(&self, value: &DateTime<Z>, max_points: usize) -> Option<String> {
+        let ticks = self.key_points(max_points);
+        Some(format_date_time(
+            value,
+            &ticks,
+            &self.range(),
+            &self.0.timezone(),
+            self.2,
+        ))
+    }
+}
 
-                let mut ret = vec![];
+/// The ranged coordinate for the date and time which are shown in another timezone, such as
+/// the timestamps stored in UTC which are shown in the local time. The key points are aligned
+/// in the display timezone, so the ticks are at the local midnight and the same wall clock
+/// times every day, even across the daylight saving time changes. It's created by
+/// `RangedDateTime::in_timezone`, and any chrono timezone can be used, such as `chrono::Local`,
+/// `FixedOffset` and the `Tz` of the chrono-tz crate.
+#[derive(Clone)]
+pub struct RangedDateTimeIn<Z: TimeZone, D: TimeZone>(DateTime<Z>, DateTime<Z>, bool, D);
 
-                while start_time < self.1 {
-                    ret.push(start_time.clone());
-                    start_time = start_time + Duration::nanoseconds(actual_ns_per_point as i64);
-                }
+impl<Z: TimeZone, D: TimeZone> AsRangedCoord for RangedDateTimeIn<Z, D> {
+    type CoordDescType = Self;
+    type Value = DateTime<Z>;
+}
 
-                return ret;
+impl<Z: TimeZone, D: TimeZone> RangedDateTimeIn<Z, D> {
+    /// Show the labels in two levels, see `RangedDateTime::two_level`
+    pub fn two_level(mut self) -> Self {
+        self.2 = true;
+        self
+    }
+}
+
+impl<Z: TimeZone, D: TimeZone> Ranged for RangedDateTimeIn<Z, D> {
+    type ValueType = DateTime<Z>;
+
+    fn range(&self) -> Range<DateTime<Z>> {
+        self.0.clone()..self.1.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        TimeValue::map_coord(value, &self.0, &self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        date_time_key_points(&self.0, &self.1, &self.3, max_points)
+    }
+
+    fn format_key_point(&self, value: &DateTime<Z>, max_points: usize) -> Option<String> {
+        let ticks = self.key_points(max_points);
+        Some(format_date_time(
+            value,
+            &ticks,
+            &self.range(),
+            &self.3,
+            self.2,
+        ))
+    }
+}
+
+fn local_midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+}
+
+// The key points of the time range which are aligned in the display timezone. The wall clock
+// times which are skipped by the daylight saving time changes are dropped, and the repeated
+// ones are only used once.
+fn date_time_key_points<Z: TimeZone, D: TimeZone>(
+    start: &DateTime<Z>,
+    end: &DateTime<Z>,
+    tz: &D,
+    max_points: usize,
+) -> Vec<DateTime<Z>> {
+    let to_value = |local: NaiveDateTime| {
+        tz.from_local_datetime(&local)
+            .earliest()
+            .map(|time| time.with_timezone(&start.timezone()))
+    };
+    let total_span = end.clone() - start.clone();
+
+    if let Some(total_ns) = total_span.num_nanoseconds() {
+        if let Some(ns_per_point) = compute_period_per_point(total_ns as u64, max_points, true) {
+            let local_start = start.with_timezone(tz).naive_local();
+            let mut date = local_start.date();
+            let start_ns = (local_start - local_midnight(date))
+                .num_nanoseconds()
+                .unwrap_or(0) as u64;
+            // The period always divides a day, so the ticks are at the same times every day
+            let mut offset = start_ns.div_ceil(ns_per_point) * ns_per_point;
+            let mut ret = vec![];
+            loop {
+                if offset >= NS_PER_DAY {
+                    date += Duration::days(1);
+                    offset = 0;
+                }
+                let local = local_midnight(date) + Duration::nanoseconds(offset as i64);
+                offset += ns_per_point;
+                match to_value(local) {
+                    Some(value) if value >= *end => break,
+                    Some(value) if value >= *start => ret.push(value),
+                    _ => {}
+                }
             }
+            return ret;
         }
+    }
 
-        // Otherwise, it actually behaves like a date
-        let date_range = RangedDate(self.0.date_ceil(), self.1.date_floor());
+    // Otherwise, it actually behaves like a date
+    let date_range = RangedDate(
+        start.with_timezone(tz).date_ceil(),
+        end.with_timezone(tz).date_floor(),
+        false,
+    );
+
+    date_range
+        .key_points(max_points)
+        .into_iter()
+        .filter_map(|date| to_value(local_midnight(date.naive_local())))
+        .collect()
+}
 
-        date_range
-            .key_points(max_points)
-            .into_iter()
-            .map(|x| x.and_hms(0, 0, 0))
-            .collect()
-    }
+// Format the label of a tick of the date and time in the display timezone
+fn format_date_time<Z: TimeZone, D: TimeZone>(
+    value: &DateTime<Z>,
+    ticks: &[DateTime<Z>],
+    range: &Range<DateTime<Z>>,
+    tz: &D,
+    two_level: bool,
+) -> String {
+    let spacing = match ticks {
+        [first, second, ..] => second.clone() - first.clone(),
+        _ => range.end.clone() - range.start.clone(),
+    };
+    let unit = spacing
+        .num_nanoseconds()
+        .map_or(TimeUnit::Day, |ns| TimeUnit::of_spacing(ns.max(0) as u64));
+    let with_year = range.start.with_timezone(tz).year() != range.end.with_timezone(tz).year();
+    format_tick(value, ticks, unit, with_year, two_level, |time| {
+        date_time_fields(&time.with_timezone(tz))
+    })
 }
 
 /// The coordinate that for duration of time
@@ -514,9 +733,7 @@ impl Ranged for RangedDuration {
 
         if let Some(total_ns) = total_span.num_nanoseconds() {
             if let Some(value_ns) = value_span.num_nanoseconds() {
-                return limit.0
-                    + (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64 + 1e-10)
-                        as i32;
+                return map_ratio(value_ns as f64 / total_ns as f64, limit);
             }
             return limit.1;
         }
@@ -524,8 +741,7 @@ impl Ranged for RangedDuration {
         let total_days = total_span.num_days();
         let value_days = value_span.num_days();
 
-        limit.0
-            + (f64::from(limit.1 - limit.0) * value_days as f64 / total_days as f64 + 1e-10) as i32
+        map_ratio(value_days as f64 / total_days as f64, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
@@ -594,76 +810,6 @@ impl Ranged for RangedDuration {
     }
 }
 
-#[allow(clippy::inconsistent_digit_grouping)]
-fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
-    let min_ns_per_point = total_ns as f64 / max_points as f64;
-    let actual_ns_per_point: u64 = (10u64).pow((min_ns_per_point as f64).log10().floor() as u32);
-
-    fn determine_actual_ns_per_point(
-        total_ns: u64,
-        mut actual_ns_per_point: u64,
-        units: &[u64],
-        base: u64,
-        max_points: usize,
-    ) -> u64 {
-        let mut unit_per_point_idx = 0;
-        while total_ns / actual_ns_per_point > max_points as u64 * units[unit_per_point_idx] {
-            unit_per_point_idx += 1;
-            if unit_per_point_idx == units.len() {
-                unit_per_point_idx = 0;
-                actual_ns_per_point *= base;
-            }
-        }
-        units[unit_per_point_idx] * actual_ns_per_point
-    }
-
-    if actual_ns_per_point < 1_000_000_000 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            actual_ns_per_point,
-            &[1, 2, 5],
-            10,
-            max_points,
-        ))
-    } else if actual_ns_per_point < 3600_000_000_000 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            1_000_000_000,
-            &[1, 2, 5, 10, 15, 20, 30],
-            60,
-            max_points,
-        ))
-    } else if actual_ns_per_point < 3600_000_000_000 * 24 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            3600_000_000_000,
-            &[1, 2, 4, 8, 12],
-            24,
-            max_points,
-        ))
-    } else if !sub_daily {
-        if actual_ns_per_point < 3600_000_000_000 * 24 * 10 {
-            Some(determine_actual_ns_per_point(
-                total_ns as u64,
-                3600_000_000_000 * 24,
-                &[1, 2, 5, 7],
-                10,
-                max_points,
-            ))
-        } else {
-            Some(determine_actual_ns_per_point(
-                total_ns as u64,
-                3600_000_000_000 * 24 * 10,
-                &[1, 2, 5],
-                10,
-                max_points,
-            ))
-        }
-    } else {
-        None
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -951,4 +1097,167 @@ mod test {
         assert!(max == min);
         assert_eq!(max, 3600 * 2);
     }
+
+    #[test]
+    fn test_label_format_by_span() {
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2021, 3, 3).and_hms(0, 0, 0)..Utc.ymd(2021, 3, 4).and_hms(0, 0, 0)).into();
+        let ticks = coord.key_points(12);
+        assert_eq!(coord.format_key_point(&ticks[1], 12).unwrap(), "02:00");
+
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2021, 3, 3).and_hms(14, 0, 0)..Utc.ymd(2021, 3, 3).and_hms(14, 1, 0)).into();
+        let ticks = coord.key_points(6);
+        assert_eq!(coord.format_key_point(&ticks[1], 6).unwrap(), "14:00:10");
+
+        let coord: RangedDate<_> = (Utc.ymd(2021, 3, 1)..Utc.ymd(2021, 3, 10)).into();
+        assert_eq!(
+            coord.format_key_point(&Utc.ymd(2021, 3, 3), 20).unwrap(),
+            "Mar 3"
+        );
+        let coord: RangedDate<_> = (Utc.ymd(2020, 12, 1)..Utc.ymd(2021, 2, 1)).into();
+        let ticks = coord.key_points(20);
+        assert_eq!(
+            coord.format_key_point(&ticks[0], 20).unwrap(),
+            "Dec 1, 2020"
+        );
+
+        let coord = (Utc.ymd(2021, 1, 1)..Utc.ymd(2021, 12, 31)).monthly();
+        let ticks = coord.key_points(12);
+        assert_eq!(coord.format_key_point(&ticks[2], 12).unwrap(), "Mar 2021");
+        let coord = (Utc.ymd(1990, 1, 1)..Utc.ymd(2021, 1, 1)).monthly();
+        let ticks = coord.key_points(10);
+        assert_eq!(coord.format_key_point(&ticks[0], 10).unwrap(), "1990");
+        let coord = (Utc.ymd(1990, 1, 1)..Utc.ymd(2021, 1, 1)).yearly();
+        assert_eq!(
+            coord.format_key_point(&Utc.ymd(2000, 1, 1), 10).unwrap(),
+            "2000"
+        );
+    }
+
+    #[test]
+    fn test_two_level_labels() {
+        let coord = RangedDateTime::from(
+            Utc.ymd(2021, 3, 3).and_hms(12, 0, 0)..Utc.ymd(2021, 3, 5).and_hms(0, 0, 0),
+        )
+        .two_level();
+        let ticks = coord.key_points(6);
+        let labels: Vec<_> = ticks
+            .iter()
+            .map(|t| coord.format_key_point(t, 6).unwrap())
+            .collect();
+        assert_eq!(labels, ["16:00\nMar 3", "00:00\nMar 4", "08:00", "16:00"]);
+
+        let coord = RangedDate::from(Utc.ymd(2021, 3, 29)..Utc.ymd(2021, 4, 2)).two_level();
+        let ticks = coord.key_points(10);
+        let labels: Vec<_> = ticks
+            .iter()
+            .map(|t| coord.format_key_point(t, 10).unwrap())
+            .collect();
+        assert_eq!(labels, ["29\nMar 2021", "30", "31", "1\nApr 2021", "2"]);
+
+        let coord = (Utc.ymd(2020, 11, 1)..Utc.ymd(2021, 2, 1))
+            .monthly()
+            .two_level();
+        let ticks = coord.key_points(10);
+        let labels: Vec<_> = ticks
+            .iter()
+            .map(|t| coord.format_key_point(t, 10).unwrap())
+            .collect();
+        assert_eq!(labels, ["Nov\n2020", "Dec", "Jan\n2021", "Feb"]);
+    }
+
+    // The central European time, which switches to the summer time at 01:00 UTC on
+    // 2021-03-28, the clocks go from 02:00 to 03:00
+    #[derive(Clone, Copy, Debug)]
+    struct Cet;
+
+    impl TimeZone for Cet {
+        type Offset = chrono::FixedOffset;
+
+        fn from_offset(_: &chrono::FixedOffset) -> Self {
+            Cet
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> chrono::LocalResult<Self::Offset> {
+            self.offset_from_local_datetime(&local_midnight(*local))
+        }
+
+        fn offset_from_local_datetime(
+            &self,
+            local: &NaiveDateTime,
+        ) -> chrono::LocalResult<Self::Offset> {
+            let valid: Vec<_> = [1, 2]
+                .iter()
+                .map(|hours| chrono::FixedOffset::east_opt(hours * 3600).unwrap())
+                .filter(|offset| self.offset_from_utc_datetime(&(*local - *offset)) == *offset)
+                .collect();
+            match valid[..] {
+                [offset] => chrono::LocalResult::Single(offset),
+                [first, second] => chrono::LocalResult::Ambiguous(first, second),
+                _ => chrono::LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+            self.offset_from_utc_datetime(&local_midnight(*utc))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+            let switch =
+                local_midnight(NaiveDate::from_ymd_opt(2021, 3, 28).unwrap()) + Duration::hours(1);
+            let hours = if *utc < switch { 1 } else { 2 };
+            chrono::FixedOffset::east_opt(hours * 3600).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_date_time_in_timezone() {
+        let range = Utc.ymd(2021, 3, 26).and_hms(23, 0, 0)..Utc.ymd(2021, 3, 29).and_hms(22, 0, 0);
+
+        // The days start at the local midnight, which moves by an hour in UTC
+        let coord = RangedDateTime::from(range.clone()).in_timezone(Cet);
+        let ticks = coord.key_points(5);
+        assert_eq!(
+            ticks,
+            [
+                Utc.ymd(2021, 3, 26).and_hms(23, 0, 0),
+                Utc.ymd(2021, 3, 27).and_hms(23, 0, 0),
+                Utc.ymd(2021, 3, 28).and_hms(22, 0, 0),
+            ]
+        );
+        let labels: Vec<_> = ticks
+            .iter()
+            .map(|t| coord.format_key_point(t, 5).unwrap())
+            .collect();
+        assert_eq!(labels, ["Mar 27", "Mar 28", "Mar 29"]);
+
+        // The ticks stay at the same wall clock times across the change
+        let ticks = coord.key_points(12);
+        let labels: Vec<_> = ticks
+            .iter()
+            .map(|t| coord.format_key_point(t, 12).unwrap())
+            .collect();
+        assert_eq!(labels.len(), 9);
+        assert!(labels
+            .iter()
+            .zip(["00:00", "08:00", "16:00"].iter().cycle())
+            .all(|(label, expected)| label == expected));
+        assert_eq!(ticks[3], Utc.ymd(2021, 3, 27).and_hms(23, 0, 0));
+        assert_eq!(ticks[4], Utc.ymd(2021, 3, 28).and_hms(6, 0, 0));
+        assert_eq!(ticks[5], Utc.ymd(2021, 3, 28).and_hms(14, 0, 0));
+
+        // Without the timezone, the ticks are aligned in UTC
+        let coord = RangedDateTime::from(range);
+        assert_eq!(
+            coord
+                .format_key_point(&coord.key_points(12)[0], 12)
+                .unwrap(),
+            "00:00"
+        );
+        assert_eq!(
+            coord.key_points(12)[0],
+            Utc.ymd(2021, 3, 27).and_hms(0, 0, 0)
+        );
+    }
 }
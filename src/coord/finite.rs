@@ -0,0 +1,85 @@
+/// The coordinate values which may be missing, such as the NaN values of the floats
+use super::Category;
+
+/// The trait of the coordinate values which can be checked for the NaN and infinite numbers,
+/// the line series is broken at the points which aren't finite. The values other than the
+/// floats are always finite, so a custom coordinate type can implement it with
+/// `impl FiniteValue for MyType {}`.
+pub trait FiniteValue {
+    /// Returns whether the value is a finite number, rather than a NaN or infinite value
+    fn is_finite_value(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_finite_float {
+    ($($t:ty),*) => {
+        $(impl FiniteValue for $t {
+            fn is_finite_value(&self) -> bool {
+                self.is_finite()
+            }
+        })*
+    };
+}
+impl_finite_float!(f32, f64);
+
+macro_rules! impl_finite_always {
+    ($($t:ty),*) => {
+        $(impl FiniteValue for $t {})*
+    };
+}
+impl_finite_always!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: FiniteValue + ?Sized> FiniteValue for &T {
+    fn is_finite_value(&self) -> bool {
+        (**self).is_finite_value()
+    }
+}
+
+impl<T: PartialEq> FiniteValue for Category<T> {}
+
+impl<A: FiniteValue, B: FiniteValue> FiniteValue for (A, B) {
+    fn is_finite_value(&self) -> bool {
+        self.0.is_finite_value() && self.1.is_finite_value()
+    }
+}
+
+impl<A: FiniteValue, B: FiniteValue, C: FiniteValue> FiniteValue for (A, B, C) {
+    fn is_finite_value(&self) -> bool {
+        self.0.is_finite_value() && self.1.is_finite_value() && self.2.is_finite_value()
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_values {
+    use super::FiniteValue;
+    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+
+    impl<Z: TimeZone> FiniteValue for Date<Z> {}
+    impl<Z: TimeZone> FiniteValue for DateTime<Z> {}
+    impl FiniteValue for NaiveDate {}
+    impl FiniteValue for NaiveDateTime {}
+    impl FiniteValue for Duration {}
+}
+
+#[cfg(feature = "time")]
+mod time_values {
+    use super::FiniteValue;
+
+    impl FiniteValue for time::Date {}
+    impl FiniteValue for time::OffsetDateTime {}
+    impl FiniteValue for time::PrimitiveDateTime {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_finite_value() {
+        assert!((1.0, 2).is_finite_value());
+        assert!(!(f64::NAN, 2).is_finite_value());
+        assert!(!(0, 1, f32::INFINITY).is_finite_value());
+        assert!(!(&f64::NAN, 2).is_finite_value());
+    }
+}
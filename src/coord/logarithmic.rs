@@ -1,6 +1,8 @@
 use super::{AsRangedCoord, Ranged, RangedCoordf64};
-use std::marker::PhantomData;
-use std::ops::Range;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::marker::PhantomData;
+use core::ops::Range;
 
 /// The trait for the type that is able to be presented in the log scale
 pub trait LogScalable: Clone {
@@ -56,11 +58,19 @@ impl<V: LogScalable + Clone> Clone for LogRange<V> {
     }
 }
 
+impl<V: LogScalable> LogRange<V> {
+    /// Set the style of the labels of the powers of ten, see `LogCoord::label_style`
+    pub fn label_style(self, style: LogLabelStyle) -> LogCoord<V> {
+        LogCoord::from(self).label_style(style)
+    }
+}
+
 impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
     fn from(range: LogRange<V>) -> LogCoord<V> {
         LogCoord {
             linear: (range.0.start.as_f64().ln()..range.0.end.as_f64().ln()).into(),
             logic: range.0,
+            label_style: LogLabelStyle::default(),
             marker: PhantomData,
         }
     }
@@ -71,13 +81,96 @@ impl<V: LogScalable> AsRangedCoord for LogRange<V> {
     type Value = V;
 }
 
-/// A log scaled coordinate axis
+/// The style of the labels of the powers of ten on a log axis
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LogLabelStyle {
+    /// The power with the superscript exponent, such as `10⁻³` and `10³`
+    #[default]
+    Superscript,
+    /// The scientific notation, such as `1e-3` and `1e3`
+    Scientific,
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl LogLabelStyle {
+    fn format_power(self, exp: i32) -> String {
+        match self {
+            LogLabelStyle::Superscript => {
+                let mut text = "10".to_string();
+                if exp < 0 {
+                    text.push('⁻');
+                }
+                for digit in exp.unsigned_abs().to_string().bytes() {
+                    text.push(SUPERSCRIPT_DIGITS[(digit - b'0') as usize]);
+                }
+                text
+            }
+            LogLabelStyle::Scientific => format!("1e{}", exp),
+        }
+    }
+}
+
+// The exponent of the value if it's a power of ten
+fn decade_of(value: f64) -> Option<i32> {
+    let exp = value.log10().round();
+    if value > 0.0 && ((value / 10f64.powf(exp)) - 1.0).abs() < 1e-9 {
+        Some(exp as i32)
+    } else {
+        None
+    }
+}
+
+/// A log scaled coordinate axis.
+///
+/// The powers of ten are labeled as `10³`, or in the style set with `label_style`, and the
+/// ticks at 2 to 9 times of them are left unlabeled. The ranges spanning less than a decade
+/// are labeled with the plain numbers.
 pub struct LogCoord<V: LogScalable> {
     linear: RangedCoordf64,
     logic: Range<V>,
+    label_style: LogLabelStyle,
     marker: PhantomData<V>,
 }
 
+impl<V: LogScalable> LogCoord<V> {
+    /// Set the style of the labels of the powers of ten
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let coord = LogRange(1e-3..1e3).label_style(LogLabelStyle::Scientific);
+    /// assert_eq!(coord.format_key_point(&1e-3, 10), Some("1e-3".to_string()));
+    /// assert_eq!(coord.format_key_point(&2e-3, 10), Some("".to_string()));
+    /// ```
+    pub fn label_style(mut self, style: LogLabelStyle) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    // The exponents of the powers of ten within the range
+    fn decades(&self) -> Range<i32> {
+        let (start, end) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+        let (low, high) = (start.min(end), start.max(end));
+        // Allow the rounding error of the bounds which are powers of ten
+        (low.log10() - 1e-9).ceil() as i32..(high.log10() + 1e-9).floor() as i32 + 1
+    }
+
+    // The ranges with less than two powers of ten are labeled as linear axes
+    fn is_narrow(&self) -> bool {
+        self.decades().len() < 2
+    }
+
+    fn linear_logic(&self) -> RangedCoordf64 {
+        (self.logic.start.as_f64()..self.logic.end.as_f64()).into()
+    }
+}
+
+impl<V: LogScalable> AsRangedCoord for LogCoord<V> {
+    type CoordDescType = Self;
+    type Value = V;
+}
+
 impl<V: LogScalable> Ranged for LogCoord<V> {
     type ValueType = V;
 
@@ -88,50 +181,77 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let tier_1 = (self.logic.end.as_f64() / self.logic.start.as_f64())
-            .log10()
-            .abs()
-            .floor()
-            .max(1.0) as usize;
-
-        let tier_2_density = if max_points < tier_1 {
-            0
-        } else {
-            let density = 1 + (max_points - tier_1) / tier_1;
-            let mut exp = 1;
-            while exp * 10 <= density {
-                exp *= 10;
+        if max_points == 0 {
+            return vec![];
+        }
+
+        if self.is_narrow() {
+            let mut ret: Vec<V> = vec![];
+            for v in self.linear_logic().key_points(max_points) {
+                let v = V::from_f64(v);
+                if ret.last().is_none_or(|last| last.as_f64() != v.as_f64()) {
+                    ret.push(v);
+                }
             }
-            exp - 1
+            return ret;
+        }
+
+        let decades = self.decades();
+        let step = decades.len().div_ceil(max_points).max(1);
+        let count = decades.len().div_ceil(step);
+        let (low, high) = {
+            let (start, end) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+            (start.min(end), start.max(end))
         };
 
-        let mut multiplier = 10.0;
-        let mut cnt = 1;
-        while max_points < tier_1 / cnt {
-            multiplier *= 10.0;
-            cnt += 1;
+        // Add the minor ticks within each decade when there's room for them
+        let mut minors: &[f64] = &[];
+        if step == 1 {
+            for candidate in [
+                &[2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0][..],
+                &[2.0, 5.0][..],
+            ] {
+                let extra = (decades.start - 1..decades.end)
+                    .flat_map(|exp| candidate.iter().map(move |m| m * 10f64.powi(exp)))
+                    .filter(|v| *v >= low && *v <= high)
+                    .count();
+                if count + extra <= max_points {
+                    minors = candidate;
+                    break;
+                }
+            }
         }
 
+        // The minor ticks may also be before the first power of ten
+        let first = decades.start - if minors.is_empty() { 0 } else { 1 };
         let mut ret = vec![];
-        let mut val = (10f64).powf(self.logic.start.as_f64().log10().ceil());
-
-        while val <= self.logic.end.as_f64() {
-            ret.push(V::from_f64(val));
-            for i in 1..=tier_2_density {
-                let v = val
-                    * (1.0
-                        + multiplier / f64::from(tier_2_density as u32 + 1) * f64::from(i as u32));
-                if v > self.logic.end.as_f64() {
-                    break;
+        for exp in (first..decades.end).step_by(step) {
+            let base = 10f64.powi(exp);
+            if exp >= decades.start {
+                ret.push(V::from_f64(base));
+            }
+            for m in minors {
+                let v = base * m;
+                if v >= low && v <= high {
+                    ret.push(V::from_f64(v));
                 }
-                ret.push(V::from_f64(v));
             }
-            val *= multiplier;
         }
 
         ret
     }
 
+    fn format_key_point(&self, value: &V, max_points: usize) -> Option<String> {
+        let v = value.as_f64();
+        if self.is_narrow() {
+            return self.linear_logic().format_key_point(&v, max_points);
+        }
+        Some(match decade_of(v) {
+            Some(exp) => self.label_style.format_power(exp),
+            None => String::new(),
+        })
+    }
+
     fn range(&self) -> Range<V> {
         self.logic.clone()
     }
@@ -145,4 +265,49 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_key_points() {
+        let coord: LogCoord<f64> = LogRange(1e-3..1e3).into();
+        assert_eq!(
+            coord.key_points(10),
+            vec![1e-3, 1e-2, 1e-1, 1e0, 1e1, 1e2, 1e3]
+        );
+        assert_eq!(coord.key_points(3), vec![1e-3, 1e0, 1e3]);
+
+        // The minor ticks are added when there's room for them
+        let coord: LogCoord<f64> = LogRange(1.0..100.0).into();
+        assert_eq!(coord.key_points(3).len(), 3);
+        assert_eq!(
+            coord.key_points(8),
+            vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+        );
+        let points = coord.key_points(100);
+        assert_eq!(points.len(), 19);
+        assert!(points.contains(&9.0) && points.contains(&90.0));
+
+        // The minor ticks before the first power of ten are kept within the range
+        let coord: LogCoord<u32> = LogRange(3..200).into();
+        assert_eq!(coord.key_points(20)[..3], [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_log_labels() {
+        let coord: LogCoord<f64> = LogRange(1e-3..1e3).into();
+        let labels: Vec<_> = coord
+            .key_points(10)
+            .iter()
+            .map(|v| coord.format_key_point(v, 10).unwrap())
+            .collect();
+        assert_eq!(labels, ["10⁻³", "10⁻²", "10⁻¹", "10⁰", "10¹", "10²", "10³"]);
+
+        let coord = LogRange(1.0..1e12).label_style(LogLabelStyle::Scientific);
+        assert_eq!(coord.format_key_point(&1e12, 10).unwrap(), "1e12");
+        assert_eq!(coord.format_key_point(&3e5, 10).unwrap(), "");
+
+        // The ranges within a decade are labeled with the plain numbers
+        let coord: LogCoord<f64> = LogRange(2.0..8.0).into();
+        assert!(coord.key_points(10).len() > 2);
+        assert_eq!(coord.format_key_point(&4.0, 10).unwrap(), "4");
+    }
 }
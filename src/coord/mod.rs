@@ -25,28 +25,38 @@ use crate::drawing::backend::BackendCoord;
 mod category;
 #[cfg(feature = "chrono")]
 mod datetime;
+mod finite;
 mod logarithmic;
 mod numeric;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod period;
 mod ranged;
+#[cfg(feature = "time")]
+mod time_crate;
 
 #[cfg(feature = "chrono")]
-pub use datetime::{IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration};
+pub use datetime::{
+    IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDateTimeIn, RangedDuration,
+};
+pub use finite::FiniteValue;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
     RangedCoordu128, RangedCoordu32, RangedCoordu64,
 };
 pub use ranged::{
-    AsRangedCoord, DiscreteRanged, IntoCentric, IntoPartialAxis, MeshLine, Ranged, RangedCoord,
-    ReversibleRanged,
+    map_ratio, AsRangedCoord, DiscreteRanged, IntoCentric, IntoPartialAxis, MeshCell, MeshLine,
+    PaddedRange, Ranged, RangedCoord, ReversibleRanged, MAX_PIXEL_COORD,
 };
+#[cfg(feature = "time")]
+pub use time_crate::{RangedOffsetDateTime, RangedTimeDate};
 
 pub use ranged::make_partial_axis;
 
-pub use logarithmic::{LogCoord, LogRange, LogScalable};
+pub use logarithmic::{LogCoord, LogLabelStyle, LogRange, LogScalable};
 
+use alloc::rc::Rc;
+use alloc::sync::Arc;
 pub use numeric::group_integer_by::{GroupBy, ToGroupByRange};
-use std::rc::Rc;
-use std::sync::Arc;
 
 pub use category::Category;
 
@@ -92,13 +102,19 @@ pub struct Shift(pub BackendCoord);
 impl CoordTranslate for Shift {
     type From = BackendCoord;
     fn translate(&self, from: &Self::From) -> BackendCoord {
-        (from.0 + (self.0).0, from.1 + (self.0).1)
+        (
+            from.0.saturating_add((self.0).0),
+            from.1.saturating_add((self.0).1),
+        )
     }
 }
 
 impl ReverseCoordTranslate for Shift {
     fn reverse_translate(&self, input: BackendCoord) -> Option<BackendCoord> {
-        Some((input.0 - (self.0).0, input.1 - (self.0).1))
+        Some((
+            input.0.saturating_sub((self.0).0),
+            input.1.saturating_sub((self.0).1),
+        ))
     }
 }
 
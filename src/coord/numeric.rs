@@ -1,6 +1,9 @@
-use std::ops::Range;
+use core::ops::Range;
 
-use super::{AsRangedCoord, DiscreteRanged, Ranged, ReversibleRanged};
+use super::{map_ratio, AsRangedCoord, DiscreteRanged, Ranged, ReversibleRanged};
+use crate::data::float::pretty_print_float_in_range;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 macro_rules! impl_discrete_trait {
     ($name:ident) => {
@@ -27,7 +30,7 @@ macro_rules! impl_ranged_type_trait {
 }
 
 macro_rules! make_numeric_coord {
-    ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
+    ($type:ty, $name:ident, $key_points:ident, $doc: expr $(, $format:ident)?) => {
         #[doc = $doc]
         #[derive(Clone)]
         pub struct $name($type, $type);
@@ -39,14 +42,9 @@ macro_rules! make_numeric_coord {
         impl Ranged for $name {
             type ValueType = $type;
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
-                let logic_length = (*v - self.0) as f64 / (self.1 - self.0) as f64;
-                let actual_length = limit.1 - limit.0;
-
-                if actual_length == 0 {
-                    return limit.1;
-                }
-
-                return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
+                // Subtract in f64, the difference may not fit in the value type
+                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
+                map_ratio(logic_length, limit)
             }
             fn key_points(&self, max_points: usize) -> Vec<$type> {
                 $key_points((self.0, self.1), max_points)
@@ -54,6 +52,11 @@ macro_rules! make_numeric_coord {
             fn range(&self) -> Range<$type> {
                 return self.0..self.1;
             }
+            $(
+            fn format_key_point(&self, v: &$type, max_points: usize) -> Option<String> {
+                Some($format(self, *v, max_points))
+            }
+            )?
         }
 
         impl ReversibleRanged for $name {
@@ -62,9 +65,9 @@ macro_rules! make_numeric_coord {
                     return None;
                 }
 
-                let logical_offset = (p - min) as f64 / (max - min) as f64;
+                let logical_offset = (f64::from(p) - f64::from(min)) / (f64::from(max) - f64::from(min));
 
-                return Some(((self.1 - self.0) as f64 * logical_offset + self.0 as f64) as $type);
+                return Some(((self.1 as f64 - self.0 as f64) * logical_offset + self.0 as f64) as $type);
             }
         }
     };
@@ -175,17 +178,33 @@ gen_key_points_comp!(integer, compute_u128_key_points, u128);
 gen_key_points_comp!(integer, compute_isize_key_points, isize);
 gen_key_points_comp!(integer, compute_usize_key_points, usize);
 
+// Format the key point of a float axis with the precision of the tick spacing
+fn format_float_key_point<R: Ranged>(coord: &R, value: R::ValueType, max_points: usize) -> String
+where
+    R::ValueType: Into<f64> + Copy,
+{
+    let range = coord.range();
+    let (start, end) = (range.start.into(), range.end.into());
+    let step = match &coord.key_points(max_points)[..] {
+        [a, b, ..] => (*b).into() - (*a).into(),
+        _ => (end - start).abs(),
+    };
+    pretty_print_float_in_range(value.into(), start..end, step)
+}
+
 make_numeric_coord!(
     f32,
     RangedCoordf32,
     compute_f32_key_points,
-    "The ranged coordinate for type f32"
+    "The ranged coordinate for type f32",
+    format_float_key_point
 );
 make_numeric_coord!(
     f64,
     RangedCoordf64,
     compute_f64_key_points,
-    "The ranged coordinate for type f64"
+    "The ranged coordinate for type f64",
+    format_float_key_point
 );
 make_numeric_coord!(
     u32,
@@ -260,8 +279,10 @@ impl_ranged_type_trait!(usize, RangedCoordusize);
 pub mod group_integer_by {
     use super::Ranged;
     use super::{AsRangedCoord, DiscreteRanged};
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+    use core::ops::{Mul, Range};
     use num_traits::{FromPrimitive, PrimInt, ToPrimitive};
-    use std::ops::{Mul, Range};
 
     /// The ranged value spec that needs to be grouped.
     /// This is useful, for example, when we have an X axis is a integer and denotes days.
@@ -387,6 +408,33 @@ mod test {
         assert_eq!(coord.map(&5.0, (0, 100)), 25);
     }
 
+    #[test]
+    fn test_extreme_coord_map() {
+        // The values before the start of an unsigned range
+        let coord: RangedCoordu32 = (10..20).into();
+        assert_eq!(coord.map(&0, (0, 100)), -100);
+
+        // The ranges which don't fit in the value type
+        let coord: RangedCoordi32 = (i32::MIN..i32::MAX).into();
+        assert_eq!(coord.map(&0, (0, 100)), 50);
+        assert_eq!(coord.map(&i32::MAX, (0, 100)), 100);
+
+        // The values far outside of the range are clamped
+        let coord: RangedCoordf64 = (0.0..1e-12).into();
+        assert_eq!(coord.map(&1e300, (0, 100)), MAX_PIXEL_COORD);
+        assert_eq!(coord.map(&-1e300, (100, 0)), MAX_PIXEL_COORD);
+        assert_eq!(coord.map(&f64::INFINITY, (0, 100)), MAX_PIXEL_COORD);
+        assert_eq!(coord.map(&f64::NAN, (0, 100)), 0);
+
+        let coord: RangedCoordi64 = (0..1).into();
+        assert_eq!(coord.map(&i64::MIN, (0, 100)), -MAX_PIXEL_COORD);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(0));
+
+        let coord: RangedCoordf64 = (1.0..1.0).into();
+        assert_eq!(coord.map(&1.0, (0, 100)), 0);
+        assert_eq!(coord.map(&1.0, (50, 50)), 50);
+    }
+
     #[test]
     fn test_linear_coord_system() {
         let _coord =
@@ -0,0 +1,294 @@
+// The spacing of the key points of the time coordinates and the format of their labels, which
+// are shared by the coordinates of chrono and the time crate
+
+#[allow(clippy::inconsistent_digit_grouping)]
+pub(super) fn compute_period_per_point(
+    total_ns: u64,
+    max_points: usize,
+    sub_daily: bool,
+) -> Option<u64> {
+    let min_ns_per_point = total_ns as f64 / max_points as f64;
+    let actual_ns_per_point: u64 = (10u64).pow(min_ns_per_point.log10().floor() as u32);
+
+    fn determine_actual_ns_per_point(
+        total_ns: u64,
+        mut actual_ns_per_point: u64,
+        units: &[u64],
+        base: u64,
+        max_points: usize,
+    ) -> u64 {
+        let mut unit_per_point_idx = 0;
+        while total_ns / actual_ns_per_point > max_points as u64 * units[unit_per_point_idx] {
+            unit_per_point_idx += 1;
+            if unit_per_point_idx == units.len() {
+                unit_per_point_idx = 0;
+                actual_ns_per_point *= base;
+            }
+        }
+        units[unit_per_point_idx] * actual_ns_per_point
+    }
+
+    if actual_ns_per_point < 1_000_000_000 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            actual_ns_per_point,
+            &[1, 2, 5],
+            10,
+            max_points,
+        ))
+    } else if actual_ns_per_point < 3600_000_000_000 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            1_000_000_000,
+            &[1, 2, 5, 10, 15, 20, 30],
+            60,
+            max_points,
+        ))
+    } else if actual_ns_per_point < 3600_000_000_000 * 24 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            3600_000_000_000,
+            &[1, 2, 4, 8, 12],
+            24,
+            max_points,
+        ))
+    } else if !sub_daily {
+        if actual_ns_per_point < 3600_000_000_000 * 24 * 10 {
+            Some(determine_actual_ns_per_point(
+                total_ns,
+                3600_000_000_000 * 24,
+                &[1, 2, 5, 7],
+                10,
+                max_points,
+            ))
+        } else {
+            Some(determine_actual_ns_per_point(
+                total_ns,
+                3600_000_000_000 * 24 * 10,
+                &[1, 2, 5],
+                10,
+                max_points,
+            ))
+        }
+    } else {
+        None
+    }
+}
+
+const NS_PER_SECOND: u64 = 1_000_000_000;
+pub(super) const NS_PER_DAY: u64 = 86_400 * NS_PER_SECOND;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// The unit of the spacing of the ticks on a time axis, which decides the format of the labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum TimeUnit {
+    Millisecond,
+    Second,
+    Minute,
+    Day,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    // The unit of the ticks which are the nanoseconds apart
+    pub(super) fn of_spacing(ns: u64) -> Self {
+        match ns {
+            ns if ns < NS_PER_SECOND => TimeUnit::Millisecond,
+            ns if ns < 60 * NS_PER_SECOND => TimeUnit::Second,
+            ns if ns < NS_PER_DAY => TimeUnit::Minute,
+            _ => TimeUnit::Day,
+        }
+    }
+
+    // The unit of the ticks which are the months apart
+    pub(super) fn of_month_spacing(months: i32) -> Self {
+        if months < 12 {
+            TimeUnit::Month
+        } else {
+            TimeUnit::Year
+        }
+    }
+}
+
+// The calendar fields of a time value which are shown in the labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct CalendarFields {
+    pub(super) year: i32,
+    pub(super) month: u32,
+    pub(super) day: u32,
+    pub(super) hour: u32,
+    pub(super) minute: u32,
+    pub(super) second: u32,
+    pub(super) millisecond: u32,
+}
+
+impl CalendarFields {
+    fn month_name(&self) -> &'static str {
+        MONTH_NAMES[(self.month as usize + 11) % 12]
+    }
+
+    // The fields down to the coarse unit of the two-level labels of the unit
+    fn coarse(&self, unit: TimeUnit) -> (i32, u32, u32) {
+        match unit {
+            TimeUnit::Millisecond | TimeUnit::Second | TimeUnit::Minute => {
+                (self.year, self.month, self.day)
+            }
+            TimeUnit::Day => (self.year, self.month, 0),
+            TimeUnit::Month | TimeUnit::Year => (self.year, 0, 0),
+        }
+    }
+}
+
+// Format the label of a tick with the unit of the spacing of the ticks, which is "14:05" for
+// the minutes and hours, "Mar 3" for the days and "2021" for the years. The year is added to
+// the days if the axis spans multiple years.
+pub(super) fn format_time_label(
+    fields: &CalendarFields,
+    unit: TimeUnit,
+    with_year: bool,
+) -> String {
+    match unit {
+        TimeUnit::Millisecond => format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            fields.hour, fields.minute, fields.second, fields.millisecond
+        ),
+        TimeUnit::Second => format!(
+            "{:02}:{:02}:{:02}",
+            fields.hour, fields.minute, fields.second
+        ),
+        TimeUnit::Minute => format!("{:02}:{:02}", fields.hour, fields.minute),
+        TimeUnit::Day if with_year => {
+            format!("{} {}, {}", fields.month_name(), fields.day, fields.year)
+        }
+        TimeUnit::Day => format!("{} {}", fields.month_name(), fields.day),
+        TimeUnit::Month => format!("{} {}", fields.month_name(), fields.year),
+        TimeUnit::Year => format!("{}", fields.year),
+    }
+}
+
+// Format the label of a tick on a two-level axis, the first line is the fine unit and the
+// second line is the coarse unit, such as the days on top of the months. The coarse unit is
+// only shown on the first tick and where it changes from the previous tick.
+pub(super) fn format_two_level_label(
+    fields: &CalendarFields,
+    prev: Option<&CalendarFields>,
+    unit: TimeUnit,
+) -> String {
+    let fine = match unit {
+        TimeUnit::Day => format!("{}", fields.day),
+        TimeUnit::Month => fields.month_name().to_string(),
+        _ => format_time_label(fields, unit, false),
+    };
+    if unit == TimeUnit::Year || prev.is_some_and(|p| p.coarse(unit) == fields.coarse(unit)) {
+        return fine;
+    }
+    let coarse = match unit {
+        TimeUnit::Day => format!("{} {}", fields.month_name(), fields.year),
+        TimeUnit::Month => format!("{}", fields.year),
+        _ => format_time_label(fields, TimeUnit::Day, false),
+    };
+    format!("{}\n{}", fine, coarse)
+}
+
+// Format the label of a tick of a time axis, the tick before it is looked up in the ticks for
+// the two-level labels
+pub(super) fn format_tick<V: PartialEq, F: Fn(&V) -> CalendarFields>(
+    value: &V,
+    ticks: &[V],
+    unit: TimeUnit,
+    with_year: bool,
+    two_level: bool,
+    fields: F,
+) -> String {
+    let current = fields(value);
+    if !two_level {
+        return format_time_label(&current, unit, with_year);
+    }
+    let prev = ticks
+        .iter()
+        .position(|tick| tick == value)
+        .and_then(|idx| idx.checked_sub(1))
+        .map(|idx| fields(&ticks[idx]));
+    format_two_level_label(&current, prev.as_ref(), unit)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fields(month: u32, day: u32, hour: u32) -> CalendarFields {
+        CalendarFields {
+            year: 2021,
+            month,
+            day,
+            hour,
+            minute: 5,
+            second: 9,
+            millisecond: 250,
+        }
+    }
+
+    #[test]
+    fn test_time_unit() {
+        assert_eq!(TimeUnit::of_spacing(500_000_000), TimeUnit::Millisecond);
+        assert_eq!(TimeUnit::of_spacing(30 * NS_PER_SECOND), TimeUnit::Second);
+        assert_eq!(TimeUnit::of_spacing(3600 * NS_PER_SECOND), TimeUnit::Minute);
+        assert_eq!(TimeUnit::of_spacing(7 * NS_PER_DAY), TimeUnit::Day);
+        assert_eq!(TimeUnit::of_month_spacing(3), TimeUnit::Month);
+        assert_eq!(TimeUnit::of_month_spacing(24), TimeUnit::Year);
+    }
+
+    #[test]
+    fn test_format_time_label() {
+        let value = fields(3, 3, 14);
+        assert_eq!(
+            format_time_label(&value, TimeUnit::Millisecond, false),
+            "14:05:09.250"
+        );
+        assert_eq!(
+            format_time_label(&value, TimeUnit::Second, false),
+            "14:05:09"
+        );
+        assert_eq!(format_time_label(&value, TimeUnit::Minute, false), "14:05");
+        assert_eq!(format_time_label(&value, TimeUnit::Day, false), "Mar 3");
+        assert_eq!(
+            format_time_label(&value, TimeUnit::Day, true),
+            "Mar 3, 2021"
+        );
+        assert_eq!(
+            format_time_label(&value, TimeUnit::Month, false),
+            "Mar 2021"
+        );
+        assert_eq!(format_time_label(&value, TimeUnit::Year, false), "2021");
+    }
+
+    #[test]
+    fn test_format_two_level_label() {
+        let (first, second, next_month) = (fields(3, 30, 0), fields(3, 31, 0), fields(4, 1, 0));
+        assert_eq!(
+            format_two_level_label(&first, None, TimeUnit::Day),
+            "30\nMar 2021"
+        );
+        assert_eq!(
+            format_two_level_label(&second, Some(&first), TimeUnit::Day),
+            "31"
+        );
+        assert_eq!(
+            format_two_level_label(&next_month, Some(&second), TimeUnit::Day),
+            "1\nApr 2021"
+        );
+        assert_eq!(
+            format_two_level_label(&second, Some(&first), TimeUnit::Minute),
+            "00:05\nMar 31"
+        );
+        assert_eq!(
+            format_two_level_label(&second, Some(&first), TimeUnit::Month),
+            "Mar"
+        );
+        assert_eq!(format_two_level_label(&first, None, TimeUnit::Year), "2021");
+    }
+}
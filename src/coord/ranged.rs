@@ -2,7 +2,9 @@ use super::{CoordTranslate, ReverseCoordTranslate};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::style::ShapeStyle;
 
-use std::ops::Range;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::ops::Range;
 
 /// The trait that indicates we have a ordered and ranged value
 /// Which is used to describe the axis
@@ -19,6 +21,15 @@ pub trait Ranged {
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
+    /// Format a key point as the default tick label, `None` means the `Debug` representation
+    /// of the value is used
+    /// - `value`: The key point to format
+    /// - `max_points`: The maximum number of key points on the axis, which decides the spacing
+    ///   of the ticks
+    fn format_key_point(&self, _value: &Self::ValueType, _max_points: usize) -> Option<String> {
+        None
+    }
+
     /// This function provides the on-axis part of its range
     #[allow(clippy::range_plus_one)]
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
@@ -30,6 +41,40 @@ pub trait Ranged {
     }
 }
 
+/// The largest distance from the origin of a mapped pixel coordinate. The values far outside
+/// of the axis range are clamped to it, so the pixel coordinates can be added and multiplied
+/// by the drawing code without overflowing `i32`, while they are still far enough outside of
+/// any drawing area to be clipped
+pub const MAX_PIXEL_COORD: i32 = 1 << 24;
+
+/// Map the relative position of a value in the axis range to the pixel coordinate, this is
+/// the overflow-safe building block of `Ranged::map`
+///
+/// - `ratio`: The position of the value, 0 is the start and 1 is the end of the axis range
+/// - `limit`: The pixel coordinates of the start and the end of the axis
+/// - **returns**: The pixel coordinate clamped to `-MAX_PIXEL_COORD..=MAX_PIXEL_COORD`. It's
+///   the end of the axis if the axis is a single pixel, and the start of the axis if `ratio`
+///   is NaN, such as for an empty axis range.
+///
+/// ```rust
+/// use plotters::coord::{map_ratio, MAX_PIXEL_COORD};
+///
+/// assert_eq!(map_ratio(0.5, (0, 100)), 50);
+/// assert_eq!(map_ratio(1e30, (0, 100)), MAX_PIXEL_COORD);
+/// assert_eq!(map_ratio(f64::NAN, (0, 100)), 0);
+/// ```
+pub fn map_ratio(ratio: f64, limit: (i32, i32)) -> i32 {
+    if limit.0 == limit.1 {
+        return limit.1;
+    }
+    if ratio.is_nan() {
+        return limit.0;
+    }
+    let pixel = f64::from(limit.0) + (f64::from(limit.1) - f64::from(limit.0)) * ratio;
+    let max = f64::from(MAX_PIXEL_COORD);
+    (pixel + 1e-3).floor().max(-max).min(max) as i32
+}
+
 /// The trait indicates the ranged value can be map reversely, which means
 /// an pixel-based coordinate is given, it's possible to figure out the underlying
 /// logic value.
@@ -71,6 +116,26 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         }
     }
 
+    /// Create a new ranged value coordinate system, the types of the axes are inferred from
+    /// the ranges, such as `RangedCoordf32` for `0f32..1f32`, so there's no need to name them
+    /// as `new` requires
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let coord = RangedCoord::cartesian_2d(-1f32..1f32, 0..10, (0..200, 0..100));
+    /// assert_eq!(coord.translate(&(0.0, 5)), (100, 50));
+    /// ```
+    pub fn cartesian_2d<SX, SY>(x_spec: SX, y_spec: SY, actual: (Range<i32>, Range<i32>)) -> Self
+    where
+        SX: AsRangedCoord<CoordDescType = X>,
+        SY: AsRangedCoord<CoordDescType = Y>,
+        X: From<SX>,
+        Y: From<SY>,
+    {
+        Self::new(x_spec, y_spec, actual)
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
         &self,
@@ -104,6 +169,36 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         Ok(())
     }
 
+    /// Draw the cells between the mesh lines of the coordinate system, the cells at the
+    /// edges are bounded by the border of the plotting area
+    pub fn draw_mesh_cells<E, DrawCell: FnMut(MeshCell<X, Y>) -> Result<(), E>>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        mut draw_cell: DrawCell,
+    ) -> Result<(), E> {
+        let (xkp, ykp) = (
+            self.logic_x.key_points(v_limit),
+            self.logic_y.key_points(h_limit),
+        );
+        let x_borders = mesh_cell_borders(&self.logic_x, &xkp, self.back_x);
+        let y_borders = mesh_cell_borders(&self.logic_y, &ykp, self.back_y);
+
+        for (row, y) in y_borders.windows(2).enumerate() {
+            for (col, x) in x_borders.windows(2).enumerate() {
+                draw_cell(MeshCell {
+                    index: (col, row),
+                    upper_left: (x[0].0, y[0].0),
+                    bottom_right: (x[1].0, y[1].0),
+                    x_values: (x[0].1, x[1].1),
+                    y_values: (y[0].1, y[1].1),
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -172,6 +267,57 @@ impl<'a, X: Ranged, Y: Ranged> MeshLine<'a, X, Y> {
     }
 }
 
+/// A cell of the coordinate mesh, which is the rectangle between two adjacent mesh lines
+/// in each direction. The cells are visited row by row from the top left corner of the
+/// plotting area.
+pub struct MeshCell<'a, X: Ranged, Y: Ranged> {
+    /// The column and row of the cell
+    pub index: (usize, usize),
+    /// The upper left corner of the cell in pixels
+    pub upper_left: BackendCoord,
+    /// The bottom right corner of the cell in pixels
+    pub bottom_right: BackendCoord,
+    /// The key points on the left and right side of the cell, `None` if the side is the
+    /// border of the plotting area
+    pub x_values: (Option<&'a X::ValueType>, Option<&'a X::ValueType>),
+    /// The key points on the top and bottom side of the cell, `None` if the side is the
+    /// border of the plotting area
+    pub y_values: (Option<&'a Y::ValueType>, Option<&'a Y::ValueType>),
+}
+
+impl<'a, X: Ranged, Y: Ranged> MeshCell<'a, X, Y> {
+    /// Fill the cell with the given style
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.draw_rect(self.upper_left, self.bottom_right, style, true)
+    }
+}
+
+// The pixel positions of the cell borders in the ascending order, along with the key
+// points on them. Key points outside of the plotting area are dropped and the key points
+// win over the borders of the plotting area on the same pixel.
+fn mesh_cell_borders<'a, R: Ranged>(
+    spec: &R,
+    key_points: &'a [R::ValueType],
+    limit: (i32, i32),
+) -> Vec<(i32, Option<&'a R::ValueType>)> {
+    let (lo, hi) = (limit.0.min(limit.1), limit.0.max(limit.1));
+    let mut borders: Vec<_> = key_points
+        .iter()
+        .map(|v| (spec.map(v, limit), Some(v)))
+        .filter(|(p, _)| *p >= lo && *p <= hi)
+        .collect();
+    borders.push((lo, None));
+    borders.push((hi, None));
+    // The sort is stable, so the key points stay in front of the borders
+    borders.sort_by_key(|(p, _)| *p);
+    borders.dedup_by_key(|(p, _)| *p);
+    borders
+}
+
 /// The trait indicates the coordinate is discrete, so that we can draw histogram on it
 pub trait DiscreteRanged
 where
@@ -395,3 +541,129 @@ where
 
     Some(PartialAxis(full_range.into(), axis_range.range()))
 }
+
+/// The trait for the numeric ranges which can be widened, so the series at the bounds of the
+/// range aren't drawn right on the border of the plotting area
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// assert_eq!((0.0..10.0).padded(0.05), -0.5..10.5);
+/// assert_eq!((3..97).snapped(10), 0..100);
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let chart = ChartBuilder::on(&root)
+///     .build_ranged((0.0..10.0).padded(0.05), (0.13..0.92).snapped(10))
+///     .unwrap();
+/// ```
+pub trait PaddedRange: Sized {
+    /// Widen the range by the fraction of its width on both sides, the bounds of the integer
+    /// ranges are rounded outward
+    /// - `fraction`: The room added to each side, relative to the width of the range
+    fn padded(self, fraction: f64) -> Self;
+
+    /// Snap the bounds of the range outward to the nearest key points of the axis
+    /// - `max_points`: The maximum number of the key points, which is the number of the
+    ///   labels of the axis
+    fn snapped(self, max_points: usize) -> Self;
+}
+
+// Round the value to the multiple of the step, the steps less than 1 are inverted first, so
+// the bounds like 0.3 are exact
+fn round_to_step(value: f64, step: f64, round: fn(f64) -> f64) -> f64 {
+    if step < 1.0 {
+        let inverse = (1.0 / step).round();
+        round(value * inverse) / inverse
+    } else {
+        round(value / step) * step
+    }
+}
+
+// If the type can't represent the fractions
+fn is_integer<T: num_traits::NumCast>() -> bool {
+    num_traits::cast::<f64, T>(0.5).and_then(num_traits::cast::<T, f64>) != Some(0.5)
+}
+
+fn cast_bounds<T: num_traits::NumCast + Clone>(range: &Range<T>) -> Option<(f64, f64)> {
+    Some((
+        num_traits::cast(range.start.clone())?,
+        num_traits::cast(range.end.clone())?,
+    ))
+}
+
+impl<T> PaddedRange for Range<T>
+where
+    T: num_traits::NumCast + Clone,
+    Range<T>: AsRangedCoord<Value = T>,
+{
+    fn padded(self, fraction: f64) -> Self {
+        let (start, end) = match cast_bounds(&self) {
+            Some(bounds) => bounds,
+            None => return self,
+        };
+        let padding = (end - start) * fraction;
+        let (start, end) = if is_integer::<T>() {
+            ((start - padding).floor(), (end + padding).ceil())
+        } else {
+            (start - padding, end + padding)
+        };
+        match (num_traits::cast(start), num_traits::cast(end)) {
+            (Some(start), Some(end)) => start..end,
+            _ => self,
+        }
+    }
+
+    fn snapped(self, max_points: usize) -> Self {
+        let coord: <Self as AsRangedCoord>::CoordDescType = self.clone().into();
+        let points: Vec<f64> = coord
+            .key_points(max_points)
+            .into_iter()
+            .filter_map(num_traits::cast)
+            .collect();
+        let (start, end) = match cast_bounds(&self) {
+            Some(bounds) if points.len() >= 2 => bounds,
+            _ => return self,
+        };
+        let step = (points[1] - points[0]).abs();
+        let (low, high) = (
+            round_to_step(start.min(end), step, f64::floor),
+            round_to_step(start.max(end), step, f64::ceil),
+        );
+        let (start, end) = if start <= end {
+            (low, high)
+        } else {
+            (high, low)
+        };
+        match (num_traits::cast(start), num_traits::cast(end)) {
+            (Some(start), Some(end)) => start..end,
+            _ => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_padded_range() {
+        assert_eq!((0.0..10.0).padded(0.1), -1.0..11.0);
+        assert_eq!((0.0f32..2.0).padded(0.25), -0.5..2.5);
+        // The integer bounds are rounded outward
+        assert_eq!((0..10).padded(0.05), -1..11);
+        assert_eq!((10u32..20).padded(0.0), 10..20);
+
+        assert_eq!((0.13..0.92).snapped(10), 0.1..1.0);
+        assert_eq!((-37.0..512.0).snapped(10), -100.0..600.0);
+        assert_eq!((3..97).snapped(10), 0..100);
+        // The reversed ranges are snapped too
+        assert_eq!(
+            Range { start: 92, end: 13 }.snapped(10),
+            Range {
+                start: 100,
+                end: 10
+            }
+        );
+    }
+}
@@ -0,0 +1,264 @@
+/// The date and time coordinates of the `time` crate, for the projects which don't use chrono
+use std::ops::Range;
+use time::{Date, Duration, OffsetDateTime, Time};
+
+use super::period::{compute_period_per_point, format_tick, CalendarFields, TimeUnit};
+use super::{map_ratio, AsRangedCoord, DiscreteRanged, Ranged};
+
+// The calendar fields of the date and time of the `time` crate
+fn calendar_fields(date: Date, time: Time) -> CalendarFields {
+    CalendarFields {
+        year: date.year(),
+        month: date.month() as u32,
+        day: u32::from(date.day()),
+        hour: u32::from(time.hour()),
+        minute: u32::from(time.minute()),
+        second: u32::from(time.second()),
+        millisecond: u32::from(time.millisecond()),
+    }
+}
+
+/// The ranged coordinate for the dates of the `time` crate, the counterpart of `RangedDate`
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use time::macros::date;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(20)
+///     .y_label_area_size(20)
+///     .build_ranged(date!(2020 - 01 - 01)..date!(2020 - 02 - 01), 0.0..10.0)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RangedTimeDate(Date, Date, bool);
+
+impl From<Range<Date>> for RangedTimeDate {
+    fn from(range: Range<Date>) -> Self {
+        Self(range.start, range.end, false)
+    }
+}
+
+impl RangedTimeDate {
+    /// Show the labels in two levels, see `RangedDate::two_level`
+    pub fn two_level(mut self) -> Self {
+        self.2 = true;
+        self
+    }
+}
+
+impl AsRangedCoord for Range<Date> {
+    type CoordDescType = RangedTimeDate;
+    type Value = Date;
+}
+
+impl Ranged for RangedTimeDate {
+    type ValueType = Date;
+
+    fn range(&self) -> Range<Date> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Date, limit: (i32, i32)) -> i32 {
+        let total_days = (self.1 - self.0).whole_days();
+        let value_days = (*value - self.0).whole_days();
+        map_ratio(value_days as f64 / total_days as f64, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Date> {
+        let total_days = (self.1 - self.0).whole_days();
+        let total_weeks = (self.1 - self.0).whole_weeks();
+
+        if total_days > 0 && total_days as usize <= max_points {
+            return (0..=total_days)
+                .map(|day_idx| self.0 + Duration::days(day_idx))
+                .collect();
+        }
+
+        if total_weeks > 0 && total_weeks as usize <= max_points {
+            return (0..=total_weeks)
+                .map(|week_idx| self.0 + Duration::weeks(week_idx))
+                .collect();
+        }
+
+        if max_points == 0 || total_weeks <= 0 {
+            return vec![];
+        }
+
+        let week_per_point = ((total_weeks as f64) / (max_points as f64)).ceil() as usize;
+
+        (0..=(total_weeks as usize / week_per_point))
+            .map(|idx| self.0 + Duration::weeks((idx * week_per_point) as i64))
+            .collect()
+    }
+
+    fn format_key_point(&self, value: &Date, max_points: usize) -> Option<String> {
+        Some(format_tick(
+            value,
+            &self.key_points(max_points),
+            TimeUnit::Day,
+            self.0.year() != self.1.year(),
+            self.2,
+            |date| calendar_fields(*date, Time::MIDNIGHT),
+        ))
+    }
+}
+
+impl DiscreteRanged for RangedTimeDate {
+    type RangeParameter = ();
+    fn get_range_parameter(&self) {}
+    fn next_value(this: &Date, _: &()) -> Date {
+        *this + Duration::days(1)
+    }
+
+    fn previous_value(this: &Date, _: &()) -> Date {
+        *this - Duration::days(1)
+    }
+}
+
+/// The ranged coordinate for the date and time of the `time` crate, the counterpart of
+/// `RangedDateTime`. The key points are aligned in the UTC offset of the start of the range.
+#[derive(Clone)]
+pub struct RangedOffsetDateTime(OffsetDateTime, OffsetDateTime, bool);
+
+impl From<Range<OffsetDateTime>> for RangedOffsetDateTime {
+    fn from(range: Range<OffsetDateTime>) -> Self {
+        Self(range.start, range.end, false)
+    }
+}
+
+impl RangedOffsetDateTime {
+    /// Show the labels in two levels, see `RangedDateTime::two_level`
+    pub fn two_level(mut self) -> Self {
+        self.2 = true;
+        self
+    }
+}
+
+impl AsRangedCoord for Range<OffsetDateTime> {
+    type CoordDescType = RangedOffsetDateTime;
+    type Value = OffsetDateTime;
+}
+
+impl Ranged for RangedOffsetDateTime {
+    type ValueType = OffsetDateTime;
+
+    fn range(&self) -> Range<OffsetDateTime> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &OffsetDateTime, limit: (i32, i32)) -> i32 {
+        // The nanoseconds between any two values of the time crate fit in i128
+        let total_ns = (self.1 - self.0).whole_nanoseconds();
+        let value_ns = (*value - self.0).whole_nanoseconds();
+        map_ratio(value_ns as f64 / total_ns as f64, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<OffsetDateTime> {
+        let total_ns = (self.1 - self.0).whole_nanoseconds();
+        if total_ns <= 0 || max_points == 0 {
+            return vec![];
+        }
+
+        if let Some(ns_per_point) = compute_period_per_point(total_ns as u64, max_points, true) {
+            let midnight = self.0.replace_time(Time::MIDNIGHT);
+            let start_ns = (self.0 - midnight).whole_nanoseconds() as u64;
+            let first_ns = start_ns.div_ceil(ns_per_point) * ns_per_point;
+
+            let mut ret = vec![];
+            let mut time = midnight + Duration::nanoseconds(first_ns as i64);
+            while time < self.1 {
+                ret.push(time);
+                time += Duration::nanoseconds(ns_per_point as i64);
+            }
+            return ret;
+        }
+
+        // Otherwise, it actually behaves like a date
+        let first_day = if self.0.time() == Time::MIDNIGHT {
+            self.0.date()
+        } else {
+            self.0.date() + Duration::days(1)
+        };
+        let offset = self.0.offset();
+        RangedTimeDate(first_day, self.1.to_offset(offset).date(), false)
+            .key_points(max_points)
+            .into_iter()
+            .map(|date| date.midnight().assume_offset(offset))
+            .collect()
+    }
+
+    fn format_key_point(&self, value: &OffsetDateTime, max_points: usize) -> Option<String> {
+        let ticks = self.key_points(max_points);
+        let spacing = match &ticks[..] {
+            [first, second, ..] => *second - *first,
+            _ => self.1 - self.0,
+        };
+        let unit = TimeUnit::of_spacing(spacing.whole_nanoseconds().max(0) as u64);
+        let offset = self.0.offset();
+        Some(format_tick(
+            value,
+            &ticks,
+            unit,
+            self.0.year() != self.1.to_offset(offset).year(),
+            self.2,
+            |time| {
+                let time = time.to_offset(offset);
+                calendar_fields(time.date(), time.time())
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_time_date_range() {
+        let coord: RangedTimeDate = (date!(2019 - 01 - 01)..date!(2019 - 01 - 21)).into();
+        assert_eq!(coord.map(&date!(2019 - 01 - 11), (0, 100)), 50);
+        assert_eq!(coord.key_points(30).len(), 21);
+        assert_eq!(coord.key_points(5).len(), 3);
+        assert_eq!(
+            coord.format_key_point(&date!(2019 - 01 - 11), 5),
+            Some("Jan 11".to_string())
+        );
+
+        let coord: RangedTimeDate = (date!(1000 - 01 - 01)..date!(2999 - 01 - 01)).into();
+        let kps = coord.key_points(23);
+        assert!(kps.len() <= 23);
+        assert!(kps.windows(2).all(|w| (w[1] - w[0]).whole_days() % 7 == 0));
+    }
+
+    #[test]
+    fn test_offset_date_time_range() {
+        let coord: RangedOffsetDateTime =
+            (datetime!(2019-01-01 0:00 UTC)..datetime!(2019-01-02 0:00 UTC)).into();
+        assert_eq!(coord.map(&datetime!(2019-01-01 12:00 UTC), (0, 100)), 50);
+        // The same instant in another offset maps to the same pixel
+        assert_eq!(coord.map(&datetime!(2019-01-01 20:00 +8), (0, 100)), 50);
+
+        let kps = coord.key_points(5);
+        assert_eq!(kps.len(), 3);
+        assert_eq!(kps[1], datetime!(2019-01-01 8:00 UTC));
+        assert_eq!(
+            coord.format_key_point(&kps[1], 5),
+            Some("08:00".to_string())
+        );
+
+        let coord: RangedOffsetDateTime =
+            (datetime!(2019-01-01 6:00 +2)..datetime!(2019-06-01 0:00 +2)).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert_eq!(kps[0], datetime!(2019-01-02 0:00 +2));
+        assert_eq!(
+            coord.format_key_point(&kps[0], 10),
+            Some("Jan 2".to_string())
+        );
+    }
+}
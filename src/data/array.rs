@@ -0,0 +1,158 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+#[cfg(feature = "ndarray")]
+use super::GridValues;
+#[cfg(feature = "ndarray")]
+use ndarray::{ArrayBase, Axis, Data, Ix1, Ix2};
+
+/// Turn a sequence of values, such as a one dimensional array, into a series with the index
+/// of each value as its x, which can be drawn as a line or point series directly. Any iterator
+/// works, for example a slice, and `array1_series` takes the `ndarray` arrays.
+///
+/// - `values`: The values of the series
+/// - **returns** The iterator of `(index, value)` points
+///
+/// ```rust
+/// use plotters::data::indexed_series;
+///
+/// let series: Vec<_> = indexed_series(&[3.0, 1.5, 2.0]).collect();
+/// assert_eq!(series, vec![(0.0, 3.0), (1.0, 1.5), (2.0, 2.0)]);
+/// ```
+pub fn indexed_series<'a, T: Into<f64> + Copy + 'a, I: IntoIterator<Item = &'a T>>(
+    values: I,
+) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    I::IntoIter: 'a,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(idx, v)| (idx as f64, (*v).into()))
+}
+
+/// Turn a one dimensional `ndarray` array, or a view of it, into a series with the index of
+/// each value as its x, see `indexed_series`. The array doesn't need to be contiguous.
+///
+/// - `array`: The array of the values
+/// - **returns** The iterator of `(index, value)` points
+///
+/// ```rust
+/// use ndarray::array;
+/// use plotters::data::array1_series;
+///
+/// let values = array![3.0, 1.5, 2.0, 4.0];
+/// let series: Vec<_> = array1_series(&values.slice(ndarray::s![..;2])).collect();
+/// assert_eq!(series, vec![(0.0, 3.0), (1.0, 2.0)]);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn array1_series<'a, T: Into<f64> + Copy + 'a, S: Data<Elem = T>>(
+    array: &'a ArrayBase<S, Ix1>,
+) -> impl Iterator<Item = (f64, f64)> + 'a {
+    indexed_series(array.iter())
+}
+
+/// Split a two dimensional `ndarray` array, or a view of it, into its columns, which is the
+/// input of `Spectrogram`. Unlike `row_major_columns`, the array doesn't need to be contiguous.
+///
+/// - `array`: The array, each row of which is a row of the grid
+/// - **returns** The columns of the array
+///
+/// ```rust
+/// use ndarray::array;
+/// use plotters::data::array2_columns;
+///
+/// let columns = array2_columns(&array![[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(columns, vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn array2_columns<T: Into<f64> + Copy, S: Data<Elem = T>>(
+    array: &ArrayBase<S, Ix2>,
+) -> Vec<Vec<f64>> {
+    array
+        .axis_iter(Axis(1))
+        .map(|column| column.iter().map(|v| (*v).into()).collect())
+        .collect()
+}
+
+#[cfg(feature = "ndarray")]
+impl GridValues {
+    /// Create the grid values of a heatmap from a two dimensional `ndarray` array, with the
+    /// indices of the cells as the coordinates: the value of row `j` and column `i` is at
+    /// `(i, j)`.
+    ///
+    /// ```rust
+    /// use ndarray::Array2;
+    /// use plotters::data::GridValues;
+    ///
+    /// let array = Array2::from_shape_fn((2, 3), |(row, col)| (row * 3 + col) as f64);
+    /// let grid = GridValues::from_array(&array);
+    /// assert_eq!((grid.xs(), grid.ys()), (&[0.0, 1.0, 2.0][..], &[0.0, 1.0][..]));
+    /// assert_eq!(grid.z_range(), Some(0.0..5.0));
+    /// ```
+    pub fn from_array<T: Into<f64> + Copy, S: Data<Elem = T>>(array: &ArrayBase<S, Ix2>) -> Self {
+        let (rows, cols) = array.dim();
+        let xs = (0..cols).map(|x| x as f64).collect();
+        let ys = (0..rows).map(|y| y as f64).collect();
+        // The logical order of the array is row major, whatever its memory layout is
+        let z = array.iter().map(|v| (*v).into()).collect();
+        Self::new(xs, ys, z).unwrap()
+    }
+}
+
+/// Split a two dimensional array stored in row-major order, which is the layout of
+/// `ndarray::Array2::as_slice` and of the nested `Vec`s flattened row by row, into its
+/// columns. The result can be used as the input of `Spectrogram` and the other grid based
+/// series without copying the cells one by one.
+///
+/// - `cells`: The cells of the array, row by row
+/// - `cols`: The number of columns of the array
+/// - **returns** The columns, the cells of an incomplete last row are dropped
+///
+/// ```rust
+/// use plotters::data::row_major_columns;
+///
+/// let columns = row_major_columns(&[1, 2, 3, 4, 5, 6], 3);
+/// assert_eq!(columns, vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+/// ```
+pub fn row_major_columns<T: Into<f64> + Copy>(cells: &[T], cols: usize) -> Vec<Vec<f64>> {
+    if cols == 0 {
+        return vec![];
+    }
+    let rows = cells.len() / cols;
+    (0..cols)
+        .map(|c| (0..rows).map(|r| cells[r * cols + c].into()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_row_major_columns() {
+        assert!(row_major_columns(&[1.0, 2.0], 0).is_empty());
+        assert_eq!(
+            row_major_columns(&[1.0, 2.0, 3.0], 2),
+            vec![vec![1.0], vec![2.0]]
+        );
+        assert_eq!(indexed_series(&Vec::<f32>::new()).count(), 0);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_ndarray_adaptors() {
+        // The transposed array is in the column major layout
+        let array = ndarray::array![[1.0f32, 2.0], [3.0, 4.0], [5.0, 6.0]].reversed_axes();
+        assert_eq!(
+            array2_columns(&array),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]
+        );
+        let grid = GridValues::from_array(&array);
+        assert_eq!(grid.xs(), &[0.0, 1.0, 2.0]);
+        assert_eq!(grid.cells().next().map(|(_, _, z)| z), Some(1.0));
+        let series: Vec<_> = array1_series(&array.row(1)).collect();
+        assert_eq!(series, vec![(0.0, 2.0), (1.0, 4.0), (2.0, 6.0)]);
+        assert_eq!(array1_series(&ndarray::Array1::<f64>::zeros(0)).count(), 0);
+    }
+}
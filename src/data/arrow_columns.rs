@@ -0,0 +1,250 @@
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, PrimitiveArray, RecordBatch};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+/// The columns of an Arrow record batch, which can be picked by their names and converted into
+/// the series ready to feed into the series types, such as `LineSeries` and `Histogram`. The
+/// record batches are the tables of the Arrow data frames, such as the polars or the DataFusion
+/// ones, and of the Parquet readers.
+///
+/// ```rust
+/// use arrow_array::{ArrayRef, Float64Array, Int32Array, RecordBatch};
+/// use plotters::data::ArrowColumns;
+/// use plotters::prelude::*;
+/// use std::sync::Arc;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("time", Arc::new(Int32Array::from(vec![0, 1, 2, 3])) as ArrayRef),
+///     ("low", Arc::new(Float64Array::from(vec![Some(1.5), Some(2.5), None, Some(0.5)])) as _),
+///     ("high", Arc::new(Float64Array::from(vec![2.0, 3.0, 2.5, 1.5])) as _),
+/// ])
+/// .unwrap();
+/// let columns = ArrowColumns::new(batch);
+/// let series = columns.multi_series("time", &["low", "high"]).unwrap();
+/// assert_eq!(series[0], vec![(0.0, 1.5), (1.0, 2.5), (3.0, 0.5)]);
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..3.0, 0.0..3.0).unwrap();
+/// for (line, color) in series.into_iter().zip([RED, BLUE].iter()) {
+///     chart.draw_series(LineSeries::new(line, color)).unwrap();
+/// }
+/// ```
+pub struct ArrowColumns {
+    batch: RecordBatch,
+}
+
+// Convert the values of a numeric column to f64, the nulls are `None`
+macro_rules! numeric_column {
+    ($array:expr, $($t:ty),*) => {
+        $(if let Some(array) = $array.as_any().downcast_ref::<PrimitiveArray<$t>>() {
+            return Some(array.iter().map(|v| v.map(|v| v as f64)).collect());
+        })*
+    };
+}
+
+// Convert the values of a date or timestamp column to the UTC time, the nulls are `None`
+#[cfg(feature = "chrono")]
+macro_rules! date_column {
+    ($array:expr, $($t:ty),*) => {
+        $(if let Some(array) = $array.as_any().downcast_ref::<PrimitiveArray<$t>>() {
+            return Some(
+                (0..array.len())
+                    .map(|idx| {
+                        let time = array.is_valid(idx).then(|| array.value_as_datetime(idx))??;
+                        Some(DateTime::from_naive_utc_and_offset(time, Utc))
+                    })
+                    .collect(),
+            );
+        })*
+    };
+}
+
+// Pair the X values with a column of Y values, the rows with a missing value are skipped
+fn pair<X: Clone>(xs: &[Option<X>], ys: Vec<Option<f64>>) -> Vec<(X, f64)> {
+    xs.iter()
+        .zip(ys)
+        .filter_map(|(x, y)| Some((x.clone()?, y?)))
+        .collect()
+}
+
+impl ArrowColumns {
+    /// Create the columns of a record batch
+    /// - `batch`: The record batch
+    /// - **returns** The columns
+    pub fn new(batch: RecordBatch) -> Self {
+        Self { batch }
+    }
+
+    /// Get the names of the columns
+    pub fn names(&self) -> Vec<&str> {
+        self.batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect()
+    }
+
+    /// Get the number of rows
+    pub fn len(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    /// Check if there's no row
+    pub fn is_empty(&self) -> bool {
+        self.batch.num_rows() == 0
+    }
+
+    /// Get a column by its name, as an Arrow array
+    /// - `name`: The name of the column
+    /// - **returns** The column, or `None` if there's no such column
+    pub fn array(&self, name: &str) -> Option<&ArrayRef> {
+        self.batch.column_by_name(name)
+    }
+
+    /// Convert a numeric column, of any integer or float type, to f64 values
+    /// - `name`: The name of the column
+    /// - **returns** The values of the column, `None` for the nulls, or `None` if there's no
+    ///   such numeric column
+    pub fn column(&self, name: &str) -> Option<Vec<Option<f64>>> {
+        let array = self.array(name)?;
+        numeric_column!(
+            array,
+            Float64Type,
+            Float32Type,
+            Int8Type,
+            Int16Type,
+            Int32Type,
+            Int64Type,
+            UInt8Type,
+            UInt16Type,
+            UInt32Type,
+            UInt64Type
+        );
+        None
+    }
+
+    /// Pair two numeric columns as a series, the rows with a null are skipped
+    /// - `x`: The name of the column of X values
+    /// - `y`: The name of the column of Y values
+    /// - **returns** The series, or `None` if any of the columns isn't a numeric column
+    pub fn series(&self, x: &str, y: &str) -> Option<Vec<(f64, f64)>> {
+        Some(pair(&self.column(x)?, self.column(y)?))
+    }
+
+    /// Pair a numeric column of X values with each of the numeric columns of Y values
+    /// - `x`: The name of the column of X values
+    /// - `ys`: The names of the columns of Y values
+    /// - **returns** The series of each Y column, or `None` if any of the columns isn't a
+    ///   numeric column
+    pub fn multi_series(&self, x: &str, ys: &[&str]) -> Option<Vec<Vec<(f64, f64)>>> {
+        let xs = self.column(x)?;
+        ys.iter()
+            .map(|y| Some(pair(&xs, self.column(y)?)))
+            .collect()
+    }
+
+    /// Convert a date or timestamp column, which is a `Date32`, `Date64` or `Timestamp`
+    /// column of any unit, to the times in UTC
+    /// - `name`: The name of the column
+    /// - **returns** The times of the column, `None` for the nulls, or `None` if there's no
+    ///   such date column
+    #[cfg(feature = "chrono")]
+    pub fn date_column(&self, name: &str) -> Option<Vec<Option<DateTime<Utc>>>> {
+        let array = self.array(name)?;
+        date_column!(
+            array,
+            Date32Type,
+            Date64Type,
+            TimestampSecondType,
+            TimestampMillisecondType,
+            TimestampMicrosecondType,
+            TimestampNanosecondType
+        );
+        None
+    }
+
+    /// Pair a date column with a numeric column, the rows with a null are skipped
+    /// - `x`: The name of the date column
+    /// - `y`: The name of the column of Y values
+    /// - **returns** The series, or `None` if any of the columns doesn't exist or has another
+    ///   type
+    #[cfg(feature = "chrono")]
+    pub fn date_series(&self, x: &str, y: &str) -> Option<Vec<(DateTime<Utc>, f64)>> {
+        Some(pair(&self.date_column(x)?, self.column(y)?))
+    }
+
+    /// Pair a date column with each of the numeric columns of Y values
+    /// - `x`: The name of the date column
+    /// - `ys`: The names of the columns of Y values
+    /// - **returns** The series of each Y column, or `None` if any of the columns doesn't
+    ///   exist or has another type
+    #[cfg(feature = "chrono")]
+    #[allow(clippy::type_complexity)]
+    pub fn date_multi_series(
+        &self,
+        x: &str,
+        ys: &[&str],
+    ) -> Option<Vec<Vec<(DateTime<Utc>, f64)>>> {
+        let xs = self.date_column(x)?;
+        ys.iter()
+            .map(|y| Some(pair(&xs, self.column(y)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::{Date32Array, StringArray, TimestampMillisecondArray, UInt8Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_arrow_columns() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "day",
+                Arc::new(Date32Array::from(vec![Some(0), None, Some(2)])) as ArrayRef,
+            ),
+            (
+                "time",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1500, 3000])) as _,
+            ),
+            ("count", Arc::new(UInt8Array::from(vec![3, 4, 5])) as _),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as _,
+            ),
+        ])
+        .unwrap();
+        let columns = ArrowColumns::new(batch);
+        assert_eq!(columns.names(), ["day", "time", "count", "name"]);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(
+            columns.column("count").unwrap(),
+            vec![Some(3.0), Some(4.0), Some(5.0)]
+        );
+        assert!(columns.column("name").is_none());
+        assert!(columns.series("count", "missing").is_none());
+        assert!(columns.multi_series("count", &[]).unwrap().is_empty());
+
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::TimeZone;
+            let series = columns.date_multi_series("day", &["count"]).unwrap();
+            assert_eq!(
+                series[0],
+                vec![
+                    (Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap(), 3.0),
+                    (Utc.with_ymd_and_hms(1970, 1, 3, 0, 0, 0).unwrap(), 5.0)
+                ]
+            );
+            let times = columns.date_column("time").unwrap();
+            assert_eq!(times[2], Utc.timestamp_opt(3, 0).single());
+            assert!(columns.date_column("count").is_none());
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use super::Quartiles;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::ops::Range;
+use num_traits::AsPrimitive;
+
+/// The strategy used to compute the histogram bins from raw samples
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinningStrategy {
+    /// Sturges' rule: `ceil(log2(n)) + 1` bins, works well for normally distributed data
+    Sturges,
+    /// Scott's normal reference rule: bin width is `3.49 * stddev * n^(-1/3)`
+    Scott,
+    /// Freedman–Diaconis rule: bin width is `2 * IQR * n^(-1/3)`, robust to outliers
+    FreedmanDiaconis,
+    /// Use a fixed number of bins
+    Count(usize),
+    /// Use a fixed bin width
+    Width(f64),
+}
+
+/// The normalization applied to the bin values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinNormalization {
+    /// The raw (weighted) count of each bin
+    Count,
+    /// The fraction of the total, all the bins sum up to 1
+    Probability,
+    /// The percentage of the total, all the bins sum up to 100
+    Percentage,
+    /// The probability density, the area of all the bins sum up to 1
+    Density,
+}
+
+// Prevent a degenerated bin width from allocating an unreasonable amount of bins
+const MAX_BIN_COUNT: usize = 100_000;
+
+/// The bins computed from a set of raw samples.
+///
+/// All the bins have the same width, and the bin `i` covers the half-open range
+/// `[start + i * width, start + (i + 1) * width)`, except the last bin which also
+/// includes its upper bound.
+///
+/// ```rust
+/// use plotters::data::{BinningStrategy, Bins};
+///
+/// let bins = Bins::new(&[1.0, 2.0, 2.5, 3.0, 4.0], BinningStrategy::Count(3));
+/// assert_eq!(bins.width(), 1.0);
+/// assert_eq!(bins.values(), &[1.0, 2.0, 2.0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bins {
+    start: f64,
+    width: f64,
+    values: Vec<f64>,
+}
+
+impl Bins {
+    /// Compute the bins for the samples and count the samples that falls into each bin.
+    /// Non-finite samples are ignored.
+    ///
+    /// - `samples`: The raw samples
+    /// - `strategy`: The strategy used to decide the bin width
+    /// - **returns** The bins, which is empty if there is no valid sample
+    pub fn new<T: AsPrimitive<f64>>(samples: &[T], strategy: BinningStrategy) -> Self {
+        let samples: Vec<f64> = samples
+            .iter()
+            .map(|&x| x.as_())
+            .filter(|x: &f64| x.is_finite())
+            .collect();
+
+        let mut bins = Self::from_samples(&samples, strategy);
+        for x in samples {
+            bins.add(x, 1.0);
+        }
+        bins
+    }
+
+    /// Compute the bins for the samples, each sample contributes its weight to the bin
+    /// instead of 1. Samples with a non-finite value or weight are ignored.
+    ///
+    /// - `samples`: The `(value, weight)` pairs
+    /// - `strategy`: The strategy used to decide the bin width, which only depends on the values
+    /// - **returns** The bins
+    ///
+    /// ```rust
+    /// use plotters::data::{BinningStrategy, Bins};
+    ///
+    /// let bins = Bins::weighted(&[(1.0, 0.5), (1.2, 2.0), (3.0, 1.0)], BinningStrategy::Width(1.0));
+    /// assert_eq!(bins.values(), &[2.5, 1.0]);
+    /// ```
+    pub fn weighted<T: AsPrimitive<f64>, W: AsPrimitive<f64>>(
+        samples: &[(T, W)],
+        strategy: BinningStrategy,
+    ) -> Self {
+        let samples: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|&(x, w)| (x.as_(), w.as_()))
+            .filter(|(x, w)| x.is_finite() && w.is_finite())
+            .collect();
+
+        let values: Vec<_> = samples.iter().map(|(x, _)| *x).collect();
+        let mut bins = Self::from_samples(&values, strategy);
+        for (x, w) in samples {
+            bins.add(x, w);
+        }
+        bins
+    }
+
+    /// Normalize the bin values, so that histograms from datasets with different sizes
+    /// can be compared.
+    ///
+    /// - `method`: The normalization method
+    /// - **returns** The normalized bins
+    pub fn normalized(&self, method: BinNormalization) -> Self {
+        let total: f64 = self.values.iter().sum();
+        let factor = match method {
+            BinNormalization::Count => 1.0,
+            BinNormalization::Probability => 1.0 / total,
+            BinNormalization::Percentage => 100.0 / total,
+            BinNormalization::Density => 1.0 / (total * self.width),
+        };
+        let factor = if factor.is_finite() { factor } else { 0.0 };
+        Self {
+            start: self.start,
+            width: self.width,
+            values: self.values.iter().map(|v| v * factor).collect(),
+        }
+    }
+
+    fn from_samples(samples: &[f64], strategy: BinningStrategy) -> Self {
+        if samples.is_empty() {
+            return Self {
+                start: 0.0,
+                width: 1.0,
+                values: vec![],
+            };
+        }
+
+        let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let span = max - min;
+        let n = samples.len() as f64;
+
+        let sturges = (n.log2().ceil() + 1.0).max(1.0) as usize;
+        let width = match strategy {
+            BinningStrategy::Sturges => span / sturges as f64,
+            BinningStrategy::Scott => {
+                let mean = samples.iter().sum::<f64>() / n;
+                let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+                3.49 * var.sqrt() * n.powf(-1.0 / 3.0)
+            }
+            BinningStrategy::FreedmanDiaconis => {
+                let mut sorted = samples.to_vec();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let iqr = Quartiles::percentile_of_sorted(&sorted, 75.0)
+                    - Quartiles::percentile_of_sorted(&sorted, 25.0);
+                2.0 * iqr * n.powf(-1.0 / 3.0)
+            }
+            BinningStrategy::Count(count) => span / count.max(1) as f64,
+            BinningStrategy::Width(width) => width,
+        };
+
+        // Fallback to Sturges' rule when the strategy can not decide a valid width
+        let width = if width.is_finite() && width > 0.0 {
+            width
+        } else if span > 0.0 {
+            span / sturges as f64
+        } else {
+            1.0
+        };
+
+        let count = match strategy {
+            BinningStrategy::Count(count) if span > 0.0 => count.max(1),
+            _ => ((span / width).ceil() as usize).max(1),
+        };
+
+        // The bins are widened when there are too many of them, so they still cover all the
+        // samples
+        let (width, count) = if count > MAX_BIN_COUNT {
+            (span / MAX_BIN_COUNT as f64, MAX_BIN_COUNT)
+        } else {
+            (width, count)
+        };
+
+        Self {
+            start: min,
+            width,
+            values: vec![0.0; count],
+        }
+    }
+
+    fn add(&mut self, x: f64, weight: f64) {
+        if self.values.is_empty() {
+            return;
+        }
+        let idx = ((x - self.start) / self.width).floor().max(0.0) as usize;
+        let idx = idx.min(self.values.len() - 1);
+        self.values[idx] += weight;
+    }
+
+    /// Get the lower bound of the first bin
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Get the width of each bin
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Get the number of bins
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if there's no bin at all
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get the value of each bin, which is the number of samples (or the sum of weights) in the bin
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Get the range covered by the bin
+    pub fn bin_range(&self, idx: usize) -> Range<f64> {
+        let lo = self.start + self.width * idx as f64;
+        lo..lo + self.width
+    }
+
+    /// Get the range covered by all the bins
+    pub fn range(&self) -> Range<f64> {
+        self.start..self.start + self.width * self.values.len() as f64
+    }
+
+    /// Iterate over the bins, yields the range and value of each bin
+    pub fn iter(&self) -> impl Iterator<Item = (Range<f64>, f64)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(idx, value)| (self.bin_range(idx), *value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binning_strategies() {
+        let samples: Vec<_> = (0..1000).map(|x| x as f64 / 10.0).collect();
+
+        let bins = Bins::new(&samples, BinningStrategy::Sturges);
+        assert_eq!(bins.len(), 11);
+
+        let bins = Bins::new(&samples, BinningStrategy::FreedmanDiaconis);
+        assert!((bins.width() - 2.0 * 49.95 / 10.0).abs() < 1e-9);
+        assert_eq!(bins.values().iter().sum::<f64>(), 1000.0);
+
+        let bins = Bins::new(&samples, BinningStrategy::Width(10.0));
+        assert_eq!(bins.len(), 10);
+        assert_eq!(bins.values()[9], 100.0);
+        assert_eq!(bins.range(), 0.0..100.0);
+    }
+
+    #[test]
+    fn test_degenerated_samples() {
+        let bins = Bins::new(&[5.0, 5.0, f64::NAN], BinningStrategy::Scott);
+        assert_eq!(bins.values(), &[2.0]);
+        assert_eq!(bins.bin_range(0), 5.0..6.0);
+
+        let empty: [f64; 0] = [];
+        assert!(Bins::new(&empty, BinningStrategy::Sturges).is_empty());
+        assert!(Bins::new(&empty, BinningStrategy::Sturges)
+            .normalized(BinNormalization::Density)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_bin_count_limit() {
+        // The bins of a tiny width are widened to cover the wide range of the samples
+        let samples: Vec<i64> = vec![0, 1_000_000_000, 2_000_000_000];
+        let bins = Bins::new(&samples, BinningStrategy::Width(1.0));
+        assert_eq!(bins.len(), MAX_BIN_COUNT);
+        assert_eq!(bins.range(), 0.0..2e9);
+        assert_eq!(bins.values()[MAX_BIN_COUNT / 2], 1.0);
+        assert_eq!(bins.values()[MAX_BIN_COUNT - 1], 1.0);
+        assert_eq!(
+            Bins::new(&[1u64, 2, 2], BinningStrategy::Count(2)).values(),
+            &[1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_normalized_bins() {
+        let bins = Bins::new(&[0.0, 0.5, 1.0, 1.5, 2.0], BinningStrategy::Width(0.5));
+        assert_eq!(bins.values(), &[1.0, 1.0, 1.0, 2.0]);
+
+        let prob = bins.normalized(BinNormalization::Probability);
+        assert_eq!(prob.values(), &[0.2, 0.2, 0.2, 0.4]);
+
+        let pct = bins.normalized(BinNormalization::Percentage);
+        assert_eq!(pct.values().iter().sum::<f64>(), 100.0);
+
+        let density = bins.normalized(BinNormalization::Density);
+        let area: f64 = density.values().iter().map(|v| v * density.width()).sum();
+        assert!((area - 1.0).abs() < 1e-9);
+    }
+}
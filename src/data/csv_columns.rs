@@ -0,0 +1,180 @@
+use std::io::Read;
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+/// The columns of a CSV file, which can be picked by their names and parsed into typed series
+/// ready to feed into the series types, such as `LineSeries` and `Histogram`.
+///
+/// ```rust
+/// use plotters::data::CsvColumns;
+/// use plotters::prelude::*;
+///
+/// let data = "time,value\n0,1.5\n1,2.5\n2,N/A\n3,0.5\n";
+/// let columns = CsvColumns::from_reader(csv::Reader::from_reader(data.as_bytes())).unwrap();
+/// let series: Vec<(f64, f64)> = columns.series("time", "value").unwrap();
+/// assert_eq!(series, vec![(0.0, 1.5), (1.0, 2.5), (3.0, 0.5)]);
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..3.0, 0.0..3.0).unwrap();
+/// chart.draw_series(LineSeries::new(series, &RED)).unwrap();
+/// ```
+pub struct CsvColumns {
+    headers: Vec<String>,
+    records: Vec<csv::StringRecord>,
+}
+
+impl CsvColumns {
+    /// Read all the records of a CSV reader, the reader should have a header row, which gives
+    /// the names of the columns
+    /// - `reader`: The CSV reader
+    /// - **returns** The columns, or the error of the reader
+    pub fn from_reader<R: Read>(mut reader: csv::Reader<R>) -> Result<Self, csv::Error> {
+        let headers = reader.headers()?.iter().map(str::to_string).collect();
+        let records = reader.records().collect::<Result<_, _>>()?;
+        Ok(Self { headers, records })
+    }
+
+    /// Get the names of the columns
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Get the number of rows
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Check if there's no row
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.headers.iter().position(|h| h == name)
+    }
+
+    /// Parse a column with a parse function
+    /// - `name`: The name of the column
+    /// - `parse`: The function that parses a cell, `None` means the cell is missing
+    /// - **returns** The values of the column, or `None` if there's no such column
+    pub fn column_with<T, F: Fn(&str) -> Option<T>>(
+        &self,
+        name: &str,
+        parse: F,
+    ) -> Option<Vec<Option<T>>> {
+        let idx = self.index_of(name)?;
+        Some(
+            self.records
+                .iter()
+                .map(|r| r.get(idx).and_then(|cell| parse(cell.trim())))
+                .collect(),
+        )
+    }
+
+    /// Parse a column, the cells which can't be parsed are `None`
+    /// - `name`: The name of the column
+    /// - **returns** The values of the column, or `None` if there's no such column
+    pub fn column<T: FromStr>(&self, name: &str) -> Option<Vec<Option<T>>> {
+        self.column_with(name, |cell| cell.parse().ok())
+    }
+
+    /// Pair two columns with the parse functions, the rows with a missing cell are skipped
+    /// - `x`: The name of the column of X values
+    /// - `y`: The name of the column of Y values
+    /// - `parse_x`: The function that parses the X values
+    /// - `parse_y`: The function that parses the Y values
+    /// - **returns** The series, or `None` if any of the columns doesn't exist
+    pub fn series_with<X, Y, FX, FY>(
+        &self,
+        x: &str,
+        y: &str,
+        parse_x: FX,
+        parse_y: FY,
+    ) -> Option<Vec<(X, Y)>>
+    where
+        FX: Fn(&str) -> Option<X>,
+        FY: Fn(&str) -> Option<Y>,
+    {
+        let (xs, ys) = (self.column_with(x, parse_x)?, self.column_with(y, parse_y)?);
+        Some(
+            xs.into_iter()
+                .zip(ys)
+                .filter_map(|(x, y)| Some((x?, y?)))
+                .collect(),
+        )
+    }
+
+    /// Pair two columns as a series, the rows with a cell which can't be parsed are skipped
+    /// - `x`: The name of the column of X values
+    /// - `y`: The name of the column of Y values
+    /// - **returns** The series, or `None` if any of the columns doesn't exist
+    pub fn series<X: FromStr, Y: FromStr>(&self, x: &str, y: &str) -> Option<Vec<(X, Y)>> {
+        self.series_with(x, y, |cell| cell.parse().ok(), |cell| cell.parse().ok())
+    }
+
+    /// Pair a date column with a value column, the dates are parsed in UTC with the `strftime`
+    /// like format, either as dates or as date and time
+    /// - `x`: The name of the date column
+    /// - `y`: The name of the column of Y values
+    /// - `format`: The format of the dates, for example, `%Y-%m-%d`
+    /// - **returns** The series, or `None` if any of the columns doesn't exist
+    #[cfg(feature = "chrono")]
+    pub fn date_series<Y: FromStr>(
+        &self,
+        x: &str,
+        y: &str,
+        format: &str,
+    ) -> Option<Vec<(DateTime<Utc>, Y)>> {
+        let parse_date = |cell: &str| {
+            let time = NaiveDateTime::parse_from_str(cell, format)
+                .ok()
+                .or_else(|| {
+                    NaiveDate::parse_from_str(cell, format)
+                        .ok()
+                        .and_then(|date| date.and_hms_opt(0, 0, 0))
+                })?;
+            Some(DateTime::from_naive_utc_and_offset(time, Utc))
+        };
+        self.series_with(x, y, parse_date, |cell| cell.parse().ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_csv_columns() {
+        let data = "date, count ,name\n2020-01-02,3,a\n2020-01-05,,b\nbad,4,c\n";
+        let columns = CsvColumns::from_reader(
+            csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(data.as_bytes()),
+        )
+        .unwrap();
+        assert_eq!(columns.headers(), ["date", "count", "name"]);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(
+            columns.column::<u32>("count").unwrap(),
+            vec![Some(3), None, Some(4)]
+        );
+        assert!(columns.column::<u32>("missing").is_none());
+        assert_eq!(
+            columns.series::<String, u32>("name", "count").unwrap(),
+            vec![("a".to_string(), 3), ("c".to_string(), 4)]
+        );
+
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::TimeZone;
+            let series = columns.date_series::<u32>("date", "count", "%Y-%m-%d");
+            assert_eq!(
+                series.unwrap(),
+                vec![(Utc.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap(), 3)]
+            );
+        }
+    }
+}
@@ -1,10 +1,11 @@
-use std::cmp::{Ordering, PartialOrd};
-use std::iter::IntoIterator;
-use std::ops::Range;
+use core::cmp::{Ordering, PartialOrd};
+use core::iter::IntoIterator;
+use core::ops::Range;
 
 use num_traits::{One, Zero};
 
-/// Build a range that fits the data
+/// Build a range that fits the data, the values which can't be compared, like NaN, are
+/// ignored. When there's no value, the range is `0..1`.
 ///
 /// - `iter`: the iterator over the data
 /// - **returns** The resulting range
@@ -15,6 +16,9 @@ use num_traits::{One, Zero};
 /// let data = [4, 14, -2, 2, 5];
 /// let range = fitting_range(&data);
 /// assert_eq!(range, std::ops::Range { start: -2, end: 14 });
+///
+/// assert_eq!(fitting_range(&[f64::NAN, 1.5, 0.5]), 0.5..1.5);
+/// assert_eq!(fitting_range::<f64, _>(&[]), 0.0..1.0);
 /// ```
 pub fn fitting_range<'a, T: 'a, I: IntoIterator<Item = &'a T>>(iter: I) -> Range<T>
 where
@@ -23,6 +27,10 @@ where
     let (mut lb, mut ub) = (None, None);
 
     for value in iter.into_iter() {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+
         if let Some(Ordering::Greater) = lb
             .as_ref()
             .map_or(Some(Ordering::Greater), |lbv: &T| lbv.partial_cmp(value))
@@ -40,3 +48,44 @@ where
 
     lb.unwrap_or_else(Zero::zero)..ub.unwrap_or_else(One::one)
 }
+
+/// Build a range that fits the data with some room around it, so the points on the edges
+/// aren't drawn on the border of the plotting area. The values which are not finite are
+/// ignored. When all the values are the same, the range is centered on the value.
+///
+/// - `iter`: the iterator over the data
+/// - `padding`: The room added to both side of the range, relative to the width of the range
+/// - **returns** The resulting range
+///
+/// ```rust
+/// use plotters::data::padded_fitting_range;
+///
+/// let data = [1.0, 3.0, f64::INFINITY, 2.0];
+/// assert_eq!(padded_fitting_range(&data, 0.1), 0.8..3.2);
+/// assert_eq!(padded_fitting_range(&[2.0], 0.1), 1.0..3.0);
+/// ```
+pub fn padded_fitting_range<'a, I: IntoIterator<Item = &'a f64>>(
+    iter: I,
+    padding: f64,
+) -> Range<f64> {
+    let range = fitting_range(iter.into_iter().filter(|v| v.is_finite()));
+    let width = range.end - range.start;
+    if width == 0.0 {
+        let half = range.start.abs().max(1.0) / 2.0;
+        return (range.start - half)..(range.end + half);
+    }
+    (range.start - width * padding)..(range.end + width * padding)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fitting_range_of_floats() {
+        assert_eq!(fitting_range(&[f64::NAN]), 0.0..1.0);
+        assert_eq!(fitting_range(&[2.0, f64::NAN, -1.0]), -1.0..2.0);
+        assert_eq!(padded_fitting_range(&[], 0.5), -0.5..1.5);
+        assert_eq!(padded_fitting_range(&[-4.0], 0.5), -6.0..-2.0);
+    }
+}
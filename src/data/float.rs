@@ -1,4 +1,6 @@
 // The code that is related to float number handling
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 fn find_minimal_repr(n: f64, eps: f64) -> (f64, usize) {
     if eps >= 1.0 {
@@ -82,6 +84,59 @@ pub fn pretty_print_float(n: f64, allow_sn: bool) -> String {
     }
 }
 
+// The number of decimal digits needed to represent the multiples of a positive step
+fn decimal_digits_of_step(step: f64) -> usize {
+    (0..15)
+        .find(|&p| {
+            let scaled = step * 10f64.powi(p as i32);
+            (scaled - scaled.round()).abs() <= 1e-6 * scaled.max(1.0)
+        })
+        .unwrap_or(15)
+}
+
+/// Pretty print a value on an axis. Unlike `pretty_print_float`, the precision is decided by
+/// the spacing of the ticks, so all the labels of the axis have the same number of digits and
+/// the rounding noise of the tick values is dropped. The scientific notation is used when the
+/// values of the axis are very large or very small.
+///
+/// - `n`: The value to pretty-print
+/// - `range`: The range of the axis
+/// - `step`: The spacing between two ticks of the axis
+/// - **returns**: The pretty printed string
+///
+/// ```rust
+/// use plotters::data::float::pretty_print_float_in_range;
+///
+/// assert_eq!(pretty_print_float_in_range(0.30000000000000004, 0.0..1.0, 0.1), "0.3");
+/// assert_eq!(pretty_print_float_in_range(1.0, 0.0..1.0, 0.25), "1.00");
+/// assert_eq!(pretty_print_float_in_range(20.0, 0.0..100.0, 20.0), "20");
+/// assert_eq!(pretty_print_float_in_range(2.5e7, 0.0..5e7, 5e6), "2.5e7");
+/// ```
+pub fn pretty_print_float_in_range(n: f64, range: core::ops::Range<f64>, step: f64) -> String {
+    let magnitude = range.start.abs().max(range.end.abs());
+    if !step.is_finite() || step <= 0.0 || !magnitude.is_finite() || !n.is_finite() {
+        return pretty_print_float(n, true);
+    }
+
+    let repr = if magnitude > 0.0 && !(1e-3..1e6).contains(&magnitude) {
+        let exp = magnitude.log10().floor() as i32;
+        let scale = 10f64.powi(exp);
+        let precision = decimal_digits_of_step(step / scale);
+        if (n / step).abs() < 1e-6 {
+            return "0".to_string();
+        }
+        format!("{:.*}e{}", precision, n / scale, exp)
+    } else {
+        format!("{:.*}", decimal_digits_of_step(step), n)
+    };
+
+    // The values rounded to zero shouldn't have the minus sign
+    match repr.strip_prefix('-') {
+        Some(abs) if abs.chars().all(|c| c == '0' || c == '.') => abs.to_string(),
+        _ => repr,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -101,4 +156,13 @@ mod test {
         assert_eq!(pretty_print_float(1234567890f64, true), "1234567890");
         assert_eq!(pretty_print_float(1000000001f64, true), "1e9");
     }
+
+    #[test]
+    fn test_pretty_printing_in_range() {
+        assert_eq!(pretty_print_float_in_range(-0.0001, -1.0..1.0, 0.5), "0.0");
+        assert_eq!(pretty_print_float_in_range(-0.5, -1.0..1.0, 0.5), "-0.5");
+        assert_eq!(pretty_print_float_in_range(0.0, 0.0..5e7, 5e6), "0");
+        assert_eq!(pretty_print_float_in_range(3e-4, 0.0..5e-4, 1e-4), "3e-4");
+        assert_eq!(pretty_print_float_in_range(1.0, 0.0..1.0, 0.0), "1");
+    }
 }
@@ -0,0 +1,191 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::ops::Range;
+
+/// The z values sampled on a regular grid, where the grid lines are given by the X and Y
+/// coordinate vectors. The coordinates don't need to be evenly spaced, but they should be
+/// increasing. This is the common input of the series drawing a surface, such as a heatmap:
+/// each value covers the cell between the midpoints to its neighbours.
+///
+/// ```rust
+/// use plotters::data::GridValues;
+/// use plotters::prelude::*;
+///
+/// let xs: Vec<f64> = (0..10).map(|x| x as f64 / 10.0).collect();
+/// let ys: Vec<f64> = (0..5).map(|y| y as f64 / 5.0).collect();
+/// let grid = GridValues::from_fn(xs, ys, |x, y| x * y);
+/// assert_eq!(grid.z_range(), Some(0.0..0.9 * 0.8));
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(grid.x_range(), grid.y_range())
+///     .unwrap();
+/// chart
+///     .draw_series(grid.cells().map(|(upper_left, bottom_right, z)| {
+///         Rectangle::new([upper_left, bottom_right], HSLColor(z, 0.7, 0.5).filled())
+///     }))
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridValues {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    // The values in row major order, each row has the same Y coordinate
+    z: Vec<f64>,
+}
+
+// The boundaries of the cells around the grid lines
+fn cell_bounds(coords: &[f64]) -> Vec<f64> {
+    match coords.len() {
+        0 => vec![],
+        1 => vec![coords[0] - 0.5, coords[0] + 0.5],
+        n => {
+            let mut bounds = Vec::with_capacity(n + 1);
+            bounds.push(coords[0] - (coords[1] - coords[0]) / 2.0);
+            bounds.extend(coords.windows(2).map(|w| (w[0] + w[1]) / 2.0));
+            bounds.push(coords[n - 1] + (coords[n - 1] - coords[n - 2]) / 2.0);
+            bounds
+        }
+    }
+}
+
+impl GridValues {
+    /// Create the grid values
+    /// - `xs`: The X coordinates of the grid
+    /// - `ys`: The Y coordinates of the grid
+    /// - `z`: The values in row major order, the value at `(xs[i], ys[j])` is
+    ///   `z[j * xs.len() + i]`
+    /// - **returns** The grid values, or `None` if the number of values doesn't match the grid
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>, z: Vec<f64>) -> Option<Self> {
+        if xs.len() * ys.len() != z.len() {
+            return None;
+        }
+        Some(Self { xs, ys, z })
+    }
+
+    /// Create the grid values by evaluating a function at each grid point
+    /// - `xs`: The X coordinates of the grid
+    /// - `ys`: The Y coordinates of the grid
+    /// - `func`: The function which gives the value at `(x, y)`
+    pub fn from_fn<F: FnMut(f64, f64) -> f64>(xs: Vec<f64>, ys: Vec<f64>, mut func: F) -> Self {
+        let z = ys
+            .iter()
+            .flat_map(|&y| xs.iter().map(move |&x| (x, y)))
+            .map(|(x, y)| func(x, y))
+            .collect();
+        Self { xs, ys, z }
+    }
+
+    /// Get the X coordinates of the grid
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    /// Get the Y coordinates of the grid
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// Get the number of columns and rows of the grid
+    pub fn size(&self) -> (usize, usize) {
+        (self.xs.len(), self.ys.len())
+    }
+
+    /// Get the value at the grid point `(xs[i], ys[j])`
+    pub fn get(&self, i: usize, j: usize) -> Option<f64> {
+        if i >= self.xs.len() {
+            return None;
+        }
+        self.z.get(j * self.xs.len() + i).copied()
+    }
+
+    /// Get the X range covered by the cells
+    pub fn x_range(&self) -> Range<f64> {
+        let bounds = cell_bounds(&self.xs);
+        match (bounds.first(), bounds.last()) {
+            (Some(&lo), Some(&hi)) => lo..hi,
+            _ => 0.0..1.0,
+        }
+    }
+
+    /// Get the Y range covered by the cells
+    pub fn y_range(&self) -> Range<f64> {
+        let bounds = cell_bounds(&self.ys);
+        match (bounds.first(), bounds.last()) {
+            (Some(&lo), Some(&hi)) => lo..hi,
+            _ => 0.0..1.0,
+        }
+    }
+
+    /// Get the range of the finite values, or `None` if there's no finite value
+    pub fn z_range(&self) -> Option<Range<f64>> {
+        let finite = self.z.iter().copied().filter(|v| v.is_finite());
+        finite.fold(None, |range, v| match range {
+            None => Some(v..v),
+            Some(r) => Some(r.start.min(v)..r.end.max(v)),
+        })
+    }
+
+    /// Iterate over the grid points as `(x, y, z)`, row by row
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64, f64)> + '_ {
+        let cols = self.xs.len().max(1);
+        self.z
+            .iter()
+            .enumerate()
+            .map(move |(idx, &z)| (self.xs[idx % cols], self.ys[idx / cols], z))
+    }
+
+    /// Iterate over the cells around the grid points as `(upper_left, bottom_right, z)`, the
+    /// corners are in the guest coordinate, the cells with a value which isn't finite are
+    /// skipped
+    pub fn cells(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64), f64)> + '_ {
+        let x_bounds = cell_bounds(&self.xs);
+        let y_bounds = cell_bounds(&self.ys);
+        let cols = self.xs.len().max(1);
+        self.z
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| z.is_finite())
+            .map(move |(idx, &z)| {
+                let (i, j) = (idx % cols, idx / cols);
+                (
+                    (x_bounds[i], y_bounds[j + 1]),
+                    (x_bounds[i + 1], y_bounds[j]),
+                    z,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grid_values() {
+        assert!(GridValues::new(vec![0.0, 1.0], vec![0.0], vec![1.0]).is_none());
+
+        let grid = GridValues::new(
+            vec![0.0, 1.0, 3.0],
+            vec![0.0, 2.0],
+            (0..6).map(f64::from).collect(),
+        )
+        .unwrap();
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(5.0));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.x_range(), -0.5..4.0);
+        assert_eq!(grid.y_range(), -1.0..3.0);
+        assert_eq!(grid.points().nth(4), Some((1.0, 2.0, 4.0)));
+
+        let cells: Vec<_> = grid.cells().collect();
+        assert_eq!(cells[1], ((0.5, 1.0), (2.0, -1.0), 1.0));
+        assert_eq!(cells[5], ((2.0, 3.0), (4.0, 1.0), 5.0));
+
+        let single = GridValues::from_fn(vec![1.0], vec![1.0], |_, _| f64::NAN);
+        assert_eq!(single.x_range(), 0.5..1.5);
+        assert_eq!(single.z_range(), None);
+        assert_eq!(single.cells().count(), 0);
+    }
+}
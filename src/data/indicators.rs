@@ -0,0 +1,266 @@
+use crate::data::SlidingWindow;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::series::MovingAverage;
+use num_traits::NumCast;
+
+// The finite values of the series, the other samples are skipped
+fn finite_values<X, Y: NumCast, I: IntoIterator<Item = (X, Y)>>(
+    data: I,
+) -> impl Iterator<Item = (X, f64)> {
+    data.into_iter().filter_map(|(x, y)| match y.to_f64() {
+        Some(y) if y.is_finite() => Some((x, y)),
+        _ => None,
+    })
+}
+
+/// A point of the Bollinger bands
+#[derive(Clone, Debug, PartialEq)]
+pub struct BollingerBand<X> {
+    /// The x value of the point
+    pub x: X,
+    /// The rolling mean of the window
+    pub middle: f64,
+    /// The middle line minus the multiple of the standard deviation
+    pub lower: f64,
+    /// The middle line plus the multiple of the standard deviation
+    pub upper: f64,
+}
+
+/// Compute the Bollinger bands of a series, which are the rolling mean of the last `window`
+/// values and the bands of `k` standard deviations of the window around it. The points start
+/// when the first window is full, and the values which aren't finite are skipped.
+///
+/// - `data`: The `(x, value)` samples of the series, such as the close prices
+/// - `window`: The number of values of the rolling window, usually 20
+/// - `k`: The number of standard deviations between the middle line and the bands, usually 2
+/// - **returns** The points of the bands
+///
+/// ```rust
+/// use plotters::data::bollinger_bands;
+///
+/// let bands = bollinger_bands(vec![(0, 1.0), (1, 3.0), (2, 1.0), (3, 3.0)], 2, 2.0);
+/// assert_eq!(bands.len(), 3);
+/// assert_eq!((bands[0].x, bands[0].middle), (1, 2.0));
+/// assert_eq!((bands[0].lower, bands[0].upper), (0.0, 4.0));
+/// ```
+pub fn bollinger_bands<X, Y: NumCast, I: IntoIterator<Item = (X, Y)>>(
+    data: I,
+    window: usize,
+    k: f64,
+) -> Vec<BollingerBand<X>> {
+    let window = window.max(1);
+    // The rolling mean of the squares goes along with the x values through the rolling mean of
+    // the values, the variance of the window is the mean of the squares minus the squared mean
+    let mut squares = SlidingWindow::new(window);
+    let values = finite_values(data).map(move |(x, value)| {
+        squares.push(value * value);
+        ((x, squares.len(), squares.mean().unwrap_or(0.0)), value)
+    });
+    MovingAverage::mean(values, window)
+        .filter(|&((_, len, _), _)| len == window)
+        .map(|((x, _, mean_square), middle): (_, f64)| {
+            let delta = k * (mean_square - middle * middle).max(0.0).sqrt();
+            BollingerBand {
+                x,
+                middle,
+                lower: middle - delta,
+                upper: middle + delta,
+            }
+        })
+        .collect()
+}
+
+/// Compute the relative strength index of a series with the smoothing of Wilder, which is
+/// the momentum of the series in the range of 0 to 100. It's usually drawn in a panel below
+/// the price chart, with the lines at 30 and 70. The points start after the first `period`
+/// changes, and the values which aren't finite are skipped.
+///
+/// - `data`: The `(x, value)` samples of the series, such as the close prices
+/// - `period`: The number of changes which are averaged, usually 14
+/// - **returns** The `(x, rsi)` points
+///
+/// ```rust
+/// use plotters::data::rsi;
+///
+/// let prices = vec![(0, 10.0), (1, 11.0), (2, 10.5), (3, 12.0)];
+/// let index = rsi(prices, 2);
+/// assert_eq!(index.len(), 2);
+/// assert!((index[0].1 - 100.0 * 2.0 / 3.0).abs() < 1e-9);
+/// ```
+pub fn rsi<X, Y: NumCast, I: IntoIterator<Item = (X, Y)>>(data: I, period: usize) -> Vec<(X, f64)> {
+    let period = period.max(1);
+    let n = period as f64;
+    let (mut gain, mut loss) = (0.0, 0.0);
+    let mut changes = 0;
+    let mut last = None;
+    let mut index = vec![];
+    for (x, value) in finite_values(data) {
+        let prev = last.replace(value);
+        let change = match prev {
+            Some(prev) => value - prev,
+            None => continue,
+        };
+        changes += 1;
+        let (up, down) = (change.max(0.0), (-change).max(0.0));
+        if changes <= period {
+            // The first averages are the simple means of the changes
+            gain += up / n;
+            loss += down / n;
+            if changes < period {
+                continue;
+            }
+        } else {
+            gain = (gain * (n - 1.0) + up) / n;
+            loss = (loss * (n - 1.0) + down) / n;
+        }
+        let value = if gain + loss == 0.0 {
+            50.0
+        } else {
+            100.0 * gain / (gain + loss)
+        };
+        index.push((x, value));
+    }
+    index
+}
+
+/// A point of the moving average convergence divergence
+#[derive(Clone, Debug, PartialEq)]
+pub struct MacdPoint<X> {
+    /// The x value of the point
+    pub x: X,
+    /// The fast exponential moving average minus the slow one
+    pub macd: f64,
+    /// The exponential moving average of the MACD line
+    pub signal: f64,
+    /// The MACD line minus the signal line, which is usually drawn as bars
+    pub histogram: f64,
+}
+
+/// Compute the moving average convergence divergence of a series, which is the difference
+/// of a fast and a slow exponential moving average, with the signal line smoothing it. The
+/// points start when the slow average has `slow` values, and the values which aren't finite
+/// are skipped.
+///
+/// - `data`: The `(x, value)` samples of the series, such as the close prices
+/// - `fast`: The period of the fast moving average, usually 12
+/// - `slow`: The period of the slow moving average, usually 26
+/// - `signal`: The period of the moving average of the signal line, usually 9
+/// - **returns** The points of the MACD
+///
+/// ```rust
+/// use plotters::data::macd;
+///
+/// let prices: Vec<_> = (0..100).map(|x| (x, x as f64)).collect();
+/// let points = macd(prices, 12, 26, 9);
+/// assert_eq!(points.len(), 75);
+/// // The fast average is ahead of the slow one when the price rises
+/// assert!(points.iter().all(|p| p.macd > 0.0));
+/// ```
+pub fn macd<X, Y: NumCast, I: IntoIterator<Item = (X, Y)>>(
+    data: I,
+    fast: usize,
+    slow: usize,
+    signal: usize,
+) -> Vec<MacdPoint<X>> {
+    let alpha = |period: usize| 2.0 / (period.max(1) as f64 + 1.0);
+    let (fast_alpha, slow_alpha, signal_alpha) = (alpha(fast), alpha(slow), alpha(signal));
+    let ema = |last: Option<f64>, value: f64, alpha: f64| match last {
+        Some(last) => alpha * value + (1.0 - alpha) * last,
+        None => value,
+    };
+    let (mut fast_ema, mut slow_ema, mut signal_ema) = (None, None, None);
+    let mut points = vec![];
+    for (idx, (x, value)) in finite_values(data).enumerate() {
+        let (fast_value, slow_value) = (
+            ema(fast_ema, value, fast_alpha),
+            ema(slow_ema, value, slow_alpha),
+        );
+        fast_ema = Some(fast_value);
+        slow_ema = Some(slow_value);
+        if idx + 1 < slow.max(fast) {
+            continue;
+        }
+        let macd = fast_value - slow_value;
+        let signal = ema(signal_ema, macd, signal_alpha);
+        signal_ema = Some(signal);
+        points.push(MacdPoint {
+            x,
+            macd,
+            signal,
+            histogram: macd - signal,
+        });
+    }
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bollinger_bands() {
+        let data = vec![
+            (0, 2.0),
+            (1, 4.0),
+            (2, f64::NAN),
+            (3, 4.0),
+            (4, 4.0),
+            (5, 8.0),
+        ];
+        let bands = bollinger_bands(data, 3, 1.0);
+        assert_eq!(bands.len(), 3);
+        assert_eq!(bands[0].x, 3);
+        assert!((bands[0].middle - 10.0 / 3.0).abs() < 1e-9);
+        assert!((bands[0].upper - bands[0].middle - (8.0f64 / 9.0).sqrt()).abs() < 1e-9);
+        // A constant window has no width
+        assert_eq!(
+            bands[1],
+            BollingerBand {
+                x: 4,
+                middle: 4.0,
+                lower: 4.0,
+                upper: 4.0
+            }
+        );
+        assert!(bollinger_bands(vec![(0, 1.0)], 2, 2.0).is_empty());
+    }
+
+    #[test]
+    fn test_rsi() {
+        // The rising series is at the top of the range, and the constant one in the middle
+        let rising: Vec<_> = (0..20).map(|x| (x, x as f64)).collect();
+        assert!(rsi(rising, 14).iter().all(|(_, v)| *v == 100.0));
+        let flat: Vec<_> = (0..20).map(|x| (x, 1.0)).collect();
+        assert!(rsi(flat, 14).iter().all(|(_, v)| *v == 50.0));
+
+        let prices = vec![(0, 10.0), (1, 11.0), (2, 10.5), (3, 12.0), (4, 11.0)];
+        let index = rsi(prices, 2);
+        assert_eq!(
+            index.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        // The averages are smoothed: gain (0.5 + 1.5) / 2 = 1, loss (0.25 + 0) / 2 = 0.125
+        assert!((index[1].1 - 100.0 / 1.125).abs() < 1e-9);
+        // Gain 0.5 and loss (0.125 + 1) / 2
+        assert!((index[2].1 - 100.0 * 0.5 / (0.5 + 0.5625)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_macd() {
+        let flat: Vec<_> = (0..40).map(|x| (x, 5.0)).collect();
+        let points = macd(flat, 12, 26, 9);
+        assert_eq!(points.len(), 15);
+        assert_eq!(points[0].x, 25);
+        assert!(points
+            .iter()
+            .all(|p| p.macd == 0.0 && p.signal == 0.0 && p.histogram == 0.0));
+
+        // The histogram turns negative when the series falls
+        let mut data: Vec<_> = (0..40).map(|x| (x, x as f64)).collect();
+        data.extend((40..60).map(|x| (x, 80.0 - x as f64)));
+        let points = macd(data, 3, 6, 3);
+        assert!(points[30].histogram > 0.0);
+        assert!(points.last().unwrap().histogram < 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+use super::Quartiles;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::ops::Range;
+
+/// The rule used to select the bandwidth of the kernel density estimation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb: `0.9 * min(stddev, IQR / 1.34) * n^(-1/5)`
+    Silverman,
+    /// Scott's rule: `1.06 * stddev * n^(-1/5)`
+    Scott,
+    /// Use the given bandwidth
+    Fixed(f64),
+}
+
+/// The Gaussian kernel density estimation of a set of samples.
+///
+/// ```rust
+/// use plotters::data::{Bandwidth, KernelDensity};
+///
+/// let kde = KernelDensity::new(&[1.0, 2.0, 3.0], Bandwidth::Fixed(1.0));
+/// assert!(kde.density(2.0) > kde.density(0.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct KernelDensity {
+    samples: Vec<f64>,
+    bandwidth: f64,
+}
+
+impl KernelDensity {
+    /// Create the kernel density estimation from the samples, non-finite samples are ignored.
+    ///
+    /// - `samples`: The raw samples
+    /// - `bandwidth`: The bandwidth selection rule
+    /// - **returns** The kernel density estimation
+    pub fn new<T: Into<f64> + Copy>(samples: &[T], bandwidth: Bandwidth) -> Self {
+        let mut samples: Vec<f64> = samples
+            .iter()
+            .map(|&x| x.into())
+            .filter(|x: &f64| x.is_finite())
+            .collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let n = samples.len() as f64;
+        let bandwidth = match bandwidth {
+            Bandwidth::Fixed(value) => value,
+            _ if samples.len() < 2 => 1.0,
+            rule => {
+                let mean = samples.iter().sum::<f64>() / n;
+                let sd =
+                    (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+                let spread = if rule == Bandwidth::Silverman {
+                    let iqr = Quartiles::percentile_of_sorted(&samples, 75.0)
+                        - Quartiles::percentile_of_sorted(&samples, 25.0);
+                    if iqr > 0.0 {
+                        sd.min(iqr / 1.34)
+                    } else {
+                        sd
+                    }
+                } else {
+                    sd
+                };
+                let factor = if rule == Bandwidth::Silverman {
+                    0.9
+                } else {
+                    1.06
+                };
+                factor * spread * n.powf(-0.2)
+            }
+        };
+
+        Self {
+            samples,
+            bandwidth: if bandwidth.is_finite() && bandwidth > 0.0 {
+                bandwidth
+            } else {
+                1.0
+            },
+        }
+    }
+
+    /// Get the bandwidth used by the estimation
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    /// Evaluate the estimated probability density at the given point
+    pub fn density(&self, x: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let h = self.bandwidth;
+        let norm = 1.0 / ((2.0 * core::f64::consts::PI).sqrt() * h * self.samples.len() as f64);
+        self.samples
+            .iter()
+            .map(|s| (-0.5 * ((x - s) / h).powi(2)).exp())
+            .sum::<f64>()
+            * norm
+    }
+
+    /// Get the range where the density is noticeable, which is the sample range extended
+    /// by 3 times of the bandwidth on both sides
+    pub fn range(&self) -> Range<f64> {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(lo), Some(hi)) => lo - 3.0 * self.bandwidth..hi + 3.0 * self.bandwidth,
+            _ => 0.0..0.0,
+        }
+    }
+
+    /// Evaluate the density on evenly spaced points of the range
+    /// - `range`: The range to evaluate
+    /// - `count`: The number of points
+    /// - **returns** The list of `(x, density)` points
+    pub fn sample(&self, range: Range<f64>, count: usize) -> Vec<(f64, f64)> {
+        let count = count.max(2);
+        let step = (range.end - range.start) / (count - 1) as f64;
+        (0..count)
+            .map(|idx| {
+                let x = range.start + step * idx as f64;
+                (x, self.density(x))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kde_integrates_to_one() {
+        let samples: Vec<f64> = (0..100).map(|x| ((x * 37) % 100) as f64 / 10.0).collect();
+        for rule in [Bandwidth::Silverman, Bandwidth::Scott].iter() {
+            let kde = KernelDensity::new(&samples, *rule);
+            let points = kde.sample(kde.range(), 1000);
+            let dx = points[1].0 - points[0].0;
+            let area: f64 = points.iter().map(|(_, y)| y * dx).sum();
+            assert!((area - 1.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_kde_bandwidth() {
+        let kde = KernelDensity::new(&[1.0, 2.0, 3.0, 4.0], Bandwidth::Scott);
+        assert!((kde.bandwidth() - 1.06 * 1.6666f64.sqrt() * 4f64.powf(-0.2)).abs() < 1e-3);
+        let kde = KernelDensity::new(&[1.0], Bandwidth::Silverman);
+        assert_eq!(kde.bandwidth(), 1.0);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// Downsample a series with the Largest-Triangle-Three-Buckets algorithm. The points are split
+/// into buckets, and the point of each bucket which makes the largest triangle with the point
+/// picked in the previous bucket and the average of the next bucket is kept. So the peaks and
+/// the overall shape of the series are preserved, while plotting millions of samples stays
+/// fast.
+///
+/// - `data`: The points of the series, sorted by x
+/// - `threshold`: The number of points to keep, the first and the last points are always kept
+/// - **returns** The downsampled points, or all the points if there are no more than `threshold`
+///
+/// ```rust
+/// use plotters::data::lttb;
+///
+/// let data: Vec<_> = (0..1000).map(|x| (x as f64, (x as f64 / 50.0).sin())).collect();
+/// let sampled = lttb(&data, 100);
+/// assert_eq!(sampled.len(), 100);
+/// assert_eq!(sampled[0], data[0]);
+/// assert_eq!(sampled[99], data[999]);
+/// ```
+pub fn lttb<X: Into<f64> + Copy, Y: Into<f64> + Copy>(
+    data: &[(X, Y)],
+    threshold: usize,
+) -> Vec<(X, Y)> {
+    if threshold >= data.len() {
+        return data.to_vec();
+    }
+    if threshold < 3 {
+        // There's only room for the end points
+        return [data[0], data[data.len() - 1]][..threshold].to_vec();
+    }
+
+    let point = |idx: usize| (data[idx].0.into(), data[idx].1.into());
+    // The buckets between the first and the last points
+    let bucket_size = (data.len() - 2) as f64 / (threshold - 2) as f64;
+    let bucket = |idx: usize| {
+        let begin = (idx as f64 * bucket_size) as usize + 1;
+        let end = (((idx + 1) as f64 * bucket_size) as usize + 1).min(data.len() - 1);
+        begin..end
+    };
+
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(data[0]);
+    let mut prev = 0;
+
+    for idx in 0..threshold - 2 {
+        // The average point of the next bucket, which is the last point after the last bucket
+        let next = if idx + 1 < threshold - 2 {
+            bucket(idx + 1)
+        } else {
+            data.len() - 1..data.len()
+        };
+        let n = next.len() as f64;
+        let (ax, ay) = next.fold((0.0, 0.0), |(sx, sy), i| {
+            let (x, y) = point(i);
+            (sx + x / n, sy + y / n)
+        });
+
+        let (px, py): (f64, f64) = point(prev);
+        let mut best = (-1.0, prev);
+        for i in bucket(idx) {
+            let (x, y) = point(i);
+            let area = ((px - ax) * (y - py) - (px - x) * (ay - py)).abs();
+            if area > best.0 {
+                best = (area, i);
+            }
+        }
+
+        prev = best.1;
+        sampled.push(data[prev]);
+    }
+
+    sampled.push(data[data.len() - 1]);
+    sampled
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lttb_keeps_peaks() {
+        let mut data: Vec<(f64, f64)> = (0..100).map(|x| (x as f64, 0.0)).collect();
+        data[42].1 = 10.0;
+        data[77].1 = -5.0;
+        let sampled = lttb(&data, 10);
+        assert_eq!(sampled.len(), 10);
+        assert!(sampled.contains(&(42.0, 10.0)));
+        assert!(sampled.contains(&(77.0, -5.0)));
+        assert!(sampled.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_lttb_small_threshold() {
+        let data = [(0, 1), (1, 2), (2, 3)];
+        assert_eq!(lttb(&data, 5), data.to_vec());
+        assert_eq!(lttb(&data, 2), vec![(0, 1), (2, 3)]);
+        assert!(lttb(&data, 0).is_empty());
+        assert!(lttb::<i32, i32>(&[], 2).is_empty());
+    }
+}
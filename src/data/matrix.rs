@@ -0,0 +1,224 @@
+use super::{indexed_series, GridValues};
+use nalgebra::{Dim, Matrix, Matrix3, Matrix4, Point2, Point3, RawStorage, Scalar, Vector, U2};
+
+/// Turn the `nalgebra` 2D points into a series, which can be drawn as a line or point series
+/// directly.
+///
+/// - `points`: The points of the series
+/// - **returns** The iterator of `(x, y)` points
+///
+/// ```rust
+/// use nalgebra::Point2;
+/// use plotters::data::point2_series;
+///
+/// let points = vec![Point2::new(1.0, 2.0), Point2::new(3.0, 4.5)];
+/// let series: Vec<_> = point2_series(&points).collect();
+/// assert_eq!(series, vec![(1.0, 2.0), (3.0, 4.5)]);
+/// ```
+pub fn point2_series<'a, T, I>(points: I) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    T: Scalar + Into<f64> + Copy,
+    I: IntoIterator<Item = &'a Point2<T>>,
+    I::IntoIter: 'a,
+{
+    points.into_iter().map(|p| (p.x.into(), p.y.into()))
+}
+
+/// Turn a `nalgebra` vector, such as a `DVector` or a column of a matrix, into a series with
+/// the index of each value as its x, see `indexed_series`.
+///
+/// - `vector`: The vector of the values
+/// - **returns** The iterator of `(index, value)` points
+///
+/// ```rust
+/// use nalgebra::DVector;
+/// use plotters::data::vector_series;
+///
+/// let values = DVector::from_vec(vec![3.0, 1.5, 2.0]);
+/// let series: Vec<_> = vector_series(&values).collect();
+/// assert_eq!(series, vec![(0.0, 3.0), (1.0, 1.5), (2.0, 2.0)]);
+/// ```
+pub fn vector_series<'a, T, D, S>(
+    vector: &'a Vector<T, D, S>,
+) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    T: Scalar + Into<f64> + Copy,
+    D: Dim,
+    S: RawStorage<T, D>,
+{
+    indexed_series(vector.iter())
+}
+
+/// Turn a `nalgebra` matrix with two rows, each column of which is a point, into a series.
+/// It's the layout of the point sets of the robotics and the geometry code, such as a
+/// `Matrix2xX` of the scanned points.
+///
+/// - `matrix`: The matrix of the points, the x in the first row and the y in the second
+/// - **returns** The iterator of `(x, y)` points
+///
+/// ```rust
+/// use nalgebra::Matrix2xX;
+/// use plotters::data::matrix_points;
+///
+/// let points = Matrix2xX::from_column_slice(&[0.0, 1.0, 2.0, 3.0]);
+/// let series: Vec<_> = matrix_points(&points).collect();
+/// assert_eq!(series, vec![(0.0, 1.0), (2.0, 3.0)]);
+/// ```
+pub fn matrix_points<'a, T, C, S>(
+    matrix: &'a Matrix<T, U2, C, S>,
+) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    T: Scalar + Into<f64> + Copy,
+    C: Dim,
+    S: RawStorage<T, U2, C>,
+{
+    matrix
+        .column_iter()
+        .map(|column| (column[0].into(), column[1].into()))
+}
+
+/// Split a `nalgebra` matrix into its columns, which is the input of `Spectrogram`, see
+/// `array2_columns`.
+///
+/// - `matrix`: The matrix, each row of which is a row of the grid
+/// - **returns** The columns of the matrix
+///
+/// ```rust
+/// use nalgebra::Matrix2x3;
+/// use plotters::data::matrix_columns;
+///
+/// let columns = matrix_columns(&Matrix2x3::new(1, 2, 3, 4, 5, 6));
+/// assert_eq!(columns, vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+/// ```
+pub fn matrix_columns<T, R, C, S>(matrix: &Matrix<T, R, C, S>) -> Vec<Vec<f64>>
+where
+    T: Scalar + Into<f64> + Copy,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    matrix
+        .column_iter()
+        .map(|column| column.iter().map(|v| (*v).into()).collect())
+        .collect()
+}
+
+/// Map the points of a series with a 2D affine transformation, given as its homogeneous
+/// matrix, such as the `to_homogeneous` of a `nalgebra::Isometry2` or `Similarity2`. So a
+/// shape, or the trajectory of a robot, can be drawn in the frame of the world.
+///
+/// - `points`: The points of the series
+/// - `transform`: The homogeneous matrix of the transformation
+/// - **returns** The iterator of the transformed points
+///
+/// ```rust
+/// use nalgebra::{Isometry2, Vector2};
+/// use plotters::data::affine_series;
+///
+/// let pose = Isometry2::new(Vector2::new(1.0, 0.0), std::f64::consts::FRAC_PI_2);
+/// let series: Vec<_> = affine_series(vec![(1.0, 0.0)], &pose.to_homogeneous()).collect();
+/// assert!((series[0].0 - 1.0).abs() < 1e-9 && (series[0].1 - 1.0).abs() < 1e-9);
+/// ```
+pub fn affine_series<'a, X, Y, I>(
+    points: I,
+    transform: &'a Matrix3<f64>,
+) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    X: Into<f64>,
+    Y: Into<f64>,
+    I: IntoIterator<Item = (X, Y)>,
+    I::IntoIter: 'a,
+{
+    points.into_iter().map(move |(x, y)| {
+        let p = transform.transform_point(&Point2::new(x.into(), y.into()));
+        (p.x, p.y)
+    })
+}
+
+/// Project the `nalgebra` 3D points onto the plane of a chart, with a homogeneous matrix such
+/// as the product of a `nalgebra::Perspective3` or `Orthographic3` and the view matrix of a
+/// camera. The points are divided by their `w`, so the perspective projections work.
+///
+/// - `points`: The 3D points
+/// - `projection`: The homogeneous matrix of the projection
+/// - **returns** The iterator of the projected `(x, y)` points
+///
+/// ```rust
+/// use nalgebra::{Matrix4, Point3, Vector3};
+/// use plotters::data::projected_series;
+///
+/// let view = Matrix4::new_translation(&Vector3::new(0.0, 0.0, -2.0));
+/// let points = vec![Point3::new(1.0, 2.0, 0.0)];
+/// let series: Vec<_> = projected_series(&points, &view).collect();
+/// assert_eq!(series, vec![(1.0, 2.0)]);
+/// ```
+pub fn projected_series<'a, T, I>(
+    points: I,
+    projection: &'a Matrix4<f64>,
+) -> impl Iterator<Item = (f64, f64)> + 'a
+where
+    T: Scalar + Into<f64> + Copy,
+    I: IntoIterator<Item = &'a Point3<T>>,
+    I::IntoIter: 'a,
+{
+    points.into_iter().map(move |p| {
+        let p = Point3::new(p.x.into(), p.y.into(), p.z.into());
+        let p = projection.transform_point(&p);
+        (p.x, p.y)
+    })
+}
+
+impl GridValues {
+    /// Create the grid values of a heatmap from a `nalgebra` matrix, with the indices of the
+    /// cells as the coordinates: the value of row `j` and column `i` is at `(i, j)`.
+    ///
+    /// ```rust
+    /// use nalgebra::DMatrix;
+    /// use plotters::data::GridValues;
+    ///
+    /// let matrix = DMatrix::from_fn(2, 3, |row, col| (row * 3 + col) as f64);
+    /// let grid = GridValues::from_matrix(&matrix);
+    /// assert_eq!((grid.xs(), grid.ys()), (&[0.0, 1.0, 2.0][..], &[0.0, 1.0][..]));
+    /// assert_eq!(grid.z_range(), Some(0.0..5.0));
+    /// ```
+    pub fn from_matrix<T, R, C, S>(matrix: &Matrix<T, R, C, S>) -> Self
+    where
+        T: Scalar + Into<f64> + Copy,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+    {
+        let (rows, cols) = matrix.shape();
+        let xs = (0..cols).map(|x| x as f64).collect();
+        let ys = (0..rows).map(|y| y as f64).collect();
+        // The matrix is stored column by column, the grid is row major
+        let z = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| matrix[(row, col)].into()))
+            .collect();
+        Self::new(xs, ys, z).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nalgebra::{DMatrix, Matrix2xX, Rotation2};
+
+    #[test]
+    fn test_nalgebra_adaptors() {
+        let matrix = DMatrix::from_row_slice(2, 2, &[1.0f32, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            matrix_columns(&matrix),
+            vec![vec![1.0, 3.0], vec![2.0, 4.0]]
+        );
+        let grid = GridValues::from_matrix(&matrix);
+        assert_eq!(grid.cells().nth(1).map(|(_, _, z)| z), Some(2.0));
+        let series: Vec<_> = vector_series(&matrix.column(1)).collect();
+        assert_eq!(series, vec![(0.0, 2.0), (1.0, 4.0)]);
+        assert_eq!(matrix_points(&Matrix2xX::<f64>::zeros(0)).count(), 0);
+
+        let rotation = Rotation2::new(core::f64::consts::PI).to_homogeneous();
+        let (x, y) = affine_series(vec![(2, 1)], &rotation).next().unwrap();
+        assert!((x + 2.0).abs() < 1e-9 && (y + 1.0).abs() < 1e-9);
+    }
+}
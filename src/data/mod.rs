@@ -3,10 +3,64 @@ The data processing module, which implements algorithms related to visualization
 Such as, down-sampling, etc.
 */
 
+mod array;
+#[cfg(feature = "ndarray")]
+pub use array::{array1_series, array2_columns};
+pub use array::{indexed_series, row_major_columns};
+
+#[cfg(feature = "arrow")]
+mod arrow_columns;
+#[cfg(feature = "arrow")]
+pub use arrow_columns::ArrowColumns;
+
+mod binning;
+pub use binning::{BinNormalization, BinningStrategy, Bins};
+
+#[cfg(feature = "csv")]
+mod csv_columns;
+#[cfg(feature = "csv")]
+pub use csv_columns::CsvColumns;
+
 mod data_range;
-pub use data_range::fitting_range;
+pub use data_range::{fitting_range, padded_fitting_range};
+
+mod grid;
+pub use grid::GridValues;
+
+mod indicators;
+pub use indicators::{bollinger_bands, macd, rsi, BollingerBand, MacdPoint};
+
+mod kde;
+pub use kde::{Bandwidth, KernelDensity};
+
+#[cfg(feature = "nalgebra")]
+mod matrix;
+#[cfg(feature = "nalgebra")]
+pub use matrix::{
+    affine_series, matrix_columns, matrix_points, point2_series, projected_series, vector_series,
+};
+
+mod lttb;
+pub use lttb::lttb;
+
+mod outliers;
+pub use outliers::{split_outliers, split_outliers_by, OutlierRule};
 
 mod quartiles;
 pub use quartiles::Quartiles;
 
+mod regression;
+pub use regression::PolynomialFit;
+
+mod resample;
+#[cfg(feature = "chrono")]
+pub use resample::floor_to_duration;
+pub use resample::{floor_to_step, resample_mean, resample_ohlc, resample_with, Ohlc};
+
+mod streaming;
+pub use streaming::{Reservoir, RingBuffer, SlidingWindow};
+
+mod summary;
+pub use summary::SummaryStats;
+
 pub mod float;
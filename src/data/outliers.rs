@@ -0,0 +1,111 @@
+use super::SummaryStats;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The rule which decides if a value is an outlier
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutlierRule {
+    /// The values farther than `k` times the interquartile range from the quartiles are
+    /// outliers, with `k = 1.5` they are the values beyond the fences of a box plot
+    Iqr(f64),
+    /// The values farther than `k` standard deviations from the mean are outliers
+    ZScore(f64),
+}
+
+impl Default for OutlierRule {
+    fn default() -> Self {
+        OutlierRule::Iqr(1.5)
+    }
+}
+
+impl OutlierRule {
+    // The range of the values which are not outliers
+    fn bounds(&self, stats: &SummaryStats) -> (f64, f64) {
+        match *self {
+            OutlierRule::Iqr(k) => (
+                stats.lower_quartile() - k * stats.iqr(),
+                stats.upper_quartile() + k * stats.iqr(),
+            ),
+            OutlierRule::ZScore(k) => (
+                stats.mean() - k * stats.population_std_dev(),
+                stats.mean() + k * stats.population_std_dev(),
+            ),
+        }
+    }
+}
+
+/// Split the samples into the inliers and the outliers by the values given by a key function,
+/// for example, the Y values of the points for drawing the outliers as separate markers
+///
+/// - `samples`: The samples to split, the order is kept in both parts
+/// - `key`: The function which gives the value of a sample, the NaN values are outliers
+/// - `rule`: The rule which decides if a value is an outlier
+/// - **returns** The inliers and the outliers
+///
+/// ```rust
+/// use plotters::data::{split_outliers_by, OutlierRule};
+///
+/// let points = vec![(0, 1.0), (1, 1.2), (2, 0.9), (3, 9.0), (4, 1.1)];
+/// let (inliers, outliers) = split_outliers_by(points, |p| p.1, OutlierRule::default());
+/// assert_eq!(inliers.len(), 4);
+/// assert_eq!(outliers, vec![(3, 9.0)]);
+/// ```
+pub fn split_outliers_by<T, I, F>(samples: I, key: F, rule: OutlierRule) -> (Vec<T>, Vec<T>)
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> f64,
+{
+    let samples: Vec<T> = samples.into_iter().collect();
+    let stats = match SummaryStats::new(samples.iter().map(&key)) {
+        Some(stats) => stats,
+        None => return (vec![], samples),
+    };
+    let (lo, hi) = rule.bounds(&stats);
+    samples.into_iter().partition(|s| {
+        let v = key(s);
+        lo <= v && v <= hi
+    })
+}
+
+/// Split the values into the inliers and the outliers
+///
+/// - `values`: The values to split, the order is kept in both parts
+/// - `rule`: The rule which decides if a value is an outlier
+/// - **returns** The inliers and the outliers
+///
+/// ```rust
+/// use plotters::data::{split_outliers, OutlierRule};
+///
+/// let (inliers, outliers) = split_outliers(vec![1, 2, 3, 2, 100], OutlierRule::ZScore(1.5));
+/// assert_eq!(inliers, vec![1, 2, 3, 2]);
+/// assert_eq!(outliers, vec![100]);
+/// ```
+pub fn split_outliers<T: Into<f64> + Copy, I: IntoIterator<Item = T>>(
+    values: I,
+    rule: OutlierRule,
+) -> (Vec<T>, Vec<T>) {
+    split_outliers_by(values, |v| (*v).into(), rule)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::Quartiles;
+
+    #[test]
+    fn test_split_outliers() {
+        let values = [7.0, 15.0, 36.0, 39.0, 40.0, 41.0, -10.0, 200.0, f64::NAN];
+        let (inliers, outliers) = split_outliers(values.iter().copied(), OutlierRule::default());
+        // The default rule agrees with the fences of the quartiles
+        let fences = Quartiles::new(&values[..8]).values();
+        assert!(inliers
+            .iter()
+            .all(|&v| v >= fences[0] as f64 && v <= fences[4] as f64));
+        assert_eq!(inliers.len(), 7);
+        assert_eq!(outliers.len(), 2);
+        assert!(outliers[1].is_nan());
+
+        let (inliers, outliers) = split_outliers(Vec::<f64>::new(), OutlierRule::ZScore(2.0));
+        assert!(inliers.is_empty() && outliers.is_empty());
+    }
+}
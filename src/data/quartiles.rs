@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
 /// The quartiles
 #[derive(Clone, Debug)]
 pub struct Quartiles {
@@ -11,7 +14,7 @@ pub struct Quartiles {
 impl Quartiles {
     // Extract a value representing the `pct` percentile of a
     // sorted `s`, using linear interpolation.
-    fn percentile_of_sorted<T: Into<f64> + Copy>(s: &[T], pct: f64) -> f64 {
+    pub(crate) fn percentile_of_sorted<T: Into<f64> + Copy>(s: &[T], pct: f64) -> f64 {
         assert!(!s.is_empty());
         if s.len() == 1 {
             return s[0].into();
@@ -19,7 +22,7 @@ impl Quartiles {
         assert!(0_f64 <= pct);
         let hundred = 100_f64;
         assert!(pct <= hundred);
-        if (pct - hundred).abs() < std::f64::EPSILON {
+        if (pct - hundred).abs() < core::f64::EPSILON {
             return s[s.len() - 1].into();
         }
         let length = (s.len() - 1) as f64;
@@ -46,10 +49,14 @@ impl Quartiles {
     pub fn new<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Self {
         let mut s = s.to_owned();
         s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Self::from_sorted(&s)
+    }
 
-        let lower = Quartiles::percentile_of_sorted(&s, 25_f64);
-        let median = Quartiles::percentile_of_sorted(&s, 50_f64);
-        let upper = Quartiles::percentile_of_sorted(&s, 75_f64);
+    // Compute the quartiles of the values which are already sorted
+    pub(crate) fn from_sorted<T: Into<f64> + Copy>(s: &[T]) -> Self {
+        let lower = Quartiles::percentile_of_sorted(s, 25_f64);
+        let median = Quartiles::percentile_of_sorted(s, 50_f64);
+        let upper = Quartiles::percentile_of_sorted(s, 75_f64);
         let iqr = upper - lower;
         let lower_fence = lower - 1.5 * iqr;
         let upper_fence = upper + 1.5 * iqr;
@@ -0,0 +1,222 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The result of a least-squares polynomial fit over a set of points.
+///
+/// The fitted polynomial is `c[0] + c[1] * x + c[2] * x^2 + ...`, where `c` is the
+/// coefficient list returned by `coefficients`.
+#[derive(Clone, Debug)]
+pub struct PolynomialFit {
+    coefficients: Vec<f64>,
+    // The fit is computed on the x values centered at `offset` and divided by `scale`, which
+    // keeps the normal equations well conditioned for the tiny values and the timestamps
+    scaled: Vec<f64>,
+    offset: f64,
+    scale: f64,
+    r_squared: f64,
+}
+
+impl PolynomialFit {
+    /// Compute the ordinary-least-squares linear fit of the points.
+    ///
+    /// - `points`: The input points
+    /// - **returns** The fit, or `None` if there are less than 2 distinct x values
+    ///
+    /// ```rust
+    /// use plotters::data::PolynomialFit;
+    ///
+    /// let fit = PolynomialFit::linear(vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+    /// assert!((fit.slope() - 2.0).abs() < 1e-9);
+    /// assert!((fit.intercept() - 1.0).abs() < 1e-9);
+    /// assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn linear<X: Into<f64>, Y: Into<f64>, I: IntoIterator<Item = (X, Y)>>(
+        points: I,
+    ) -> Option<Self> {
+        Self::new(points, 1)
+    }
+
+    /// Compute the least-squares polynomial fit of the points.
+    ///
+    /// - `points`: The input points
+    /// - `degree`: The degree of the polynomial
+    /// - **returns** The fit, or `None` if the points can not determine a polynomial of the given degree
+    pub fn new<X: Into<f64>, Y: Into<f64>, I: IntoIterator<Item = (X, Y)>>(
+        points: I,
+        degree: usize,
+    ) -> Option<Self> {
+        let points: Vec<(f64, f64)> = points
+            .into_iter()
+            .map(|(x, y)| (x.into(), y.into()))
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+
+        let n = degree + 1;
+        if points.len() < n {
+            return None;
+        }
+
+        let offset = points.iter().map(|(x, _)| x).sum::<f64>() / points.len() as f64;
+        let scale = points
+            .iter()
+            .map(|(x, _)| (x - offset).abs())
+            .fold(0.0, f64::max);
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+
+        // Build the normal equations (A^T A) c = A^T y as an augmented matrix
+        let mut matrix = vec![vec![0.0; n + 1]; n];
+        for &(x, y) in points.iter() {
+            let x = (x - offset) / scale;
+            let mut powers = vec![1.0; 2 * n - 1];
+            for i in 1..powers.len() {
+                powers[i] = powers[i - 1] * x;
+            }
+            for (i, row) in matrix.iter_mut().enumerate() {
+                for (j, value) in row.iter_mut().take(n).enumerate() {
+                    *value += powers[i + j];
+                }
+                row[n] += powers[i] * y;
+            }
+        }
+
+        let scaled = solve_linear_system(matrix)?;
+
+        // Expand the polynomial of (x - offset) / scale into the coefficients of x
+        let mut coefficients = vec![0.0; n];
+        for &c in scaled.iter().rev() {
+            let mut next = vec![0.0; n];
+            for (i, value) in coefficients.iter().enumerate() {
+                next[i] -= value * offset / scale;
+                if i + 1 < n {
+                    next[i + 1] += value / scale;
+                }
+            }
+            next[0] += c;
+            coefficients = next;
+        }
+
+        let mean = points.iter().map(|(_, y)| y).sum::<f64>() / points.len() as f64;
+        let (mut ss_res, mut ss_tot) = (0.0, 0.0);
+        for &(x, y) in points.iter() {
+            ss_res += (y - eval_polynomial(&scaled, (x - offset) / scale)).powi(2);
+            ss_tot += (y - mean).powi(2);
+        }
+        let r_squared = if ss_tot == 0.0 {
+            1.0
+        } else {
+            1.0 - ss_res / ss_tot
+        };
+
+        Some(Self {
+            coefficients,
+            scaled,
+            offset,
+            scale,
+            r_squared,
+        })
+    }
+
+    /// Get the coefficients of the polynomial, from the constant term to the highest degree term
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Get the degree of the fitted polynomial
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Get the constant term of the polynomial, which is the intercept of a linear fit
+    pub fn intercept(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// Get the first order coefficient of the polynomial, which is the slope of a linear fit
+    pub fn slope(&self) -> f64 {
+        self.coefficients.get(1).copied().unwrap_or(0.0)
+    }
+
+    /// Get the coefficient of determination (R²) of the fit
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Evaluate the fitted polynomial at the given point
+    pub fn eval(&self, x: f64) -> f64 {
+        eval_polynomial(&self.scaled, (x - self.offset) / self.scale)
+    }
+}
+
+fn eval_polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+// Solve the augmented linear system with Gaussian elimination and partial pivoting, the
+// system is singular when a pivot is negligible compared to the largest diagonal value
+fn solve_linear_system(mut matrix: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = matrix.len();
+    let tolerance = (0..n).map(|i| matrix[i][i].abs()).fold(0.0, f64::max) * 1e-12;
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| {
+            matrix[a][col]
+                .abs()
+                .partial_cmp(&matrix[b][col].abs())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][col].abs() <= tolerance {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for row in matrix.iter_mut().skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (target, source) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *target -= factor * source;
+            }
+        }
+    }
+
+    let mut result = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| matrix[row][k] * result[k]).sum();
+        result[row] = (matrix[row][n] - sum) / matrix[row][row];
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_polynomial_fit() {
+        let fit = PolynomialFit::new((-10..10).map(|x| (x, 2 * x * x - x + 3)), 2).unwrap();
+        assert_eq!(fit.degree(), 2);
+        for (actual, expected) in fit.coefficients().iter().zip([3.0, -1.0, 2.0].iter()) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+        assert!((fit.eval(20.0) - 783.0).abs() < 1e-6);
+        assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_conditioning() {
+        // The tiny and the huge x values, such as timestamps, are fitted as well
+        let fit = PolynomialFit::linear((0..10).map(|x| (x as f64 * 1e-9, x as f64))).unwrap();
+        assert!((fit.slope() - 1e9).abs() < 1e-3);
+        let points: Vec<_> = (0..10)
+            .map(|x| (1.7e9 + x as f64, (x * x) as f64))
+            .collect();
+        let fit = PolynomialFit::new(points, 2).unwrap();
+        assert!((fit.eval(1.7e9 + 20.0) - 400.0).abs() < 1e-6);
+        assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_degenerated_fit() {
+        assert!(PolynomialFit::linear(vec![(1, 1)]).is_none());
+        assert!(PolynomialFit::linear(vec![(1, 1), (1, 2)]).is_none());
+        let fit = PolynomialFit::linear(vec![(0.0, 0.0), (1.0, 1.0), (2.0, f64::NAN)]);
+        assert!((fit.unwrap().slope() - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Duration, TimeZone};
+
+#[cfg(feature = "candlestick")]
+use crate::element::CandleStick;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+#[cfg(feature = "candlestick")]
+use crate::style::ShapeStyle;
+
+/// The open, high, low and close values of a time bucket
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ohlc<X> {
+    /// The start of the bucket
+    pub time: X,
+    /// The first value in the bucket
+    pub open: f64,
+    /// The largest value in the bucket
+    pub high: f64,
+    /// The smallest value in the bucket
+    pub low: f64,
+    /// The last value in the bucket
+    pub close: f64,
+    /// The number of values in the bucket
+    pub count: usize,
+}
+
+#[cfg(feature = "candlestick")]
+impl<X: Clone> Ohlc<X> {
+    /// Make the candlestick element of the bucket
+    /// - `gain_style`: The style of the candlestick when the close value is higher
+    /// - `loss_style`: The style of the candlestick otherwise
+    /// - `width`: The width of the candlestick
+    pub fn to_candlestick<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        &self,
+        gain_style: GS,
+        loss_style: LS,
+        width: u32,
+    ) -> CandleStick<X, f64> {
+        CandleStick::new(
+            self.time.clone(),
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            gain_style,
+            loss_style,
+            width,
+        )
+    }
+}
+
+/// Group the values of a time series by time buckets and aggregate each bucket. The series
+/// should be sorted by time, the consecutive values in the same bucket are aggregated
+/// together.
+///
+/// - `data`: The `(time, value)` samples of the series
+/// - `bucket`: The function that gives the start of the bucket of a time, such as
+///   `floor_to_step` and `floor_to_duration`
+/// - `aggregate`: The function that aggregates the values of a bucket
+/// - **returns** The start and the aggregated value of each bucket
+///
+/// ```rust
+/// use plotters::data::{floor_to_step, resample_with};
+///
+/// let ticks = vec![(0.0, 1.0), (20.0, 3.0), (65.0, 2.0), (130.0, 4.0), (170.0, 8.0)];
+/// let max = resample_with(ticks, floor_to_step(60.0), |v| v.iter().cloned().fold(0.0, f64::max));
+/// assert_eq!(max, vec![(0.0, 3.0), (60.0, 2.0), (120.0, 8.0)]);
+/// ```
+pub fn resample_with<X, Y, R, I, B, A>(data: I, bucket: B, mut aggregate: A) -> Vec<(X, R)>
+where
+    X: PartialEq,
+    I: IntoIterator<Item = (X, Y)>,
+    B: Fn(&X) -> X,
+    A: FnMut(&[Y]) -> R,
+{
+    let mut result = vec![];
+    let mut current: Option<(X, Vec<Y>)> = None;
+    for (time, value) in data {
+        let start = bucket(&time);
+        match current.as_mut() {
+            Some((key, values)) if *key == start => values.push(value),
+            _ => {
+                if let Some((key, values)) = current.take() {
+                    result.push((key, aggregate(&values)));
+                }
+                current = Some((start, vec![value]));
+            }
+        }
+    }
+    if let Some((key, values)) = current {
+        result.push((key, aggregate(&values)));
+    }
+    result
+}
+
+/// Resample a time series to the mean value of each time bucket, which can be drawn as a
+/// line series
+///
+/// ```rust
+/// use plotters::data::{floor_to_step, resample_mean};
+///
+/// let samples = vec![(0.0, 1.0), (0.5, 3.0), (1.2, 5.0)];
+/// assert_eq!(resample_mean(samples, floor_to_step(1.0)), vec![(0.0, 2.0), (1.0, 5.0)]);
+/// ```
+pub fn resample_mean<X, I, B>(data: I, bucket: B) -> Vec<(X, f64)>
+where
+    X: PartialEq,
+    I: IntoIterator<Item = (X, f64)>,
+    B: Fn(&X) -> X,
+{
+    resample_with(data, bucket, |values| {
+        values.iter().sum::<f64>() / values.len() as f64
+    })
+}
+
+/// Resample a time series to the open, high, low and close values of each time bucket, which
+/// can be drawn as candlesticks
+///
+/// ```rust
+/// use plotters::data::{floor_to_step, resample_ohlc};
+///
+/// let ticks = vec![(0.0, 10.0), (15.0, 12.0), (30.0, 9.0), (45.0, 11.0), (61.0, 11.5)];
+/// let bars = resample_ohlc(ticks, floor_to_step(60.0));
+/// assert_eq!(bars.len(), 2);
+/// assert_eq!((bars[0].open, bars[0].high, bars[0].low, bars[0].close), (10.0, 12.0, 9.0, 11.0));
+/// assert_eq!(bars[1].count, 1);
+/// ```
+pub fn resample_ohlc<X, I, B>(data: I, bucket: B) -> Vec<Ohlc<X>>
+where
+    X: PartialEq,
+    I: IntoIterator<Item = (X, f64)>,
+    B: Fn(&X) -> X,
+{
+    resample_with(data, bucket, |values| {
+        (
+            values[0],
+            values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            values.iter().cloned().fold(f64::INFINITY, f64::min),
+            values[values.len() - 1],
+            values.len(),
+        )
+    })
+    .into_iter()
+    .map(|(time, (open, high, low, close, count))| Ohlc {
+        time,
+        open,
+        high,
+        low,
+        close,
+        count,
+    })
+    .collect()
+}
+
+/// Make the bucket function of numeric times, the buckets are `step` wide and aligned to 0
+/// - `step`: The width of a bucket
+pub fn floor_to_step(step: f64) -> impl Fn(&f64) -> f64 {
+    move |t| (t / step).floor() * step
+}
+
+/// Make the bucket function of date and time, the buckets are aligned to the UNIX epoch, for
+/// example, one minute buckets start at the beginning of each minute
+/// - `duration`: The width of a bucket, which is at least one second
+#[cfg(feature = "chrono")]
+pub fn floor_to_duration<Z: TimeZone>(duration: Duration) -> impl Fn(&DateTime<Z>) -> DateTime<Z> {
+    let step = duration.num_seconds().max(1);
+    move |t| {
+        let offset = t.timestamp().rem_euclid(step);
+        t.clone()
+            - Duration::seconds(offset)
+            - Duration::nanoseconds(i64::from(t.timestamp_subsec_nanos()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resample() {
+        assert!(resample_mean(Vec::<(f64, f64)>::new(), floor_to_step(1.0)).is_empty());
+        assert_eq!(
+            resample_mean(vec![(-0.5, 1.0), (0.5, 2.0)], floor_to_step(1.0)),
+            vec![(-1.0, 1.0), (0.0, 2.0)]
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_resample_by_duration() {
+        use chrono::Utc;
+        let t0 = Utc.with_ymd_and_hms(2020, 1, 1, 10, 0, 30).unwrap();
+        let ticks = vec![
+            (t0, 1.0),
+            (t0 + Duration::seconds(20), 4.0),
+            (t0 + Duration::seconds(40), 2.0),
+        ];
+        let bars = resample_ohlc(ticks, floor_to_duration(Duration::minutes(1)));
+        assert_eq!(bars.len(), 2);
+        assert_eq!(
+            bars[0].time,
+            Utc.with_ymd_and_hms(2020, 1, 1, 10, 0, 0).unwrap()
+        );
+        assert_eq!((bars[0].open, bars[0].close, bars[0].high), (1.0, 4.0, 4.0));
+        assert_eq!(
+            bars[1].time,
+            Utc.with_ymd_and_hms(2020, 1, 1, 10, 1, 0).unwrap()
+        );
+    }
+}
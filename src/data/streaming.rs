@@ -0,0 +1,300 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::collections::VecDeque;
+
+/// A buffer which keeps the latest values of a stream, the oldest value is dropped when a
+/// value is pushed into a full buffer. It's the data source of a chart which is redrawn
+/// repeatedly in real time, since the memory doesn't grow with the length of the stream.
+///
+/// ```rust
+/// use plotters::data::RingBuffer;
+///
+/// let mut buffer = RingBuffer::new(3);
+/// for x in 0..5 {
+///     buffer.push(x);
+/// }
+/// assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RingBuffer<T> {
+    data: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Create a new buffer
+    /// - `capacity`: The maximum number of values in the buffer
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            data: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Push a value into the buffer
+    /// - `value`: The new value
+    /// - **returns** The oldest value if it's dropped to make room for the new value
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(value);
+        }
+        let dropped = if self.data.len() == self.capacity {
+            self.data.pop_front()
+        } else {
+            None
+        };
+        self.data.push_back(value);
+        dropped
+    }
+
+    /// Get the maximum number of values in the buffer
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of values in the buffer
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Iterate over the values, from the oldest to the latest
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.data.iter()
+    }
+
+    /// Drop all the values in the buffer
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+/// The aggregation of the latest values of a stream: the minimum, the maximum and the mean
+/// of the window are updated in constant amortized time for each pushed value.
+///
+/// ```rust
+/// use plotters::data::SlidingWindow;
+///
+/// let mut window = SlidingWindow::new(3);
+/// for &v in [4.0, 1.0, 3.0, 8.0].iter() {
+///     window.push(v);
+/// }
+/// assert_eq!(window.min(), Some(1.0));
+/// assert_eq!(window.max(), Some(8.0));
+/// assert_eq!(window.mean(), Some(4.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SlidingWindow {
+    values: RingBuffer<f64>,
+    sum: f64,
+    // The number of the values pushed so far, which is the index of the next value
+    pushed: u64,
+    // The candidates of the minimum and the maximum with their indices, the values are
+    // increasing in `min_queue` and decreasing in `max_queue`
+    min_queue: VecDeque<(u64, f64)>,
+    max_queue: VecDeque<(u64, f64)>,
+}
+
+impl SlidingWindow {
+    /// Create a new sliding window
+    /// - `size`: The number of the latest values in the window
+    pub fn new(size: usize) -> Self {
+        Self {
+            values: RingBuffer::new(size.max(1)),
+            sum: 0.0,
+            pushed: 0,
+            min_queue: VecDeque::new(),
+            max_queue: VecDeque::new(),
+        }
+    }
+
+    /// Push a value into the window, NaN is ignored
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if let Some(dropped) = self.values.push(value) {
+            self.sum -= dropped;
+        }
+        self.sum += value;
+
+        let idx = self.pushed;
+        self.pushed += 1;
+        let first = self.pushed - self.values.len() as u64;
+
+        while self.min_queue.back().is_some_and(|&(_, v)| v >= value) {
+            self.min_queue.pop_back();
+        }
+        self.min_queue.push_back((idx, value));
+        while self.min_queue.front().is_some_and(|&(i, _)| i < first) {
+            self.min_queue.pop_front();
+        }
+
+        while self.max_queue.back().is_some_and(|&(_, v)| v <= value) {
+            self.max_queue.pop_back();
+        }
+        self.max_queue.push_back((idx, value));
+        while self.max_queue.front().is_some_and(|&(i, _)| i < first) {
+            self.max_queue.pop_front();
+        }
+    }
+
+    /// Get the number of values in the window
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the window is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get the minimum of the window
+    pub fn min(&self) -> Option<f64> {
+        self.min_queue.front().map(|&(_, v)| v)
+    }
+
+    /// Get the maximum of the window
+    pub fn max(&self) -> Option<f64> {
+        self.max_queue.front().map(|&(_, v)| v)
+    }
+
+    /// Get the mean of the window
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.sum / self.len() as f64)
+    }
+
+    /// Iterate over the values of the window, from the oldest to the latest
+    pub fn iter(&self) -> impl Iterator<Item = &f64> + '_ {
+        self.values.iter()
+    }
+}
+
+/// A uniform random sample of a stream with bounded size, every value pushed so far has the
+/// same chance to be in the sample. It keeps the overview of an arbitrarily long stream
+/// within a fixed memory. The sampling is deterministic for a given seed.
+///
+/// ```rust
+/// use plotters::data::Reservoir;
+///
+/// let mut reservoir = Reservoir::new(100);
+/// for x in 0..10000 {
+///     reservoir.push(x);
+/// }
+/// assert_eq!(reservoir.samples().len(), 100);
+/// assert_eq!(reservoir.seen(), 10000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reservoir<T> {
+    samples: Vec<T>,
+    capacity: usize,
+    seen: u64,
+    state: u64,
+}
+
+impl<T> Reservoir<T> {
+    /// Create a new reservoir with the default seed
+    /// - `capacity`: The size of the sample
+    pub fn new(capacity: usize) -> Self {
+        Self::with_seed(capacity, 0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Create a new reservoir with the seed of the random number generator
+    /// - `capacity`: The size of the sample
+    /// - `seed`: The seed of the random number generator
+    pub fn with_seed(capacity: usize, seed: u64) -> Self {
+        Self {
+            samples: Vec::with_capacity(capacity),
+            capacity,
+            seen: 0,
+            state: seed.max(1),
+        }
+    }
+
+    // Xorshift, which is good enough for sampling
+    fn next_random(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Push a value of the stream
+    pub fn push(&mut self, value: T) {
+        self.seen += 1;
+        if self.samples.len() < self.capacity {
+            self.samples.push(value);
+            return;
+        }
+        let idx = self.next_random() % self.seen;
+        if idx < self.capacity as u64 {
+            self.samples[idx as usize] = value;
+        }
+    }
+
+    /// Get the number of values pushed so far
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Get the sample, the values are not in the order they are pushed
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sliding_window() {
+        let mut window = SlidingWindow::new(4);
+        assert_eq!(window.min(), None);
+        assert_eq!(window.mean(), None);
+
+        let values = [5.0, 3.0, 7.0, 1.0, 9.0, 2.0, f64::NAN, 6.0, 8.0, 4.0];
+        let valid: Vec<_> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+        let mut count = 0;
+        for &v in values.iter() {
+            window.push(v);
+            if v.is_nan() {
+                continue;
+            }
+            count += 1;
+            let expected = &valid[count.max(4) - 4..count];
+            let min = expected.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = expected.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let mean = expected.iter().sum::<f64>() / expected.len() as f64;
+            assert_eq!(window.min(), Some(min));
+            assert_eq!(window.max(), Some(max));
+            assert!((window.mean().unwrap() - mean).abs() < 1e-9);
+        }
+        assert_eq!(
+            window.iter().copied().collect::<Vec<_>>(),
+            vec![2.0, 6.0, 8.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn test_reservoir_is_uniform() {
+        let mut reservoir = Reservoir::with_seed(1000, 42);
+        for x in 0..10000 {
+            reservoir.push(x);
+        }
+        // A uniform sample has about the same number of values in each half of the stream
+        let low = reservoir.samples().iter().filter(|&&x| x < 5000).count();
+        assert!(low > 400 && low < 600);
+
+        let mut buffer = RingBuffer::new(0);
+        assert_eq!(buffer.push(1), Some(1));
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use super::Quartiles;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::ops::Range;
+
+/// The summary statistics of a set of values: the count, the extremes, the quartiles, the mean
+/// and the standard deviation. It's the numeric backbone of box plots, violin plots and the
+/// automatic ranging of the axes. The NaN values are ignored.
+///
+/// ```rust
+/// use plotters::data::SummaryStats;
+///
+/// let stats = SummaryStats::new(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.range(), 2.0..9.0);
+/// assert_eq!(stats.median(), 4.5);
+/// assert_eq!(stats.mean(), 5.0);
+/// assert_eq!(stats.population_std_dev(), 2.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SummaryStats {
+    count: usize,
+    min: f64,
+    max: f64,
+    lower: f64,
+    median: f64,
+    upper: f64,
+    mean: f64,
+    // The sum of the squared differences from the mean
+    sum_sq: f64,
+    quartiles: Quartiles,
+}
+
+impl SummaryStats {
+    /// Compute the summary statistics of the values
+    ///
+    /// - `values`: The iterator of the values
+    /// - **returns** The summary statistics, or `None` if there's no value other than NaN
+    pub fn new<T: Into<f64>, I: IntoIterator<Item = T>>(values: I) -> Option<Self> {
+        let mut values: Vec<f64> = values
+            .into_iter()
+            .map(Into::into)
+            .filter(|v: &f64| !v.is_nan())
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let count = values.len();
+        let mean = values.iter().sum::<f64>() / count as f64;
+        let sum_sq = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+        Some(Self {
+            count,
+            min: values[0],
+            max: values[count - 1],
+            lower: Quartiles::percentile_of_sorted(&values, 25.0),
+            median: Quartiles::percentile_of_sorted(&values, 50.0),
+            upper: Quartiles::percentile_of_sorted(&values, 75.0),
+            mean,
+            sum_sq,
+            quartiles: Quartiles::from_sorted(&values),
+        })
+    }
+
+    /// Get the number of values
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the smallest value
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Get the largest value
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Get the range from the smallest value to the largest value
+    pub fn range(&self) -> Range<f64> {
+        self.min..self.max
+    }
+
+    /// Get the lower quartile, which is the 25th percentile
+    pub fn lower_quartile(&self) -> f64 {
+        self.lower
+    }
+
+    /// Get the median
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// Get the upper quartile, which is the 75th percentile
+    pub fn upper_quartile(&self) -> f64 {
+        self.upper
+    }
+
+    /// Get the interquartile range
+    pub fn iqr(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Get the arithmetic mean
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Get the sample standard deviation, which is 0 for a single value
+    pub fn std_dev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        (self.sum_sq / (self.count - 1) as f64).sqrt()
+    }
+
+    /// Get the population standard deviation
+    pub fn population_std_dev(&self) -> f64 {
+        (self.sum_sq / self.count as f64).sqrt()
+    }
+
+    /// Get the quartiles with the fences, which can be drawn as a box plot
+    pub fn quartiles(&self) -> &Quartiles {
+        &self.quartiles
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_summary_stats() {
+        assert!(SummaryStats::new(Vec::<f64>::new()).is_none());
+        assert!(SummaryStats::new(vec![f64::NAN]).is_none());
+
+        let stats = SummaryStats::new(vec![41, 7, 36, 15, 40, 39]).unwrap();
+        assert_eq!(stats.range(), 7.0..41.0);
+        assert_eq!(stats.lower_quartile(), 20.25);
+        assert_eq!(stats.upper_quartile(), 39.75);
+        assert_eq!(stats.iqr(), 19.5);
+        assert_eq!(
+            stats.quartiles().values(),
+            Quartiles::new(&[7, 15, 36, 39, 40, 41]).values()
+        );
+
+        let stats = SummaryStats::new(vec![3.0, f64::NAN]).unwrap();
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.std_dev(), 0.0);
+        assert_eq!(stats.median(), 3.0);
+    }
+}
@@ -1,16 +1,22 @@
 /// The abstraction of a drawing area
-use super::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::coord::{CoordTranslate, MeshLine, Ranged, RangedCoord, Shift};
-use crate::element::{Drawable, PointCollection};
+use super::backend::{BackendCoord, DrawingBackend, DrawingErrorKind, RenderEvent};
+#[cfg(feature = "rayon")]
+use super::RecordingBackend;
+use super::{ClippedBackend, SharedClippedBackend};
+use crate::coord::{CoordTranslate, MeshCell, MeshLine, Ranged, RangedCoord, Shift};
+use crate::element::{draw_styled_text, Drawable, PointCollection, Text};
+use crate::error::ErrorContext;
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{Color, FontDesc, SizeDesc, TextStyle};
+use crate::style::{Color, FontDesc, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle};
 
-use std::borrow::Borrow;
-use std::cell::RefCell;
-use std::error::Error;
-use std::iter::{once, repeat};
-use std::ops::Range;
-use std::rc::Rc;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::borrow::Borrow;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::error::Error;
+use core::iter::{once, repeat};
+use core::ops::Range;
 
 /// The representation of the rectangle in backend canvas
 #[derive(Clone, Debug)]
@@ -72,8 +78,9 @@ impl Rect {
     ) -> impl Iterator<Item = Rect> {
         let mut xs = vec![self.x0, self.x1];
         let mut ys = vec![self.y0, self.y1];
-        xs.extend(x_breaks.map(|v| v + self.x0));
-        ys.extend(y_breaks.map(|v| v + self.y0));
+        // The break points out of the rectangle make empty cells on its border
+        xs.extend(x_breaks.map(|v| (v + self.x0).max(self.x0).min(self.x1)));
+        ys.extend(y_breaks.map(|v| (v + self.y0).max(self.y0).min(self.y1)));
 
         xs.sort();
         ys.sort();
@@ -115,6 +122,8 @@ pub struct DrawingArea<DB: DrawingBackend, CT: CoordTranslate> {
     backend: Rc<RefCell<DB>>,
     rect: Rect,
     coord: CT,
+    // If the context is attached to the errors, see `attach_error_context`
+    error_context: bool,
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, CT> {
@@ -123,11 +132,17 @@ impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, C
             backend: self.copy_backend_ref(),
             rect: self.rect.clone(),
             coord: self.coord.clone(),
+            error_context: self.error_context,
         }
     }
 }
 
 /// The error description of any drawing area API
+///
+/// The errors of the drawing operations are wrapped in `WithContext`, which tells where the
+/// error happened, only when the drawing area is created with
+/// [`attach_error_context`](DrawingArea::attach_error_context), so the code matching on the
+/// other variants should match on [`root_cause`](DrawingAreaErrorKind::root_cause) then.
 #[derive(Debug)]
 pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     /// The error is due to drawing backend failure
@@ -138,16 +153,64 @@ pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     SharingError,
     /// The error caused by invalid layout
     LayoutError,
+    /// The error with the context where it happened, such as which element was being drawn
+    WithContext(ErrorContext, Box<DrawingAreaErrorKind<E>>),
 }
 
-impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl<E: Error + Send + Sync> DrawingAreaErrorKind<E> {
+    /// Attach the context to the error, the fields of the context which are already known
+    /// are kept, since the inner operations know better where the error happened
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            DrawingAreaErrorKind::WithContext(known, inner) => {
+                DrawingAreaErrorKind::WithContext(known.merge(context), inner)
+            }
+            error => DrawingAreaErrorKind::WithContext(context, Box::new(error)),
+        }
+    }
+
+    /// Get the context where the error happened, if it's known
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            DrawingAreaErrorKind::WithContext(context, _) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Get the error without the context
+    ///
+    /// ```rust
+    /// use plotters::drawing::backend::DrawingErrorKind;
+    /// use plotters::drawing::DrawingAreaErrorKind;
+    /// use plotters::error::ErrorContext;
+    ///
+    /// let error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+    /// let error = DrawingAreaErrorKind::BackendError(DrawingErrorKind::DrawingError(error))
+    ///     .with_context(ErrorContext::for_step("series"));
+    ///
+    /// assert!(!matches!(error, DrawingAreaErrorKind::BackendError(_)));
+    /// assert!(matches!(
+    ///     error.root_cause(),
+    ///     DrawingAreaErrorKind::BackendError(DrawingErrorKind::DrawingError(_))
+    /// ));
+    /// ```
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            DrawingAreaErrorKind::WithContext(_, inner) => inner.root_cause(),
+            error => error,
+        }
+    }
+}
+
+impl<E: Error + Send + Sync> core::fmt::Display for DrawingAreaErrorKind<E> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         match self {
             DrawingAreaErrorKind::BackendError(e) => write!(fmt, "backend error: {}", e),
             DrawingAreaErrorKind::SharingError => {
                 write!(fmt, "Multiple backend operation in progress")
             }
             DrawingAreaErrorKind::LayoutError => write!(fmt, "Bad layout"),
+            DrawingAreaErrorKind::WithContext(context, e) => write!(fmt, "{} ({})", e, context),
         }
     }
 }
@@ -169,18 +232,63 @@ impl<'a, DB: DrawingBackend> From<&'a Rc<RefCell<DB>>> for DrawingArea<DB, Shift
     }
 }
 
-/// A type which can be converted into a root drawing area
-pub trait IntoDrawingArea: DrawingBackend + Sized {
-    /// Convert the type into a root drawing area
-    fn into_drawing_area(self) -> DrawingArea<Self, Shift>;
+/// A type which yields a drawing area: any drawing backend, which becomes the root drawing
+/// area, or a drawing area itself. The generic helper functions can accept anything that
+/// yields a drawing area without naming the exact `DrawingArea` type.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// fn draw_dot<A: IntoDrawingArea>(target: A) -> Result<(), PlottersError>
+/// where
+///     <A::Backend as DrawingBackend>::ErrorType: 'static,
+/// {
+///     let area = target.into_drawing_area();
+///     area.draw(&Circle::new((10, 10), 5, RED.filled()))?;
+///     Ok(())
+/// }
+///
+/// let mut buf = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut buf, (100, 100)).into_drawing_area();
+///     let (left, right) = root.split_horizontally(50);
+///     draw_dot(&left).unwrap();
+///     draw_dot(right).unwrap();
+/// }
+/// draw_dot(SVGBackend::with_string(&mut buf, (100, 100))).unwrap();
+/// ```
+pub trait IntoDrawingArea: Sized {
+    /// The drawing backend of the drawing area
+    type Backend: DrawingBackend;
+
+    /// Convert the type into a drawing area
+    fn into_drawing_area(self) -> DrawingArea<Self::Backend, Shift>;
 }
 
 impl<T: DrawingBackend> IntoDrawingArea for T {
+    type Backend = T;
+
     fn into_drawing_area(self) -> DrawingArea<T, Shift> {
         self.into()
     }
 }
 
+impl<DB: DrawingBackend> IntoDrawingArea for DrawingArea<DB, Shift> {
+    type Backend = DB;
+
+    fn into_drawing_area(self) -> DrawingArea<DB, Shift> {
+        self
+    }
+}
+
+impl<DB: DrawingBackend> IntoDrawingArea for &DrawingArea<DB, Shift> {
+    type Backend = DB;
+
+    fn into_drawing_area(self) -> DrawingArea<DB, Shift> {
+        self.clone()
+    }
+}
+
 impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>> {
     /// Draw the mesh on a area
     pub fn draw_mesh<DrawFunc>(
@@ -198,6 +306,79 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
         })
     }
 
+    /// Draw the mesh lines in batches: the selected lines of each axis are drawn with a single
+    /// `DrawingBackend::draw_lines` call, which saves most of the cost of a dense grid
+    /// - `select_func`: The function which is called for each line in order, and decides if
+    ///   the line is drawn
+    /// - `style`: The style of the mesh lines
+    /// - `y_count_max`: The maximum number of the horizontal lines
+    /// - `x_count_max`: The maximum number of the vertical lines
+    pub fn draw_mesh_batched<SelectFunc>(
+        &self,
+        mut select_func: SelectFunc,
+        style: &ShapeStyle,
+        y_count_max: usize,
+        x_count_max: usize,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        SelectFunc: FnMut(&MeshLine<X, Y>) -> bool,
+    {
+        let (mut x_lines, mut y_lines) = (vec![], vec![]);
+        self.backend_ops(move |b| {
+            self.coord.draw_mesh(y_count_max, x_count_max, |line| {
+                if select_func(&line) {
+                    match line {
+                        MeshLine::XMesh(from, to, _) => x_lines.push((from, to)),
+                        MeshLine::YMesh(from, to, _) => y_lines.push((from, to)),
+                    }
+                }
+                Ok(())
+            })?;
+            b.draw_lines(x_lines, style)?;
+            b.draw_lines(y_lines, style)
+        })
+    }
+
+    /// Draw the cells between the mesh lines on a area, which is useful for checkerboards,
+    /// cell-shaded backgrounds and custom grids below the chart
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let area = root.apply_coord_spec(RangedCoord::cartesian_2d(
+    ///     0..10,
+    ///     0..10,
+    ///     (0..300, 0..200),
+    /// ));
+    /// area.draw_mesh_cells(
+    ///     |b, cell| {
+    ///         if (cell.index.0 + cell.index.1) % 2 == 0 {
+    ///             cell.draw(b, &BLACK.mix(0.1).filled())?;
+    ///         }
+    ///         Ok(())
+    ///     },
+    ///     10,
+    ///     10,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn draw_mesh_cells<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_count_max: usize,
+        x_count_max: usize,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshCell<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_mesh_cells(y_count_max, x_count_max, |cell| draw_func(b, cell))
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -223,12 +404,38 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0, self.rect.y0)
     }
 
-    /// Strip the applied coordinate specification and returns a shift-based drawing area
+    /// Strip the applied coordinate specification and returns a shift-based drawing area.
+    /// The new area covers the same region in pixels, so decorations such as watermarks can
+    /// be drawn at fixed positions of a chart, and the coordinate can be applied again with
+    /// `apply_coord_spec`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .build_ranged(0.0..10.0, 0.0..1.0)
+    ///     .unwrap();
+    /// let data_area = chart.plotting_area();
+    ///
+    /// let pixel_area = data_area.strip_coord_spec();
+    /// pixel_area
+    ///     .draw(&Text::new("DRAFT", (10, 10), ("sans-serif", 20)))
+    ///     .unwrap();
+    ///
+    /// let restored = pixel_area.apply_coord_spec(data_area.as_coord_spec().clone());
+    /// assert_eq!(
+    ///     restored.map_coordinate(&(5.0, 0.5)),
+    ///     data_area.map_coordinate(&(5.0, 0.5))
+    /// );
+    /// ```
     pub fn strip_coord_spec(&self) -> DrawingArea<DB, Shift> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.copy_backend_ref(),
             coord: Shift((self.rect.x0, self.rect.y0)),
+            error_context: self.error_context,
         }
     }
 
@@ -255,6 +462,59 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0..self.rect.x1, self.rect.y0..self.rect.y1)
     }
 
+    /// Attach the context to the errors of this drawing area and the areas derived from it, such
+    /// as the split areas and the plotting areas of the charts. The context tells which backend,
+    /// which element and which step of drawing the chart failed. It's off by default, since the
+    /// errors are wrapped in `DrawingAreaErrorKind::WithContext` then.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (100, 100))
+    ///     .into_drawing_area()
+    ///     .attach_error_context(true);
+    /// let error = ChartBuilder::on(&root)
+    ///     .set_label_area_size(LabelAreaPosition::Left, 120)
+    ///     .build_ranged(0..10, 0..10)
+    ///     .err()
+    ///     .unwrap();
+    /// assert!(matches!(error.root_cause(), DrawingAreaErrorKind::LayoutError));
+    /// assert_eq!(error.context().unwrap().step, Some("label areas"));
+    /// ```
+    pub fn attach_error_context(mut self, attach: bool) -> Self {
+        self.error_context = attach;
+        self
+    }
+
+    // Attach the context to the error when it's turned on, see `attach_error_context`
+    pub(crate) fn in_context(
+        &self,
+        error: DrawingAreaError<DB>,
+        context: ErrorContext,
+    ) -> DrawingAreaError<DB> {
+        if self.error_context {
+            error.with_context(context)
+        } else {
+            error
+        }
+    }
+
+    // Attach the step of drawing the chart to the errors, see `in_context`
+    pub(crate) fn in_step(
+        &self,
+        step: &'static str,
+    ) -> impl Fn(DrawingAreaError<DB>) -> DrawingAreaError<DB> {
+        let attach = self.error_context;
+        move |error| {
+            if attach {
+                error.with_context(ErrorContext::for_step(step))
+            } else {
+                error
+            }
+        }
+    }
+
     /// Copy the drawing context
     fn copy_backend_ref(&self) -> Rc<RefCell<DB>> {
         self.backend.clone()
@@ -265,13 +525,14 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         &self,
         ops: O,
     ) -> Result<R, DrawingAreaError<DB>> {
-        if let Ok(mut db) = self.backend.try_borrow_mut() {
+        let result = if let Ok(mut db) = self.backend.try_borrow_mut() {
             db.ensure_prepared()
-                .map_err(DrawingAreaErrorKind::BackendError)?;
-            ops(&mut db).map_err(DrawingAreaErrorKind::BackendError)
+                .and_then(|_| ops(&mut db))
+                .map_err(DrawingAreaErrorKind::BackendError)
         } else {
             Err(DrawingAreaErrorKind::SharingError)
-        }
+        };
+        result.map_err(|e| self.in_context(e, ErrorContext::for_backend::<DB>()))
     }
 
     /// Fill the entire drawing area with a color
@@ -296,11 +557,44 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.draw_pixel(pos, &color.to_rgba()))
     }
 
-    /// Present all the pending changes to the backend
+    /// Present all the pending changes to the backend. The backends saving to files or
+    /// buffers, such as `BitMapBackend` and `SVGBackend`, present the changes when they're
+    /// dropped, so there's no need to close them, but the errors are ignored there: call this
+    /// to handle them. Drawing after an explicit `present` is fine, the changes are presented
+    /// again on drop.
     pub fn present(&self) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| b.present())
     }
 
+    /// Draw a frame of an animation or a real-time plot. The area is cleared with the
+    /// background color, the frame is drawn and then presented, so the same drawing area can
+    /// render frames in a loop. For the GIF backend each call appends a frame to the file.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// for frame in 0..3 {
+    ///     root.draw_frame(&WHITE, |area| {
+    ///         area.draw(&Circle::new((50 + frame * 100, 100), 10, RED.filled()))
+    ///     })
+    ///     .unwrap();
+    /// }
+    /// ```
+    pub fn draw_frame<ColorType: Color, DrawFunc>(
+        &self,
+        background: &ColorType,
+        draw_func: DrawFunc,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        DrawFunc: FnOnce(&Self) -> Result<(), DrawingAreaError<DB>>,
+    {
+        self.fill(background)?;
+        draw_func(self)?;
+        self.present()
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
@@ -311,7 +605,166 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
             let b = p.borrow();
             self.rect.truncate(self.coord.translate(b))
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
+        self.backend_ops(move |b| {
+            b.on_render_event(RenderEvent::Element);
+            element.draw(backend_coords, b, self.dim_in_pixel())
+        })
+        .map_err(|e| self.in_context(e, ErrorContext::for_element::<E>()))
+    }
+
+    /// Draw the elements in parallel with rayon. The coordinates of the elements are mapped
+    /// and the elements are drawn into the recorded operations on the thread pool, then the
+    /// operations are drawn on the backend in the order of the elements, so the result is the
+    /// same as drawing the elements one by one with `draw`.
+    ///
+    /// This is useful for a large number of elements which are expensive to map or to draw,
+    /// the rasterization of the recorded operations still happens on the backend.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// root.draw_par(
+    ///     (0..1000)
+    ///         .into_par_iter()
+    ///         .map(|i| Circle::new((i % 300, i % 200), 2, &RED)),
+    /// )
+    /// .unwrap();
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn draw_par<E, R, S>(&self, elements: S) -> Result<(), DrawingAreaError<DB>>
+    where
+        for<'a> &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<RecordingBackend<DB>>,
+        R: Borrow<E> + Send,
+        S: rayon::iter::IntoParallelIterator<Item = R>,
+        CT: Sync,
+    {
+        use rayon::prelude::*;
+        let size = RefCell::borrow(&self.backend).get_size();
+        let (coord, rect, dim) = (&self.coord, &self.rect, self.dim_in_pixel());
+        let recorded: Vec<_> = elements
+            .into_par_iter()
+            .map(|element| {
+                let element = element.borrow();
+                let mut backend = RecordingBackend::new(size);
+                let backend_coords = element.point_iter().into_iter().map(|p| {
+                    let b = p.borrow();
+                    rect.truncate(coord.translate(b))
+                });
+                element.draw(backend_coords, &mut backend, dim)?;
+                Ok(backend)
+            })
+            .collect();
+        self.backend_ops(move |b| {
+            for backend in recorded {
+                b.on_render_event(RenderEvent::Element);
+                backend?.replay(b)?;
+            }
+            Ok(())
+        })
+        .map_err(|e| self.in_context(e, ErrorContext::for_element::<E>()))
+    }
+
+    /// Draw an high-level element which is clipped by the drawing area. Unlike `draw`, which
+    /// moves the points outside of the area onto its border, the shapes are cut exactly at the
+    /// border, so a series line leaving the plotting area keeps its slope and never bleeds
+    /// into the sibling areas.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    /// let area = root.apply_coord_spec(RangedCoord::cartesian_2d(
+    ///     0.0..1.0,
+    ///     0.0..1.0,
+    ///     (0..300, 0..200),
+    /// ));
+    /// area.draw_clipped(&PathElement::new(vec![(0.0, 0.0), (2.0, 2.0)], &RED))
+    ///     .unwrap();
+    /// ```
+    pub fn draw_clipped<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: for<'b> Drawable<ClippedBackend<'b, DB>>,
+    {
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()));
+        self.backend_ops(move |b| {
+            let mut clipped = ClippedBackend::new(
+                b,
+                (self.rect.x0, self.rect.y0),
+                (self.rect.x1 - 1, self.rect.y1 - 1),
+            );
+            clipped.on_render_event(RenderEvent::Element);
+            element.draw(backend_coords, &mut clipped, self.dim_in_pixel())
+        })
+        .map_err(|e| self.in_context(e, ErrorContext::for_element::<E>()))
+    }
+
+    /// Draw an high-level element which is clipped by the drawing area like `draw_clipped`, on
+    /// the backend shared with the drawing area, see `SharedClippedBackend`
+    pub fn draw_shared_clipped<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<SharedClippedBackend<DB>>,
+    {
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()));
+        self.backend_ops(|_| Ok(()))?;
+        // The chart maps the end of the X range to the pixel right after the area and the end
+        // of the Y range to the one right above it, which are kept so the shapes along the
+        // bounds of the ranges aren't cut off
+        let mut clipped = SharedClippedBackend::new(
+            self.backend.clone(),
+            (self.rect.x0, self.rect.y0 - 1),
+            (self.rect.x1, self.rect.y1 - 1),
+        );
+        clipped.on_render_event(RenderEvent::Element);
+        element
+            .draw(backend_coords, &mut clipped, self.dim_in_pixel())
+            .map_err(DrawingAreaErrorKind::BackendError)
+            .map_err(|e| {
+                let e = self.in_context(e, ErrorContext::for_backend::<DB>());
+                self.in_context(e, ErrorContext::for_element::<E>())
+            })
+    }
+
+    /// Report the progress of the rendering to the backend, see
+    /// `DrawingBackend::on_render_event`
+    pub fn render_event(&self, event: RenderEvent) {
+        if let Ok(mut backend) = self.backend.try_borrow_mut() {
+            backend.on_render_event(event);
+        }
+    }
+
+    /// Start a logical group of the shapes drawn on the backend, see
+    /// `DrawingBackend::begin_group`
+    pub fn begin_group(&self, label: &str) {
+        if let Ok(mut backend) = self.backend.try_borrow_mut() {
+            backend.begin_group(label);
+        }
+    }
+
+    /// End the group started last, see `DrawingBackend::end_group`
+    pub fn end_group(&self) {
+        if let Ok(mut backend) = self.backend.try_borrow_mut() {
+            backend.end_group();
+        }
+    }
+
+    /// Change the label of the group ended last, see `DrawingBackend::set_group_label`
+    pub fn set_group_label(&self, label: &str) {
+        if let Ok(mut backend) = self.backend.try_borrow_mut() {
+            backend.set_group_label(label);
+        }
     }
 
     /// Map coordinate to the backend coordinate
@@ -348,10 +801,28 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             },
             backend,
             coord: Shift((0, 0)),
+            error_context: false,
         }
     }
 
-    /// Shrink the region, note all the locations are in guest coordinate
+    /// Shrink the region to a sub-region, note all the locations are in guest coordinate. The
+    /// offset and the size may be relative to the size of the region, so the insets, legends
+    /// and logos keep their proportional positions when the image is resized. The sub-region
+    /// is clipped to the region.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (800, 600)).into_drawing_area();
+    /// // An inset in the upper right quarter of the image
+    /// let inset = root.clone().shrink(
+    ///     ((60).percent_width(), (5).percent_height()),
+    ///     ((35).percent_width(), (30).percent_height()),
+    /// );
+    /// assert_eq!(inset.get_base_pixel(), (480, 30));
+    /// assert_eq!(inset.dim_in_pixel(), (280, 180));
+    /// ```
     pub fn shrink<A: SizeDesc, B: SizeDesc, C: SizeDesc, D: SizeDesc>(
         mut self,
         left_upper: (A, B),
@@ -359,23 +830,26 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
     ) -> DrawingArea<DB, Shift> {
         let left_upper = (left_upper.0.in_pixels(&self), left_upper.1.in_pixels(&self));
         let dimension = (dimension.0.in_pixels(&self), dimension.1.in_pixels(&self));
+        let (x1, y1) = (self.rect.x1, self.rect.y1);
         self.rect.x0 = self.rect.x1.min(self.rect.x0 + left_upper.0);
         self.rect.y0 = self.rect.y1.min(self.rect.y0 + left_upper.1);
 
-        self.rect.x1 = self.rect.x0.max(self.rect.x0 + dimension.0);
-        self.rect.y1 = self.rect.y0.max(self.rect.y0 + dimension.1);
+        self.rect.x1 = x1.min(self.rect.x0.max(self.rect.x0 + dimension.0));
+        self.rect.y1 = y1.min(self.rect.y0.max(self.rect.y0 + dimension.1));
 
         self.coord = Shift((self.rect.x0, self.rect.y0));
 
         self
     }
 
-    /// Apply a new coord transformation object and returns a new drawing area
+    /// Apply a new coord transformation object and returns a new drawing area, which covers
+    /// the same region in pixels. See `strip_coord_spec` for going back to the pixels.
     pub fn apply_coord_spec<CT: CoordTranslate>(&self, coord_spec: CT) -> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.copy_backend_ref(),
             coord: coord_spec,
+            error_context: self.error_context,
         }
     }
 
@@ -400,6 +874,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             },
             backend: self.copy_backend_ref(),
             coord: Shift((self.rect.x0 + left, self.rect.y0 + top)),
+            error_context: self.error_context,
         }
     }
 
@@ -411,6 +886,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             rect: rect.clone(),
             backend: self.copy_backend_ref(),
             coord: Shift((rect.x0, rect.y0)),
+            error_context: self.error_context,
         });
 
         (ret.next().unwrap(), ret.next().unwrap())
@@ -424,6 +900,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             rect: rect.clone(),
             backend: self.copy_backend_ref(),
             coord: Shift((rect.x0, rect.y0)),
+            error_context: self.error_context,
         });
 
         (ret.next().unwrap(), ret.next().unwrap())
@@ -437,11 +914,27 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 rect: rect.clone(),
                 backend: self.copy_backend_ref(),
                 coord: Shift((rect.x0, rect.y0)),
+                error_context: self.error_context,
             })
             .collect()
     }
 
-    /// Split the drawing area into a grid with specified breakpoints on both X axis and Y axis
+    /// Split the drawing area into a grid with specified breakpoints on both X axis and Y axis.
+    /// The break points are the offsets from the upper left corner and may be relative to the
+    /// size of the area, `n` break points on an axis make `n + 1` rows or columns, and the
+    /// cells are returned row by row. The break points out of the area are moved to its border,
+    /// so they make empty cells.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (1000, 600)).into_drawing_area();
+    /// // A chart on the left 70% of the area and a legend on the right
+    /// let cells = root.split_by_breakpoints([(70).percent_width()], [] as [i32; 0]);
+    /// assert_eq!(cells[0].dim_in_pixel(), (700, 600));
+    /// assert_eq!(cells[1].dim_in_pixel(), (300, 600));
+    /// ```
     pub fn split_by_breakpoints<
         XSize: SizeDesc,
         YSize: SizeDesc,
@@ -461,42 +954,127 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 rect: rect.clone(),
                 backend: self.copy_backend_ref(),
                 coord: Shift((rect.x0, rect.y0)),
+                error_context: self.error_context,
+            })
+            .collect()
+    }
+
+    /// Split the drawing area into a grid with the sizes of the columns and the rows proportional
+    /// to the weights, the cells are returned row by row. The cell borders are rounded to the
+    /// nearest pixel from the start of the area, so the cells always fill the whole area. An
+    /// empty list of weights makes a single row or column.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (600, 400)).into_drawing_area();
+    /// // A main plot with a slim residual panel below it
+    /// let panels = root.split_by_weights([1], [3, 1]);
+    /// assert_eq!(panels[0].dim_in_pixel(), (600, 300));
+    /// assert_eq!(panels[1].dim_in_pixel(), (600, 100));
+    /// ```
+    pub fn split_by_weights<XW: Into<f64> + Copy, YW: Into<f64> + Copy, XS, YS>(
+        &self,
+        xs: XS,
+        ys: YS,
+    ) -> Vec<Self>
+    where
+        XS: AsRef<[XW]>,
+        YS: AsRef<[YW]>,
+    {
+        // The offsets between the cells, from the cumulative weights
+        fn break_points<W: Into<f64> + Copy>(weights: &[W], size: i32) -> Vec<i32> {
+            let weights: Vec<f64> = weights
+                .iter()
+                .map(|w| Into::<f64>::into(*w).max(0.0))
+                .collect();
+            let total: f64 = weights.iter().sum();
+            if total <= 0.0 {
+                return vec![];
+            }
+            let mut sum = 0.0;
+            weights[..weights.len() - 1]
+                .iter()
+                .map(|w| {
+                    sum += w;
+                    (f64::from(size) * sum / total).round() as i32
+                })
+                .collect()
+        }
+        let (w, h) = self.dim_in_pixel();
+        self.rect
+            .split_grid(
+                break_points(xs.as_ref(), w as i32).into_iter(),
+                break_points(ys.as_ref(), h as i32).into_iter(),
+            )
+            .map(|rect| Self {
+                rect: rect.clone(),
+                backend: self.copy_backend_ref(),
+                coord: Shift((rect.x0, rect.y0)),
+                error_context: self.error_context,
             })
             .collect()
     }
 
-    /// Draw a title of the drawing area and return the remaining drawing area
-    pub fn titled<'a, S: Into<TextStyle<'a>>>(
+    /// Draw a title across the top of the drawing area and return the remaining drawing area,
+    /// so the panels of a multi-panel figure are labeled without computing the margins. The
+    /// font size may be relative to the size of the area.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (600, 400)).into_drawing_area();
+    /// for (idx, panel) in root.split_evenly((1, 2)).iter().enumerate() {
+    ///     let title = format!("Panel {}", idx + 1);
+    ///     let panel = panel.titled(&title, ("sans-serif", (8).percent_height())).unwrap();
+    ///     assert!(panel.dim_in_pixel().1 < 400);
+    /// }
+    /// ```
+    pub fn titled<'a, S: IntoTextStyle<'a>>(
         &self,
         text: &str,
         style: S,
     ) -> Result<Self, DrawingAreaError<DB>> {
-        let style = style.into();
+        let style = style.into_text_style(self);
 
         let x_padding = (self.rect.x1 - self.rect.x0) / 2;
 
         let (_, text_h) = self.estimate_text_size(text, &style.font)?;
         let y_padding = (text_h / 2).min(5) as i32;
+        // The background box of the title is drawn around the text
+        let box_padding = match style.background {
+            Some(_) => style.padding as i32,
+            None => 0,
+        };
+        let title_h = (y_padding + box_padding) * 2 + text_h as i32;
 
         let style = &style.pos(Pos::new(HPos::Center, VPos::Top));
 
         self.backend_ops(|b| {
-            b.draw_text(
+            draw_styled_text(
+                b,
                 text,
                 &style,
-                (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
+                (
+                    self.rect.x0 + x_padding,
+                    self.rect.y0 + y_padding + box_padding,
+                ),
             )
-        })?;
+        })
+        .map_err(|e| self.in_context(e, ErrorContext::for_element::<Text<(), &str>>()))?;
 
         Ok(Self {
             rect: Rect {
                 x0: self.rect.x0,
-                y0: self.rect.y0 + y_padding * 2 + text_h as i32,
+                y0: self.rect.y0 + title_h,
                 x1: self.rect.x1,
                 y1: self.rect.y1,
             },
             backend: self.copy_backend_ref(),
-            coord: Shift((self.rect.x0, self.rect.y0 + y_padding * 2 + text_h as i32)),
+            coord: Shift((self.rect.x0, self.rect.y0 + title_h)),
+            error_context: self.error_context,
         })
     }
 
@@ -508,8 +1086,14 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         pos: BackendCoord,
     ) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| {
-            b.draw_text(text, &style, (pos.0 + self.rect.x0, pos.1 + self.rect.y0))
+            draw_styled_text(
+                b,
+                text,
+                &style,
+                (pos.0 + self.rect.x0, pos.1 + self.rect.y0),
+            )
         })
+        .map_err(|e| self.in_context(e, ErrorContext::for_element::<Text<(), &str>>()))
     }
 }
 
@@ -694,6 +1278,41 @@ mod drawing_area_tests {
             }
         }
     }
+    #[test]
+    fn test_split_by_breakpoints_out_of_area() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let cells = drawing_area.split_by_breakpoints([150, -20], [40]);
+        let dims: Vec<_> = cells.iter().map(|cell| cell.dim_in_pixel()).collect();
+        assert_eq!(
+            dims,
+            vec![(0, 40), (100, 40), (0, 40), (0, 60), (100, 60), (0, 60)]
+        );
+        assert_eq!(cells[4].get_base_pixel(), (0, 40));
+    }
+
+    #[test]
+    fn test_split_by_weights() {
+        let drawing_area = create_mocked_drawing_area(100, 90, |_| {});
+        let cells = drawing_area.split_by_weights([1, 1, 1], [2.0, 0.0, 1.0]);
+        let dims: Vec<_> = cells.iter().map(|cell| cell.dim_in_pixel()).collect();
+        assert_eq!(
+            dims,
+            vec![
+                (33, 60),
+                (34, 60),
+                (33, 60),
+                (33, 0),
+                (34, 0),
+                (33, 0),
+                (33, 30),
+                (34, 30),
+                (33, 30)
+            ]
+        );
+        assert_eq!(cells[8].get_base_pixel(), (67, 60));
+        assert_eq!(drawing_area.split_by_weights([0], [] as [u32; 0]).len(), 1);
+    }
+
     #[test]
     fn test_titled() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -724,6 +1343,28 @@ mod drawing_area_tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_titled_relative_size() {
+        let drawing_area = create_mocked_drawing_area(1000, 600, |m| {
+            m.check_draw_text(|_, font, size, pos, text| {
+                assert_eq!(font, "sans-serif");
+                assert_eq!(size, 30.0);
+                assert_eq!(pos.0, 500);
+                assert_eq!("Panel", text);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        let panel = drawing_area
+            .titled("Panel", ("sans-serif", (5).percent_height()))
+            .unwrap();
+        let (_, y) = panel.get_base_pixel();
+        assert!(y > 30);
+        assert_eq!(panel.dim_in_pixel(), (1000, 600 - y as u32));
+    }
+
     #[test]
     fn test_margin() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -765,6 +1406,114 @@ mod drawing_area_tests {
         assert_eq!(y_range, 0..200);
     }
 
+    #[test]
+    fn test_draw_frame() {
+        let root = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (10, 50));
+            });
+            m.check_draw_rect(|c, _, _, _, _| {
+                assert_eq!(c, WHITE.to_rgba());
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (20, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_present_call, 2);
+            });
+        });
+        for frame in 1..3 {
+            root.draw_frame(&WHITE, |area| {
+                area.draw(&Circle::new((frame * 10, 50), 5, &RED))
+            })
+            .expect("Drawing Failure");
+        }
+    }
+
+    #[test]
+    fn test_coord_spec_round_trip() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!((ul, br), ((112, 50), (131, 69)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+            });
+        });
+        let area =
+            root.margin(50, 0, 100, 0).apply_coord_spec(RangedCoord::<
+                RangedCoordi32,
+                RangedCoordi32,
+            >::new(
+                0..100, 0..100, (100..1024, 50..768)
+            ));
+
+        let pixel = area.strip_coord_spec();
+        assert_eq!(pixel.get_base_pixel(), area.get_base_pixel());
+        assert_eq!(pixel.dim_in_pixel(), area.dim_in_pixel());
+        pixel
+            .draw(&Rectangle::new([(12, 0), (31, 19)], &RED))
+            .expect("Drawing Failure");
+
+        let data = pixel.apply_coord_spec(area.as_coord_spec().clone());
+        assert_eq!(
+            data.map_coordinate(&(50, 50)),
+            area.map_coordinate(&(50, 50))
+        );
+        assert_eq!(data.get_x_range(), 0..100);
+    }
+
+    #[test]
+    fn test_mesh_cells() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|_, _, filled, ul, br| {
+                assert!(filled);
+                assert_eq!((ul, br), ((0, 0), (20, 20)));
+            });
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!((ul, br), ((40, 0), (60, 20)));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 13);
+            });
+        })
+        .apply_coord_spec(RangedCoord::<RangedCoordi32, RangedCoordi32>::new(
+            0..10,
+            0..10,
+            (0..100, 0..100),
+        ));
+
+        let mut cells = vec![];
+        drawing_area
+            .draw_mesh_cells(
+                |b, cell| {
+                    cells.push((
+                        cell.index,
+                        cell.x_values.0.cloned(),
+                        cell.y_values.0.cloned(),
+                    ));
+                    if (cell.index.0 + cell.index.1) % 2 == 0 {
+                        cell.draw(b, &BLACK.filled())?;
+                    }
+                    Ok(())
+                },
+                6,
+                6,
+            )
+            .expect("Drawing Failure");
+
+        assert_eq!(cells.len(), 25);
+        assert_eq!(cells[0], ((0, 0), Some(0), Some(0)));
+        assert_eq!(cells[6], ((1, 1), Some(2), Some(2)));
+    }
+
     #[test]
     fn test_relative_size() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |_m| {});
@@ -845,4 +1594,67 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[test]
+    fn test_shrink_clipped() {
+        let drawing_area = create_mocked_drawing_area(1000, 1200, |_| {})
+            .shrink((900, 100), ((20).percent_width(), 2000));
+        assert_eq!(drawing_area.get_base_pixel(), (900, 100));
+        assert_eq!(drawing_area.dim_in_pixel(), (100, 1100));
+    }
+
+    #[cfg(all(feature = "bitmap", feature = "line_series"))]
+    #[test]
+    fn test_draw_far_outside() {
+        let mut buf = vec![0; 100 * 100 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buf, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0.0..1.0, 0.0..1e-9)
+                .unwrap();
+            chart
+                .draw_series(std::iter::once(Rectangle::new(
+                    [(-1e300, 1e300), (0.5, -1e300)],
+                    RED.filled(),
+                )))
+                .unwrap();
+            chart
+                .draw_series(LineSeries::new(
+                    vec![(-1e300, 0.5e-9), (1e300, 0.5e-9)],
+                    &BLUE,
+                ))
+                .unwrap();
+        }
+        assert_eq!(&buf[(10 * 100 + 10) * 3..][..3], &[255, 0, 0]);
+        assert_eq!(&buf[(10 * 100 + 90) * 3..][..3], &[0, 0, 0]);
+        assert_eq!(&buf[(49 * 100 + 90) * 3..][..3], &[0, 0, 255]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_draw_par_keeps_order() {
+        use rayon::prelude::*;
+        let drawing_area = create_mocked_drawing_area(1000, 1000, |m| {
+            let mut next = 0;
+            m.check_draw_circle(move |c, _, _, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(center, (next + 100, next + 200));
+                assert_eq!(radius, 3);
+                next += 1;
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 500);
+            });
+        })
+        .shrink((100, 200), (800, 700));
+
+        drawing_area
+            .draw_par(
+                (0..500)
+                    .into_par_iter()
+                    .map(|i| Circle::new((i, i), 3, &RED)),
+            )
+            .unwrap();
+    }
 }
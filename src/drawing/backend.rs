@@ -1,6 +1,9 @@
-use crate::style::text_anchor::{HPos, VPos};
-use crate::style::{Color, FontDesc, FontError, RGBAColor, ShapeStyle, TextStyle};
-use std::error::Error;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{
+    Color, FontDesc, FontError, Gradient, Pattern, RGBAColor, ShapeStyle, TextStyle,
+};
+use core::error::Error;
 
 /// A coordinate in the image
 pub type BackendCoord = (i32, i32);
@@ -17,8 +20,8 @@ where
     FontError(FontError),
 }
 
-impl<E: Error + Send + Sync> std::fmt::Display for DrawingErrorKind<E> {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl<E: Error + Send + Sync> core::fmt::Display for DrawingErrorKind<E> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         match self {
             DrawingErrorKind::DrawingError(e) => write!(fmt, "Drawing backend error: {}", e),
             DrawingErrorKind::FontError(e) => write!(fmt, "Font loading error: {}", e),
@@ -28,6 +31,30 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingErrorKind<E> {
 
 impl<E: Error + Send + Sync> Error for DrawingErrorKind<E> {}
 
+/// The phase of drawing a chart, which is reported to the backend with `RenderEvent`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RenderPhase {
+    /// Laying out the chart, such as the caption and the label areas
+    Layout,
+    /// Drawing the mesh, the axes and the labels
+    Mesh,
+    /// Drawing the data series
+    Series,
+}
+
+/// The progress of the rendering, which is reported to the backend with
+/// `DrawingBackend::on_render_event`, so a backend like `ProfiledBackend` can tell what the
+/// drawing operations are for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderEvent {
+    /// A phase of drawing a chart starts
+    PhaseStart(RenderPhase),
+    /// A phase of drawing a chart ends
+    PhaseEnd(RenderPhase),
+    /// A high-level element is drawn on a drawing area
+    Element,
+}
+
 /// The style data for the backend drawing API
 pub trait BackendStyle {
     /// The underlying type represents the color for this style
@@ -40,6 +67,16 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// The gradient the filled shapes are painted with, or `None` for the solid color
+    fn gradient(&self) -> Option<&Gradient> {
+        None
+    }
+
+    /// The pattern the filled shapes are painted with, or `None` for the solid color
+    fn pattern(&self) -> Option<&Pattern> {
+        None
+    }
 }
 
 impl<T: Color> BackendStyle for T {
@@ -57,6 +94,12 @@ impl BackendStyle for ShapeStyle {
     fn stroke_width(&self) -> u32 {
         self.stroke_width
     }
+    fn gradient(&self) -> Option<&Gradient> {
+        self.gradient.as_ref()
+    }
+    fn pattern(&self) -> Option<&Pattern> {
+        self.pattern.as_ref()
+    }
 }
 
 ///  The drawing backend trait, which implements the low-level drawing APIs.
@@ -81,8 +124,45 @@ pub trait DrawingBackend: Sized {
     /// The backend may implement in the following way, when `ensure_prepared` is called
     /// it checks if it needs a fresh buffer and `present` is called rendering all the
     /// pending changes on the screen.
+    ///
+    /// The backends which save the result should also present the pending changes when
+    /// they're dropped, so forgetting to call `present` never loses the drawing.
     fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Present the changes within the regions only, the regions are the inclusive bounding
+    /// boxes of the changed pixels, and they're tracked by `DirtyRectBackend`. The default
+    /// implementation presents the whole frame, a real-time backend may override this to
+    /// flush only the changed regions.
+    fn present_regions(
+        &mut self,
+        _regions: &[(BackendCoord, BackendCoord)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.present()
+    }
+
+    /// Receive the progress of the rendering, such as the phases of drawing a chart. The
+    /// default implementation ignores the events, the backends wrapping another backend
+    /// should pass them through.
+    fn on_render_event(&mut self, _event: RenderEvent) {}
+
+    /// Set the tooltip of the shapes drawn after it, until it's reset with `None`, see the
+    /// `Tooltip` element. The default implementation ignores the tooltips, the backends
+    /// wrapping another backend should pass them through.
+    fn set_tooltip(&mut self, _tooltip: Option<&str>) {}
+
+    /// Start a logical group of the shapes drawn after it, such as a data series or an axis,
+    /// until it's ended with `end_group`. The groups can be nested, and the label describes the
+    /// group to the assistive technologies like the screen readers. The default implementation
+    /// ignores the groups, the backends wrapping another backend should pass them through.
+    fn begin_group(&mut self, _label: &str) {}
+
+    /// End the group started last by `begin_group`
+    fn end_group(&mut self) {}
+
+    /// Change the label of the group ended last, since the label of a data series is only
+    /// known after the series is drawn, see `SeriesAnno::label`
+    fn set_group_label(&mut self, _label: &str) {}
+
     /// Draw a pixel on the drawing backend
     /// - `point`: The backend pixel-based coordinate to draw
     /// - `color`: The color of the pixel
@@ -120,6 +200,52 @@ pub trait DrawingBackend: Sized {
         super::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    /// Draw a batch of pixels on the drawing backend. The default implementation draws the
+    /// pixels one by one, the backend may override this to avoid the overhead of a call for
+    /// each pixel.
+    /// - `pixels`: The iterator of the pixel coordinates and colors
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (point, color) in pixels {
+            self.draw_pixel(point, &color)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a batch of lines with the same style on the drawing backend. The default
+    /// implementation draws the lines one by one.
+    /// - `lines`: The iterator of the start and end points of the lines
+    /// - `style`: The style of the lines
+    fn draw_lines<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        lines: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (from, to) in lines {
+            self.draw_line(from, to, style)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a batch of rectangles with the same style on the drawing backend. The default
+    /// implementation draws the rectangles one by one.
+    /// - `rects`: The iterator of the upper-left and bottom-right corners of the rectangles
+    /// - `style`: The style of the rectangles
+    /// - `fill`: If the rectangles should be filled
+    fn draw_rects<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        rects: I,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (upper_left, bottom_right) in rects {
+            self.draw_rect(upper_left, bottom_right, style, fill)?;
+        }
+        Ok(())
+    }
+
     /// Draw a path on the drawing backend
     /// - `path`: The iterator of key points of the path
     /// - `style`: The style of the path
@@ -151,6 +277,18 @@ pub trait DrawingBackend: Sized {
         Ok(())
     }
 
+    /// Draw a cubic Bézier curve on the drawing backend. By default, the curve is flattened
+    /// into a path, backends with native curve support may override this.
+    /// - `curve`: The start point, the two control points and the end point
+    /// - `style`: The style of the curve
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_path(super::rasterizer::flatten_cubic_bezier(curve), style)
+    }
+
     /// Draw a circle on the drawing backend
     /// - `center`: The center coordinate of the circle
     /// - `radius`: The radius of the circle
@@ -186,40 +324,7 @@ pub trait DrawingBackend: Sized {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let font = &style.font;
-        let color = &style.color;
-        if color.alpha() == 0.0 {
-            return Ok(());
-        }
-
-        let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
-        let ((min_x, min_y), (max_x, max_y)) = layout;
-        let width = (max_x - min_x) as i32;
-        let height = (max_y - min_y) as i32;
-        let dx = match style.pos.h_pos {
-            HPos::Left => 0,
-            HPos::Right => -width,
-            HPos::Center => -width / 2,
-        };
-        let dy = match style.pos.v_pos {
-            VPos::Top => 0,
-            VPos::Center => -height / 2,
-            VPos::Bottom => -height,
-        };
-        let trans = font.get_transform();
-        let (w, h) = self.get_size();
-        match font.draw(text, (0, 0), |x, y, v| {
-            let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
-            let (x, y) = (pos.0 + x, pos.1 + y);
-            if x >= 0 && x < w as i32 && y >= 0 && y < h as i32 {
-                self.draw_pixel((x, y), &color.mix(f64::from(v)))
-            } else {
-                Ok(())
-            }
-        }) {
-            Ok(drawing_result) => drawing_result,
-            Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
-        }
+        super::rasterizer::draw_text(self, text, style, pos, f64::from)
     }
 
     /// Estimate the size of the horizontal text if rendered on this backend.
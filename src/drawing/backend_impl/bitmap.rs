@@ -1,6 +1,14 @@
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
-use crate::style::{Color, RGBAColor};
-use std::marker::PhantomData;
+use crate::style::text_anchor::{HPos, VPos};
+use crate::style::{is_deterministic_rendering, Color, FontTransform, RGBAColor, TextStyle};
+use core::marker::PhantomData;
+
+#[cfg(feature = "rayon")]
+use crate::coord::Shift;
+#[cfg(feature = "rayon")]
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod image_encoding_support {
@@ -18,19 +26,20 @@ pub enum BitMapBackendError {
     /// The buffer provided is invalid, for example, wrong pixel buffer size
     InvalidBuffer,
     /// Some IO error occurs while the bitmap maniuplation
+    #[cfg(feature = "std")]
     IOError(std::io::Error),
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     /// Image encoding error
     ImageError(ImageError),
 }
 
-impl std::fmt::Display for BitMapBackendError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for BitMapBackendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
-impl std::error::Error for BitMapBackendError {}
+impl core::error::Error for BitMapBackendError {}
 
 #[inline(always)]
 fn blend(prev: &mut u8, new: u8, a: u64) {
@@ -41,6 +50,87 @@ fn blend(prev: &mut u8, new: u8, a: u64) {
     }
 }
 
+#[cfg(all(unix, feature = "mmap"))]
+mod mmap_support {
+    use super::BitMapBackendError;
+    use std::fs::{File, OpenOptions};
+    use std::os::unix::io::AsRawFd;
+    use std::path::{Path, PathBuf};
+
+    // A scratch file mapped into the memory, which is removed when it's dropped
+    pub(super) struct MappedFile {
+        ptr: *mut u8,
+        len: usize,
+        path: PathBuf,
+        _file: File,
+    }
+
+    // The mapping is owned exclusively by the buffer, just like a `Vec<u8>`
+    unsafe impl Send for MappedFile {}
+    unsafe impl Sync for MappedFile {}
+
+    impl MappedFile {
+        pub(super) fn new(path: PathBuf, len: usize) -> Result<Self, BitMapBackendError> {
+            if len == 0 {
+                return Err(BitMapBackendError::InvalidBuffer);
+            }
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&path)
+                .map_err(BitMapBackendError::IOError)?;
+            let mapped = file.set_len(len as u64).and_then(|_| {
+                let ptr = unsafe {
+                    libc::mmap(
+                        core::ptr::null_mut(),
+                        len,
+                        libc::PROT_READ | libc::PROT_WRITE,
+                        libc::MAP_SHARED,
+                        file.as_raw_fd(),
+                        0,
+                    )
+                };
+                if ptr == libc::MAP_FAILED {
+                    Err(std::io::Error::last_os_error())
+                } else {
+                    Ok(ptr as *mut u8)
+                }
+            });
+            match mapped {
+                Ok(ptr) => Ok(Self {
+                    ptr,
+                    len,
+                    path,
+                    _file: file,
+                }),
+                Err(err) => {
+                    std::fs::remove_file(&path).ok();
+                    Err(BitMapBackendError::IOError(err))
+                }
+            }
+        }
+
+        pub(super) fn as_mut_slice(&mut self) -> &mut [u8] {
+            unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+        }
+
+        pub(super) fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for MappedFile {
+        fn drop(&mut self) {
+            unsafe {
+                libc::munmap(self.ptr as *mut libc::c_void, self.len);
+            }
+            std::fs::remove_file(&self.path).ok();
+        }
+    }
+}
+
 #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
 mod gif_support {
     use super::*;
@@ -107,6 +197,8 @@ enum Buffer<'a> {
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     Owned(Vec<u8>),
     Borrowed(&'a mut [u8]),
+    #[cfg(all(unix, feature = "mmap"))]
+    Mapped(mmap_support::MappedFile),
 }
 
 impl<'a> Buffer<'a> {
@@ -116,6 +208,8 @@ impl<'a> Buffer<'a> {
             #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
             Buffer::Owned(buf) => &mut buf[..],
             Buffer::Borrowed(buf) => *buf,
+            #[cfg(all(unix, feature = "mmap"))]
+            Buffer::Mapped(file) => file.as_mut_slice(),
         }
     }
 }
@@ -177,7 +271,7 @@ pub trait PixelFormat: Sized {
         let dst = target.get_raw_pixel_buffer();
         let (mut y0, mut y1) = ys;
         if y0 > y1 {
-            std::mem::swap(&mut y0, &mut y1);
+            core::mem::swap(&mut y0, &mut y1);
         }
         // And check the y axis isn't out of bound
         y0 = y0.max(0);
@@ -203,7 +297,69 @@ pub trait PixelFormat: Sized {
         r: u8,
         g: u8,
         b: u8,
-    );
+    ) {
+        let (w, h) = target.get_size();
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0).max(0),
+            upper_left.1.min(bottom_right.1).max(0),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
+            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
+        );
+
+        // This may happen when the minimal value is larger than the limit.
+        // Thus we just have something that is completely out-of-range
+        if x0 > x1 || y0 > y1 {
+            return;
+        }
+
+        let dst = target.get_raw_pixel_buffer();
+        let row_size = w as usize * Self::PIXEL_SIZE;
+        let (y0, y1) = (y0 as usize, y1 as usize);
+        let row = x0 as usize * Self::PIXEL_SIZE..(x1 as usize + 1) * Self::PIXEL_SIZE;
+
+        if r == g && g == b {
+            // If r == g == b, then we can use memset
+            if row.len() != row_size {
+                // If it's not the entire row is filled, we can only do
+                // memset per row
+                for y in y0..=y1 {
+                    let base = y * row_size;
+                    dst[base + row.start..base + row.end]
+                        .iter_mut()
+                        .for_each(|e| *e = r);
+                }
+            } else {
+                // If the entire memory block is going to be filled, just use single memset
+                dst[y0 * row_size..(y1 + 1) * row_size]
+                    .iter_mut()
+                    .for_each(|e| *e = r);
+            }
+            return;
+        }
+
+        // Fill the first row pixel by pixel, and copy it to the other rows, which is a memcpy
+        let first = y0 * row_size;
+        for pixel in dst[first + row.start..first + row.end].chunks_exact_mut(Self::PIXEL_SIZE) {
+            for (idx, byte) in pixel.iter_mut().enumerate() {
+                *byte = Self::byte_at(r, g, b, 0, idx);
+            }
+        }
+        if row.len() == row_size {
+            // The rows are continuous, so the filled part is doubled in each copy
+            let (mut filled, end) = (row_size, (y1 - y0 + 1) * row_size);
+            while filled < end {
+                let len = filled.min(end - filled);
+                dst.copy_within(first..first + len, first + filled);
+                filled += len;
+            }
+        } else {
+            for y in y0 + 1..=y1 {
+                dst.copy_within(first + row.start..first + row.end, y * row_size + row.start);
+            }
+        }
+    }
 
     #[inline(always)]
     /// Drawing a single pixel in this format
@@ -326,7 +482,7 @@ impl PixelFormat for RGBPixel {
         // thus, this type purning should work for both LE and BE CPUs
         #[rustfmt::skip]
         let (p1, p2, p3): (u64, u64, u64) = unsafe {
-            std::mem::transmute([
+            core::mem::transmute([
                 u16::from(r), u16::from(b), u16::from(g), u16::from(r), // QW1
                 u16::from(b), u16::from(g), u16::from(r), u16::from(b), // QW2
                 u16::from(g), u16::from(r), u16::from(b), u16::from(g), // QW3
@@ -335,7 +491,7 @@ impl PixelFormat for RGBPixel {
 
         #[rustfmt::skip]
         let (q1, q2, q3): (u64, u64, u64) = unsafe {
-            std::mem::transmute([
+            core::mem::transmute([
                 u16::from(g), u16::from(r), u16::from(b), u16::from(g), // QW1
                 u16::from(r), u16::from(b), u16::from(g), u16::from(r), // QW2
                 u16::from(b), u16::from(g), u16::from(r), u16::from(b), // QW3
@@ -350,10 +506,10 @@ impl PixelFormat for RGBPixel {
             let count = (x1 - x0 + 1) as usize;
 
             let start_ptr = &mut dst[start * Self::PIXEL_SIZE] as *mut u8 as *mut [u8; 24];
-            let slice = unsafe { std::slice::from_raw_parts_mut(start_ptr, (count - 1) / 8) };
+            let slice = unsafe { core::slice::from_raw_parts_mut(start_ptr, (count - 1) / 8) };
             for p in slice.iter_mut() {
                 let ptr = p as *mut [u8; 24] as *mut (u64, u64, u64);
-                let (d1, d2, d3) = unsafe { *ptr };
+                let (d1, d2, d3) = unsafe { ptr.read_unaligned() };
                 let (mut h1, mut h2, mut h3) = ((d1 >> 8) & M, (d2 >> 8) & M, (d3 >> 8) & M);
                 let (mut l1, mut l2, mut l3) = (d1 & M, d2 & M, d3 & M);
 
@@ -378,7 +534,7 @@ impl PixelFormat for RGBPixel {
                 }
 
                 unsafe {
-                    *ptr = (h1 | l1, h2 | l2, h3 | l3);
+                    ptr.write_unaligned((h1 | l1, h2 | l2, h3 | l3));
                 }
             }
 
@@ -392,99 +548,6 @@ impl PixelFormat for RGBPixel {
             }
         }
     }
-
-    #[allow(clippy::many_single_char_names, clippy::cast_ptr_alignment)]
-    fn fill_rect_fast(
-        target: &mut BitMapBackend<'_, Self>,
-        upper_left: (i32, i32),
-        bottom_right: (i32, i32),
-        r: u8,
-        g: u8,
-        b: u8,
-    ) {
-        let (w, h) = target.get_size();
-        let (x0, y0) = (
-            upper_left.0.min(bottom_right.0).max(0),
-            upper_left.1.min(bottom_right.1).max(0),
-        );
-        let (x1, y1) = (
-            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
-            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
-        );
-
-        // This may happen when the minimal value is larger than the limit.
-        // Thus we just have something that is completely out-of-range
-        if x0 > x1 || y0 > y1 {
-            return;
-        }
-
-        let dst = target.get_raw_pixel_buffer();
-
-        if r == g && g == b {
-            // If r == g == b, then we can use memset
-            if x0 != 0 || x1 != w as i32 - 1 {
-                // If it's not the entire row is filled, we can only do
-                // memset per row
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let count = (x1 - x0 + 1) as usize;
-                    dst[(start * Self::PIXEL_SIZE)..((start + count) * Self::PIXEL_SIZE)]
-                        .iter_mut()
-                        .for_each(|e| *e = r);
-                }
-            } else {
-                // If the entire memory block is going to be filled, just use single memset
-                dst[Self::PIXEL_SIZE * (y0 * w as i32) as usize
-                    ..((y1 + 1) * w as i32) as usize * Self::PIXEL_SIZE]
-                    .iter_mut()
-                    .for_each(|e| *e = r);
-            }
-        } else {
-            let count = (x1 - x0 + 1) as usize;
-            if count < 8 {
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let mut iter = dst
-                        [(start * Self::PIXEL_SIZE)..((start + count) * Self::PIXEL_SIZE)]
-                        .iter_mut();
-                    for _ in 0..=(x1 - x0) {
-                        *iter.next().unwrap() = r;
-                        *iter.next().unwrap() = g;
-                        *iter.next().unwrap() = b;
-                    }
-                }
-            } else {
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let start_ptr = &mut dst[start * Self::PIXEL_SIZE] as *mut u8 as *mut [u8; 24];
-                    let slice =
-                        unsafe { std::slice::from_raw_parts_mut(start_ptr, (count - 1) / 8) };
-                    for p in slice.iter_mut() {
-                        // In this case, we can actually fill 8 pixels in one iteration with
-                        // only 3 movq instructions.
-                        // TODO: Consider using AVX instructions when possible
-                        let ptr = p as *mut [u8; 24] as *mut u64;
-                        unsafe {
-                            let (d1, d2, d3): (u64, u64, u64) = std::mem::transmute([
-                                r, g, b, r, g, b, r, g, // QW1
-                                b, r, g, b, r, g, b, r, // QW2
-                                g, b, r, g, b, r, g, b, // QW3
-                            ]);
-                            *ptr = d1;
-                            *ptr.offset(1) = d2;
-                            *ptr.offset(2) = d3;
-                        }
-                    }
-
-                    for idx in (slice.len() * 8)..count {
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE] = r;
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE + 1] = g;
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE + 2] = b;
-                    }
-                }
-            }
-        }
-    }
 }
 
 impl PixelFormat for BGRXPixel {
@@ -545,14 +608,14 @@ impl PixelFormat for BGRXPixel {
         // thus, this type purning should work for both LE and BE CPUs
         #[rustfmt::skip]
         let p: u64 = unsafe {
-            std::mem::transmute([
+            core::mem::transmute([
                 u16::from(b), u16::from(r), u16::from(b), u16::from(r), // QW1
             ])
         };
 
         #[rustfmt::skip]
         let q: u64 = unsafe {
-            std::mem::transmute([
+            core::mem::transmute([
                 u16::from(g), 0u16, u16::from(g), 0u16, // QW1
             ])
         };
@@ -565,10 +628,10 @@ impl PixelFormat for BGRXPixel {
             let count = (x1 - x0 + 1) as usize;
 
             let start_ptr = &mut dst[start * Self::PIXEL_SIZE] as *mut u8 as *mut [u8; 8];
-            let slice = unsafe { std::slice::from_raw_parts_mut(start_ptr, (count - 1) / 2) };
+            let slice = unsafe { core::slice::from_raw_parts_mut(start_ptr, (count - 1) / 2) };
             for rp in slice.iter_mut() {
                 let ptr = rp as *mut [u8; 8] as *mut u64;
-                let d1 = unsafe { *ptr };
+                let d1 = unsafe { ptr.read_unaligned() };
                 let mut h = (d1 >> 8) & M;
                 let mut l = d1 & M;
 
@@ -585,7 +648,7 @@ impl PixelFormat for BGRXPixel {
                 }
 
                 unsafe {
-                    *ptr = h | l;
+                    ptr.write_unaligned(h | l);
                 }
             }
 
@@ -600,96 +663,19 @@ impl PixelFormat for BGRXPixel {
             }
         }
     }
+}
 
-    #[allow(clippy::many_single_char_names, clippy::cast_ptr_alignment)]
-    fn fill_rect_fast(
-        target: &mut BitMapBackend<'_, Self>,
-        upper_left: (i32, i32),
-        bottom_right: (i32, i32),
-        r: u8,
-        g: u8,
-        b: u8,
-    ) {
-        let (w, h) = target.get_size();
-        let (x0, y0) = (
-            upper_left.0.min(bottom_right.0).max(0),
-            upper_left.1.min(bottom_right.1).max(0),
-        );
-        let (x1, y1) = (
-            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
-            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
-        );
-
-        // This may happen when the minimal value is larger than the limit.
-        // Thus we just have something that is completely out-of-range
-        if x0 > x1 || y0 > y1 {
-            return;
-        }
-
-        let dst = target.get_raw_pixel_buffer();
-
-        if r == g && g == b {
-            // If r == g == b, then we can use memset
-            if x0 != 0 || x1 != w as i32 - 1 {
-                // If it's not the entire row is filled, we can only do
-                // memset per row
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let count = (x1 - x0 + 1) as usize;
-                    dst[(start * Self::PIXEL_SIZE)..((start + count) * Self::PIXEL_SIZE)]
-                        .iter_mut()
-                        .for_each(|e| *e = r);
-                }
-            } else {
-                // If the entire memory block is going to be filled, just use single memset
-                dst[Self::PIXEL_SIZE * (y0 * w as i32) as usize
-                    ..((y1 + 1) * w as i32) as usize * Self::PIXEL_SIZE]
-                    .iter_mut()
-                    .for_each(|e| *e = r);
-            }
-        } else {
-            let count = (x1 - x0 + 1) as usize;
-            if count < 8 {
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let mut iter = dst
-                        [(start * Self::PIXEL_SIZE)..((start + count) * Self::PIXEL_SIZE)]
-                        .iter_mut();
-                    for _ in 0..=(x1 - x0) {
-                        *iter.next().unwrap() = b;
-                        *iter.next().unwrap() = g;
-                        *iter.next().unwrap() = r;
-                        iter.next();
-                    }
-                }
-            } else {
-                for y in y0..=y1 {
-                    let start = (y * w as i32 + x0) as usize;
-                    let start_ptr = &mut dst[start * Self::PIXEL_SIZE] as *mut u8 as *mut [u8; 8];
-                    let slice =
-                        unsafe { std::slice::from_raw_parts_mut(start_ptr, (count - 1) / 2) };
-                    for p in slice.iter_mut() {
-                        // In this case, we can actually fill 8 pixels in one iteration with
-                        // only 3 movq instructions.
-                        // TODO: Consider using AVX instructions when possible
-                        let ptr = p as *mut [u8; 8] as *mut u64;
-                        unsafe {
-                            let d: u64 = std::mem::transmute([
-                                b, g, r, 0, b, g, r, 0, // QW1
-                            ]);
-                            *ptr = d;
-                        }
-                    }
-
-                    for idx in (slice.len() * 2)..count {
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE] = b;
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE + 1] = g;
-                        dst[start * Self::PIXEL_SIZE + idx * Self::PIXEL_SIZE + 2] = r;
-                    }
-                }
-            }
-        }
-    }
+/// The way the bitmap backend rasterizes the text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextRendering {
+    /// The edges of the glyphs are anti-aliased with gray levels, this is the default
+    Grayscale,
+    /// The edges of the glyphs are anti-aliased with the sub-pixels of a horizontal RGB LCD
+    /// screen, which keeps the small text sharp on such screens. The rotated text is drawn
+    /// in gray levels.
+    Subpixel,
+    /// The glyphs aren't anti-aliased, the pixels at least half covered by a glyph are painted
+    Aliased,
 }
 
 /// The backend that drawing a bitmap
@@ -703,6 +689,10 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// How the text is rasterized
+    text_rendering: TextRendering,
+    /// If the glyphs are fitted to the pixel grid
+    hinting: bool,
     _pantomdata: PhantomData<P>,
 }
 
@@ -720,7 +710,56 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            text_rendering: TextRendering::Grayscale,
+            hinting: false,
+            _pantomdata: PhantomData,
+        }
+    }
+
+    /// Create a new bitmap backend whose pixel buffer is a memory-mapped scratch file, so a
+    /// huge image, such as a poster, doesn't need to fit in the memory. The scratch file is
+    /// the path of the image with the `.pixels` suffix, it's removed when the backend is
+    /// dropped, and the image is encoded from it when the backend is presented.
+    ///
+    /// - `path`: The path to the image file to create
+    /// - `dimension`: The size of the image in pixels
+    /// - **returns**: The newly created bitmap backend
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let root = BitMapBackend::with_mapped_buffer("poster.png", (20000, 15000))
+    ///     .unwrap()
+    ///     .into_drawing_area();
+    /// root.fill(&WHITE).unwrap();
+    /// root.present().unwrap();
+    /// ```
+    #[cfg(all(unix, feature = "mmap"))]
+    pub fn with_mapped_buffer<T: AsRef<Path> + ?Sized>(
+        path: &'a T,
+        (w, h): (u32, u32),
+    ) -> Result<Self, BitMapBackendError> {
+        let mut scratch = path.as_ref().as_os_str().to_owned();
+        scratch.push(".pixels");
+        let len = Self::PIXEL_SIZE * w as usize * h as usize;
+        Ok(Self {
+            target: Target::File(path.as_ref()),
+            size: (w, h),
+            buffer: Buffer::Mapped(mmap_support::MappedFile::new(scratch.into(), len)?),
+            saved: false,
+            text_rendering: TextRendering::Grayscale,
+            hinting: false,
             _pantomdata: PhantomData,
+        })
+    }
+
+    /// Get the path of the memory-mapped scratch file of the backend created by
+    /// `with_mapped_buffer`
+    #[cfg(all(unix, feature = "mmap"))]
+    pub fn mapped_file(&self) -> Option<&Path> {
+        match &self.buffer {
+            Buffer::Mapped(file) => Some(file.path()),
+            _ => None,
         }
     }
 
@@ -748,6 +787,8 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            text_rendering: TextRendering::Grayscale,
+            hinting: false,
             _pantomdata: PhantomData,
         })
     }
@@ -791,10 +832,124 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
             saved: false,
+            text_rendering: TextRendering::Grayscale,
+            hinting: false,
             _pantomdata: PhantomData,
         })
     }
 
+    /// Set how the text is rasterized, the text is anti-aliased with gray levels by default
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 300 * 200 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (300, 200))
+    ///     .text_rendering(TextRendering::Subpixel)
+    ///     .into_drawing_area();
+    /// ```
+    pub fn text_rendering(mut self, rendering: TextRendering) -> Self {
+        self.text_rendering = rendering;
+        self
+    }
+
+    /// Turn on or off the hinting of the text, which is off by default. The hinting snaps the
+    /// x-height of the glyphs and their positions to whole pixels, so the small text is sharper
+    /// at the cost of the exact glyph shapes and spacing. The hinting instructions of the fonts
+    /// aren't run.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 300 * 200 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (300, 200))
+    ///     .hinting(true)
+    ///     .into_drawing_area();
+    /// ```
+    pub fn hinting(mut self, hinting: bool) -> Self {
+        self.hinting = hinting;
+        self
+    }
+
+    // Draw the text with a coverage for each of the red, green and blue sub-pixels
+    fn draw_subpixel_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let color = &style.color;
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let ((min_x, min_y), (max_x, max_y)) = style
+            .font
+            .layout_box(text)
+            .map_err(DrawingErrorKind::FontError)?;
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let dx = match style.pos.h_pos {
+            HPos::Left => 0,
+            HPos::Right => -width,
+            HPos::Center => -width / 2,
+        };
+        let dy = match style.pos.v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+
+        // Rasterize the text three times larger, so each sub-pixel gets its own coverage
+        let font = style.font.resize(style.font.get_size() * 3.0);
+        let ((min_x, min_y), (max_x, max_y)) =
+            font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+        let (cw, ch) = ((max_x - min_x + 3) as usize, (max_y - min_y + 3) as usize);
+        let mut coverage = vec![0.0; cw * ch];
+        font.draw(text, (0, 0), |x, y, v| {
+            let (x, y) = ((x - min_x) as usize, (y - min_y) as usize);
+            if x < cw && y < ch {
+                coverage[y * cw + x] = f64::from(v);
+            }
+            Ok(())
+        })
+        .map_err(DrawingErrorKind::FontError)??;
+
+        let (r, g, b) = color.rgb();
+        let alpha = color.alpha();
+        let (w, h) = self.get_size();
+        let mix = |from: u8, to: u8, a: f64| {
+            (f64::from(from) + (f64::from(to) - f64::from(from)) * a.min(1.0)).round() as u8
+        };
+        for py in 0..ch / 3 {
+            for px in 0..cw / 3 {
+                let mut channels = [0.0; 3];
+                for (c, value) in channels.iter_mut().enumerate() {
+                    let sum: f64 = (py * 3..py * 3 + 3)
+                        .map(|y| coverage[y * cw + px * 3 + c])
+                        .sum();
+                    *value = sum / 3.0 * alpha;
+                }
+                let (x, y) = (pos.0 + dx + px as i32, pos.1 + dy + py as i32);
+                if channels.iter().all(|v| *v <= 0.0)
+                    || x < 0
+                    || y < 0
+                    || x >= w as i32
+                    || y >= h as i32
+                {
+                    continue;
+                }
+                let base = (y as usize * w as usize + x as usize) * P::PIXEL_SIZE;
+                let (br, bg, bb, _) = P::decode_pixel(&self.get_raw_pixel_buffer()[base..]);
+                let rgb = (
+                    mix(br, r, channels[0]),
+                    mix(bg, g, channels[1]),
+                    mix(bb, b, channels[2]),
+                );
+                P::draw_pixel(self, (x, y), rgb, 1.0);
+            }
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     fn get_raw_pixel_buffer(&mut self) -> &mut [u8] {
         self.buffer.borrow_buffer()
@@ -805,8 +960,10 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
     ///
     /// - `area_size`: The size of the area
     /// - **returns**: The splitted backends that can be rendered in parallel
-    pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend<P>> {
+    pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend<'_, P>> {
         let (w, h) = self.get_size();
+        let text_rendering = self.text_rendering;
+        let hinting = self.hinting;
         let buf = self.get_raw_pixel_buffer();
 
         let base_addr = &mut buf[0] as *mut u8;
@@ -825,15 +982,79 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             .zip(split_points.iter().skip(1))
             .map(|(begin, end)| {
                 let actual_buf = unsafe {
-                    std::slice::from_raw_parts_mut(
+                    core::slice::from_raw_parts_mut(
                         base_addr.offset((begin * w) as isize * Self::PIXEL_SIZE as isize),
                         ((end - begin) * w) as usize * Self::PIXEL_SIZE,
                     )
                 };
-                Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
+                Self::with_buffer_and_format(actual_buf, (w, end - begin))
+                    .unwrap()
+                    .text_rendering(text_rendering)
+                    .hinting(hinting)
             })
             .collect()
     }
+
+    /// Split a bitmap backend into horizontal bands of about the same height, the bands are
+    /// disjoint parts of the same buffer, so they can be rendered concurrently and the result
+    /// is in the original buffer once all the bands are dropped.
+    ///
+    /// - `bands`: The number of bands, the result has fewer bands when the bitmap is too short
+    /// - **returns**: The backends of the bands, from the top to the bottom
+    pub fn split_into_bands(&mut self, bands: usize) -> Vec<BitMapBackend<'_, P>> {
+        let bands = bands.max(1);
+        let (_, h) = self.get_size();
+        let height = (h as usize).div_ceil(bands).max(1) as u32;
+        self.split(&vec![height; bands - 1])
+    }
+
+    /// Render the horizontal bands of a bitmap backend in parallel with rayon, see
+    /// `split_into_bands` for how the bitmap is split.
+    ///
+    /// - `bands`: The number of bands
+    /// - `draw_func`: The function that draws a band, it's called with the index of the band
+    ///   and the drawing area of the band
+    /// - **returns**: The first error returned by the drawing function
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = vec![0; 400 * 400 * 3];
+    /// BitMapBackend::with_buffer(&mut buf, (400, 400))
+    ///     .render_bands(4, |idx, area| {
+    ///         area.fill(&WHITE)?;
+    ///         let mut chart = ChartBuilder::on(area)
+    ///             .caption(format!("Panel {}", idx), ("sans-serif", 15))
+    ///             .build_ranged(0.0..1.0, 0.0..1.0)?;
+    ///         chart.draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &RED))?;
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn render_bands<F>(
+        &mut self,
+        bands: usize,
+        draw_func: F,
+    ) -> Result<(), DrawingAreaErrorKind<BitMapBackendError>>
+    where
+        P: Send,
+        F: Fn(
+                usize,
+                &DrawingArea<BitMapBackend<P>, Shift>,
+            ) -> Result<(), DrawingAreaErrorKind<BitMapBackendError>>
+            + Sync,
+    {
+        use rayon::prelude::*;
+        self.split_into_bands(bands)
+            .into_par_iter()
+            .enumerate()
+            .try_for_each(|(idx, band)| {
+                let area = band.into_drawing_area();
+                draw_func(idx, &area)?;
+                area.present()
+            })
+    }
 }
 
 impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
@@ -943,7 +1164,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         let alpha = style.as_color().alpha();
         let (r, g, b) = style.as_color().rgb();
-        if fill {
+        if fill && style.gradient().is_none() && style.pattern().is_none() {
             if alpha >= 1.0 {
                 P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
             } else {
@@ -954,6 +1175,75 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         crate::drawing::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let (w, h) = self.get_size();
+        for (point, color) in pixels {
+            if point.0 < 0 || point.1 < 0 || point.0 as u32 >= w || point.1 as u32 >= h {
+                continue;
+            }
+            P::draw_pixel(self, point, color.rgb(), color.alpha());
+        }
+        Ok(())
+    }
+
+    fn draw_rects<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        rects: I,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !fill || style.gradient().is_some() || style.pattern().is_some() {
+            for (upper_left, bottom_right) in rects {
+                self.draw_rect(upper_left, bottom_right, style, fill)?;
+            }
+            return Ok(());
+        }
+        // The color is resolved once for the whole batch
+        let alpha = style.as_color().alpha();
+        let (r, g, b) = style.as_color().rgb();
+        for (upper_left, bottom_right) in rects {
+            if alpha >= 1.0 {
+                P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
+            } else {
+                P::blend_rect_fast(self, upper_left, bottom_right, r, g, b, alpha);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let hinted;
+        let style = if self.hinting {
+            hinted = TextStyle {
+                font: style.font.hinting(true),
+                ..style.clone()
+            };
+            &hinted
+        } else {
+            style
+        };
+        let rotated = !matches!(style.font.get_transform(), FontTransform::None);
+        match self.text_rendering {
+            // The built-in font of the deterministic mode has no sub-pixel glyphs
+            TextRendering::Subpixel if !rotated && !is_deterministic_rendering() => {
+                self.draw_subpixel_text(text, style, pos)
+            }
+            TextRendering::Aliased => {
+                let coverage = |v: f32| if v >= 0.5 { 1.0 } else { 0.0 };
+                crate::drawing::rasterizer::draw_text(self, text, style, pos, coverage)
+            }
+            _ => crate::drawing::rasterizer::draw_text(self, text, style, pos, f64::from),
+        }
+    }
+
     fn blit_bitmap<'b>(
         &mut self,
         pos: BackendCoord,
@@ -1038,6 +1328,39 @@ fn test_bitmap_backend() {
     assert!(buffer.into_iter().all(|x| x == 255));
 }
 
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_split_into_bands() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 10 * 10 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let bands = back.split_into_bands(3);
+        assert_eq!(
+            bands.iter().map(|b| b.get_size()).collect::<Vec<_>>(),
+            vec![(10, 4), (10, 4), (10, 2)]
+        );
+        for (idx, band) in bands.into_iter().enumerate() {
+            let area = band.into_drawing_area();
+            let color = if idx == 1 { RED } else { BLUE };
+            area.draw_pixel((0, 0), &color).unwrap();
+            area.present().unwrap();
+        }
+    }
+
+    // The origin of each band is the first row of the band in the original bitmap
+    for y in 0..10 {
+        let expected = match y {
+            0 | 8 => (0, 0, 255),
+            4 => (255, 0, 0),
+            _ => (0, 0, 0),
+        };
+        let pixel = (buffer[y * 30], buffer[y * 30 + 1], buffer[y * 30 + 2]);
+        assert_eq!(pixel, expected);
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_bitmap_backend_fill_half() {
@@ -1341,6 +1664,293 @@ fn test_draw_simple_lines() {
     assert_eq!(nz_count, 6 * 1000 * 3);
 }
 
+#[cfg(test)]
+#[test]
+fn test_draw_thick_circle_and_filled_rect() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 100 * 100 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (100, 100));
+        back.draw_circle(
+            (50, 50),
+            20,
+            &ShapeStyle::from(&WHITE).stroke_width(4),
+            false,
+        )
+        .unwrap();
+        // The stroke width doesn't make the filled rectangle larger
+        back.draw_rect((0, 0), (9, 9), &WHITE.filled().stroke_width(5), true)
+            .unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| buffer[(y * 100 + x) * 3];
+    for &(x, y) in [(68, 50), (72, 50), (50, 28), (50, 72), (30, 50)].iter() {
+        assert_eq!(pixel(x, y), 255);
+    }
+    for &(x, y) in [(50, 50), (66, 50), (74, 50), (11, 5), (5, 11)].iter() {
+        assert_eq!(pixel(x, y), 0);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_gradient_fills() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 100 * 100 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (100, 100));
+        let style =
+            ShapeStyle::from(&RED).gradient(Gradient::linear(0.0).stop(0.0, &RED).stop(1.0, &BLUE));
+        back.draw_rect((0, 0), (40, 40), &style, true).unwrap();
+        let style = ShapeStyle::from(&RED)
+            .gradient(Gradient::linear(90.0).stop(0.0, &GREEN).stop(1.0, &BLUE));
+        back.fill_polygon(vec![(50, 50), (99, 50), (99, 99), (50, 99)], &style)
+            .unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 100 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+    assert_eq!(pixel(0, 20), (255, 0, 0));
+    assert_eq!(pixel(20, 20), (128, 0, 128));
+    assert_eq!(pixel(40, 20), (0, 0, 255));
+    let (top, bottom) = (pixel(75, 55), pixel(75, 95));
+    assert!(top.1 > 200 && top.2 < 50);
+    assert!(bottom.1 < 50 && bottom.2 > 200);
+}
+
+#[cfg(all(test, not(feature = "ttf")))]
+#[test]
+fn test_text_without_ttf() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 100 * 40 * 3];
+    let root = BitMapBackend::with_buffer(&mut buffer, (100, 40)).into_drawing_area();
+    // The text can't be drawn without a rasterizer, but it's an error rather than a panic
+    assert!(root
+        .draw_text("x", &("sans-serif", 20).into(), (0, 0))
+        .is_err());
+    root.draw_pixel((0, 0), &RED).unwrap();
+}
+
+#[cfg(all(test, feature = "ttf"))]
+#[test]
+fn test_text_rendering() {
+    use crate::prelude::*;
+    let _lock = crate::style::DETERMINISTIC_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let draw = |rendering: TextRendering| {
+        let mut buffer = vec![255; 100 * 40 * 3];
+        {
+            let mut back =
+                BitMapBackend::with_buffer(&mut buffer, (100, 40)).text_rendering(rendering);
+            let style = TextStyle::from(("sans-serif", 14).into_font()).color(&BLACK);
+            back.draw_text("Axis 42", &style, (5, 5)).unwrap();
+        }
+        buffer
+    };
+
+    let aliased = draw(TextRendering::Aliased);
+    assert!(aliased.iter().all(|v| *v == 0 || *v == 255));
+    assert!(aliased.contains(&0));
+
+    // The sub-pixels of the glyph edges are covered differently
+    let subpixel = draw(TextRendering::Subpixel);
+    assert!(subpixel.chunks(3).any(|p| p[0] != p[1] || p[1] != p[2]));
+    let grayscale = draw(TextRendering::Grayscale);
+    assert!(grayscale.chunks(3).all(|p| p[0] == p[1] && p[1] == p[2]));
+}
+
+#[cfg(all(test, feature = "ttf"))]
+#[test]
+fn test_hinting() {
+    use crate::prelude::*;
+    let _lock = crate::style::DETERMINISTIC_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let draw = |hinting: bool| {
+        let mut buffer = vec![255; 100 * 40 * 3];
+        {
+            let mut back = BitMapBackend::with_buffer(&mut buffer, (100, 40)).hinting(hinting);
+            let style = TextStyle::from(("sans-serif", 13.3).into_font()).color(&BLACK);
+            back.draw_text("xenon", &style, (5, 5)).unwrap();
+        }
+        buffer
+    };
+
+    let hinted = draw(true);
+    assert!(hinted.contains(&0));
+    assert!(hinted != draw(false));
+
+    // The split backends keep the hinting
+    let mut buffer = vec![255; 100 * 40 * 3];
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (100, 40)).hinting(true);
+        let mut parts = back.split(&[40]);
+        let style = TextStyle::from(("sans-serif", 13.3).into_font()).color(&BLACK);
+        parts[0].draw_text("xenon", &style, (5, 5)).unwrap();
+    }
+    assert!(buffer == hinted);
+}
+
+#[cfg(test)]
+#[test]
+fn test_deterministic_rendering() {
+    use crate::prelude::*;
+    let _lock = crate::style::DETERMINISTIC_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    use crate::style::set_deterministic_rendering;
+    let draw = |family: &str, rendering: TextRendering| {
+        let mut buffer = vec![0; 200 * 100 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, (200, 100))
+                .text_rendering(rendering)
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Golden", (family, 20))
+                .x_label_area_size(20)
+                .y_label_area_size(30)
+                .build_ranged(0.0..1.0, 0.0..1.0)
+                .unwrap();
+            chart
+                .configure_mesh()
+                .label_style((family, 12))
+                .draw()
+                .unwrap();
+        }
+        buffer
+    };
+
+    set_deterministic_rendering(true);
+    let golden = draw("sans-serif", TextRendering::Grayscale);
+    // The built-in font doesn't depend on the family and the fonts installed
+    assert!(golden == draw("serif", TextRendering::Subpixel));
+    assert!(golden == draw("no-such-font", TextRendering::Aliased));
+    assert!(golden.chunks(3).all(|p| p[0] == p[1] && p[1] == p[2]));
+    // The worker threads drawing the split backends are in the same mode
+    assert!(std::thread::spawn(is_deterministic_rendering)
+        .join()
+        .unwrap());
+    set_deterministic_rendering(false);
+    assert!(!is_deterministic_rendering());
+}
+
+#[cfg(all(test, unix, feature = "mmap"))]
+#[test]
+fn test_bitmap_mapped_buffer() {
+    use crate::prelude::*;
+    let path = std::env::temp_dir().join(format!("plotters-mmap-{}.png", std::process::id()));
+    let scratch;
+    {
+        let backend = BitMapBackend::with_mapped_buffer(&path, (64, 32)).unwrap();
+        scratch = backend.mapped_file().unwrap().to_path_buf();
+        assert!(scratch.exists());
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        root.draw(&Rectangle::new(
+            [(10, 5), (20, 15)],
+            RGBColor(10, 20, 30).filled(),
+        ))
+        .unwrap();
+        root.present().unwrap();
+    }
+    // The scratch file is removed and the image is encoded from the mapped pixels
+    assert!(!scratch.exists());
+    let image = image::open(&path).unwrap().to_rgb();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(image.dimensions(), (64, 32));
+    assert_eq!(image.get_pixel(15, 10).0, [10, 20, 30]);
+    assert_eq!(image.get_pixel(30, 10).0, [255, 255, 255]);
+
+    assert!(BitMapBackend::with_mapped_buffer(&path, (0, 10)).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_rect_fast() {
+    use crate::prelude::*;
+    // The fast fill agrees with the per-pixel drawing for any alignment of the rectangle
+    for &(ul, br) in [((3, 1), (16, 7)), ((0, 2), (18, 9)), ((5, 5), (5, 5))].iter() {
+        for color in [RGBColor(12, 34, 56), RGBColor(200, 200, 200)].iter() {
+            let mut fast = vec![7; 19 * 11 * 4];
+            let mut slow = fast.clone();
+            BitMapBackend::<BGRXPixel>::with_buffer_and_format(&mut fast, (19, 11))
+                .unwrap()
+                .draw_rect(ul, br, color, true)
+                .unwrap();
+            {
+                let mut back =
+                    BitMapBackend::<BGRXPixel>::with_buffer_and_format(&mut slow, (19, 11))
+                        .unwrap();
+                for x in ul.0..=br.0 {
+                    for y in ul.1..=br.1 {
+                        back.draw_pixel((x, y), &color.to_rgba()).unwrap();
+                    }
+                }
+            }
+            let rgb =
+                |buf: &[u8]| -> Vec<u8> { buf.chunks(4).flat_map(|p| p[..3].to_vec()).collect() };
+            assert_eq!(rgb(&fast), rgb(&slow));
+
+            let mut fast = vec![7; 19 * 11 * 3];
+            let mut slow = fast.clone();
+            BitMapBackend::with_buffer(&mut fast, (19, 11))
+                .draw_rect(ul, br, color, true)
+                .unwrap();
+            {
+                let mut back = BitMapBackend::with_buffer(&mut slow, (19, 11));
+                for x in ul.0..=br.0 {
+                    for y in ul.1..=br.1 {
+                        back.draw_pixel((x, y), &color.to_rgba()).unwrap();
+                    }
+                }
+            }
+            assert_eq!(fast, slow);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_pattern_fills() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 100 * 100 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (100, 100));
+        let hatch = Pattern::new(PatternKind::DiagonalHatch).spacing(4);
+        back.draw_rect(
+            (0, 0),
+            (40, 40),
+            &ShapeStyle::from(&RED).pattern(hatch),
+            true,
+        )
+        .unwrap();
+        let lines = Pattern::new(PatternKind::HorizontalLines)
+            .spacing(5)
+            .background(&WHITE);
+        back.fill_polygon(
+            vec![(50, 50), (99, 50), (99, 99), (50, 99)],
+            &ShapeStyle::from(&BLUE).pattern(lines),
+        )
+        .unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 100 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+    assert_eq!(pixel(3, 1), (255, 0, 0));
+    assert_eq!(pixel(4, 1), (0, 0, 0));
+    assert_eq!(pixel(75, 60), (0, 0, 255));
+    assert_eq!(pixel(75, 61), (255, 255, 255));
+}
+
 #[cfg(test)]
 #[test]
 fn test_bitmap_blit() {
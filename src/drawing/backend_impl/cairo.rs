@@ -4,7 +4,7 @@ use cairo::{Context as CairoContext, FontSlant, FontWeight, Status as CairoStatu
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 use crate::style::text_anchor::{HPos, VPos};
 #[allow(unused_imports)]
-use crate::style::{Color, FontDesc, FontStyle, FontTransform, RGBAColor, TextStyle};
+use crate::style::{Color, FontDesc, FontTransform, RGBAColor, TextStyle};
 
 /// The drawing backend that is backed with a Cairo context
 pub struct CairoBackend<'a> {
@@ -68,20 +68,19 @@ impl<'a> CairoBackend<'a> {
     fn set_font<'b>(&self, font: &FontDesc<'b>) -> Result<(), DrawingErrorKind<CairoError>> {
         let actual_size = font.get_size();
         self.call_cairo(|c| {
-            match font.get_style() {
-                FontStyle::Normal => {
-                    c.select_font_face(font.get_name(), FontSlant::Normal, FontWeight::Normal)
-                }
-                FontStyle::Bold => {
-                    c.select_font_face(font.get_name(), FontSlant::Normal, FontWeight::Bold)
-                }
-                FontStyle::Oblique => {
-                    c.select_font_face(font.get_name(), FontSlant::Oblique, FontWeight::Normal)
-                }
-                FontStyle::Italic => {
-                    c.select_font_face(font.get_name(), FontSlant::Italic, FontWeight::Normal)
-                }
+            let style = font.get_style();
+            let slant = match style.slant() {
+                "italic" => FontSlant::Italic,
+                "oblique" => FontSlant::Oblique,
+                _ => FontSlant::Normal,
+            };
+            // Cairo's toy font API only has the normal and the bold weights
+            let weight = if style.weight() >= 600 {
+                FontWeight::Bold
+            } else {
+                FontWeight::Normal
             };
+            c.select_font_face(font.get_name(), slant, weight);
             c.set_font_size(actual_size);
         })
     }
@@ -281,6 +280,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
             FontTransform::Rotate90 => 90.0,
             FontTransform::Rotate180 => 180.0,
             FontTransform::Rotate270 => 270.0,
+            FontTransform::RotateAngle(angle) => f64::from(angle),
         } / 180.0
             * std::f64::consts::PI;
 
@@ -1,6 +1,9 @@
 use js_sys::JSON;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
+use web_sys::{
+    window, CanvasRenderingContext2d, HtmlCanvasElement, OffscreenCanvas,
+    OffscreenCanvasRenderingContext2d,
+};
 
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 use crate::style::text_anchor::{HPos, VPos};
@@ -61,18 +64,8 @@ impl CanvasBackend {
     pub fn with_canvas_object(canvas: HtmlCanvasElement) -> Option<Self> {
         Self::init_backend(canvas)
     }
-}
 
-fn make_canvas_color(color: RGBAColor) -> JsValue {
-    let (r, g, b) = color.rgb();
-    let a = color.alpha();
-    format!("rgba({},{},{},{})", r, g, b, a).into()
-}
-
-impl DrawingBackend for CanvasBackend {
-    type ErrorType = CanvasError;
-
-    fn get_size(&self) -> (u32, u32) {
+    fn canvas_size(&self) -> (u32, u32) {
         // Getting just canvas.width gives poor results on HighDPI screens.
         let window = window().unwrap();
         let mut dpr = window.device_pixel_ratio();
@@ -80,223 +73,294 @@ impl DrawingBackend for CanvasBackend {
         ((self.canvas.width() as f64 / dpr) as u32,
          (self.canvas.height() as f64 / dpr) as u32)
     }
+}
 
-    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
-        Ok(())
-    }
+/// The backend that is drawing on an `OffscreenCanvas`, which is available in the web workers,
+/// so the heavy charts can be rendered without blocking the UI thread of the browser.
+///
+/// The offscreen canvas either comes from `HtmlCanvasElement::transfer_control_to_offscreen`,
+/// then the drawings are shown on the canvas element automatically, or it's created in the
+/// worker, then the frames are taken with `OffscreenCanvas::transfer_to_image_bitmap`, posted
+/// to the main thread as the transferable objects without copying the pixels, and shown with
+/// `ImageBitmapRenderingContext::transfer_from_image_bitmap`.
+///
+/// ```ignore
+/// // In the web worker
+/// let canvas = OffscreenCanvas::new(800, 600)?;
+/// let backend = OffscreenCanvasBackend::with_offscreen_canvas(canvas.clone()).unwrap();
+/// draw_chart(&backend.into_drawing_area())?;
+/// let bitmap = canvas.transfer_to_image_bitmap()?;
+/// scope.post_message_with_transfer(&bitmap, &js_sys::Array::of1(&bitmap))?;
+/// ```
+pub struct OffscreenCanvasBackend {
+    canvas: OffscreenCanvas,
+    context: OffscreenCanvasRenderingContext2d,
+}
 
-    fn present(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
-        Ok(())
+impl OffscreenCanvasBackend {
+    /// Create a new drawing backend on a new offscreen canvas of the given size
+    /// - `width`: The width of the canvas in pixels
+    /// - `height`: The height of the canvas in pixels
+    /// - Return either the drawing backend or None for error
+    pub fn new(width: u32, height: u32) -> Option<Self> {
+        Self::with_offscreen_canvas(OffscreenCanvas::new(width, height).ok()?)
     }
 
-    fn draw_pixel(
-        &mut self,
-        point: BackendCoord,
-        style: &RGBAColor,
-    ) -> Result<(), DrawingErrorKind<CanvasError>> {
-        if style.alpha() == 0.0 {
-            return Ok(());
-        }
+    /// Create a new drawing backend on the offscreen canvas passed in, for example, the one
+    /// transferred from the main thread
+    /// - `canvas`: The offscreen canvas we want to use as backend
+    /// - Return either the drawing backend or None for error
+    pub fn with_offscreen_canvas(canvas: OffscreenCanvas) -> Option<Self> {
+        let context: OffscreenCanvasRenderingContext2d =
+            canvas.get_context("2d").ok()??.dyn_into().ok()?;
+        Some(OffscreenCanvasBackend { canvas, context })
+    }
 
-        self.context
-            .set_fill_style(&make_canvas_color(style.as_color()));
-        self.context
-            .fill_rect(f64::from(point.0), f64::from(point.1), 1.0, 1.0);
-        Ok(())
+    fn canvas_size(&self) -> (u32, u32) {
+        (self.canvas.width(), self.canvas.height())
     }
+}
 
-    fn draw_line<S: BackendStyle>(
-        &mut self,
-        from: BackendCoord,
-        to: BackendCoord,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.as_color().alpha() == 0.0 {
-            return Ok(());
-        }
+fn make_canvas_color(color: RGBAColor) -> JsValue {
+    let (r, g, b) = color.rgb();
+    let a = color.alpha();
+    format!("rgba({},{},{},{})", r, g, b, a).into()
+}
 
-        self.context
-            .set_stroke_style(&make_canvas_color(style.as_color()));
-        self.context.set_line_width(style.stroke_width() as f64);
-        self.context.begin_path();
-        self.context.move_to(f64::from(from.0), f64::from(from.1));
-        self.context.line_to(f64::from(to.0), f64::from(to.1));
-        self.context.stroke();
-        Ok(())
-    }
+// The drawing operations are the same on the 2D contexts of the HTML canvas and the offscreen
+// canvas, which are different types in web-sys
+macro_rules! impl_canvas_backend {
+    ($backend:ty) => {
+        impl DrawingBackend for $backend {
+            type ErrorType = CanvasError;
 
-    fn draw_rect<S: BackendStyle>(
-        &mut self,
-        upper_left: BackendCoord,
-        bottom_right: BackendCoord,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.as_color().alpha() == 0.0 {
-            return Ok(());
-        }
-        if fill {
-            self.context
-                .set_fill_style(&make_canvas_color(style.as_color()));
-            self.context.fill_rect(
-                f64::from(upper_left.0),
-                f64::from(upper_left.1),
-                f64::from(bottom_right.0 - upper_left.0),
-                f64::from(bottom_right.1 - upper_left.1),
-            );
-        } else {
-            self.context
-                .set_stroke_style(&make_canvas_color(style.as_color()));
-            self.context.stroke_rect(
-                f64::from(upper_left.0),
-                f64::from(upper_left.1),
-                f64::from(bottom_right.0 - upper_left.0),
-                f64::from(bottom_right.1 - upper_left.1),
-            );
-        }
-        Ok(())
-    }
+            fn get_size(&self) -> (u32, u32) {
+                self.canvas_size()
+            }
 
-    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
-        &mut self,
-        path: I,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.as_color().alpha() == 0.0 {
-            return Ok(());
-        }
-        let mut path = path.into_iter();
-        self.context.begin_path();
-        if let Some(start) = path.next() {
-            self.context
-                .set_stroke_style(&make_canvas_color(style.as_color()));
-            self.context.move_to(f64::from(start.0), f64::from(start.1));
-            for next in path {
-                self.context.line_to(f64::from(next.0), f64::from(next.1));
+            fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
+                Ok(())
             }
-        }
-        self.context.stroke();
-        Ok(())
-    }
 
-    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
-        &mut self,
-        path: I,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.as_color().alpha() == 0.0 {
-            return Ok(());
-        }
-        let mut path = path.into_iter();
-        self.context.begin_path();
-        if let Some(start) = path.next() {
-            self.context
-                .set_fill_style(&make_canvas_color(style.as_color()));
-            self.context.move_to(f64::from(start.0), f64::from(start.1));
-            for next in path {
-                self.context.line_to(f64::from(next.0), f64::from(next.1));
+            fn present(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
+                Ok(())
             }
-            self.context.close_path();
-        }
-        self.context.fill();
-        Ok(())
-    }
 
-    fn draw_circle<S: BackendStyle>(
-        &mut self,
-        center: BackendCoord,
-        radius: u32,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.as_color().alpha() == 0.0 {
-            return Ok(());
-        }
-        if fill {
-            self.context
-                .set_fill_style(&make_canvas_color(style.as_color()));
-        } else {
-            self.context
-                .set_stroke_style(&make_canvas_color(style.as_color()));
-        }
-        self.context.begin_path();
-        self.context.arc(
-            f64::from(center.0),
-            f64::from(center.1),
-            f64::from(radius),
-            0.0,
-            std::f64::consts::PI * 2.0,
-        )?;
-        if fill {
-            self.context.fill();
-        } else {
-            self.context.stroke();
-        }
-        Ok(())
-    }
+            fn draw_pixel(
+                &mut self,
+                point: BackendCoord,
+                style: &RGBAColor,
+            ) -> Result<(), DrawingErrorKind<CanvasError>> {
+                if style.alpha() == 0.0 {
+                    return Ok(());
+                }
 
-    fn draw_text(
-        &mut self,
-        text: &str,
-        style: &TextStyle,
-        pos: BackendCoord,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let font = &style.font;
-        let color = &style.color;
-        if color.alpha() == 0.0 {
-            return Ok(());
-        }
+                self.context
+                    .set_fill_style(&make_canvas_color(style.as_color()));
+                self.context
+                    .fill_rect(f64::from(point.0), f64::from(point.1), 1.0, 1.0);
+                Ok(())
+            }
 
-        let (mut x, mut y) = (pos.0, pos.1);
-
-        let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
-            FontTransform::Rotate90 => 90.0,
-            FontTransform::Rotate180 => 180.0,
-            FontTransform::Rotate270 => 270.0,
-        } / 180.0
-            * std::f64::consts::PI;
-
-        if degree != 0.0 {
-            self.context.save();
-            self.context.translate(f64::from(x), f64::from(y))?;
-            self.context.rotate(degree)?;
-            x = 0;
-            y = 0;
-        }
+            fn draw_line<S: BackendStyle>(
+                &mut self,
+                from: BackendCoord,
+                to: BackendCoord,
+                style: &S,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                if style.as_color().alpha() == 0.0 {
+                    return Ok(());
+                }
 
-        let text_baseline = match style.pos.v_pos {
-            VPos::Top => "top",
-            VPos::Center => "middle",
-            VPos::Bottom => "bottom",
-        };
-        self.context.set_text_baseline(text_baseline);
-
-        let text_align = match style.pos.h_pos {
-            HPos::Left => "start",
-            HPos::Right => "end",
-            HPos::Center => "center",
-        };
-        self.context.set_text_align(text_align);
-
-        self.context
-            .set_fill_style(&make_canvas_color(color.clone()));
-        self.context.set_font(&format!(
-            "{} {}px {}",
-            font.get_style().as_str(),
-            font.get_size(),
-            font.get_name()
-        ));
-        self.context.fill_text(text, f64::from(x), f64::from(y))?;
-
-        if degree != 0.0 {
-            self.context.restore();
-        }
+                self.context
+                    .set_stroke_style(&make_canvas_color(style.as_color()));
+                self.context.set_line_width(style.stroke_width() as f64);
+                self.context.begin_path();
+                self.context.move_to(f64::from(from.0), f64::from(from.1));
+                self.context.line_to(f64::from(to.0), f64::from(to.1));
+                self.context.stroke();
+                Ok(())
+            }
 
-        Ok(())
-    }
+            fn draw_rect<S: BackendStyle>(
+                &mut self,
+                upper_left: BackendCoord,
+                bottom_right: BackendCoord,
+                style: &S,
+                fill: bool,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                if style.as_color().alpha() == 0.0 {
+                    return Ok(());
+                }
+                if fill {
+                    self.context
+                        .set_fill_style(&make_canvas_color(style.as_color()));
+                    self.context.fill_rect(
+                        f64::from(upper_left.0),
+                        f64::from(upper_left.1),
+                        f64::from(bottom_right.0 - upper_left.0),
+                        f64::from(bottom_right.1 - upper_left.1),
+                    );
+                } else {
+                    self.context
+                        .set_stroke_style(&make_canvas_color(style.as_color()));
+                    self.context.stroke_rect(
+                        f64::from(upper_left.0),
+                        f64::from(upper_left.1),
+                        f64::from(bottom_right.0 - upper_left.0),
+                        f64::from(bottom_right.1 - upper_left.1),
+                    );
+                }
+                Ok(())
+            }
+
+            fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+                &mut self,
+                path: I,
+                style: &S,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                if style.as_color().alpha() == 0.0 {
+                    return Ok(());
+                }
+                let mut path = path.into_iter();
+                self.context.begin_path();
+                if let Some(start) = path.next() {
+                    self.context
+                        .set_stroke_style(&make_canvas_color(style.as_color()));
+                    self.context.move_to(f64::from(start.0), f64::from(start.1));
+                    for next in path {
+                        self.context.line_to(f64::from(next.0), f64::from(next.1));
+                    }
+                }
+                self.context.stroke();
+                Ok(())
+            }
+
+            fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+                &mut self,
+                path: I,
+                style: &S,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                if style.as_color().alpha() == 0.0 {
+                    return Ok(());
+                }
+                let mut path = path.into_iter();
+                self.context.begin_path();
+                if let Some(start) = path.next() {
+                    self.context
+                        .set_fill_style(&make_canvas_color(style.as_color()));
+                    self.context.move_to(f64::from(start.0), f64::from(start.1));
+                    for next in path {
+                        self.context.line_to(f64::from(next.0), f64::from(next.1));
+                    }
+                    self.context.close_path();
+                }
+                self.context.fill();
+                Ok(())
+            }
+
+            fn draw_circle<S: BackendStyle>(
+                &mut self,
+                center: BackendCoord,
+                radius: u32,
+                style: &S,
+                fill: bool,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                if style.as_color().alpha() == 0.0 {
+                    return Ok(());
+                }
+                if fill {
+                    self.context
+                        .set_fill_style(&make_canvas_color(style.as_color()));
+                } else {
+                    self.context
+                        .set_stroke_style(&make_canvas_color(style.as_color()));
+                }
+                self.context.begin_path();
+                self.context.arc(
+                    f64::from(center.0),
+                    f64::from(center.1),
+                    f64::from(radius),
+                    0.0,
+                    std::f64::consts::PI * 2.0,
+                )?;
+                if fill {
+                    self.context.fill();
+                } else {
+                    self.context.stroke();
+                }
+                Ok(())
+            }
+
+            fn draw_text(
+                &mut self,
+                text: &str,
+                style: &TextStyle,
+                pos: BackendCoord,
+            ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+                let font = &style.font;
+                let color = &style.color;
+                if color.alpha() == 0.0 {
+                    return Ok(());
+                }
+
+                let (mut x, mut y) = (pos.0, pos.1);
+
+                let degree = match font.get_transform() {
+                    FontTransform::None => 0.0,
+                    FontTransform::Rotate90 => 90.0,
+                    FontTransform::Rotate180 => 180.0,
+                    FontTransform::Rotate270 => 270.0,
+                    FontTransform::RotateAngle(angle) => f64::from(angle),
+                } / 180.0
+                    * std::f64::consts::PI;
+
+                if degree != 0.0 {
+                    self.context.save();
+                    self.context.translate(f64::from(x), f64::from(y))?;
+                    self.context.rotate(degree)?;
+                    x = 0;
+                    y = 0;
+                }
+
+                let text_baseline = match style.pos.v_pos {
+                    VPos::Top => "top",
+                    VPos::Center => "middle",
+                    VPos::Bottom => "bottom",
+                };
+                self.context.set_text_baseline(text_baseline);
+
+                let text_align = match style.pos.h_pos {
+                    HPos::Left => "start",
+                    HPos::Right => "end",
+                    HPos::Center => "center",
+                };
+                self.context.set_text_align(text_align);
+
+                self.context
+                    .set_fill_style(&make_canvas_color(color.clone()));
+                self.context.set_font(&format!(
+                    "{} {} {}px {}",
+                    font.get_style().slant(),
+                    font.get_style().weight(),
+                    font.get_size(),
+                    font.get_name()
+                ));
+                self.context.fill_text(text, f64::from(x), f64::from(y))?;
+
+                if degree != 0.0 {
+                    self.context.restore();
+                }
+
+                Ok(())
+            }
+        }
+    };
 }
 
+impl_canvas_backend!(CanvasBackend);
+impl_canvas_backend!(OffscreenCanvasBackend);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -530,4 +594,26 @@ mod test {
 
         check_content(&document, "test_draw_pixel_alphas");
     }
+
+    #[wasm_bindgen_test]
+    fn test_offscreen_canvas() {
+        let canvas = OffscreenCanvas::new(300, 200).unwrap();
+        let backend = OffscreenCanvasBackend::with_offscreen_canvas(canvas.clone())
+            .expect("cannot create the offscreen canvas");
+        assert_eq!(backend.get_size(), (300, 200));
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE).unwrap();
+
+        let mut chart = ChartBuilder::on(&root)
+            .set_all_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        chart
+            .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+            .unwrap();
+
+        let bitmap = canvas.transfer_to_image_bitmap().unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (300, 200));
+    }
 }
@@ -4,7 +4,7 @@ use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, Drawin
 use crate::drawing::DrawingArea;
 use crate::style::{Color, RGBAColor, TextStyle};
 
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
 
 pub struct MockedBackend {
     height: u32,
@@ -18,6 +18,7 @@ pub struct MockedBackend {
     pub num_draw_text_call: u32,
     pub num_draw_path_call: u32,
     pub num_fill_polygon_call: u32,
+    pub num_present_call: u32,
     check_draw_pixel: VecDeque<Box<dyn FnMut(RGBAColor, BackendCoord)>>,
     check_draw_line: VecDeque<Box<dyn FnMut(RGBAColor, u32, BackendCoord, BackendCoord)>>,
     check_draw_rect: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, BackendCoord)>>,
@@ -57,6 +58,7 @@ impl MockedBackend {
             num_draw_text_call: 0,
             num_draw_path_call: 0,
             num_fill_polygon_call: 0,
+            num_present_call: 0,
             check_draw_pixel: vec![].into(),
             check_draw_line: vec![].into(),
             check_draw_rect: vec![].into(),
@@ -93,13 +95,13 @@ impl MockedBackend {
 #[derive(Debug)]
 pub struct MockedError;
 
-impl std::fmt::Display for MockedError {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for MockedError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(fmt, "MockedError")
     }
 }
 
-impl std::error::Error for MockedError {}
+impl core::error::Error for MockedError {}
 
 impl DrawingBackend for MockedBackend {
     type ErrorType = MockedError;
@@ -114,6 +116,7 @@ impl DrawingBackend for MockedBackend {
     }
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<MockedError>> {
+        self.num_present_call += 1;
         self.init_count = 0;
         self.draw_count = 0;
         Ok(())
@@ -257,7 +260,7 @@ impl DrawingBackend for MockedBackend {
 impl Drop for MockedBackend {
     fn drop(&mut self) {
         let mut temp = None;
-        std::mem::swap(&mut temp, &mut self.drop_check);
+        core::mem::swap(&mut temp, &mut self.drop_check);
 
         if let Some(mut checker) = temp {
             checker(self);
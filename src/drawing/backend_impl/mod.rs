@@ -6,7 +6,7 @@ pub use self::svg::SVGBackend;
 #[cfg(feature = "bitmap")]
 mod bitmap;
 #[cfg(feature = "bitmap")]
-pub use bitmap::BitMapBackend;
+pub use bitmap::{BitMapBackend, TextRendering};
 
 #[cfg(feature = "bitmap")]
 pub mod bitmap_pixel {
@@ -16,7 +16,7 @@ pub mod bitmap_pixel {
 #[cfg(target_arch = "wasm32")]
 mod canvas;
 #[cfg(target_arch = "wasm32")]
-pub use canvas::CanvasBackend;
+pub use canvas::{CanvasBackend, OffscreenCanvasBackend};
 
 #[cfg(test)]
 mod mocked;
@@ -37,10 +37,10 @@ pub use self::cairo::CairoBackend;
 #[derive(Debug)]
 pub struct DummyBackendError;
 
-impl std::fmt::Display for DummyBackendError {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for DummyBackendError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(fmt, "{:?}", self)
     }
 }
 
-impl std::error::Error for DummyBackendError {}
+impl core::error::Error for DummyBackendError {}
@@ -4,12 +4,16 @@ The SVG image drawing backend
 
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 use crate::style::text_anchor::{HPos, VPos};
-use crate::style::{Color, FontStyle, FontTransform, RGBAColor, TextStyle};
+use crate::style::{
+    Color, FontTransform, Gradient, GradientKind, Pattern, PatternKind, RGBAColor, TextStyle,
+};
 
+use std::collections::HashMap;
 use std::fs::File;
 #[allow(unused_imports)]
 use std::io::Cursor;
 use std::io::{BufWriter, Error, Write};
+use std::ops::Range;
 use std::path::Path;
 
 fn make_svg_color<C: Color>(color: &C) -> String {
@@ -42,14 +46,22 @@ impl Target<'_> {
     }
 }
 
+#[derive(Clone)]
 enum SVGTag {
     SVG,
+    Defs,
+    LinearGradient,
+    RadialGradient,
+    Stop,
+    Pattern,
     Circle,
     Line,
+    Path,
     Polygon,
     Polyline,
     Rectangle,
     Text,
+    Group,
     #[allow(dead_code)]
     Image,
 }
@@ -58,15 +70,72 @@ impl SVGTag {
     fn to_tag_name(&self) -> &'static str {
         match self {
             SVGTag::SVG => "svg",
+            SVGTag::Defs => "defs",
+            SVGTag::LinearGradient => "linearGradient",
+            SVGTag::RadialGradient => "radialGradient",
+            SVGTag::Stop => "stop",
+            SVGTag::Pattern => "pattern",
             SVGTag::Circle => "circle",
             SVGTag::Line => "line",
+            SVGTag::Path => "path",
             SVGTag::Polyline => "polyline",
             SVGTag::Rectangle => "rect",
             SVGTag::Text => "text",
+            SVGTag::Group => "g",
             SVGTag::Image => "image",
             SVGTag::Polygon => "polygon",
         }
     }
+
+    fn is_shape(&self) -> bool {
+        matches!(
+            self,
+            SVGTag::Circle
+                | SVGTag::Line
+                | SVGTag::Path
+                | SVGTag::Polygon
+                | SVGTag::Polyline
+                | SVGTag::Rectangle
+        )
+    }
+}
+
+// The attributes which are moved into the shared CSS classes
+const STYLE_ATTRS: [&str; 4] = ["fill", "opacity", "stroke", "stroke-width"];
+
+// Drop the points within `tolerance` pixels of the previous point, and the points in the middle
+// of a straight run, the end points are always kept
+fn merge_points<I: IntoIterator<Item = BackendCoord>>(
+    path: I,
+    tolerance: u32,
+) -> Vec<BackendCoord> {
+    let tolerance = tolerance as i32;
+    let mut merged: Vec<BackendCoord> = vec![];
+    let mut last = None;
+    for p in path {
+        last = Some(p);
+        if let Some(&(x, y)) = merged.last() {
+            if (p.0 - x).abs() <= tolerance && (p.1 - y).abs() <= tolerance {
+                continue;
+            }
+        }
+        if merged.len() >= 2 {
+            let (a, b) = (merged[merged.len() - 2], merged[merged.len() - 1]);
+            let (d0, d1) = ((b.0 - a.0, b.1 - a.1), (p.0 - b.0, p.1 - b.1));
+            let cross = i64::from(d0.0) * i64::from(d1.1) - i64::from(d0.1) * i64::from(d1.0);
+            let dot = i64::from(d0.0) * i64::from(d1.0) + i64::from(d0.1) * i64::from(d1.1);
+            if cross == 0 && dot > 0 {
+                merged.pop();
+            }
+        }
+        merged.push(p);
+    }
+    if let Some(p) = last {
+        if merged.last() != Some(&p) {
+            merged.push(p);
+        }
+    }
+    merged
 }
 
 /// The SVG image drawing backend
@@ -75,8 +144,48 @@ pub struct SVGBackend<'a> {
     size: (u32, u32),
     tag_stack: Vec<SVGTag>,
     saved: bool,
+    // The position of the closing tags written by `present` and the tags they close, so the
+    // document can be reopened
+    presented: Option<(usize, Vec<SVGTag>)>,
+    num_gradients: usize,
+    num_patterns: usize,
+    precision: Option<usize>,
+    merge_tolerance: Option<u32>,
+    // The CSS classes of the shared styles, keyed by the style rule
+    shared_styles: Option<HashMap<String, usize>>,
+    // Whether the tooltips are written, and whether the script showing them is written
+    interactive: bool,
+    tooltip_script: bool,
+    tooltip_open: bool,
+    // Whether the groups are written, and the positions of the labels of the groups which are
+    // open and of the group ended last in the document, so the label can be changed
+    accessible: bool,
+    group_labels: Vec<Range<usize>>,
+    last_group_label: Option<Range<usize>>,
 }
 
+// Show the tooltip of the shape under the pointer in a box next to the pointer, the native
+// tooltip of the `title` element shows up too late to inspect the data
+const TOOLTIP_SCRIPT: &str = r#"<style>.plotters-tooltip:hover{opacity:0.7}</style>
+<script><![CDATA[(function(){
+var svg=document.currentScript.closest('svg'),ns='http://www.w3.org/2000/svg';
+var tip=document.createElementNS(ns,'g'),box=document.createElementNS(ns,'rect'),text=document.createElementNS(ns,'text');
+tip.style.pointerEvents='none';tip.style.display='none';
+box.setAttribute('fill','#FFFFE0');box.setAttribute('stroke','#000000');
+text.setAttribute('font-family','sans-serif');text.setAttribute('font-size','12');
+tip.appendChild(box);tip.appendChild(text);
+svg.addEventListener('mouseover',function(e){
+var g=e.target.closest('[data-tooltip]');
+if(!g){tip.style.display='none';return;}
+text.textContent=g.getAttribute('data-tooltip');svg.appendChild(tip);tip.style.display='';
+var p=svg.createSVGPoint();p.x=e.clientX;p.y=e.clientY;p=p.matrixTransform(svg.getScreenCTM().inverse());
+text.setAttribute('x',p.x+8);text.setAttribute('y',p.y-8);var b=text.getBBox();
+box.setAttribute('x',b.x-3);box.setAttribute('y',b.y-2);box.setAttribute('width',b.width+6);box.setAttribute('height',b.height+4);
+});
+svg.addEventListener('mouseout',function(e){if(!e.relatedTarget||!e.relatedTarget.closest('[data-tooltip]'))tip.style.display='none';});
+})();]]></script>
+"#;
+
 impl<'a> SVGBackend<'a> {
     fn escape_and_push(buf: &mut String, value: &str) {
         value.chars().for_each(|c| match c {
@@ -88,7 +197,69 @@ impl<'a> SVGBackend<'a> {
             other => buf.push(other),
         });
     }
+    // Round the fractional number to the precision of the document
+    fn format_value(&self, value: &str) -> String {
+        let digits = match self.precision {
+            Some(digits) if value.contains('.') => digits,
+            _ => return value.to_string(),
+        };
+        match value.parse::<f64>() {
+            Ok(number) => {
+                let rounded = format!("{:.*}", digits, number);
+                if rounded.contains('.') {
+                    rounded
+                        .trim_end_matches('0')
+                        .trim_end_matches('.')
+                        .to_string()
+                } else {
+                    rounded
+                }
+            }
+            Err(_) => value.to_string(),
+        }
+    }
+
+    // Move the style attributes into a shared CSS class, the class is defined the first time
+    // the style is used, and the name of the class is returned
+    fn share_style(&mut self, attr: &mut Vec<(&str, String)>) -> Option<String> {
+        let styles = self.shared_styles.as_mut()?;
+        let mut rule = String::new();
+        attr.retain(|(key, value)| {
+            if !STYLE_ATTRS.contains(key) {
+                return true;
+            }
+            rule.push_str(&format!("{}:{};", key, value));
+            false
+        });
+        if rule.is_empty() {
+            return None;
+        }
+        let next = styles.len();
+        let (id, is_new) = match styles.get(&rule) {
+            Some(&id) => (id, false),
+            None => {
+                styles.insert(rule.clone(), next);
+                (next, true)
+            }
+        };
+        let class = format!("plotters-style-{}", id);
+        if is_new {
+            let def = format!("<style>.{}{{{}}}</style>\n", class, rule);
+            self.target.get_mut().push_str(&def);
+        }
+        Some(class)
+    }
+
     fn open_tag(&mut self, tag: SVGTag, attr: &[(&str, &str)], close: bool) {
+        let mut attr: Vec<(&str, String)> = attr
+            .iter()
+            .map(|(key, value)| (*key, self.format_value(value)))
+            .collect();
+        if close && tag.is_shape() {
+            if let Some(class) = self.share_style(&mut attr) {
+                attr.push(("class", class));
+            }
+        }
         let buf = self.target.get_mut();
         buf.push_str("<");
         buf.push_str(tag.to_tag_name());
@@ -96,7 +267,7 @@ impl<'a> SVGBackend<'a> {
             buf.push_str(" ");
             buf.push_str(key);
             buf.push_str("=\"");
-            Self::escape_and_push(buf, value);
+            Self::escape_and_push(buf, &value);
             buf.push_str("\"");
         }
         if close {
@@ -118,6 +289,150 @@ impl<'a> SVGBackend<'a> {
         false
     }
 
+    // Define the gradient and get the fill attribute which references it
+    fn make_gradient_fill(&mut self, gradient: &Gradient) -> String {
+        let id = format!("plotters-gradient-{}", self.num_gradients);
+        self.num_gradients += 1;
+        self.open_tag(SVGTag::Defs, &[], false);
+        match gradient.kind() {
+            GradientKind::Linear(_) => {
+                let ((x1, y1), (x2, y2)) = gradient.linear_end_points();
+                self.open_tag(
+                    SVGTag::LinearGradient,
+                    &[
+                        ("id", &id),
+                        ("x1", &format!("{}", x1)),
+                        ("y1", &format!("{}", y1)),
+                        ("x2", &format!("{}", x2)),
+                        ("y2", &format!("{}", y2)),
+                    ],
+                    false,
+                );
+            }
+            GradientKind::Radial => self.open_tag(SVGTag::RadialGradient, &[("id", &id)], false),
+        }
+        for (offset, color) in gradient.stops() {
+            self.open_tag(
+                SVGTag::Stop,
+                &[
+                    ("offset", &format!("{}", offset)),
+                    ("stop-color", &make_svg_color(color)),
+                    ("stop-opacity", &make_svg_opacity(color)),
+                ],
+                true,
+            );
+        }
+        self.close_tag();
+        self.close_tag();
+        format!("url(#{})", id)
+    }
+
+    // Define the pattern tile and get the fill attribute which references it
+    fn make_pattern_fill<C: Color>(&mut self, pattern: &Pattern, color: &C) -> String {
+        let id = format!("plotters-pattern-{}", self.num_patterns);
+        self.num_patterns += 1;
+        let (s, w) = (pattern.get_spacing(), pattern.get_width());
+        let size = format!("{}", s);
+        let color = make_svg_color(color);
+        self.open_tag(SVGTag::Defs, &[], false);
+        self.open_tag(
+            SVGTag::Pattern,
+            &[
+                ("id", &id),
+                ("patternUnits", "userSpaceOnUse"),
+                ("width", &size),
+                ("height", &size),
+            ],
+            false,
+        );
+        if let Some(background) = pattern.get_background() {
+            self.open_tag(
+                SVGTag::Rectangle,
+                &[
+                    ("width", &size),
+                    ("height", &size),
+                    ("fill", &make_svg_color(background)),
+                    ("fill-opacity", &make_svg_opacity(background)),
+                ],
+                true,
+            );
+        }
+        // The tile repeats, so the lines are extended into the corners of the neighbours
+        let diagonal = format!(
+            "M0,{0} L{0},0 M-1,1 L1,-1 M{1},{2} L{2},{1}",
+            s,
+            s - 1,
+            s + 1
+        );
+        let back_diagonal = format!(
+            "M0,0 L{0},{0} M-1,{1} L1,{2} M{1},-1 L{2},1",
+            s,
+            s - 1,
+            s + 1
+        );
+        let paths = match pattern.kind() {
+            PatternKind::DiagonalHatch => vec![diagonal],
+            PatternKind::BackDiagonalHatch => vec![back_diagonal],
+            PatternKind::CrossHatch => vec![diagonal, back_diagonal],
+            _ => vec![],
+        };
+        for d in paths {
+            self.open_tag(
+                SVGTag::Path,
+                &[
+                    ("d", &d),
+                    ("fill", "none"),
+                    ("stroke", &color),
+                    ("stroke-width", &format!("{}", w)),
+                ],
+                true,
+            );
+        }
+        let (width, height) = match pattern.kind() {
+            PatternKind::HorizontalLines => (s, w),
+            PatternKind::VerticalLines => (w, s),
+            _ => (0, 0),
+        };
+        if width > 0 {
+            self.open_tag(
+                SVGTag::Rectangle,
+                &[
+                    ("width", &format!("{}", width)),
+                    ("height", &format!("{}", height)),
+                    ("fill", &color),
+                ],
+                true,
+            );
+        }
+        if pattern.kind() == PatternKind::Dots {
+            let center = format!("{}", s / 2);
+            self.open_tag(
+                SVGTag::Circle,
+                &[
+                    ("cx", &center),
+                    ("cy", &center),
+                    ("r", &format!("{}", w)),
+                    ("fill", &color),
+                ],
+                true,
+            );
+        }
+        self.close_tag();
+        self.close_tag();
+        format!("url(#{})", id)
+    }
+
+    // Get the fill attribute of a filled shape
+    fn make_fill<S: BackendStyle>(&mut self, style: &S) -> String {
+        if let Some(gradient) = style.gradient() {
+            self.make_gradient_fill(gradient)
+        } else if let Some(pattern) = style.pattern() {
+            self.make_pattern_fill(pattern, &style.as_color())
+        } else {
+            make_svg_color(&style.as_color())
+        }
+    }
+
     fn init_svg_file(&mut self, size: (u32, u32)) {
         self.open_tag(
             SVGTag::SVG,
@@ -138,6 +453,18 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            presented: None,
+            num_gradients: 0,
+            num_patterns: 0,
+            precision: None,
+            merge_tolerance: None,
+            shared_styles: None,
+            interactive: false,
+            tooltip_script: false,
+            tooltip_open: false,
+            accessible: false,
+            group_labels: vec![],
+            last_group_label: None,
         };
 
         ret.init_svg_file(size);
@@ -155,6 +482,18 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            presented: None,
+            num_gradients: 0,
+            num_patterns: 0,
+            precision: None,
+            merge_tolerance: None,
+            shared_styles: None,
+            interactive: false,
+            tooltip_script: false,
+            tooltip_open: false,
+            accessible: false,
+            group_labels: vec![],
+            last_group_label: None,
         };
 
         ret.init_svg_file(size);
@@ -169,12 +508,92 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            presented: None,
+            num_gradients: 0,
+            num_patterns: 0,
+            precision: None,
+            merge_tolerance: None,
+            shared_styles: None,
+            interactive: false,
+            tooltip_script: false,
+            tooltip_open: false,
+            accessible: false,
+            group_labels: vec![],
+            last_group_label: None,
         };
 
         ret.init_svg_file(size);
 
         ret
     }
+
+    /// Set the number of decimal digits of the fractional numbers in the document, such as the
+    /// opacity and the font size, the coordinates are always whole pixels
+    pub fn float_precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Merge the points of the lines and polygons: the points within `tolerance` pixels of the
+    /// previous point and the points in the middle of a straight run are dropped. With a long
+    /// series, most of the points are mapped to the same few pixels, so this makes the document
+    /// much smaller without visible change.
+    pub fn merge_points(mut self, tolerance: u32) -> Self {
+        self.merge_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Define the styles of the shapes as CSS classes, which are shared by all the shapes with
+    /// the same style, instead of repeating the style attributes on each shape
+    pub fn share_styles(mut self) -> Self {
+        self.shared_styles = Some(HashMap::new());
+        self
+    }
+
+    /// Write the tooltips of the elements, such as the data values of the points drawn with
+    /// `ChartContext::draw_series_with_tooltips`. The shapes of an element are grouped with its
+    /// tooltip as the `title`, and a small generated script shows the tooltip next to the
+    /// pointer when hovering over the shapes, without any JavaScript charting library. The
+    /// script runs when the SVG is inlined into a page or opened directly, not in an `img` tag.
+    pub fn interactive(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    /// Make the document accessible to the screen readers: the document is given the title and
+    /// the description, which is skipped if it's empty, and the shapes are grouped by what
+    /// they are part of, such as the grid, each axis and each data series. The groups are
+    /// labeled with the descriptions of the axes and the labels of the series, so a screen
+    /// reader can navigate through the parts of the chart.
+    pub fn accessible(mut self, title: &str, description: &str) -> Self {
+        self.accessible = true;
+        let buf = self.target.get_mut();
+        if let Some(start) = buf.rfind("<svg ") {
+            if let Some(end) = buf[start..].find(">\n") {
+                buf.insert_str(start + end, r#" role="graphics-document document""#);
+            }
+        }
+        buf.push_str("<title>");
+        Self::escape_and_push(buf, title);
+        buf.push_str("</title>\n");
+        if !description.is_empty() {
+            buf.push_str("<desc>");
+            Self::escape_and_push(buf, description);
+            buf.push_str("</desc>\n");
+        }
+        self
+    }
+
+    fn make_points<I: IntoIterator<Item = BackendCoord>>(&self, path: I) -> String {
+        let points = match self.merge_tolerance {
+            Some(tolerance) => merge_points(path, tolerance),
+            None => path.into_iter().collect(),
+        };
+        points.into_iter().fold(String::new(), |mut s, (x, y)| {
+            s.push_str(&format!("{},{} ", x, y));
+            s
+        })
+    }
 }
 
 impl<'a> DrawingBackend for SVGBackend<'a> {
@@ -185,11 +604,29 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
     }
 
     fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        // Reopen the document which is already presented, so the shapes drawn after an
+        // explicit `present` are added to the document and saved again when it's dropped
+        if self.saved {
+            if let Some((start, tags)) = self.presented.take() {
+                let closing: String = tags
+                    .iter()
+                    .rev()
+                    .map(|tag| format!("</{}>\n", tag.to_tag_name()))
+                    .collect();
+                let buf = self.target.get_mut();
+                if buf.get(start..) == Some(closing.as_str()) {
+                    buf.truncate(start);
+                    self.tag_stack = tags;
+                }
+            }
+            self.saved = false;
+        }
         Ok(())
     }
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
         if !self.saved {
+            self.presented = Some((self.target.get_mut().len(), self.tag_stack.clone()));
             while self.close_tag() {}
             match self.target {
                 Target::File(ref buf, path) => {
@@ -211,6 +648,71 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn set_tooltip(&mut self, tooltip: Option<&str>) {
+        if !self.interactive {
+            return;
+        }
+        if self.tooltip_open {
+            self.close_tag();
+            self.tooltip_open = false;
+        }
+        if let Some(text) = tooltip {
+            if !self.tooltip_script {
+                self.target.get_mut().push_str(TOOLTIP_SCRIPT);
+                self.tooltip_script = true;
+            }
+            self.open_tag(
+                SVGTag::Group,
+                &[("class", "plotters-tooltip"), ("data-tooltip", text)],
+                false,
+            );
+            let buf = self.target.get_mut();
+            buf.push_str("<title>");
+            Self::escape_and_push(buf, text);
+            buf.push_str("</title>\n");
+            self.tooltip_open = true;
+        }
+    }
+
+    fn begin_group(&mut self, label: &str) {
+        if !self.accessible {
+            return;
+        }
+        let buf = self.target.get_mut();
+        buf.push_str(r#"<g role="graphics-object group" aria-label=""#);
+        let start = buf.len();
+        Self::escape_and_push(buf, label);
+        let end = buf.len();
+        buf.push_str("\">\n");
+        self.tag_stack.push(SVGTag::Group);
+        self.group_labels.push(start..end);
+    }
+
+    fn end_group(&mut self) {
+        if let Some(label) = self.group_labels.pop() {
+            self.close_tag();
+            self.last_group_label = Some(label);
+        }
+    }
+
+    fn set_group_label(&mut self, label: &str) {
+        let old = match self.last_group_label.take() {
+            Some(old) => old,
+            None => return,
+        };
+        let mut escaped = String::new();
+        Self::escape_and_push(&mut escaped, label);
+        self.target.get_mut().replace_range(old.clone(), &escaped);
+        // The labels of the groups opened after it are moved
+        for open in self.group_labels.iter_mut() {
+            if open.start >= old.end {
+                let moved = |pos: usize| pos - old.len() + escaped.len();
+                *open = moved(open.start)..moved(open.end);
+            }
+        }
+        self.last_group_label = Some(old.start..old.start + escaped.len());
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -260,6 +762,39 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn draw_lines<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        lines: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        // All the lines are the sub-paths of a single path
+        let mut d = String::new();
+        for (from, to) in lines {
+            if !d.is_empty() {
+                d.push(' ');
+            }
+            d.push_str(&format!("M {},{} L {},{}", from.0, from.1, to.0, to.1));
+        }
+        if d.is_empty() {
+            return Ok(());
+        }
+        self.open_tag(
+            SVGTag::Path,
+            &[
+                ("fill", "none"),
+                ("opacity", &make_svg_opacity(&style.as_color())),
+                ("stroke", &make_svg_color(&style.as_color())),
+                ("stroke-width", &format!("{}", style.stroke_width())),
+                ("d", &d),
+            ],
+            true,
+        );
+        Ok(())
+    }
+
     fn draw_rect<S: BackendStyle>(
         &mut self,
         upper_left: BackendCoord,
@@ -274,8 +809,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         let (fill, stroke) = if !fill {
             ("none".to_string(), make_svg_color(&style.as_color()))
         } else {
-            (make_svg_color(&style.as_color()), "none".to_string())
+            (self.make_fill(style), "none".to_string())
         };
+        let stroke_width = format!("{}", style.stroke_width());
 
         self.open_tag(
             SVGTag::Rectangle,
@@ -287,6 +823,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 ("opacity", &make_svg_opacity(&style.as_color())),
                 ("fill", &fill),
                 ("stroke", &stroke),
+                ("stroke-width", &stroke_width),
             ],
             true,
         );
@@ -302,19 +839,43 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
+        let points = self.make_points(path);
         self.open_tag(
             SVGTag::Polyline,
+            &[
+                ("fill", "none"),
+                ("opacity", &make_svg_opacity(&style.as_color())),
+                ("stroke", &make_svg_color(&style.as_color())),
+                ("stroke-width", &format!("{}", style.stroke_width())),
+                ("points", &points),
+            ],
+            true,
+        );
+        Ok(())
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let [p0, p1, p2, p3] = curve;
+        self.open_tag(
+            SVGTag::Path,
             &[
                 ("fill", "none"),
                 ("opacity", &make_svg_opacity(&style.as_color())),
                 ("stroke", &make_svg_color(&style.as_color())),
                 ("stroke-width", &format!("{}", style.stroke_width())),
                 (
-                    "points",
-                    &path.into_iter().fold(String::new(), |mut s, (x, y)| {
-                        s.push_str(&format!("{},{} ", x, y));
-                        s
-                    }),
+                    "d",
+                    &format!(
+                        "M {},{} C {},{} {},{} {},{}",
+                        p0.0, p0.1, p1.0, p1.1, p2.0, p2.1, p3.0, p3.1
+                    ),
                 ),
             ],
             true,
@@ -330,18 +891,14 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
+        let fill = self.make_fill(style);
+        let points = self.make_points(path);
         self.open_tag(
             SVGTag::Polygon,
             &[
                 ("opacity", &make_svg_opacity(&style.as_color())),
-                ("fill", &make_svg_color(&style.as_color())),
-                (
-                    "points",
-                    &path.into_iter().fold(String::new(), |mut s, (x, y)| {
-                        s.push_str(&format!("{},{} ", x, y));
-                        s
-                    }),
-                ),
+                ("fill", &fill),
+                ("points", &points),
             ],
             true,
         );
@@ -372,6 +929,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 ("opacity", &make_svg_opacity(&style.as_color())),
                 ("fill", &fill),
                 ("stroke", &stroke),
+                ("stroke-width", &format!("{}", style.stroke_width())),
             ],
             true,
         );
@@ -422,28 +980,34 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 (x0 + fx1 - fx0, y0 + fy1 - fy0),
                 &crate::prelude::RED,
                 false,
-            )
-            .unwrap();
-            self.draw_circle((x0, y0), 2, &crate::prelude::RED, false)
-                .unwrap();
+            )?;
+            self.draw_circle((x0, y0), 2, &crate::prelude::RED, false)?;
         }
 
+        let family = std::iter::once(font.get_name())
+            .chain(font.get_fallback().iter().map(|f| f.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ");
         let mut attrs = vec![
             ("x", format!("{}", x0)),
             ("y", format!("{}", y0)),
             ("dy", dy.to_owned()),
             ("text-anchor", text_anchor.to_string()),
-            ("font-family", font.get_name().to_string()),
+            ("font-family", family),
             ("font-size", format!("{}", font.get_size() / 1.24)),
             ("opacity", make_svg_opacity(color)),
             ("fill", make_svg_color(color)),
         ];
 
-        match font.get_style() {
-            FontStyle::Normal => {}
-            FontStyle::Bold => attrs.push(("font-weight", "bold".to_string())),
-            other_style => attrs.push(("font-style", other_style.as_str().to_string())),
+        let style = font.get_style();
+        match style.weight() {
+            400 => {}
+            700 => attrs.push(("font-weight", "bold".to_string())),
+            weight => attrs.push(("font-weight", weight.to_string())),
         };
+        if style.slant() != "normal" {
+            attrs.push(("font-style", style.slant().to_string()));
+        }
 
         let trans = font.get_transform();
         match trans {
@@ -456,6 +1020,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             FontTransform::Rotate270 => {
                 attrs.push(("transform", format!("rotate(270, {}, {})", x0, y0)));
             }
+            FontTransform::RotateAngle(angle) => {
+                attrs.push(("transform", format!("rotate({}, {}, {})", angle, x0, y0)));
+            }
             _ => {}
         }
 
@@ -829,4 +1396,362 @@ mod test {
 
         checked_save_file("test_draw_pixel_alphas", &content);
     }
+
+    #[test]
+    fn test_draw_bezier() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&CubicBezier::new((0, 90), (0, 0), (90, 0), (90, 90), &RED))
+                .unwrap();
+        }
+
+        assert!(content.contains(r#"d="M 0,90 C 0,0 90,0 90,90""#));
+        checked_save_file("test_draw_bezier", &content);
+    }
+
+    #[test]
+    fn test_draw_translucent() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&Rectangle::new(
+                [(10, 10), (50, 50)],
+                RGBAColor(255, 0, 0, 0.5).filled(),
+            ))
+            .unwrap();
+        }
+
+        assert!(content.contains(r##"opacity="0.5" fill="#FF0000""##));
+        checked_save_file("test_draw_translucent", &content);
+    }
+
+    #[test]
+    fn test_draw_stroke_width() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&Rectangle::new(
+                [(10, 10), (50, 50)],
+                ShapeStyle::from(&RED).stroke_width(3),
+            ))
+            .unwrap();
+            root.draw(&Circle::new(
+                (50, 50),
+                20,
+                ShapeStyle::from(&BLUE).stroke_width(4),
+            ))
+            .unwrap();
+        }
+
+        assert!(content.contains(r##"stroke="#FF0000" stroke-width="3""##));
+        assert!(content.contains(r##"stroke="#0000FF" stroke-width="4""##));
+        checked_save_file("test_draw_stroke_width", &content);
+    }
+
+    #[test]
+    fn test_draw_font_weights() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let font = ("sans-serif", 20, FontStyle::BoldItalic).into_font();
+            root.draw_text("a", &font.color(&BLACK), (10, 10)).unwrap();
+            let font = ("sans-serif", 20, FontStyle::Weight(300)).into_font();
+            root.draw_text("b", &font.color(&BLACK), (10, 50)).unwrap();
+        }
+
+        assert!(content.contains(r#"font-weight="bold" font-style="italic""#));
+        assert!(content.contains(r#"font-weight="300""#));
+        checked_save_file("test_draw_font_weights", &content);
+    }
+
+    #[test]
+    fn test_draw_font_fallback() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let font = ("serif", 20)
+                .into_font()
+                .fallback(&["Noto Sans CJK", "sans-serif"]);
+            root.draw_text("a", &font.color(&BLACK), (10, 10)).unwrap();
+        }
+
+        assert!(content.contains(r#"font-family="serif, Noto Sans CJK, sans-serif""#));
+        checked_save_file("test_draw_font_fallback", &content);
+    }
+
+    #[test]
+    fn test_draw_emoji_text() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .color(&BLACK)
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            root.draw_text("\u{1F600} ok \u{1F680}", &style, (50, 50))
+                .unwrap();
+        }
+
+        // The viewer renders the emoji with its color fonts
+        assert!(content.contains(">\n\u{1F600} ok \u{1F680}\n</text>"));
+        checked_save_file("test_draw_emoji_text", &content);
+    }
+
+    #[test]
+    fn test_draw_gradient() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let gradient = Gradient::linear(90.0)
+                .stop(0.0, &RED)
+                .stop(1.0, &BLUE.mix(0.5));
+            let style = ShapeStyle::from(&RED).gradient(gradient);
+            root.draw(&Rectangle::new([(10, 10), (50, 50)], style.clone()))
+                .unwrap();
+            root.draw(&Polygon::new(vec![(0, 0), (20, 0), (0, 20)], style))
+                .unwrap();
+        }
+
+        assert!(content.contains(r#"<linearGradient id="plotters-gradient-0" x1="0.5""#));
+        assert!(content.contains(r##"<stop offset="1" stop-color="#0000FF" stop-opacity="0.5"/>"##));
+        assert!(content.contains(r##"fill="url(#plotters-gradient-0)""##));
+        assert!(content.contains(r##"fill="url(#plotters-gradient-1)""##));
+        checked_save_file("test_draw_gradient", &content);
+    }
+
+    #[test]
+    fn test_draw_patterns() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let hatch = Pattern::new(PatternKind::CrossHatch)
+                .spacing(6)
+                .background(&WHITE);
+            root.draw(&Rectangle::new(
+                [(10, 10), (50, 50)],
+                ShapeStyle::from(&BLACK).pattern(hatch),
+            ))
+            .unwrap();
+            let dots = Pattern::new(PatternKind::Dots).width(2);
+            root.draw(&Polygon::new(
+                vec![(0, 0), (20, 0), (0, 20)],
+                ShapeStyle::from(&BLUE).pattern(dots),
+            ))
+            .unwrap();
+        }
+
+        assert!(content.contains(
+            r#"<pattern id="plotters-pattern-0" patternUnits="userSpaceOnUse" width="6" height="6">"#
+        ));
+        assert!(content.contains(r##"<rect width="6" height="6" fill="#FFFFFF""##));
+        assert!(content.contains(r#"d="M0,6 L6,0 M-1,1 L1,-1 M5,7 L7,5""#));
+        assert!(content.contains(r##"<circle cx="4" cy="4" r="2" fill="#0000FF"/>"##));
+        assert!(content.contains(r##"fill="url(#plotters-pattern-0)""##));
+        assert!(content.contains(r##"fill="url(#plotters-pattern-1)""##));
+        checked_save_file("test_draw_patterns", &content);
+    }
+
+    #[test]
+    fn test_svg_mesh_is_batched() {
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0..100, 0..100)
+                .unwrap();
+            chart
+                .configure_mesh()
+                .line_style_1(&BLACK)
+                .line_style_2(&WHITE)
+                .x_labels(50)
+                .y_labels(50)
+                .draw()
+                .unwrap();
+        }
+        // A path for each axis of the bold and the light mesh lines, no separate lines
+        assert_eq!(content.matches("<path").count(), 4);
+        assert!(!content.contains("<line"));
+        assert!(content.contains(r##"stroke="#000000" stroke-width="1" d="M "##));
+    }
+
+    #[test]
+    fn test_draw_after_present() {
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&Circle::new((20, 20), 5, &RED)).unwrap();
+            root.present().unwrap();
+            // The document is reopened, and saved again when the backend is dropped
+            root.draw(&Circle::new((80, 80), 5, &BLUE)).unwrap();
+        }
+        assert_eq!(content.matches("<circle").count(), 2);
+        assert_eq!(content.matches("</svg>").count(), 1);
+        assert!(content.ends_with("</svg>\n"));
+
+        // The groups which are open are reopened too
+        let mut content = String::new();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100)).accessible("", "");
+            backend.begin_group("outer");
+            backend.begin_group("inner");
+            backend.draw_pixel((10, 10), &RED.to_rgba()).unwrap();
+            backend.present().unwrap();
+            backend.ensure_prepared().unwrap();
+            backend.draw_pixel((20, 20), &BLUE.to_rgba()).unwrap();
+            backend.end_group();
+            backend.draw_pixel((30, 30), &BLUE.to_rgba()).unwrap();
+            backend.end_group();
+        }
+        assert_eq!(content.matches("<g ").count(), 2);
+        assert_eq!(content.matches("</g>").count(), 2);
+        assert_eq!(content.matches("</svg>").count(), 1);
+        let inner = &content[content.find("inner").unwrap()..content.find("</g>").unwrap()];
+        assert_eq!(inner.matches("<rect").count(), 2);
+        assert!(content.ends_with("</g>\n</svg>\n"));
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_svg_size_options() {
+        let draw = |content: &mut String, optimized: bool| {
+            let mut backend = SVGBackend::with_string(content, (100, 100));
+            if optimized {
+                backend = backend.float_precision(2).merge_points(1).share_styles();
+            }
+            let root = backend.into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Size", ("sans-serif", 15))
+                .build_ranged(0.0..1.0, -1.0..1.0)
+                .unwrap();
+            let series = (0..=10000).map(|i| {
+                let x = i as f64 / 10000.0;
+                (x, (x * 20.0).sin())
+            });
+            chart
+                .draw_series(LineSeries::new(series, &RED.mix(0.3)))
+                .unwrap();
+            chart
+                .draw_series((0..10).map(|i| Circle::new(((i as f64 + 0.5) / 10.0, 0.0), 2, &BLUE)))
+                .unwrap();
+        };
+
+        let (mut plain, mut optimized) = (String::new(), String::new());
+        draw(&mut plain, false);
+        draw(&mut optimized, true);
+
+        assert!(optimized.len() * 10 < plain.len());
+        assert!(!plain.contains("<style>"));
+        // The debug feature draws the layout box of the caption with one more shared style
+        let first = if cfg!(feature = "debug") { 1 } else { 0 };
+        assert!(optimized.contains(&format!(
+            "<style>.plotters-style-{}{{fill:none;opacity:0.3;stroke:#FF0000;stroke-width:1;}}</style>",
+            first
+        )));
+        assert_eq!(optimized.matches("<style>").count(), 2 + first);
+        assert_eq!(
+            optimized
+                .matches(&format!(r#"class="plotters-style-{}""#, first + 1))
+                .count(),
+            10
+        );
+        assert!(optimized.contains(r#"font-size="12.1""#));
+        checked_save_file("test_svg_size_options", &optimized);
+
+        assert_eq!(
+            merge_points(vec![(0, 0), (1, 0), (2, 0), (2, 0), (3, 1), (3, 1)], 0),
+            vec![(0, 0), (2, 0), (3, 1)]
+        );
+        assert_eq!(
+            merge_points(vec![(0, 0), (1, 1), (5, 0), (6, 0)], 1),
+            vec![(0, 0), (5, 0), (6, 0)]
+        );
+    }
+
+    #[test]
+    fn test_svg_interactive_tooltips() {
+        let draw = |content: &mut String, interactive: bool| {
+            let mut backend = SVGBackend::with_string(content, (200, 100));
+            if interactive {
+                backend = backend.interactive();
+            }
+            let root = backend.into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(-1..10, -5..100)
+                .unwrap();
+            chart
+                .draw_series_with_tooltips((0..10).map(|x| Circle::new((x, x * x), 3, &RED)))
+                .unwrap();
+        };
+
+        let (mut plain, mut interactive) = (String::new(), String::new());
+        draw(&mut plain, false);
+        draw(&mut interactive, true);
+
+        // The tooltips are ignored unless the output is interactive
+        assert!(!plain.contains("<g"));
+        assert!(!plain.contains("<script>"));
+
+        assert_eq!(interactive.matches("<script>").count(), 1);
+        assert_eq!(
+            interactive
+                .matches(r#"<g class="plotters-tooltip""#)
+                .count(),
+            10
+        );
+        assert_eq!(interactive.matches("</g>").count(), 10);
+        assert!(interactive.contains(r#"data-tooltip="(3, 9)""#));
+        assert!(interactive.contains("<title>(3, 9)</title>"));
+        assert_eq!(interactive.matches("<circle").count(), 10);
+        checked_save_file("test_svg_interactive_tooltips", &interactive);
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_svg_accessible() {
+        let draw = |content: &mut String, accessible: bool| {
+            let mut backend = SVGBackend::with_string(content, (300, 200));
+            if accessible {
+                backend = backend.accessible("Squares", "The squares of 0 to 9 & the cubes");
+            }
+            let root = backend.into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_ranged(0..10, 0..1000)
+                .unwrap();
+            chart.configure_mesh().x_desc("Number").draw().unwrap();
+            chart
+                .draw_series(LineSeries::new((0..10).map(|x| (x, x * x)), &RED))
+                .unwrap()
+                .label("Square");
+            chart
+                .draw_series(LineSeries::new((0..10).map(|x| (x, x * x * x)), &BLUE))
+                .unwrap();
+        };
+
+        let (mut plain, mut accessible) = (String::new(), String::new());
+        draw(&mut plain, false);
+        draw(&mut accessible, true);
+
+        assert!(!plain.contains("<g"));
+        assert!(!plain.contains("role="));
+
+        assert!(accessible.starts_with("<svg "));
+        assert!(accessible
+            .contains(r#"xmlns="http://www.w3.org/2000/svg" role="graphics-document document">"#));
+        assert!(accessible.contains(
+            "<title>Squares</title>\n<desc>The squares of 0 to 9 &amp; the cubes</desc>\n"
+        ));
+        assert!(accessible.contains(r#"aria-label="Axes""#));
+        assert!(accessible.contains(r#"aria-label="X axis: Number""#));
+        assert!(accessible.contains(r#"aria-label="Y axis""#));
+        // The label of the series replaces the default one
+        assert!(accessible.contains(r#"aria-label="Square""#));
+        assert!(!accessible.contains(r#"aria-label="Series 1""#));
+        assert!(accessible.contains(r#"aria-label="Series 2""#));
+
+        assert_eq!(accessible.matches("<g ").count(), 5);
+        assert_eq!(accessible.matches("</g>").count(), 5);
+        checked_save_file("test_svg_accessible", &accessible);
+    }
 }
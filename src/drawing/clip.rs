@@ -0,0 +1,566 @@
+use super::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, RenderEvent};
+use super::rasterizer::flatten_cubic_bezier;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{FontDesc, RGBAColor, TextStyle};
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+/// A backend which clips the shapes to a rectangle before drawing them on the underlying
+/// backend. The shapes are clipped geometrically, so a line leaving the rectangle is cut at
+/// the border instead of being bent onto it. It's used by `DrawingArea::draw_clipped`.
+pub struct ClippedBackend<'a, DB: DrawingBackend> {
+    inner: &'a mut DB,
+    // The inclusive bounds of the visible pixels
+    x: (i32, i32),
+    y: (i32, i32),
+}
+
+// The number of segments a circle crossing the border is approximated with
+const CIRCLE_SEGMENTS: usize = 64;
+
+impl<'a, DB: DrawingBackend> ClippedBackend<'a, DB> {
+    /// Create a clipped backend
+    /// - `inner`: The backend to draw on
+    /// - `upper_left`: The upper left corner of the visible rectangle
+    /// - `bottom_right`: The bottom right corner of the visible rectangle, which is visible
+    pub fn new(inner: &'a mut DB, upper_left: BackendCoord, bottom_right: BackendCoord) -> Self {
+        Self {
+            inner,
+            x: (upper_left.0, bottom_right.0),
+            y: (upper_left.1, bottom_right.1),
+        }
+    }
+
+    fn contains(&self, (x, y): BackendCoord) -> bool {
+        x >= self.x.0 && x <= self.x.1 && y >= self.y.0 && y <= self.y.1
+    }
+
+    // Clip the segment with the Liang-Barsky algorithm
+    fn clip_segment(
+        &self,
+        from: BackendCoord,
+        to: BackendCoord,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        if self.contains(from) && self.contains(to) {
+            return Some((from, to));
+        }
+        let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+        let (dx, dy) = (f64::from(to.0) - x0, f64::from(to.1) - y0);
+        let (mut t0, mut t1) = (0.0f64, 1.0f64);
+        let edges = [
+            (-dx, x0 - f64::from(self.x.0)),
+            (dx, f64::from(self.x.1) - x0),
+            (-dy, y0 - f64::from(self.y.0)),
+            (dy, f64::from(self.y.1) - y0),
+        ];
+        for &(p, q) in edges.iter() {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        let at = |t: f64| ((x0 + dx * t).round() as i32, (y0 + dy * t).round() as i32);
+        Some((at(t0), at(t1)))
+    }
+
+    // Clip the polygon with the Sutherland-Hodgman algorithm
+    fn clip_polygon(&self, vert: Vec<BackendCoord>) -> Vec<BackendCoord> {
+        let mut points: Vec<(f64, f64)> = vert
+            .iter()
+            .map(|&(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+        let (x0, x1) = (f64::from(self.x.0), f64::from(self.x.1));
+        let (y0, y1) = (f64::from(self.y.0), f64::from(self.y.1));
+        // The signed distance to each edge, which is not negative inside
+        let edges: [&dyn Fn((f64, f64)) -> f64; 4] =
+            [&|p| p.0 - x0, &|p| x1 - p.0, &|p| p.1 - y0, &|p| y1 - p.1];
+        for dist in edges.iter() {
+            let input = core::mem::take(&mut points);
+            for (idx, &cur) in input.iter().enumerate() {
+                let prev = input[(idx + input.len() - 1) % input.len()];
+                let (dc, dp) = (dist(cur), dist(prev));
+                if (dc >= 0.0) != (dp >= 0.0) {
+                    let t = dp / (dp - dc);
+                    points.push((prev.0 + (cur.0 - prev.0) * t, prev.1 + (cur.1 - prev.1) * t));
+                }
+                if dc >= 0.0 {
+                    points.push(cur);
+                }
+            }
+        }
+        let mut result: Vec<BackendCoord> = points
+            .into_iter()
+            .map(|(x, y)| (x.round() as i32, y.round() as i32))
+            .collect();
+        result.dedup();
+        result
+    }
+
+    fn draw_clipped_path<S: BackendStyle>(
+        &mut self,
+        path: Vec<BackendCoord>,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // The visible parts of the path, which are split where the path leaves the rectangle
+        let mut current: Vec<BackendCoord> = vec![];
+        for segment in path.windows(2) {
+            match self.clip_segment(segment[0], segment[1]) {
+                // The segment only touches the border
+                Some((from, to)) if from == to => {}
+                Some((from, to)) => {
+                    if current.last() != Some(&from) {
+                        if current.len() > 1 {
+                            self.inner.draw_path(core::mem::take(&mut current), style)?;
+                        }
+                        current = vec![from];
+                    }
+                    current.push(to);
+                }
+                None => {
+                    if current.len() > 1 {
+                        self.inner.draw_path(core::mem::take(&mut current), style)?;
+                    }
+                    current.clear();
+                }
+            }
+        }
+        if current.len() > 1 {
+            self.inner.draw_path(current, style)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, DB: DrawingBackend> DrawingBackend for ClippedBackend<'a, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn on_render_event(&mut self, event: RenderEvent) {
+        self.inner.on_render_event(event)
+    }
+
+    fn set_tooltip(&mut self, tooltip: Option<&str>) {
+        self.inner.set_tooltip(tooltip)
+    }
+
+    fn begin_group(&mut self, label: &str) {
+        self.inner.begin_group(label)
+    }
+
+    fn end_group(&mut self) {
+        self.inner.end_group()
+    }
+
+    fn set_group_label(&mut self, label: &str) {
+        self.inner.set_group_label(label)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if self.contains(point) {
+            return self.inner.draw_pixel(point, color);
+        }
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some((from, to)) = self.clip_segment(from, to) {
+            return self.inner.draw_line(from, to, style);
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x0, x1) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (y0, y1) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        if self.contains((x0, y0)) && self.contains((x1, y1)) {
+            return self.inner.draw_rect((x0, y0), (x1, y1), style, fill);
+        }
+        if fill {
+            let (cx0, cx1) = (x0.max(self.x.0), x1.min(self.x.1));
+            let (cy0, cy1) = (y0.max(self.y.0), y1.min(self.y.1));
+            if cx0 <= cx1 && cy0 <= cy1 {
+                return self.inner.draw_rect((cx0, cy0), (cx1, cy1), style, true);
+            }
+            return Ok(());
+        }
+        self.draw_clipped_path(
+            vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+            style,
+        )
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        if path.iter().all(|p| self.contains(*p)) {
+            return self.inner.draw_path(path, style);
+        }
+        self.draw_clipped_path(path, style)
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The curve is inside the convex hull of the control points
+        if curve.iter().all(|p| self.contains(*p)) {
+            return self.inner.draw_cubic_bezier(curve, style);
+        }
+        self.draw_clipped_path(flatten_cubic_bezier(curve), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = radius as i32;
+        let (x0, y0, x1, y1) = (center.0 - r, center.1 - r, center.0 + r, center.1 + r);
+        if self.contains((x0, y0)) && self.contains((x1, y1)) {
+            return self.inner.draw_circle(center, radius, style, fill);
+        }
+        if x1 < self.x.0 || x0 > self.x.1 || y1 < self.y.0 || y0 > self.y.1 {
+            return Ok(());
+        }
+        let mut outline: Vec<BackendCoord> = (0..=CIRCLE_SEGMENTS)
+            .map(|idx| {
+                let angle = core::f64::consts::PI * 2.0 * idx as f64 / CIRCLE_SEGMENTS as f64;
+                (
+                    center.0 + (f64::from(radius) * angle.cos()).round() as i32,
+                    center.1 + (f64::from(radius) * angle.sin()).round() as i32,
+                )
+            })
+            .collect();
+        if fill {
+            outline.pop();
+            return self.fill_polygon(outline, style);
+        }
+        self.draw_clipped_path(outline, style)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        if vert.iter().all(|p| self.contains(*p)) {
+            return self.inner.fill_polygon(vert, style);
+        }
+        let vert = self.clip_polygon(vert);
+        if vert.len() >= 3 {
+            return self.inner.fill_polygon(vert, style);
+        }
+        Ok(())
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The text is kept or dropped as a whole by its anchor point
+        if self.contains(pos) {
+            return self.inner.draw_text(text, style, pos);
+        }
+        Ok(())
+    }
+
+    fn estimate_text_size<'b>(
+        &self,
+        text: &str,
+        font: &FontDesc<'b>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x1, y1) = (pos.0 + iw as i32 - 1, pos.1 + ih as i32 - 1);
+        if self.contains(pos) && self.contains((x1, y1)) {
+            return self.inner.blit_bitmap(pos, (iw, ih), src);
+        }
+        let (cx0, cx1) = (pos.0.max(self.x.0), x1.min(self.x.1));
+        let (cy0, cy1) = (pos.1.max(self.y.0), y1.min(self.y.1));
+        if cx0 > cx1 || cy0 > cy1 || iw == 0 || ih == 0 {
+            return Ok(());
+        }
+        // Copy the visible rows, the pixel size depends on the pixel format of the bitmap
+        let pixel_size = src.len() / (iw * ih) as usize;
+        let row = |y: i32| {
+            let begin = ((y - pos.1) as usize * iw as usize + (cx0 - pos.0) as usize) * pixel_size;
+            &src[begin..begin + (cx1 - cx0 + 1) as usize * pixel_size]
+        };
+        let cropped: Vec<u8> = (cy0..=cy1).flat_map(row).copied().collect();
+        self.inner.blit_bitmap(
+            (cx0, cy0),
+            ((cx1 - cx0 + 1) as u32, (cy1 - cy0 + 1) as u32),
+            &cropped,
+        )
+    }
+}
+
+/// A backend which clips the shapes like `ClippedBackend`, but shares the backend of a drawing
+/// area instead of borrowing it, so the series elements can be drawn on it without a lifetime.
+/// It's the backend `ChartContext::draw_series` draws the series on.
+pub struct SharedClippedBackend<DB: DrawingBackend> {
+    inner: Rc<RefCell<DB>>,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+}
+
+impl<DB: DrawingBackend> SharedClippedBackend<DB> {
+    pub(crate) fn new(
+        inner: Rc<RefCell<DB>>,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Self {
+        Self {
+            inner,
+            upper_left,
+            bottom_right,
+        }
+    }
+
+    // The backend is only borrowed for each operation, so the other handles of it are
+    // never left borrowed while the element is drawn
+    fn clipped<R>(&self, ops: impl FnOnce(&mut ClippedBackend<DB>) -> R) -> R {
+        let mut inner = self.inner.borrow_mut();
+        ops(&mut ClippedBackend::new(
+            &mut inner,
+            self.upper_left,
+            self.bottom_right,
+        ))
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for SharedClippedBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.borrow().get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.ensure_prepared())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.present())
+    }
+
+    fn on_render_event(&mut self, event: RenderEvent) {
+        self.clipped(|b| b.on_render_event(event))
+    }
+
+    fn set_tooltip(&mut self, tooltip: Option<&str>) {
+        self.clipped(|b| b.set_tooltip(tooltip))
+    }
+
+    fn begin_group(&mut self, label: &str) {
+        self.clipped(|b| b.begin_group(label))
+    }
+
+    fn end_group(&mut self) {
+        self.clipped(|b| b.end_group())
+    }
+
+    fn set_group_label(&mut self, label: &str) {
+        self.clipped(|b| b.set_group_label(label))
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_path(path, style))
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_cubic_bezier(curve, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.fill_polygon(vert, style))
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.draw_text(text, style, pos))
+    }
+
+    fn estimate_text_size<'b>(
+        &self,
+        text: &str,
+        font: &FontDesc<'b>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.borrow().estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clipped(|b| b.blit_bitmap(pos, size, src))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::style::{Color, RED};
+
+    #[test]
+    fn test_clip_shapes() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_line(|_, _, from, to| {
+            assert_eq!((from, to), ((10, 15), (20, 20)));
+        });
+        backend.check_draw_path(|_, _, path| {
+            assert_eq!(path, vec![(10, 10), (20, 10), (20, 20)]);
+        });
+        backend.check_draw_path(|_, _, path| {
+            assert_eq!(path, vec![(20, 15), (15, 20)]);
+        });
+        backend.check_fill_polygon(|_, vert| {
+            assert_eq!(vert, vec![(20, 20), (10, 20), (10, 10), (20, 10)]);
+        });
+        backend.check_draw_rect(|_, _, filled, ul, br| {
+            assert!(filled);
+            assert_eq!((ul, br), ((15, 10), (20, 12)));
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 1);
+            assert_eq!(b.num_draw_path_call, 2);
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_circle_call, 0);
+            assert_eq!(b.num_draw_pixel_call, 0);
+        });
+
+        let mut clipped = ClippedBackend::new(&mut backend, (10, 10), (20, 20));
+        clipped.draw_line((0, 10), (20, 20), &RED).unwrap();
+        clipped.draw_line((0, 0), (5, 30), &RED).unwrap();
+        // The path leaves the rectangle and enters it again
+        clipped
+            .draw_path(
+                vec![(10, 10), (20, 10), (20, 20), (30, 30), (25, 9), (15, 20)],
+                &RED,
+            )
+            .unwrap();
+        clipped
+            .fill_polygon(vec![(0, 30), (15, 0), (30, 30)], &RED)
+            .unwrap();
+        clipped.draw_rect((15, 0), (30, 12), &RED, true).unwrap();
+        clipped.draw_circle((50, 50), 10, &RED, true).unwrap();
+        clipped.draw_pixel((9, 10), &RED.to_rgba()).unwrap();
+    }
+}
@@ -0,0 +1,332 @@
+use super::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, RenderEvent};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{FontDesc, RGBAColor, TextStyle};
+
+type Region = (BackendCoord, BackendCoord);
+
+/// A backend which tracks the regions changed since the last `present`, so a real-time
+/// backend can flush only those regions instead of the whole frame. The regions are passed to
+/// `DrawingBackend::present_regions` of the underlying backend when the changes are presented.
+///
+/// The regions are the inclusive bounding boxes of the shapes, the overlapping regions are
+/// merged, and the closest regions are merged when there are too many of them.
+///
+/// ```rust
+/// use plotters::drawing::DirtyRectBackend;
+/// use plotters::drawing::backend::DrawingBackend;
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let mut backend = DirtyRectBackend::new(SVGBackend::with_string(&mut buf, (200, 100)));
+/// backend.draw_circle((50, 50), 10, &RED, true).unwrap();
+/// backend.draw_circle((150, 50), 10, &RED, true).unwrap();
+/// assert_eq!(
+///     backend.dirty_regions(),
+///     [((39, 39), (61, 61)), ((139, 39), (161, 61))]
+/// );
+/// ```
+pub struct DirtyRectBackend<DB: DrawingBackend> {
+    inner: DB,
+    dirty: Vec<Region>,
+    max_regions: usize,
+}
+
+fn union(a: Region, b: Region) -> Region {
+    (
+        ((a.0).0.min((b.0).0), (a.0).1.min((b.0).1)),
+        ((a.1).0.max((b.1).0), (a.1).1.max((b.1).1)),
+    )
+}
+
+fn area(r: Region) -> i64 {
+    i64::from((r.1).0 - (r.0).0 + 1) * i64::from((r.1).1 - (r.0).1 + 1)
+}
+
+fn overlaps(a: Region, b: Region) -> bool {
+    (a.0).0 <= (b.1).0 + 1
+        && (b.0).0 <= (a.1).0 + 1
+        && (a.0).1 <= (b.1).1 + 1
+        && (b.0).1 <= (a.1).1 + 1
+}
+
+// The bounding box of the points, padded by the half of the stroke width
+fn bounding_box<I: IntoIterator<Item = BackendCoord>>(points: I, width: u32) -> Option<Region> {
+    let pad = (width / 2 + 1) as i32;
+    let mut iter = points.into_iter();
+    let first = iter.next()?;
+    let (lo, hi) = iter.fold((first, first), |(lo, hi), (x, y)| {
+        ((lo.0.min(x), lo.1.min(y)), (hi.0.max(x), hi.1.max(y)))
+    });
+    Some(((lo.0 - pad, lo.1 - pad), (hi.0 + pad, hi.1 + pad)))
+}
+
+impl<DB: DrawingBackend> DirtyRectBackend<DB> {
+    /// Create a backend tracking the changes of the underlying backend
+    /// - `inner`: The backend to draw on
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            dirty: vec![],
+            max_regions: 16,
+        }
+    }
+
+    /// Set the maximum number of regions to track, the default is 16
+    pub fn max_regions(mut self, max_regions: usize) -> Self {
+        self.max_regions = max_regions.max(1);
+        self
+    }
+
+    /// Get the regions changed since the last `present`
+    pub fn dirty_regions(&self) -> &[(BackendCoord, BackendCoord)] {
+        &self.dirty
+    }
+
+    /// Mark a region as changed, for example, after drawing on the underlying backend directly
+    /// - `upper_left`: The upper left corner of the region
+    /// - `bottom_right`: The bottom right corner of the region, which is in the region
+    pub fn mark_dirty(&mut self, upper_left: BackendCoord, bottom_right: BackendCoord) {
+        let (w, h) = self.inner.get_size();
+        let mut region = (
+            (upper_left.0.max(0), upper_left.1.max(0)),
+            (
+                bottom_right.0.min(w as i32 - 1),
+                bottom_right.1.min(h as i32 - 1),
+            ),
+        );
+        if (region.0).0 > (region.1).0 || (region.0).1 > (region.1).1 {
+            return;
+        }
+        while let Some(idx) = self.dirty.iter().position(|r| overlaps(*r, region)) {
+            region = union(region, self.dirty.swap_remove(idx));
+        }
+        self.dirty.push(region);
+
+        while self.dirty.len() > self.max_regions {
+            // Merge the pair of regions which wastes the least area
+            let mut best = (0, 1, i64::MAX);
+            for i in 0..self.dirty.len() {
+                for j in i + 1..self.dirty.len() {
+                    let (a, b) = (self.dirty[i], self.dirty[j]);
+                    let waste = area(union(a, b)) - area(a) - area(b);
+                    if waste < best.2 {
+                        best = (i, j, waste);
+                    }
+                }
+            }
+            let merged = self.dirty.swap_remove(best.1);
+            self.dirty[best.0] = union(self.dirty[best.0], merged);
+        }
+    }
+
+    fn mark_region(&mut self, region: Option<Region>) {
+        if let Some((upper_left, bottom_right)) = region {
+            self.mark_dirty(upper_left, bottom_right);
+        }
+    }
+
+    /// Get the underlying backend
+    pub fn inner(&self) -> &DB {
+        &self.inner
+    }
+
+    /// Get the mutable reference to the underlying backend, the changes made through it are
+    /// not tracked unless they are marked with `mark_dirty`
+    pub fn inner_mut(&mut self) -> &mut DB {
+        &mut self.inner
+    }
+
+    /// Unwrap the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for DirtyRectBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let dirty = core::mem::take(&mut self.dirty);
+        if dirty.is_empty() {
+            return Ok(());
+        }
+        self.inner.present_regions(&dirty)
+    }
+
+    fn on_render_event(&mut self, event: RenderEvent) {
+        self.inner.on_render_event(event)
+    }
+
+    fn set_tooltip(&mut self, tooltip: Option<&str>) {
+        self.inner.set_tooltip(tooltip)
+    }
+
+    fn begin_group(&mut self, label: &str) {
+        self.inner.begin_group(label)
+    }
+
+    fn end_group(&mut self) {
+        self.inner.end_group()
+    }
+
+    fn set_group_label(&mut self, label: &str) {
+        self.inner.set_group_label(label)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.mark_dirty(point, point);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.mark_region(bounding_box(vec![from, to], style.stroke_width()));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.mark_region(bounding_box(
+            vec![upper_left, bottom_right],
+            style.stroke_width(),
+        ));
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.mark_region(bounding_box(path.iter().copied(), style.stroke_width()));
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The curve is inside the convex hull of the control points
+        self.mark_region(bounding_box(curve.iter().copied(), style.stroke_width()));
+        self.inner.draw_cubic_bezier(curve, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = radius as i32;
+        self.mark_region(bounding_box(
+            vec![(center.0 - r, center.1 - r), (center.0 + r, center.1 + r)],
+            style.stroke_width(),
+        ));
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.mark_region(bounding_box(vert.iter().copied(), 0));
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The text may be aligned and rotated around the anchor in any direction
+        let (w, h) = self.inner.estimate_text_size(text, &style.font)?;
+        let size = w.max(h) as i32;
+        self.mark_dirty((pos.0 - size, pos.1 - size), (pos.0 + size, pos.1 + size));
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<'b>(
+        &self,
+        text: &str,
+        font: &FontDesc<'b>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.mark_dirty(pos, (pos.0 + iw as i32 - 1, pos.1 + ih as i32 - 1));
+        self.inner.blit_bitmap(pos, (iw, ih), src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::style::{Color, RED};
+
+    #[test]
+    fn test_dirty_regions() {
+        let mut mocked = MockedBackend::new(100, 100);
+        mocked.drop_check(|b| {
+            assert_eq!(b.num_present_call, 1);
+        });
+        let mut backend = DirtyRectBackend::new(mocked).max_regions(2);
+        let style = RED.to_rgba();
+
+        backend.draw_pixel((5, 5), &style).unwrap();
+        backend.draw_rect((20, 20), (30, 30), &style, true).unwrap();
+        backend.draw_rect((25, 25), (40, 40), &style, true).unwrap();
+        assert_eq!(
+            backend.dirty_regions(),
+            [((5, 5), (5, 5)), ((19, 19), (41, 41))]
+        );
+
+        // The closest regions are merged when there are too many of them
+        backend.draw_pixel((95, 5), &style).unwrap();
+        assert_eq!(
+            backend.dirty_regions(),
+            [((5, 5), (95, 5)), ((19, 19), (41, 41))]
+        );
+        backend.draw_circle((90, 90), 100, &style, true).unwrap();
+        assert_eq!(backend.dirty_regions(), [((0, 0), (99, 99))]);
+
+        backend.present().unwrap();
+        assert!(backend.dirty_regions().is_empty());
+        // Nothing is presented when nothing changed
+        backend.present().unwrap();
+    }
+}
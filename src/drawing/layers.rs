@@ -0,0 +1,624 @@
+use super::area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use super::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::coord::{CoordTranslate, Shift};
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::Pos;
+use crate::style::{
+    FontDesc, FontFamily, FontStyle, FontTransform, RGBAColor, ShapeStyle, TextStyle,
+};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+
+// The text style with the owned font family names, so the text can be drawn later
+struct RecordedTextStyle {
+    family: String,
+    fallback: Vec<String>,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: RGBAColor,
+    pos: Pos,
+    line_spacing: f64,
+    letter_spacing: f64,
+    underline: bool,
+    strikethrough: bool,
+    background: Option<ShapeStyle>,
+    padding: u32,
+}
+
+impl RecordedTextStyle {
+    fn new(style: &TextStyle) -> Self {
+        Self {
+            family: style.font.get_name().to_string(),
+            fallback: style
+                .font
+                .get_fallback()
+                .iter()
+                .map(|f| f.as_str().to_string())
+                .collect(),
+            size: style.font.get_size(),
+            style: style.font.get_style(),
+            transform: style.font.get_transform(),
+            color: style.color.clone(),
+            pos: style.pos,
+            line_spacing: style.line_spacing,
+            letter_spacing: style.letter_spacing,
+            underline: style.underline,
+            strikethrough: style.strikethrough,
+            background: style.background.clone(),
+            padding: style.padding,
+        }
+    }
+
+    fn to_text_style(&self) -> TextStyle<'_> {
+        let mut font = FontDesc::new(
+            FontFamily::from(self.family.as_str()),
+            self.size,
+            self.style,
+        )
+        .transform(self.transform.clone());
+        if !self.fallback.is_empty() {
+            let fallback: Vec<&str> = self.fallback.iter().map(String::as_str).collect();
+            font = font.fallback(&fallback);
+        }
+        TextStyle {
+            font,
+            color: self.color.clone(),
+            pos: self.pos,
+            line_spacing: self.line_spacing,
+            letter_spacing: self.letter_spacing,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+            background: self.background.clone(),
+            padding: self.padding,
+        }
+    }
+}
+
+// A drawing operation queued by a layer, in the coordinate of the layer
+enum LayerOp {
+    Pixel(BackendCoord, RGBAColor),
+    Line(BackendCoord, BackendCoord, ShapeStyle),
+    Rect(BackendCoord, BackendCoord, ShapeStyle, bool),
+    Path(Vec<BackendCoord>, ShapeStyle),
+    CubicBezier([BackendCoord; 4], ShapeStyle),
+    Circle(BackendCoord, u32, ShapeStyle, bool),
+    Polygon(Vec<BackendCoord>, ShapeStyle),
+    Text(String, RecordedTextStyle, BackendCoord),
+    Bitmap(BackendCoord, (u32, u32), Vec<u8>),
+}
+
+fn record_style<S: BackendStyle>(style: &S) -> ShapeStyle {
+    ShapeStyle {
+        color: style.as_color(),
+        filled: false,
+        stroke_width: style.stroke_width(),
+        stroke_color: None,
+        gradient: style.gradient().cloned(),
+        pattern: style.pattern().cloned(),
+    }
+}
+
+impl LayerOp {
+    fn replay<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        (dx, dy): BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let shift = |(x, y): BackendCoord| (x + dx, y + dy);
+        match self {
+            LayerOp::Pixel(p, color) => backend.draw_pixel(shift(*p), color),
+            LayerOp::Line(from, to, style) => backend.draw_line(shift(*from), shift(*to), style),
+            LayerOp::Rect(ul, br, style, fill) => {
+                backend.draw_rect(shift(*ul), shift(*br), style, *fill)
+            }
+            LayerOp::Path(path, style) => backend.draw_path(path.iter().map(|p| shift(*p)), style),
+            LayerOp::CubicBezier(curve, style) => backend.draw_cubic_bezier(
+                [
+                    shift(curve[0]),
+                    shift(curve[1]),
+                    shift(curve[2]),
+                    shift(curve[3]),
+                ],
+                style,
+            ),
+            LayerOp::Circle(center, radius, style, fill) => {
+                backend.draw_circle(shift(*center), *radius, style, *fill)
+            }
+            LayerOp::Polygon(vert, style) => {
+                backend.fill_polygon(vert.iter().map(|p| shift(*p)), style)
+            }
+            LayerOp::Text(text, style, pos) => {
+                backend.draw_text(text, &style.to_text_style(), shift(*pos))
+            }
+            LayerOp::Bitmap(pos, size, src) => backend.blit_bitmap(shift(*pos), *size, src),
+        }
+    }
+}
+
+type LayerQueue = Rc<RefCell<Vec<(i32, LayerOp)>>>;
+
+/// The backend of a layer, which queues the drawing operations until the layers are
+/// composited on the target drawing area
+pub struct LayerBackend<DB: DrawingBackend> {
+    z: i32,
+    size: (u32, u32),
+    queue: LayerQueue,
+    phantom: PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend> LayerBackend<DB> {
+    fn push(&self, op: LayerOp) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.queue.borrow_mut().push((self.z, op));
+        Ok(())
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for LayerBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Pixel(point, color.clone()))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Line(from, to, record_style(style)))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Rect(
+            upper_left,
+            bottom_right,
+            record_style(style),
+            fill,
+        ))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Path(
+            path.into_iter().collect(),
+            record_style(style),
+        ))
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::CubicBezier(curve, record_style(style)))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Circle(center, radius, record_style(style), fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Polygon(
+            vert.into_iter().collect(),
+            record_style(style),
+        ))
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Text(
+            text.to_string(),
+            RecordedTextStyle::new(style),
+            pos,
+        ))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Bitmap(pos, size, src.to_vec()))
+    }
+}
+
+/// The backend which records the drawing operations of the elements, so the elements can be
+/// drawn on the worker threads and the operations are replayed on the real backend later,
+/// see `DrawingArea::draw_par`. The operations are in the coordinate of the real backend.
+pub struct RecordingBackend<DB: DrawingBackend> {
+    size: (u32, u32),
+    ops: Vec<LayerOp>,
+    phantom: PhantomData<fn() -> DB>,
+}
+
+impl<DB: DrawingBackend> RecordingBackend<DB> {
+    #[cfg(feature = "rayon")]
+    pub(crate) fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            ops: vec![],
+            phantom: PhantomData,
+        }
+    }
+
+    // Draw the recorded operations on the real backend
+    #[cfg(feature = "rayon")]
+    pub(crate) fn replay(&self, backend: &mut DB) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        for op in self.ops.iter() {
+            op.replay(backend, (0, 0))?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, op: LayerOp) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.ops.push(op);
+        Ok(())
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for RecordingBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Pixel(point, color.clone()))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Line(from, to, record_style(style)))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Rect(
+            upper_left,
+            bottom_right,
+            record_style(style),
+            fill,
+        ))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Path(
+            path.into_iter().collect(),
+            record_style(style),
+        ))
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::CubicBezier(curve, record_style(style)))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Circle(center, radius, record_style(style), fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Polygon(
+            vert.into_iter().collect(),
+            record_style(style),
+        ))
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Text(
+            text.to_string(),
+            RecordedTextStyle::new(style),
+            pos,
+        ))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.push(LayerOp::Bitmap(pos, size, src.to_vec()))
+    }
+}
+
+/// A set of layers over the same region of a drawing area. The layers queue the drawing
+/// operations, and `composite` draws them onto the area from the lowest z-order to the
+/// highest, so the layers can be drawn in any order and still stack correctly. The
+/// operations on the same layer are drawn in the order they were issued.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let layers = root.layers();
+/// let annotation = layers.layer(1);
+/// let background = layers.layer(0);
+/// annotation.draw(&Text::new("Peak", (150, 100), ("sans-serif", 20))).unwrap();
+/// background.fill(&WHITE).unwrap();
+/// // The background is drawn below the annotation
+/// layers.composite().unwrap();
+/// ```
+pub struct Layers<DB: DrawingBackend> {
+    target: DrawingArea<DB, Shift>,
+    queue: LayerQueue,
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
+    /// Create a set of layers over the pixel region of this drawing area
+    pub fn layers(&self) -> Layers<DB> {
+        Layers {
+            target: self.strip_coord_spec(),
+            queue: Rc::new(RefCell::new(vec![])),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> Layers<DB> {
+    /// Get a layer with the given z-order, the layers with a larger z-order are drawn on top.
+    /// The layer is a drawing area of the same size as the region, so charts and elements can
+    /// be drawn on it as usual.
+    pub fn layer(&self, z: i32) -> DrawingArea<LayerBackend<DB>, Shift> {
+        LayerBackend {
+            z,
+            size: self.target.dim_in_pixel(),
+            queue: self.queue.clone(),
+            phantom: PhantomData,
+        }
+        .into_drawing_area()
+    }
+
+    /// Rasterize the queued operations of all the layers in the z-order into a bitmap of the
+    /// size of the region, the queue is kept. The bitmap can be drawn on any drawing area,
+    /// so a static layer is rendered once, or a thumbnail of a chart is made with
+    /// `BitMapElement::resized`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (400, 300)).into_drawing_area();
+    /// let layers = root.layers();
+    /// let chart_layer = layers.layer(0);
+    /// chart_layer.fill(&WHITE).unwrap();
+    /// chart_layer
+    ///     .draw(&Circle::new((200, 150), 100, RED.filled()))
+    ///     .unwrap();
+    ///
+    /// let mut thumbnail = layers.rasterize().resized((80, 60));
+    /// layers.composite().unwrap();
+    /// // An overview of the chart in the upper right corner
+    /// thumbnail.move_to((310, 10));
+    /// root.draw(&thumbnail).unwrap();
+    /// ```
+    #[cfg(feature = "bitmap")]
+    pub fn rasterize(&self) -> crate::element::BitMapElement<'static, BackendCoord> {
+        let mut ops: Vec<_> = self
+            .queue
+            .borrow()
+            .iter()
+            .map(|(z, _)| *z)
+            .enumerate()
+            .collect();
+        ops.sort_by_key(|(_, z)| *z);
+        let mut bitmap = crate::element::BitMapElement::new((0, 0), self.target.dim_in_pixel());
+        {
+            let mut backend = bitmap.as_bitmap_backend();
+            let queue = self.queue.borrow();
+            for (idx, _) in ops {
+                // The bitmap backend only fails on saving, which the buffer doesn't do
+                queue[idx].1.replay(&mut backend, (0, 0)).ok();
+            }
+        }
+        bitmap
+    }
+
+    /// Draw the queued operations of all the layers onto the drawing area in the z-order and
+    /// clear the queue
+    pub fn composite(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut ops = core::mem::take(&mut *self.queue.borrow_mut());
+        // The sort is stable, so the operations of a layer keep their order
+        ops.sort_by_key(|(z, _)| *z);
+        self.target.draw(&Composite {
+            ops,
+            origin: (0, 0),
+        })
+    }
+}
+
+// The element replaying the queued operations relative to the upper left corner of the area
+struct Composite {
+    ops: Vec<(i32, LayerOp)>,
+    origin: BackendCoord,
+}
+
+impl<'a> PointCollection<'a, BackendCoord> for &'a Composite {
+    type Borrow = &'a BackendCoord;
+    type IntoIter = core::iter::Once<&'a BackendCoord>;
+    fn point_iter(self) -> core::iter::Once<&'a BackendCoord> {
+        core::iter::once(&self.origin)
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for Composite {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let base = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        for (_, op) in self.ops.iter() {
+            op.replay(backend, base)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_layers_z_order() {
+        let root = crate::create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                assert_eq!((ul, br), ((100, 0), (199, 99)));
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path, vec![(100, 10), (150, 60)]);
+            });
+            m.check_draw_text(|c, _, _, pos, text| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(text, "top");
+                assert_eq!(pos, (110, 20));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let (_, right) = root.split_horizontally(100);
+        let layers = right.layers();
+
+        let top = layers.layer(2);
+        let data = layers.layer(1);
+        let background = layers.layer(-1);
+        assert_eq!(top.dim_in_pixel(), (100, 100));
+
+        top.draw(&Text::new(
+            "top",
+            (10, 20),
+            ("sans-serif", 10).into_font().color(&RED),
+        ))
+        .expect("Drawing Failure");
+        data.draw(&PathElement::new(vec![(0, 10), (50, 60)], &BLUE))
+            .expect("Drawing Failure");
+        background.fill(&WHITE).expect("Drawing Failure");
+
+        layers.composite().expect("Drawing Failure");
+        // The queue is cleared by the composition
+        layers.composite().expect("Drawing Failure");
+    }
+
+    #[cfg(feature = "bitmap")]
+    #[test]
+    fn test_layers_rasterize() {
+        let root = crate::create_mocked_drawing_area(40, 20, |_| {});
+        let layers = root.layers();
+        layers.layer(1).fill(&BLUE).expect("Drawing Failure");
+        layers.layer(0).fill(&RED).expect("Drawing Failure");
+        layers
+            .layer(2)
+            .draw(&Rectangle::new([(0, 0), (19, 19)], WHITE.filled()))
+            .expect("Drawing Failure");
+
+        let mut buf = vec![0; 2 * 3];
+        {
+            let thumbnail = layers.rasterize().resized((2, 1));
+            let target = BitMapBackend::with_buffer(&mut buf, (2, 1)).into_drawing_area();
+            target.draw(&thumbnail).expect("Drawing Failure");
+            target.present().expect("Drawing Failure");
+        }
+        // The white rectangle covers the left half, the blue layer covers the red one
+        assert_eq!(buf, vec![255, 255, 255, 0, 0, 255]);
+    }
+}
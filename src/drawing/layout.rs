@@ -0,0 +1,396 @@
+use super::area::DrawingArea;
+use super::backend::DrawingBackend;
+use crate::coord::Shift;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The direction the children of a layout box are placed in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LayoutDirection {
+    /// The children are placed from the left to the right
+    Row,
+    /// The children are placed from the top to the bottom
+    Column,
+}
+
+/// How a layout box with a fixed cross size is aligned in its parent
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LayoutAlign {
+    /// The box fills the cross axis of the parent, the cross size is ignored
+    Stretch,
+    /// The box is aligned to the left or the top of the parent
+    Start,
+    /// The box is centered in the parent
+    Center,
+    /// The box is aligned to the right or the bottom of the parent
+    End,
+}
+
+// The size of a box along the direction of its parent
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MainSize {
+    Fixed(u32),
+    Flex(f64),
+}
+
+/// A box of a layout tree. A box either is a leaf, which becomes a drawing area, or places its
+/// children in a row or a column. Along the direction of the parent a box has a fixed size or
+/// takes a share of the remaining space by its flex weight, but never less than its minimal
+/// size. The margin is the space around the box and the padding is the space between the box
+/// and its children, both in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (800, 600)).into_drawing_area();
+/// let layout = LayoutBox::column()
+///     .padding(10)
+///     .gap(10)
+///     // The header
+///     .child(LayoutBox::leaf().fixed(40))
+///     .child(
+///         LayoutBox::row()
+///             .gap(10)
+///             // A side bar and the main chart
+///             .child(LayoutBox::leaf().flex(1.0).min_size(200))
+///             .child(LayoutBox::leaf().flex(3.0)),
+///     );
+/// let areas = root.layout(&layout);
+/// assert_eq!(areas.len(), 3);
+/// assert_eq!(areas[0].dim_in_pixel(), (780, 40));
+/// assert_eq!(areas[1].get_base_pixel(), (10, 60));
+/// assert_eq!(areas[1].dim_in_pixel(), (200, 530));
+/// assert_eq!(areas[2].dim_in_pixel(), (570, 530));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LayoutBox {
+    direction: Option<LayoutDirection>,
+    size: MainSize,
+    min_size: u32,
+    cross_size: Option<u32>,
+    align: LayoutAlign,
+    margin: [u32; 4],
+    padding: [u32; 4],
+    gap: u32,
+    children: Vec<LayoutBox>,
+}
+
+impl LayoutBox {
+    fn new(direction: Option<LayoutDirection>) -> Self {
+        Self {
+            direction,
+            size: MainSize::Flex(1.0),
+            min_size: 0,
+            cross_size: None,
+            align: LayoutAlign::Stretch,
+            margin: [0; 4],
+            padding: [0; 4],
+            gap: 0,
+            children: vec![],
+        }
+    }
+
+    /// Create a leaf box, which becomes a drawing area
+    pub fn leaf() -> Self {
+        Self::new(None)
+    }
+
+    /// Create a box which places its children from the left to the right
+    pub fn row() -> Self {
+        Self::new(Some(LayoutDirection::Row))
+    }
+
+    /// Create a box which places its children from the top to the bottom
+    pub fn column() -> Self {
+        Self::new(Some(LayoutDirection::Column))
+    }
+
+    /// Append a child box, the children of a leaf are ignored
+    pub fn child(mut self, child: LayoutBox) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Set a fixed size along the direction of the parent, in pixels
+    pub fn fixed(mut self, size: u32) -> Self {
+        self.size = MainSize::Fixed(size);
+        self
+    }
+
+    /// Set the flex weight, the boxes without a fixed size share the remaining space of the
+    /// parent by their weights, 1 by default
+    pub fn flex(mut self, weight: f64) -> Self {
+        self.size = MainSize::Flex(weight.max(0.0));
+        self
+    }
+
+    /// Set the minimal size along the direction of the parent, in pixels
+    pub fn min_size(mut self, size: u32) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Set the size across the direction of the parent, which is used with the alignment
+    pub fn cross_size(mut self, size: u32) -> Self {
+        self.cross_size = Some(size);
+        self
+    }
+
+    /// Set how the box is aligned across the direction of the parent
+    pub fn align(mut self, align: LayoutAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the same margin on all the sides
+    pub fn margin(self, size: u32) -> Self {
+        self.margins(size, size, size, size)
+    }
+
+    /// Set the margins of the sides
+    pub fn margins(mut self, top: u32, bottom: u32, left: u32, right: u32) -> Self {
+        self.margin = [top, bottom, left, right];
+        self
+    }
+
+    /// Set the same padding on all the sides
+    pub fn padding(mut self, size: u32) -> Self {
+        self.padding = [size; 4];
+        self
+    }
+
+    /// Set the space between the children, in pixels
+    pub fn gap(mut self, size: u32) -> Self {
+        self.gap = size;
+        self
+    }
+
+    // The outer size along the given direction, which includes the margins
+    fn margin_along(&self, direction: LayoutDirection) -> i32 {
+        match direction {
+            LayoutDirection::Row => (self.margin[2] + self.margin[3]) as i32,
+            LayoutDirection::Column => (self.margin[0] + self.margin[1]) as i32,
+        }
+    }
+
+    fn weight(&self) -> f64 {
+        match self.size {
+            MainSize::Flex(weight) => weight,
+            MainSize::Fixed(_) => 0.0,
+        }
+    }
+
+    // The sizes of the children along the direction, the flexible children below their
+    // minimal sizes are frozen at the minimum and the others share the rest
+    fn child_sizes(&self, direction: LayoutDirection, available: i32) -> Vec<i32> {
+        let mut sizes: Vec<Option<i32>> = self
+            .children
+            .iter()
+            .map(|c| match c.size {
+                MainSize::Fixed(size) => Some(size.max(c.min_size) as i32),
+                MainSize::Flex(_) => None,
+            })
+            .collect();
+        loop {
+            let fixed: i32 = self
+                .children
+                .iter()
+                .zip(sizes.iter())
+                .map(|(c, s)| s.unwrap_or(0) + c.margin_along(direction))
+                .sum();
+            let total_weight: f64 = self
+                .children
+                .iter()
+                .zip(sizes.iter())
+                .filter(|(_, s)| s.is_none())
+                .map(|(c, _)| c.weight())
+                .sum();
+            let remaining = f64::from((available - fixed).max(0));
+            let mut frozen = false;
+            let mut sum = 0.0;
+            let mut flex = vec![];
+            for (c, s) in self.children.iter().zip(sizes.iter()) {
+                if s.is_some() {
+                    flex.push(None);
+                    continue;
+                }
+                // Round the cumulative sizes, so the flexible boxes add up to the remaining space
+                let begin = sum;
+                sum += if total_weight > 0.0 {
+                    remaining * c.weight() / total_weight
+                } else {
+                    0.0
+                };
+                let size = (sum.round() - begin.round()) as i32;
+                if size < c.min_size as i32 {
+                    frozen = true;
+                }
+                flex.push(Some(size));
+            }
+            if !frozen {
+                return sizes
+                    .into_iter()
+                    .zip(flex)
+                    .map(|(s, f)| s.or(f).unwrap_or(0))
+                    .collect();
+            }
+            for ((c, s), f) in self.children.iter().zip(sizes.iter_mut()).zip(flex) {
+                if let Some(f) = f {
+                    if f < c.min_size as i32 {
+                        *s = Some(c.min_size as i32);
+                    }
+                }
+            }
+        }
+    }
+
+    // Resolve the rectangles of the leaves in the given outer rectangle, which includes the
+    // margins of this box
+    fn resolve(
+        &self,
+        (x0, y0, x1, y1): (i32, i32, i32, i32),
+        leaves: &mut Vec<(i32, i32, i32, i32)>,
+    ) {
+        let (x0, y0) = (x0 + self.margin[2] as i32, y0 + self.margin[0] as i32);
+        let (x1, y1) = (
+            x0.max(x1 - self.margin[3] as i32),
+            y0.max(y1 - self.margin[1] as i32),
+        );
+        let direction = match self.direction {
+            Some(direction) => direction,
+            None => {
+                leaves.push((x0, y0, x1, y1));
+                return;
+            }
+        };
+
+        let (x0, y0) = (x0 + self.padding[2] as i32, y0 + self.padding[0] as i32);
+        let (x1, y1) = (
+            x0.max(x1 - self.padding[3] as i32),
+            y0.max(y1 - self.padding[1] as i32),
+        );
+        let (main, cross) = match direction {
+            LayoutDirection::Row => ((x0, x1), (y0, y1)),
+            LayoutDirection::Column => ((y0, y1), (x0, x1)),
+        };
+        let gaps = self.gap as i32 * (self.children.len() as i32 - 1).max(0);
+        let sizes = self.child_sizes(direction, main.1 - main.0 - gaps);
+
+        let mut pos = main.0;
+        for (child, size) in self.children.iter().zip(sizes) {
+            let outer = size + child.margin_along(direction);
+            let (m0, m1) = (pos.min(main.1), (pos + outer).min(main.1));
+            pos += outer + self.gap as i32;
+
+            let (c0, c1) = match (child.align, child.cross_size) {
+                (LayoutAlign::Stretch, _) | (_, None) => cross,
+                (align, Some(size)) => {
+                    let margin = match direction {
+                        LayoutDirection::Row => child.margin_along(LayoutDirection::Column),
+                        LayoutDirection::Column => child.margin_along(LayoutDirection::Row),
+                    };
+                    let outer = (size as i32 + margin).min(cross.1 - cross.0);
+                    let free = cross.1 - cross.0 - outer;
+                    let start = match align {
+                        LayoutAlign::Start | LayoutAlign::Stretch => cross.0,
+                        LayoutAlign::Center => cross.0 + free / 2,
+                        LayoutAlign::End => cross.0 + free,
+                    };
+                    (start, start + outer)
+                }
+            };
+
+            let rect = match direction {
+                LayoutDirection::Row => (m0, c0, m1, c1),
+                LayoutDirection::Column => (c0, m0, c1, m1),
+            };
+            child.resolve(rect, leaves);
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
+    /// Resolve a layout tree in this drawing area and create the drawing areas of the leaves,
+    /// in the depth-first order of the tree. The areas are clipped to this area.
+    pub fn layout(&self, layout: &LayoutBox) -> Vec<Self> {
+        let (w, h) = self.dim_in_pixel();
+        let mut leaves = vec![];
+        layout.resolve((0, 0, w as i32, h as i32), &mut leaves);
+        leaves
+            .into_iter()
+            .map(|(x0, y0, x1, y1)| self.clone().shrink((x0, y0), (x1 - x0, y1 - y0)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::create_mocked_drawing_area;
+
+    fn rects(
+        area: &[DrawingArea<crate::drawing::MockedBackend, Shift>],
+    ) -> Vec<((i32, i32), (u32, u32))> {
+        area.iter()
+            .map(|a| (a.get_base_pixel(), a.dim_in_pixel()))
+            .collect()
+    }
+
+    #[test]
+    fn test_layout_sizes() {
+        let root = create_mocked_drawing_area(300, 200, |_| {});
+        let layout = LayoutBox::row()
+            .margin(10)
+            .gap(5)
+            .child(LayoutBox::leaf().fixed(50))
+            .child(LayoutBox::leaf().flex(2.0))
+            .child(LayoutBox::leaf().flex(1.0).min_size(100))
+            .child(LayoutBox::leaf().flex(1.0).margins(0, 0, 5, 5));
+        // The fixed box and the margins take 60 of the 265 pixels between the gaps, the box
+        // with the minimal size takes 100 and the others share the 105 pixels left
+        assert_eq!(
+            rects(&root.layout(&layout)),
+            vec![
+                ((10, 10), (50, 180)),
+                ((65, 10), (70, 180)),
+                ((140, 10), (100, 180)),
+                ((250, 10), (35, 180)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layout_alignment() {
+        let root = create_mocked_drawing_area(300, 200, |_| {});
+        let layout = LayoutBox::column()
+            .padding(10)
+            .child(LayoutBox::leaf().cross_size(100).align(LayoutAlign::Center))
+            .child(LayoutBox::leaf().cross_size(100).align(LayoutAlign::End))
+            .child(LayoutBox::leaf().cross_size(100))
+            .child(
+                LayoutBox::row()
+                    .child(LayoutBox::leaf())
+                    .child(LayoutBox::leaf()),
+            );
+        assert_eq!(
+            rects(&root.layout(&layout)),
+            vec![
+                ((100, 10), (100, 45)),
+                ((190, 55), (100, 45)),
+                ((10, 100), (280, 45)),
+                ((10, 145), (140, 45)),
+                ((150, 145), (140, 45)),
+            ]
+        );
+
+        // The boxes which don't fit are clipped
+        let layout = LayoutBox::row()
+            .child(LayoutBox::leaf().fixed(200))
+            .child(LayoutBox::leaf().fixed(200));
+        assert_eq!(
+            rects(&root.layout(&layout)),
+            vec![((0, 0), (200, 200)), ((200, 0), (100, 200))]
+        );
+    }
+}
@@ -15,10 +15,19 @@ Currently we have following backend implemented:
 - `SVGBackend`: The backend that creates SVG image, based on `svg` crate.
 - `PistonBackend`: The backend that uses Piston Window for real time rendering. Disabled by default, use feature `piston` to turn on.
 - `CanvasBackend`: The backend that operates HTML5 Canvas, this is available when `Plotters` is targeting WASM.
+- `OffscreenCanvasBackend`: The backend that draws on an `OffscreenCanvas` in a web worker, this is available when `Plotters` is targeting WASM.
 
 */
 mod area;
 mod backend_impl;
+mod clip;
+mod dirty;
+mod layers;
+mod layout;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+mod profile;
+#[cfg(feature = "bitmap")]
+mod sendable;
 
 pub mod rasterizer;
 
@@ -28,4 +37,18 @@ pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
 
 pub use backend_impl::*;
 
+pub use clip::{ClippedBackend, SharedClippedBackend};
+
+pub use dirty::DirtyRectBackend;
+
+pub use layers::{LayerBackend, Layers, RecordingBackend};
+
+pub use layout::{LayoutAlign, LayoutBox, LayoutDirection};
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use profile::{ProfiledBackend, RenderProfile, RenderStats};
+
+#[cfg(feature = "bitmap")]
+pub use sendable::SendableArea;
+
 pub use backend::DrawingBackend;
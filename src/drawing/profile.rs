@@ -0,0 +1,443 @@
+use super::backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, RenderEvent, RenderPhase,
+};
+use crate::style::{FontDesc, RGBAColor, TextStyle};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// The statistics of rendering a frame, which are collected by `ProfiledBackend`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// The number of calls to the backend, a batch of shapes is a single call
+    pub draw_calls: usize,
+    /// The number of pixels drawn
+    pub pixels: usize,
+    /// The number of lines drawn, including the lines drawn in batches
+    pub lines: usize,
+    /// The number of rectangles drawn, including the rectangles drawn in batches
+    pub rects: usize,
+    /// The number of paths and curves drawn
+    pub paths: usize,
+    /// The number of circles drawn
+    pub circles: usize,
+    /// The number of polygons filled
+    pub polygons: usize,
+    /// The number of texts drawn
+    pub texts: usize,
+    /// The number of bitmaps copied
+    pub bitmaps: usize,
+    /// The estimated number of pixels touched by the shapes, the overlapping pixels are
+    /// counted more than once
+    pub pixels_touched: u64,
+    /// The number of high-level elements drawn
+    pub elements: usize,
+    /// The time spent on laying out the charts
+    pub layout_time: Duration,
+    /// The time spent on drawing the meshes of the charts
+    pub mesh_time: Duration,
+    /// The time spent on drawing the series of the charts
+    pub series_time: Duration,
+}
+
+#[derive(Default)]
+struct ProfileState {
+    current: RenderStats,
+    presented: Option<RenderStats>,
+    phase: Option<(RenderPhase, Instant)>,
+}
+
+impl ProfileState {
+    fn end_phase(&mut self) {
+        if let Some((phase, start)) = self.phase.take() {
+            let elapsed = start.elapsed();
+            match phase {
+                RenderPhase::Layout => self.current.layout_time += elapsed,
+                RenderPhase::Mesh => self.current.mesh_time += elapsed,
+                RenderPhase::Series => self.current.series_time += elapsed,
+            }
+        }
+    }
+}
+
+/// The handle of the statistics collected by a `ProfiledBackend`, which can be read after the
+/// backend is moved into a drawing area
+#[derive(Clone)]
+pub struct RenderProfile(Rc<RefCell<ProfileState>>);
+
+impl RenderProfile {
+    /// Get the statistics of the last presented frame, or `None` if no frame is presented
+    pub fn last_frame(&self) -> Option<RenderStats> {
+        self.0.borrow().presented.clone()
+    }
+
+    /// Get the statistics collected since the last frame is presented
+    pub fn current(&self) -> RenderStats {
+        self.0.borrow().current.clone()
+    }
+}
+
+/// A backend which counts the drawing operations and times the phases of drawing the charts
+/// on the underlying backend, which helps to find out what makes a figure slow. The
+/// statistics of a frame are available from the `RenderProfile` after the frame is presented.
+///
+/// ```rust
+/// use plotters::drawing::ProfiledBackend;
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let backend = ProfiledBackend::new(SVGBackend::with_string(&mut buf, (300, 200)));
+/// let profile = backend.profile();
+/// let root = backend.into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(-0.1..1.1, 0.0..1.0)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series((0..100).map(|i| Circle::new((i as f64 / 100.0, 0.5), 2, &RED)))
+///     .unwrap();
+/// root.present().unwrap();
+///
+/// let stats = profile.last_frame().unwrap();
+/// assert_eq!(stats.circles, 100);
+/// assert!(stats.elements >= 100);
+/// ```
+pub struct ProfiledBackend<DB: DrawingBackend> {
+    inner: DB,
+    state: Rc<RefCell<ProfileState>>,
+}
+
+// The number of pixels on a line with the given stroke width
+fn line_pixels(from: BackendCoord, to: BackendCoord, width: u32) -> u64 {
+    let len = u64::from(
+        (from.0 - to.0)
+            .unsigned_abs()
+            .max((from.1 - to.1).unsigned_abs()),
+    ) + 1;
+    len * u64::from(width.max(1))
+}
+
+fn path_pixels(path: &[BackendCoord], width: u32) -> u64 {
+    match path.len() {
+        0 => 0,
+        1 => u64::from(width.max(1)),
+        _ => path
+            .windows(2)
+            .map(|w| line_pixels(w[0], w[1], width))
+            .sum(),
+    }
+}
+
+fn rect_pixels(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    width: u32,
+    fill: bool,
+) -> u64 {
+    let w = u64::from((bottom_right.0 - upper_left.0).unsigned_abs()) + 1;
+    let h = u64::from((bottom_right.1 - upper_left.1).unsigned_abs()) + 1;
+    if fill {
+        w * h
+    } else {
+        (2 * (w + h)).min(w * h) * u64::from(width.max(1))
+    }
+}
+
+// The area of the polygon with the shoelace formula
+fn polygon_pixels(vert: &[BackendCoord]) -> u64 {
+    let n = vert.len();
+    let twice_area: i64 = (0..n)
+        .map(|i| {
+            let (a, b) = (vert[i], vert[(i + 1) % n]);
+            i64::from(a.0) * i64::from(b.1) - i64::from(b.0) * i64::from(a.1)
+        })
+        .sum();
+    (twice_area.unsigned_abs() / 2).max(n.min(1) as u64)
+}
+
+impl<DB: DrawingBackend> ProfiledBackend<DB> {
+    /// Create a backend profiling the underlying backend
+    /// - `inner`: The backend to draw on
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            state: Rc::new(RefCell::new(ProfileState::default())),
+        }
+    }
+
+    /// Get the handle of the statistics collected by this backend
+    pub fn profile(&self) -> RenderProfile {
+        RenderProfile(self.state.clone())
+    }
+
+    /// Get the underlying backend
+    pub fn inner(&self) -> &DB {
+        &self.inner
+    }
+
+    /// Unwrap the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn record<F: FnOnce(&mut RenderStats)>(&self, pixels_touched: u64, update: F) {
+        let mut state = self.state.borrow_mut();
+        state.current.draw_calls += 1;
+        state.current.pixels_touched += pixels_touched;
+        update(&mut state.current);
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ProfiledBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let result = self.inner.present();
+        let mut state = self.state.borrow_mut();
+        state.end_phase();
+        let stats = std::mem::take(&mut state.current);
+        state.presented = Some(stats);
+        result
+    }
+
+    fn present_regions(
+        &mut self,
+        regions: &[(BackendCoord, BackendCoord)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let result = self.inner.present_regions(regions);
+        let mut state = self.state.borrow_mut();
+        state.end_phase();
+        let stats = std::mem::take(&mut state.current);
+        state.presented = Some(stats);
+        result
+    }
+
+    fn on_render_event(&mut self, event: RenderEvent) {
+        {
+            let mut state = self.state.borrow_mut();
+            match event {
+                RenderEvent::PhaseStart(phase) => {
+                    state.end_phase();
+                    state.phase = Some((phase, Instant::now()));
+                }
+                RenderEvent::PhaseEnd(_) => state.end_phase(),
+                RenderEvent::Element => state.current.elements += 1,
+            }
+        }
+        self.inner.on_render_event(event)
+    }
+
+    fn set_tooltip(&mut self, tooltip: Option<&str>) {
+        self.inner.set_tooltip(tooltip)
+    }
+
+    fn begin_group(&mut self, label: &str) {
+        self.inner.begin_group(label)
+    }
+
+    fn end_group(&mut self) {
+        self.inner.end_group()
+    }
+
+    fn set_group_label(&mut self, label: &str) {
+        self.inner.set_group_label(label)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.record(1, |s| s.pixels += 1);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let pixels: Vec<_> = pixels.into_iter().collect();
+        let count = pixels.len();
+        self.record(count as u64, |s| s.pixels += count);
+        self.inner.draw_pixels(pixels)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.record(line_pixels(from, to, style.stroke_width()), |s| {
+            s.lines += 1
+        });
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_lines<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        lines: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let lines: Vec<_> = lines.into_iter().collect();
+        let width = style.stroke_width();
+        let touched = lines.iter().map(|&(a, b)| line_pixels(a, b, width)).sum();
+        let count = lines.len();
+        self.record(touched, |s| s.lines += count);
+        self.inner.draw_lines(lines, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let touched = rect_pixels(upper_left, bottom_right, style.stroke_width(), fill);
+        self.record(touched, |s| s.rects += 1);
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_rects<S: BackendStyle, I: IntoIterator<Item = (BackendCoord, BackendCoord)>>(
+        &mut self,
+        rects: I,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let rects: Vec<_> = rects.into_iter().collect();
+        let width = style.stroke_width();
+        let touched = rects
+            .iter()
+            .map(|&(a, b)| rect_pixels(a, b, width, fill))
+            .sum();
+        let count = rects.len();
+        self.record(touched, |s| s.rects += count);
+        self.inner.draw_rects(rects, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.record(path_pixels(&path, style.stroke_width()), |s| s.paths += 1);
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_cubic_bezier<S: BackendStyle>(
+        &mut self,
+        curve: [BackendCoord; 4],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The curve is not longer than its control polygon
+        self.record(path_pixels(&curve, style.stroke_width()), |s| s.paths += 1);
+        self.inner.draw_cubic_bezier(curve, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = f64::from(radius);
+        let touched = if fill {
+            std::f64::consts::PI * r * r
+        } else {
+            2.0 * std::f64::consts::PI * r * f64::from(style.stroke_width().max(1))
+        };
+        self.record(touched.ceil().max(1.0) as u64, |s| s.circles += 1);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.record(polygon_pixels(&vert), |s| s.polygons += 1);
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (w, h) = self.inner.estimate_text_size(text, &style.font)?;
+        self.record(u64::from(w) * u64::from(h), |s| s.texts += 1);
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<'b>(
+        &self,
+        text: &str,
+        font: &FontDesc<'b>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.record(u64::from(iw) * u64::from(ih), |s| s.bitmaps += 1);
+        self.inner.blit_bitmap(pos, (iw, ih), src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::style::{Color, RED};
+
+    #[test]
+    fn test_render_stats() {
+        let backend = ProfiledBackend::new(MockedBackend::new(100, 100));
+        let profile = backend.profile();
+        let mut backend = backend;
+        let style = RED.to_rgba();
+
+        backend.on_render_event(RenderEvent::PhaseStart(RenderPhase::Series));
+        backend.on_render_event(RenderEvent::Element);
+        backend.draw_pixel((1, 1), &style).unwrap();
+        backend.draw_line((0, 0), (9, 3), &style).unwrap();
+        backend
+            .draw_rects(vec![((0, 0), (9, 9)), ((20, 20), (24, 21))], &style, true)
+            .unwrap();
+        backend
+            .fill_polygon(vec![(0, 0), (10, 0), (10, 10), (0, 10)], &style)
+            .unwrap();
+        assert!(profile.last_frame().is_none());
+        assert_eq!(profile.current().draw_calls, 4);
+
+        backend.present().unwrap();
+        let stats = profile.last_frame().unwrap();
+        assert_eq!(
+            (stats.pixels, stats.lines, stats.rects, stats.polygons),
+            (1, 1, 2, 1)
+        );
+        assert_eq!(stats.elements, 1);
+        assert_eq!(stats.pixels_touched, 1 + 10 + 100 + 10 + 100);
+        assert_eq!(stats.layout_time, Duration::from_secs(0));
+        // The next frame starts from scratch
+        assert_eq!(profile.current(), RenderStats::default());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::drawing::backend::BackendCoord;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+// The maximum distance in pixels between the curve and the flattened polyline
+const TOLERANCE: f64 = 0.25;
+
+type Point = (f64, f64);
+
+fn mid(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+// The distance from the control points to the chord, which bounds the flattening error
+fn flatness(curve: &[Point; 4]) -> f64 {
+    let (p0, p3) = (curve[0], curve[3]);
+    let (dx, dy) = (p3.0 - p0.0, p3.1 - p0.1);
+    let len = dx.hypot(dy);
+    let dist = |p: Point| {
+        if len == 0.0 {
+            (p.0 - p0.0).hypot(p.1 - p0.1)
+        } else {
+            ((p.0 - p0.0) * dy - (p.1 - p0.1) * dx).abs() / len
+        }
+    };
+    dist(curve[1]).max(dist(curve[2]))
+}
+
+fn subdivide(curve: [Point; 4], depth: u32, out: &mut Vec<Point>) {
+    if depth == 0 || flatness(&curve) <= TOLERANCE {
+        out.push(curve[3]);
+        return;
+    }
+    // De Casteljau's algorithm at t = 0.5
+    let ab = mid(curve[0], curve[1]);
+    let bc = mid(curve[1], curve[2]);
+    let cd = mid(curve[2], curve[3]);
+    let abc = mid(ab, bc);
+    let bcd = mid(bc, cd);
+    let center = mid(abc, bcd);
+    subdivide([curve[0], ab, abc, center], depth - 1, out);
+    subdivide([center, bcd, cd, curve[3]], depth - 1, out);
+}
+
+/// Flatten a cubic Bézier curve into a polyline, the curve is subdivided adaptively until
+/// each piece is flat enough
+///
+/// - `curve`: The start point, the two control points and the end point
+/// - **returns**: The vertices of the polyline, including both end points
+pub fn flatten_cubic_bezier(curve: [BackendCoord; 4]) -> Vec<BackendCoord> {
+    let to_f64 = |(x, y): BackendCoord| (f64::from(x), f64::from(y));
+    let curve = [
+        to_f64(curve[0]),
+        to_f64(curve[1]),
+        to_f64(curve[2]),
+        to_f64(curve[3]),
+    ];
+    let mut points = vec![curve[0]];
+    subdivide(curve, 16, &mut points);
+
+    let mut ret: Vec<BackendCoord> = Vec::with_capacity(points.len());
+    for (x, y) in points {
+        let p = (x.round() as i32, y.round() as i32);
+        if ret.last() != Some(&p) {
+            ret.push(p);
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flatten_cubic_bezier() {
+        let line = flatten_cubic_bezier([(0, 0), (10, 0), (20, 0), (30, 0)]);
+        assert_eq!(line, vec![(0, 0), (30, 0)]);
+
+        let curve = flatten_cubic_bezier([(0, 100), (0, 0), (100, 0), (100, 100)]);
+        assert_eq!(curve.first(), Some(&(0, 100)));
+        assert_eq!(curve.last(), Some(&(100, 100)));
+        assert!(curve.len() > 10);
+        // The curve is symmetric and reaches y = 25 in the middle
+        assert!(curve.contains(&(50, 25)));
+    }
+}
@@ -15,7 +15,7 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
     }
 
     if !fill && style.stroke_width() != 1 {
-        // FIXME: We are currently ignore the stroke width for circles
+        return draw_ring(b, center, radius, style);
     }
 
     let min = (f64::from(radius) * (1.0 - (2f64).sqrt() / 2.0)).ceil() as i32;
@@ -65,3 +65,32 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
 
     Ok(())
 }
+
+// The outline of a circle with a thick stroke, which is filled between the inner and the
+// outer edge of the stroke
+fn draw_ring<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    radius: u32,
+    style: &S,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let half = f64::from(style.stroke_width()) / 2.0;
+    let outer = f64::from(radius) + half;
+    let inner = f64::from(radius) - half;
+    let color = style.as_color();
+
+    let extent = outer.floor() as i32;
+    for dy in -extent..=extent {
+        let y = center.1 + dy;
+        let dy = f64::from(dy);
+        let outer_x = (outer * outer - dy * dy).sqrt().round() as i32;
+        if inner <= 0.0 || dy.abs() >= inner {
+            check_result!(b.draw_line((center.0 - outer_x, y), (center.0 + outer_x, y), &color));
+            continue;
+        }
+        let inner_x = (inner * inner - dy * dy).sqrt().round() as i32;
+        check_result!(b.draw_line((center.0 - outer_x, y), (center.0 - inner_x, y), &color));
+        check_result!(b.draw_line((center.0 + inner_x, y), (center.0 + outer_x, y), &color));
+    }
+    Ok(())
+}
@@ -42,11 +42,15 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         return back.fill_polygon(vertices, &style.as_color());
     }
 
+    // The pixels outside of the backend are skipped, so the lines far outside of the backend
+    // don't take forever to draw
+    let (w, h) = back.get_size();
+
     if from.0 == to.0 {
         if from.1 > to.1 {
-            std::mem::swap(&mut from, &mut to);
+            core::mem::swap(&mut from, &mut to);
         }
-        for y in from.1..=to.1 {
+        for y in from.1.max(-1)..=to.1.min(h as i32) {
             check_result!(back.draw_pixel((from.0, y), &style.as_color()));
         }
         return Ok(());
@@ -54,9 +58,9 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
 
     if from.1 == to.1 {
         if from.0 > to.0 {
-            std::mem::swap(&mut from, &mut to);
+            core::mem::swap(&mut from, &mut to);
         }
-        for x in from.0..=to.0 {
+        for x in from.0.max(-1)..=to.0.min(w as i32) {
             check_result!(back.draw_pixel((x, from.1), &style.as_color()));
         }
         return Ok(());
@@ -8,6 +8,30 @@ macro_rules! check_result {
     };
 }
 
+use crate::drawing::backend::{BackendCoord, BackendStyle};
+use crate::style::RGBAColor;
+
+// Check if the filled shapes of the style are painted with a single color
+fn is_solid_fill<S: BackendStyle>(style: &S) -> bool {
+    style.gradient().is_none() && style.pattern().is_none()
+}
+
+// The color of a pixel of a filled shape with the given bounding box, or `None` if the pattern
+// of the style leaves the pixel unpainted
+fn fill_color_at<S: BackendStyle>(
+    style: &S,
+    point: BackendCoord,
+    (upper_left, bottom_right): (BackendCoord, BackendCoord),
+) -> Option<RGBAColor> {
+    if let Some(gradient) = style.gradient() {
+        return Some(gradient.color_at_point(point, upper_left, bottom_right));
+    }
+    if let Some(pattern) = style.pattern() {
+        return pattern.color_at(point, &style.as_color());
+    }
+    Some(style.as_color())
+}
+
 mod line;
 pub use line::draw_line;
 
@@ -22,3 +46,9 @@ pub use polygon::fill_polygon;
 
 mod path;
 pub use path::polygonize;
+
+mod bezier;
+pub use bezier::flatten_cubic_bezier;
+
+mod text;
+pub use text::draw_text;
@@ -1,4 +1,6 @@
 use crate::drawing::backend::BackendCoord;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 fn get_dir_vector(from: BackendCoord, to: BackendCoord, flag: bool) -> ((f64, f64), (f64, f64)) {
     let v = (i64::from(to.0 - from.0), i64::from(to.1 - from.1));
@@ -1,9 +1,13 @@
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingErrorKind};
 use crate::drawing::DrawingBackend;
 
+use super::{fill_color_at, is_solid_fill};
+
 use crate::style::Color;
 
-use std::cmp::{Ord, Ordering, PartialOrd};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::cmp::{Ord, Ordering, PartialOrd};
 
 #[derive(Clone, Debug)]
 struct Edge {
@@ -20,7 +24,7 @@ impl Edge {
         }
 
         if from.0 > to.0 {
-            std::mem::swap(&mut from, &mut to);
+            core::mem::swap(&mut from, &mut to);
         }
 
         Some(Edge {
@@ -99,6 +103,7 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
         }
 
         let horizontal_sweep = x_span.1 - x_span.0 > y_span.1 - y_span.0;
+        let bbox = ((x_span.0, y_span.0), (x_span.1, y_span.1));
 
         let mut edges: Vec<_> = vertices
             .iter()
@@ -117,10 +122,10 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
         for edge in &mut edges.iter_mut() {
             if horizontal_sweep {
                 if (edge.0).0 > (edge.1).0 {
-                    std::mem::swap(&mut edge.0, &mut edge.1);
+                    core::mem::swap(&mut edge.0, &mut edge.1);
                 }
             } else if (edge.0).1 > (edge.1).1 {
-                std::mem::swap(&mut edge.0, &mut edge.1);
+                core::mem::swap(&mut edge.0, &mut edge.1);
             }
         }
 
@@ -203,34 +208,42 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                             continue;
                         }
 
-                        if horizontal_sweep {
+                        // The pixel coordinate of a position on the sweep line
+                        let at = |pos: i32| {
+                            if horizontal_sweep {
+                                (sweep_line, pos)
+                            } else {
+                                (pos, sweep_line)
+                            }
+                        };
+                        let draw_pixel =
+                            |back: &mut DB, point: BackendCoord, alpha: f64| match fill_color_at(
+                                style, point, bbox,
+                            ) {
+                                Some(color) => back.draw_pixel(point, &color.mix(alpha)),
+                                None => Ok(()),
+                            };
+
+                        if is_solid_fill(style) {
                             check_result!(back.draw_line(
-                                (sweep_line, from.ceil() as i32),
-                                (sweep_line, to.floor() as i32),
+                                at(from.ceil() as i32),
+                                at(to.floor() as i32),
                                 &style.as_color(),
                             ));
-                            check_result!(back.draw_pixel(
-                                (sweep_line, from.floor() as i32),
-                                &style.as_color().mix(from.ceil() - from),
-                            ));
-                            check_result!(back.draw_pixel(
-                                (sweep_line, to.ceil() as i32),
-                                &style.as_color().mix(to - to.floor()),
-                            ));
                         } else {
-                            check_result!(back.draw_line(
-                                (from.ceil() as i32, sweep_line),
-                                (to.floor() as i32, sweep_line),
-                                &style.as_color(),
-                            ));
-                            check_result!(back.draw_pixel(
-                                (from.floor() as i32, sweep_line),
-                                &style.as_color().mix(from.ceil() - from),
-                            ));
-                            check_result!(back.draw_pixel(
-                                (to.ceil() as i32, sweep_line),
-                                &style.as_color().mix(to.floor() - to),
-                            ));
+                            for pos in from.ceil() as i32..=to.floor() as i32 {
+                                check_result!(draw_pixel(back, at(pos), 1.0));
+                            }
+                        }
+                        check_result!(draw_pixel(
+                            back,
+                            at(from.floor() as i32),
+                            from.ceil() - from
+                        ));
+                        if horizontal_sweep {
+                            check_result!(draw_pixel(back, at(to.ceil() as i32), to - to.floor()));
+                        } else {
+                            check_result!(draw_pixel(back, at(to.ceil() as i32), to.floor() - to));
                         }
 
                         first = None;
@@ -1,6 +1,8 @@
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingErrorKind};
 use crate::drawing::DrawingBackend;
 
+use super::{fill_color_at, is_solid_fill};
+
 use crate::style::Color;
 
 pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
@@ -24,14 +26,32 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
         ),
     );
 
-    if fill {
+    // Only the part inside of the backend is filled, the rest is invisible anyway
+    let (w, h) = b.get_size();
+    let (fill_upper_left, fill_bottom_right) = (
+        (upper_left.0.max(-1), upper_left.1.max(-1)),
+        (bottom_right.0.min(w as i32), bottom_right.1.min(h as i32)),
+    );
+
+    if fill && !is_solid_fill(style) {
+        for y in fill_upper_left.1..=fill_bottom_right.1 {
+            for x in fill_upper_left.0..=fill_bottom_right.0 {
+                if let Some(color) = fill_color_at(style, (x, y), (upper_left, bottom_right)) {
+                    check_result!(b.draw_pixel((x, y), &color));
+                }
+            }
+        }
+    } else if fill {
+        // The stroke width doesn't apply to the fill
+        let color = style.as_color();
+        let (upper_left, bottom_right) = (fill_upper_left, fill_bottom_right);
         if bottom_right.0 - upper_left.0 < bottom_right.1 - upper_left.1 {
             for x in upper_left.0..=bottom_right.0 {
-                check_result!(b.draw_line((x, upper_left.1), (x, bottom_right.1), style));
+                check_result!(b.draw_line((x, upper_left.1), (x, bottom_right.1), &color));
             }
         } else {
             for y in upper_left.1..=bottom_right.1 {
-                check_result!(b.draw_line((upper_left.0, y), (bottom_right.0, y), style));
+                check_result!(b.draw_line((upper_left.0, y), (bottom_right.0, y), &color));
             }
         }
     } else {
@@ -0,0 +1,66 @@
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+
+use crate::style::text_anchor::{HPos, VPos};
+use crate::style::{Color, FontTransform, RGBAColor, TextStyle};
+
+/// Draw the text pixel by pixel with the font rasterizer
+/// - `coverage`: Maps the glyph coverage of a pixel to the opacity it's drawn with
+pub fn draw_text<B: DrawingBackend, F: Fn(f32) -> f64>(
+    b: &mut B,
+    text: &str,
+    style: &TextStyle,
+    pos: BackendCoord,
+    coverage: F,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let font = &style.font;
+    let color = &style.color;
+    if color.alpha() == 0.0 {
+        return Ok(());
+    }
+
+    let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+    let ((min_x, min_y), (max_x, max_y)) = layout;
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    let dx = match style.pos.h_pos {
+        HPos::Left => 0,
+        HPos::Right => -width,
+        HPos::Center => -width / 2,
+    };
+    let dy = match style.pos.v_pos {
+        VPos::Top => 0,
+        VPos::Center => -height / 2,
+        VPos::Bottom => -height,
+    };
+    let trans = font.get_transform();
+    let (w, h) = b.get_size();
+    // An arbitrary rotation doesn't map the pixel grid onto itself, so the pixels are
+    // split into sub-pixels to avoid holes in the rotated glyphs
+    let samples: &[(f64, f64)] = match trans {
+        FontTransform::RotateAngle(_) => &[(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)],
+        _ => &[(0.0, 0.0)],
+    };
+    let alpha = color.alpha();
+    match font.draw_color(text, (0, 0), color.rgb(), |x, y, v, glyph_color| {
+        // The color glyphs keep their colors, with the opacity of the text
+        let pixel_color = match glyph_color {
+            Some((r, g, b)) => RGBAColor(r, g, b, alpha),
+            None => color.to_rgba(),
+        };
+        for &(sx, sy) in samples {
+            let (x, y) = trans.transform_f64(
+                f64::from(x + dx - min_x) + sx,
+                f64::from(y + dy - min_y) + sy,
+            );
+            let (x, y) = (pos.0 + x.floor() as i32, pos.1 + y.floor() as i32);
+            if x >= 0 && x < w as i32 && y >= 0 && y < h as i32 {
+                b.draw_pixel((x, y), &pixel_color.mix(coverage(v)))?;
+            }
+        }
+        Ok(())
+    }) {
+        Ok(drawing_result) => drawing_result,
+        Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
+    }
+}
@@ -0,0 +1,129 @@
+use super::area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use super::backend::{BackendCoord, DrawingBackend};
+use super::BitMapBackend;
+use crate::coord::{CoordTranslate, Shift};
+use crate::element::BitMapElement;
+
+type SendableAreaError =
+    DrawingAreaErrorKind<<BitMapBackend<'static> as DrawingBackend>::ErrorType>;
+
+/// A detached copy of a drawing area which can be sent to another thread. The drawing area is
+/// bound to its backend, which can't be shared between threads, so the panel is drawn on an
+/// owned bitmap of the same size and composed into the original drawing area at the end.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (400, 200)).into_drawing_area();
+/// let panels: Vec<_> = root
+///     .split_evenly((1, 2))
+///     .iter()
+///     .map(|area| area.to_sendable())
+///     .collect();
+///
+/// let workers: Vec<_> = panels
+///     .into_iter()
+///     .enumerate()
+///     .map(|(idx, mut panel)| {
+///         std::thread::spawn(move || {
+///             panel
+///                 .draw(|area| {
+///                     let mut chart = ChartBuilder::on(area)
+///                         .caption(format!("Panel {}", idx), ("sans-serif", 15))
+///                         .build_ranged(0.0..1.0, 0.0..1.0)?;
+///                     chart.draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &RED))?;
+///                     Ok(())
+///                 })
+///                 .unwrap();
+///             panel
+///         })
+///     })
+///     .collect();
+///
+/// for worker in workers {
+///     root.compose(&worker.join().unwrap()).unwrap();
+/// }
+/// ```
+pub struct SendableArea {
+    pos: BackendCoord,
+    bitmap: BitMapElement<'static, BackendCoord>,
+}
+
+impl SendableArea {
+    /// Get the upper left corner of the panel in the backend of the original drawing area
+    pub fn get_base_pixel(&self) -> BackendCoord {
+        self.pos
+    }
+
+    /// Draw on the panel, the panel is filled with white before it's drawn the first time
+    /// - `draw_func`: The function that draws on the drawing area of the panel
+    pub fn draw<DrawFunc>(&mut self, draw_func: DrawFunc) -> Result<(), SendableAreaError>
+    where
+        DrawFunc: FnOnce(&DrawingArea<BitMapBackend, Shift>) -> Result<(), SendableAreaError>,
+    {
+        let area = self.bitmap.as_bitmap_backend().into_drawing_area();
+        draw_func(&area)?;
+        area.present()
+    }
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
+    /// Make a detached panel which covers the same pixels as this drawing area, and can be
+    /// drawn from another thread
+    pub fn to_sendable(&self) -> SendableArea {
+        let (w, h) = self.dim_in_pixel();
+        let buf = vec![255; (w * h) as usize * 3];
+        SendableArea {
+            pos: self.get_base_pixel(),
+            bitmap: BitMapElement::with_owned_buffer((0, 0), (w, h), buf)
+                .expect("Wrong buffer size"),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
+    /// Compose a panel made by `to_sendable` back at its original position
+    /// - `panel`: The panel to compose
+    pub fn compose(&self, panel: &SendableArea) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x0, y0) = self.get_base_pixel();
+        let (x, y) = panel.get_base_pixel();
+        self.draw(&panel.bitmap.copy_to((x - x0, y - y0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sendable_area() {
+        let drawing_area = create_mocked_drawing_area(20, 10, |m| {
+            m.check_draw_pixel(|c, (x, y)| {
+                assert!(x >= 10);
+                assert!(y < 10);
+                let expected = if (x, y) == (12, 3) { RED } else { WHITE };
+                assert_eq!(c, expected.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 100);
+            });
+        });
+
+        let areas = drawing_area.split_evenly((1, 2));
+        let mut panel = areas[1].to_sendable();
+        assert_eq!(panel.get_base_pixel(), (10, 0));
+
+        panel = std::thread::spawn(move || {
+            panel
+                .draw(|area| area.draw_pixel((2, 3), &RED))
+                .expect("Drawing error");
+            panel
+        })
+        .join()
+        .unwrap();
+
+        // The panel is composed relatively to the area it's composed into
+        areas[1].compose(&panel).expect("Drawing error");
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+
+/// The shape of the arrow head
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowHead {
+    /// A filled triangle
+    Filled,
+    /// Two strokes forming an open "V"
+    Open,
+    /// A filled triangle whose back edge is notched, like a stealth arrow
+    Stealth,
+}
+
+/// An element of an arrow from one coordinate to another.
+///
+/// The head size is in pixels, so the arrow head keeps its shape regardless of the chart
+/// range. Since it's a regular element, an arrow can also be used inside composed elements,
+/// in which case the coordinates are offsets in pixels:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let annotation = EmptyElement::<_, SVGBackend>::at((1.0, 2.0))
+///     + Arrow::new((30, -30), (0, 0), &RED)
+///     + Text::new("peak", (32, -40), ("sans-serif", 12).into_font());
+/// # let _ = annotation;
+/// ```
+pub struct Arrow<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    head: ArrowHead,
+    head_size: u32,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a new arrow element
+    /// - `from` The tail of the arrow
+    /// - `to` The position the arrow points to
+    /// - `style` The style of the arrow
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self {
+            points: [from, to],
+            style: style.into(),
+            head: ArrowHead::Filled,
+            head_size: 10,
+        }
+    }
+
+    /// Set the shape of the arrow head
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Set the length of the arrow head in pixels
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+
+        let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return Ok(());
+        }
+        let (ux, uy) = (dx / length, dy / length);
+        let size = f64::from(self.head_size).min(length);
+        let half_width = size * 0.4;
+
+        let at = |along: f64, side: f64| {
+            (
+                (f64::from(to.0) - ux * along - uy * side).round() as i32,
+                (f64::from(to.1) - uy * along + ux * side).round() as i32,
+            )
+        };
+        let (left, right) = (at(size, half_width), at(size, -half_width));
+
+        // The shaft stops at the back of a filled head, so a thick line won't poke out of the tip
+        let shaft_end = match self.head {
+            ArrowHead::Filled => at(size, 0.0),
+            ArrowHead::Stealth => at(size * 0.7, 0.0),
+            ArrowHead::Open => to,
+        };
+        backend.draw_path(vec![from, shaft_end], &self.style)?;
+
+        match self.head {
+            ArrowHead::Filled => backend.fill_polygon(vec![to, left, right], &self.style.color),
+            ArrowHead::Stealth => backend.fill_polygon(
+                vec![to, left, at(size * 0.7, 0.0), right],
+                &self.style.color,
+            ),
+            ArrowHead::Open => backend.draw_path(vec![left, to, right], &self.style),
+        }
+    }
+}
+
+impl<Coord> WithOpacity for Arrow<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_arrow_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(10, 10), (90, 10)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(100, 10), (90, 14), (90, 6)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(100, 100), (100, 200)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(96, 190), (100, 200), (104, 190)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        da.draw(&Arrow::new((10, 10), (100, 10), &RED))
+            .expect("Drawing Failure");
+        da.draw(&Arrow::new((100, 100), (100, 200), &RED).head(ArrowHead::Open))
+            .expect("Drawing Failure");
+    }
+}
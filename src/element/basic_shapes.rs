@@ -1,5 +1,7 @@
-use super::{Drawable, PointCollection};
+use super::{Drawable, PointCollection, WithOpacity};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::{ShapeStyle, SizeDesc};
 
 /// An element of a single pixel
@@ -19,9 +21,9 @@ impl<Coord> Pixel<Coord> {
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Pixel<Coord> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = core::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        core::iter::once(&self.pos)
     }
 }
 
@@ -39,6 +41,13 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Pixel<Coord> {
     }
 }
 
+impl<Coord> WithOpacity for Pixel<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_pixel_element() {
@@ -59,6 +68,142 @@ fn test_pixel_element() {
         .expect("Drawing Failure");
 }
 
+/// An element of many pixels with the same style, which are submitted to the backend as a
+/// single batch. It's much faster than a series of `Pixel` elements for large scatter plots.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = vec![0; 100 * 100 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buf, (100, 100)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..1.0, 0.0..1.0).unwrap();
+/// let points: Vec<_> = (0..10000)
+///     .map(|i| ((i % 100) as f64 / 100.0, (i / 100) as f64 / 100.0))
+///     .collect();
+/// chart.draw_series(std::iter::once(Pixels::new(points, &RED))).unwrap();
+/// ```
+pub struct Pixels<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> Pixels<Coord> {
+    /// Create a new batch of pixels
+    /// - `points`: The iterator of the positions of the pixels
+    /// - `style`: The style of the pixels
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(points: I, style: S) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Pixels<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Pixels<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = &self.style.color;
+        backend.draw_pixels(points.map(|p| (p, color.clone())))
+    }
+}
+
+impl<Coord> WithOpacity for Pixels<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+/// An element of many unconnected line segments with the same style, which are submitted to
+/// the backend as a single batch
+pub struct Segments<Coord> {
+    // The start and the end points of the segments, one after another
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> Segments<Coord> {
+    /// Create a new batch of line segments
+    /// - `segments`: The iterator of the start and end points of the segments
+    /// - `style`: The style of the segments
+    pub fn new<I: IntoIterator<Item = (Coord, Coord)>, S: Into<ShapeStyle>>(
+        segments: I,
+        style: S,
+    ) -> Self {
+        Self {
+            points: segments
+                .into_iter()
+                .flat_map(|(from, to)| vec![from, to])
+                .collect(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Segments<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Segments<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let lines = core::iter::from_fn(move || Some((points.next()?, points.next()?)));
+        backend.draw_lines(lines, &self.style)
+    }
+}
+
+impl<Coord> WithOpacity for Segments<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_batched_elements() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|c, s, from, to| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(s, 1);
+            assert_eq!(from.0 + 10, to.0);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_pixel_call, 3);
+            assert_eq!(b.num_draw_line_call, 2);
+            assert_eq!(b.draw_count, 5);
+        });
+    });
+    da.draw(&Pixels::new(vec![(1, 1), (2, 2), (3, 3)], &RED))
+        .expect("Drawing Failure");
+    da.draw(&Segments::new(
+        vec![((0, 0), (10, 0)), ((5, 5), (15, 20))],
+        &BLUE,
+    ))
+    .expect("Drawing Failure");
+}
+
 #[deprecated(note = "Use new name PathElement instead")]
 pub type Path<Coord> = PathElement<Coord>;
 
@@ -66,6 +211,7 @@ pub type Path<Coord> = PathElement<Coord>;
 pub struct PathElement<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    downsample: bool,
 }
 impl<Coord> PathElement<Coord> {
     /// Create a new path
@@ -76,8 +222,67 @@ impl<Coord> PathElement<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            downsample: false,
         }
     }
+
+    /// Reduce the points of the path when it's drawn, to the first, the last, the lowest and
+    /// the highest points of each run of points in the same pixel column. The path looks the
+    /// same, but a path of millions of points is drawn with a few points per pixel.
+    /// - returns the downsampled path
+    pub fn downsample(mut self) -> Self {
+        self.downsample = true;
+        self
+    }
+}
+
+// Keep the first, the last, the lowest and the highest points of each run of the points in
+// the same column, in their order in the path
+fn reduce_columns<I: Iterator<Item = BackendCoord>>(points: I) -> Vec<BackendCoord> {
+    reduce_columns_by(points, |point| *point)
+}
+
+// Keep the first, the last, the lowest and the highest items of each run of the items whose
+// positions are in the same column, the items at the same position are kept once
+pub(crate) fn reduce_columns_by<T: Copy, I: Iterator<Item = T>, F: Fn(&T) -> BackendCoord>(
+    items: I,
+    pos: F,
+) -> Vec<T> {
+    let mut reduced: Vec<T> = vec![];
+    // The first, the lowest, the highest and the last items of the run, with their indices
+    let mut run: Option<[(usize, T); 4]> = None;
+    let flush = |mut run: [(usize, T); 4], reduced: &mut Vec<T>| {
+        run.sort_by_key(|(idx, _)| *idx);
+        for (_, item) in run.iter() {
+            if reduced.last().map(&pos) != Some(pos(item)) {
+                reduced.push(*item);
+            }
+        }
+    };
+    for (idx, item) in items.enumerate() {
+        let point = pos(&item);
+        match run.as_mut() {
+            Some(run) if pos(&run[0].1).0 == point.0 => {
+                if point.1 < pos(&run[1].1).1 {
+                    run[1] = (idx, item);
+                }
+                if point.1 > pos(&run[2].1).1 {
+                    run[2] = (idx, item);
+                }
+                run[3] = (idx, item);
+            }
+            _ => {
+                if let Some(run) = run.take() {
+                    flush(run, &mut reduced);
+                }
+                run = Some([(idx, item); 4]);
+            }
+        }
+    }
+    if let Some(run) = run {
+        flush(run, &mut reduced);
+    }
+    reduced
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a PathElement<Coord> {
@@ -95,7 +300,22 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        // A line is drawn in the stroke color, if there's one
+        let style = self
+            .style
+            .outline_style()
+            .unwrap_or_else(|| self.style.clone());
+        if self.downsample {
+            return backend.draw_path(reduce_columns(points), &style);
+        }
+        backend.draw_path(points, &style)
+    }
+}
+
+impl<Coord> WithOpacity for PathElement<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
     }
 }
 
@@ -121,11 +341,81 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_downsampled_path_element() {
+    assert_eq!(
+        reduce_columns(
+            vec![
+                (0, 5),
+                (0, 2),
+                (0, 9),
+                (0, 4),
+                (1, 4),
+                (2, 0),
+                (2, 1),
+                (3, 3)
+            ]
+            .into_iter()
+        ),
+        vec![
+            (0, 5),
+            (0, 2),
+            (0, 9),
+            (0, 4),
+            (1, 4),
+            (2, 0),
+            (2, 1),
+            (3, 3)
+        ]
+    );
+    assert_eq!(
+        reduce_columns(
+            vec![
+                (0, 5),
+                (0, 6),
+                (0, 2),
+                (0, 3),
+                (0, 9),
+                (0, 8),
+                (0, 4),
+                (1, 1),
+                (1, 1)
+            ]
+            .into_iter()
+        ),
+        vec![(0, 5), (0, 2), (0, 9), (0, 4), (1, 1)]
+    );
+
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|_, _, path| {
+            // Each column keeps the first, the lowest, the highest and the last points
+            assert!(path.len() <= 400);
+            assert!(path.len() > 100);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let da = da.apply_coord_spec(RangedCoord::<RangedCoordf64, RangedCoordf64>::new(
+        0.0..1.0,
+        -1.0..1.0,
+        (0..100, 0..100),
+    ));
+    let points: Vec<_> = (0..100_000)
+        .map(|i| (i as f64 / 100_000.0, (i as f64).sin()))
+        .collect();
+    da.draw(&PathElement::new(points, &RED).downsample())
+        .expect("Drawing Failure");
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    corner_radius: u32,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -138,6 +428,7 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            corner_radius: 0,
         }
     }
 
@@ -150,6 +441,35 @@ impl<Coord> Rectangle<Coord> {
         self.margin = (t, b, l, r);
         self
     }
+
+    /// Set the radius of the rounded corners in pixels, the radius is limited to the half of
+    /// the shorter side of the rectangle
+    /// - `radius`: The corner radius, 0 means square corners
+    pub fn set_corner_radius(&mut self, radius: u32) -> &mut Self {
+        self.corner_radius = radius;
+        self
+    }
+}
+
+// The outline of a rounded rectangle, in clockwise order
+fn rounded_rect_outline(a: BackendCoord, b: BackendCoord, radius: u32) -> Vec<BackendCoord> {
+    let r = f64::from(radius).min(f64::from((b.0 - a.0).min(b.1 - a.1)) / 2.0);
+    let steps = (r / 2.0).ceil().max(1.0) as usize;
+    let corners = [
+        (f64::from(b.0) - r, f64::from(a.1) + r, -90.0),
+        (f64::from(b.0) - r, f64::from(b.1) - r, 0.0),
+        (f64::from(a.0) + r, f64::from(b.1) - r, 90.0),
+        (f64::from(a.0) + r, f64::from(a.1) + r, 180.0),
+    ];
+    let mut outline = Vec::with_capacity((steps + 1) * 4);
+    for &(cx, cy, start) in corners.iter() {
+        for idx in 0..=steps {
+            let angle: f64 = start + 90.0 * idx as f64 / steps as f64;
+            let (sin, cos) = angle.to_radians().sin_cos();
+            outline.push(((cx + r * cos).round() as i32, (cy + r * sin).round() as i32));
+        }
+    }
+    outline
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
@@ -174,13 +494,41 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style, self.style.filled)
+                let outline_style = self.style.outline_style();
+                if self.corner_radius > 0 && b.0 > a.0 && b.1 > a.1 {
+                    let outline = rounded_rect_outline(a, b, self.corner_radius);
+                    if self.style.filled {
+                        backend.fill_polygon(outline.iter().copied(), &self.style.color)?;
+                    }
+                    if let Some(outline_style) = outline_style {
+                        let closing = outline[0];
+                        backend.draw_path(
+                            outline.into_iter().chain(core::iter::once(closing)),
+                            &outline_style,
+                        )?;
+                    }
+                    return Ok(());
+                }
+                if self.style.filled {
+                    backend.draw_rect(a, b, &self.style, true)?;
+                }
+                if let Some(outline_style) = outline_style {
+                    backend.draw_rect(a, b, &outline_style, false)?;
+                }
+                Ok(())
             }
             _ => Ok(()),
         }
     }
 }
 
+impl<Coord> WithOpacity for Rectangle<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_rect_element() {
@@ -222,6 +570,40 @@ fn test_rect_element() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert!(path
+                .iter()
+                .all(|&(x, y)| (100..=200).contains(&x) && (100..=150).contains(&y)));
+            assert!(!path.contains(&(100, 100)));
+            assert!(path.contains(&(190, 100)));
+            assert!(path.contains(&(200, 110)));
+        });
+        m.check_draw_path(|_, _, path| {
+            // The radius is limited by the height of the rectangle
+            assert!(path.contains(&(175, 100)));
+            assert!(path.contains(&(200, 125)));
+            assert_eq!(path.first(), path.last());
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 0);
+        });
+    });
+
+    let mut rect = Rectangle::new([(100, 100), (200, 150)], BLUE.filled());
+    rect.set_corner_radius(10);
+    da.draw(&rect).expect("Drawing Failure");
+
+    let mut rect = Rectangle::new([(100, 100), (200, 150)], &BLUE);
+    rect.set_corner_radius(100);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
@@ -246,9 +628,9 @@ impl<Coord, Size: SizeDesc> Circle<Coord, Size> {
 
 impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Circle<Coord, Size> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
-    fn point_iter(self) -> std::iter::Once<&'a Coord> {
-        std::iter::once(&self.center)
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
     }
 }
 
@@ -261,12 +643,24 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             let size = self.size.in_pixels(&ps).max(0) as u32;
-            return backend.draw_circle((x, y), size, &self.style, self.style.filled);
+            if self.style.filled {
+                backend.draw_circle((x, y), size, &self.style, true)?;
+            }
+            if let Some(outline_style) = self.style.outline_style() {
+                backend.draw_circle((x, y), size, &outline_style, false)?;
+            }
         }
         Ok(())
     }
 }
 
+impl<Coord, Size: SizeDesc> WithOpacity for Circle<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_circle_element() {
@@ -287,10 +681,464 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_faded_stroke_color() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_circle(|c, _, _, _, _| {
+            assert_eq!(c, WHITE.mix(0.2));
+        });
+        m.check_draw_circle(|c, _, _, _, _| {
+            assert_eq!(c, RED.mix(0.2));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 2);
+        });
+    });
+    let circle = Circle::new((150, 150), 20, WHITE.filled().stroke_color(&RED));
+    da.draw(&circle.opacity(0.2)).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_stroke_color_of_shapes() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        for color in [RED, BLUE, GREEN, MAGENTA] {
+            m.check_draw_path(move |c, _, _| {
+                assert_eq!(c, color.to_rgba());
+            });
+        }
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 2);
+            assert_eq!(b.num_draw_path_call, 4);
+        });
+    });
+    let style = WHITE.filled();
+    da.draw(&Ellipse::new(
+        (150, 150),
+        (40, 20),
+        style.stroke_color(&RED),
+    ))
+    .expect("Drawing Failure");
+    da.draw(&PieSlice::new(
+        (150, 150),
+        50,
+        (0.0, 90.0),
+        style.stroke_color(&BLUE),
+    ))
+    .expect("Drawing Failure");
+    da.draw(&PathElement::new(
+        vec![(10, 10), (20, 20)],
+        ShapeStyle::from(&BLACK).stroke_color(&GREEN),
+    ))
+    .expect("Drawing Failure");
+    da.draw(&FilledPath::new(
+        vec![(10, 10), (50, 10), (50, 50)],
+        style.stroke_color(&MAGENTA),
+    ))
+    .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_and_stroke_colors() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_circle(|c, _, f, _, _| {
+            assert_eq!(c, WHITE.to_rgba());
+            assert!(f);
+        });
+        m.check_draw_circle(|c, w, f, _, _| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(w, 2);
+            assert!(!f);
+        });
+        m.check_draw_rect(|c, _, f, _, _| {
+            assert_eq!(c, WHITE.to_rgba());
+            assert!(f);
+        });
+        m.check_draw_rect(|c, _, f, _, _| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert!(!f);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 2);
+            assert_eq!(b.num_draw_rect_call, 2);
+        });
+    });
+    let style = WHITE.filled().stroke_width(2);
+    da.draw(&Circle::new((150, 151), 20, style.stroke_color(&RED)))
+        .expect("Drawing Failure");
+    da.draw(&Rectangle::new(
+        [(10, 10), (50, 50)],
+        WHITE.filled().stroke_color(&BLUE),
+    ))
+    .expect("Drawing Failure");
+}
+
+enum EllipseRadii<Size: SizeDesc> {
+    // The radii are given in pixels, only the center is in the guest coordinate
+    Pixel(Size, Size),
+    // The ends of the two semi-axes are given in the guest coordinate after the center
+    Guest,
+}
+
+/// An element of an ellipse, with independent radii along the x and y axes
+pub struct Ellipse<Coord, Size: SizeDesc = i32> {
+    points: Vec<Coord>,
+    radii: EllipseRadii<Size>,
+    rotation: f64,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Ellipse<Coord, Size> {
+    /// Create a new ellipse element whose radii are in pixels
+    /// - `center` The center of the ellipse
+    /// - `radii` The radii along the x and y axes
+    /// - `style` The style of the ellipse
+    /// - Return: The newly created ellipse element
+    pub fn new<S: Into<ShapeStyle>>(center: Coord, radii: (Size, Size), style: S) -> Self {
+        Self {
+            points: vec![center],
+            radii: EllipseRadii::Pixel(radii.0, radii.1),
+            rotation: 0.0,
+            style: style.into(),
+        }
+    }
+
+    /// Rotate the ellipse on the screen around its center
+    /// - `degrees` The rotation angle in counter-clockwise degrees
+    /// - Return: The rotated ellipse element
+    pub fn rotate(mut self, degrees: f64) -> Self {
+        self.rotation = degrees;
+        self
+    }
+}
+
+impl Ellipse<(f64, f64)> {
+    /// Create a new ellipse element whose radii are in the guest coordinate, which follows
+    /// the aspect ratio of the chart, e.g. a confidence ellipse of a covariance matrix.
+    /// - `center` The center of the ellipse
+    /// - `radii` The radii along the x and y axes, in the guest coordinate
+    /// - `angle` The counter-clockwise angle between the first semi-axis and the x axis in
+    ///   degrees, measured in the guest coordinate
+    /// - `style` The style of the ellipse
+    /// - Return: The newly created ellipse element
+    pub fn in_guest_units<S: Into<ShapeStyle>>(
+        center: (f64, f64),
+        radii: (f64, f64),
+        angle: f64,
+        style: S,
+    ) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (cx, cy) = center;
+        Self {
+            points: vec![
+                center,
+                (cx + radii.0 * cos, cy + radii.0 * sin),
+                (cx - radii.1 * sin, cy + radii.1 * cos),
+            ],
+            radii: EllipseRadii::Guest,
+            rotation: 0.0,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Ellipse<Coord, Size> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Ellipse<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        // The two conjugate semi-axes in the backend coordinate
+        let (u, v) = match &self.radii {
+            EllipseRadii::Pixel(rx, ry) => (
+                (f64::from(rx.in_pixels(&ps)), 0.0),
+                (0.0, -f64::from(ry.in_pixels(&ps))),
+            ),
+            EllipseRadii::Guest => match (points.next(), points.next()) {
+                (Some(a), Some(b)) => (
+                    (f64::from(a.0 - cx), f64::from(a.1 - cy)),
+                    (f64::from(b.0 - cx), f64::from(b.1 - cy)),
+                ),
+                _ => return Ok(()),
+            },
+        };
+
+        // The backend y axis points down, thus counter-clockwise is a negative angle
+        let (sin, cos) = (-self.rotation.to_radians()).sin_cos();
+        let rotate = |(x, y): (f64, f64)| (x * cos - y * sin, x * sin + y * cos);
+        let (u, v) = (rotate(u), rotate(v));
+
+        let perimeter = (u.0.hypot(u.1) + v.0.hypot(v.1)) * core::f64::consts::PI;
+        // A multiple of 4 steps, so the ends of both semi-axes are on the outline
+        let steps = (perimeter / 8.0).ceil().clamp(2.0, 180.0) as usize * 4;
+        let outline: Vec<BackendCoord> = (0..steps)
+            .map(|idx| {
+                let t = 2.0 * core::f64::consts::PI * idx as f64 / steps as f64;
+                let (sin, cos) = t.sin_cos();
+                (
+                    (f64::from(cx) + u.0 * cos + v.0 * sin).round() as i32,
+                    (f64::from(cy) + u.1 * cos + v.1 * sin).round() as i32,
+                )
+            })
+            .collect();
+
+        if self.style.filled {
+            backend.fill_polygon(outline.iter().copied(), &self.style.color)?;
+        }
+        if let Some(outline_style) = self.style.outline_style() {
+            let closing = outline[0];
+            backend.draw_path(
+                outline.into_iter().chain(core::iter::once(closing)),
+                &outline_style,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<Coord, Size: SizeDesc> WithOpacity for Ellipse<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path.first(), path.last());
+            assert!(path.contains(&(190, 150)));
+            assert!(path.contains(&(150, 130)));
+            assert!(path.iter().all(|&(x, y)| {
+                let (dx, dy) = (f64::from(x - 150) / 40.0, f64::from(y - 150) / 20.0);
+                (dx * dx + dy * dy - 1.0).abs() < 0.1
+            }));
+        });
+        m.check_fill_polygon(|_, path| {
+            // Rotated by 90 degrees, the long axis becomes vertical
+            assert!(path.contains(&(150, 110)));
+            assert!(path.iter().all(|&(x, _)| (x - 150).abs() <= 20));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&Ellipse::new((150, 150), (40, 20), &BLUE))
+        .expect("Drawing Failure");
+    da.draw(&Ellipse::new((150, 150), (40, 20), BLUE.filled()).rotate(90.0))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_in_guest_units() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(200, 100, |m| {
+        m.check_fill_polygon(|_, path| {
+            // The circle in the guest coordinate is stretched by the aspect ratio
+            let width =
+                path.iter().map(|p| p.0).max().unwrap() - path.iter().map(|p| p.0).min().unwrap();
+            let height =
+                path.iter().map(|p| p.1).max().unwrap() - path.iter().map(|p| p.1).min().unwrap();
+            // Both ends of each extent are rounded to the pixels, which is up to 2 pixels of
+            // error for the width and 4 pixels for the doubled height
+            assert!((width - 2 * height).abs() <= 4);
+        });
+    });
+    let chart = ChartBuilder::on(&da)
+        .build_ranged(-1.0..1.0, -1.0..1.0)
+        .expect("Build chart error");
+    chart
+        .plotting_area()
+        .draw(&Ellipse::in_guest_units(
+            (0.0, 0.0),
+            (0.5, 0.5),
+            30.0,
+            RED.filled(),
+        ))
+        .expect("Drawing Failure");
+}
+
+/// An element of a pie slice, a.k.a. the annular sector or arc wedge, which is the building
+/// block of pie charts, donut charts and gauges
+pub struct PieSlice<Coord, Size: SizeDesc = i32> {
+    center: Coord,
+    radius: Size,
+    inner_radius: Option<Size>,
+    angles: (f64, f64),
+    style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
+}
+
+impl<Coord, Size: SizeDesc> PieSlice<Coord, Size> {
+    /// Create a new pie slice element
+    /// - `center` The center of the pie
+    /// - `radius` The outer radius in pixels
+    /// - `angles` The start and end angles in degrees, counter-clockwise from the positive x axis
+    /// - `style` The fill style of the slice
+    /// - Return: The newly created pie slice element
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: Size,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: None,
+            angles,
+            style: style.into(),
+            border_style: None,
+        }
+    }
+
+    /// Set the inner radius in pixels, which turns the slice into an annular sector
+    pub fn inner_radius(mut self, radius: Size) -> Self {
+        self.inner_radius = Some(radius);
+        self
+    }
+
+    /// Set the style of the outline
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a PieSlice<Coord, Size> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for PieSlice<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let outer = f64::from(self.radius.in_pixels(&ps).max(0));
+        let inner = self
+            .inner_radius
+            .as_ref()
+            .map_or(0.0, |r| f64::from(r.in_pixels(&ps).max(0)));
+
+        let (start, end) = self.angles;
+        let sweep = (end - start).clamp(-360.0, 360.0);
+        let steps = (sweep.abs() / 2.0).ceil().max(1.0) as usize;
+        let arc = |r: f64, idx: usize| {
+            let angle = (start + sweep * idx as f64 / steps as f64).to_radians();
+            (
+                (f64::from(cx) + r * angle.cos()).round() as i32,
+                // The backend y axis points down
+                (f64::from(cy) - r * angle.sin()).round() as i32,
+            )
+        };
+
+        let mut outline: Vec<BackendCoord> = (0..=steps).map(|idx| arc(outer, idx)).collect();
+        if inner > 0.0 {
+            outline.extend((0..=steps).rev().map(|idx| arc(inner, idx)));
+        } else if sweep.abs() < 360.0 {
+            outline.push((cx, cy));
+        }
+
+        backend.fill_polygon(outline.iter().copied(), &self.style.color)?;
+        // The outline is drawn if either the border style or the stroke color is set
+        let border_style = self.border_style.clone().or_else(|| {
+            self.style
+                .stroke_color
+                .as_ref()
+                .and_then(|_| self.style.outline_style())
+        });
+        if let Some(border_style) = border_style {
+            let closing = outline[0];
+            backend.draw_path(
+                outline.into_iter().chain(core::iter::once(closing)),
+                &border_style,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<Coord, Size: SizeDesc> WithOpacity for PieSlice<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        if let Some(style) = self.border_style.as_mut() {
+            *style = style.mix(value);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_pie_slice_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path.first(), Some(&(200, 150)));
+            assert!(path.contains(&(150, 100)));
+            assert_eq!(path.last(), Some(&(150, 150)));
+        });
+        m.check_fill_polygon(|_, path| {
+            assert!(path.iter().all(|&(x, y)| {
+                let r = f64::from(x - 150).hypot(f64::from(y - 150));
+                (19.0..=51.0).contains(&r)
+            }));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 2);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    da.draw(&PieSlice::new((150, 150), 50, (0.0, 90.0), &RED))
+        .expect("Drawing Failure");
+    da.draw(
+        &PieSlice::new((150, 150), 50, (90.0, 300.0), &BLUE)
+            .inner_radius(20)
+            .border_style(&BLACK),
+    )
+    .expect("Drawing Failure");
+}
+
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
 }
 impl<Coord> Polygon<Coord> {
     /// Create a new polygon
@@ -301,8 +1149,17 @@ impl<Coord> Polygon<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            border_style: None,
         }
     }
+
+    /// Set the style of the outline, which is drawn as a closed path on top of the fill
+    /// - `style`: The outline style
+    /// - returns the modified element
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
@@ -320,7 +1177,30 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.fill_polygon(points, &self.style.color)
+        // The outline is drawn if either the border style or the stroke color is set
+        let border_style = self.border_style.clone().or_else(|| {
+            self.style
+                .stroke_color
+                .as_ref()
+                .and_then(|_| self.style.outline_style())
+        });
+        if let Some(border_style) = border_style {
+            let points: Vec<_> = points.collect();
+            backend.fill_polygon(points.iter().copied(), &self.style)?;
+            let closing = points.first().copied();
+            return backend.draw_path(points.into_iter().chain(closing), &border_style);
+        }
+        backend.fill_polygon(points, &self.style)
+    }
+}
+
+impl<Coord> WithOpacity for Polygon<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        if let Some(style) = self.border_style.as_mut() {
+            *style = style.mix(value);
+        }
+        self
     }
 }
 
@@ -346,3 +1226,28 @@ fn test_polygon_element() {
     da.draw(&Polygon::new(points.clone(), &BLUE))
         .expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_polygon_border() {
+    use crate::prelude::*;
+    let points = vec![(100, 100), (50, 500), (300, 400)];
+
+    let da = crate::create_mocked_drawing_area(800, 800, |m| {
+        m.check_fill_polygon(|c, _| {
+            assert_eq!(c, BLUE.to_rgba());
+        });
+        m.check_draw_path(|c, s, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(s, 2);
+            assert_eq!(path, vec![(100, 100), (50, 500), (300, 400), (100, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+
+    da.draw(&Polygon::new(points, &BLUE).border_style(RED.stroke_width(2)))
+        .expect("Drawing Failure");
+}
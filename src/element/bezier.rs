@@ -0,0 +1,170 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+
+/// A cubic Bézier curve element, defined by the start point, two control points and the end
+/// point in the guest coordinate.
+///
+/// The curve is passed to the backend as is, so the SVG backend emits a native `<path>`,
+/// while the bitmap backend flattens it adaptively into line segments.
+pub struct CubicBezier<Coord> {
+    points: [Coord; 4],
+    style: ShapeStyle,
+}
+
+impl<Coord> CubicBezier<Coord> {
+    /// Create a new cubic Bézier curve element
+    /// - `from`: The start point of the curve
+    /// - `ctrl1`: The control point next to the start point
+    /// - `ctrl2`: The control point next to the end point
+    /// - `to`: The end point of the curve
+    /// - `style`: The style of the curve
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(
+        from: Coord,
+        ctrl1: Coord,
+        ctrl2: Coord,
+        to: Coord,
+        style: S,
+    ) -> Self {
+        Self {
+            points: [from, ctrl1, ctrl2, to],
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a CubicBezier<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for CubicBezier<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match (points.next(), points.next(), points.next(), points.next()) {
+            (Some(p0), Some(p1), Some(p2), Some(p3)) => {
+                // A curve is drawn in the stroke color, if there's one
+                let style = self
+                    .style
+                    .outline_style()
+                    .unwrap_or_else(|| self.style.clone());
+                backend.draw_cubic_bezier([p0, p1, p2, p3], &style)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<Coord> WithOpacity for CubicBezier<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+/// A quadratic Bézier curve element, defined by the start point, one control point and the
+/// end point in the guest coordinate.
+pub struct QuadBezier<Coord> {
+    points: [Coord; 3],
+    style: ShapeStyle,
+}
+
+impl<Coord> QuadBezier<Coord> {
+    /// Create a new quadratic Bézier curve element
+    /// - `from`: The start point of the curve
+    /// - `ctrl`: The control point
+    /// - `to`: The end point of the curve
+    /// - `style`: The style of the curve
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, ctrl: Coord, to: Coord, style: S) -> Self {
+        Self {
+            points: [from, ctrl, to],
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a QuadBezier<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for QuadBezier<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(p0), Some(p1), Some(p2)) = (points.next(), points.next(), points.next()) {
+            // A quadratic curve is exactly a cubic one with the control points 2/3 of the way
+            // to the quadratic control point
+            let toward = |from: BackendCoord| {
+                (
+                    from.0 + ((p1.0 - from.0) as f64 * 2.0 / 3.0).round() as i32,
+                    from.1 + ((p1.1 - from.1) as f64 * 2.0 / 3.0).round() as i32,
+                )
+            };
+            let style = self
+                .style
+                .outline_style()
+                .unwrap_or_else(|| self.style.clone());
+            return backend.draw_cubic_bezier([p0, toward(p0), toward(p2), p2], &style);
+        }
+        Ok(())
+    }
+}
+
+impl<Coord> WithOpacity for QuadBezier<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bezier_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.first(), Some(&(0, 100)));
+                assert_eq!(path.last(), Some(&(100, 100)));
+                assert!(path.contains(&(50, 25)));
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.first(), Some(&(0, 100)));
+                assert_eq!(path.last(), Some(&(200, 100)));
+                assert!(path.contains(&(100, 50)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        da.draw(&CubicBezier::new(
+            (0, 100),
+            (0, 0),
+            (100, 0),
+            (100, 100),
+            &RED,
+        ))
+        .expect("Drawing Failure");
+        da.draw(&QuadBezier::new((0, 100), (100, 0), (200, 100), &RED))
+            .expect("Drawing Failure");
+    }
+}
@@ -1,8 +1,10 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crate::data::Quartiles;
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::element::{Drawable, PointCollection};
+use crate::element::{Drawable, PointCollection, WithOpacity};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::{ShapeStyle, BLACK};
 
 /// The boxplot orientation trait
@@ -248,6 +250,13 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
     }
 }
 
+impl<K, O: BoxplotOrient<K, f32>> WithOpacity for Boxplot<K, O> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
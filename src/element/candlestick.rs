@@ -2,10 +2,12 @@
   The candlestick element, which showing the high/low/open/close price
 */
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::element::{Drawable, PointCollection};
+use crate::element::{Drawable, PointCollection, WithOpacity};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::ShapeStyle;
 
 /// The candlestick data point element
@@ -98,3 +100,10 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         Ok(())
     }
 }
+
+impl<X, Y: PartialOrd> WithOpacity for CandleStick<X, Y> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
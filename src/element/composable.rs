@@ -1,9 +1,11 @@
 use super::*;
 use crate::drawing::backend::DrawingBackend;
-use std::borrow::Borrow;
-use std::iter::{once, Once};
-use std::marker::PhantomData;
-use std::ops::Add;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::borrow::Borrow;
+use core::iter::{once, Once};
+use core::marker::PhantomData;
+use core::ops::Add;
 
 /// An empty composable element, which is the start point of an ad-hoc composable element
 pub struct EmptyElement<Coord, DB: DrawingBackend> {
@@ -54,6 +56,12 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for EmptyElement<Coord, DB> {
     }
 }
 
+impl<Coord, DB: DrawingBackend> WithOpacity for EmptyElement<Coord, DB> {
+    fn opacity(self, _: f64) -> Self {
+        self
+    }
+}
+
 /// An composed element has only one component
 pub struct BoxedElement<Coord, DB: DrawingBackend, A: Drawable<DB>> {
     inner: A,
@@ -96,6 +104,15 @@ where
     }
 }
 
+impl<Coord, DB: DrawingBackend, A: Drawable<DB> + WithOpacity> WithOpacity
+    for BoxedElement<Coord, DB, A>
+{
+    fn opacity(mut self, value: f64) -> Self {
+        self.inner = self.inner.opacity(value);
+        self
+    }
+}
+
 impl<Coord, DB: DrawingBackend, My, Yours> Add<Yours> for BoxedElement<Coord, DB, My>
 where
     My: Drawable<DB>,
@@ -174,6 +191,18 @@ where
     }
 }
 
+impl<Coord, DB: DrawingBackend, A, B> WithOpacity for ComposedElement<Coord, DB, A, B>
+where
+    A: Drawable<DB> + WithOpacity,
+    B: Drawable<DB> + WithOpacity,
+{
+    fn opacity(mut self, value: f64) -> Self {
+        self.first = self.first.opacity(value);
+        self.second = self.second.opacity(value);
+        self
+    }
+}
+
 impl<Coord, DB: DrawingBackend, A, B, C> Add<C> for ComposedElement<Coord, DB, A, B>
 where
     A: Drawable<DB>,
@@ -198,3 +227,153 @@ where
         }
     }
 }
+
+/// A composed element with any number of components. Different from `EmptyElement + ...`, the
+/// number of the components doesn't need to be known at compile time, which is useful for
+/// the point decorations built on the fly.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut group = ElementGroup::<_, SVGBackend>::at((1.0, 2.0));
+/// for i in 0..5 {
+///     group.push(Circle::new((i * 5, 0), 2, &RED));
+/// }
+/// group.push(Text::new("label", (0, 5), ("sans-serif", 12).into_font()));
+/// assert_eq!(group.len(), 6);
+/// ```
+pub struct ElementGroup<'a, Coord, DB: DrawingBackend> {
+    offset: Coord,
+    children: Vec<DynElement<'a, DB, BackendCoord>>,
+}
+
+impl<'a, Coord, DB: DrawingBackend> ElementGroup<'a, Coord, DB> {
+    /// Create an empty group at the given position
+    pub fn at(coord: Coord) -> Self {
+        Self {
+            offset: coord,
+            children: vec![],
+        }
+    }
+
+    /// Create a group at the given position from the boxed components
+    /// - `coord`: The position of the group
+    /// - `children`: The components, whose coordinates are offsets in pixels
+    pub fn with_children<I: IntoIterator<Item = DynElement<'a, DB, BackendCoord>>>(
+        coord: Coord,
+        children: I,
+    ) -> Self {
+        Self {
+            offset: coord,
+            children: children.into_iter().collect(),
+        }
+    }
+
+    /// Append a component to the group, the coordinates of the component are offsets in
+    /// pixels from the position of the group
+    pub fn push<E: IntoDynElement<'a, DB, BackendCoord>>(&mut self, element: E) -> &mut Self {
+        self.children.push(element.into_dyn());
+        self
+    }
+
+    /// Get the number of the components
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Check if the group has no component
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend, E> Add<E> for ElementGroup<'a, Coord, DB>
+where
+    E: IntoDynElement<'a, DB, BackendCoord>,
+{
+    type Output = Self;
+    fn add(mut self, element: E) -> Self {
+        self.push(element);
+        self
+    }
+}
+
+impl<'b, 'a, Coord, DB: DrawingBackend> PointCollection<'b, Coord>
+    for &'b ElementGroup<'a, Coord, DB>
+{
+    type Borrow = &'b Coord;
+    type IntoIter = Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.offset)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for ElementGroup<'a, Coord, DB> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            for child in self.children.iter() {
+                child.draw(
+                    child.point_iter().iter().map(|p| (p.0 + x0, p.1 + y0)),
+                    backend,
+                    ps,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_composed_element_opacity() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(|c, _, _, _, _| {
+                assert_eq!(c, RED.mix(0.25));
+            });
+            m.check_draw_text(|c, _, _, _, _| {
+                assert_eq!(c, BLACK.mix(0.5));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let element = EmptyElement::at((100, 100))
+            + Circle::new((0, 0), 5, RED.mix(0.5).filled())
+            + Text::new("label", (5, 0), ("sans-serif", 12).into_font());
+        da.draw(&element.opacity(0.5)).expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_element_group() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (100, 100));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (110, 100));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center.1, 100);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 5);
+                assert_eq!(b.num_draw_rect_call, 1);
+            });
+        });
+        let group = (0..5).fold(ElementGroup::at((100, 100)), |group, i| {
+            group + Circle::new((i * 10, 0), 2, &RED)
+        });
+        da.draw(&(group + Rectangle::new([(0, 0), (40, 5)], &BLUE)))
+            .expect("Drawing Failure");
+    }
+}
@@ -1,7 +1,9 @@
 use super::{Drawable, PointCollection};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-use std::borrow::Borrow;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::borrow::Borrow;
 
 trait DynDrawable<DB: DrawingBackend> {
     fn draw_dyn(
@@ -23,7 +25,34 @@ impl<DB: DrawingBackend, T: Drawable<DB>> DynDrawable<DB> for T {
     }
 }
 
-/// The container for a dynamically dispatched element
+/// The container for a dynamically dispatched element.
+///
+/// Any element, including composed ones, can be turned into a `DynElement` with
+/// [`IntoDynElement::into_dyn`](trait.IntoDynElement.html#tymethod.into_dyn). This makes it
+/// possible to store elements of different types in the same collection, or to return them
+/// from a function without spelling out the concrete type:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// fn label<'a, DB: DrawingBackend + 'a>(pos: (i32, i32), text: &'a str) -> DynElement<'a, DB, (i32, i32)> {
+///     (EmptyElement::at(pos)
+///         + Circle::new((0, 0), 3, ShapeStyle::from(&BLACK).filled())
+///         + Text::new(text, (5, -5), ("sans-serif", 12).into_font()))
+///     .into_dyn()
+/// }
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (200, 100)).into_drawing_area();
+/// let elements = vec![
+///     Rectangle::new([(10, 10), (50, 50)], &RED).into_dyn(),
+///     Circle::new((100, 50), 20, &BLUE).into_dyn(),
+///     label((150, 50), "label"),
+/// ];
+/// for element in elements.iter() {
+///     root.draw(element).unwrap();
+/// }
+/// ```
 pub struct DynElement<'a, DB, Coord>
 where
     DB: DrawingBackend,
@@ -82,3 +111,33 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heterogeneous_elements() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        // Elements borrowing non-static data can be boxed as well
+        let text = String::from("label");
+        let elements: Vec<DynElement<_, (i32, i32)>> = vec![
+            Rectangle::new([(10, 10), (50, 50)], &RED).into_dyn(),
+            Circle::new((100, 100), 10, &BLUE).into_dyn(),
+            (EmptyElement::at((200, 200))
+                + Circle::new((0, 0), 5, &BLACK)
+                + Text::new(text.as_str(), (5, 5), ("sans-serif", 12).into_font()))
+            .into_dyn(),
+        ];
+        for element in elements.iter() {
+            da.draw(element).expect("Drawing Failure");
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::element::{Drawable, PointCollection};
+use crate::element::{Drawable, PointCollection, WithOpacity};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::ShapeStyle;
 
 pub trait ErrorBarOrient<K, V> {
@@ -48,14 +50,39 @@ impl<K, V> ErrorBarOrient<K, V> for ErrorBarOrientV<K, V> {
     }
 }
 
+/// The error bar element, which is a line from the minimum to the maximum value with caps at
+/// both ends and a circle at the center value.
+///
+/// Since the element is created from plain values, it can be built by the closure of
+/// `PointSeries::of_element` for any kind of data, for example `(x, y, error)` tuples:
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// let data = vec![(1.0, 2.0, 0.5), (2.0, 3.0, 0.2)];
+/// let make_bar = |(x, y, e): (f64, f64, f64), size: u32, style: ShapeStyle| {
+///     ErrorBar::new_vertical(x, y - e, y, y + e, style, size).center_size(0)
+/// };
+/// let series = PointSeries::of_element(data, 6, &RED, &make_bar);
+/// assert_eq!(series.count(), 2);
+/// ```
 pub struct ErrorBar<K, V, O: ErrorBarOrient<K, V>> {
     style: ShapeStyle,
     width: u32,
+    center_size: Option<u32>,
     key: K,
     values: [V; 3],
     _p: PhantomData<O>,
 }
 
+impl<K, V, O: ErrorBarOrient<K, V>> ErrorBar<K, V, O> {
+    /// Set the radius of the circle at the center value in pixels, 0 hides the circle.
+    /// By default, it's half of the cap width.
+    pub fn center_size(mut self, size: u32) -> Self {
+        self.center_size = Some(size);
+        self
+    }
+}
+
 impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
     pub fn new_vertical<S: Into<ShapeStyle>>(
         key: K,
@@ -68,6 +95,7 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
         Self {
             style: style.into(),
             width,
+            center_size: None,
             key,
             values: [min, avg, max],
             _p: PhantomData,
@@ -87,6 +115,7 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
         Self {
             style: style.into(),
             width,
+            center_size: None,
             key,
             values: [min, avg, max],
             _p: PhantomData,
@@ -124,12 +153,22 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
 
         backend.draw_line(points[0], points[2], &self.style)?;
 
-        backend.draw_circle(points[1], self.width / 2, &self.style, self.style.filled)?;
+        let center_size = self.center_size.unwrap_or(self.width / 2);
+        if center_size > 0 {
+            backend.draw_circle(points[1], center_size, &self.style, self.style.filled)?;
+        }
 
         Ok(())
     }
 }
 
+impl<K, V, O: ErrorBarOrient<K, V>> WithOpacity for ErrorBar<K, V, O> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_preserve_stroke_width() {
@@ -145,3 +184,23 @@ fn test_preserve_stroke_width() {
     da.draw(&h).expect("Drawing Failure");
     da.draw(&v).expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_error_bar_center_size() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_circle(|_, _, _, center, r| {
+            assert_eq!(center, (100, 50));
+            assert_eq!(r, 4);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 6);
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+    });
+    da.draw(&ErrorBar::new_vertical(100, 20, 50, 70, &RED, 6).center_size(4))
+        .expect("Drawing Failure");
+    da.draw(&ErrorBar::new_vertical(100, 20, 50, 70, &RED, 6).center_size(0))
+        .expect("Drawing Failure");
+}
@@ -0,0 +1,223 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::ShapeStyle;
+
+/// The rule that decides which part of a self-intersecting path is the interior
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the path an odd number of times
+    EvenOdd,
+    /// A point is inside if the path winds around it a non-zero number of times
+    NonZero,
+}
+
+/// An element of a closed path, whose interior is filled with the fill rule.
+///
+/// Unlike `Polygon`, whose result for a self-intersecting outline depends on the backend,
+/// the interior is computed by the element itself, so all the backends agree.
+pub struct FilledPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
+    rule: FillRule,
+}
+
+impl<Coord> FilledPath<Coord> {
+    /// Create a new filled path, the path is closed automatically
+    /// - `points`: The vertices of the path
+    /// - `style`: The fill style
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            style: style.into(),
+            border_style: None,
+            rule: FillRule::NonZero,
+        }
+    }
+
+    /// Set the fill rule, the default is `FillRule::NonZero`
+    pub fn fill_rule(mut self, rule: FillRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the style of the outline
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a FilledPath<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+// Compute the filled spans `[begin, end)` of the scan line at `y + 0.5`
+fn scan_line(vertices: &[BackendCoord], y: i32, rule: FillRule) -> Vec<(i32, i32)> {
+    let y = f64::from(y) + 0.5;
+    let mut crossings = vec![];
+    for (idx, &(x0, y0)) in vertices.iter().enumerate() {
+        let (x1, y1) = vertices[(idx + 1) % vertices.len()];
+        let (fy0, fy1) = (f64::from(y0), f64::from(y1));
+        if (fy0 <= y) == (fy1 <= y) {
+            continue;
+        }
+        let x = f64::from(x0) + (y - fy0) * f64::from(x1 - x0) / (fy1 - fy0);
+        crossings.push((x, if y1 > y0 { 1 } else { -1 }));
+    }
+    crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut spans: Vec<(i32, i32)> = vec![];
+    let mut winding = 0;
+    let mut begin = 0.0;
+    for (x, dir) in crossings {
+        let was_inside = winding != 0;
+        winding = match rule {
+            FillRule::EvenOdd => (winding + 1) % 2,
+            FillRule::NonZero => winding + dir,
+        };
+        let inside = winding != 0;
+        if !was_inside && inside {
+            begin = x;
+        } else if was_inside && !inside {
+            let span = (begin.round() as i32, x.round() as i32);
+            if span.1 <= span.0 {
+                continue;
+            }
+            match spans.last_mut() {
+                Some(last) if last.1 >= span.0 => last.1 = last.1.max(span.1),
+                _ => spans.push(span),
+            }
+        }
+    }
+    spans
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for FilledPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 3 {
+            return Ok(());
+        }
+
+        // Only the rows inside of the backend are scanned, the rest is invisible anyway
+        let (_, h) = backend.get_size();
+        let min_y = points.iter().map(|p| p.1).min().unwrap().max(0);
+        let max_y = points.iter().map(|p| p.1).max().unwrap().min(h as i32);
+
+        // The rows with the same spans are merged into a single rectangle
+        let mut pending: Option<(i32, Vec<(i32, i32)>)> = None;
+        for y in min_y..=max_y {
+            let spans = if y < max_y {
+                scan_line(&points, y, self.rule)
+            } else {
+                vec![]
+            };
+            if let Some((start, prev)) = pending.take() {
+                if prev == spans {
+                    pending = Some((start, prev));
+                    continue;
+                }
+                for (x0, x1) in prev {
+                    backend.draw_rect((x0, start), (x1 - 1, y - 1), &self.style.color, true)?;
+                }
+            }
+            if !spans.is_empty() {
+                pending = Some((y, spans));
+            }
+        }
+
+        // The outline is drawn if either the border style or the stroke color is set
+        let border_style = self.border_style.clone().or_else(|| {
+            self.style
+                .stroke_color
+                .as_ref()
+                .and_then(|_| self.style.outline_style())
+        });
+        if let Some(border_style) = border_style {
+            let closing = points.first().copied();
+            backend.draw_path(points.into_iter().chain(closing), &border_style)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Coord> WithOpacity for FilledPath<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        if let Some(style) = self.border_style.as_mut() {
+            *style = style.mix(value);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_rules() {
+        // A five-pointed star, whose center is covered twice
+        let star = [(50, 0), (80, 100), (0, 40), (100, 40), (20, 100)];
+        let even_odd = scan_line(&star, 50, FillRule::EvenOdd);
+        let non_zero = scan_line(&star, 50, FillRule::NonZero);
+        assert_eq!(even_odd.len(), 2);
+        assert_eq!(non_zero.len(), 1);
+        assert_eq!(non_zero[0].0, even_odd[0].0);
+        assert_eq!(non_zero[0].1, even_odd[1].1);
+    }
+
+    #[test]
+    fn test_filled_path_element() {
+        use crate::prelude::*;
+        let da = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(filled);
+                assert_eq!(ul, (10, 10));
+                assert_eq!(br, (49, 49));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        da.draw(
+            &FilledPath::new(vec![(10, 10), (50, 10), (50, 50), (10, 50)], &BLUE)
+                .fill_rule(FillRule::EvenOdd)
+                .border_style(&BLACK),
+        )
+        .expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_filled_path_far_off_vertex() {
+        use crate::prelude::*;
+        let da = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert!(ul.1 >= 0 && br.1 < 200);
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_rect_call <= 200);
+            });
+        });
+        da.draw(&FilledPath::new(
+            vec![(10, 10), (190, 10), (100, 1 << 24)],
+            &BLUE,
+        ))
+        .expect("Drawing Failure");
+    }
+}
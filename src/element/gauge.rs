@@ -0,0 +1,252 @@
+use super::{Drawable, PieSlice, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, RGBAColor, RGBColor, ShapeStyle, TextStyle, BLACK};
+
+/// A gauge, a.k.a. dial, element for dashboard-style single value displays. It draws an arc
+/// scale with colored zones and ticks, a needle pointing to the value and the value label.
+///
+/// The gauge sweeps clockwise from the lower left to the lower right, 270 degrees in total,
+/// and all the sizes are in pixels:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (200, 200)).into_drawing_area();
+/// root.draw(
+///     &Gauge::new((100, 100), 80, (0.0, 100.0), 72.5)
+///         .zone(0.0, 60.0, &GREEN)
+///         .zone(60.0, 85.0, &YELLOW)
+///         .zone(85.0, 100.0, &RED)
+///         .label("72.5 %"),
+/// )
+/// .unwrap();
+/// ```
+pub struct Gauge<Coord> {
+    center: Coord,
+    radius: u32,
+    range: (f64, f64),
+    value: f64,
+    angles: (f64, f64),
+    thickness: u32,
+    zones: Vec<(f64, f64, RGBAColor)>,
+    scale_color: RGBAColor,
+    ticks: usize,
+    needle_style: ShapeStyle,
+    label: String,
+    font: FontDesc<'static>,
+}
+
+impl<Coord> Gauge<Coord> {
+    /// Create a new gauge element
+    /// - `center`: The center of the dial
+    /// - `radius`: The outer radius of the arc scale in pixels
+    /// - `range`: The values at the start and the end of the scale
+    /// - `value`: The value the needle points to, clamped to the range
+    /// - returns the created element
+    pub fn new(center: Coord, radius: u32, range: (f64, f64), value: f64) -> Self {
+        Self {
+            center,
+            radius,
+            range,
+            value,
+            angles: (225.0, -45.0),
+            thickness: (radius / 5).max(1),
+            zones: vec![],
+            scale_color: RGBColor(200, 200, 200).to_rgba(),
+            ticks: 5,
+            needle_style: BLACK.filled(),
+            label: format!("{}", value),
+            font: ("sans-serif", (radius / 5).max(8)).into(),
+        }
+    }
+
+    /// Set the start and end angles of the scale in degrees, counter-clockwise from the
+    /// positive x axis. By default, the scale goes from 225 to -45 degrees.
+    pub fn angles(mut self, start: f64, end: f64) -> Self {
+        self.angles = (start, end);
+        self
+    }
+
+    /// Set the thickness of the arc scale in pixels
+    pub fn thickness(mut self, thickness: u32) -> Self {
+        self.thickness = thickness.min(self.radius);
+        self
+    }
+
+    /// Add a colored zone to the scale, the zones are drawn on top of the plain scale
+    /// - `from`: The value at which the zone starts
+    /// - `to`: The value at which the zone ends
+    /// - `color`: The color of the zone
+    pub fn zone<C: Color>(mut self, from: f64, to: f64, color: &C) -> Self {
+        self.zones.push((from, to, color.to_rgba()));
+        self
+    }
+
+    /// Set the number of the divisions between the major ticks, 0 means no ticks
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Set the style of the needle
+    pub fn needle_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.needle_style = style.into();
+        self
+    }
+
+    /// Set the value label, which is the value itself by default
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the font of the value label and the tick labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    // Map a value to the angle of the scale, values out of the range are clamped
+    fn angle_of(&self, value: f64) -> f64 {
+        let (lo, hi) = self.range;
+        let ratio = if hi == lo {
+            0.0
+        } else {
+            ((value - lo) / (hi - lo)).clamp(0.0, 1.0)
+        };
+        self.angles.0 + (self.angles.1 - self.angles.0) * ratio
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Gauge<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Gauge<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let radius = self.radius as i32;
+        let inner = (self.radius - self.thickness.min(self.radius)) as i32;
+        let at = |r: f64, angle: f64| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            (
+                (f64::from(cx) + r * cos).round() as i32,
+                // The backend y axis points down
+                (f64::from(cy) - r * sin).round() as i32,
+            )
+        };
+
+        PieSlice::new((cx, cy), radius, self.angles, self.scale_color.filled())
+            .inner_radius(inner)
+            .draw(core::iter::once((cx, cy)), backend, ps)?;
+        for &(from, to, ref color) in self.zones.iter() {
+            let angles = (self.angle_of(from), self.angle_of(to));
+            PieSlice::new((cx, cy), radius, angles, color.filled())
+                .inner_radius(inner)
+                .draw(core::iter::once((cx, cy)), backend, ps)?;
+        }
+
+        let tick_style = TextStyle::from(self.font.clone().resize(self.font.get_size() * 0.7))
+            .color(&BLACK)
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        if self.ticks > 0 {
+            let (lo, hi) = self.range;
+            for idx in 0..=self.ticks {
+                let value = lo + (hi - lo) * idx as f64 / self.ticks as f64;
+                let angle = self.angle_of(value);
+                backend.draw_line(
+                    at(f64::from(inner), angle),
+                    at(f64::from(inner) - 5.0, angle),
+                    &BLACK,
+                )?;
+                let label_pos = at(f64::from(inner) - 5.0 - tick_style.font.get_size(), angle);
+                backend.draw_text(&format!("{}", value), &tick_style, label_pos)?;
+            }
+        }
+
+        // The needle is a thin triangle from the hub to the scale
+        let angle = self.angle_of(self.value);
+        let hub = (self.radius / 20).max(2);
+        backend.fill_polygon(
+            vec![
+                at(f64::from(hub), angle + 90.0),
+                at(f64::from(radius), angle),
+                at(f64::from(hub), angle - 90.0),
+            ],
+            &self.needle_style.color,
+        )?;
+        backend.draw_circle((cx, cy), hub, &self.needle_style.color, true)?;
+
+        let label_style = TextStyle::from(self.font.clone())
+            .color(&BLACK)
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        backend.draw_text(&self.label, &label_style, (cx, cy + radius / 2))
+    }
+}
+
+impl<Coord> WithOpacity for Gauge<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.scale_color = self.scale_color.mix(value);
+        for zone in self.zones.iter_mut() {
+            zone.2 = zone.2.mix(value);
+        }
+        self.needle_style = self.needle_style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gauge_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, RGBColor(200, 200, 200).to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, GREEN.to_rgba());
+            });
+            m.check_fill_polygon(|c, path| {
+                // The needle points straight up at the middle of the range
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(path[1], (150, 50));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "0");
+                assert!(pos.0 < 150 && pos.1 > 150);
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+                assert_eq!(b.num_draw_line_call, 3);
+                // Three tick labels and the value label
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+        da.draw(
+            &Gauge::new((150, 150), 100, (0.0, 10.0), 5.0)
+                .zone(0.0, 5.0, &GREEN)
+                .ticks(2),
+        )
+        .expect("Drawing Failure");
+    }
+}
@@ -1,13 +1,16 @@
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 use image::{DynamicImage, GenericImageView};
 
-use super::{Drawable, PointCollection};
+use super::{Drawable, PointCollection, WithOpacity};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::drawing::bitmap_pixel::{PixelFormat, RGBPixel};
 
 use crate::drawing::BitMapBackend;
-use std::borrow::Borrow;
-use std::marker::PhantomData;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{Color, RGBColor};
+use alloc::borrow::Borrow;
+use core::marker::PhantomData;
 
 enum Buffer<'a> {
     Owned(Vec<u8>),
@@ -51,6 +54,8 @@ impl<'a> Buffer<'a> {
 /// The element that contains a bitmap on it
 pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
+    // The optional alpha channel, one byte for each pixel
+    alpha: Option<Vec<u8>>,
     size: (u32, u32),
     pos: Coord,
     phantom: PhantomData<P>,
@@ -65,6 +70,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
     pub fn new(pos: Coord, size: (u32, u32)) -> Self {
         Self {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
+            alpha: None,
             size,
             pos,
             phantom: PhantomData,
@@ -86,6 +92,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 
         Some(Self {
             image: Buffer::Owned(buf),
+            alpha: None,
             size,
             pos,
             phantom: PhantomData,
@@ -106,6 +113,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 
         Some(Self {
             image: Buffer::BorrowedMut(buf),
+            alpha: None,
             size,
             pos,
             phantom: PhantomData,
@@ -127,24 +135,117 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 
         Some(Self {
             image: Buffer::Borrowed(buf),
+            alpha: None,
             size,
             pos,
             phantom: PhantomData,
         })
     }
 
+    /// Scale the bitmap to the given size with the nearest neighbor sampling, the scaled
+    /// bitmap owns its buffer
+    ///
+    /// - `size`: The size of the scaled bitmap
+    /// - **returns**: The scaled bitmap element
+    pub fn scale_to(self, size: (u32, u32)) -> Self {
+        let (w, h) = self.size;
+        if size == self.size || w == 0 || h == 0 {
+            return self;
+        }
+        let src = self.image.as_ref();
+        let mut image = Vec::with_capacity((size.0 * size.1) as usize * P::PIXEL_SIZE);
+        let mut alpha = self
+            .alpha
+            .as_ref()
+            .map(|_| Vec::with_capacity((size.0 * size.1) as usize));
+        for y in 0..size.1 {
+            let sy = (u64::from(y) * u64::from(h) / u64::from(size.1)) as usize;
+            for x in 0..size.0 {
+                let sx = (u64::from(x) * u64::from(w) / u64::from(size.0)) as usize;
+                let idx = sy * w as usize + sx;
+                image.extend_from_slice(&src[idx * P::PIXEL_SIZE..(idx + 1) * P::PIXEL_SIZE]);
+                if let (Some(alpha), Some(src_alpha)) = (alpha.as_mut(), self.alpha.as_ref()) {
+                    alpha.push(src_alpha[idx]);
+                }
+            }
+        }
+        Self {
+            image: Buffer::Owned(image),
+            alpha,
+            size,
+            pos: self.pos,
+            phantom: PhantomData,
+        }
+    }
+
     /// Copy the existing bitmap element to another location
     ///
     /// - `pos`: The new location to copy
-    pub fn copy_to<Coord2>(&self, pos: Coord2) -> BitMapElement<Coord2, P> {
+    pub fn copy_to<Coord2>(&self, pos: Coord2) -> BitMapElement<'_, Coord2, P> {
         BitMapElement {
             image: Buffer::Borrowed(self.image.borrow()),
+            alpha: self.alpha.clone(),
             size: self.size,
             pos,
             phantom: PhantomData,
         }
     }
 
+    /// Make a copy of the bitmap resized to the given size, for example a thumbnail of a
+    /// cached chart. Each pixel of the copy is the average of the pixels it covers.
+    ///
+    /// - `size`: The size of the copy
+    /// - **returns**: The resized copy at the same position
+    pub fn resized(&self, size: (u32, u32)) -> BitMapElement<'static, Coord, P>
+    where
+        Coord: Clone,
+    {
+        let ((sw, sh), (dw, dh)) = (self.size, size);
+        let src: &[u8] = self.image.borrow();
+        let pixel_size = P::PIXEL_SIZE;
+        let mut image = vec![0; (dw * dh) as usize * pixel_size];
+        let mut alpha = self.alpha.as_ref().map(|_| vec![0; (dw * dh) as usize]);
+        // The range of the source pixels covered by a pixel of the copy
+        let span = |i: u32, s: u32, d: u32| {
+            let begin = (u64::from(i) * u64::from(s) / u64::from(d)) as u32;
+            let end = (u64::from(i + 1) * u64::from(s) / u64::from(d)) as u32;
+            begin..end.max(begin + 1).min(s)
+        };
+        for y in 0..dh {
+            let ys = span(y, sh, dh);
+            for x in 0..dw {
+                let xs = span(x, sw, dw);
+                let count = (xs.len() * ys.len()).max(1) as u32;
+                let mut sum = vec![0u32; pixel_size + 1];
+                for sy in ys.clone() {
+                    for sx in xs.clone() {
+                        let idx = (sy * sw + sx) as usize;
+                        for (c, v) in sum[..pixel_size].iter_mut().zip(&src[idx * pixel_size..]) {
+                            *c += u32::from(*v);
+                        }
+                        if let Some(a) = self.alpha.as_ref() {
+                            sum[pixel_size] += u32::from(a[idx]);
+                        }
+                    }
+                }
+                let idx = (y * dw + x) as usize;
+                for (c, v) in sum[..pixel_size].iter().enumerate() {
+                    image[idx * pixel_size + c] = ((v + count / 2) / count) as u8;
+                }
+                if let Some(a) = alpha.as_mut() {
+                    a[idx] = ((sum[pixel_size] + count / 2) / count) as u8;
+                }
+            }
+        }
+        BitMapElement {
+            image: Buffer::Owned(image),
+            alpha,
+            size,
+            pos: self.pos.clone(),
+            phantom: PhantomData,
+        }
+    }
+
     /// Move the existing bitmap element to a new position
     ///
     /// - `pos`: The new position
@@ -154,19 +255,54 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 
     /// Make the bitmap element as a bitmap backend, so that we can use
     /// plotters drawing functionality on the bitmap element
-    pub fn as_bitmap_backend(&mut self) -> BitMapBackend<P> {
+    pub fn as_bitmap_backend(&mut self) -> BitMapBackend<'_, P> {
         BitMapBackend::with_buffer_and_format(self.image.to_mut(), self.size).unwrap()
     }
 }
 
+impl<'a, Coord> BitMapElement<'a, Coord, RGBPixel> {
+    /// Create a new bitmap element from the RGBA pixels, the pixels are blended with the
+    /// existing content of the drawing area according to their alpha value
+    ///
+    /// - `pos`: The left upper coordinate of the element
+    /// - `size`: The size of the bitmap
+    /// - `buf`: The RGBA pixels, four bytes for each pixel
+    /// - **returns**: The newly created image element, or `None` if the buffer is smaller
+    ///   than the image dimension
+    pub fn with_rgba(pos: Coord, size: (u32, u32), buf: &[u8]) -> Option<Self> {
+        let count = (size.0 * size.1) as usize;
+        if buf.len() < count * 4 {
+            return None;
+        }
+        let mut image = Vec::with_capacity(count * 3);
+        let mut alpha = Vec::with_capacity(count);
+        for pixel in buf[..count * 4].chunks(4) {
+            image.extend_from_slice(&pixel[..3]);
+            alpha.push(pixel[3]);
+        }
+        Some(Self {
+            image: Buffer::Owned(image),
+            alpha: Some(alpha),
+            size,
+            pos,
+            phantom: PhantomData,
+        })
+    }
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixel> {
     fn from((pos, image): (Coord, DynamicImage)) -> Self {
         let (w, h) = image.dimensions();
-        let rgb_image = image.to_rgb().into_raw();
+        if image.color().has_alpha() {
+            let rgba_image = image.into_rgba8().into_raw();
+            return Self::with_rgba(pos, (w, h), &rgba_image).unwrap();
+        }
+        let rgb_image = image.into_rgb8().into_raw();
         Self {
             pos,
             image: Buffer::Owned(rgb_image),
+            alpha: None,
             size: (w, h),
             phantom: PhantomData,
         }
@@ -179,10 +315,14 @@ impl<'a, Coord> From<(Coord, DynamicImage)>
 {
     fn from((pos, image): (Coord, DynamicImage)) -> Self {
         let (w, h) = image.dimensions();
-        let rgb_image = image.to_bgra().into_raw();
+        let mut rgb_image = image.into_rgba8().into_raw();
+        for pixel in rgb_image.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
         Self {
             pos,
             image: Buffer::Owned(rgb_image),
+            alpha: None,
             size: (w, h),
             phantom: PhantomData,
         }
@@ -191,9 +331,9 @@ impl<'a, Coord> From<(Coord, DynamicImage)>
 
 impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = core::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        core::iter::once(&self.pos)
     }
 }
 
@@ -205,9 +345,110 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
+            if let Some(alpha) = self.alpha.as_ref() {
+                let (w, h) = self.size;
+                let image: &[u8] = self.image.as_ref();
+                for (dy, row_alpha) in alpha.chunks(w as usize).take(h as usize).enumerate() {
+                    let row = &image[dy * w as usize * 3..];
+                    let y = y + dy as i32;
+                    // The runs of the opaque pixels are blitted, only the translucent pixels
+                    // are blended one by one
+                    let mut run_start = None;
+                    for (dx, &a) in row_alpha.iter().chain(core::iter::once(&0)).enumerate() {
+                        if a == 255 {
+                            run_start.get_or_insert(dx);
+                            continue;
+                        }
+                        if let Some(start) = run_start.take() {
+                            backend.blit_bitmap(
+                                (x + start as i32, y),
+                                ((dx - start) as u32, 1),
+                                &row[start * 3..dx * 3],
+                            )?;
+                        }
+                        if a == 0 || dx == row_alpha.len() {
+                            continue;
+                        }
+                        let rgb = &row[dx * 3..dx * 3 + 3];
+                        let color = RGBColor(rgb[0], rgb[1], rgb[2]).mix(f64::from(a) / 255.0);
+                        backend.draw_pixel((x + dx as i32, y), &color)?;
+                    }
+                }
+                return Ok(());
+            }
             // TODO: convert the pixel format when needed
             return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
         }
         Ok(())
     }
 }
+
+impl<'a, Coord> WithOpacity for BitMapElement<'a, Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        let (w, h) = self.size;
+        let alpha = self
+            .alpha
+            .get_or_insert_with(|| vec![255; (w * h) as usize]);
+        for a in alpha.iter_mut() {
+            *a = (f64::from(*a) * value.clamp(0.0, 1.0)).round() as u8;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rgba_bitmap_element() {
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        // The pixel of the bitmap, then the 2x2 pixels of its scaled copy row by row
+        for &expected in &[(10, 20), (10, 20), (11, 20), (10, 21), (11, 21)] {
+            m.check_draw_pixel(move |c, pos| {
+                assert_eq!(c, RGBColor(255, 0, 0).mix(0.2));
+                assert_eq!(pos, expected);
+            });
+        }
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_pixel_call, 5);
+        });
+    });
+
+    let pixels = [255, 0, 0, 51, 0, 0, 255, 0];
+    let image = BitMapElement::with_rgba((10, 20), (2, 1), &pixels).unwrap();
+    da.draw(&image).expect("Drawing Failure");
+
+    // Only the left half of the scaled bitmap is visible
+    let scaled = image.scale_to((4, 2));
+    da.draw(&scaled).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_rgba_bitmap_opaque_runs() {
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        // The runs of opaque pixels are blitted, and the mocked backend draws them with the
+        // default implementation of `blit_bitmap`
+        let expected = [
+            (RGBColor(255, 0, 0).to_rgba(), (10, 20)),
+            (RGBColor(0, 255, 0).to_rgba(), (11, 20)),
+            (RGBColor(0, 0, 255).mix(0.2), (12, 20)),
+            (RGBColor(255, 0, 0).to_rgba(), (10, 21)),
+            (RGBColor(0, 0, 255).to_rgba(), (12, 21)),
+        ];
+        for (color, expected) in expected {
+            m.check_draw_pixel(move |c, pos| {
+                assert_eq!(c, color);
+                assert_eq!(pos, expected);
+            });
+        }
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_pixel_call, 5);
+        });
+    });
+
+    let pixels = [
+        255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 51, // The first row
+        255, 0, 0, 255, 0, 255, 0, 0, 0, 0, 255, 255, // The second row
+    ];
+    let image = BitMapElement::with_rgba((10, 20), (3, 2), &pixels).unwrap();
+    da.draw(&image).expect("Drawing Failure");
+}
@@ -0,0 +1,243 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, ShapeStyle, TextStyle, BLACK, TRANSPARENT};
+
+/// The glyph drawn in front of a legend entry
+#[derive(Clone)]
+pub enum LegendGlyph {
+    /// A horizontal line segment, for line series
+    Line(ShapeStyle),
+    /// A small rectangle, for bars and areas
+    Rect(ShapeStyle),
+    /// A small circle, for point series
+    Circle(ShapeStyle),
+}
+
+impl LegendGlyph {
+    fn style_mut(&mut self) -> &mut ShapeStyle {
+        match self {
+            LegendGlyph::Line(style) | LegendGlyph::Rect(style) | LegendGlyph::Circle(style) => {
+                style
+            }
+        }
+    }
+
+    fn draw<DB: DrawingBackend>(
+        &self,
+        (x, y): BackendCoord,
+        width: i32,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self {
+            LegendGlyph::Line(style) => backend.draw_line((x, y), (x + width, y), style),
+            LegendGlyph::Rect(style) => {
+                backend.draw_rect((x, y - 5), (x + width, y + 5), style, style.filled)
+            }
+            LegendGlyph::Circle(style) => {
+                backend.draw_circle((x + width / 2, y), 4, style, style.filled)
+            }
+        }
+    }
+}
+
+/// A legend element, which is a list of glyphs with labels in a box. Unlike the series labels
+/// of a chart context, it can be drawn at any position of any drawing area, for example
+/// a legend shared by the panels of a multi-panel figure. The position is the upper left
+/// corner of the box and the sizes are in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// root.draw(
+///     &Legend::new((10, 10))
+///         .entry("measured", LegendGlyph::Line(RED.stroke_width(2)))
+///         .entry("predicted", LegendGlyph::Circle(BLUE.filled()))
+///         .border_style(&BLACK),
+/// )
+/// .unwrap();
+/// ```
+pub struct Legend<Coord> {
+    pos: Coord,
+    entries: Vec<(String, LegendGlyph)>,
+    font: FontDesc<'static>,
+    glyph_width: u32,
+    margin: u32,
+    border_style: ShapeStyle,
+    background: ShapeStyle,
+}
+
+impl<Coord> Legend<Coord> {
+    /// Create a new legend element without entries
+    /// - `pos`: The upper left corner of the legend box
+    /// - returns the created element
+    pub fn new(pos: Coord) -> Self {
+        Self {
+            pos,
+            entries: vec![],
+            font: ("sans-serif", 12).into(),
+            glyph_width: 30,
+            margin: 10,
+            border_style: (&TRANSPARENT).into(),
+            background: (&TRANSPARENT).into(),
+        }
+    }
+
+    /// Append an entry to the legend
+    /// - `label`: The label of the entry
+    /// - `glyph`: The glyph drawn in front of the label
+    pub fn entry<S: Into<String>>(mut self, label: S, glyph: LegendGlyph) -> Self {
+        self.entries.push((label.into(), glyph));
+        self
+    }
+
+    /// Set the font of the labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Set the width of the area reserved for the glyphs, in pixels
+    pub fn legend_area_size(mut self, size: u32) -> Self {
+        self.glyph_width = size;
+        self
+    }
+
+    /// Set the margin between the border and the entries, in pixels
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the style of the border, the border is transparent by default
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the style of the background, the background is transparent by default
+    pub fn background_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.background = style.into();
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Legend<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Legend<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut text_width = 0;
+        let mut row_height = self.font.get_size() as u32;
+        for (label, _) in self.entries.iter() {
+            let (w, h) = backend.estimate_text_size(label, &self.font)?;
+            text_width = text_width.max(w);
+            row_height = row_height.max(h);
+        }
+        let margin = self.margin as i32;
+        let row_height = row_height as i32 + 4;
+        let w = text_width as i32 + self.glyph_width as i32 + margin * 2;
+        let h = row_height * self.entries.len() as i32 + margin * 2;
+
+        backend.draw_rect((x0, y0), (x0 + w, y0 + h), &self.background, true)?;
+        backend.draw_rect((x0, y0), (x0 + w, y0 + h), &self.border_style, false)?;
+
+        let style = TextStyle::from(self.font.clone())
+            .color(&BLACK)
+            .pos(Pos::new(HPos::Left, VPos::Center));
+        let glyph_width = (self.glyph_width as i32 - 10).max(0);
+        for (idx, (label, glyph)) in self.entries.iter().enumerate() {
+            let y = y0 + margin + row_height * idx as i32 + row_height / 2;
+            glyph.draw((x0 + margin, y), glyph_width, backend)?;
+            backend.draw_text(label, &style, (x0 + margin + self.glyph_width as i32, y))?;
+        }
+        Ok(())
+    }
+}
+
+impl<Coord> WithOpacity for Legend<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        for (_, glyph) in self.entries.iter_mut() {
+            let style = glyph.style_mut();
+            *style = style.mix(value);
+        }
+        self.border_style = self.border_style.mix(value);
+        self.background = self.background.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{Color, BLUE, RED};
+
+    #[test]
+    fn test_legend_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|_, _, filled, ul, _| {
+                // The background
+                assert!(filled);
+                assert_eq!(ul, (20, 20));
+            });
+            m.check_draw_rect(|c, _, filled, ul, _| {
+                assert!(!filled);
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(ul, (20, 20));
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(from.0, 30);
+                assert_eq!(to.0, 50);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos.0, 60);
+            });
+            m.check_draw_circle(|c, _, filled, center, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(filled);
+                assert_eq!(center.0, 40);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "b");
+                assert_eq!(pos.0, 60);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_line_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        da.draw(
+            &Legend::new((20, 20))
+                .entry("a", LegendGlyph::Line((&RED).into()))
+                .entry("b", LegendGlyph::Circle(BLUE.filled()))
+                .border_style(&BLACK),
+        )
+        .expect("Drawing Failure");
+    }
+}
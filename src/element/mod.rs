@@ -18,7 +18,7 @@
 
     An example of element that draws a red "X" in a red rectangle onto the backend:
 
-    ```rust
+    ```rust,no_run
     use std::iter::{Once, once};
     use plotters::element::{PointCollection, Drawable};
     use plotters::drawing::backend::{BackendCoord, DrawingErrorKind};
@@ -64,6 +64,60 @@
     ```
       ![](https://plotters-rs.github.io/plotters-doc-data/element-0.png)
 
+      ## Lazily Computed Points
+      The point iterator doesn't need to borrow from the element, the `Borrow` type can be the
+      coordinate itself and `IntoIter` can be any `IntoIterator`, including a lazy generator. In this
+      way, an element with thousands of points can compute them on the fly, rather than collecting them
+      into a `Vec` before drawing. The points are mapped to the backend coordinate one by one as the
+      `Drawable::draw` method consumes the iterator.
+
+      For example, a sine wave element which computes its points from the period:
+    ```rust
+    use std::iter::Map;
+    use std::ops::Range;
+    use plotters::element::{PointCollection, Drawable};
+    use plotters::drawing::backend::{BackendCoord, DrawingErrorKind};
+    use plotters::prelude::*;
+
+    struct SineWave {
+        period: f64,
+        samples: u32,
+    }
+
+    impl<'a> PointCollection<'a, (f64, f64)> for &'a SineWave {
+        // The points are owned values, nothing is borrowed from the element
+        type Borrow = (f64, f64);
+        type IntoIter = Map<Range<u32>, Box<dyn Fn(u32) -> (f64, f64) + 'a>>;
+        fn point_iter(self) -> Self::IntoIter {
+            let step = self.period / f64::from(self.samples);
+            let point: Box<dyn Fn(u32) -> (f64, f64) + 'a> = Box::new(move |i| {
+                let x = f64::from(i) * step;
+                (x, (x / self.period * 2.0 * std::f64::consts::PI).sin())
+            });
+            (0..self.samples + 1).map(point)
+        }
+    }
+
+    impl<DB: DrawingBackend> Drawable<DB> for SineWave {
+        fn draw<I: Iterator<Item = BackendCoord>>(
+            &self,
+            pos: I,
+            backend: &mut DB,
+            _: (u32, u32),
+        ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+            backend.draw_path(pos, &RED)
+        }
+    }
+
+    fn main() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = String::new();
+        let root = SVGBackend::with_string(&mut buf, (640, 480)).into_drawing_area();
+        let chart = ChartBuilder::on(&root).build_ranged(0.0..1.0, -1.0..1.0)?;
+        chart.plotting_area().draw(&SineWave { period: 1.0, samples: 1000 })?;
+        Ok(())
+    }
+    ```
+
       ## Composable Elements
       You also have an convenient way to build an element that isn't built into the Plotters library by
       combining existing elements into a logic group. To build an composable element, you need to use an
@@ -72,7 +126,7 @@
       the `+` operator.
 
       For example, the red boxed X element can be implemented with Composable element in the following way:
-    ```rust
+    ```rust,no_run
     use plotters::prelude::*;
     fn main() -> Result<(), Box<dyn std::error::Error>> {
         let root = BitMapBackend::new(
@@ -102,7 +156,7 @@
     `into_dyn` function which would wrap the element into a dynamic element wrapper.
 
     For example, the following code counts the number of factors of integer and mark all prime numbers in cross.
-    ```rust
+    ```rust,no_run
     use plotters::prelude::*;
     fn num_of_factor(n: i32) -> i32 {
         let mut ret = 2;
@@ -156,19 +210,49 @@
     ![](https://plotters-rs.github.io/plotters-doc-data/element-3.png)
 */
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use std::borrow::Borrow;
+use alloc::borrow::Borrow;
+
+mod arrow;
+pub use arrow::{Arrow, ArrowHead};
 
 mod basic_shapes;
 pub use basic_shapes::*;
 
+mod bezier;
+pub use bezier::{CubicBezier, QuadBezier};
+
+mod filled_path;
+pub use filled_path::{FillRule, FilledPath};
+
+mod gauge;
+pub use gauge::Gauge;
+
+mod legend;
+pub use legend::{Legend, LegendGlyph};
+
+mod pie;
+pub use pie::{Pie, PieLabelPosition, PieValueFormat};
+
+mod scale_bar;
+pub use scale_bar::{NorthArrow, ScaleBar};
+
+mod span;
+pub use span::{HSpan, VSpan};
+
+mod table;
+pub use table::Table;
+
 mod text;
 pub use text::*;
 
+mod tooltip;
+pub use tooltip::Tooltip;
+
 mod points;
 pub use points::*;
 
 mod composable;
-pub use composable::{ComposedElement, EmptyElement};
+pub use composable::{ComposedElement, ElementGroup, EmptyElement};
 
 #[cfg(feature = "candlestick")]
 mod candlestick;
@@ -198,13 +282,32 @@ pub trait PointCollection<'a, Coord> {
     /// The item in point iterator
     type Borrow: Borrow<Coord>;
 
-    /// The point iterator
+    /// The point iterator, which can be any iterator, including a lazily computed one
     type IntoIter: IntoIterator<Item = Self::Borrow>;
 
     /// framework to do the coordinate mapping
     fn point_iter(self) -> Self::IntoIter;
 }
 
+/// The trait for the elements which can be faded as a whole. The opacity is multiplied into
+/// the colors of all the styles of the element, including all the children of a composed
+/// element, so a series or an annotation group can be faded without editing every color.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let faded = (EmptyElement::<_, SVGBackend>::at((1.0, 2.0))
+///     + Circle::new((0, 0), 3, RED.filled())
+///     + Text::new("label", (5, 0), ("sans-serif", 12).into_font()))
+/// .opacity(0.3);
+/// # let _ = faded;
+/// ```
+pub trait WithOpacity: Sized {
+    /// Multiply the opacity into the styles of the element
+    /// - `value`: The opacity, from 0 (transparent) to 1 (unchanged)
+    fn opacity(self, value: f64) -> Self;
+}
+
 /// The trait indicates we are able to draw it on a drawing area
 pub trait Drawable<DB: DrawingBackend> {
     /// Actually draws the element. The key points is already translated into the
@@ -0,0 +1,286 @@
+use super::{Drawable, PieSlice, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, RGBAColor, TextStyle, BLACK, WHITE};
+
+/// Where the labels of a pie are placed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PieLabelPosition {
+    /// In the middle of the slices
+    Inside,
+    /// Outside of the pie, connected to the slices by leader lines
+    Outside,
+}
+
+/// What is appended to the label of a slice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PieValueFormat {
+    /// The percentage of the slice, e.g. `apple: 25.0%`
+    Percentage,
+    /// The size of the slice, e.g. `apple: 3`
+    Value,
+    /// Only the label
+    None,
+}
+
+/// A self-contained pie chart element, which draws the slices and their labels without a
+/// chart context. The slices go clockwise from the top and all the sizes are in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 300)).into_drawing_area();
+/// root.draw(&Pie::new(
+///     (150, 150),
+///     100,
+///     &[3.0, 2.0, 1.0],
+///     &[RED, GREEN, BLUE],
+///     &["apple", "banana", "cherry"],
+/// ))
+/// .unwrap();
+/// ```
+pub struct Pie<Coord> {
+    center: Coord,
+    radius: u32,
+    sizes: Vec<f64>,
+    colors: Vec<RGBAColor>,
+    stroke_color: RGBAColor,
+    labels: Vec<String>,
+    start_angle: f64,
+    hole: u32,
+    label_position: PieLabelPosition,
+    value_format: PieValueFormat,
+    font: FontDesc<'static>,
+}
+
+impl<Coord> Pie<Coord> {
+    /// Create a new pie element
+    /// - `center`: The center of the pie
+    /// - `radius`: The radius in pixels
+    /// - `sizes`: The sizes of the slices, negative sizes are treated as 0
+    /// - `colors`: The colors of the slices, which are reused if there are more slices than
+    ///   colors
+    /// - `labels`: The labels of the slices, a slice without a label is not labeled
+    /// - returns the created element
+    pub fn new<C: Color, S: ToString>(
+        center: Coord,
+        radius: u32,
+        sizes: &[f64],
+        colors: &[C],
+        labels: &[S],
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            sizes: sizes.iter().map(|s| s.max(0.0)).collect(),
+            colors: colors.iter().map(Color::to_rgba).collect(),
+            stroke_color: WHITE.to_rgba(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            start_angle: 90.0,
+            hole: 0,
+            label_position: PieLabelPosition::Outside,
+            value_format: PieValueFormat::Percentage,
+            font: ("sans-serif", 12).into(),
+        }
+    }
+
+    /// Set the angle where the first slice starts, in degrees counter-clockwise from the
+    /// positive x axis. The default is 90, which is the top of the pie.
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Set the radius of the hole in the middle in pixels, which makes a donut chart
+    pub fn donut_hole(mut self, radius: u32) -> Self {
+        self.hole = radius.min(self.radius);
+        self
+    }
+
+    /// Set the color of the borders between the slices, the default is white
+    pub fn stroke_color<C: Color>(mut self, color: &C) -> Self {
+        self.stroke_color = color.to_rgba();
+        self
+    }
+
+    /// Set where the labels are placed
+    pub fn label_position(mut self, position: PieLabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Set what is appended to the labels
+    pub fn value_format(mut self, format: PieValueFormat) -> Self {
+        self.value_format = format;
+        self
+    }
+
+    /// Set the font of the labels
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    fn label_text(&self, idx: usize, total: f64) -> Option<String> {
+        let label = self.labels.get(idx)?;
+        let size = self.sizes[idx];
+        Some(match self.value_format {
+            PieValueFormat::Percentage => format!("{}: {:.1}%", label, size / total * 100.0),
+            PieValueFormat::Value => format!("{}: {}", label, size),
+            PieValueFormat::None => label.clone(),
+        })
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Pie<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Pie<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let total: f64 = self.sizes.iter().sum();
+        if total <= 0.0 || self.colors.is_empty() {
+            return Ok(());
+        }
+        let at = |r: f64, angle: f64| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            (
+                (f64::from(cx) + r * cos).round() as i32,
+                // The backend y axis points down
+                (f64::from(cy) - r * sin).round() as i32,
+            )
+        };
+        let radius = f64::from(self.radius);
+
+        let mut start = self.start_angle;
+        for (idx, size) in self.sizes.iter().enumerate() {
+            let sweep = size / total * 360.0;
+            if sweep == 0.0 {
+                continue;
+            }
+            let end = start - sweep;
+            let color = &self.colors[idx % self.colors.len()];
+            let mut slice = PieSlice::new((cx, cy), self.radius as i32, (start, end), color)
+                .border_style(&self.stroke_color);
+            if self.hole > 0 {
+                slice = slice.inner_radius(self.hole as i32);
+            }
+            slice.draw(core::iter::once((cx, cy)), backend, ps)?;
+
+            if let Some(text) = self.label_text(idx, total) {
+                let mid = (start + end) / 2.0;
+                match self.label_position {
+                    PieLabelPosition::Inside => {
+                        let style = TextStyle::from(self.font.clone())
+                            .color(&BLACK)
+                            .pos(Pos::new(HPos::Center, VPos::Center));
+                        let r = (radius + f64::from(self.hole)) / 2.0;
+                        backend.draw_text(&text, &style, at(r.max(radius * 0.6), mid))?;
+                    }
+                    PieLabelPosition::Outside => {
+                        let elbow = at(radius + 10.0, mid);
+                        let right = mid.to_radians().cos() >= 0.0;
+                        let end = (elbow.0 + if right { 10 } else { -10 }, elbow.1);
+                        backend.draw_path(vec![at(radius, mid), elbow, end], &BLACK)?;
+                        let h_pos = if right { HPos::Left } else { HPos::Right };
+                        let style = TextStyle::from(self.font.clone())
+                            .color(&BLACK)
+                            .pos(Pos::new(h_pos, VPos::Center));
+                        let offset = if right { 3 } else { -3 };
+                        backend.draw_text(&text, &style, (end.0 + offset, end.1))?;
+                    }
+                }
+            }
+            start = end;
+        }
+        Ok(())
+    }
+}
+
+impl<Coord> WithOpacity for Pie<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        for color in self.colors.iter_mut() {
+            *color = color.mix(value);
+        }
+        self.stroke_color = self.stroke_color.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pie_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a: 75.0%");
+                // The first slice is on the right of the pie
+                assert!(pos.0 > 230);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "b: 25.0%");
+                assert!(pos.0 < 70);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert!(pos.0 > 150);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "b");
+                assert!(pos.0 < 150);
+            });
+            m.drop_check(|b| {
+                // Two slices with borders, two of them with leader lines
+                assert_eq!(b.num_fill_polygon_call, 4);
+                assert_eq!(b.num_draw_path_call, 6);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+        da.draw(&Pie::new(
+            (150, 150),
+            100,
+            &[3.0, 1.0, 0.0],
+            &[RED, BLUE],
+            &["a", "b", "c"],
+        ))
+        .expect("Drawing Failure");
+        da.draw(
+            &Pie::new((150, 150), 100, &[3.0, 1.0], &[RED, BLUE], &["a", "b"])
+                .label_position(PieLabelPosition::Inside)
+                .value_format(PieValueFormat::None)
+                .donut_hole(50),
+        )
+        .expect("Drawing Failure");
+    }
+}
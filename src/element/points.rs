@@ -1,6 +1,8 @@
 use super::*;
 use super::{Drawable, PointCollection};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::{ShapeStyle, SizeDesc};
 
 /// The element that used to describe a point
@@ -25,11 +27,11 @@ impl<Coord, Size: SizeDesc> Cross<Coord, Size> {
     }
 }
 
-impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Cross<Coord, Size> {
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Cross<Coord, Size> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
-    fn point_iter(self) -> std::iter::Once<&'a Coord> {
-        std::iter::once(&self.center)
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
     }
 }
 
@@ -51,6 +53,13 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Cross<Coord, Si
     }
 }
 
+impl<Coord, Size: SizeDesc> WithOpacity for Cross<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
 /// Describe a triangle marker
 pub struct TriangleMarker<Coord, Size: SizeDesc> {
     center: Coord,
@@ -68,11 +77,11 @@ impl<Coord, Size: SizeDesc> TriangleMarker<Coord, Size> {
     }
 }
 
-impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a TriangleMarker<Coord, Size> {
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a TriangleMarker<Coord, Size> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
-    fn point_iter(self) -> std::iter::Once<&'a Coord> {
-        std::iter::once(&self.center)
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
     }
 }
 
@@ -87,19 +96,270 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
             let size = self.size.in_pixels(&ps);
             let points = [-90, -210, -330]
                 .iter()
-                .map(|deg| f64::from(*deg) * std::f64::consts::PI / 180.0)
+                .map(|deg| f64::from(*deg) * core::f64::consts::PI / 180.0)
                 .map(|rad| {
                     (
                         (rad.cos() * f64::from(size) + f64::from(x)).ceil() as i32,
                         (rad.sin() * f64::from(size) + f64::from(y)).ceil() as i32,
                     )
                 });
-            backend.fill_polygon(points, &self.style.color)?;
+            if self.style.stroke_color.is_none() {
+                return backend.fill_polygon(points, &self.style.color);
+            }
+            let points: Vec<_> = points.collect();
+            backend.fill_polygon(points.iter().copied(), &self.style.color)?;
+            if let Some(outline_style) = self.style.outline_style() {
+                let closing = points.first().copied();
+                backend.draw_path(points.into_iter().chain(closing), &outline_style)?;
+            }
         }
         Ok(())
     }
 }
 
+impl<Coord, Size: SizeDesc> WithOpacity for TriangleMarker<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+/// The shape of a `Marker`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkerShape {
+    /// A circle
+    Circle,
+    /// A square
+    Square,
+    /// A square rotated by 45 degrees
+    Diamond,
+    /// A triangle pointing up
+    TriangleUp,
+    /// A triangle pointing down
+    TriangleDown,
+    /// A five-pointed star
+    Star,
+    /// A "+" sign
+    Plus,
+    /// A "x" sign
+    Cross,
+}
+
+impl MarkerShape {
+    /// All the marker shapes, which is useful to pick a different shape for each series
+    pub const ALL: [MarkerShape; 8] = [
+        MarkerShape::Circle,
+        MarkerShape::Square,
+        MarkerShape::TriangleUp,
+        MarkerShape::Diamond,
+        MarkerShape::TriangleDown,
+        MarkerShape::Star,
+        MarkerShape::Plus,
+        MarkerShape::Cross,
+    ];
+
+    // The vertices of the polygon shapes, in the angles and the distances to the center
+    fn polygon(self) -> Option<Vec<(f64, f64)>> {
+        let vertices = match self {
+            MarkerShape::Diamond => vec![(-90.0, 1.0), (0.0, 1.0), (90.0, 1.0), (180.0, 1.0)],
+            MarkerShape::TriangleUp => vec![(-90.0, 1.0), (30.0, 1.0), (150.0, 1.0)],
+            MarkerShape::TriangleDown => vec![(90.0, 1.0), (210.0, 1.0), (330.0, 1.0)],
+            MarkerShape::Star => (0..10)
+                .map(|idx| {
+                    let r = if idx % 2 == 0 { 1.0 } else { 0.4 };
+                    (-90.0 + 36.0 * f64::from(idx), r)
+                })
+                .collect(),
+            _ => return None,
+        };
+        Some(vertices)
+    }
+}
+
+/// A marker of the given shape, which shares the same size and style API for all the shapes.
+///
+/// The size is the radius of the circumscribed circle in pixels. Outlined shapes are drawn
+/// unless the style is filled, except the plus and cross signs which are always strokes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let markers: Vec<_> = MarkerShape::ALL
+///     .iter()
+///     .enumerate()
+///     .map(|(idx, shape)| Marker::new((idx as f64, 0.0), 5, *shape, RED.filled()))
+///     .collect();
+/// # let _ = markers;
+/// ```
+pub struct Marker<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    shape: MarkerShape,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Marker<Coord, Size> {
+    /// Create a new marker
+    /// - `coord` The center of the marker
+    /// - `size` The radius of the marker
+    /// - `shape` The shape of the marker
+    /// - `style` The style of the marker
+    pub fn new<T: Into<ShapeStyle>>(
+        coord: Coord,
+        size: Size,
+        shape: MarkerShape,
+        style: T,
+    ) -> Self {
+        Self {
+            center: coord,
+            size,
+            shape,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Marker<Coord, Size> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Marker<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        let size = self.size.in_pixels(&ps).max(0);
+        let style = &self.style;
+        match self.shape {
+            MarkerShape::Circle => {
+                if style.filled {
+                    backend.draw_circle((x, y), size as u32, style, true)?;
+                }
+                match style.outline_style() {
+                    Some(outline) => backend.draw_circle((x, y), size as u32, &outline, false),
+                    None => Ok(()),
+                }
+            }
+            MarkerShape::Square => {
+                // Keep the area close to the circle of the same size
+                let half = (f64::from(size) * 0.85).round() as i32;
+                let (ul, br) = ((x - half, y - half), (x + half, y + half));
+                if style.filled {
+                    backend.draw_rect(ul, br, style, true)?;
+                }
+                match style.outline_style() {
+                    Some(outline) => backend.draw_rect(ul, br, &outline, false),
+                    None => Ok(()),
+                }
+            }
+            MarkerShape::Plus => {
+                backend.draw_line((x - size, y), (x + size, y), style)?;
+                backend.draw_line((x, y - size), (x, y + size), style)
+            }
+            MarkerShape::Cross => {
+                let half = (f64::from(size) * core::f64::consts::FRAC_1_SQRT_2).round() as i32;
+                backend.draw_line((x - half, y - half), (x + half, y + half), style)?;
+                backend.draw_line((x - half, y + half), (x + half, y - half), style)
+            }
+            shape => {
+                let vertices: Vec<BackendCoord> = shape
+                    .polygon()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(deg, r): (f64, f64)| {
+                        let (sin, cos) = deg.to_radians().sin_cos();
+                        (
+                            (f64::from(x) + cos * r * f64::from(size)).round() as i32,
+                            (f64::from(y) + sin * r * f64::from(size)).round() as i32,
+                        )
+                    })
+                    .collect();
+                if style.filled {
+                    backend.fill_polygon(vertices.iter().copied(), &style.color)?;
+                }
+                match style.outline_style() {
+                    Some(outline) => {
+                        let closing = vertices.first().copied();
+                        backend.draw_path(vertices.into_iter().chain(closing), &outline)
+                    }
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl<Coord, Size: SizeDesc> WithOpacity for Marker<Coord, Size> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style = self.style.mix(value);
+        self
+    }
+}
+
+macro_rules! def_marker {
+    ($name:ident, $shape:ident, $doc:literal) => {
+        #[doc = $doc]
+        pub struct $name<Coord, Size: SizeDesc>(Marker<Coord, Size>);
+
+        impl<Coord, Size: SizeDesc> $name<Coord, Size> {
+            pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+                $name(Marker::new(coord, size, MarkerShape::$shape, style))
+            }
+        }
+
+        impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a $name<Coord, Size> {
+            type Borrow = &'a Coord;
+            type IntoIter = core::iter::Once<&'a Coord>;
+            fn point_iter(self) -> core::iter::Once<&'a Coord> {
+                core::iter::once(&self.0.center)
+            }
+        }
+
+        impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for $name<Coord, Size> {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                points: I,
+                backend: &mut DB,
+                ps: (u32, u32),
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                self.0.draw(points, backend, ps)
+            }
+        }
+
+        impl<Coord, Size: SizeDesc> WithOpacity for $name<Coord, Size> {
+            fn opacity(self, value: f64) -> Self {
+                $name(self.0.opacity(value))
+            }
+        }
+
+        impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for $name<Coord, Size> {
+            fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+                Self::new(pos, size, style)
+            }
+        }
+    };
+}
+
+def_marker!(SquareMarker, Square, "Describe a square marker");
+def_marker!(DiamondMarker, Diamond, "Describe a diamond marker");
+def_marker!(
+    TriangleDownMarker,
+    TriangleDown,
+    "Describe a triangle marker pointing down"
+);
+def_marker!(StarMarker, Star, "Describe a five-pointed star marker");
+def_marker!(PlusMarker, Plus, "Describe a plus sign marker");
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Cross<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -123,3 +383,49 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_marker_shapes() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|_, _, filled, ul, br| {
+                assert!(filled);
+                assert_eq!((ul, br), ((91, 91), (109, 109)));
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(100, 90), (110, 100), (100, 110), (90, 100)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(100, 110), (91, 95), (109, 95)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 11);
+                assert_eq!(path.first(), path.last());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_line_call, 2);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        da.draw(&SquareMarker::new((100, 100), 10, RED.filled()))
+            .expect("Drawing Failure");
+        da.draw(&DiamondMarker::new((100, 100), 10, RED.filled()))
+            .expect("Drawing Failure");
+        da.draw(&StarMarker::new((100, 100), 10, &RED))
+            .expect("Drawing Failure");
+        da.draw(&PlusMarker::new((100, 100), 10, &RED))
+            .expect("Drawing Failure");
+        da.draw(&TriangleDownMarker::new((100, 100), 10, RED.filled()))
+            .expect("Drawing Failure");
+        da.draw(&Marker::new((100, 100), 10, MarkerShape::Circle, &RED))
+            .expect("Drawing Failure");
+    }
+}
@@ -0,0 +1,294 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, RGBAColor, TextStyle, BLACK, WHITE};
+
+/// A scale bar element for map-like plots, which shows how long a round distance is in the
+/// plot. The distance is the largest 1, 2 or 5 times a power of ten that fits in the maximum
+/// width. The position is the left end of the bar, in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (400, 300)).into_drawing_area();
+/// // The x axis spans 0 to 35 km over 400 pixels
+/// let bar = ScaleBar::from_range((20, 280), (0.0, 35.0), 400, "km");
+/// assert_eq!(bar.length(), (5.0, 57));
+/// root.draw(&bar).unwrap();
+/// ```
+pub struct ScaleBar<Coord> {
+    pos: Coord,
+    units_per_pixel: f64,
+    unit: String,
+    max_width: u32,
+    color: RGBAColor,
+    font: FontDesc<'static>,
+}
+
+impl<Coord> ScaleBar<Coord> {
+    /// Create a new scale bar element
+    /// - `pos`: The left end of the bar
+    /// - `units_per_pixel`: How much distance a pixel of the plot covers
+    /// - `unit`: The name of the distance unit, which is appended to the label
+    /// - returns the created element
+    pub fn new<S: Into<String>>(pos: Coord, units_per_pixel: f64, unit: S) -> Self {
+        Self {
+            pos,
+            units_per_pixel: units_per_pixel.abs(),
+            unit: unit.into(),
+            max_width: 100,
+            color: BLACK.to_rgba(),
+            font: ("sans-serif", 12).into(),
+        }
+    }
+
+    /// Create a new scale bar element for an axis
+    /// - `pos`: The left end of the bar
+    /// - `range`: The range of the axis, in the distance unit
+    /// - `pixels`: The length of the axis in pixels
+    /// - `unit`: The name of the distance unit
+    /// - returns the created element
+    pub fn from_range<S: Into<String>>(
+        pos: Coord,
+        range: (f64, f64),
+        pixels: u32,
+        unit: S,
+    ) -> Self {
+        Self::new(pos, (range.1 - range.0) / f64::from(pixels.max(1)), unit)
+    }
+
+    /// Set the maximum width of the bar in pixels, 100 by default
+    pub fn max_width(mut self, width: u32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Set the color of the bar and the label
+    pub fn color<C: Color>(mut self, color: &C) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /// Set the font of the label
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Compute the distance the bar represents and its length in pixels
+    pub fn length(&self) -> (f64, u32) {
+        let max = f64::from(self.max_width) * self.units_per_pixel;
+        if !max.is_normal() {
+            return (0.0, 0);
+        }
+        let base = 10f64.powf(max.log10().floor());
+        let distance = [5.0, 2.0, 1.0]
+            .iter()
+            .map(|m| m * base)
+            .find(|d| *d <= max)
+            .unwrap_or(base);
+        (distance, (distance / self.units_per_pixel).round() as u32)
+    }
+
+    fn label(&self, distance: f64) -> String {
+        let decimals = (-distance.log10().floor()).max(0.0) as usize;
+        format!("{:.*} {}", decimals, distance, self.unit)
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a ScaleBar<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for ScaleBar<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        let (distance, width) = self.length();
+        if width == 0 {
+            return Ok(());
+        }
+        let (width, half) = (width as i32, width as i32 / 2);
+
+        // The bar is split in a filled half and a hollow half
+        backend.draw_rect((x, y - 3), (x + half, y + 3), &self.color, true)?;
+        backend.draw_rect((x + half, y - 3), (x + width, y + 3), &WHITE, true)?;
+        backend.draw_rect((x, y - 3), (x + width, y + 3), &self.color, false)?;
+
+        let style = TextStyle::from(self.font.clone())
+            .color(&self.color)
+            .pos(Pos::new(HPos::Center, VPos::Bottom));
+        backend.draw_text(&self.label(distance), &style, (x + half, y - 5))
+    }
+}
+
+impl<Coord> WithOpacity for ScaleBar<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.color = self.color.mix(value);
+        self
+    }
+}
+
+/// A north arrow element for map-like plots. The position is the center of the arrow and
+/// the size is its height in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (400, 300)).into_drawing_area();
+/// root.draw(&NorthArrow::new((370, 40), 30)).unwrap();
+/// ```
+pub struct NorthArrow<Coord> {
+    pos: Coord,
+    size: u32,
+    rotation: f64,
+    color: RGBAColor,
+    font: FontDesc<'static>,
+}
+
+impl<Coord> NorthArrow<Coord> {
+    /// Create a new north arrow element
+    /// - `pos`: The center of the arrow
+    /// - `size`: The height of the arrow in pixels
+    /// - returns the created element
+    pub fn new(pos: Coord, size: u32) -> Self {
+        Self {
+            pos,
+            size,
+            rotation: 0.0,
+            color: BLACK.to_rgba(),
+            font: ("sans-serif", (size / 2).max(8)).into(),
+        }
+    }
+
+    /// Set the angle between the north and the top of the plot in degrees, clockwise
+    pub fn rotation(mut self, angle: f64) -> Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// Set the color of the arrow and the label
+    pub fn color<C: Color>(mut self, color: &C) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /// Set the font of the label
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a NorthArrow<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for NorthArrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        // Rotate the offset from the center clockwise, the backend y axis points down
+        let at = |dx: f64, dy: f64| {
+            (
+                (f64::from(cx) + dx * cos - dy * sin).round() as i32,
+                (f64::from(cy) + dx * sin + dy * cos).round() as i32,
+            )
+        };
+        let half = f64::from(self.size) / 2.0;
+        let tip = at(0.0, -half);
+        let left = at(-half * 0.6, half);
+        let notch = at(0.0, half * 0.5);
+        let right = at(half * 0.6, half);
+
+        backend.fill_polygon(vec![tip, left, notch], &self.color)?;
+        backend.draw_path(vec![tip, left, notch, right, tip], &self.color)?;
+
+        let style = TextStyle::from(self.font.clone())
+            .color(&self.color)
+            .pos(Pos::new(HPos::Center, VPos::Bottom));
+        backend.draw_text("N", &style, at(0.0, -half - 2.0))
+    }
+}
+
+impl<Coord> WithOpacity for NorthArrow<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.color = self.color.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale_bar_length() {
+        assert_eq!(ScaleBar::new((0, 0), 0.5, "m").length(), (50.0, 100));
+        assert_eq!(ScaleBar::new((0, 0), 0.35, "m").length(), (20.0, 57));
+        let bar = ScaleBar::new((0, 0), 0.0003, "km");
+        assert_eq!(bar.length(), (0.02, 67));
+        assert_eq!(bar.label(0.02), "0.02 km");
+        assert_eq!(ScaleBar::new((0, 0), 0.0, "m").length(), (0.0, 0));
+    }
+
+    #[test]
+    fn test_scale_bar_and_north_arrow() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert!(filled);
+                assert_eq!((ul, br), ((10, 97), (60, 103)));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "50 m");
+                assert_eq!(pos, (60, 95));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "N");
+                assert_eq!(pos, (200, 183));
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path[0], (200, 185));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        da.draw(&ScaleBar::new((10, 100), 0.5, "m"))
+            .expect("Drawing Failure");
+        da.draw(&NorthArrow::new((200, 200), 30))
+            .expect("Drawing Failure");
+    }
+}
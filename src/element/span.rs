@@ -0,0 +1,98 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+use core::ops::Range;
+
+/// A vertical band which highlights a range of the x axis across the full height of the
+/// plotting area, for example to mark an event or a recession. It can be created with
+/// `ChartContext::vspan`, which fills in the range of the y axis:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..10.0, -1.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(chart.vspan(2.0..4.0, RED.mix(0.2).filled())))
+///     .unwrap();
+/// ```
+pub struct VSpan<X, Y> {
+    points: [(X, Y); 2],
+    style: ShapeStyle,
+}
+
+impl<X: Clone, Y: Clone> VSpan<X, Y> {
+    /// Create a new vertical band
+    /// - `x`: The range of the x axis to highlight
+    /// - `y`: The full range of the y axis, the band covers all of it
+    /// - `style`: The style of the band
+    pub fn new<S: Into<ShapeStyle>>(x: Range<X>, y: Range<Y>, style: S) -> Self {
+        Self {
+            points: [(x.start, y.start), (x.end, y.end)],
+            style: style.into(),
+        }
+    }
+}
+
+/// A horizontal band which highlights a range of the y axis across the full width of the
+/// plotting area, for example to mark a threshold. It can be created with
+/// `ChartContext::hspan`, which fills in the range of the x axis.
+pub struct HSpan<X, Y> {
+    points: [(X, Y); 2],
+    style: ShapeStyle,
+}
+
+impl<X: Clone, Y: Clone> HSpan<X, Y> {
+    /// Create a new horizontal band
+    /// - `x`: The full range of the x axis, the band covers all of it
+    /// - `y`: The range of the y axis to highlight
+    /// - `style`: The style of the band
+    pub fn new<S: Into<ShapeStyle>>(x: Range<X>, y: Range<Y>, style: S) -> Self {
+        Self {
+            points: [(x.start, y.start), (x.end, y.end)],
+            style: style.into(),
+        }
+    }
+}
+
+macro_rules! impl_span {
+    ($name:ident) => {
+        impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a $name<X, Y> {
+            type Borrow = &'a (X, Y);
+            type IntoIter = &'a [(X, Y)];
+            fn point_iter(self) -> &'a [(X, Y)] {
+                &self.points
+            }
+        }
+
+        impl<X, Y, DB: DrawingBackend> Drawable<DB> for $name<X, Y> {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                mut points: I,
+                backend: &mut DB,
+                _: (u32, u32),
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                // The points are already clamped to the plotting area
+                if let (Some(a), Some(b)) = (points.next(), points.next()) {
+                    let ul = (a.0.min(b.0), a.1.min(b.1));
+                    let br = (a.0.max(b.0), a.1.max(b.1));
+                    return backend.draw_rect(ul, br, &self.style, self.style.filled);
+                }
+                Ok(())
+            }
+        }
+
+        impl<X, Y> WithOpacity for $name<X, Y> {
+            fn opacity(mut self, value: f64) -> Self {
+                self.style = self.style.mix(value);
+                self
+            }
+        }
+    };
+}
+
+impl_span!(VSpan);
+impl_span!(HSpan);
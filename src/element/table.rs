@@ -0,0 +1,254 @@
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, FontStyle, RGBAColor, RGBColor, ShapeStyle, TextStyle, BLACK};
+
+/// A table element, which renders a grid of text cells with borders, so a small data table
+/// or a summary can be placed next to a chart in the same image. The position is the upper
+/// left corner of the table and all the sizes are in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// root.draw(&Table::new(
+///     (10, 10),
+///     vec![
+///         vec!["series", "mean", "stddev"],
+///         vec!["a", "1.25", "0.5"],
+///         vec!["b", "3.5", "1.75"],
+///     ],
+/// ))
+/// .unwrap();
+/// ```
+pub struct Table<Coord> {
+    pos: Coord,
+    rows: Vec<Vec<String>>,
+    column_widths: Option<Vec<u32>>,
+    padding: u32,
+    font: FontDesc<'static>,
+    header: bool,
+    header_background: RGBAColor,
+    background: Option<RGBAColor>,
+    border_style: ShapeStyle,
+}
+
+impl<Coord> Table<Coord> {
+    /// Create a new table element
+    /// - `pos`: The upper left corner of the table
+    /// - `rows`: The rows of the table, the first row is the header by default
+    /// - returns the created element
+    pub fn new<R, C, S>(pos: Coord, rows: R) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Self {
+            pos,
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+                .collect(),
+            column_widths: None,
+            padding: 4,
+            font: ("sans-serif", 12).into(),
+            header: true,
+            header_background: RGBColor(220, 220, 220).to_rgba(),
+            background: None,
+            border_style: (&BLACK).into(),
+        }
+    }
+
+    /// Set the widths of the columns in pixels. By default, each column is as wide as its
+    /// widest cell. The columns without a width are sized automatically.
+    pub fn column_widths(mut self, widths: Vec<u32>) -> Self {
+        self.column_widths = Some(widths);
+        self
+    }
+
+    /// Set the padding between the text and the borders of the cells
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the font of the cells, the header uses the bold variant of it
+    pub fn label_font<F: Into<FontDesc<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Set if the first row is styled as the header
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Set the background color of the header row
+    pub fn header_background<C: Color>(mut self, color: &C) -> Self {
+        self.header_background = color.to_rgba();
+        self
+    }
+
+    /// Set the background color of the table, the table is transparent by default
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the style of the borders
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    fn font_of(&self, row: usize) -> FontDesc<'static> {
+        if self.header && row == 0 {
+            self.font.style(FontStyle::Bold)
+        } else {
+            self.font.clone()
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Table<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = core::iter::Once<&'a Coord>;
+    fn point_iter(self) -> core::iter::Once<&'a Coord> {
+        core::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Table<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return Ok(());
+        }
+
+        let mut widths = vec![0; columns];
+        let mut height = 0;
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            let font = self.font_of(row_idx);
+            for (col, cell) in row.iter().enumerate() {
+                let (w, h) = backend.estimate_text_size(cell, &font)?;
+                widths[col] = widths[col].max(w);
+                height = height.max(h);
+            }
+        }
+        let given = self.column_widths.as_deref().unwrap_or(&[]);
+        for (col, width) in widths.iter_mut().enumerate() {
+            *width = match given.get(col) {
+                Some(&w) => w,
+                None => *width + 2 * self.padding,
+            };
+        }
+        let row_height = (height.max(self.font.get_size() as u32) + 2 * self.padding) as i32;
+        let total_width: i32 = widths.iter().map(|&w| w as i32).sum();
+        let total_height = row_height * self.rows.len() as i32;
+
+        if let Some(color) = self.background.as_ref() {
+            backend.draw_rect((x0, y0), (x0 + total_width, y0 + total_height), color, true)?;
+        }
+        if self.header {
+            backend.draw_rect(
+                (x0, y0),
+                (x0 + total_width, y0 + row_height),
+                &self.header_background,
+                true,
+            )?;
+        }
+
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            let style = TextStyle::from(self.font_of(row_idx))
+                .color(&BLACK)
+                .pos(Pos::new(HPos::Left, VPos::Center));
+            let top = y0 + row_height * row_idx as i32;
+            let mut left = x0;
+            for (col, &width) in widths.iter().enumerate() {
+                let right = left + width as i32;
+                backend.draw_rect(
+                    (left, top),
+                    (right, top + row_height),
+                    &self.border_style,
+                    false,
+                )?;
+                if let Some(cell) = row.get(col) {
+                    let pos = (left + self.padding as i32, top + row_height / 2);
+                    backend.draw_text(cell, &style, pos)?;
+                }
+                left = right;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Coord> WithOpacity for Table<Coord> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.header_background = self.header_background.mix(value);
+        if let Some(color) = self.background.as_mut() {
+            *color = color.mix(value);
+        }
+        self.border_style = self.border_style.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_element() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|_, _, filled, ul, br| {
+                // The header background
+                assert!(filled);
+                assert_eq!(ul, (10, 10));
+                assert_eq!(br.0, 110);
+            });
+            m.check_draw_rect(|_, _, filled, ul, br| {
+                assert!(!filled);
+                assert_eq!(ul, (10, 10));
+                assert_eq!(br.0, 40);
+            });
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!(ul.0, 40);
+                assert_eq!(br.0, 110);
+            });
+            m.check_draw_rect(|_, _, filled, _, _| {
+                assert!(!filled);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos.0, 14);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "b");
+                assert_eq!(pos.0, 44);
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 7);
+                assert_eq!(b.num_draw_text_call, 5);
+            });
+        });
+        let table = Table::new((10, 10), vec![vec!["a", "b"], vec!["1", "2"], vec!["3"]])
+            .column_widths(vec![30, 70]);
+        da.draw(&table).expect("Drawing Failure");
+    }
+}
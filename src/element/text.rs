@@ -1,9 +1,82 @@
-use std::borrow::Borrow;
-use std::i32;
+use alloc::borrow::Borrow;
+use core::i32;
 
-use super::{Drawable, PointCollection};
+use super::{Drawable, PointCollection, WithOpacity};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, FontResult, FontTransform, LayoutBox, ShapeStyle, TextStyle};
+
+/// Draw a line of text with the letter spacing, the decorations and the background box of the
+/// style, which are not supported by the backends
+pub(crate) fn draw_styled_text<DB: DrawingBackend>(
+    backend: &mut DB,
+    text: &str,
+    style: &TextStyle,
+    (x, y): BackendCoord,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let rotated = !matches!(style.font.get_transform(), FontTransform::None);
+    if !style.is_decorated() || rotated {
+        return backend.draw_text(text, style, (x, y));
+    }
+
+    // The offset of each character from the left side of the text
+    let chars: Vec<_> = text.char_indices().collect();
+    let mut offsets = Vec::with_capacity(chars.len());
+    for (n, &(idx, _)) in chars.iter().enumerate() {
+        let (w, _) = backend.estimate_text_size(&text[..idx], &style.font)?;
+        offsets.push(f64::from(w) + style.letter_spacing * n as f64);
+    }
+    let (w, h) = backend.estimate_text_size(text, &style.font)?;
+    let extra = style.letter_spacing * (chars.len().max(1) - 1) as f64;
+    let (w, h) = ((f64::from(w) + extra).round() as i32, h as i32);
+
+    let x0 = match style.pos.h_pos {
+        HPos::Left => x,
+        HPos::Center => x - w / 2,
+        HPos::Right => x - w,
+    };
+    let y0 = match style.pos.v_pos {
+        VPos::Top => y,
+        VPos::Center => y - h / 2,
+        VPos::Bottom => y - h,
+    };
+
+    if let Some(background) = &style.background {
+        let p = style.padding as i32;
+        backend.draw_rect(
+            (x0 - p, y0 - p),
+            (x0 + w + p, y0 + h + p),
+            background,
+            background.filled,
+        )?;
+    }
+
+    let char_style = TextStyle {
+        pos: Pos::new(HPos::Left, VPos::Top),
+        ..style.clone()
+    };
+    if style.letter_spacing == 0.0 {
+        backend.draw_text(text, &char_style, (x0, y0))?;
+    } else {
+        for (n, &(idx, c)) in chars.iter().enumerate() {
+            let end = idx + c.len_utf8();
+            let cx = x0 + offsets[n].round() as i32;
+            backend.draw_text(&text[idx..end], &char_style, (cx, y0))?;
+        }
+    }
+
+    let thickness = (style.font.get_size() / 15.0).round().max(1.0) as u32;
+    let line_style = ShapeStyle::from(&style.color).stroke_width(thickness);
+    if style.underline {
+        backend.draw_line((x0, y0 + h), (x0 + w, y0 + h), &line_style)?;
+    }
+    if style.strikethrough {
+        backend.draw_line((x0, y0 + h / 2), (x0 + w, y0 + h / 2), &line_style)?;
+    }
+    Ok(())
+}
 
 /// A single line text element. This can be owned or borrowed string, dependents on
 /// `String` or `str` moved into.
@@ -28,11 +101,11 @@ impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
     }
 }
 
-impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
+impl<'b, 'a, Coord, T: Borrow<str>> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = core::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.coord)
+        core::iter::once(&self.coord)
     }
 }
 
@@ -44,12 +117,19 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            return backend.draw_text(self.text.borrow(), &self.style, a);
+            return draw_styled_text(backend, self.text.borrow(), &self.style, a);
         }
         Ok(())
     }
 }
 
+impl<'a, Coord, T: Borrow<str>> WithOpacity for Text<'a, Coord, T> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style.color = self.style.color.mix(value);
+        self
+    }
+}
+
 /// An multi-line text element. The `Text` element allows only single line text
 /// and the `MultiLineText` supports drawing multiple lines
 pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
@@ -63,18 +143,20 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
     /// Create an empty multi-line text element.
     /// Lines can be append to the empty multi-line by calling `push_line` method
     ///
-    /// `pos`: The upper left corner
-    /// `style`: The style of the text
+    /// `pos`: The anchor point of the whole text block, the upper left corner by default
+    /// `style`: The style of the text, whose anchor position aligns the block
     pub fn new<S: Into<TextStyle<'a>>>(pos: Coord, style: S) -> Self {
+        let style: TextStyle = style.into();
         MultiLineText {
             lines: vec![],
             coord: pos,
-            style: style.into(),
-            line_height: 1.25,
+            line_height: style.line_spacing,
+            style,
         }
     }
 
-    /// Set the line height of the multi-line text element
+    /// Set the line height of the multi-line text element, which is the line spacing of the
+    /// text style by default
     pub fn set_line_height(&mut self, value: f64) -> &mut Self {
         self.line_height = value;
         self
@@ -109,11 +191,24 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         let actual_line_height = font_height * self.line_height;
         (0..self.lines.len() as u32).map(move |idx| {
             let y = f64::from(y0) + f64::from(idx) * actual_line_height;
-            // TODO: Support text alignment as well, currently everything is left aligned
             let x = f64::from(x0);
             (x.round() as i32, y.round() as i32)
         })
     }
+
+    // The anchor points of the lines. Each line is aligned horizontally by the backend, which
+    // honors the anchor of the text style, and the whole block is shifted vertically so that
+    // the anchor applies to the block rather than the first line.
+    fn anchored_lines(&self, (x0, y0): BackendCoord) -> impl Iterator<Item = BackendCoord> {
+        let block =
+            (self.lines.len().max(1) - 1) as f64 * self.style.font.get_size() * self.line_height;
+        let shift = match self.style.pos.v_pos {
+            VPos::Top => 0.0,
+            VPos::Center => block / 2.0,
+            VPos::Bottom => block,
+        };
+        self.layout_lines((x0, y0 - shift.round() as i32))
+    }
 }
 
 fn layout_multiline_text<'a, F: FnMut(&'a str)>(
@@ -122,33 +217,52 @@ fn layout_multiline_text<'a, F: FnMut(&'a str)>(
     font: FontDesc<'a>,
     mut func: F,
 ) {
+    let measure = |s: &str| font.box_size(s).unwrap_or((0, 0)).0;
     for line in text.lines() {
         if max_width == 0 || line.is_empty() {
             func(line);
         } else {
-            let mut remaining = &line[0..];
-
-            while !remaining.is_empty() {
-                let mut left = 0;
-                while left < remaining.len() {
-                    let width = font.box_size(&remaining[0..=left]).unwrap_or((0, 0)).0 as i32;
+            wrap_line(line, max_width, &measure, &mut func);
+        }
+    }
+}
 
-                    if width > max_width as i32 {
-                        break;
-                    }
-                    left += 1;
-                }
+// Break the line at the whitespaces so that each piece fits into the max width. A word which
+// is wider than the max width is broken at the character boundary.
+fn wrap_line<'a, M: Fn(&str) -> u32, F: FnMut(&'a str)>(
+    line: &'a str,
+    max_width: u32,
+    measure: &M,
+    func: &mut F,
+) {
+    let mut remaining = line;
+    while !remaining.is_empty() {
+        let mut last_fit = 0;
+        let mut last_space = None;
+        for (idx, c) in remaining.char_indices() {
+            let end = idx + c.len_utf8();
+            if c.is_whitespace() {
+                last_space = Some(idx);
+            }
+            if measure(&remaining[..end]) > max_width {
+                break;
+            }
+            last_fit = end;
+        }
 
-                if left == 0 {
-                    left += 1;
-                }
+        if last_fit == remaining.len() {
+            func(remaining);
+            return;
+        }
 
-                let cur_line = &remaining[..left];
-                remaining = &remaining[left..];
+        let split = match last_space {
+            Some(pos) if pos > 0 && pos <= last_fit => pos,
+            // Make sure we always make progress, even if a single character doesn't fit
+            _ => last_fit.max(remaining.chars().next().map_or(1, char::len_utf8)),
+        };
 
-                func(cur_line);
-            }
-        }
+        func(remaining[..split].trim_end());
+        remaining = remaining[split..].trim_start();
     }
 }
 
@@ -156,9 +270,20 @@ impl<'a, T: Borrow<str>> MultiLineText<'a, BackendCoord, T> {
     /// Compute the line layout
     pub fn compute_line_layout(&self) -> FontResult<Vec<LayoutBox>> {
         let mut ret = vec![];
-        for ((x, y), t) in self.layout_lines(self.coord).zip(self.lines.iter()) {
+        for ((x, y), t) in self.anchored_lines(self.coord).zip(self.lines.iter()) {
             let (dx, dy) = self.style.font.box_size(t.borrow())?;
-            ret.push(((x, y), (x + dx as i32, y + dy as i32)));
+            let (dx, dy) = (dx as i32, dy as i32);
+            let x = match self.style.pos.h_pos {
+                HPos::Left => x,
+                HPos::Center => x - dx / 2,
+                HPos::Right => x - dx,
+            };
+            let y = match self.style.pos.v_pos {
+                VPos::Top => y,
+                VPos::Center => y - dy / 2,
+                VPos::Bottom => y - dy,
+            };
+            ret.push(((x, y), (x + dx, y + dy)));
         }
         Ok(ret)
     }
@@ -213,13 +338,11 @@ impl<'a, Coord> MultiLineText<'a, Coord, String> {
     }
 }
 
-impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
-    for &'a MultiLineText<'b, Coord, T>
-{
+impl<'b, 'a, Coord, T: Borrow<str>> PointCollection<'a, Coord> for &'a MultiLineText<'b, Coord, T> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = core::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.coord)
+        core::iter::once(&self.coord)
     }
 }
 
@@ -233,10 +356,243 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
-                backend.draw_text(text.borrow(), &self.style, point)?;
+            for (point, text) in self.anchored_lines(a).zip(self.lines.iter()) {
+                draw_styled_text(backend, text.borrow(), &self.style, point)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Coord, T: Borrow<str>> WithOpacity for MultiLineText<'a, Coord, T> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style.color = self.style.color.mix(value);
+        self
+    }
+}
+
+/// A text element which follows a path. Each character is placed on the path and rotated
+/// along its direction, which makes curved labels on polar charts and labeled contour lines
+/// possible. The characters which don't fit on the path are dropped.
+pub struct TextAlongPath<'a, Coord, T: Borrow<str>> {
+    text: T,
+    points: Vec<Coord>,
+    style: TextStyle<'a>,
+}
+
+impl<'a, Coord, T: Borrow<str>> TextAlongPath<'a, Coord, T> {
+    /// Create a new text element along the path
+    /// - `text`: The text for the element
+    /// - `points`: The path the text follows, from the start of the text
+    /// - `style`: The text style, the vertical anchor decides which side of the path the
+    ///   text is on
+    /// - Return the newly created text element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<TextStyle<'a>>>(text: T, points: P, style: S) -> Self {
+        Self {
+            text,
+            points: points.into(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'b, 'a, Coord, T: Borrow<str>> PointCollection<'a, Coord> for &'a TextAlongPath<'b, Coord, T> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for TextAlongPath<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<(f64, f64)> = points.map(|(x, y)| (f64::from(x), f64::from(y))).collect();
+        let segments: Vec<_> = points
+            .windows(2)
+            .map(|w| (w[0], w[1], (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1)))
+            .filter(|&(_, _, len)| len > 0.0)
+            .collect();
+
+        let text = self.text.borrow();
+        let width = |s: &str| {
+            self.style
+                .font
+                .box_size(s)
+                .map(|(w, _)| f64::from(w))
+                .map_err(DrawingErrorKind::FontError)
+        };
+
+        let (mut seg_idx, mut seg_start) = (0, 0.0);
+        for (idx, c) in text.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let end = idx + c.len_utf8();
+            // The center of the character, measured along the path
+            let center = (width(&text[..idx])? + width(&text[..end])?) / 2.0;
+            while seg_idx < segments.len() && seg_start + segments[seg_idx].2 < center {
+                seg_start += segments[seg_idx].2;
+                seg_idx += 1;
             }
+            let (from, to, len) = match segments.get(seg_idx) {
+                Some(segment) => *segment,
+                None => break,
+            };
+            let t = (center - seg_start) / len;
+            let pos = (
+                (from.0 + (to.0 - from.0) * t).round() as i32,
+                (from.1 + (to.1 - from.1) * t).round() as i32,
+            );
+            // The backend y axis points down, so this is the clockwise angle
+            let angle = (to.1 - from.1).atan2(to.0 - from.0).to_degrees();
+            let style = TextStyle {
+                font: self
+                    .style
+                    .font
+                    .transform(FontTransform::RotateAngle(angle as f32)),
+                pos: Pos::new(HPos::Center, self.style.pos.v_pos),
+                ..self.style.clone()
+            };
+            backend.draw_text(&text[idx..end], &style, pos)?;
         }
         Ok(())
     }
 }
+
+impl<'a, Coord, T: Borrow<str>> WithOpacity for TextAlongPath<'a, Coord, T> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.style.color = self.style.color.mix(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_word_wrapping() {
+        let measure = |s: &str| s.chars().count() as u32 * 10;
+        let wrap = |line, width| {
+            let mut lines = vec![];
+            wrap_line(line, width, &measure, &mut |l| lines.push(l));
+            lines
+        };
+
+        assert_eq!(
+            wrap("the quick brown fox", 100),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(wrap("abcdefgh ij", 50), vec!["abcde", "fgh", "ij"]);
+        assert_eq!(wrap("ünïcödé", 30), vec!["ünï", "cöd", "é"]);
+        assert_eq!(wrap("wide", 5), vec!["w", "i", "d", "e"]);
+    }
+
+    #[test]
+    fn test_multi_line_text_anchor() {
+        use crate::prelude::*;
+        use crate::style::text_anchor::Pos;
+
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "first");
+                assert_eq!(pos, (150, 125));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "second");
+                assert_eq!(pos, (150, 150));
+            });
+            m.check_draw_text(|_, _, _, pos, _| {
+                assert_eq!(pos, (150, 175));
+            });
+        });
+
+        let style = TextStyle::from(("sans-serif", 20).into_font())
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        let mut text = MultiLineText::<_, &str>::new((150, 150), style);
+        text.push_line("first");
+        text.push_line("second");
+        text.push_line("third");
+        da.draw(&text).expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_text_along_path() {
+        use crate::prelude::*;
+
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos.1, 100);
+                assert!(pos.0 > 100 && pos.0 < 120);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_ne!(text, " ");
+                // The characters are either on the horizontal or the vertical segment
+                assert!(
+                    (pos.1 == 100 && (100..=120).contains(&pos.0))
+                        || (pos.0 == 120 && (100..=120).contains(&pos.1))
+                );
+            });
+            m.drop_check(|b| {
+                // The characters which don't fit on the path are dropped
+                assert!(b.num_draw_text_call > 2 && b.num_draw_text_call < 10);
+            });
+        });
+        let style = TextStyle::from(("sans-serif", 20).into_font());
+        let element = TextAlongPath::new(
+            "a bcdefghijklmnop",
+            vec![(100, 100), (120, 100), (120, 120)],
+            style,
+        );
+        da.draw(&element).expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_decorated_text() {
+        use crate::prelude::*;
+
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                assert_eq!(ul, (95, 95));
+                assert!(br.0 > 110 && br.1 > 110);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos, (100, 100));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "b");
+                assert!(pos.0 > 110);
+                assert_eq!(pos.1, 100);
+            });
+            m.check_draw_line(|c, w, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(w, 1);
+                assert_eq!(from.0, 100);
+                assert_eq!(from.1, to.1);
+                assert!(to.0 > 110);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+                assert_eq!(b.num_draw_line_call, 1);
+            });
+        });
+
+        let style = TextStyle::from(("sans-serif", 15).into_font())
+            .color(&RED)
+            .letter_spacing(10.0)
+            .underline()
+            .background(WHITE.filled(), 5);
+        da.draw(&Text::new("ab", (100, 100), style))
+            .expect("Drawing Failure");
+    }
+}
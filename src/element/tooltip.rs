@@ -0,0 +1,105 @@
+use core::borrow::Borrow;
+
+use super::{Drawable, PointCollection, WithOpacity};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// An element with a tooltip, which is shown when hovering over the element on the backends
+/// supporting it, such as `SVGBackend::interactive`, the other backends draw the element only.
+/// `ChartContext::draw_series_with_tooltips` gives each element of a series the tooltip of its
+/// data point.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut buf, (300, 200))
+///         .interactive()
+///         .into_drawing_area();
+///     root.draw(&Tooltip::new(Circle::new((50, 50), 5, RED.filled()), "The answer is 42"))
+///         .unwrap();
+/// }
+/// assert!(buf.contains("<title>The answer is 42</title>"));
+/// ```
+pub struct Tooltip<Coord, E> {
+    points: Vec<Coord>,
+    element: E,
+    text: String,
+}
+
+impl<Coord: Clone, E> Tooltip<Coord, E> {
+    /// Attach a tooltip to an element
+    /// - `element`: The element to draw
+    /// - `text`: The text of the tooltip
+    pub fn new<S: Into<String>>(element: E, text: S) -> Self
+    where
+        for<'a> &'a E: PointCollection<'a, Coord>,
+    {
+        Self {
+            points: element
+                .point_iter()
+                .into_iter()
+                .map(|point| point.borrow().clone())
+                .collect(),
+            element,
+            text: text.into(),
+        }
+    }
+
+    /// Get the text of the tooltip
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a Tooltip<Coord, E> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<Coord, E: WithOpacity> WithOpacity for Tooltip<Coord, E> {
+    fn opacity(mut self, value: f64) -> Self {
+        self.element = self.element.opacity(value);
+        self
+    }
+}
+
+impl<Coord, DB: DrawingBackend, E: Drawable<DB>> Drawable<DB> for Tooltip<Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.set_tooltip(Some(&self.text));
+        let result = self.element.draw(points, backend, parent_dim);
+        backend.set_tooltip(None);
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::Circle;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_tooltip_element() {
+        let tooltip = Tooltip::new(Circle::new((1, 2), 3, &RED), "tip");
+        assert_eq!(tooltip.text(), "tip");
+        let points: Vec<_> = tooltip.point_iter().iter().collect();
+        assert_eq!(points, [&(1, 2)]);
+
+        // The backends without tooltips just draw the element
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        drawing_area.draw(&tooltip).unwrap();
+    }
+}
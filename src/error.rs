@@ -0,0 +1,322 @@
+/*!
+  The crate-wide error type.
+
+  The drawing APIs return the errors generic over the error type of the drawing backend, such
+  as `DrawingAreaErrorKind<BitMapBackendError>`. They can be converted into `PlottersError`,
+  which doesn't depend on the backend type, so the functions drawing on different backends
+  share the same error type. Both of them carry the `ErrorContext`, which tells which backend,
+  which element and which step of drawing the chart failed, when the drawing area is created
+  with `DrawingArea::attach_error_context`.
+
+  ```rust
+  use plotters::prelude::*;
+
+  fn draw<DB: DrawingBackend>(backend: DB) -> Result<(), PlottersError>
+  where
+      DB::ErrorType: 'static,
+  {
+      let root = backend.into_drawing_area().attach_error_context(true);
+      root.fill(&WHITE)?;
+      let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10)?;
+      chart.configure_mesh().draw()?;
+      Ok(())
+  }
+
+  let mut buf = String::new();
+  draw(SVGBackend::with_string(&mut buf, (300, 200))).unwrap();
+  ```
+*/
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::drawing::backend::DrawingErrorKind;
+use crate::drawing::DrawingAreaErrorKind;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::FontError;
+
+/// Where an error happened, the fields which are unknown are `None`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The name of the drawing backend type, such as `SVGBackend`
+    pub backend: Option<&'static str>,
+    /// The name of the element type being drawn, such as `Circle`
+    pub element: Option<&'static str>,
+    /// The step of drawing the chart, such as `caption`, `mesh` or `series`
+    pub step: Option<&'static str>,
+}
+
+// The type name without the module path and the type parameters
+pub(crate) fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let name = &name[..name.find('<').unwrap_or(name.len())];
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+impl ErrorContext {
+    /// The context of an error of the drawing backend with type `DB`
+    pub fn for_backend<DB: ?Sized>() -> Self {
+        Self {
+            backend: Some(short_type_name::<DB>()),
+            ..Self::default()
+        }
+    }
+
+    /// The context of an error while drawing an element of type `E`
+    pub fn for_element<E: ?Sized>() -> Self {
+        Self {
+            element: Some(short_type_name::<E>()),
+            ..Self::default()
+        }
+    }
+
+    /// The context of an error in a step of drawing the chart
+    pub fn for_step(step: &'static str) -> Self {
+        Self {
+            step: Some(step),
+            ..Self::default()
+        }
+    }
+
+    /// Fill the fields which are unknown with the other context
+    pub fn merge(self, other: ErrorContext) -> Self {
+        Self {
+            backend: self.backend.or(other.backend),
+            element: self.element.or(other.element),
+            step: self.step.or(other.step),
+        }
+    }
+
+    /// Check if nothing is known about the context
+    pub fn is_empty(&self) -> bool {
+        self.backend.is_none() && self.element.is_none() && self.step.is_none()
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        let fields = [
+            ("backend", self.backend),
+            ("element", self.element),
+            ("step", self.step),
+        ];
+        let mut first = true;
+        for (name, value) in fields.iter() {
+            if let Some(value) = value {
+                write!(fmt, "{}{} {}", if first { "" } else { ", " }, name, value)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error of any drawing operation, which doesn't depend on the drawing backend type
+#[derive(Debug)]
+pub enum PlottersError {
+    /// The drawing backend failed, such as an IO error when saving the image
+    Backend {
+        /// The error of the backend
+        source: Box<dyn Error + Send + Sync>,
+        /// Where the error happened
+        context: ErrorContext,
+    },
+    /// The font couldn't be loaded or rendered
+    Font {
+        /// The error of the font implementation
+        source: FontError,
+        /// Where the error happened
+        context: ErrorContext,
+    },
+    /// The drawing backend is used by another drawing operation
+    Sharing {
+        /// Where the error happened
+        context: ErrorContext,
+    },
+    /// The layout is invalid
+    Layout {
+        /// Where the error happened
+        context: ErrorContext,
+    },
+}
+
+impl PlottersError {
+    /// Get where the error happened
+    pub fn context(&self) -> &ErrorContext {
+        match self {
+            PlottersError::Backend { context, .. }
+            | PlottersError::Font { context, .. }
+            | PlottersError::Sharing { context }
+            | PlottersError::Layout { context } => context,
+        }
+    }
+
+    fn context_mut(&mut self) -> &mut ErrorContext {
+        match self {
+            PlottersError::Backend { context, .. }
+            | PlottersError::Font { context, .. }
+            | PlottersError::Sharing { context }
+            | PlottersError::Layout { context } => context,
+        }
+    }
+}
+
+impl Display for PlottersError {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        match self {
+            PlottersError::Backend { source, .. } => {
+                write!(fmt, "Drawing backend error: {}", source)?
+            }
+            PlottersError::Font { source, .. } => write!(fmt, "Font error: {}", source)?,
+            PlottersError::Sharing { .. } => write!(fmt, "Multiple backend operation in progress")?,
+            PlottersError::Layout { .. } => write!(fmt, "Bad layout")?,
+        }
+        if !self.context().is_empty() {
+            write!(fmt, " ({})", self.context())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for PlottersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PlottersError::Backend { source, .. } => Some(source.as_ref()),
+            PlottersError::Font { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<DrawingErrorKind<E>> for PlottersError {
+    fn from(error: DrawingErrorKind<E>) -> Self {
+        let context = ErrorContext::default();
+        match error {
+            DrawingErrorKind::DrawingError(e) => PlottersError::Backend {
+                source: Box::new(e),
+                context,
+            },
+            DrawingErrorKind::FontError(e) => PlottersError::Font { source: e, context },
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<DrawingAreaErrorKind<E>> for PlottersError {
+    fn from(error: DrawingAreaErrorKind<E>) -> Self {
+        match error {
+            DrawingAreaErrorKind::BackendError(e) => e.into(),
+            DrawingAreaErrorKind::SharingError => PlottersError::Sharing {
+                context: ErrorContext::default(),
+            },
+            DrawingAreaErrorKind::LayoutError => PlottersError::Layout {
+                context: ErrorContext::default(),
+            },
+            DrawingAreaErrorKind::WithContext(context, inner) => {
+                let mut error = PlottersError::from(*inner);
+                let known = core::mem::take(error.context_mut());
+                *error.context_mut() = known.merge(context);
+                error
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::backend::{BackendCoord, DrawingBackend};
+    use crate::prelude::*;
+    use crate::style::RGBAColor;
+
+    #[derive(Debug)]
+    struct BrokenPipe;
+
+    impl Display for BrokenPipe {
+        fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+            write!(fmt, "broken pipe")
+        }
+    }
+
+    impl Error for BrokenPipe {}
+
+    // A backend which fails to draw the pixels in the right half
+    struct HalfBackend;
+
+    impl DrawingBackend for HalfBackend {
+        type ErrorType = BrokenPipe;
+
+        fn get_size(&self) -> (u32, u32) {
+            (100, 100)
+        }
+
+        fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BrokenPipe>> {
+            Ok(())
+        }
+
+        fn present(&mut self) -> Result<(), DrawingErrorKind<BrokenPipe>> {
+            Ok(())
+        }
+
+        fn draw_pixel(
+            &mut self,
+            point: BackendCoord,
+            _color: &RGBAColor,
+        ) -> Result<(), DrawingErrorKind<BrokenPipe>> {
+            if point.0 >= 50 {
+                return Err(DrawingErrorKind::DrawingError(BrokenPipe));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_error_context() {
+        assert_eq!(short_type_name::<Circle<(i32, i32), i32>>(), "Circle");
+
+        // The errors have no context by default
+        let error = HalfBackend
+            .into_drawing_area()
+            .draw(&Circle::new((70, 50), 5, RED.filled()))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::DrawingError(BrokenPipe))
+        ));
+        assert!(PlottersError::from(error).context().is_empty());
+
+        let root = HalfBackend.into_drawing_area().attach_error_context(true);
+        let error: PlottersError = root
+            .draw(&Circle::new((70, 50), 5, RED.filled()))
+            .unwrap_err()
+            .into();
+        assert!(matches!(error, PlottersError::Backend { .. }));
+        assert_eq!(
+            error.context(),
+            &ErrorContext {
+                backend: Some("HalfBackend"),
+                element: Some("Circle"),
+                step: None,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Drawing backend error: broken pipe (backend HalfBackend, element Circle)"
+        );
+
+        let (left, right) = root.split_horizontally(50);
+        let mut chart = ChartBuilder::on(&left).build_ranged(0..10, 0..10).unwrap();
+        chart
+            .draw_series((0..10).map(|x| Circle::new((x, x), 2, &RED)))
+            .unwrap();
+        let mut chart = ChartBuilder::on(&right).build_ranged(0..10, 0..10).unwrap();
+        let error = match chart.draw_series((0..10).map(|x| Circle::new((x, x), 2, &RED))) {
+            Err(error) => error,
+            Ok(_) => panic!("The series should fail to draw"),
+        };
+        assert_eq!(error.context().unwrap().step, Some("series"));
+        assert!(matches!(
+            error.root_cause(),
+            DrawingAreaErrorKind::BackendError(_)
+        ));
+    }
+}
@@ -269,7 +269,7 @@ plotters = "^0.2.15"
 
 And the following code draws a quadratic function. `src/main.rs`,
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let root = BitMapBackend::new("plotters-doc-data/0.png", (640, 480)).into_drawing_area();
@@ -390,6 +390,9 @@ very useful for visualization on a web page and would have a huge performance im
 Plotters currently supports backend that uses the HTML5 canvas. To use the WASM support, you can simply use
 `CanvasBackend` instead of other backend and all other API remains the same!
 
+To keep the heavy charts from blocking the UI thread, `OffscreenCanvasBackend` draws on an `OffscreenCanvas`
+in a web worker, the frames can be transferred back to the main thread as `ImageBitmap`s without copying the pixels.
+
 There's a small demo for Plotters + WASM under `examples/wasm-demo` directory of this repo.
 To play with the deployed version, follow this [link](https://plumberserver.com/plotters-wasm-demo/index.html).
 
@@ -409,7 +412,7 @@ But Plotter is aimed to be a platform that is fully extendable to support any ot
 ### Drawing Back-ends
 Plotters can use different drawing back-ends, including SVG, BitMap, and even real-time rendering. For example, a bitmap drawing backend.
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a 800*600 bitmap and start drawing
@@ -430,7 +433,7 @@ This is done by creating sub-drawing-areas.
 
 Besides that, the drawing area also allows the customized coordinate system, by doing so, the coordinate mapping is done by the drawing area automatically.
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let root_drawing_area =
@@ -457,7 +460,7 @@ You may also combine existing elements to build a complex element.
 
 To learn more about the element system, please read the [element module documentation](./element/index.html).
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let root = BitMapBackend::new("plotters-doc-data/3.png", (300, 200)).into_drawing_area();
@@ -482,7 +485,7 @@ coordinate which has the upper-left corner defined as `(0,0)` is used for furthe
 
 For example, we can have an element which includes a dot and its coordinate.
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -490,7 +493,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     root.fill(&RGBColor(240, 200, 200))?;
 
-    let root = root.apply_coord_spec(RangedCoord::<RangedCoordf32, RangedCoordf32>::new(
+    let root = root.apply_coord_spec(RangedCoord::cartesian_2d(
         0f32..1f32,
         0f32..1f32,
         (0..640, 0..480),
@@ -522,7 +525,7 @@ The chart context defines even higher level constructs compare to the drawing ar
 For example, you can define the label areas, meshes, and put a data series onto the drawing area with the help
 of the chart context object.
 
-```rust
+```rust,no_run
 use plotters::prelude::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let root = BitMapBackend::new("plotters-doc-data/5.png", (640, 480)).into_drawing_area();
@@ -587,6 +590,12 @@ use `default_features = false` in the dependency description in `Cargo.toml` and
 
 - `svg` Enable the `SVGBackend`
 - `bitmap` Enable the `BitMapBackend`
+- `ttf` Enable the TrueType font rendering, without it the text size is only estimated and the `BitMapBackend` fails to draw text
+- `shaping` Shape the text with `rustybuzz`, so the ligatures and the joining forms of the Arabic letters are substituted, implies `ttf`
+- `color_font` Draw the color glyphs of the `CBDT`, `sbix` and `COLR` fonts, such as the emoji, in their colors on the `BitMapBackend`, implies `ttf`
+- `chrono` Enable the date and time coordinates
+- `time` Enable the date and time coordinates of the `time` crate, for the projects which don't use `chrono`
+- `std` Use the standard library, without it the crate is `no_std` and only needs `alloc`
 
 For example, the following dependency description would avoid compiling with bitmap support:
 
@@ -595,6 +604,20 @@ For example, the following dependency description would avoid compiling with bit
 plotters = { git = "https://github.com/38/plotters.git", default_features = false, features = ["svg"] }
 ```
 
+The features are independent of each other, so the slim builds for embedded and WASM targets only pay for what they use.
+For example, `features = ["svg", "line_series"]` compiles neither the font libraries nor `chrono`, and
+`features = ["bitmap", "line_series"]` renders the shapes to a pixel buffer without any dependency but `itertools`.
+
+Without the `std` feature, the crate is `no_std` and only needs `alloc`, so the firmware can render the charts to
+the framebuffer of an LCD with `BitMapBackend::with_buffer`. The coordinates, the elements, the drawing areas and
+the charts are available, while the features which need the standard library, such as the file IO of `image_encoder`,
+`chrono`, `ttf`, `svg` and `histogram`, enable `std` implicitly.
+
+```toml
+[dependencies]
+plotters = { git = "https://github.com/38/plotters.git", default_features = false, features = ["bitmap", "line_series"] }
+```
+
 The library also allows consumers to make use of the [`Palette`](https://crates.io/crates/palette/) crate's color types by default.
 This behavior can also be turned off by setting `default_features = false`.
 
@@ -607,27 +630,38 @@ By doing so, you can minimize the number of dependencies down to only `itertools
 
 The following list is a complete list of features that can be opt in and out.
 
+- Platform features
+
+| Name    |  Description | Additional Dependency |Default?|
+|---------|--------------|--------|------------|
+| std | Use the standard library, without it the crate is `no_std` and only needs `alloc` | None | Yes |
+
 - Drawing backends related features
 
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
-| image\_encoder  | Allow `BitMapBackend` save the result to bitmap files | image, rusttype, font-kit | Yes |
+| bitmap | Enable `BitMapBackend` Support | None | Yes |
+| image\_encoder  | Allow `BitMapBackend` save the result to bitmap files, implies `bitmap` enabled | image | Yes |
 | svg     | Enable `SVGBackend` Support | None | Yes |
 | gif\_backend| Opt-in GIF animation Rendering support for `BitMapBackend`, implies `bitmap` enabled | gif | Yes |
 | piston | Enable `PistonWindowBackend` | piston\_window, rusttype, font-kit | No |
 | cairo | Enable `CairoBackend` | cairo-rs, rusttype, font-kit | No |
+| rayon | Render the bands of a `BitMapBackend` and draw the series in parallel with `render_bands` and `draw_series_par` | rayon | No |
+| mmap | Back the pixel buffer of a `BitMapBackend` with a memory-mapped file, see `with_mapped_buffer` | libc | No |
 
 - Font manipulation features
 
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
-| ttf | Allows TrueType font support | rusttype, font-kit | Yes |
+| ttf | Allows TrueType font support, which the `BitMapBackend` needs to draw text | rusttype, font-kit | Yes |
 
 - Coordinate features
 
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
-| datetime | Eanble the date and time coordinate support | chrono | Yes |
+| chrono | Enable the date and time coordinate support | chrono | Yes |
+| datetime | An alias of `chrono` | chrono | No |
+| time | Enable the date and time coordinate support for the `time` crate, `RangedTimeDate` and `RangedOffsetDateTime` | time | No |
 
 - Element, series and util functions
 
@@ -637,9 +671,13 @@ The following list is a complete list of features that can be opt in and out.
 | candlestick | The candlestick element support | None | Yes |
 | boxplot | The boxplot element support | None | Yes |
 | area\_series | The area series support | None | Yes |
+| band\_series | The band (confidence interval) series support | None | Yes |
 | line\_series | The line series support | None | Yes |
 | histogram | The histogram series support | None | Yes |
 | point\_series| The point series support | None | Yes |
+| spectrogram | The spectrogram and strip chart series support | None | Yes |
+| density\_series | The kernel density estimation series support | None | Yes |
+| csv | Read the columns of CSV files with `data::CsvColumns` | csv | No |
 
 - Misc
 
@@ -647,6 +685,11 @@ The following list is a complete list of features that can be opt in and out.
 |---------|--------------|--------|------------|
 | deprecated\_items | This feature allows use of deprecated items which is going to be removed in the future | None | Yes |
 | debug | Enable the code used for debugging | None | No |
+| full\_palette | The full set of the CSS named colors in `style::colors::full_palette` | None | No |
+| quick | The one-liners for the common plots in the `quick` module, like `quick::line(&xs, &ys).save("out.png")` | None | Yes |
+| spec | The declarative chart specification `spec::ChartSpec`, which can be deserialized with serde | serde | No |
+| vega\_lite | Export the chart specifications as Vega-Lite JSON with `ChartSpec::to_vega_lite` | serde\_json | No |
+| testing | The golden image test utilities in the `testing` module and the `chart_snapshot_test!` macro | None | No |
 
 
 ## FAQ List
@@ -684,14 +727,41 @@ The following list is a complete list of features that can be opt in and out.
 
 
 */
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+
+// The items of the std prelude which are not in the core prelude, and the float functions,
+// which are provided by libm without std
+#[cfg(not(feature = "std"))]
+mod no_std_prelude {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::boxed::Box;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    pub use num_traits::Float;
+}
+
+pub mod animation;
 pub mod chart;
 pub mod coord;
 pub mod data;
 pub mod drawing;
 pub mod element;
+pub mod error;
 pub mod series;
 pub mod style;
 
+#[cfg(feature = "quick")]
+pub mod quick;
+
+#[cfg(feature = "spec")]
+pub mod spec;
+
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
+
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
@@ -703,36 +773,61 @@ pub use palette;
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        ChartBuilder, ChartContext, Colorbar, FacetGrid, Funnel, GridLine, LabelAreaPosition,
+        LabelContext, LabelFormat, LabelOverlap, ParetoChart, SeriesLabelPosition, SharedLegend,
+        Treemap, TreemapNode,
+    };
     pub use crate::coord::{
-        Category, CoordTranslate, GroupBy, IntoCentric, IntoPartialAxis, LogCoord, LogRange,
-        LogScalable, Ranged, RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32,
-        RangedCoordi64, RangedCoordu32, RangedCoordu64, ToGroupByRange,
+        Category, CoordTranslate, FiniteValue, GroupBy, IntoCentric, IntoPartialAxis, LogCoord,
+        LogLabelStyle, LogRange, LogScalable, PaddedRange, Ranged, RangedCoord, RangedCoordf32,
+        RangedCoordf64, RangedCoordi32, RangedCoordi64, RangedCoordu32, RangedCoordu64,
+        ToGroupByRange,
     };
 
     #[cfg(feature = "chrono")]
-    pub use crate::coord::{make_partial_axis, RangedDate, RangedDateTime, RangedDuration};
+    pub use crate::coord::{
+        make_partial_axis, RangedDate, RangedDateTime, RangedDateTimeIn, RangedDuration,
+    };
+    #[cfg(feature = "chrono")]
+    pub use crate::chart::CalendarHeatmap;
 
     pub use crate::drawing::*;
+    pub use crate::error::PlottersError;
     #[cfg(feature = "area_series")]
     pub use crate::series::AreaSeries;
+    #[cfg(feature = "band_series")]
+    pub use crate::series::BandSeries;
+    #[cfg(feature = "density_series")]
+    pub use crate::series::DensitySeries;
+    pub use crate::series::EmpiricalCdf;
     #[cfg(feature = "histogram")]
-    pub use crate::series::Histogram;
+    pub use crate::series::{BinnedHistogram, Histogram, Histogram2D, RoseHistogram};
     #[cfg(feature = "line_series")]
-    pub use crate::series::LineSeries;
+    pub use crate::series::{ChunkedLineSeries, LineSeries};
+    pub use crate::series::MovingAverage;
     #[cfg(feature = "point_series")]
     pub use crate::series::PointSeries;
+    #[cfg(feature = "spectrogram")]
+    pub use crate::series::{Spectrogram, StripChart};
+    #[cfg(feature = "trend_line_series")]
+    pub use crate::series::TrendLineSeries;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBColor, ShapeStyle,
-        SimpleColor, TextStyle,
+        AsRelative, Color, ColorMap, CustomPalette, FontDesc, FontFamily, FontStyle, FontTransform,
+        Gradient, HSLColor, HSVColor, Heat, Inferno, IntoFont, IntoTextStyle, LinearColorMap,
+        Magma, Palette, Palette100, Palette99, Palette9999, PaletteColor, Pattern, PatternKind,
+        Plasma, RGBAColor, RGBColor, ShapeStyle, SimpleColor, TextStyle, Theme, Turbo, Viridis,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
     pub use crate::element::{
-        Circle, Cross, DynElement, EmptyElement, IntoDynElement, MultiLineText, PathElement, Pixel,
-        Polygon, Rectangle, Text, TriangleMarker,
+        Arrow, ArrowHead, Circle, Cross, CubicBezier, DiamondMarker, DynElement, ElementGroup,
+        Ellipse, EmptyElement, FillRule, FilledPath, Gauge, HSpan, IntoDynElement, Legend,
+        LegendGlyph, Marker, MarkerShape, MultiLineText, NorthArrow, PathElement, Pie,
+        PieLabelPosition, PieSlice, PieValueFormat, Pixel, Pixels, PlusMarker, Polygon, QuadBezier,
+        Rectangle, ScaleBar, Segments, SquareMarker, StarMarker, Table, Text, TextAlongPath,
+        Tooltip, TriangleDownMarker, TriangleMarker, VSpan, WithOpacity,
     };
 
     #[cfg(feature = "boxplot")]
@@ -0,0 +1,279 @@
+/*!
+  The one-liners for the common plots.
+
+  The functions of this module create a `QuickPlot`, which can be decorated with a title, the
+  axis descriptions and more series, and then saved to a file or drawn on a drawing area. The
+  ranges are fitted to the data and the series get the colors of the default theme. The full
+  `ChartBuilder` API is the way to go once more control is needed.
+
+  ```rust,no_run
+  use plotters::quick;
+
+  let xs: Vec<f64> = (0..100).map(|x| x as f64 / 10.0).collect();
+  let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+  quick::line(&xs, &ys)
+      .title("sin(x)")
+      .x_label("x")
+      .scatter(&[1.0, 2.0], &[0.5, -0.5])
+      .label("samples")
+      .save("out.png")
+      .unwrap();
+  ```
+*/
+use num_traits::ToPrimitive;
+
+use crate::chart::ChartBuilder;
+use crate::coord::Shift;
+use crate::data::fitting_range;
+use crate::drawing::{DrawingArea, DrawingBackend};
+use crate::element::{Circle, PathElement};
+use crate::error::PlottersError;
+use crate::series::{LineSeries, PointSeries};
+use crate::style::{Color, BLACK, WHITE};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::drawing::{BitMapBackend, IntoDrawingArea, SVGBackend};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum QuickKind {
+    Line,
+    Scatter,
+}
+
+struct QuickSeries {
+    kind: QuickKind,
+    data: Vec<(f64, f64)>,
+    label: Option<String>,
+}
+
+/// A plot created by the functions of the `quick` module
+pub struct QuickPlot {
+    title: Option<String>,
+    x_label: Option<String>,
+    y_label: Option<String>,
+    size: (u32, u32),
+    series: Vec<QuickSeries>,
+}
+
+// The points of the series, the values which can't be converted to `f64` are skipped
+fn zip_points<X: ToPrimitive, Y: ToPrimitive>(xs: &[X], ys: &[Y]) -> Vec<(f64, f64)> {
+    xs.iter()
+        .zip(ys.iter())
+        .filter_map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+        .collect()
+}
+
+/// Plot the points `(xs[i], ys[i])` connected with a line
+/// - `xs`: The X coordinates
+/// - `ys`: The Y coordinates, the extra values of the longer one are ignored
+pub fn line<X: ToPrimitive, Y: ToPrimitive>(xs: &[X], ys: &[Y]) -> QuickPlot {
+    QuickPlot::new().line(xs, ys)
+}
+
+/// Plot the points `(xs[i], ys[i])` as circles
+/// - `xs`: The X coordinates
+/// - `ys`: The Y coordinates, the extra values of the longer one are ignored
+pub fn scatter<X: ToPrimitive, Y: ToPrimitive>(xs: &[X], ys: &[Y]) -> QuickPlot {
+    QuickPlot::new().scatter(xs, ys)
+}
+
+impl QuickPlot {
+    /// Create an empty plot
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            x_label: None,
+            y_label: None,
+            size: (640, 480),
+            series: vec![],
+        }
+    }
+
+    fn push<X: ToPrimitive, Y: ToPrimitive>(mut self, kind: QuickKind, xs: &[X], ys: &[Y]) -> Self {
+        self.series.push(QuickSeries {
+            kind,
+            data: zip_points(xs, ys),
+            label: None,
+        });
+        self
+    }
+
+    /// Add a line series to the plot
+    pub fn line<X: ToPrimitive, Y: ToPrimitive>(self, xs: &[X], ys: &[Y]) -> Self {
+        self.push(QuickKind::Line, xs, ys)
+    }
+
+    /// Add a scatter series to the plot
+    pub fn scatter<X: ToPrimitive, Y: ToPrimitive>(self, xs: &[X], ys: &[Y]) -> Self {
+        self.push(QuickKind::Scatter, xs, ys)
+    }
+
+    /// Set the label of the last series added, the legend is drawn if any series has a label
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        if let Some(series) = self.series.last_mut() {
+            series.label = Some(label.into());
+        }
+        self
+    }
+
+    /// Set the caption of the plot
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description of the X axis
+    pub fn x_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.x_label = Some(label.into());
+        self
+    }
+
+    /// Set the description of the Y axis
+    pub fn y_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.y_label = Some(label.into());
+        self
+    }
+
+    /// Set the size of the image `save` creates, the default is 640x480
+    pub fn size(mut self, w: u32, h: u32) -> Self {
+        self.size = (w, h);
+        self
+    }
+
+    /// Draw the plot on a drawing area, which isn't filled with a background
+    pub fn draw_on<DB: DrawingBackend>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+    ) -> Result<(), PlottersError>
+    where
+        DB::ErrorType: 'static,
+    {
+        let xs: Vec<f64> = self
+            .series
+            .iter()
+            .flat_map(|s| s.data.iter().map(|p| p.0))
+            .collect();
+        let ys: Vec<f64> = self
+            .series
+            .iter()
+            .flat_map(|s| s.data.iter().map(|p| p.1))
+            .collect();
+
+        let mut builder = ChartBuilder::on(root);
+        builder
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40);
+        if let Some(title) = &self.title {
+            builder.caption(title, ("sans-serif", 20));
+        }
+        let mut chart = builder.build_ranged(fitting_range(&xs), fitting_range(&ys))?;
+
+        {
+            let mut mesh = chart.configure_mesh();
+            if let Some(label) = &self.x_label {
+                mesh.x_desc(label.as_str());
+            }
+            if let Some(label) = &self.y_label {
+                mesh.y_desc(label.as_str());
+            }
+            mesh.draw()?;
+        }
+
+        for series in &self.series {
+            let color = chart.next_color().to_rgba();
+            let data = series.data.iter().copied();
+            let anno = match series.kind {
+                QuickKind::Line => chart.draw_series(LineSeries::new(data, &color))?,
+                QuickKind::Scatter => chart.draw_series(
+                    PointSeries::<_, _, Circle<_, _>, _>::new(data, 3, color.filled()),
+                )?,
+            };
+            if let Some(label) = &series.label {
+                anno.label(label.as_str()).legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+                });
+            }
+        }
+
+        if self.series.iter().any(|s| s.label.is_some()) {
+            chart
+                .configure_series_labels()
+                .background_style(&WHITE.mix(0.8))
+                .border_style(&BLACK)
+                .draw()?;
+        }
+        Ok(())
+    }
+
+    /// Save the plot to a file on a white background, the files with the `svg` extension are
+    /// drawn with `SVGBackend` and the others with `BitMapBackend`, which picks the image
+    /// format by the extension
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), PlottersError> {
+        let path = path.as_ref();
+        let is_svg = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+        if is_svg {
+            self.save_on(SVGBackend::new(path, self.size).into_drawing_area())
+        } else {
+            self.save_on(BitMapBackend::new(path, self.size).into_drawing_area())
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_on<DB: DrawingBackend>(&self, root: DrawingArea<DB, Shift>) -> Result<(), PlottersError>
+    where
+        DB::ErrorType: 'static,
+    {
+        root.fill(&WHITE)?;
+        self.draw_on(&root)?;
+        root.present()?;
+        Ok(())
+    }
+}
+
+impl Default for QuickPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::create_mocked_drawing_area;
+
+    #[test]
+    fn test_quick_plot() {
+        let plot = line(&[0, 1, 2], &[1.0, 3.0, 2.0, 5.0])
+            .title("Quick")
+            .label("first")
+            .scatter(&[0.5f32], &[f64::NAN]);
+        assert_eq!(plot.series[0].data, [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]);
+        assert_eq!(plot.series[0].label.as_deref(), Some("first"));
+        assert_eq!(plot.series[1].kind, QuickKind::Scatter);
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.drop_check(|b| {
+                assert!(b.num_draw_path_call > 0);
+                assert!(b.num_draw_text_call > 0);
+            });
+        });
+        plot.draw_on(&drawing_area).unwrap();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_quick_save() {
+        let plot = line(&[0, 1, 2], &[1, 3, 2]).title("Quick");
+        let path = std::env::temp_dir().join(format!("plotters-quick-{}.svg", std::process::id()));
+        plot.save(&path).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(svg.starts_with("<svg") && svg.contains("Quick"));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::drawing::DrawingBackend;
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::colors::TRANSPARENT;
 use crate::style::ShapeStyle;
 
@@ -10,7 +12,7 @@ pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     baseline: Y,
     data: Vec<(X, Y)>,
     state: u32,
-    _p: std::marker::PhantomData<DB>,
+    _p: core::marker::PhantomData<DB>,
 }
 
 impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
@@ -25,7 +27,7 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
             data: iter.into_iter().collect(),
             state: 0,
             border_style: (&TRANSPARENT).into(),
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
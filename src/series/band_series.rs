@@ -0,0 +1,224 @@
+use crate::data::bollinger_bands;
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::colors::TRANSPARENT;
+use crate::style::ShapeStyle;
+use num_traits::NumCast;
+
+/// A band series fills the region between a lower and an upper curve, which is
+/// useful for confidence intervals, prediction intervals, mean ± stddev, etc.
+///
+/// The series is built from an iterator of `(x, lower, upper)` triples. The band
+/// is rendered as a single filled polygon and each edge can optionally be
+/// stroked with the border style.
+pub struct BandSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
+    band_style: ShapeStyle,
+    border_style: ShapeStyle,
+    data: Vec<(X, Y, Y)>,
+    state: u32,
+    _p: core::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone> BandSeries<DB, X, Y> {
+    /// Create a new band series
+    /// - `iter`: The iterator of `(x, lower, upper)` triples
+    /// - `band_style`: The style used to fill the band, normally a translucent color
+    /// - **returns** The newly created band series
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y, Y)>>(
+        iter: I,
+        band_style: S,
+    ) -> Self {
+        Self {
+            band_style: band_style.into(),
+            border_style: (&TRANSPARENT).into(),
+            data: iter.into_iter().collect(),
+            state: 0,
+            _p: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a new band series from a center line and the half width of the band
+    /// at each point, for example `mean ± stddev`.
+    /// - `iter`: The iterator of `(x, center, delta)` triples
+    /// - `band_style`: The style used to fill the band
+    /// - **returns** The newly created band series
+    pub fn from_center<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y, Y)>>(
+        iter: I,
+        band_style: S,
+    ) -> Self
+    where
+        Y: core::ops::Add<Output = Y> + core::ops::Sub<Output = Y>,
+    {
+        Self::new(
+            iter.into_iter()
+                .map(|(x, c, d)| (x, c.clone() - d.clone(), c + d)),
+            band_style,
+        )
+    }
+
+    /// Set the style used to stroke the upper and lower edges of the band
+    /// - `style`: The border style, by default the border is transparent
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone> BandSeries<DB, X, f64> {
+    /// Create the Bollinger bands of a series, see `data::bollinger_bands`. The middle line
+    /// of the bands is drawn with a `LineSeries` of the same function, and the indicators
+    /// drawn on a panel below the price chart, such as `data::rsi` and `data::macd`, are drawn
+    /// with the series of their points.
+    ///
+    /// ```rust
+    /// use plotters::data::{bollinger_bands, rsi};
+    /// use plotters::prelude::*;
+    ///
+    /// let prices: Vec<_> = (0..200)
+    ///     .map(|x| (x, 100.0 + (x as f64 / 10.0).sin() * 10.0 + (x % 7) as f64))
+    ///     .collect();
+    ///
+    /// let mut buf = String::new();
+    /// let root = SVGBackend::with_string(&mut buf, (640, 480)).into_drawing_area();
+    /// let (upper, lower) = root.split_vertically(360);
+    ///
+    /// let mut price_chart = ChartBuilder::on(&upper)
+    ///     .build_ranged(0..200, 80.0..125.0)
+    ///     .unwrap();
+    /// price_chart
+    ///     .draw_series(BandSeries::bollinger(prices.clone(), 20, 2.0, &BLUE.mix(0.2)))
+    ///     .unwrap();
+    /// let middle = bollinger_bands(prices.clone(), 20, 2.0);
+    /// price_chart
+    ///     .draw_series(LineSeries::new(middle.iter().map(|b| (b.x, b.middle)), &BLUE))
+    ///     .unwrap();
+    /// price_chart
+    ///     .draw_series(LineSeries::new(prices.clone(), &BLACK))
+    ///     .unwrap();
+    ///
+    /// let mut rsi_chart = ChartBuilder::on(&lower)
+    ///     .build_ranged(0..200, 0.0..100.0)
+    ///     .unwrap();
+    /// rsi_chart
+    ///     .draw_series(LineSeries::new(rsi(prices, 14), &RED))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `data`: The `(x, value)` samples of the series, such as the close prices
+    /// - `window`: The number of values of the rolling window, usually 20
+    /// - `k`: The number of standard deviations between the middle line and the bands, usually 2
+    /// - `band_style`: The style used to fill the band
+    /// - **returns** The newly created band series
+    pub fn bollinger<Y: NumCast, I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        data: I,
+        window: usize,
+        k: f64,
+        band_style: S,
+    ) -> Self {
+        Self::new(
+            bollinger_bands(data, window, k)
+                .into_iter()
+                .map(|band| (band.x, band.lower, band.upper)),
+            band_style,
+        )
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for BandSeries<DB, X, Y> {
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        match self.state {
+            0 => {
+                self.state = 1;
+                let upper = self.data.iter().map(|(x, _, u)| (x.clone(), u.clone()));
+                let lower = self
+                    .data
+                    .iter()
+                    .rev()
+                    .map(|(x, l, _)| (x.clone(), l.clone()));
+                let points: Vec<_> = upper.chain(lower).collect();
+                Some(Polygon::new(points, self.band_style.clone()).into_dyn())
+            }
+            1 => {
+                self.state = 2;
+                let points: Vec<_> = self
+                    .data
+                    .iter()
+                    .map(|(x, _, u)| (x.clone(), u.clone()))
+                    .collect();
+                Some(PathElement::new(points, self.border_style.clone()).into_dyn())
+            }
+            2 => {
+                self.state = 3;
+                let points: Vec<_> = self
+                    .data
+                    .iter()
+                    .map(|(x, l, _)| (x.clone(), l.clone()))
+                    .collect();
+                Some(PathElement::new(points, self.border_style.clone()).into_dyn())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_band_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.mix(0.2).to_rgba());
+                assert_eq!(path.len(), 200);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.len(), 100);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100, 0..100)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                BandSeries::from_center((0..100).map(|x| (x, 50, x / 4)), &RED.mix(0.2))
+                    .border_style(&BLUE),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_bollinger_band_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|_, path| {
+                // The bands start when the first window of 10 values is full
+                assert_eq!(path.len(), 2 * 91);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100, 0.0..100.0)
+            .expect("Build chart error");
+
+        let prices = (0..100).map(|x| (x, 50.0 + (x % 5) as f64));
+        chart
+            .draw_series(BandSeries::bollinger(prices, 10, 2.0, &RED.mix(0.2)))
+            .expect("Drawing Error");
+    }
+}
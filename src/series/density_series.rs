@@ -0,0 +1,144 @@
+use crate::data::{Bandwidth, KernelDensity};
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::ShapeStyle;
+use core::ops::Range;
+
+/// The series that draws the Gaussian kernel density estimation of the raw samples,
+/// either as a smooth curve or as a filled area.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::data::Bandwidth;
+///
+/// let samples = [1.0, 2.0, 2.5, 3.0, 7.0];
+/// let series: DensitySeries<SVGBackend> = DensitySeries::new(&samples, &RED)
+///     .bandwidth(Bandwidth::Scott)
+///     .filled(&RED.mix(0.2));
+/// let range = series.estimation().range();
+/// assert!(range.start < 1.0 && range.end > 7.0);
+/// ```
+pub struct DensitySeries<DB: DrawingBackend> {
+    kde: KernelDensity,
+    samples: Vec<f64>,
+    line_style: ShapeStyle,
+    area_style: Option<ShapeStyle>,
+    range: Option<Range<f64>>,
+    resolution: usize,
+    state: u32,
+    _p: core::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend> DensitySeries<DB> {
+    /// Create a new density series, which uses Silverman's rule to select the bandwidth
+    /// - `samples`: The raw samples
+    /// - `style`: The style of the density curve
+    /// - **returns** The newly created series
+    pub fn new<T: Into<f64> + Copy, S: Into<ShapeStyle>>(samples: &[T], style: S) -> Self {
+        let samples: Vec<f64> = samples.iter().map(|&x| x.into()).collect();
+        Self {
+            kde: KernelDensity::new(&samples, Bandwidth::Silverman),
+            samples,
+            line_style: style.into(),
+            area_style: None,
+            range: None,
+            resolution: 200,
+            state: 0,
+            _p: core::marker::PhantomData,
+        }
+    }
+
+    /// Set the bandwidth selection rule
+    pub fn bandwidth(mut self, bandwidth: Bandwidth) -> Self {
+        self.kde = KernelDensity::new(&self.samples, bandwidth);
+        self
+    }
+
+    /// Fill the area under the density curve with the given style
+    pub fn filled<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.area_style = Some(style.into());
+        self
+    }
+
+    /// Set the x range to evaluate, by default it's the range returned by `KernelDensity::range`
+    pub fn range(mut self, range: Range<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the number of points used to draw the curve
+    pub fn resolution(mut self, count: usize) -> Self {
+        self.resolution = count;
+        self
+    }
+
+    /// Get the underlying kernel density estimation
+    pub fn estimation(&self) -> &KernelDensity {
+        &self.kde
+    }
+
+    fn points(&self) -> Vec<(f64, f64)> {
+        let range = self.range.clone().unwrap_or_else(|| self.kde.range());
+        self.kde.sample(range, self.resolution)
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for DensitySeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        if self.state == 0 {
+            self.state = 1;
+            if let Some(style) = self.area_style.take() {
+                let mut points = self.points();
+                points.push((points[points.len() - 1].0, 0.0));
+                points.push((points[0].0, 0.0));
+                return Some(Polygon::new(points, style).into_dyn());
+            }
+        }
+        if self.state == 1 {
+            self.state = 2;
+            return Some(PathElement::new(self.points(), self.line_style.clone()).into_dyn());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_density_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path.len(), 52);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 50);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(-5.0..5.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                DensitySeries::new(&[-1.0, 0.0, 0.5, 1.0], &RED)
+                    .filled(&RED.mix(0.3))
+                    .range(-5.0..5.0)
+                    .resolution(50),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The iterator adaptor which produces the empirical cumulative distribution function of
+/// the raw samples as a step function.
+///
+/// Each distinct sample value produces a vertical step, so tied samples are merged into a
+/// single step with the combined height. The emitted points are plain `(x, y)` data which
+/// can be fed into any series, for example `LineSeries`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let cdf: Vec<_> = EmpiricalCdf::new(&[3.0, 1.0, 3.0, 2.0]).collect();
+/// assert_eq!(
+///     cdf,
+///     vec![(1.0, 0.0), (1.0, 0.25), (2.0, 0.25), (2.0, 0.5), (3.0, 0.5), (3.0, 1.0)]
+/// );
+/// ```
+pub struct EmpiricalCdf {
+    points: alloc::vec::IntoIter<(f64, f64)>,
+}
+
+impl EmpiricalCdf {
+    fn from_samples<T: Into<f64> + Copy>(samples: &[T], complementary: bool) -> Self {
+        let mut samples: Vec<f64> = samples
+            .iter()
+            .map(|&x| x.into())
+            .filter(|x: &f64| !x.is_nan())
+            .collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let n = samples.len() as f64;
+        let mut points = Vec::with_capacity(samples.len() * 2);
+        let mut idx = 0;
+        while idx < samples.len() {
+            let value = samples[idx];
+            let mut next = idx;
+            while next < samples.len() && samples[next] == value {
+                next += 1;
+            }
+            let (before, after) = (idx as f64 / n, next as f64 / n);
+            if complementary {
+                // Use P(X >= x), which never reaches zero at a sample, so it works with log axes.
+                points.push((value, 1.0 - before));
+                if next < samples.len() {
+                    points.push((samples[next], 1.0 - before));
+                }
+            } else {
+                points.push((value, before));
+                points.push((value, after));
+            }
+            idx = next;
+        }
+
+        Self {
+            points: points.into_iter(),
+        }
+    }
+
+    /// Create the empirical CDF `P(X <= x)` of the samples
+    /// - `samples`: The raw samples, NaN is ignored
+    /// - **returns** The iterator of the step points
+    pub fn new<T: Into<f64> + Copy>(samples: &[T]) -> Self {
+        Self::from_samples(samples, false)
+    }
+
+    /// Create the complementary CDF `P(X >= x)` of the samples. Unlike `1 - P(X <= x)`,
+    /// the value is always positive, thus it can be used with logarithmic axes.
+    /// - `samples`: The raw samples, NaN is ignored
+    /// - **returns** The iterator of the step points
+    pub fn complementary<T: Into<f64> + Copy>(samples: &[T]) -> Self {
+        Self::from_samples(samples, true)
+    }
+}
+
+impl Iterator for EmpiricalCdf {
+    type Item = (f64, f64);
+    fn next(&mut self) -> Option<(f64, f64)> {
+        self.points.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_complementary_cdf() {
+        let ccdf: Vec<_> = EmpiricalCdf::complementary(&[1, 2, 2, 4]).collect();
+        assert_eq!(
+            ccdf,
+            vec![
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (2.0, 0.75),
+                (4.0, 0.75),
+                (4.0, 0.25)
+            ]
+        );
+        assert!(ccdf.iter().all(|(_, y)| *y > 0.0));
+        assert_eq!(EmpiricalCdf::new(&[f64::NAN]).count(), 0);
+    }
+}
@@ -1,13 +1,15 @@
 use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 
 use crate::chart::ChartContext;
 use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::data::{BinNormalization, BinningStrategy, Bins};
 use crate::drawing::DrawingBackend;
 use crate::element::Rectangle;
 use crate::style::{Color, ShapeStyle, GREEN};
+use num_traits::AsPrimitive;
 
 pub trait HistogramType {}
 pub struct Vertical;
@@ -211,3 +213,188 @@ where
         None
     }
 }
+
+type BinStyleFunc<'a> = Box<dyn Fn(&Range<f64>, f64) -> ShapeStyle + 'a>;
+
+/// The histogram series that computes the bins from the raw samples, instead of requiring
+/// the data to be aggregated on a discrete coordinate. This works with the continuous
+/// coordinate, such as `RangedCoordf64`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::data::BinningStrategy;
+///
+/// let samples = [1.0, 1.5, 2.0, 2.1, 2.2, 3.9];
+/// let hist = BinnedHistogram::vertical(&samples, BinningStrategy::Width(1.0));
+/// assert_eq!(hist.bins().values(), &[2.0, 3.0, 1.0]);
+/// ```
+pub struct BinnedHistogram<'a, Tag = Vertical>
+where
+    Tag: HistogramType,
+{
+    bins: Bins,
+    style: BinStyleFunc<'a>,
+    margin: u32,
+    baseline: f64,
+    idx: usize,
+    _p: PhantomData<Tag>,
+}
+
+impl<'a, Tag: HistogramType> BinnedHistogram<'a, Tag> {
+    fn from_bins(bins: Bins) -> Self {
+        Self {
+            bins,
+            style: Box::new(|_, _| GREEN.filled()),
+            margin: 0,
+            baseline: 0.0,
+            idx: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the histogram
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        let style = style.into();
+        self.style = Box::new(move |_, _| style.clone());
+        self
+    }
+
+    /// Set the style of histogram using a lambda function, which takes the range and value of the bin
+    pub fn style_func(mut self, style_func: impl Fn(&Range<f64>, f64) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Set the baseline of the histogram
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the margin for each bar
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Normalize the histogram, e.g. draw the probability density instead of the count
+    pub fn normalize(mut self, method: BinNormalization) -> Self {
+        self.bins = self.bins.normalized(method);
+        self
+    }
+
+    /// Get the bins computed from the samples, which is useful to decide the chart range
+    pub fn bins(&self) -> &Bins {
+        &self.bins
+    }
+
+    fn next_bin(&mut self) -> Option<(Range<f64>, f64, ShapeStyle)> {
+        if self.idx >= self.bins.len() {
+            return None;
+        }
+        let range = self.bins.bin_range(self.idx);
+        let value = self.bins.values()[self.idx];
+        let style = (self.style)(&range, value);
+        self.idx += 1;
+        Some((range, value, style))
+    }
+}
+
+impl<'a> BinnedHistogram<'a, Vertical> {
+    /// Create a new vertical histogram series from the raw samples
+    /// - `samples`: The raw samples
+    /// - `strategy`: The strategy to compute the bins
+    /// - **returns** The newly created histogram series
+    pub fn vertical<T: AsPrimitive<f64>>(samples: &[T], strategy: BinningStrategy) -> Self {
+        Self::from_bins(Bins::new(samples, strategy))
+    }
+
+    /// Create a new vertical histogram series from the weighted samples
+    /// - `samples`: The `(value, weight)` pairs
+    /// - `strategy`: The strategy to compute the bins
+    /// - **returns** The newly created histogram series
+    pub fn vertical_weighted<T: AsPrimitive<f64>, W: AsPrimitive<f64>>(
+        samples: &[(T, W)],
+        strategy: BinningStrategy,
+    ) -> Self {
+        Self::from_bins(Bins::weighted(samples, strategy))
+    }
+}
+
+impl<'a> BinnedHistogram<'a, Horizontal> {
+    /// Create a new horizontal histogram series from the raw samples
+    /// - `samples`: The raw samples
+    /// - `strategy`: The strategy to compute the bins
+    /// - **returns** The newly created histogram series
+    pub fn horizontal<T: AsPrimitive<f64>>(samples: &[T], strategy: BinningStrategy) -> Self {
+        Self::from_bins(Bins::new(samples, strategy))
+    }
+
+    /// Create a new horizontal histogram series from the weighted samples
+    /// - `samples`: The `(value, weight)` pairs
+    /// - `strategy`: The strategy to compute the bins
+    /// - **returns** The newly created histogram series
+    pub fn horizontal_weighted<T: AsPrimitive<f64>, W: AsPrimitive<f64>>(
+        samples: &[(T, W)],
+        strategy: BinningStrategy,
+    ) -> Self {
+        Self::from_bins(Bins::weighted(samples, strategy))
+    }
+}
+
+impl<'a> Iterator for BinnedHistogram<'a, Vertical> {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, value, style) = self.next_bin()?;
+        let mut rect = Rectangle::new([(range.start, value), (range.end, self.baseline)], style);
+        rect.set_margin(0, 0, self.margin, self.margin);
+        Some(rect)
+    }
+}
+
+impl<'a> Iterator for BinnedHistogram<'a, Horizontal> {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, value, style) = self.next_bin()?;
+        let mut rect = Rectangle::new([(value, range.start), (self.baseline, range.end)], style);
+        rect.set_margin(self.margin, self.margin, 0, 0);
+        Some(rect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data::{BinNormalization, BinningStrategy};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_binned_histogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|_, _, fill, _, _| {
+                assert!(fill);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..4.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let samples: Vec<f64> = (0..40).map(|x| x as f64 / 10.0).collect();
+        let hist =
+            BinnedHistogram::vertical(&samples, BinningStrategy::Count(4)).style(RED.filled());
+
+        assert_eq!(hist.bins().values(), &[10.0, 10.0, 10.0, 10.0]);
+        assert_eq!(
+            BinnedHistogram::vertical(&samples, BinningStrategy::Count(4))
+                .normalize(BinNormalization::Probability)
+                .bins()
+                .values(),
+            &[0.25, 0.25, 0.25, 0.25]
+        );
+
+        chart.draw_series(hist).expect("Drawing Error");
+    }
+}
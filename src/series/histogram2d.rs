@@ -0,0 +1,233 @@
+use crate::element::Polygon;
+use crate::style::{Color, ColorMap, LinearColorMap, ShapeStyle, BLUE, WHITE};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy)]
+enum GridShape {
+    Rectangular,
+    Hexagonal,
+}
+
+type CellStyleFunc<'a> = Box<dyn Fn(f64, f64) -> ShapeStyle + 'a>;
+
+/// The series that bins the `(x, y)` samples into a grid and draws each non-empty cell
+/// with a color decided by the number of samples in the cell.
+///
+/// This is useful to visualize a scatter plot with a huge number of overlapping points.
+/// The grid is either rectangular or hexagonal, and the cells are laid out in the guest
+/// coordinate covering the range of the samples.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let samples: Vec<_> = (0..100).map(|x| (x as f64, (x % 10) as f64)).collect();
+/// let hist = Histogram2D::rectangular(samples, (10, 10));
+/// assert_eq!(hist.max_count(), 1.0);
+/// assert_eq!(hist.cell_count(), 100);
+/// ```
+pub struct Histogram2D<'a> {
+    shape: GridShape,
+    origin: (f64, f64),
+    cell_size: (f64, f64),
+    cells: std::vec::IntoIter<((i64, i64), f64)>,
+    max: f64,
+    style: CellStyleFunc<'a>,
+}
+
+impl<'a> Histogram2D<'a> {
+    fn new<I: IntoIterator<Item = (f64, f64)>>(
+        iter: I,
+        shape: GridShape,
+        bins: (usize, usize),
+    ) -> Self {
+        let samples: Vec<(f64, f64)> = iter
+            .into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+
+        let (mut x0, mut x1, mut y0, mut y1) = (
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        );
+        for &(x, y) in samples.iter() {
+            x0 = x0.min(x);
+            x1 = x1.max(x);
+            y0 = y0.min(y);
+            y1 = y1.max(y);
+        }
+
+        let cell_size = |lo: f64, hi: f64, n: usize| {
+            let size = (hi - lo) / n.max(1) as f64;
+            if size > 0.0 && size.is_finite() {
+                size
+            } else {
+                1.0
+            }
+        };
+
+        let size = (cell_size(x0, x1, bins.0), cell_size(y0, y1, bins.1));
+
+        let mut buffer = HashMap::<(i64, i64), f64>::new();
+        for (x, y) in samples {
+            let ix = (x - x0) / size.0;
+            let iy = (y - y0) / size.1;
+            let key = match shape {
+                GridShape::Rectangular => (
+                    (ix.floor() as i64).min(bins.0.max(1) as i64 - 1),
+                    (iy.floor() as i64).min(bins.1.max(1) as i64 - 1),
+                ),
+                GridShape::Hexagonal => {
+                    // The hexagon centers form two interleaved rectangular lattices,
+                    // the first at integer positions and the second at half-integer positions.
+                    // Odd (x, y) keys denote the second lattice.
+                    let (ix1, iy1) = (ix.round(), iy.round());
+                    let (ix2, iy2) = (ix.floor(), iy.floor());
+                    let d1 = (ix - ix1).powi(2) + 3.0 * (iy - iy1).powi(2);
+                    let d2 = (ix - ix2 - 0.5).powi(2) + 3.0 * (iy - iy2 - 0.5).powi(2);
+                    if d1 <= d2 {
+                        (ix1 as i64 * 2, iy1 as i64 * 2)
+                    } else {
+                        (ix2 as i64 * 2 + 1, iy2 as i64 * 2 + 1)
+                    }
+                }
+            };
+            *buffer.entry(key).or_insert(0.0) += 1.0;
+        }
+
+        let max = buffer.values().copied().fold(0.0, f64::max);
+        let color_map = LinearColorMap::new(&[WHITE, BLUE]);
+        let mut cells: Vec<_> = buffer.into_iter().collect();
+        cells.sort_by_key(|cell| cell.0);
+
+        Self {
+            shape,
+            origin: (x0, y0),
+            cell_size: size,
+            cells: cells.into_iter(),
+            max,
+            style: Box::new(move |count, max| color_map.get_color(count / max).filled()),
+        }
+    }
+
+    /// Create a 2D histogram with rectangular cells
+    /// - `iter`: The iterator of samples
+    /// - `bins`: The number of bins on the x and y axis
+    /// - **returns** The newly created series
+    pub fn rectangular<I: IntoIterator<Item = (f64, f64)>>(iter: I, bins: (usize, usize)) -> Self {
+        Self::new(iter, GridShape::Rectangular, bins)
+    }
+
+    /// Create a 2D histogram with hexagonal cells, a.k.a. hexbin
+    /// - `iter`: The iterator of samples
+    /// - `grid_size`: The number of hexagons along the x axis
+    /// - **returns** The newly created series
+    pub fn hexagonal<I: IntoIterator<Item = (f64, f64)>>(iter: I, grid_size: usize) -> Self {
+        let grid_size = grid_size.max(1);
+        let y_size = ((grid_size as f64 / 3f64.sqrt()) as usize).max(1);
+        Self::new(iter, GridShape::Hexagonal, (grid_size, y_size))
+    }
+
+    /// Set the color map of the cells, the counts from 0 to the maximum count are mapped to
+    /// the color map. The default is `LinearColorMap::new(&[WHITE, BLUE])`, the same color map
+    /// is given to the `Colorbar` of the range `0.0..max_count()`.
+    pub fn color_map<M: ColorMap + 'a>(self, color_map: M) -> Self {
+        self.style_func(move |count, max| color_map.get_color(count / max).filled())
+    }
+
+    /// Set the style of the cells with a function of the cell count and the maximum count
+    pub fn style_func(mut self, style_func: impl Fn(f64, f64) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Get the maximum number of samples in a cell
+    pub fn max_count(&self) -> f64 {
+        self.max
+    }
+
+    /// Get the number of non-empty cells
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<'a> Iterator for Histogram2D<'a> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((kx, ky), count) = self.cells.next()?;
+        let (x0, y0) = self.origin;
+        let (sx, sy) = self.cell_size;
+        let points = match self.shape {
+            GridShape::Rectangular => {
+                let (l, b) = (x0 + kx as f64 * sx, y0 + ky as f64 * sy);
+                vec![(l, b), (l + sx, b), (l + sx, b + sy), (l, b + sy)]
+            }
+            GridShape::Hexagonal => {
+                let (cx, cy) = (x0 + kx as f64 * sx / 2.0, y0 + ky as f64 * sy / 2.0);
+                [
+                    (0.5, -0.5),
+                    (0.5, 0.5),
+                    (0.0, 1.0),
+                    (-0.5, 0.5),
+                    (-0.5, -0.5),
+                    (0.0, -1.0),
+                ]
+                .iter()
+                .map(|(dx, dy)| (cx + dx * sx, cy + dy * sy / 3.0))
+                .collect()
+            }
+        };
+        Some(Polygon::new(points, (self.style)(count, self.max)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rectangular_histogram2d() {
+        let samples = vec![(0.0, 0.0), (0.1, 0.1), (1.0, 1.0), (2.0, 2.0)];
+        let hist = Histogram2D::rectangular(samples, (2, 2));
+        assert_eq!(hist.cell_count(), 2);
+        assert_eq!(hist.max_count(), 2.0);
+        let cells: Vec<_> = hist.collect();
+        assert_eq!(cells.len(), 2);
+    }
+
+    #[test]
+    fn test_histogram2d_color_map() {
+        use crate::prelude::*;
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, Viridis.get_color(1.0).to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..2.0)
+            .expect("Build chart error");
+        let samples = vec![(0.0, 0.0), (0.1, 0.1), (1.0, 1.0), (2.0, 2.0)];
+        chart
+            .draw_series(Histogram2D::rectangular(samples, (2, 2)).color_map(Viridis))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_hexagonal_histogram2d() {
+        let samples: Vec<_> = (0..1000)
+            .map(|i| ((i % 37) as f64, (i % 23) as f64))
+            .collect();
+        let hist = Histogram2D::hexagonal(samples, 10);
+        assert!(hist.cell_count() > 10);
+        assert_eq!(
+            hist.cells.as_slice().iter().map(|(_, c)| c).sum::<f64>(),
+            1000.0
+        );
+        assert_eq!(Histogram2D::hexagonal(vec![], 10).cell_count(), 0);
+    }
+}
@@ -1,54 +1,292 @@
+use crate::coord::FiniteValue;
 use crate::drawing::DrawingBackend;
 use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::ShapeStyle;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+use num_traits::NumCast;
 
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
+///
+/// The line can have gaps, such as the dropouts of a sensor, which are made either with the
+/// missing points of `LineSeries::with_gaps`, or with the NaN and infinite values, which are
+/// dropped. The line is drawn as a path for each part between the gaps.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// let readings = vec![Some(1.0), Some(3.0), None, Some(4.0), Some(2.0)];
+/// chart
+///     .draw_series(LineSeries::with_gaps(
+///         readings
+///             .into_iter()
+///             .enumerate()
+///             .map(|(x, y)| y.map(|y| (x as f64, y))),
+///         &RED,
+///     ))
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(
+///         vec![(0.0, 5.0), (1.0, f64::NAN), (2.0, 6.0)],
+///         &BLUE,
+///     ))
+///     .unwrap();
+/// ```
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
     data: Vec<Coord>,
+    // The indices of the points in `data` starting a new part of the line after a gap
+    gaps: Vec<usize>,
+    paths: Option<Vec<Vec<Coord>>>,
     point_idx: usize,
     point_size: u32,
+    downsample: bool,
     phantom: PhantomData<DB>,
 }
 
 impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coord> {
     type Item = DynElement<'static, DB, Coord>;
     fn next(&mut self) -> Option<Self::Item> {
+        if self.point_size > 0 && self.point_idx < self.data.len() {
+            let idx = self.point_idx;
+            self.point_idx += 1;
+            return Some(
+                Circle::new(self.data[idx].clone(), self.point_size, self.style.clone()).into_dyn(),
+            );
+        }
         if !self.data.is_empty() {
-            if self.point_size > 0 && self.point_idx < self.data.len() {
-                let idx = self.point_idx;
-                self.point_idx += 1;
-                return Some(
-                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
-                        .into_dyn(),
-                );
-            }
-            let mut data = vec![];
-            std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style.clone()).into_dyn())
-        } else {
-            None
+            let mut paths = match self.paths.take() {
+                Some(paths) => paths,
+                None => self.parts().iter().map(|part| part.to_vec()).collect(),
+            };
+            // The paths are taken from the back
+            paths.reverse();
+            self.paths = Some(paths);
+            self.data.clear();
         }
+        let path = PathElement::new(self.paths.as_mut()?.pop()?, self.style.clone());
+        if self.downsample {
+            return Some(path.downsample().into_dyn());
+        }
+        Some(path.into_dyn())
     }
 }
 
-impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
+impl<DB: DrawingBackend, Coord: FiniteValue> LineSeries<DB, Coord> {
+    /// Create a line series connecting all the points. The points with a NaN or infinite value
+    /// are dropped and the line is broken there, so the missing values of the data appear as
+    /// the gaps of the line.
+    /// - `iter`: The points of the line
+    /// - `style`: The style of the line
+    /// - **returns** The line series
     pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
-        Self {
+        Self::with_gaps(iter.into_iter().map(Some), style)
+    }
+
+    /// Create a line series with the missing points, the line is broken at each `None` instead
+    /// of connecting the points around it, as well as at the NaN and infinite values
+    /// - `iter`: The points of the line, `None` for the missing points
+    /// - `style`: The style of the line
+    /// - **returns** The line series with the gaps
+    pub fn with_gaps<I: IntoIterator<Item = Option<Coord>>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+    ) -> Self {
+        let mut series = Self {
             style: style.into(),
-            data: iter.into_iter().collect(),
+            data: vec![],
+            gaps: vec![],
+            paths: None,
             point_size: 0,
             point_idx: 0,
+            downsample: false,
             phantom: PhantomData,
+        };
+        for point in iter {
+            match point {
+                Some(point) if point.is_finite_value() => series.data.push(point),
+                _ => series.break_line(),
+            }
         }
+        series
     }
+}
 
+impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
     pub fn point_size(mut self, size: u32) -> Self {
         self.point_size = size;
         self
     }
+
+    /// Downsample the line when it's drawn, to a few points for each pixel column of the
+    /// plotting area, see `PathElement::downsample`. It keeps the huge series, such as the
+    /// waveforms of millions of samples, fast to draw and the output files small, while the
+    /// line looks the same.
+    ///
+    /// - **returns** The downsampled line series
+    pub fn downsample(mut self) -> Self {
+        self.downsample = true;
+        self
+    }
+
+    // Start a new part of the line at the next point
+    fn break_line(&mut self) {
+        let next = self.data.len();
+        if next > 0 && self.gaps.last() != Some(&next) {
+            self.gaps.push(next);
+        }
+    }
+
+    // The parts of the line between the gaps
+    fn parts(&self) -> Vec<&[Coord]> {
+        let mut parts = vec![];
+        let mut begin = 0;
+        for &end in self.gaps.iter().chain(core::iter::once(&self.data.len())) {
+            if end > begin {
+                parts.push(&self.data[begin..end]);
+            }
+            begin = end;
+        }
+        parts
+    }
+}
+
+impl<DB: DrawingBackend, X: NumCast + Clone, Y: NumCast + Clone> LineSeries<DB, (X, Y)> {
+    /// Render the line as a smooth cardinal spline passing through all the data points,
+    /// instead of straight segments. The point markers are still drawn on the original data.
+    /// Each part of a line with gaps is a separated spline.
+    ///
+    /// - `tension`: The tension of the curve in `[0, 1]`, 0 gives a Catmull-Rom spline and 1 gives straight segments
+    /// - `samples`: The number of points sampled for each segment between two data points
+    /// - **returns** The line series with spline interpolation
+    pub fn spline(mut self, tension: f64, samples: usize) -> Self {
+        let paths = self
+            .parts()
+            .into_iter()
+            .map(|part| spline_path(part, tension, samples))
+            .collect::<Option<Vec<_>>>();
+        if paths.is_some() {
+            self.paths = paths;
+        }
+        self
+    }
+}
+
+// Sample the spline through the points, or None if a point isn't a number
+fn spline_path<X: NumCast + Clone, Y: NumCast + Clone>(
+    data: &[(X, Y)],
+    tension: f64,
+    samples: usize,
+) -> Option<Vec<(X, Y)>> {
+    let points: Vec<(f64, f64)> = data
+        .iter()
+        .map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+        .collect::<Option<_>>()?;
+
+    if points.len() < 3 {
+        return Some(data.to_vec());
+    }
+
+    let scale = (1.0 - tension.clamp(0.0, 1.0)) / 2.0;
+    let samples = samples.max(1);
+    let tangent = |i: usize| {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(points.len() - 1)];
+        ((next.0 - prev.0) * scale, (next.1 - prev.1) * scale)
+    };
+
+    let mut path = vec![data[0].clone()];
+    for i in 0..points.len() - 1 {
+        let (p0, p1) = (points[i], points[i + 1]);
+        let (m0, m1) = (tangent(i), tangent(i + 1));
+        for step in 1..samples {
+            let t = step as f64 / samples as f64;
+            let (t2, t3) = (t * t, t * t * t);
+            let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+            let h10 = t3 - 2.0 * t2 + t;
+            let h01 = -2.0 * t3 + 3.0 * t2;
+            let h11 = t3 - t2;
+            let x = h00 * p0.0 + h10 * m0.0 + h01 * p1.0 + h11 * m1.0;
+            let y = h00 * p0.1 + h10 * m0.1 + h01 * p1.1 + h11 * m1.1;
+            if let (Some(x), Some(y)) = (X::from(x), Y::from(y)) {
+                path.push((x, y));
+            }
+        }
+        path.push(data[i + 1].clone());
+    }
+    Some(path)
+}
+
+/// The line series which pulls the points lazily from an iterator, and draws the line as
+/// consecutive paths of at most `chunk_size` points. Unlike `LineSeries`, it never collects all
+/// the points, so it can draw a generator or a stream of any length within bounded memory.
+/// The last point of a chunk is the first point of the next one, so the line is continuous.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..1.0, -1.0..1.0)
+///     .unwrap();
+/// let points = (0..100_000).map(|i| {
+///     let x = i as f64 / 100_000.0;
+///     (x, (x * 50.0).sin())
+/// });
+/// chart
+///     .draw_series_chunked(ChunkedLineSeries::new(points, &RED, 1024), 16)
+///     .unwrap();
+/// ```
+pub struct ChunkedLineSeries<I: Iterator> {
+    points: I,
+    style: ShapeStyle,
+    chunk_size: usize,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator> ChunkedLineSeries<I> {
+    /// Create a new chunked line series
+    /// - `points`: The points of the line
+    /// - `style`: The style of the line
+    /// - `chunk_size`: The maximum number of points of a path, at least 2
+    pub fn new<T: IntoIterator<IntoIter = I>, S: Into<ShapeStyle>>(
+        points: T,
+        style: S,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            points: points.into_iter(),
+            style: style.into(),
+            chunk_size: chunk_size.max(2),
+            last: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ChunkedLineSeries<I>
+where
+    I::Item: Clone,
+{
+    type Item = PathElement<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        chunk.extend(self.last.take());
+        let continued = !chunk.is_empty();
+        chunk.extend(self.points.by_ref().take(self.chunk_size - chunk.len()));
+        if chunk.is_empty() || (continued && chunk.len() == 1) {
+            return None;
+        }
+        self.last = chunk.last().cloned();
+        Some(PathElement::new(chunk, self.style.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +321,141 @@ mod test {
             ))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_spline_line_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 41);
+                assert_eq!(path[0], (8, 90));
+                assert_eq!(path[40], (91, 90));
+                // The curve should be smooth around the peak at (50, 100)
+                assert!(path[15].1 < 50 && path[25].1 < 50);
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 5);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(-10.0..110.0, -10.0..110.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                LineSeries::new(
+                    vec![
+                        (0.0, 0.0),
+                        (25.0, 50.0),
+                        (50.0, 100.0),
+                        (75.0, 50.0),
+                        (100.0, 0.0),
+                    ],
+                    &RED,
+                )
+                .point_size(2)
+                .spline(0.0, 10),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_gaps() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // Both of the series are drawn as the same two parts
+            let parts = [vec![(20, 179), (40, 159)], vec![(120, 79), (140, 59)]];
+            let mut idx = 0;
+            m.check_draw_path(move |_, _, path| {
+                assert_eq!(path, parts[idx % 2]);
+                idx += 1;
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 4);
+                assert_eq!(b.num_draw_circle_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = vec![
+            Some((1.0, 1.0)),
+            Some((2.0, 2.0)),
+            None,
+            None,
+            Some((6.0, 6.0)),
+            Some((7.0, 7.0)),
+        ];
+        chart
+            .draw_series(LineSeries::with_gaps(points, &RED).point_size(1))
+            .expect("Drawing Error");
+
+        // The NaN breaks the line as the missing points do
+        let points = vec![
+            (1.0, 1.0),
+            (2.0, 2.0),
+            (3.0, f64::NAN),
+            (6.0, 6.0),
+            (7.0, 7.0),
+        ];
+        chart
+            .draw_series(LineSeries::new(points, &RED).spline(0.5, 1))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_downsampled_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() <= 4 * 201);
+                assert!(path.windows(2).all(|w| w[0].0 <= w[1].0));
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..1.0, -1.0..1.0)
+            .expect("Build chart error");
+
+        let points = (0..1_000_000).map(|x| (x as f64 / 1_000_000.0, (x as f64).sin()));
+        chart
+            .draw_series(LineSeries::new(points, &RED).downsample())
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_chunked_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let mut next = 0;
+            m.check_draw_path(move |_, _, path| {
+                // The chunks overlap at the joints
+                assert_eq!(path[0], (next * 2, 200 - next * 2 - 1));
+                next += path.len() as i32 - 1;
+                assert!(path.len() <= 10);
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 11);
+                assert_eq!(b.num_present_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100, 0..100)
+            .expect("Build chart error");
+
+        chart
+            .draw_series_chunked(
+                ChunkedLineSeries::new((0..100).map(|x| (x, x)), &RED, 10),
+                3,
+            )
+            .expect("Drawing Error");
+    }
 }
@@ -12,18 +12,46 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "band_series")]
+mod band_series;
+#[cfg(feature = "density_series")]
+mod density_series;
+mod ecdf;
 #[cfg(feature = "histogram")]
 mod histogram;
+#[cfg(feature = "histogram")]
+mod histogram2d;
 #[cfg(feature = "line_series")]
 mod line_series;
+mod moving_average;
 #[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(feature = "histogram")]
+mod rose_histogram;
+#[cfg(feature = "spectrogram")]
+mod spectrogram;
+#[cfg(feature = "trend_line_series")]
+mod trend_line_series;
 
 #[cfg(feature = "area_series")]
 pub use area_series::AreaSeries;
+#[cfg(feature = "band_series")]
+pub use band_series::BandSeries;
+#[cfg(feature = "density_series")]
+pub use density_series::DensitySeries;
+pub use ecdf::EmpiricalCdf;
 #[cfg(feature = "histogram")]
-pub use histogram::Histogram;
+pub use histogram::{BinnedHistogram, Histogram};
+#[cfg(feature = "histogram")]
+pub use histogram2d::Histogram2D;
 #[cfg(feature = "line_series")]
-pub use line_series::LineSeries;
+pub use line_series::{ChunkedLineSeries, LineSeries};
+pub use moving_average::MovingAverage;
 #[cfg(feature = "point_series")]
-pub use point_series::PointSeries;
+pub use point_series::{DownsampledPoints, PointSeries};
+#[cfg(feature = "histogram")]
+pub use rose_histogram::RoseHistogram;
+#[cfg(feature = "spectrogram")]
+pub use spectrogram::{Spectrogram, StripChart};
+#[cfg(feature = "trend_line_series")]
+pub use trend_line_series::TrendLineSeries;
@@ -0,0 +1,131 @@
+use crate::data::SlidingWindow;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::collections::VecDeque;
+use num_traits::NumCast;
+
+#[derive(Clone, Debug)]
+enum SmoothingMethod {
+    // The rolling sum of the window is kept, so the mean is updated in constant time
+    Mean(SlidingWindow),
+    Median(usize),
+    Exponential(f64),
+}
+
+/// The iterator adaptor which smooths a sequence of data points.
+///
+/// This takes an iterator of `(x, y)` points, and emits the same x values with
+/// the smoothed y values. The rolling window is a trailing window, so the first few
+/// points are computed from a partially filled window. As the emitted values are
+/// plain data points, the result can be fed into any series, for example:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![(0, 1.0), (1, 3.0), (2, 2.0), (3, 6.0)];
+/// let smoothed: Vec<_> = MovingAverage::mean(data, 2).collect();
+/// assert_eq!(smoothed, vec![(0, 1.0), (1, 2.0), (2, 2.5), (3, 4.0)]);
+/// ```
+pub struct MovingAverage<I: Iterator<Item = (X, Y)>, X, Y> {
+    iter: I,
+    method: SmoothingMethod,
+    window: VecDeque<f64>,
+    last: Option<f64>,
+}
+
+impl<I: Iterator<Item = (X, Y)>, X, Y: NumCast> MovingAverage<I, X, Y> {
+    fn new<T: IntoIterator<IntoIter = I>>(iter: T, method: SmoothingMethod) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            method,
+            window: VecDeque::new(),
+            last: None,
+        }
+    }
+
+    /// Smooth the data with the rolling mean of the last `window` points
+    pub fn mean<T: IntoIterator<IntoIter = I>>(iter: T, window: usize) -> Self {
+        Self::new(iter, SmoothingMethod::Mean(SlidingWindow::new(window)))
+    }
+
+    /// Smooth the data with the rolling median of the last `window` points
+    pub fn median<T: IntoIterator<IntoIter = I>>(iter: T, window: usize) -> Self {
+        Self::new(iter, SmoothingMethod::Median(window.max(1)))
+    }
+
+    /// Smooth the data with the exponential moving average
+    /// - `alpha`: The smoothing factor in `(0, 1]`, larger value gives more weight to recent points
+    pub fn exponential<T: IntoIterator<IntoIter = I>>(iter: T, alpha: f64) -> Self {
+        Self::new(
+            iter,
+            SmoothingMethod::Exponential(alpha.clamp(f64::EPSILON, 1.0)),
+        )
+    }
+
+    fn push_window(&mut self, value: f64, size: usize) {
+        self.window.push_back(value);
+        while self.window.len() > size {
+            self.window.pop_front();
+        }
+    }
+}
+
+impl<I: Iterator<Item = (X, Y)>, X, Y: NumCast> Iterator for MovingAverage<I, X, Y> {
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<(X, Y)> {
+        loop {
+            let (x, y) = self.iter.next()?;
+            let value = match y.to_f64() {
+                Some(value) if value.is_finite() => value,
+                _ => continue,
+            };
+
+            let smoothed = match &mut self.method {
+                SmoothingMethod::Mean(window) => {
+                    window.push(value);
+                    window.mean().unwrap_or(value)
+                }
+                &mut SmoothingMethod::Median(size) => {
+                    self.push_window(value, size);
+                    let mut sorted: Vec<_> = self.window.iter().copied().collect();
+                    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    let mid = sorted.len() / 2;
+                    if sorted.len() % 2 == 0 {
+                        (sorted[mid - 1] + sorted[mid]) / 2.0
+                    } else {
+                        sorted[mid]
+                    }
+                }
+                &mut SmoothingMethod::Exponential(alpha) => match self.last {
+                    Some(last) => alpha * value + (1.0 - alpha) * last,
+                    None => value,
+                },
+            };
+
+            self.last = Some(smoothed);
+
+            if let Some(y) = Y::from(smoothed) {
+                return Some((x, y));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_median() {
+        let data = vec![(0, 1), (1, 9), (2, 2), (3, 3), (4, 100)];
+        let result: Vec<_> = MovingAverage::median(data, 3).collect();
+        assert_eq!(result, vec![(0, 1), (1, 5), (2, 2), (3, 3), (4, 3)]);
+    }
+
+    #[test]
+    fn test_exponential_moving_average() {
+        let data = vec![(0, 2.0), (1, 4.0), (2, f64::NAN), (3, 8.0)];
+        let result: Vec<_> = MovingAverage::exponential(data, 0.5).collect();
+        assert_eq!(result, vec![(0, 2.0), (1, 3.0), (3, 5.5)]);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::element::PointElement;
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{reduce_columns_by, Drawable, PointCollection, PointElement};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::style::{ShapeStyle, SizeDesc};
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
@@ -59,3 +62,116 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
         }
     }
 }
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
+    PointSeries<'a, Coord, I, E, Size>
+{
+    /// Downsample the points when they're drawn, to the first, the last, the lowest and the
+    /// highest points of each run of points in the same pixel column of the plotting area, as
+    /// `PathElement::downsample` does for the lines. So a series of millions of samples is
+    /// drawn with a few points per pixel column, and looks the same as the full series.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buf = String::new();
+    /// {
+    ///     let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+    ///     let mut chart = ChartBuilder::on(&root)
+    ///         .build_ranged(0.0..1.0, -1.0..1.0)
+    ///         .unwrap();
+    ///     let samples = (0..1_000_000).map(|i| {
+    ///         let x = i as f64 / 1_000_000.0;
+    ///         (x, (x * 100.0).sin())
+    ///     });
+    ///     let series = PointSeries::<_, _, Circle<_, _>, _>::new(samples, 1, &RED);
+    ///     chart.draw_series(series.downsample()).unwrap();
+    /// }
+    /// assert!(buf.matches("<circle").count() <= 4 * 300);
+    /// ```
+    ///
+    /// - **returns** The element drawing the downsampled points
+    pub fn downsample(self) -> DownsampledPoints<'a, Coord, E, Size> {
+        DownsampledPoints {
+            points: self.data_iter.collect(),
+            size: self.size,
+            style: self.style,
+            make_point: self.make_point,
+        }
+    }
+}
+
+/// The points of a `PointSeries` which are downsampled to the pixel columns when they're
+/// drawn, see `PointSeries::downsample`. It's a single element, drawn as the point elements
+/// which are kept.
+pub struct DownsampledPoints<'a, Coord, E, Size: SizeDesc + Clone> {
+    points: Vec<Coord>,
+    size: Size,
+    style: ShapeStyle,
+    make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+}
+
+impl<'a, Coord, E, Size: SizeDesc + Clone> IntoIterator for DownsampledPoints<'a, Coord, E, Size> {
+    type Item = Self;
+    type IntoIter = core::iter::Once<Self>;
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self)
+    }
+}
+
+impl<'a, 'b, Coord, E, Size: SizeDesc + Clone> PointCollection<'b, Coord>
+    for &'b DownsampledPoints<'a, Coord, E, Size>
+{
+    type Borrow = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord: Clone, E: Drawable<DB>, Size: SizeDesc + Clone, DB: DrawingBackend> Drawable<DB>
+    for DownsampledPoints<'a, Coord, E, Size>
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        for (idx, point) in reduce_columns_by(pos.enumerate(), |(_, point)| *point) {
+            let element = (self.make_point)(
+                self.points[idx].clone(),
+                self.size.clone(),
+                self.style.clone(),
+            );
+            element.draw(core::iter::once(point), backend, parent_dim)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_downsampled_point_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // Each pixel column keeps the first, the lowest, the highest and the last points
+                assert!(b.num_draw_circle_call > 200);
+                assert!(b.num_draw_circle_call <= 4 * 200);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..1.0, -1.0..1.0)
+            .expect("Build chart error");
+
+        let points = (0..100_000).map(|x| (x as f64 / 100_000.0, (x as f64).sin()));
+        let series = PointSeries::<_, _, Circle<_, _>, _>::new(points, 1, &RED);
+        chart
+            .draw_series(series.downsample())
+            .expect("Drawing Error");
+    }
+}
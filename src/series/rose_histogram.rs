@@ -0,0 +1,147 @@
+use crate::element::Polygon;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+type WedgeStyleFunc<'a> = Box<dyn Fn(usize) -> ShapeStyle + 'a>;
+
+/// The polar histogram, a.k.a. the rose diagram or wind rose.
+///
+/// The angles are binned into sectors with the given angular width, and each sector is drawn
+/// as a wedge whose radius is the number of samples in the sector. Samples can be tagged with
+/// a category, in which case the wedges of different categories are stacked radially.
+///
+/// Angles are in degrees, following the compass convention: 0 degree points up and the angle
+/// increases clockwise. The wedges are polygons in the cartesian guest coordinate centered at
+/// the origin, so the chart should be built with symmetric ranges, for example
+/// `-r..r` on both axes where `r = hist.max_radius()`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let hist = RoseHistogram::new(90.0).data(vec![10.0, 20.0, 100.0, 350.0]);
+/// assert_eq!(hist.max_radius(), 2.0);
+/// assert_eq!(hist.wedge_count(), 3);
+/// ```
+pub struct RoseHistogram<'a> {
+    bin_width: f64,
+    // counts[bin][category]
+    counts: Vec<Vec<f64>>,
+    style: WedgeStyleFunc<'a>,
+    wedges: alloc::vec::IntoIter<(usize, usize, f64, f64)>,
+}
+
+impl<'a> RoseHistogram<'a> {
+    /// Create a new empty rose histogram
+    /// - `bin_width`: The angular width of each sector in degrees
+    pub fn new(bin_width: f64) -> Self {
+        let bin_width = if bin_width > 0.0 && bin_width <= 360.0 {
+            bin_width
+        } else {
+            30.0
+        };
+        let bins = (360.0 / bin_width).ceil() as usize;
+        Self {
+            bin_width,
+            counts: vec![vec![]; bins],
+            style: Box::new(|idx| Palette99::pick(idx).filled()),
+            wedges: vec![].into_iter(),
+        }
+    }
+
+    /// Add the angles, in degrees, of the samples that belong to the first category
+    pub fn data<I: IntoIterator<Item = f64>>(self, iter: I) -> Self {
+        self.stacked_data(iter.into_iter().map(|angle| (angle, 0)))
+    }
+
+    /// Add the `(angle, category)` pairs of the samples, the wedges of different
+    /// categories are stacked in the order of the category index
+    pub fn stacked_data<I: IntoIterator<Item = (f64, usize)>>(mut self, iter: I) -> Self {
+        for (angle, category) in iter {
+            if !angle.is_finite() {
+                continue;
+            }
+            let bin = (angle.rem_euclid(360.0) / self.bin_width) as usize % self.counts.len();
+            let bin = &mut self.counts[bin];
+            if bin.len() <= category {
+                bin.resize(category + 1, 0.0);
+            }
+            bin[category] += 1.0;
+        }
+        self.update_wedges();
+        self
+    }
+
+    /// Set the style of the wedges with a function of the category index
+    pub fn style_func(mut self, style_func: impl Fn(usize) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Get the radius of the largest stacked sector, which is useful to decide the chart range
+    pub fn max_radius(&self) -> f64 {
+        self.counts
+            .iter()
+            .map(|bin| bin.iter().sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Get the number of non-empty wedges
+    pub fn wedge_count(&self) -> usize {
+        self.wedges.len()
+    }
+
+    fn update_wedges(&mut self) {
+        let mut wedges = vec![];
+        for (bin, categories) in self.counts.iter().enumerate() {
+            let mut base = 0.0;
+            for (category, value) in categories.iter().enumerate() {
+                if *value > 0.0 {
+                    wedges.push((bin, category, base, base + value));
+                }
+                base += value;
+            }
+        }
+        self.wedges = wedges.into_iter();
+    }
+}
+
+impl<'a> Iterator for RoseHistogram<'a> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (bin, category, inner, outer) = self.wedges.next()?;
+        let start = bin as f64 * self.bin_width;
+        let end = (start + self.bin_width).min(360.0);
+        let steps = ((end - start) / 2.0).ceil().max(1.0) as usize;
+        let arc = |r: f64, step: usize| {
+            let angle = (start + (end - start) * step as f64 / steps as f64).to_radians();
+            (r * angle.sin(), r * angle.cos())
+        };
+        let mut points: Vec<_> = (0..=steps).map(|step| arc(outer, step)).collect();
+        if inner > 0.0 {
+            points.extend((0..=steps).rev().map(|step| arc(inner, step)));
+        } else {
+            points.push((0.0, 0.0));
+        }
+        Some(Polygon::new(points, (self.style)(category)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stacked_rose_histogram() {
+        let hist = RoseHistogram::new(45.0).stacked_data(vec![
+            (0.0, 0),
+            (10.0, 1),
+            (-10.0, 0),
+            (720.0, 1),
+            (f64::NAN, 0),
+        ]);
+        assert_eq!(hist.max_radius(), 3.0);
+        assert_eq!(hist.wedge_count(), 3);
+
+        let wedges: Vec<_> = hist.collect();
+        assert_eq!(wedges.len(), 3);
+    }
+}
@@ -0,0 +1,252 @@
+use alloc::collections::VecDeque;
+use core::ops::Range;
+
+use crate::element::Rectangle;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::style::{Color, ColorMap, Heat, ShapeStyle};
+
+type MagnitudeStyleFunc<'a> = Box<dyn Fn(f64) -> ShapeStyle + 'a>;
+
+/// The spectrogram series, which renders a time by frequency magnitude matrix as a grid
+/// of colored cells.
+///
+/// Each column of the matrix is the spectrum at a time step, and each value of the column is
+/// the magnitude of a frequency bin. The magnitudes are normalized into `[0, 1]` by the
+/// magnitude range before they are passed to the style function.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let spectrogram = Spectrogram::new(vec![vec![0.0, 1.0], vec![2.0, 3.0]])
+///     .time_axis(0.0, 0.5)
+///     .frequency_axis(0.0, 100.0);
+/// assert_eq!(spectrogram.time_range(), 0.0..1.0);
+/// assert_eq!(spectrogram.frequency_range(), 0.0..200.0);
+/// assert_eq!(spectrogram.count(), 4);
+/// ```
+pub struct Spectrogram<'a> {
+    columns: Vec<Vec<f64>>,
+    time: (f64, f64),
+    frequency: (f64, f64),
+    magnitude: Option<(f64, f64)>,
+    style: MagnitudeStyleFunc<'a>,
+    idx: (usize, usize),
+}
+
+impl<'a> Spectrogram<'a> {
+    /// Create a new spectrogram series
+    /// - `columns`: The spectra, one for each time step
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = Vec<f64>>>(columns: I) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            time: (0.0, 1.0),
+            frequency: (0.0, 1.0),
+            magnitude: None,
+            style: Box::new(|value| Heat.get_color(value).filled()),
+            idx: (0, 0),
+        }
+    }
+
+    /// Set the time of the first column and the time between two columns
+    pub fn time_axis(mut self, start: f64, step: f64) -> Self {
+        self.time = (start, step);
+        self
+    }
+
+    /// Set the frequency of the first bin and the width of each frequency bin
+    pub fn frequency_axis(mut self, start: f64, step: f64) -> Self {
+        self.frequency = (start, step);
+        self
+    }
+
+    /// Set the magnitude range mapped to the ends of the color ramp, by default it's the
+    /// range of the data
+    pub fn magnitude_range(mut self, min: f64, max: f64) -> Self {
+        self.magnitude = Some((min, max));
+        self
+    }
+
+    /// Convert the magnitudes to decibels with `20 * log10(m)`, the magnitudes which are not
+    /// positive are clamped to -120 dB
+    pub fn decibel(mut self) -> Self {
+        for value in self.columns.iter_mut().flat_map(|c| c.iter_mut()) {
+            if value.is_nan() {
+                continue;
+            }
+            *value = if *value > 0.0 {
+                (20.0 * value.log10()).max(-120.0)
+            } else {
+                -120.0
+            };
+        }
+        self
+    }
+
+    /// Set the color map of the cells, the default is `Heat`. The same color map is given to
+    /// the `Colorbar` of the magnitude range.
+    pub fn color_map<M: ColorMap + 'a>(self, color_map: M) -> Self {
+        self.style_func(move |value| color_map.get_color(value).filled())
+    }
+
+    /// Set the style of the cells with a function of the normalized magnitude
+    pub fn style_func(mut self, style_func: impl Fn(f64) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Get the time range covered by the series
+    pub fn time_range(&self) -> Range<f64> {
+        let (start, step) = self.time;
+        start..start + step * self.columns.len() as f64
+    }
+
+    /// Get the frequency range covered by the series
+    pub fn frequency_range(&self) -> Range<f64> {
+        let (start, step) = self.frequency;
+        let bins = self.columns.iter().map(Vec::len).max().unwrap_or(0);
+        start..start + step * bins as f64
+    }
+
+    fn magnitude(&self) -> (f64, f64) {
+        if let Some(range) = self.magnitude {
+            return range;
+        }
+        let values = self.columns.iter().flatten().filter(|v| v.is_finite());
+        values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(*v), hi.max(*v))
+        })
+    }
+}
+
+impl<'a> Iterator for Spectrogram<'a> {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.magnitude.is_none() {
+            self.magnitude = Some(self.magnitude());
+        }
+        let (min, max) = self.magnitude();
+        loop {
+            let (col, row) = self.idx;
+            let column = self.columns.get(col)?;
+            if row >= column.len() {
+                self.idx = (col + 1, 0);
+                continue;
+            }
+            self.idx = (col, row + 1);
+
+            let value = column[row];
+            if !value.is_finite() {
+                continue;
+            }
+            let value = if max > min {
+                (value - min) / (max - min)
+            } else {
+                1.0
+            };
+
+            let t0 = self.time.0 + self.time.1 * col as f64;
+            let f0 = self.frequency.0 + self.frequency.1 * row as f64;
+            return Some(Rectangle::new(
+                [(t0, f0 + self.frequency.1), (t0 + self.time.1, f0)],
+                (self.style)(value),
+            ));
+        }
+    }
+}
+
+/// The scrolling strip chart for live signals. New spectra are appended on the right, and
+/// once the capacity is reached, the oldest columns are dropped from the left.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut strip = StripChart::new(2, 0.5);
+/// strip.push(vec![1.0, 2.0]);
+/// strip.push(vec![3.0, 4.0]);
+/// strip.push(vec![5.0, 6.0]);
+/// assert_eq!(strip.len(), 2);
+/// assert_eq!(strip.time_range(), 0.5..1.5);
+/// assert_eq!(strip.spectrogram().count(), 4);
+/// ```
+pub struct StripChart {
+    columns: VecDeque<Vec<f64>>,
+    capacity: usize,
+    time_step: f64,
+    time_start: f64,
+}
+
+impl StripChart {
+    /// Create a new empty strip chart
+    /// - `capacity`: The maximum number of columns to keep
+    /// - `time_step`: The time between two columns
+    pub fn new(capacity: usize, time_step: f64) -> Self {
+        Self {
+            columns: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+            time_step,
+            time_start: 0.0,
+        }
+    }
+
+    /// Append a new spectrum, dropping the oldest one if the strip chart is full
+    pub fn push(&mut self, column: Vec<f64>) {
+        if self.columns.len() == self.capacity {
+            self.columns.pop_front();
+            self.time_start += self.time_step;
+        }
+        self.columns.push_back(column);
+    }
+
+    /// Get the number of columns in the strip chart
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Check if the strip chart is empty
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Get the time range of the visible window, which is useful to update the chart range
+    pub fn time_range(&self) -> Range<f64> {
+        let end = self.time_start + self.time_step * self.capacity as f64;
+        self.time_start..end
+    }
+
+    /// Make a spectrogram series of the columns currently in the strip chart
+    pub fn spectrogram(&self) -> Spectrogram<'static> {
+        Spectrogram::new(self.columns.iter().cloned()).time_axis(self.time_start, self.time_step)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_spectrogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, fill, _, _| {
+                assert!(fill);
+                assert_ne!(c.rgb(), (0, 0, 0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..3.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(
+                Spectrogram::new(vec![vec![1.0, 0.0, f64::NAN], vec![1e-3, 1e-2]])
+                    .decibel()
+                    .magnitude_range(-60.0, 0.0),
+            )
+            .expect("Drawing Error");
+    }
+}
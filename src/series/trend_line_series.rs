@@ -0,0 +1,149 @@
+use crate::data::PolynomialFit;
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, PathElement};
+use crate::style::ShapeStyle;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::marker::PhantomData;
+use core::ops::Range;
+use num_traits::NumCast;
+
+/// The trend line series, which computes the least-squares fit of the input points
+/// and draws the fitted curve.
+///
+/// By default the curve covers the x range of the input data, `ChartContext::trend_line`
+/// creates the series covering the full range of the x axis.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let series: TrendLineSeries<SVGBackend, _, _> =
+///     TrendLineSeries::new(vec![(0.0, 1.0), (1.0, 2.9), (2.0, 5.1)], &RED);
+/// let fit = series.fit().unwrap();
+/// assert!((fit.slope() - 2.05).abs() < 1e-9);
+/// ```
+pub struct TrendLineSeries<DB: DrawingBackend, X, Y> {
+    style: ShapeStyle,
+    fit: Option<PolynomialFit>,
+    x_range: (f64, f64),
+    samples: usize,
+    done: bool,
+    phantom: PhantomData<(DB, X, Y)>,
+}
+
+impl<DB: DrawingBackend, X: NumCast, Y: NumCast> TrendLineSeries<DB, X, Y> {
+    /// Create a new linear trend line series
+    /// - `iter`: The iterator of data points
+    /// - `style`: The style of the trend line
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self::polynomial(iter, 1, style)
+    }
+
+    /// Create a new trend line series with a polynomial fit
+    /// - `iter`: The iterator of data points
+    /// - `degree`: The degree of the fitted polynomial
+    /// - `style`: The style of the trend line
+    /// - **returns** The newly created series
+    pub fn polynomial<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        iter: I,
+        degree: usize,
+        style: S,
+    ) -> Self {
+        let points: Vec<(f64, f64)> = iter
+            .into_iter()
+            .filter_map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+            .collect();
+
+        let x_range = points
+            .iter()
+            .filter(|(x, _)| x.is_finite())
+            .fold(None, |range: Option<(f64, f64)>, &(x, _)| match range {
+                None => Some((x, x)),
+                Some((l, r)) => Some((l.min(x), r.max(x))),
+            })
+            .unwrap_or((0.0, 0.0));
+
+        Self {
+            style: style.into(),
+            fit: PolynomialFit::new(points, degree),
+            x_range,
+            samples: if degree <= 1 { 2 } else { 100 },
+            done: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set the x range the trend line should cover
+    pub fn x_range(mut self, range: Range<X>) -> Self {
+        if let (Some(l), Some(r)) = (range.start.to_f64(), range.end.to_f64()) {
+            self.x_range = (l, r);
+        }
+        self
+    }
+
+    /// Set the number of points used to draw the fitted curve
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(2);
+        self
+    }
+
+    /// Get the fit result, `None` if the input data can not be fitted
+    pub fn fit(&self) -> Option<&PolynomialFit> {
+        self.fit.as_ref()
+    }
+}
+
+impl<DB: DrawingBackend, X: NumCast + Clone + 'static, Y: NumCast + Clone + 'static> Iterator
+    for TrendLineSeries<DB, X, Y>
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.done = true;
+
+        let fit = self.fit.as_ref()?;
+        let (l, r) = self.x_range;
+        let step = (r - l) / (self.samples - 1) as f64;
+        let points: Vec<_> = (0..self.samples)
+            .filter_map(|idx| {
+                let x = l + step * idx as f64;
+                Some((X::from(x)?, Y::from(fit.eval(x))?))
+            })
+            .collect();
+
+        Some(PathElement::new(points, self.style.clone()).into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_trend_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(0, 199), (200, 99)]);
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..100.0, 0.0..100.0)
+            .expect("Build chart error");
+
+        let series = chart.trend_line((0..10).map(|x| (x as f64, x as f64 / 2.0)), 1, &RED);
+
+        assert!((series.fit().unwrap().slope() - 0.5).abs() < 1e-9);
+
+        chart.draw_series(series).expect("Drawing Error");
+    }
+}
@@ -0,0 +1,337 @@
+/*!
+  The declarative chart specification.
+
+  A chart, including the axis ranges, the series data, the styles and the labels, can be
+  described by a `ChartSpec`, which can be deserialized with serde from a config file or the
+  request of a chart templating service, and then rendered onto any backend.
+
+  ```rust
+  use plotters::prelude::*;
+  use plotters::spec::ChartSpec;
+
+  let spec: ChartSpec = serde_json::from_str(r##"{
+      "caption": "Temperature",
+      "x_axis": { "label": "Hour" },
+      "y_axis": { "label": "Celsius", "range": [0, 30] },
+      "series": [
+          { "label": "Inside", "data": [[0, 21], [6, 20], [12, 23], [18, 22]] },
+          { "label": "Outside", "kind": "points", "color": "#1f77b4",
+            "data": [[0, 4], [6, 2], [12, 11], [18, 8]] }
+      ]
+  }"##).unwrap();
+
+  let mut buf = String::new();
+  let root = SVGBackend::with_string(&mut buf, (640, 480)).into_drawing_area();
+  root.fill(&WHITE).unwrap();
+  spec.render(&root).unwrap();
+  ```
+*/
+#[cfg(feature = "vega_lite")]
+mod vega_lite;
+
+use std::convert::TryFrom;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chart::{ChartBuilder, ChartContext};
+use crate::coord::{LogRange, Ranged, RangedCoord, Shift};
+use crate::data::fitting_range;
+use crate::drawing::{DrawingArea, DrawingBackend};
+use crate::element::{Circle, PathElement};
+use crate::error::PlottersError;
+use crate::series::{AreaSeries, LineSeries, PointSeries};
+use crate::style::{Color, CustomPalette, RGBColor, BLACK, WHITE};
+
+/// The description of a chart
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChartSpec {
+    /// The caption of the chart
+    #[serde(default)]
+    pub caption: Option<String>,
+    /// The font size of the caption, in pixels
+    #[serde(default = "default_caption_size")]
+    pub caption_size: u32,
+    /// The X axis
+    #[serde(default)]
+    pub x_axis: AxisSpec,
+    /// The Y axis
+    #[serde(default)]
+    pub y_axis: AxisSpec,
+    /// The margin around the chart, in pixels
+    #[serde(default = "default_margin")]
+    pub margin: u32,
+    /// The size of the areas of the tick labels, in pixels
+    #[serde(default = "default_label_area_size")]
+    pub label_area_size: u32,
+    /// Whether the grid lines are drawn, the axes are drawn anyway
+    #[serde(default = "default_true")]
+    pub mesh: bool,
+    /// The series drawn on the chart, in the drawing order
+    #[serde(default)]
+    pub series: Vec<SeriesSpec>,
+}
+
+/// The description of an axis
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AxisSpec {
+    /// The range of the axis, if it's `None`, the range fits the data of all the series
+    #[serde(default)]
+    pub range: Option<(f64, f64)>,
+    /// The description of the axis
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Whether the axis is logarithmic
+    #[serde(default)]
+    pub log: bool,
+}
+
+/// The way a series is drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesKind {
+    /// The points are connected with a line
+    #[default]
+    Line,
+    /// Each point is drawn as a circle
+    Points,
+    /// The area between the line and the X axis is filled
+    Area,
+}
+
+/// The description of a series
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeriesSpec {
+    /// The way the series is drawn
+    #[serde(default)]
+    pub kind: SeriesKind,
+    /// The label of the series in the legend, the legend is drawn if any series has a label
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The `(x, y)` points of the series
+    pub data: Vec<(f64, f64)>,
+    /// The color of the series, if it's `None`, the next color of the chart is used
+    #[serde(default)]
+    pub color: Option<SpecColor>,
+    /// The width of the line, in pixels
+    #[serde(default = "default_stroke_width")]
+    pub stroke_width: u32,
+    /// The radius of the points, in pixels
+    #[serde(default = "default_point_size")]
+    pub point_size: u32,
+}
+
+/// A color of the specification, which is written as a hex color code like `"#ff9300"`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SpecColor(pub u8, pub u8, pub u8);
+
+impl TryFrom<String> for SpecColor {
+    type Error = String;
+    fn try_from(code: String) -> Result<Self, String> {
+        CustomPalette::from_hex(&[&code])
+            .map(|palette| {
+                let (r, g, b) = palette.pick(0).rgb();
+                SpecColor(r, g, b)
+            })
+            .ok_or_else(|| format!("invalid color code {:?}", code))
+    }
+}
+
+impl From<SpecColor> for String {
+    fn from(color: SpecColor) -> String {
+        format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+    }
+}
+
+fn default_caption_size() -> u32 {
+    20
+}
+
+fn default_margin() -> u32 {
+    10
+}
+
+fn default_label_area_size() -> u32 {
+    30
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_stroke_width() -> u32 {
+    1
+}
+
+fn default_point_size() -> u32 {
+    3
+}
+
+impl AxisSpec {
+    // The range of the axis, the values which can't be drawn on a logarithmic axis are ignored
+    fn range_of<I: Iterator<Item = f64>>(&self, values: I) -> Range<f64> {
+        if let Some((start, end)) = self.range {
+            return start..end;
+        }
+        let log = self.log;
+        let values: Vec<f64> = values.filter(|v| !log || *v > 0.0).collect();
+        let range = fitting_range(&values);
+        if log && values.is_empty() {
+            return 1.0..10.0;
+        }
+        range
+    }
+}
+
+impl ChartSpec {
+    /// Render the chart onto a drawing area
+    ///
+    /// - `root`: The drawing area to render the chart on, it's not filled with a background
+    /// - **returns**: The result of the rendering, which is a layout error if the ranges or
+    ///   the sizes of the chart are invalid
+    pub fn render<DB: DrawingBackend>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+    ) -> Result<(), PlottersError>
+    where
+        DB::ErrorType: 'static,
+    {
+        let mut builder = ChartBuilder::on(root);
+        builder
+            .margin(self.margin)
+            .x_label_area_size(self.label_area_size)
+            .y_label_area_size(self.label_area_size);
+        if let Some(caption) = &self.caption {
+            builder.caption(caption, ("sans-serif", self.caption_size));
+        }
+
+        let points = || self.series.iter().flat_map(|s| s.data.iter());
+        let x_range = self.x_axis.range_of(points().map(|p| p.0));
+        let y_range = self.y_axis.range_of(points().map(|p| p.1));
+
+        match (self.x_axis.log, self.y_axis.log) {
+            (false, false) => self.draw(builder.build_ranged(x_range, y_range)?),
+            (true, false) => self.draw(builder.build_ranged(LogRange(x_range), y_range)?),
+            (false, true) => self.draw(builder.build_ranged(x_range, LogRange(y_range))?),
+            (true, true) => self.draw(builder.build_ranged(LogRange(x_range), LogRange(y_range))?),
+        }
+    }
+
+    fn draw<'a, DB, X, Y>(
+        &self,
+        mut chart: ChartContext<'a, DB, RangedCoord<X, Y>>,
+    ) -> Result<(), PlottersError>
+    where
+        DB: DrawingBackend + 'a,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        {
+            let mut mesh = chart.configure_mesh();
+            if !self.mesh {
+                mesh.disable_mesh();
+            }
+            if let Some(label) = &self.x_axis.label {
+                mesh.x_desc(label.as_str());
+            }
+            if let Some(label) = &self.y_axis.label {
+                mesh.y_desc(label.as_str());
+            }
+            mesh.draw()?;
+        }
+
+        let baseline = chart.y_range().start;
+        for series in &self.series {
+            let color = match &series.color {
+                Some(SpecColor(r, g, b)) => RGBColor(*r, *g, *b).to_rgba(),
+                None => chart.next_color().to_rgba(),
+            };
+            let style = color.stroke_width(series.stroke_width);
+            let data = series.data.iter().copied();
+            let anno = match series.kind {
+                SeriesKind::Line => chart.draw_series(LineSeries::new(data, style))?,
+                SeriesKind::Points => {
+                    chart.draw_series(PointSeries::<_, _, Circle<_, _>, _>::new(
+                        data,
+                        series.point_size,
+                        style.filled(),
+                    ))?
+                }
+                SeriesKind::Area => chart.draw_series(
+                    AreaSeries::new(data, baseline, color.mix(0.2).filled()).border_style(style),
+                )?,
+            };
+            if let Some(label) = &series.label {
+                anno.label(label.as_str()).legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+                });
+            }
+        }
+
+        if self.series.iter().any(|s| s.label.is_some()) {
+            chart
+                .configure_series_labels()
+                .background_style(&WHITE.mix(0.8))
+                .border_style(&BLACK)
+                .draw()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::create_mocked_drawing_area;
+
+    #[test]
+    fn test_chart_spec() {
+        let spec: ChartSpec = serde_json::from_str(
+            r#"{
+                "caption": "Test",
+                "y_axis": { "log": true },
+                "series": [
+                    { "data": [[0, 1], [1, 10], [2, 100]], "color": "ff0000" },
+                    { "kind": "area", "data": [[0, 2], [2, 3]], "label": "Area" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(spec.margin, 10);
+        assert_eq!(spec.series[0].kind, SeriesKind::Line);
+        assert_eq!(spec.series[0].color, Some(SpecColor(255, 0, 0)));
+        assert_eq!(spec.x_axis.range_of([0.0, 2.0].iter().copied()), 0.0..2.0);
+        assert_eq!(spec.y_axis.range_of([-1.0, 0.0].iter().copied()), 1.0..10.0);
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.drop_check(|b| {
+                assert!(b.num_draw_path_call > 0);
+                assert!(b.num_draw_text_call > 0);
+            });
+        });
+        spec.render(&drawing_area).unwrap();
+
+        // The spec can be written back
+        let json = serde_json::to_string(&spec).unwrap();
+        assert!(json.contains("\"#ff0000\""));
+        assert_eq!(serde_json::from_str::<ChartSpec>(&json).unwrap(), spec);
+
+        let error = serde_json::from_str::<ChartSpec>(
+            r#"{ "series": [
+            { "data": [], "color": "red" }
+        ] }"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("invalid color code"));
+
+        // The empty ranges are layout errors
+        let spec: ChartSpec = serde_json::from_str(r#"{ "x_axis": { "range": [1, 1] } }"#).unwrap();
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        assert!(matches!(
+            spec.render(&drawing_area),
+            Err(PlottersError::Layout { .. })
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+use std::f64::consts::PI;
+
+use serde_json::{json, Map, Value};
+
+use super::{ChartSpec, SeriesKind, SpecColor};
+use crate::style::{RGBColor, Theme};
+
+const SCHEMA: &str = "https://vega.github.io/schema/vega-lite/v5.json";
+
+fn axis_encoding(field: &str, label: &Option<String>, range: (f64, f64), log: bool) -> Value {
+    let mut scale = json!({ "domain": [range.0, range.1], "nice": false, "zero": false });
+    if log {
+        scale["type"] = json!("log");
+    }
+    json!({
+        "field": field,
+        "type": "quantitative",
+        "title": label,
+        "scale": scale,
+    })
+}
+
+impl ChartSpec {
+    /// Convert the chart into a [Vega-Lite](https://vega.github.io/vega-lite/) specification,
+    /// so the same chart can be displayed interactively by a web frontend
+    ///
+    /// The axis ranges are the ones the chart is rendered with, and the series without a color
+    /// get the colors of the default theme, so both renderings look alike.
+    ///
+    /// ```rust
+    /// use plotters::spec::ChartSpec;
+    ///
+    /// let spec: ChartSpec = serde_json::from_str(r#"{
+    ///     "caption": "Squares",
+    ///     "series": [{ "label": "x^2", "data": [[0, 0], [1, 1], [2, 4]] }]
+    /// }"#).unwrap();
+    /// let vega = spec.to_vega_lite();
+    /// assert_eq!(vega["title"]["text"], "Squares");
+    /// assert_eq!(vega["layer"][0]["mark"]["type"], "line");
+    /// ```
+    pub fn to_vega_lite(&self) -> Value {
+        let points = || self.series.iter().flat_map(|s| s.data.iter());
+        let x_range = self.x_axis.range_of(points().map(|p| p.0));
+        let y_range = self.y_axis.range_of(points().map(|p| p.1));
+
+        let theme = Theme::default();
+        let colors: Vec<String> = (0..self.series.len())
+            .map(|idx| {
+                let color = self.series[idx].color.unwrap_or_else(|| {
+                    let RGBColor(r, g, b) = theme.series_color(idx);
+                    SpecColor(r, g, b)
+                });
+                color.into()
+            })
+            .collect();
+
+        // The labeled series share a color scale, which is shown as the legend
+        let (labels, label_colors): (Vec<_>, Vec<_>) = self
+            .series
+            .iter()
+            .zip(colors.iter())
+            .filter_map(|(s, c)| s.label.as_ref().map(|l| (l, c)))
+            .unzip();
+
+        let layers: Vec<Value> = self
+            .series
+            .iter()
+            .zip(colors.iter())
+            .map(|(series, color)| {
+                let values: Vec<Value> = series
+                    .data
+                    .iter()
+                    .map(|(x, y)| json!({ "x": x, "y": y }))
+                    .collect();
+                let mark = match series.kind {
+                    SeriesKind::Line => json!({
+                        "type": "line",
+                        "color": color,
+                        "strokeWidth": series.stroke_width,
+                    }),
+                    SeriesKind::Points => json!({
+                        "type": "circle",
+                        "color": color,
+                        "opacity": 1,
+                        "size": PI * f64::from(series.point_size).powi(2),
+                    }),
+                    SeriesKind::Area => json!({
+                        "type": "area",
+                        "color": color,
+                        "fillOpacity": 0.2,
+                        "line": { "color": color, "strokeWidth": series.stroke_width },
+                    }),
+                };
+                let mut encoding = Map::new();
+                if series.kind == SeriesKind::Area {
+                    encoding.insert("y2".to_string(), json!({ "datum": y_range.start }));
+                }
+                if let Some(label) = &series.label {
+                    encoding.insert(
+                        "color".to_string(),
+                        json!({
+                            "datum": label,
+                            "scale": { "domain": labels, "range": label_colors },
+                            "legend": { "title": null },
+                        }),
+                    );
+                }
+                json!({
+                    "data": { "values": values },
+                    "mark": mark,
+                    "encoding": encoding,
+                })
+            })
+            .collect();
+
+        let mut ret = json!({
+            "$schema": SCHEMA,
+            "padding": self.margin,
+            "encoding": {
+                "x": axis_encoding("x", &self.x_axis.label, (x_range.start, x_range.end), self.x_axis.log),
+                "y": axis_encoding("y", &self.y_axis.label, (y_range.start, y_range.end), self.y_axis.log),
+            },
+            "layer": layers,
+            "config": { "axis": { "grid": self.mesh } },
+        });
+        if let Some(caption) = &self.caption {
+            ret["title"] = json!({ "text": caption, "fontSize": self.caption_size });
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vega_lite() {
+        let spec: ChartSpec = serde_json::from_str(
+            r##"{
+                "x_axis": { "label": "Time", "range": [0, 10] },
+                "y_axis": { "log": true },
+                "mesh": false,
+                "series": [
+                    { "data": [[0, 1], [10, 100]] },
+                    { "kind": "area", "label": "A", "color": "#ff0000", "data": [[0, 2]] },
+                    { "kind": "points", "label": "B", "point_size": 2, "data": [[5, 10]] }
+                ]
+            }"##,
+        )
+        .unwrap();
+        let vega = spec.to_vega_lite();
+
+        assert_eq!(vega["$schema"], SCHEMA);
+        assert!(vega.get("title").is_none());
+        assert_eq!(vega["config"]["axis"]["grid"], false);
+        assert_eq!(vega["encoding"]["x"]["title"], "Time");
+        assert_eq!(vega["encoding"]["x"]["scale"]["domain"], json!([0.0, 10.0]));
+        assert_eq!(vega["encoding"]["y"]["scale"]["type"], "log");
+
+        let layers = vega["layer"].as_array().unwrap();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(
+            layers[0]["data"]["values"][1],
+            json!({ "x": 10.0, "y": 100.0 })
+        );
+        // The series without a color gets the first color of the default theme
+        let RGBColor(r, g, b) = Theme::default().series_color(0);
+        assert_eq!(layers[0]["mark"]["color"], String::from(SpecColor(r, g, b)));
+        assert!(layers[0]["encoding"].get("color").is_none());
+
+        assert_eq!(layers[1]["mark"]["type"], "area");
+        assert_eq!(layers[1]["encoding"]["y2"]["datum"], 1.0);
+        let legend =
+            json!({ "domain": ["A", "B"], "range": ["#ff0000", layers[2]["mark"]["color"]] });
+        assert_eq!(layers[1]["encoding"]["color"]["scale"], legend);
+        assert_eq!(layers[2]["encoding"]["color"]["datum"], "B");
+        assert_eq!(layers[2]["mark"]["type"], "circle");
+    }
+}
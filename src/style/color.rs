@@ -1,7 +1,7 @@
 use super::palette::Palette;
 use super::ShapeStyle;
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// Any color representation
 pub trait Color {
@@ -40,12 +40,42 @@ pub trait Color {
     {
         Into::<ShapeStyle>::into(self).stroke_width(width)
     }
+
+    /// Make the color lighter by increasing its HSL lightness, the alpha channel is kept
+    /// - `amount`: The amount added to the lightness, in the range of 0 to 1
+    fn lighten(&self, amount: f64) -> RGBAColor {
+        let HSLColor(h, s, l) = HSLColor::from_color(self);
+        HSLColor(h, s, (l + amount).clamp(0.0, 1.0)).mix(self.alpha())
+    }
+
+    /// Make the color darker by decreasing its HSL lightness, the alpha channel is kept
+    /// - `amount`: The amount subtracted from the lightness, in the range of 0 to 1
+    fn darken(&self, amount: f64) -> RGBAColor {
+        self.lighten(-amount)
+    }
+
+    /// Make a color with the same hue and lightness but a different HSL saturation
+    /// - `saturation`: The new saturation, in the range of 0 to 1
+    fn with_saturation(&self, saturation: f64) -> RGBAColor {
+        let HSLColor(h, _, l) = HSLColor::from_color(self);
+        HSLColor(h, saturation.clamp(0.0, 1.0), l).mix(self.alpha())
+    }
 }
 
 /// The RGBA representation of the color, Plotters use RGBA as the internal representation
-/// of color
+/// of color. The alpha channel is in the range of 0 to 1 and it's kept by the shape styles,
+/// so the bitmap backend blends the color with the existing pixels and the SVG backend
+/// emits the opacity of the shapes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let translucent_red = RGBAColor(255, 0, 0, 0.5);
+/// assert_eq!(RED.mix(0.5), translucent_red);
+/// assert_eq!(translucent_red.mix(0.5).alpha(), 0.25);
+/// ```
 #[derive(Clone, PartialEq, Debug)]
-pub struct RGBAColor(pub(super) u8, pub(super) u8, pub(super) u8, pub(super) f64);
+pub struct RGBAColor(pub u8, pub u8, pub u8, pub f64);
 
 impl Color for RGBAColor {
     #[inline(always)]
@@ -84,13 +114,14 @@ pub struct PaletteColor<P: Palette>(usize, PhantomData<P>);
 impl<P: Palette> PaletteColor<P> {
     /// Pick a color from the palette
     pub fn pick(idx: usize) -> PaletteColor<P> {
-        PaletteColor(idx % P::COLORS.len(), PhantomData)
+        PaletteColor(idx.checked_rem(P::COLORS.len()).unwrap_or(0), PhantomData)
     }
 }
 
 impl<P: Palette> SimpleColor for PaletteColor<P> {
     fn rgb(&self) -> (u8, u8, u8) {
-        P::COLORS[self.0]
+        // An empty palette gives black
+        P::COLORS.get(self.0).copied().unwrap_or((0, 0, 0))
     }
 }
 
@@ -104,9 +135,53 @@ impl SimpleColor for RGBColor {
     }
 }
 
-/// The color described by HSL color space
+/// The color described by HSL color space, all the components are in the range of 0 to 1.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// assert_eq!(HSLColor(0.0, 1.0, 0.5).to_rgba(), RED.to_rgba());
+/// assert_eq!(RED.darken(0.25).rgb(), (128, 0, 0));
+/// assert_eq!(RED.with_saturation(0.0).rgb(), (128, 128, 128));
+/// ```
 pub struct HSLColor(pub f64, pub f64, pub f64);
 
+// The hue in the range of 0 to 1, the maximum and minimum of the RGB components
+fn hue_of((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    (h / 6.0, max, min)
+}
+
+impl HSLColor {
+    /// Convert a color to the HSL color space, the alpha channel is dropped
+    pub fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        let (h, max, min) = hue_of(color.rgb());
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        HSLColor(h, s, l)
+    }
+}
+
 impl SimpleColor for HSLColor {
     #[allow(clippy::many_single_char_names)]
     fn rgb(&self) -> (u8, u8, u8) {
@@ -150,3 +225,64 @@ impl SimpleColor for HSLColor {
         (cvt(h + 1.0 / 3.0), cvt(h), cvt(h - 1.0 / 3.0))
     }
 }
+
+/// The color described by HSV color space, all the components are in the range of 0 to 1
+pub struct HSVColor(pub f64, pub f64, pub f64);
+
+impl HSVColor {
+    /// Convert a color to the HSV color space, the alpha channel is dropped
+    pub fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        let (h, max, min) = hue_of(color.rgb());
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        HSVColor(h, s, max)
+    }
+}
+
+impl SimpleColor for HSVColor {
+    fn rgb(&self) -> (u8, u8, u8) {
+        let (h, s, v) = (
+            self.0.clamp(0.0, 1.0),
+            self.1.clamp(0.0, 1.0),
+            self.2.clamp(0.0, 1.0),
+        );
+        // Convert through HSL, which shares the hue
+        let l = v * (1.0 - s / 2.0);
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (v - l) / l.min(1.0 - l)
+        };
+        SimpleColor::rgb(&HSLColor(h, s, l))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_color_space_round_trip() {
+        for &rgb in [(255, 0, 0), (12, 200, 99), (128, 128, 128), (250, 240, 10)].iter() {
+            let color = RGBColor(rgb.0, rgb.1, rgb.2);
+            assert_eq!(SimpleColor::rgb(&HSLColor::from_color(&color)), rgb);
+            assert_eq!(SimpleColor::rgb(&HSVColor::from_color(&color)), rgb);
+        }
+        assert_eq!(
+            SimpleColor::rgb(&HSVColor(1.0 / 3.0, 1.0, 0.5)),
+            (0, 128, 0)
+        );
+    }
+
+    #[test]
+    fn test_lighten_and_darken() {
+        let color = RGBColor(0, 0, 255).mix(0.5);
+        let lighter = color.lighten(0.25);
+        assert_eq!(lighter.rgb(), (128, 128, 255));
+        assert_eq!(lighter.alpha(), 0.5);
+        assert_eq!(color.darken(1.0).rgb(), (0, 0, 0));
+        assert_eq!(
+            RGBColor(255, 0, 0).with_saturation(0.5).rgb(),
+            (191, 64, 64)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use super::color::{Color, RGBColor};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The trait for color maps, which map a value in the range of 0 to 1 to a color. They are
+/// used to encode a value with colors, for example in heat maps and color mapped scatter
+/// plots.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// assert_eq!(Viridis.get_color(0.0).to_rgba(), RGBColor(68, 1, 84).to_rgba());
+/// // Map a value of the data range
+/// let style = Magma.get_color_normalized(25.0, 0.0, 100.0).filled();
+///
+/// let custom = LinearColorMap::new(&[BLUE, WHITE, RED]);
+/// assert_eq!(custom.get_color(0.5).to_rgba(), WHITE.to_rgba());
+/// ```
+pub trait ColorMap {
+    /// Get the color of the value, the value is clamped to the range of 0 to 1
+    fn get_color(&self, value: f64) -> RGBColor;
+
+    /// Get the color of a value in the given range
+    /// - `value`: The value to map
+    /// - `min`: The value mapped to the start of the color map
+    /// - `max`: The value mapped to the end of the color map
+    fn get_color_normalized(&self, value: f64, min: f64, max: f64) -> RGBColor {
+        if max == min {
+            return self.get_color(0.0);
+        }
+        self.get_color((value - min) / (max - min))
+    }
+}
+
+impl<M: ColorMap + ?Sized> ColorMap for &M {
+    fn get_color(&self, value: f64) -> RGBColor {
+        (**self).get_color(value)
+    }
+}
+
+// Interpolate the evenly spaced stops linearly
+fn interpolate(stops: &[(u8, u8, u8)], value: f64) -> RGBColor {
+    let value = if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    };
+    let pos = value * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 2);
+    let (a, b) = (stops[idx], stops[idx + 1]);
+    let t = pos - idx as f64;
+    let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    RGBColor(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+macro_rules! def_color_map {
+    ($name:ident, $doc:expr, [$($stop:expr),*]) => {
+        #[doc = $doc]
+        pub struct $name;
+
+        impl $name {
+            const STOPS: &'static [(u8, u8, u8)] = &[$($stop),*];
+        }
+
+        impl ColorMap for $name {
+            fn get_color(&self, value: f64) -> RGBColor {
+                interpolate(Self::STOPS, value)
+            }
+        }
+    };
+}
+
+def_color_map!(
+    Viridis,
+    "The perceptually uniform viridis color map, from dark purple to yellow",
+    [
+        (68, 1, 84),
+        (72, 40, 120),
+        (62, 74, 137),
+        (49, 104, 142),
+        (38, 130, 142),
+        (31, 158, 137),
+        (53, 183, 121),
+        (109, 205, 89),
+        (180, 222, 44),
+        (253, 231, 37)
+    ]
+);
+
+def_color_map!(
+    Magma,
+    "The perceptually uniform magma color map, from black to light yellow through purple",
+    [
+        (0, 0, 4),
+        (24, 15, 62),
+        (69, 16, 119),
+        (114, 31, 129),
+        (159, 47, 127),
+        (205, 64, 113),
+        (241, 96, 93),
+        (253, 149, 103),
+        (254, 201, 141),
+        (252, 253, 191)
+    ]
+);
+
+def_color_map!(
+    Plasma,
+    "The perceptually uniform plasma color map, from dark blue to yellow through magenta",
+    [
+        (13, 8, 135),
+        (71, 3, 159),
+        (115, 1, 168),
+        (156, 23, 158),
+        (189, 55, 134),
+        (216, 87, 107),
+        (237, 121, 83),
+        (250, 158, 59),
+        (253, 201, 38),
+        (240, 249, 33)
+    ]
+);
+
+def_color_map!(
+    Inferno,
+    "The perceptually uniform inferno color map, from black to light yellow through red",
+    [
+        (0, 0, 4),
+        (27, 12, 66),
+        (75, 12, 107),
+        (120, 28, 109),
+        (165, 44, 96),
+        (207, 68, 70),
+        (237, 105, 37),
+        (251, 154, 6),
+        (247, 208, 60),
+        (252, 255, 164)
+    ]
+);
+
+def_color_map!(
+    Turbo,
+    "The turbo rainbow color map, from dark blue to dark red",
+    [
+        (48, 18, 59),
+        (70, 98, 215),
+        (54, 170, 249),
+        (26, 228, 182),
+        (114, 254, 94),
+        (199, 239, 52),
+        (250, 186, 57),
+        (246, 107, 25),
+        (203, 42, 4),
+        (122, 4, 3)
+    ]
+);
+
+def_color_map!(
+    Heat,
+    "The heat color map, from dark blue to light yellow through purple and red",
+    [(0, 0, 64), (128, 0, 128), (240, 64, 0), (255, 255, 128)]
+);
+
+/// A custom color map, which interpolates linearly between the control points
+pub struct LinearColorMap {
+    stops: Vec<(f64, (u8, u8, u8))>,
+}
+
+impl LinearColorMap {
+    /// Create a color map from evenly spaced colors
+    /// - `colors`: The colors, the first one is at 0 and the last one is at 1
+    pub fn new<C: Color>(colors: &[C]) -> Self {
+        let n = colors.len().max(2) - 1;
+        Self {
+            stops: colors
+                .iter()
+                .enumerate()
+                .map(|(idx, c)| (idx as f64 / n as f64, c.rgb()))
+                .collect(),
+        }
+    }
+
+    /// Create a color map from control points
+    /// - `stops`: The positions in the range of 0 to 1 and the colors at the positions
+    pub fn from_stops<C: Color>(stops: &[(f64, C)]) -> Self {
+        let mut stops: Vec<_> = stops.iter().map(|(pos, c)| (*pos, c.rgb())).collect();
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+        Self { stops }
+    }
+}
+
+impl ColorMap for LinearColorMap {
+    fn get_color(&self, value: f64) -> RGBColor {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return RGBColor(0, 0, 0),
+        };
+        let value = if value.is_nan() { first.0 } else { value };
+        if value <= first.0 {
+            let (r, g, b) = first.1;
+            return RGBColor(r, g, b);
+        }
+        if value >= last.0 {
+            let (r, g, b) = last.1;
+            return RGBColor(r, g, b);
+        }
+        let idx = self
+            .stops
+            .iter()
+            .position(|(pos, _)| *pos > value)
+            .unwrap_or(1);
+        let ((p0, c0), (p1, c1)) = (self.stops[idx - 1], self.stops[idx]);
+        interpolate(&[c0, c1], (value - p0) / (p1 - p0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builtin_color_maps() {
+        assert_eq!(Viridis.get_color(-1.0).rgb(), (68, 1, 84));
+        assert_eq!(Viridis.get_color(2.0).rgb(), (253, 231, 37));
+        assert_eq!(Viridis.get_color(f64::NAN).rgb(), (68, 1, 84));
+        // Half way between the second and the third stops
+        assert_eq!(Viridis.get_color(1.5 / 9.0).rgb(), (67, 57, 129));
+        assert_eq!(
+            Turbo.get_color_normalized(5.0, 5.0, 5.0).rgb(),
+            (48, 18, 59)
+        );
+        assert_eq!(
+            Magma.get_color_normalized(30.0, 10.0, 30.0).rgb(),
+            (252, 253, 191)
+        );
+        assert_eq!(Heat.get_color(0.5).rgb(), (184, 32, 64));
+        // The color maps are shared by reference
+        fn last_color<M: ColorMap>(color_map: M) -> (u8, u8, u8) {
+            color_map.get_color(1.0).rgb()
+        }
+        assert_eq!(last_color(&Heat), (255, 255, 128));
+    }
+
+    #[test]
+    fn test_linear_color_map() {
+        let map = LinearColorMap::from_stops(&[
+            (1.0, RGBColor(0, 0, 0)),
+            (0.0, RGBColor(255, 0, 0)),
+            (0.2, RGBColor(0, 255, 0)),
+        ]);
+        assert_eq!(map.get_color(0.1).rgb(), (128, 128, 0));
+        assert_eq!(map.get_color(0.6).rgb(), (0, 128, 0));
+        assert_eq!(map.get_color(1.5).rgb(), (0, 0, 0));
+
+        let single = LinearColorMap::new(&[RGBColor(1, 2, 3)]);
+        assert_eq!(single.get_color(0.7).rgb(), (1, 2, 3));
+        let empty = LinearColorMap::new::<RGBColor>(&[]);
+        assert_eq!(empty.get_color(0.5).rgb(), (0, 0, 0));
+    }
+}
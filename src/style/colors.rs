@@ -23,13 +23,16 @@ predefined_color!(CYAN, 0, 255, 255, "The predefined cyan color");
 predefined_color!(MAGENTA, 255, 0, 255, "The predefined magenta color");
 predefined_color!(TRANSPARENT, 0, 0, 0, 0.0, "The predefined transparent");
 
+#[cfg(feature = "full_palette")]
+pub mod full_palette;
+
 /// Predefined Color definitions using the [palette](https://docs.rs/palette/) color types
 #[cfg(feature = "palette_ext")]
 pub mod palette_ext {
     use palette::rgb::Srgb;
     use palette::Alpha;
 
-    use std::marker::PhantomData;
+    use core::marker::PhantomData;
 
     macro_rules! predefined_color_pal {
         ($name:ident, $r:expr, $g:expr, $b:expr, $doc:expr) => {
@@ -0,0 +1,426 @@
+//! The full set of the CSS named colors, see
+//! [the CSS color module](https://www.w3.org/TR/css-color-4/#named-colors).
+//!
+//! The names follow CSS, so some of them differ from the basic predefined colors, for example
+//! `GREEN` is `RGBColor(0, 128, 0)` here.
+use super::super::RGBColor;
+
+predefined_color!(ALICEBLUE, 240, 248, 255, "The CSS color `aliceblue`");
+predefined_color!(ANTIQUEWHITE, 250, 235, 215, "The CSS color `antiquewhite`");
+predefined_color!(AQUA, 0, 255, 255, "The CSS color `aqua`");
+predefined_color!(AQUAMARINE, 127, 255, 212, "The CSS color `aquamarine`");
+predefined_color!(AZURE, 240, 255, 255, "The CSS color `azure`");
+predefined_color!(BEIGE, 245, 245, 220, "The CSS color `beige`");
+predefined_color!(BISQUE, 255, 228, 196, "The CSS color `bisque`");
+predefined_color!(BLACK, 0, 0, 0, "The CSS color `black`");
+predefined_color!(
+    BLANCHEDALMOND,
+    255,
+    235,
+    205,
+    "The CSS color `blanchedalmond`"
+);
+predefined_color!(BLUE, 0, 0, 255, "The CSS color `blue`");
+predefined_color!(BLUEVIOLET, 138, 43, 226, "The CSS color `blueviolet`");
+predefined_color!(BROWN, 165, 42, 42, "The CSS color `brown`");
+predefined_color!(BURLYWOOD, 222, 184, 135, "The CSS color `burlywood`");
+predefined_color!(CADETBLUE, 95, 158, 160, "The CSS color `cadetblue`");
+predefined_color!(CHARTREUSE, 127, 255, 0, "The CSS color `chartreuse`");
+predefined_color!(CHOCOLATE, 210, 105, 30, "The CSS color `chocolate`");
+predefined_color!(CORAL, 255, 127, 80, "The CSS color `coral`");
+predefined_color!(
+    CORNFLOWERBLUE,
+    100,
+    149,
+    237,
+    "The CSS color `cornflowerblue`"
+);
+predefined_color!(CORNSILK, 255, 248, 220, "The CSS color `cornsilk`");
+predefined_color!(CRIMSON, 220, 20, 60, "The CSS color `crimson`");
+predefined_color!(CYAN, 0, 255, 255, "The CSS color `cyan`");
+predefined_color!(DARKBLUE, 0, 0, 139, "The CSS color `darkblue`");
+predefined_color!(DARKCYAN, 0, 139, 139, "The CSS color `darkcyan`");
+predefined_color!(DARKGOLDENROD, 184, 134, 11, "The CSS color `darkgoldenrod`");
+predefined_color!(DARKGRAY, 169, 169, 169, "The CSS color `darkgray`");
+predefined_color!(DARKGREEN, 0, 100, 0, "The CSS color `darkgreen`");
+predefined_color!(DARKGREY, 169, 169, 169, "The CSS color `darkgrey`");
+predefined_color!(DARKKHAKI, 189, 183, 107, "The CSS color `darkkhaki`");
+predefined_color!(DARKMAGENTA, 139, 0, 139, "The CSS color `darkmagenta`");
+predefined_color!(
+    DARKOLIVEGREEN,
+    85,
+    107,
+    47,
+    "The CSS color `darkolivegreen`"
+);
+predefined_color!(DARKORANGE, 255, 140, 0, "The CSS color `darkorange`");
+predefined_color!(DARKORCHID, 153, 50, 204, "The CSS color `darkorchid`");
+predefined_color!(DARKRED, 139, 0, 0, "The CSS color `darkred`");
+predefined_color!(DARKSALMON, 233, 150, 122, "The CSS color `darksalmon`");
+predefined_color!(DARKSEAGREEN, 143, 188, 143, "The CSS color `darkseagreen`");
+predefined_color!(DARKSLATEBLUE, 72, 61, 139, "The CSS color `darkslateblue`");
+predefined_color!(DARKSLATEGRAY, 47, 79, 79, "The CSS color `darkslategray`");
+predefined_color!(DARKSLATEGREY, 47, 79, 79, "The CSS color `darkslategrey`");
+predefined_color!(DARKTURQUOISE, 0, 206, 209, "The CSS color `darkturquoise`");
+predefined_color!(DARKVIOLET, 148, 0, 211, "The CSS color `darkviolet`");
+predefined_color!(DEEPPINK, 255, 20, 147, "The CSS color `deeppink`");
+predefined_color!(DEEPSKYBLUE, 0, 191, 255, "The CSS color `deepskyblue`");
+predefined_color!(DIMGRAY, 105, 105, 105, "The CSS color `dimgray`");
+predefined_color!(DIMGREY, 105, 105, 105, "The CSS color `dimgrey`");
+predefined_color!(DODGERBLUE, 30, 144, 255, "The CSS color `dodgerblue`");
+predefined_color!(FIREBRICK, 178, 34, 34, "The CSS color `firebrick`");
+predefined_color!(FLORALWHITE, 255, 250, 240, "The CSS color `floralwhite`");
+predefined_color!(FORESTGREEN, 34, 139, 34, "The CSS color `forestgreen`");
+predefined_color!(FUCHSIA, 255, 0, 255, "The CSS color `fuchsia`");
+predefined_color!(GAINSBORO, 220, 220, 220, "The CSS color `gainsboro`");
+predefined_color!(GHOSTWHITE, 248, 248, 255, "The CSS color `ghostwhite`");
+predefined_color!(GOLD, 255, 215, 0, "The CSS color `gold`");
+predefined_color!(GOLDENROD, 218, 165, 32, "The CSS color `goldenrod`");
+predefined_color!(GRAY, 128, 128, 128, "The CSS color `gray`");
+predefined_color!(GREEN, 0, 128, 0, "The CSS color `green`");
+predefined_color!(GREENYELLOW, 173, 255, 47, "The CSS color `greenyellow`");
+predefined_color!(GREY, 128, 128, 128, "The CSS color `grey`");
+predefined_color!(HONEYDEW, 240, 255, 240, "The CSS color `honeydew`");
+predefined_color!(HOTPINK, 255, 105, 180, "The CSS color `hotpink`");
+predefined_color!(INDIANRED, 205, 92, 92, "The CSS color `indianred`");
+predefined_color!(INDIGO, 75, 0, 130, "The CSS color `indigo`");
+predefined_color!(IVORY, 255, 255, 240, "The CSS color `ivory`");
+predefined_color!(KHAKI, 240, 230, 140, "The CSS color `khaki`");
+predefined_color!(LAVENDER, 230, 230, 250, "The CSS color `lavender`");
+predefined_color!(
+    LAVENDERBLUSH,
+    255,
+    240,
+    245,
+    "The CSS color `lavenderblush`"
+);
+predefined_color!(LAWNGREEN, 124, 252, 0, "The CSS color `lawngreen`");
+predefined_color!(LEMONCHIFFON, 255, 250, 205, "The CSS color `lemonchiffon`");
+predefined_color!(LIGHTBLUE, 173, 216, 230, "The CSS color `lightblue`");
+predefined_color!(LIGHTCORAL, 240, 128, 128, "The CSS color `lightcoral`");
+predefined_color!(LIGHTCYAN, 224, 255, 255, "The CSS color `lightcyan`");
+predefined_color!(
+    LIGHTGOLDENRODYELLOW,
+    250,
+    250,
+    210,
+    "The CSS color `lightgoldenrodyellow`"
+);
+predefined_color!(LIGHTGRAY, 211, 211, 211, "The CSS color `lightgray`");
+predefined_color!(LIGHTGREEN, 144, 238, 144, "The CSS color `lightgreen`");
+predefined_color!(LIGHTGREY, 211, 211, 211, "The CSS color `lightgrey`");
+predefined_color!(LIGHTPINK, 255, 182, 193, "The CSS color `lightpink`");
+predefined_color!(LIGHTSALMON, 255, 160, 122, "The CSS color `lightsalmon`");
+predefined_color!(LIGHTSEAGREEN, 32, 178, 170, "The CSS color `lightseagreen`");
+predefined_color!(LIGHTSKYBLUE, 135, 206, 250, "The CSS color `lightskyblue`");
+predefined_color!(
+    LIGHTSLATEGRAY,
+    119,
+    136,
+    153,
+    "The CSS color `lightslategray`"
+);
+predefined_color!(
+    LIGHTSLATEGREY,
+    119,
+    136,
+    153,
+    "The CSS color `lightslategrey`"
+);
+predefined_color!(
+    LIGHTSTEELBLUE,
+    176,
+    196,
+    222,
+    "The CSS color `lightsteelblue`"
+);
+predefined_color!(LIGHTYELLOW, 255, 255, 224, "The CSS color `lightyellow`");
+predefined_color!(LIME, 0, 255, 0, "The CSS color `lime`");
+predefined_color!(LIMEGREEN, 50, 205, 50, "The CSS color `limegreen`");
+predefined_color!(LINEN, 250, 240, 230, "The CSS color `linen`");
+predefined_color!(MAGENTA, 255, 0, 255, "The CSS color `magenta`");
+predefined_color!(MAROON, 128, 0, 0, "The CSS color `maroon`");
+predefined_color!(
+    MEDIUMAQUAMARINE,
+    102,
+    205,
+    170,
+    "The CSS color `mediumaquamarine`"
+);
+predefined_color!(MEDIUMBLUE, 0, 0, 205, "The CSS color `mediumblue`");
+predefined_color!(MEDIUMORCHID, 186, 85, 211, "The CSS color `mediumorchid`");
+predefined_color!(MEDIUMPURPLE, 147, 112, 219, "The CSS color `mediumpurple`");
+predefined_color!(
+    MEDIUMSEAGREEN,
+    60,
+    179,
+    113,
+    "The CSS color `mediumseagreen`"
+);
+predefined_color!(
+    MEDIUMSLATEBLUE,
+    123,
+    104,
+    238,
+    "The CSS color `mediumslateblue`"
+);
+predefined_color!(
+    MEDIUMSPRINGGREEN,
+    0,
+    250,
+    154,
+    "The CSS color `mediumspringgreen`"
+);
+predefined_color!(
+    MEDIUMTURQUOISE,
+    72,
+    209,
+    204,
+    "The CSS color `mediumturquoise`"
+);
+predefined_color!(
+    MEDIUMVIOLETRED,
+    199,
+    21,
+    133,
+    "The CSS color `mediumvioletred`"
+);
+predefined_color!(MIDNIGHTBLUE, 25, 25, 112, "The CSS color `midnightblue`");
+predefined_color!(MINTCREAM, 245, 255, 250, "The CSS color `mintcream`");
+predefined_color!(MISTYROSE, 255, 228, 225, "The CSS color `mistyrose`");
+predefined_color!(MOCCASIN, 255, 228, 181, "The CSS color `moccasin`");
+predefined_color!(NAVAJOWHITE, 255, 222, 173, "The CSS color `navajowhite`");
+predefined_color!(NAVY, 0, 0, 128, "The CSS color `navy`");
+predefined_color!(OLDLACE, 253, 245, 230, "The CSS color `oldlace`");
+predefined_color!(OLIVE, 128, 128, 0, "The CSS color `olive`");
+predefined_color!(OLIVEDRAB, 107, 142, 35, "The CSS color `olivedrab`");
+predefined_color!(ORANGE, 255, 165, 0, "The CSS color `orange`");
+predefined_color!(ORANGERED, 255, 69, 0, "The CSS color `orangered`");
+predefined_color!(ORCHID, 218, 112, 214, "The CSS color `orchid`");
+predefined_color!(
+    PALEGOLDENROD,
+    238,
+    232,
+    170,
+    "The CSS color `palegoldenrod`"
+);
+predefined_color!(PALEGREEN, 152, 251, 152, "The CSS color `palegreen`");
+predefined_color!(
+    PALETURQUOISE,
+    175,
+    238,
+    238,
+    "The CSS color `paleturquoise`"
+);
+predefined_color!(
+    PALEVIOLETRED,
+    219,
+    112,
+    147,
+    "The CSS color `palevioletred`"
+);
+predefined_color!(PAPAYAWHIP, 255, 239, 213, "The CSS color `papayawhip`");
+predefined_color!(PEACHPUFF, 255, 218, 185, "The CSS color `peachpuff`");
+predefined_color!(PERU, 205, 133, 63, "The CSS color `peru`");
+predefined_color!(PINK, 255, 192, 203, "The CSS color `pink`");
+predefined_color!(PLUM, 221, 160, 221, "The CSS color `plum`");
+predefined_color!(POWDERBLUE, 176, 224, 230, "The CSS color `powderblue`");
+predefined_color!(PURPLE, 128, 0, 128, "The CSS color `purple`");
+predefined_color!(REBECCAPURPLE, 102, 51, 153, "The CSS color `rebeccapurple`");
+predefined_color!(RED, 255, 0, 0, "The CSS color `red`");
+predefined_color!(ROSYBROWN, 188, 143, 143, "The CSS color `rosybrown`");
+predefined_color!(ROYALBLUE, 65, 105, 225, "The CSS color `royalblue`");
+predefined_color!(SADDLEBROWN, 139, 69, 19, "The CSS color `saddlebrown`");
+predefined_color!(SALMON, 250, 128, 114, "The CSS color `salmon`");
+predefined_color!(SANDYBROWN, 244, 164, 96, "The CSS color `sandybrown`");
+predefined_color!(SEAGREEN, 46, 139, 87, "The CSS color `seagreen`");
+predefined_color!(SEASHELL, 255, 245, 238, "The CSS color `seashell`");
+predefined_color!(SIENNA, 160, 82, 45, "The CSS color `sienna`");
+predefined_color!(SILVER, 192, 192, 192, "The CSS color `silver`");
+predefined_color!(SKYBLUE, 135, 206, 235, "The CSS color `skyblue`");
+predefined_color!(SLATEBLUE, 106, 90, 205, "The CSS color `slateblue`");
+predefined_color!(SLATEGRAY, 112, 128, 144, "The CSS color `slategray`");
+predefined_color!(SLATEGREY, 112, 128, 144, "The CSS color `slategrey`");
+predefined_color!(SNOW, 255, 250, 250, "The CSS color `snow`");
+predefined_color!(SPRINGGREEN, 0, 255, 127, "The CSS color `springgreen`");
+predefined_color!(STEELBLUE, 70, 130, 180, "The CSS color `steelblue`");
+predefined_color!(TAN, 210, 180, 140, "The CSS color `tan`");
+predefined_color!(TEAL, 0, 128, 128, "The CSS color `teal`");
+predefined_color!(THISTLE, 216, 191, 216, "The CSS color `thistle`");
+predefined_color!(TOMATO, 255, 99, 71, "The CSS color `tomato`");
+predefined_color!(TURQUOISE, 64, 224, 208, "The CSS color `turquoise`");
+predefined_color!(VIOLET, 238, 130, 238, "The CSS color `violet`");
+predefined_color!(WHEAT, 245, 222, 179, "The CSS color `wheat`");
+predefined_color!(WHITE, 255, 255, 255, "The CSS color `white`");
+predefined_color!(WHITESMOKE, 245, 245, 245, "The CSS color `whitesmoke`");
+predefined_color!(YELLOW, 255, 255, 0, "The CSS color `yellow`");
+predefined_color!(YELLOWGREEN, 154, 205, 50, "The CSS color `yellowgreen`");
+
+static NAMED_COLORS: [(&str, RGBColor); 148] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+/// Look up a CSS named color, the name is case insensitive
+///
+/// ```rust
+/// use plotters::style::colors::full_palette::from_css_name;
+/// use plotters::style::Color;
+///
+/// let color = from_css_name("CornflowerBlue").unwrap();
+/// assert_eq!(color.rgb(), (100, 149, 237));
+/// assert!(from_css_name("not-a-color").is_none());
+/// ```
+pub fn from_css_name(name: &str) -> Option<&'static RGBColor> {
+    let name = name.to_ascii_lowercase();
+    NAMED_COLORS
+        .binary_search_by(|(n, _)| n.cmp(&name.as_str()))
+        .ok()
+        .map(|idx| &NAMED_COLORS[idx].1)
+}
@@ -0,0 +1,193 @@
+// The font built into Plotters, which is used by the deterministic rendering mode. The glyphs
+// are 1-bit bitmaps of the printable ASCII characters, rasterized from DejaVu Sans Mono, and they
+// are scaled by an integer factor, so the text looks the same on every machine and every font
+// size maps to the same pixels.
+use super::LayoutBox;
+
+// The size of the glyph cells, the baseline is at the 11th row
+const CELL_WIDTH: i32 = 7;
+const CELL_HEIGHT: i32 = 14;
+
+// The rows of the glyphs from ' ' to '~', the most significant of the 7 bits is the left pixel
+#[rustfmt::skip]
+const GLYPHS: [[u8; CELL_HEIGHT as usize]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x08, 0x08, 0x00, 0x00, 0x00], // '!'
+    [0x00, 0x00, 0x14, 0x14, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x00, 0x00, 0x08, 0x0a, 0x1a, 0x3f, 0x16, 0x36, 0x7e, 0x24, 0x2c, 0x00, 0x00, 0x00], // '#'
+    [0x00, 0x00, 0x00, 0x1e, 0x20, 0x20, 0x1c, 0x0e, 0x03, 0x03, 0x3e, 0x00, 0x00, 0x00], // '$'
+    [0x00, 0x00, 0x30, 0x48, 0x48, 0x39, 0x0c, 0x36, 0x09, 0x09, 0x07, 0x00, 0x00, 0x00], // '%'
+    [0x00, 0x00, 0x1c, 0x30, 0x30, 0x10, 0x28, 0x6d, 0x67, 0x23, 0x3f, 0x00, 0x00, 0x00], // '&'
+    [0x00, 0x00, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '\''
+    [0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x04, 0x04, 0x00], // '('
+    [0x00, 0x00, 0x08, 0x08, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0c, 0x08, 0x08, 0x10, 0x00], // ')'
+    [0x00, 0x00, 0x08, 0x2a, 0x0c, 0x1e, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '*'
+    [0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0c, 0x3f, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x08, 0x00, 0x00], // ','
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x0c, 0x00, 0x00, 0x00], // '.'
+    [0x00, 0x00, 0x02, 0x02, 0x04, 0x04, 0x08, 0x08, 0x18, 0x10, 0x30, 0x20, 0x00, 0x00], // '/'
+    [0x00, 0x00, 0x1e, 0x32, 0x23, 0x23, 0x2f, 0x23, 0x23, 0x32, 0x1e, 0x00, 0x00, 0x00], // '0'
+    [0x00, 0x00, 0x1c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x1f, 0x00, 0x00, 0x00], // '1'
+    [0x00, 0x00, 0x3e, 0x02, 0x02, 0x02, 0x04, 0x0c, 0x18, 0x30, 0x3e, 0x00, 0x00, 0x00], // '2'
+    [0x00, 0x00, 0x3e, 0x02, 0x02, 0x06, 0x0e, 0x02, 0x03, 0x02, 0x3e, 0x00, 0x00, 0x00], // '3'
+    [0x00, 0x00, 0x06, 0x0e, 0x0e, 0x16, 0x26, 0x26, 0x3f, 0x06, 0x06, 0x00, 0x00, 0x00], // '4'
+    [0x00, 0x00, 0x3e, 0x20, 0x20, 0x3c, 0x06, 0x02, 0x02, 0x02, 0x3c, 0x00, 0x00, 0x00], // '5'
+    [0x00, 0x00, 0x1e, 0x30, 0x20, 0x2e, 0x32, 0x23, 0x23, 0x32, 0x1e, 0x00, 0x00, 0x00], // '6'
+    [0x00, 0x00, 0x3f, 0x02, 0x02, 0x04, 0x04, 0x0c, 0x08, 0x08, 0x18, 0x00, 0x00, 0x00], // '7'
+    [0x00, 0x00, 0x1e, 0x22, 0x22, 0x32, 0x1e, 0x22, 0x23, 0x23, 0x1e, 0x00, 0x00, 0x00], // '8'
+    [0x00, 0x00, 0x1e, 0x22, 0x22, 0x23, 0x33, 0x1f, 0x02, 0x02, 0x3c, 0x00, 0x00, 0x00], // '9'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x08, 0x0c, 0x00, 0x00, 0x00], // ':'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x08, 0x00, 0x00], // ';'
+    [0x00, 0x00, 0x00, 0x00, 0x01, 0x0e, 0x38, 0x30, 0x0e, 0x03, 0x00, 0x00, 0x00, 0x00], // '<'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00], // '='
+    [0x00, 0x00, 0x00, 0x00, 0x20, 0x38, 0x07, 0x03, 0x1c, 0x60, 0x00, 0x00, 0x00, 0x00], // '>'
+    [0x00, 0x00, 0x1e, 0x02, 0x02, 0x06, 0x0c, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // '?'
+    [0x00, 0x00, 0x00, 0x1e, 0x21, 0x4f, 0x49, 0x51, 0x49, 0x4f, 0x20, 0x30, 0x0e, 0x00], // '@'
+    [0x00, 0x00, 0x0c, 0x1c, 0x14, 0x16, 0x12, 0x32, 0x3f, 0x23, 0x61, 0x00, 0x00, 0x00], // 'A'
+    [0x00, 0x00, 0x3e, 0x22, 0x23, 0x32, 0x3e, 0x23, 0x21, 0x23, 0x3e, 0x00, 0x00, 0x00], // 'B'
+    [0x00, 0x00, 0x1e, 0x30, 0x20, 0x20, 0x20, 0x20, 0x20, 0x30, 0x1e, 0x00, 0x00, 0x00], // 'C'
+    [0x00, 0x00, 0x3c, 0x26, 0x22, 0x23, 0x23, 0x23, 0x22, 0x26, 0x3c, 0x00, 0x00, 0x00], // 'D'
+    [0x00, 0x00, 0x3f, 0x20, 0x20, 0x30, 0x3e, 0x20, 0x20, 0x20, 0x3f, 0x00, 0x00, 0x00], // 'E'
+    [0x00, 0x00, 0x3f, 0x30, 0x30, 0x30, 0x3e, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00], // 'F'
+    [0x00, 0x00, 0x1e, 0x30, 0x20, 0x20, 0x26, 0x23, 0x23, 0x33, 0x1e, 0x00, 0x00, 0x00], // 'G'
+    [0x00, 0x00, 0x23, 0x23, 0x23, 0x23, 0x3f, 0x23, 0x23, 0x23, 0x23, 0x00, 0x00, 0x00], // 'H'
+    [0x00, 0x00, 0x3e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x3e, 0x00, 0x00, 0x00], // 'I'
+    [0x00, 0x00, 0x1e, 0x06, 0x02, 0x02, 0x02, 0x02, 0x06, 0x06, 0x3c, 0x00, 0x00, 0x00], // 'J'
+    [0x00, 0x00, 0x23, 0x26, 0x2c, 0x38, 0x38, 0x24, 0x26, 0x22, 0x21, 0x00, 0x00, 0x00], // 'K'
+    [0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x3f, 0x00, 0x00, 0x00], // 'L'
+    [0x00, 0x00, 0x63, 0x73, 0x77, 0x75, 0x6d, 0x69, 0x61, 0x61, 0x61, 0x00, 0x00, 0x00], // 'M'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x2b, 0x2b, 0x27, 0x27, 0x27, 0x23, 0x00, 0x00, 0x00], // 'N'
+    [0x00, 0x00, 0x1e, 0x32, 0x23, 0x23, 0x23, 0x23, 0x23, 0x32, 0x1e, 0x00, 0x00, 0x00], // 'O'
+    [0x00, 0x00, 0x3e, 0x23, 0x21, 0x23, 0x3e, 0x30, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00], // 'P'
+    [0x00, 0x00, 0x1e, 0x32, 0x23, 0x23, 0x23, 0x23, 0x23, 0x32, 0x1e, 0x06, 0x00, 0x00], // 'Q'
+    [0x00, 0x00, 0x3c, 0x22, 0x22, 0x22, 0x3c, 0x26, 0x22, 0x23, 0x21, 0x00, 0x00, 0x00], // 'R'
+    [0x00, 0x00, 0x1e, 0x20, 0x20, 0x30, 0x1e, 0x02, 0x03, 0x03, 0x3e, 0x00, 0x00, 0x00], // 'S'
+    [0x00, 0x00, 0x7f, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 'T'
+    [0x00, 0x00, 0x23, 0x23, 0x23, 0x23, 0x23, 0x23, 0x23, 0x22, 0x1e, 0x00, 0x00, 0x00], // 'U'
+    [0x00, 0x00, 0x61, 0x23, 0x22, 0x32, 0x12, 0x16, 0x14, 0x1c, 0x0c, 0x00, 0x00, 0x00], // 'V'
+    [0x00, 0x00, 0x41, 0x41, 0x69, 0x6d, 0x2d, 0x35, 0x37, 0x32, 0x32, 0x00, 0x00, 0x00], // 'W'
+    [0x00, 0x00, 0x23, 0x32, 0x16, 0x0c, 0x0c, 0x1c, 0x12, 0x22, 0x61, 0x00, 0x00, 0x00], // 'X'
+    [0x00, 0x00, 0x61, 0x22, 0x16, 0x1c, 0x0c, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 'Y'
+    [0x00, 0x00, 0x3f, 0x03, 0x02, 0x04, 0x0c, 0x08, 0x10, 0x30, 0x3f, 0x00, 0x00, 0x00], // 'Z'
+    [0x00, 0x00, 0x0c, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0c, 0x00], // '['
+    [0x00, 0x00, 0x20, 0x30, 0x10, 0x10, 0x08, 0x08, 0x04, 0x04, 0x02, 0x02, 0x00, 0x00], // '\\'
+    [0x00, 0x00, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x18, 0x00], // ']'
+    [0x00, 0x00, 0x0c, 0x16, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f], // '_'
+    [0x00, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x00, 0x00, 0x1c, 0x02, 0x02, 0x3e, 0x22, 0x22, 0x3e, 0x00, 0x00, 0x00], // 'a'
+    [0x00, 0x00, 0x20, 0x20, 0x3e, 0x32, 0x23, 0x23, 0x23, 0x32, 0x3e, 0x00, 0x00, 0x00], // 'b'
+    [0x00, 0x00, 0x00, 0x00, 0x0e, 0x10, 0x30, 0x20, 0x20, 0x10, 0x1e, 0x00, 0x00, 0x00], // 'c'
+    [0x00, 0x00, 0x02, 0x02, 0x1e, 0x32, 0x22, 0x22, 0x22, 0x22, 0x1e, 0x00, 0x00, 0x00], // 'd'
+    [0x00, 0x00, 0x00, 0x00, 0x1c, 0x32, 0x23, 0x3f, 0x20, 0x20, 0x1e, 0x00, 0x00, 0x00], // 'e'
+    [0x00, 0x00, 0x0e, 0x08, 0x3e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 'f'
+    [0x00, 0x00, 0x00, 0x00, 0x1e, 0x32, 0x22, 0x22, 0x22, 0x22, 0x1e, 0x02, 0x06, 0x1c], // 'g'
+    [0x00, 0x00, 0x20, 0x20, 0x3e, 0x32, 0x22, 0x22, 0x22, 0x22, 0x22, 0x00, 0x00, 0x00], // 'h'
+    [0x00, 0x00, 0x08, 0x00, 0x18, 0x08, 0x08, 0x08, 0x08, 0x08, 0x3f, 0x00, 0x00, 0x00], // 'i'
+    [0x00, 0x00, 0x04, 0x00, 0x1c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x08, 0x30], // 'j'
+    [0x00, 0x00, 0x30, 0x30, 0x32, 0x36, 0x3c, 0x3c, 0x34, 0x32, 0x33, 0x00, 0x00, 0x00], // 'k'
+    [0x00, 0x30, 0x18, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0e, 0x00, 0x00, 0x00], // 'l'
+    [0x00, 0x00, 0x00, 0x00, 0x3e, 0x29, 0x29, 0x29, 0x29, 0x29, 0x29, 0x00, 0x00, 0x00], // 'm'
+    [0x00, 0x00, 0x00, 0x00, 0x2e, 0x32, 0x22, 0x22, 0x22, 0x22, 0x22, 0x00, 0x00, 0x00], // 'n'
+    [0x00, 0x00, 0x00, 0x00, 0x1c, 0x32, 0x23, 0x23, 0x23, 0x22, 0x1e, 0x00, 0x00, 0x00], // 'o'
+    [0x00, 0x00, 0x00, 0x00, 0x3c, 0x32, 0x23, 0x23, 0x23, 0x32, 0x3e, 0x20, 0x20, 0x20], // 'p'
+    [0x00, 0x00, 0x00, 0x00, 0x1e, 0x32, 0x22, 0x22, 0x22, 0x22, 0x1e, 0x02, 0x02, 0x00], // 'q'
+    [0x00, 0x00, 0x00, 0x00, 0x17, 0x18, 0x18, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 'r'
+    [0x00, 0x00, 0x00, 0x00, 0x1e, 0x30, 0x30, 0x1c, 0x02, 0x02, 0x3e, 0x00, 0x00, 0x00], // 's'
+    [0x00, 0x00, 0x08, 0x08, 0x3e, 0x18, 0x08, 0x08, 0x08, 0x08, 0x0e, 0x00, 0x00, 0x00], // 't'
+    [0x00, 0x00, 0x00, 0x00, 0x22, 0x22, 0x22, 0x22, 0x22, 0x32, 0x1e, 0x00, 0x00, 0x00], // 'u'
+    [0x00, 0x00, 0x00, 0x00, 0x21, 0x22, 0x32, 0x12, 0x14, 0x1c, 0x0c, 0x00, 0x00, 0x00], // 'v'
+    [0x00, 0x00, 0x00, 0x00, 0x41, 0x41, 0x69, 0x2d, 0x26, 0x36, 0x32, 0x00, 0x00, 0x00], // 'w'
+    [0x00, 0x00, 0x00, 0x00, 0x22, 0x12, 0x1c, 0x0c, 0x1c, 0x12, 0x23, 0x00, 0x00, 0x00], // 'x'
+    [0x00, 0x00, 0x00, 0x00, 0x21, 0x23, 0x32, 0x12, 0x14, 0x0c, 0x0c, 0x08, 0x18, 0x30], // 'y'
+    [0x00, 0x00, 0x00, 0x00, 0x3e, 0x02, 0x04, 0x0c, 0x18, 0x10, 0x3e, 0x00, 0x00, 0x00], // 'z'
+    [0x00, 0x00, 0x0e, 0x08, 0x08, 0x08, 0x08, 0x38, 0x08, 0x08, 0x08, 0x08, 0x06, 0x00], // '{'
+    [0x00, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08], // '|'
+    [0x00, 0x00, 0x18, 0x08, 0x08, 0x08, 0x0c, 0x06, 0x08, 0x08, 0x08, 0x08, 0x38, 0x00], // '}'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x2f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
+
+// The glyph of the characters which aren't printable ASCII
+const MISSING_GLYPH: [u8; CELL_HEIGHT as usize] = [
+    0x00, 0x00, 0x3e, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x3e, 0x00, 0x00, 0x00,
+];
+
+// The factor the glyphs are scaled by for the font size, the glyphs are designed for 14 pixels
+fn scale_of(size: f64) -> i32 {
+    ((size / f64::from(CELL_HEIGHT)).round() as i32).max(1)
+}
+
+fn glyph_of(c: char) -> &'static [u8; CELL_HEIGHT as usize] {
+    match c {
+        ' '..='~' => &GLYPHS[c as usize - ' ' as usize],
+        _ => &MISSING_GLYPH,
+    }
+}
+
+// The layout box of the text, with the origin at the upper left corner
+pub(super) fn layout(size: f64, text: &str) -> LayoutBox {
+    let scale = scale_of(size);
+    let len = text.chars().count() as i32;
+    if len == 0 {
+        return ((0, 0), (0, 0));
+    }
+    ((0, 0), (len * CELL_WIDTH * scale, CELL_HEIGHT * scale))
+}
+
+// Draw the text with the upper left corner at the position, the pixels are fully covered
+pub(super) fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+    (x0, y0): (i32, i32),
+    size: f64,
+    text: &str,
+    mut draw: DrawFunc,
+) -> Result<(), E> {
+    let scale = scale_of(size);
+    for (idx, c) in text.chars().enumerate() {
+        let left = x0 + idx as i32 * CELL_WIDTH * scale;
+        for (row, bits) in glyph_of(c).iter().enumerate() {
+            for col in 0..CELL_WIDTH {
+                if bits >> (CELL_WIDTH - 1 - col) & 1 == 0 {
+                    continue;
+                }
+                let (x, y) = (left + col * scale, y0 + row as i32 * scale);
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        draw(x + dx, y + dy, 1.0)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builtin_font() {
+        assert_eq!(layout(12.0, "abc"), ((0, 0), (21, 14)));
+        assert_eq!(layout(28.0, "a\u{00e9}"), ((0, 0), (28, 28)));
+        assert_eq!(layout(12.0, ""), ((0, 0), (0, 0)));
+
+        let mut pixels = vec![];
+        draw::<(), _>((10, 20), 12.0, "|", |x, y, v| {
+            pixels.push((x, y, v));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(pixels.len(), 12);
+        assert!(pixels.iter().all(|&(x, _, v)| x == 13 && v == 1.0));
+
+        // The glyphs are scaled as blocks of pixels
+        let mut count = 0;
+        draw::<(), _>((0, 0), 28.0, "|\u{2603}", |_, _, _| {
+            count += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(count, 4 * (12 + 5 + 5 + 7 * 2));
+    }
+}
@@ -0,0 +1,428 @@
+//! The color glyphs of the TrueType fonts, such as the emoji. The bitmaps of the `CBDT` and the
+//! `sbix` tables are decoded and scaled to the font size, and the layers of the `COLR` table
+//! are filled with their colors and composited.
+use image::imageops::FilterType;
+use image::{ImageFormat, RgbaImage};
+use rusttype::{point, Font, Point, PositionedGlyph, Scale};
+use ttf_parser::colr::{ClipBox, CompositeMode, Paint, Painter};
+use ttf_parser::{Face, GlyphId, RasterGlyphImage, RasterImageFormat, RgbaColor, Tag, Transform};
+
+use super::ttf::RasterGlyph;
+// Write a font file with a single face from its tables
+fn write_font(mut tables: Vec<(Tag, Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| *tag);
+    let num_tables = tables.len() as u16;
+    let entry_selector = 15 - num_tables.max(1).leading_zeros() as u16;
+    let search_range = 16 << entry_selector;
+
+    let mut font = vec![];
+    font.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    for value in &[
+        num_tables,
+        search_range,
+        entry_selector,
+        num_tables * 16 - search_range,
+    ] {
+        font.extend_from_slice(&value.to_be_bytes());
+    }
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        font.extend_from_slice(&tag.to_bytes());
+        font.extend_from_slice(&0u32.to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        // The tables are aligned to 4 bytes
+        offset += (data.len() + 3) & !3;
+    }
+    for (_, data) in &tables {
+        font.extend_from_slice(data);
+        font.resize((font.len() + 3) & !3, 0);
+    }
+    font
+}
+
+/// Add the empty `glyf` and `loca` tables to a font without outlines, such as the emoji fonts
+/// which only have the bitmaps, since rusttype can't load the fonts without outlines. Returns
+/// the font file with the face alone, or `None` when the face has outlines.
+pub(super) fn add_empty_outlines(data: &[u8], index: u32) -> Option<Vec<u8>> {
+    let face = Face::parse(data, index).ok()?;
+    let raw = face.raw_face();
+    let outlines = [b"glyf", b"CFF ", b"CFF2"];
+    if outlines
+        .iter()
+        .any(|tag| raw.table(Tag::from_bytes(tag)).is_some())
+    {
+        return None;
+    }
+    let mut tables: Vec<_> = raw
+        .table_records
+        .into_iter()
+        .filter_map(|record| Some((record.tag, raw.table(record.tag)?.to_vec())))
+        .collect();
+
+    // All the glyphs are empty, thus all the offsets of the glyphs are 0
+    let offset_size = match face.tables().head.index_to_location_format {
+        ttf_parser::head::IndexToLocationFormat::Short => 2,
+        ttf_parser::head::IndexToLocationFormat::Long => 4,
+    };
+    let loca = vec![0; (usize::from(face.number_of_glyphs()) + 1) * offset_size];
+    tables.push((Tag::from_bytes(b"loca"), loca));
+    tables.push((Tag::from_bytes(b"glyf"), vec![]));
+    Some(write_font(tables))
+}
+
+/// Rasterize a color glyph with its colors, the layers of the `COLR` glyphs which are in the
+/// foreground color take `foreground`. Returns `None` when the glyph isn't a color glyph.
+pub(super) fn rasterize_color_glyph(
+    data: &[u8],
+    index: u32,
+    font: &Font<'static>,
+    scale: Scale,
+    glyph: &PositionedGlyph,
+    foreground: (u8, u8, u8),
+) -> Option<RasterGlyph> {
+    let face = Face::parse(data, index).ok()?;
+    let id = GlyphId(glyph.id().0 as u16);
+    // The scale is the height of the glyphs, like rusttype does
+    let v_metrics = font.v_metrics_unscaled();
+    let unit = scale.y / (v_metrics.ascent - v_metrics.descent);
+    let position = glyph.position();
+    let origin = (position.x.floor() as i32, position.y.floor() as i32);
+
+    if face.is_color_glyph(id) {
+        let mut painter = LayerPainter {
+            face: &face,
+            font,
+            scale,
+            unit,
+            position,
+            outline: None,
+            clips: vec![],
+            transforms: vec![],
+            layers: vec![],
+        };
+        let (r, g, b) = foreground;
+        face.paint_color_glyph(id, 0, RgbaColor::new(r, g, b, 255), &mut painter)?;
+        return composite_layers(&painter.layers, origin);
+    }
+
+    let ppem = f32::from(face.units_per_em()) * unit;
+    let image = face.glyph_raster_image(id, ppem.round() as u16)?;
+    raster_image(&image, ppem, position, origin)
+}
+
+// Decode the bitmap of a glyph and scale it to the size of the font
+fn raster_image(
+    image: &RasterGlyphImage,
+    ppem: f32,
+    position: Point<f32>,
+    origin: (i32, i32),
+) -> Option<RasterGlyph> {
+    let bitmap = match image.format {
+        RasterImageFormat::PNG => image::load_from_memory_with_format(image.data, ImageFormat::Png)
+            .ok()?
+            .to_rgba8(),
+        RasterImageFormat::BitmapPremulBgra32 => {
+            let mut pixels = image.data.to_vec();
+            for pixel in pixels.chunks_exact_mut(4) {
+                let alpha = u32::from(pixel[3]);
+                let straight = |c: u8| (u32::from(c) * 255 / alpha.max(1)).min(255) as u8;
+                let (r, g, b) = (straight(pixel[2]), straight(pixel[1]), straight(pixel[0]));
+                pixel[..3].copy_from_slice(&[r, g, b]);
+            }
+            RgbaImage::from_raw(image.width.into(), image.height.into(), pixels)?
+        }
+        // The monochrome and the gray bitmaps have no colors
+        _ => return None,
+    };
+
+    let factor = ppem / f32::from(image.pixels_per_em);
+    let width = (bitmap.width() as f32 * factor).round().max(1.0) as u32;
+    let height = (bitmap.height() as f32 * factor).round().max(1.0) as u32;
+    let bitmap = image::imageops::resize(&bitmap, width, height, FilterType::Triangle);
+    // The vertical offset of the bitmap is the one of its bottom above the baseline
+    let left = position.x + f32::from(image.x) * factor;
+    let top = position.y - f32::from(image.y) * factor - height as f32;
+
+    Some(RasterGlyph {
+        x0: left.round() as i32 - origin.0,
+        y0: top.round() as i32 - origin.1,
+        width: width as usize,
+        coverage: bitmap.pixels().map(|p| f32::from(p[3]) / 255.0).collect(),
+        colors: bitmap.pixels().map(|p| (p[0], p[1], p[2])).collect(),
+    })
+}
+
+// Collects the layers of a `COLR` glyph, which are the outlines of the other glyphs filled with
+// a color. This is a simplified painter: the gradients are filled with the average color of
+// their stops, the composite modes are all source over, and the transforms other than the
+// scaling and the translation are ignored.
+struct LayerPainter<'a, 'f> {
+    face: &'f Face<'a>,
+    font: &'f Font<'static>,
+    scale: Scale,
+    unit: f32,
+    position: Point<f32>,
+    outline: Option<GlyphId>,
+    // The glyphs of the clip paths, or `None` for the clip boxes
+    clips: Vec<Option<GlyphId>>,
+    transforms: Vec<Transform>,
+    layers: Vec<(PositionedGlyph<'static>, [f32; 4])>,
+}
+
+impl<'a> Painter<'a> for LayerPainter<'a, '_> {
+    fn outline_glyph(&mut self, glyph_id: GlyphId) {
+        self.outline = Some(glyph_id);
+    }
+
+    fn paint(&mut self, paint: Paint<'a>) {
+        let coords = self.face.variation_coordinates();
+        let stops: Vec<_> = match paint {
+            Paint::Solid(color) => vec![color],
+            Paint::LinearGradient(gradient) => gradient.stops(0, coords).map(|s| s.color).collect(),
+            Paint::RadialGradient(gradient) => gradient.stops(0, coords).map(|s| s.color).collect(),
+            Paint::SweepGradient(gradient) => gradient.stops(0, coords).map(|s| s.color).collect(),
+        };
+        if stops.is_empty() {
+            return;
+        }
+        let mut color = [0.0; 4];
+        for stop in &stops {
+            for (sum, c) in color
+                .iter_mut()
+                .zip(&[stop.red, stop.green, stop.blue, stop.alpha])
+            {
+                *sum += f32::from(*c) / 255.0 / stops.len() as f32;
+            }
+        }
+
+        // The paint fills the innermost clip path, or the outline of the layers without clip
+        let glyph = if self.clips.is_empty() {
+            self.outline
+        } else {
+            self.clips.iter().rev().find_map(|clip| *clip)
+        };
+        let glyph = match glyph {
+            Some(glyph) => glyph,
+            None => return,
+        };
+        let t = self.transforms.last().copied().unwrap_or_default();
+        let (sx, sy, dx, dy) = if t.b == 0.0 && t.c == 0.0 && t.a > 0.0 && t.d > 0.0 {
+            (t.a, t.d, t.e, t.f)
+        } else {
+            (1.0, 1.0, 0.0, 0.0)
+        };
+        let layer = self
+            .font
+            .glyph(rusttype::GlyphId(glyph.0.into()))
+            .scaled(Scale {
+                x: self.scale.x * sx,
+                y: self.scale.y * sy,
+            })
+            .positioned(point(
+                self.position.x + dx * self.unit,
+                self.position.y - dy * self.unit,
+            ));
+        self.layers.push((layer, color));
+    }
+
+    fn push_clip(&mut self) {
+        self.clips.push(self.outline);
+    }
+
+    fn push_clip_box(&mut self, _clipbox: ClipBox) {
+        self.clips.push(None);
+    }
+
+    fn pop_clip(&mut self) {
+        self.clips.pop();
+    }
+
+    fn push_layer(&mut self, _mode: CompositeMode) {}
+
+    fn pop_layer(&mut self) {}
+
+    fn push_transform(&mut self, transform: Transform) {
+        let current = self.transforms.last().copied().unwrap_or_default();
+        self.transforms.push(Transform::combine(current, transform));
+    }
+
+    fn pop_transform(&mut self) {
+        self.transforms.pop();
+    }
+}
+
+// Composite the layers from the bottom up
+fn composite_layers(
+    layers: &[(PositionedGlyph<'static>, [f32; 4])],
+    origin: (i32, i32),
+) -> Option<RasterGlyph> {
+    let rects: Vec<_> = layers
+        .iter()
+        .filter_map(|(glyph, _)| glyph.pixel_bounding_box())
+        .collect();
+    let min_x = rects.iter().map(|r| r.min.x).min()?;
+    let min_y = rects.iter().map(|r| r.min.y).min()?;
+    let max_x = rects.iter().map(|r| r.max.x).max()?;
+    let max_y = rects.iter().map(|r| r.max.y).max()?;
+    let width = (max_x - min_x) as usize;
+
+    // The premultiplied colors of the pixels
+    let mut canvas = vec![[0.0f32; 4]; width * (max_y - min_y) as usize];
+    for (glyph, color) in layers {
+        let rect = match glyph.pixel_bounding_box() {
+            Some(rect) => rect,
+            None => continue,
+        };
+        glyph.draw(|x, y, v| {
+            let x = (x as i32 + rect.min.x - min_x) as usize;
+            let y = (y as i32 + rect.min.y - min_y) as usize;
+            let pixel = &mut canvas[y * width + x];
+            let alpha = v * color[3];
+            for (c, value) in pixel.iter_mut().zip(&[color[0], color[1], color[2], 1.0]) {
+                *c = value * alpha + *c * (1.0 - alpha);
+            }
+        });
+    }
+
+    let straight = |c: f32, alpha: f32| (c / alpha * 255.0).round().min(255.0) as u8;
+    Some(RasterGlyph {
+        x0: min_x - origin.0,
+        y0: min_y - origin.1,
+        width,
+        coverage: canvas.iter().map(|p| p[3]).collect(),
+        colors: canvas
+            .iter()
+            .map(|p| match p[3] {
+                a if a > 0.0 => (straight(p[0], a), straight(p[1], a), straight(p[2], a)),
+                _ => (0, 0, 0),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use font_kit::family_name::FamilyName;
+    use font_kit::handle::Handle;
+    use font_kit::properties::Properties;
+    use font_kit::source::SystemSource;
+    use rusttype::FontCollection;
+
+    fn system_font() -> (Vec<u8>, u32) {
+        let handle = SystemSource::new()
+            .select_best_match(&[FamilyName::SansSerif], &Properties::new())
+            .unwrap();
+        match handle {
+            Handle::Path { path, font_index } => (std::fs::read(path).unwrap(), font_index),
+            Handle::Memory { bytes, font_index } => (bytes[..].to_owned(), font_index),
+        }
+    }
+
+    fn load(data: Vec<u8>, index: u32) -> Option<Font<'static>> {
+        FontCollection::from_bytes(data)
+            .ok()?
+            .font_at(index as usize)
+            .ok()
+    }
+
+    #[test]
+    fn test_add_empty_outlines() {
+        let (data, index) = system_font();
+        assert!(add_empty_outlines(&data, index).is_none());
+
+        // Remove the outlines of the font, like the emoji fonts which only have the bitmaps
+        let face = Face::parse(&data, index).unwrap();
+        let raw = face.raw_face();
+        let tables: Vec<_> = raw
+            .table_records
+            .into_iter()
+            .filter(|record| ![b"glyf", b"loca"].contains(&&record.tag.to_bytes()))
+            .map(|record| (record.tag, raw.table(record.tag).unwrap().to_vec()))
+            .collect();
+        let bitmap_font = write_font(tables);
+        assert!(load(bitmap_font.clone(), 0).is_none());
+
+        let font = load(add_empty_outlines(&bitmap_font, 0).unwrap(), 0).unwrap();
+        let original = load(data, index).unwrap();
+        let scale = Scale::uniform(20.0);
+        for c in "Ag".chars() {
+            let glyph = font.glyph(c).scaled(scale);
+            let expected = original.glyph(c).scaled(scale);
+            assert_eq!(glyph.id(), expected.id());
+            assert_eq!(glyph.h_metrics(), expected.h_metrics());
+            assert!(glyph
+                .positioned(point(0.0, 0.0))
+                .pixel_bounding_box()
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_raster_image() {
+        // A red square with a transparent column on the right
+        let mut bitmap = RgbaImage::from_pixel(4, 4, image::Rgba([255, 0, 0, 255]));
+        for y in 0..4 {
+            bitmap.put_pixel(3, y, image::Rgba([0, 0, 255, 0]));
+        }
+        let mut data = vec![];
+        image::DynamicImage::ImageRgba8(bitmap)
+            .write_to(&mut data, ImageFormat::Png)
+            .unwrap();
+        let image = RasterGlyphImage {
+            x: 1,
+            y: -1,
+            width: 4,
+            height: 4,
+            pixels_per_em: 8,
+            format: RasterImageFormat::PNG,
+            data: &data,
+        };
+
+        let raster = raster_image(&image, 16.0, point(10.5, 20.0), (10, 20)).unwrap();
+        // The bitmap is scaled twice and its bottom is 2 pixels below the baseline
+        assert_eq!((raster.width, raster.coverage.len()), (8, 64));
+        assert_eq!((raster.x0, raster.y0), (3, -6));
+        assert_eq!(raster.colors[0], (255, 0, 0));
+        assert_eq!(raster.coverage[0], 1.0);
+        assert_eq!(raster.coverage[7], 0.0);
+    }
+
+    #[test]
+    fn test_layers() {
+        let (data, index) = system_font();
+        let face = Face::parse(&data, index).unwrap();
+        let font = load(data.clone(), index).unwrap();
+        let scale = Scale::uniform(20.0);
+        let mut painter = LayerPainter {
+            face: &face,
+            font: &font,
+            scale,
+            unit: 0.0,
+            position: point(0.0, 20.0),
+            outline: None,
+            clips: vec![],
+            transforms: vec![],
+            layers: vec![],
+        };
+        let red = RgbaColor::new(255, 0, 0, 255);
+        painter.outline_glyph(face.glyph_index('I').unwrap());
+        painter.paint(Paint::Solid(red));
+        let single = composite_layers(&painter.layers, (0, 20)).unwrap();
+        assert!(single.coverage.contains(&1.0));
+
+        // The blue layer is clipped by the outline and covers the red one
+        painter.push_clip();
+        painter.paint(Paint::Solid(RgbaColor::new(0, 0, 255, 255)));
+        painter.pop_clip();
+        let layered = composite_layers(&painter.layers, (0, 20)).unwrap();
+        assert_eq!((layered.x0, layered.y0), (single.x0, single.y0));
+        for (idx, &v) in single.coverage.iter().enumerate() {
+            if v == 1.0 {
+                assert_eq!(single.colors[idx], (255, 0, 0));
+                assert_eq!(layered.colors[idx], (0, 0, 255));
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use super::{FontData, FontDataInternal};
-use crate::style::text_anchor::Pos;
+use super::{builtin, is_deterministic_rendering, FontData, FontDataInternal};
 use crate::style::{Color, TextStyle};
 
-use std::convert::From;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::convert::From;
 
 /// The error type for the font implementation
 pub type FontError = <FontDataInternal as FontData>::ErrorType;
@@ -21,6 +22,8 @@ pub enum FontTransform {
     Rotate180,
     /// Rotating the text 270 degree clockwise
     Rotate270,
+    /// Rotating the text by the given angle in degrees clockwise
+    RotateAngle(f32),
 }
 
 impl FontTransform {
@@ -35,6 +38,25 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(_) => {
+                let (x, y) = self.transform_f64(f64::from(x), f64::from(y));
+                (x.round() as i32, y.round() as i32)
+            }
+        }
+    }
+
+    /// Transform the coordinate without rounding, which is used by the rasterizer for the
+    /// rotations that don't map the pixel grid onto itself
+    pub(crate) fn transform_f64(&self, x: f64, y: f64) -> (f64, f64) {
+        match self {
+            FontTransform::None => (x, y),
+            FontTransform::Rotate90 => (-y, x),
+            FontTransform::Rotate180 => (-x, -y),
+            FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(angle) => {
+                let (sin, cos) = f64::from(*angle).to_radians().sin_cos();
+                (x * cos - y * sin, x * sin + y * cos)
+            }
         }
     }
 }
@@ -47,6 +69,23 @@ pub struct FontDesc<'a> {
     data: FontResult<FontDataInternal>,
     transform: FontTransform,
     style: FontStyle,
+    fallback: Vec<FontFamily<'a>>,
+}
+
+// Load the font data of the family with the fonts providing the missing glyphs, the fallback
+// fonts which can't be loaded are skipped
+fn load_font_data(
+    family: FontFamily,
+    style: FontStyle,
+    fallback: &[FontFamily],
+) -> FontResult<FontDataInternal> {
+    let mut data = FontDataInternal::new(family, style)?;
+    for &family in fallback {
+        if let Ok(fallback_data) = FontDataInternal::new(family, style) {
+            data.add_fallback(fallback_data);
+        }
+    }
+    Ok(data)
 }
 
 /// Describes font family.
@@ -99,16 +138,43 @@ pub enum FontStyle {
     Italic,
     /// The bold style
     Bold,
+    /// The bold and italic style
+    BoldItalic,
+    /// The upright style with a numeric weight, in the CSS range of 100 (thin) to 900 (black)
+    Weight(u16),
 }
 
 impl FontStyle {
     /// Convert the font style into a CSS compatible string which can be used in `font-style` attribute.
+    /// The numeric weights have the normal style, see `weight` for the weight of the font.
     pub fn as_str(&self) -> &str {
         match self {
             FontStyle::Normal => "normal",
             FontStyle::Italic => "italic",
             FontStyle::Oblique => "oblique",
             FontStyle::Bold => "bold",
+            FontStyle::BoldItalic => "bold italic",
+            FontStyle::Weight(_) => "normal",
+        }
+    }
+
+    /// Get the slant of the font, which is the value of the CSS `font-style` property,
+    /// one of "normal", "italic" and "oblique"
+    pub fn slant(&self) -> &str {
+        match self {
+            FontStyle::Italic | FontStyle::BoldItalic => "italic",
+            FontStyle::Oblique => "oblique",
+            _ => "normal",
+        }
+    }
+
+    /// Get the numeric weight of the font, which is the value of the CSS `font-weight` property.
+    /// The normal weight is 400 and the bold weight is 700.
+    pub fn weight(&self) -> u16 {
+        match self {
+            FontStyle::Bold | FontStyle::BoldItalic => 700,
+            FontStyle::Weight(weight) => (*weight).clamp(1, 1000),
+            _ => 400,
         }
     }
 }
@@ -120,11 +186,26 @@ impl<'a> From<&'a str> for FontStyle {
             "italic" => FontStyle::Italic,
             "oblique" => FontStyle::Oblique,
             "bold" => FontStyle::Bold,
-            _ => FontStyle::Normal,
+            "bold italic" | "italic bold" => FontStyle::BoldItalic,
+            "thin" => FontStyle::Weight(100),
+            "light" => FontStyle::Weight(300),
+            "medium" => FontStyle::Weight(500),
+            "semibold" => FontStyle::Weight(600),
+            "black" => FontStyle::Weight(900),
+            other => other
+                .parse()
+                .map(FontStyle::Weight)
+                .unwrap_or(FontStyle::Normal),
         }
     }
 }
 
+impl From<u16> for FontStyle {
+    fn from(weight: u16) -> FontStyle {
+        FontStyle::Weight(weight)
+    }
+}
+
 impl<'a> From<&'a str> for FontDesc<'a> {
     fn from(from: &'a str) -> FontDesc<'a> {
         FontDesc::new(from.into(), 1.0, FontStyle::Normal)
@@ -187,6 +268,7 @@ impl<'a> FontDesc<'a> {
             data: FontDataInternal::new(family, style),
             transform: FontTransform::None,
             style,
+            fallback: vec![],
         }
     }
 
@@ -201,6 +283,7 @@ impl<'a> FontDesc<'a> {
             data: self.data.clone(),
             transform: self.transform.clone(),
             style: self.style,
+            fallback: self.fallback.clone(),
         }
     }
 
@@ -212,12 +295,37 @@ impl<'a> FontDesc<'a> {
         Self {
             size: self.size,
             family: self.family,
-            data: self.data.clone(),
+            data: load_font_data(self.family, style, &self.fallback),
             transform: self.transform.clone(),
             style,
+            fallback: self.fallback.clone(),
         }
     }
 
+    /// Set the fallback fonts, which provide the glyphs missing from the font, such as CJK
+    /// characters and symbols. The fallback fonts are searched in order and the ones that are
+    /// not available are skipped. For the backends which render the text themselves, such as
+    /// SVG, the fallbacks are appended to the font family list.
+    ///
+    /// - `families`: The fallback font families
+    /// - **returns** The new font description with the fallback fonts
+    pub fn fallback<F: Into<FontFamily<'a>> + Copy>(&self, families: &[F]) -> Self {
+        let fallback: Vec<FontFamily<'a>> = families.iter().map(|&f| f.into()).collect();
+        Self {
+            size: self.size,
+            family: self.family,
+            data: load_font_data(self.family, self.style, &fallback),
+            transform: self.transform.clone(),
+            style: self.style,
+            fallback,
+        }
+    }
+
+    /// Get the fallback font families
+    pub fn get_fallback(&self) -> &[FontFamily<'a>] {
+        &self.fallback
+    }
+
     /// Set the font transformation
     ///
     /// - `trans`: The new transformation
@@ -229,6 +337,26 @@ impl<'a> FontDesc<'a> {
             data: self.data.clone(),
             transform: trans,
             style: self.style,
+            fallback: self.fallback.clone(),
+        }
+    }
+
+    /// Set if the glyphs are hinted, which fits them to the pixel grid
+    ///
+    /// - `hinting`: If the hinting is on
+    /// - **returns** The new font description with the hinting turned on or off
+    pub fn hinting(&self, hinting: bool) -> Self {
+        let mut data = self.data.clone();
+        if let Ok(data) = data.as_mut() {
+            data.set_hinting(hinting);
+        }
+        Self {
+            size: self.size,
+            family: self.family,
+            data,
+            transform: self.transform.clone(),
+            style: self.style,
+            fallback: self.fallback.clone(),
         }
     }
 
@@ -240,9 +368,8 @@ impl<'a> FontDesc<'a> {
     /// Set the color of the font and return the result text style object
     pub fn color<C: Color>(&self, color: &C) -> TextStyle<'a> {
         TextStyle {
-            font: self.clone(),
             color: color.to_rgba(),
-            pos: Pos::default(),
+            ..TextStyle::from(self.clone())
         }
     }
 
@@ -266,6 +393,9 @@ impl<'a> FontDesc<'a> {
     /// For a TTF type, zero point of the layout box is the left most baseline char of the string
     /// Thus the upper bound of the box is most likely be negative
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
+        if is_deterministic_rendering() {
+            return Ok(builtin::layout(self.size, text));
+        }
         match &self.data {
             Ok(ref font) => font.estimate_layout(self.size, text),
             Err(e) => Err(e.clone()),
@@ -277,6 +407,16 @@ impl<'a> FontDesc<'a> {
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
+        if let FontTransform::RotateAngle(angle) = self.transform {
+            // The bounding box of the rotated layout box
+            let (w, h) = (f64::from(max_x - min_x), f64::from(max_y - min_y));
+            let (sin, cos) = f64::from(angle).to_radians().sin_cos();
+            let (sin, cos) = (sin.abs(), cos.abs());
+            return Ok((
+                (w * cos + h * sin).round() as u32,
+                (w * sin + h * cos).round() as u32,
+            ));
+        }
         let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
         Ok((w.abs() as u32, h.abs() as u32))
     }
@@ -288,9 +428,49 @@ impl<'a> FontDesc<'a> {
         (x, y): (i32, i32),
         draw: DrawFunc,
     ) -> FontResult<Result<(), E>> {
+        if is_deterministic_rendering() {
+            return Ok(builtin::draw((x, y), self.size, text, draw));
+        }
         match &self.data {
             Ok(ref font) => font.draw((x, y), self.size, text, draw),
             Err(e) => Err(e.clone()),
         }
     }
+
+    /// Draws a font like `draw`, but the pixels of the color glyphs, such as the emoji, are
+    /// given with their RGB colors when the `color_font` feature is enabled. The layers of the
+    /// color glyphs in the foreground color take `color`, which is the color of the text.
+    pub fn draw_color<E, DrawFunc: FnMut(i32, i32, f32, Option<(u8, u8, u8)>) -> Result<(), E>>(
+        &self,
+        text: &str,
+        (x, y): (i32, i32),
+        color: (u8, u8, u8),
+        mut draw: DrawFunc,
+    ) -> FontResult<Result<(), E>> {
+        if is_deterministic_rendering() {
+            return Ok(builtin::draw((x, y), self.size, text, |x, y, v| {
+                draw(x, y, v, None)
+            }));
+        }
+        match &self.data {
+            Ok(ref font) => font.draw_color((x, y), self.size, text, color, draw),
+            Err(e) => Err(e.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_font_style_weight_and_slant() {
+        let style = FontStyle::from("Italic Bold");
+        assert_eq!((style.slant(), style.weight()), ("italic", 700));
+        let style = FontStyle::from("300");
+        assert_eq!((style.slant(), style.weight()), ("normal", 300));
+        assert_eq!(FontStyle::from("semibold").weight(), 600);
+        assert_eq!(FontStyle::from("oblique").slant(), "oblique");
+        assert_eq!(FontStyle::from("unknown").weight(), 400);
+    }
 }
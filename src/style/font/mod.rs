@@ -10,6 +10,13 @@
 mod ttf;
 #[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
 use ttf::FontDataInternal;
+#[cfg(all(not(target_arch = "wasm32"), feature = "color_font"))]
+mod color_glyph;
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+pub use ttf::{
+    clear_glyph_cache, glyph_cache_stats, list_font_families, register_font, register_font_file,
+    register_font_style, set_glyph_cache_capacity, GlyphCacheStats,
+};
 
 #[cfg(all(not(target_arch = "wasm32"), not(feature = "ttf")))]
 mod naive;
@@ -21,22 +28,83 @@ mod web;
 #[cfg(target_arch = "wasm32")]
 use web::FontDataInternal;
 
+mod builtin;
 mod font_desc;
 pub use font_desc::*;
 
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+// Serializes the snapshot tests, the tests which turn on the deterministic rendering mode and
+// the tests depending on the fonts installed, since the mode is shared by all the threads
+#[cfg(any(test, feature = "testing"))]
+pub(crate) static DETERMINISTIC_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Turn on or off the deterministic rendering mode of the whole process, including the threads
+/// drawing the split bitmap backends in parallel. In this mode, all the text is measured and
+/// drawn with the bitmap font built into Plotters, instead of the fonts installed on the
+/// system, so the same chart gives bit-identical bitmaps on every machine, and the downstream projects can commit the golden images of their charts. The
+/// font has fixed metrics, the glyphs are scaled by a whole factor of the font size, and the
+/// font family and style are ignored. The other parts are already deterministic, including
+/// the formatting of the numbers, except the text rotated by an arbitrary angle, which relies
+/// on the trigonometry of the platform.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::style::set_deterministic_rendering;
+///
+/// set_deterministic_rendering(true);
+/// let mut buf = vec![0; 300 * 200 * 3];
+/// {
+///     let root = BitMapBackend::with_buffer(&mut buf, (300, 200)).into_drawing_area();
+///     root.fill(&WHITE).unwrap();
+///     root.draw(&Text::new("Same everywhere", (10, 10), ("sans-serif", 20)))
+///         .unwrap();
+/// }
+/// set_deterministic_rendering(false);
+/// ```
+pub fn set_deterministic_rendering(enabled: bool) {
+    DETERMINISTIC.store(enabled, Ordering::Relaxed);
+}
+
+/// Check if the deterministic rendering mode is on, see `set_deterministic_rendering`
+pub fn is_deterministic_rendering() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
 pub type LayoutBox = ((i32, i32), (i32, i32));
 
 pub trait FontData: Clone {
-    type ErrorType: Sized + std::error::Error + Clone;
+    type ErrorType: Sized + core::error::Error + Clone;
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, Self::ErrorType>;
+    /// Append a font which provides the glyphs missing from this font, the fallbacks are
+    /// searched in the order they are added
+    fn add_fallback(&mut self, _fallback: Self) {}
+    /// Turn on or off the hinting, which fits the glyphs to the pixel grid so the small text
+    /// is sharper. It's ignored by the font implementations which don't rasterize the text.
+    fn set_hinting(&mut self, _hinting: bool) {}
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType>;
+    /// Draw the text with a drawing function, which gets the pixels and their coverage. The
+    /// font implementations which can't rasterize the text return an error.
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
-        _pos: (i32, i32),
-        _size: f64,
-        _text: &str,
-        _draw: DrawFunc,
+        pos: (i32, i32),
+        size: f64,
+        text: &str,
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType>;
+    /// Draw the text like `draw`, but the pixels of the color glyphs, such as the emoji, are
+    /// given with their colors, and `color` is the color of the text, which some layers of the
+    /// color glyphs take. The pixels of the other glyphs have no color.
+    fn draw_color<E, DrawFunc: FnMut(i32, i32, f32, Option<(u8, u8, u8)>) -> Result<(), E>>(
+        &self,
+        pos: (i32, i32),
+        size: f64,
+        text: &str,
+        _color: (u8, u8, u8),
+        mut draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::ErrorType> {
-        panic!("The font implementation is unable to draw text");
+        self.draw(pos, size, text, |x, y, v| draw(x, y, v, None))
     }
 }
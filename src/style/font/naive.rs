@@ -1,17 +1,23 @@
 use super::{FontData, FontFamily, FontStyle, LayoutBox};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
+/// The error of the font implementation without the `ttf` feature, which is only able to
+/// estimate the text layout
 #[derive(Debug, Clone)]
 pub struct FontError;
 
-impl std::fmt::Display for FontError {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        write!(fmt, "General Error")?;
+impl core::fmt::Display for FontError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(fmt, "Unable to rasterize text without the ttf feature")?;
         Ok(())
     }
 }
 
-impl std::error::Error for FontError {}
+impl core::error::Error for FontError {}
 
+// The family and the style are kept for the text estimation only
+#[allow(dead_code)]
 #[derive(Clone)]
 pub struct FontDataInternal(String, String);
 
@@ -37,4 +43,14 @@ impl FontData for FontDataInternal {
             ),
         ))
     }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        _pos: (i32, i32),
+        _size: f64,
+        _text: &str,
+        _draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType> {
+        Err(FontError)
+    }
 }
@@ -1,11 +1,13 @@
 use std::borrow::{Borrow, Cow};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::i32;
 use std::io::Read;
-use std::sync::{Arc, RwLock};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
 
 use lazy_static::lazy_static;
-use rusttype::{point, Error, Font, FontCollection, Scale, SharedBytes};
+use rusttype::{point, Error, Font, FontCollection, GlyphId, PositionedGlyph, Scale, SharedBytes};
 
 use font_kit::family_name::FamilyName;
 use font_kit::handle::Handle;
@@ -13,7 +15,6 @@ use font_kit::properties::{Properties, Style, Weight};
 use font_kit::source::SystemSource;
 
 use super::{FontData, FontFamily, FontStyle, LayoutBox};
-
 type FontResult<T> = Result<T, FontError>;
 
 #[derive(Debug, Clone)]
@@ -37,49 +38,310 @@ impl std::fmt::Display for FontError {
 
 impl std::error::Error for FontError {}
 
+/// A parsed font with an unique id, which identifies the font in the glyph cache
+#[derive(Clone)]
+pub struct LoadedFont {
+    id: usize,
+    font: Font<'static>,
+    // The font data and the index of the face, which are parsed again by the shaper and for
+    // the color glyphs
+    #[cfg(any(feature = "shaping", feature = "color_font"))]
+    data: Arc<[u8]>,
+    #[cfg(any(feature = "shaping", feature = "color_font"))]
+    index: u32,
+    #[cfg(feature = "color_font")]
+    color_glyphs: bool,
+}
+
+impl std::ops::Deref for LoadedFont {
+    type Target = Font<'static>;
+    fn deref(&self) -> &Font<'static> {
+        &self.font
+    }
+}
+
+impl LoadedFont {
+    // If the glyphs may have their own colors
+    #[cfg(feature = "color_font")]
+    fn has_color_glyphs(&self) -> bool {
+        self.color_glyphs
+    }
+
+    #[cfg(not(feature = "color_font"))]
+    fn has_color_glyphs(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "color_font")]
+    fn rasterize_color_glyph(
+        &self,
+        scale: Scale,
+        glyph: &PositionedGlyph,
+        foreground: (u8, u8, u8),
+    ) -> Option<RasterGlyph> {
+        super::color_glyph::rasterize_color_glyph(
+            &self.data, self.index, &self.font, scale, glyph, foreground,
+        )
+    }
+
+    #[cfg(not(feature = "color_font"))]
+    fn rasterize_color_glyph(
+        &self,
+        _scale: Scale,
+        _glyph: &PositionedGlyph,
+        _foreground: (u8, u8, u8),
+    ) -> Option<RasterGlyph> {
+        None
+    }
+}
+
+// The key of a rasterized glyph: the font id, the size, the glyph id, the sub-pixel offset in
+// steps and the color of the text, which is only given for the color glyphs
+type GlyphKey = (usize, u32, u32, u32, Option<(u8, u8, u8)>);
+
+// The glyphs are rasterized at the horizontal offsets of a quarter of a pixel, so the same glyph
+// at the close positions is rasterized once
+const SUBPIXEL_STEPS: f32 = 4.0;
+
+// The coverage of a rasterized glyph, the position of the bounding box is relative to the
+// integer part of the glyph position. The color glyphs have the colors of their pixels.
+pub(super) struct RasterGlyph {
+    pub(super) x0: i32,
+    pub(super) y0: i32,
+    pub(super) width: usize,
+    pub(super) coverage: Vec<f32>,
+    pub(super) colors: Vec<(u8, u8, u8)>,
+}
+
+struct GlyphCache {
+    glyphs: HashMap<GlyphKey, Arc<RasterGlyph>>,
+    // The keys in the order the glyphs are added, the oldest glyph is removed when it's full
+    order: VecDeque<GlyphKey>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+/// The statistics of the glyph cache, which is shared by all the text rendered in the process
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlyphCacheStats {
+    /// The number of glyphs found in the cache
+    pub hits: u64,
+    /// The number of glyphs rasterized because they were not in the cache
+    pub misses: u64,
+    /// The number of glyphs in the cache
+    pub entries: usize,
+    /// The maximum number of glyphs in the cache
+    pub capacity: usize,
+}
+
 lazy_static! {
-    static ref CACHE: RwLock<HashMap<String, FontResult<Font<'static>>>> =
+    static ref CACHE: RwLock<HashMap<String, FontResult<LoadedFont>>> = RwLock::new(HashMap::new());
+    static ref REGISTERED: RwLock<HashMap<String, Vec<(FontStyle, LoadedFont)>>> =
         RwLock::new(HashMap::new());
+    static ref GLYPH_CACHE: Mutex<GlyphCache> = Mutex::new(GlyphCache {
+        glyphs: HashMap::new(),
+        order: VecDeque::new(),
+        capacity: 4096,
+        hits: 0,
+        misses: 0,
+    });
+}
+
+// The glyph cache only keeps the rasterized glyphs, so it's still usable after a panic while
+// it was locked
+fn glyph_cache() -> MutexGuard<'static, GlyphCache> {
+    GLYPH_CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl GlyphCache {
+    // Add a glyph, the oldest glyph is removed when the cache is full
+    fn insert(&mut self, key: GlyphKey, raster: Arc<RasterGlyph>) {
+        if self.glyphs.insert(key, raster).is_none() {
+            self.evict(self.capacity - 1);
+            self.order.push_back(key);
+        }
+    }
+
+    // Remove the oldest glyphs until there are at most `len` glyphs
+    fn evict(&mut self, len: usize) {
+        while self.order.len() > len {
+            if let Some(key) = self.order.pop_front() {
+                self.glyphs.remove(&key);
+            }
+        }
+    }
 }
 
+static NEXT_FONT_ID: AtomicUsize = AtomicUsize::new(0);
+
 thread_local! {
     static FONT_SOURCE: SystemSource = SystemSource::new();
 }
 
+fn parse_font(data: Vec<u8>, idx: usize) -> FontResult<LoadedFont> {
+    let parse = |data: &Arc<[u8]>, idx| {
+        FontCollection::from_bytes(Into::<SharedBytes>::into(data.clone()))
+            .and_then(|collection| collection.font_at(idx))
+    };
+    let data: Arc<[u8]> = data.into();
+    let (font, data, idx) = match parse(&data, idx) {
+        Ok(font) => (font, data, idx),
+        // The emoji fonts which only have the bitmaps get empty outlines
+        #[cfg(feature = "color_font")]
+        Err(err) => match super::color_glyph::add_empty_outlines(&data, idx as u32) {
+            Some(outlined) => {
+                let outlined: Arc<[u8]> = outlined.into();
+                let font =
+                    parse(&outlined, 0).map_err(|err| FontError::FontLoadError(Arc::new(err)))?;
+                (font, outlined, 0)
+            }
+            None => return Err(FontError::FontLoadError(Arc::new(err))),
+        },
+        #[cfg(not(feature = "color_font"))]
+        Err(err) => return Err(FontError::FontLoadError(Arc::new(err))),
+    };
+    #[cfg(not(any(feature = "shaping", feature = "color_font")))]
+    let _ = (data, idx);
+    Ok(LoadedFont {
+        id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+        font,
+        #[cfg(feature = "color_font")]
+        color_glyphs: ttf_parser::Face::parse(&data, idx as u32).is_ok_and(|face| {
+            let tables = face.tables();
+            tables.colr.is_some() || tables.cbdt.is_some() || tables.sbix.is_some()
+        }),
+        #[cfg(any(feature = "shaping", feature = "color_font"))]
+        data,
+        #[cfg(any(feature = "shaping", feature = "color_font"))]
+        index: idx as u32,
+    })
+}
+
+/// Register the font data for a font family with the normal style, so the font is used
+/// without being installed on the system, for example a font bundled with the application with
+/// `include_bytes!`. A registered family takes precedence over the system fonts with the same
+/// name, thus registering "sans-serif" replaces the default font.
+///
+/// - `name`: The font family name, which is used as `FontFamily::Name`
+/// - `bytes`: The TrueType or OpenType font data
+/// - **returns**: The result of parsing the font data
+pub fn register_font<B: Into<Vec<u8>>>(name: &str, bytes: B) -> FontResult<()> {
+    register_font_style(name, FontStyle::Normal, bytes)
+}
+
+/// Register the font data for a style of a font family, see `register_font`. When a style
+/// is not registered, the font with the same slant and the closest weight is used.
+///
+/// - `name`: The font family name
+/// - `style`: The style the font data provides
+/// - `bytes`: The TrueType or OpenType font data
+/// - **returns**: The result of parsing the font data
+pub fn register_font_style<B: Into<Vec<u8>>>(
+    name: &str,
+    style: FontStyle,
+    bytes: B,
+) -> FontResult<()> {
+    let font = parse_font(bytes.into(), 0)?;
+    let name = name.to_lowercase();
+    {
+        let mut registered = REGISTERED.write().map_err(|_| FontError::LockError)?;
+        let faces = registered.entry(name.clone()).or_insert_with(Vec::new);
+        faces.retain(|(s, _)| (s.slant(), s.weight()) != (style.slant(), style.weight()));
+        faces.push((style, font));
+    }
+    // The family may have been resolved to a system font or another style already
+    let prefix = format!("{}, ", name);
+    CACHE
+        .write()
+        .map_err(|_| FontError::LockError)?
+        .retain(|key, _| {
+            let key = key.to_lowercase();
+            key != name && !key.starts_with(&prefix)
+        });
+    Ok(())
+}
+
+/// Register a font file for a style of a font family, see `register_font_style`.
+///
+/// - `name`: The font family name
+/// - `style`: The style the font file provides
+/// - `path`: The path to the TrueType or OpenType font file
+/// - **returns**: The result of loading the font file
+pub fn register_font_file<P: AsRef<Path>>(name: &str, style: FontStyle, path: P) -> FontResult<()> {
+    let bytes = std::fs::read(path.as_ref()).map_err(|_| {
+        FontError::NoSuchFont(
+            path.as_ref().display().to_string(),
+            style.as_str().to_owned(),
+        )
+    })?;
+    register_font_style(name, style, bytes)
+}
+
+// Find the registered font of the family which matches the style best
+fn find_registered_font(face: FontFamily, style: FontStyle) -> Option<LoadedFont> {
+    let registered = REGISTERED.read().ok()?;
+    let faces = registered.get(&face.as_str().to_lowercase())?;
+    faces
+        .iter()
+        .min_by_key(|(s, _)| {
+            let weight_diff = (i32::from(s.weight()) - i32::from(style.weight())).abs();
+            (s.slant() != style.slant(), weight_diff)
+        })
+        .map(|(_, font)| font.clone())
+}
+
 #[allow(dead_code)]
 /// Lazily load font data. Font type doesn't own actual data, which
 /// lives in the cache.
-fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static>> {
+fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<LoadedFont> {
     let key = match style {
         FontStyle::Normal => Cow::Borrowed(face.as_str()),
-        _ => Cow::Owned(format!("{}, {}", face.as_str(), style.as_str())),
+        _ => Cow::Owned(format!(
+            "{}, {}, {}",
+            face.as_str(),
+            style.slant(),
+            style.weight()
+        )),
     };
-    let cache = CACHE.read().unwrap();
+    let cache = CACHE.read().map_err(|_| FontError::LockError)?;
     if let Some(cached) = cache.get(Borrow::<str>::borrow(&key)) {
         return cached.clone();
     }
     drop(cache);
 
+    if let Some(font) = find_registered_font(face, style) {
+        CACHE
+            .write()
+            .map_err(|_| FontError::LockError)?
+            .insert(key.into_owned(), Ok(font.clone()));
+        return Ok(font);
+    }
+
     let mut properties = Properties::new();
-    match style {
-        FontStyle::Normal => properties.style(Style::Normal),
-        FontStyle::Italic => properties.style(Style::Italic),
-        FontStyle::Oblique => properties.style(Style::Oblique),
-        FontStyle::Bold => properties.weight(Weight::BOLD),
+    match style.slant() {
+        "italic" => properties.style(Style::Italic),
+        "oblique" => properties.style(Style::Oblique),
+        _ => properties.style(Style::Normal),
     };
+    properties.weight(Weight(f32::from(style.weight())));
 
-    let family = match face {
-        FontFamily::Serif => FamilyName::Serif,
-        FontFamily::SansSerif => FamilyName::SansSerif,
-        FontFamily::Monospace => FamilyName::Monospace,
-        FontFamily::Name(name) => FamilyName::Title(name.to_owned()),
+    // A named family which isn't installed is an error rather than silently replaced by the
+    // default font, unless a metric compatible replacement is installed
+    let families: Vec<_> = match face {
+        FontFamily::Serif => vec![FamilyName::Serif, FamilyName::SansSerif],
+        FontFamily::SansSerif => vec![FamilyName::SansSerif],
+        FontFamily::Monospace => vec![FamilyName::Monospace, FamilyName::SansSerif],
+        FontFamily::Name(name) => std::iter::once(name)
+            .chain(font_replacements(name).iter().copied())
+            .map(|name| FamilyName::Title(name.to_owned()))
+            .collect(),
     };
 
     let make_not_found_error =
         || FontError::NoSuchFont(face.as_str().to_owned(), style.as_str().to_owned());
 
-    if let Ok(handle) = FONT_SOURCE
-        .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties))
+    if let Ok(handle) = FONT_SOURCE.with(|source| source.select_best_match(&families, &properties))
     {
         let (data, id) = match handle {
             Handle::Path {
@@ -100,10 +362,7 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
         };
         // TODO: font-kit actually have rasterizer, so consider remove dependency for rusttype as
         // well
-        let result = FontCollection::from_bytes(Into::<SharedBytes>::into(data))
-            .map_err(|err| FontError::FontLoadError(Arc::new(err)))?
-            .font_at(id.max(0) as usize)
-            .map_err(|err| FontError::FontLoadError(Arc::new(err)));
+        let result = parse_font(data, id.max(0) as usize);
 
         CACHE
             .write()
@@ -115,6 +374,37 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
     Err(make_not_found_error())
 }
 
+// The metric compatible replacements of the common fonts which are missing on some systems
+fn font_replacements(name: &str) -> &'static [&'static str] {
+    match name.to_lowercase().as_str() {
+        "arial" | "helvetica" => &["Liberation Sans", "Arimo", "Nimbus Sans", "DejaVu Sans"],
+        "times new roman" | "times" => {
+            &["Liberation Serif", "Tinos", "Nimbus Roman", "DejaVu Serif"]
+        }
+        "courier new" | "courier" => &[
+            "Liberation Mono",
+            "Cousine",
+            "Nimbus Mono PS",
+            "DejaVu Sans Mono",
+        ],
+        _ => &[],
+    }
+}
+
+/// List the font families which can be used by name, which are the families installed on the
+/// system and the families registered with `register_font`, sorted by name
+pub fn list_font_families() -> Vec<String> {
+    let mut families = FONT_SOURCE
+        .with(|source| source.all_families())
+        .unwrap_or_default();
+    if let Ok(registered) = REGISTERED.read() {
+        families.extend(registered.keys().cloned());
+    }
+    families.sort_by_key(|name| name.to_lowercase());
+    families.dedup();
+    families
+}
+
 /// Remove all cached fonts data.
 #[allow(dead_code)]
 pub fn clear_font_cache() -> FontResult<()> {
@@ -123,25 +413,433 @@ pub fn clear_font_cache() -> FontResult<()> {
     Ok(())
 }
 
+/// Get the statistics of the glyph cache. The rasterized glyphs are cached by the font, the
+/// size and the glyph, so the repeated labels, such as the digits of the tick labels, are only
+/// rasterized once.
+pub fn glyph_cache_stats() -> GlyphCacheStats {
+    let cache = glyph_cache();
+    GlyphCacheStats {
+        hits: cache.hits,
+        misses: cache.misses,
+        entries: cache.glyphs.len(),
+        capacity: cache.capacity,
+    }
+}
+
+/// Set the maximum number of glyphs in the glyph cache, the oldest glyphs are removed when it's
+/// full. The default capacity is 4096 glyphs, and 0 disables the cache.
+pub fn set_glyph_cache_capacity(capacity: usize) {
+    let mut cache = glyph_cache();
+    cache.capacity = capacity;
+    cache.evict(capacity);
+}
+
+/// Remove all the glyphs in the glyph cache and reset the statistics
+pub fn clear_glyph_cache() {
+    let mut cache = glyph_cache();
+    cache.glyphs.clear();
+    cache.order.clear();
+    cache.hits = 0;
+    cache.misses = 0;
+}
+
+// The key of the glyph in the glyph cache
+fn glyph_key(
+    font: &LoadedFont,
+    scale: Scale,
+    glyph: &PositionedGlyph,
+    foreground: Option<(u8, u8, u8)>,
+) -> GlyphKey {
+    let x = glyph.position().x;
+    (
+        font.id,
+        scale.x.to_bits(),
+        glyph.id().0,
+        ((x - x.floor()) * SUBPIXEL_STEPS).round() as u32,
+        foreground.filter(|_| font.has_color_glyphs()),
+    )
+}
+
+// Get the coverage of a glyph from the glyph cache, or rasterize it. The glyph is moved to the
+// closest sub-pixel step. The color glyphs are rasterized with their colors when the color of
+// the text is given.
+fn rasterize_glyph(
+    font: &LoadedFont,
+    scale: Scale,
+    glyph: &PositionedGlyph,
+    foreground: Option<(u8, u8, u8)>,
+) -> Arc<RasterGlyph> {
+    let position = glyph.position();
+    let (origin_x, origin_y) = (position.x.floor(), position.y.floor());
+    let key = glyph_key(font, scale, glyph, foreground);
+    let foreground = key.4;
+    let mut glyph = glyph.clone();
+    glyph.set_position(point(origin_x + key.3 as f32 / SUBPIXEL_STEPS, position.y));
+    let mut cache = glyph_cache();
+    if let Some(raster) = cache.glyphs.get(&key).cloned() {
+        cache.hits += 1;
+        return raster;
+    }
+    cache.misses += 1;
+    drop(cache);
+
+    let color_raster =
+        foreground.and_then(|color| font.rasterize_color_glyph(scale, &glyph, color));
+    let raster = Arc::new(
+        color_raster.unwrap_or_else(|| match glyph.pixel_bounding_box() {
+            Some(rect) => {
+                let (width, height) = (rect.width() as usize, rect.height() as usize);
+                let mut coverage = vec![0.0; width * height];
+                glyph.draw(|x, y, v| coverage[y as usize * width + x as usize] = v);
+                RasterGlyph {
+                    x0: rect.min.x - origin_x as i32,
+                    y0: rect.min.y - origin_y as i32,
+                    width,
+                    coverage,
+                    colors: vec![],
+                }
+            }
+            None => RasterGlyph {
+                x0: 0,
+                y0: 0,
+                width: 0,
+                coverage: vec![],
+                colors: vec![],
+            },
+        }),
+    );
+
+    let mut cache = glyph_cache();
+    if cache.capacity > 0 && position.y == origin_y {
+        cache.insert(key, raster.clone());
+    }
+    raster
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BidiClass {
+    Left,
+    Right,
+    Number,
+    Neutral,
+}
+
+// The right-to-left scripts are Hebrew, Arabic, Syriac, Thaana, N'Ko and their presentation forms
+fn bidi_class(c: char) -> BidiClass {
+    match c as u32 {
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF => BidiClass::Right,
+        _ if c.is_ascii_digit() => BidiClass::Number,
+        _ if c.is_alphanumeric() => BidiClass::Left,
+        _ => BidiClass::Neutral,
+    }
+}
+
+fn mirror_char(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        other => other,
+    }
+}
+
+// Resolve the embedding levels of the characters, the odd levels are right-to-left. This is a
+// simplified version of the Unicode bidirectional algorithm without the explicit embeddings:
+// the paragraph direction is the one of the first strong character, the numbers keep their
+// order and the neutral characters between two runs of the same direction take that direction.
+fn bidi_levels(chars: &[char]) -> Vec<u8> {
+    use BidiClass::*;
+
+    let mut classes: Vec<_> = chars.iter().map(|c| bidi_class(*c)).collect();
+    if !classes.contains(&Right) {
+        return vec![0; chars.len()];
+    }
+    let base = match classes.iter().find(|c| **c == Left || **c == Right) {
+        Some(Right) => Right,
+        _ => Left,
+    };
+
+    // The numbers after a left-to-right character are left-to-right text
+    let mut last_strong = base;
+    for class in classes.iter_mut() {
+        match *class {
+            Left | Right => last_strong = *class,
+            Number if last_strong == Left => *class = Left,
+            _ => {}
+        }
+    }
+
+    // The numbers count as right-to-left text for the neutral characters around them
+    let direction = |class: &BidiClass| match class {
+        Left => Some(Left),
+        Right | Number => Some(Right),
+        Neutral => None,
+    };
+    let resolved: Vec<_> = (0..classes.len())
+        .map(|idx| {
+            if classes[idx] != Neutral {
+                return classes[idx];
+            }
+            let prev = classes[..idx].iter().rev().find_map(direction);
+            let next = classes[idx + 1..].iter().find_map(direction);
+            match (prev.unwrap_or(base), next.unwrap_or(base)) {
+                (a, b) if a == b => a,
+                _ => base,
+            }
+        })
+        .collect();
+
+    let base_level = if base == Right { 1 } else { 0 };
+    resolved
+        .iter()
+        .map(|class| match (class, base_level) {
+            (Left, 0) | (Right, 1) => base_level,
+            (Number, _) => 2,
+            _ => base_level + 1,
+        })
+        .collect()
+}
+
+// Split the text into the runs of the same embedding level, which are sorted in the
+// left-to-right visual order. The characters of each run are kept in the logical order.
+fn visual_runs(text: &str) -> Vec<(&str, u8)> {
+    let chars: Vec<_> = text.char_indices().collect();
+    let levels = bidi_levels(&chars.iter().map(|(_, c)| *c).collect::<Vec<_>>());
+
+    let mut runs: Vec<(usize, usize, u8)> = vec![];
+    for (&(offset, c), &level) in chars.iter().zip(levels.iter()) {
+        match runs.last_mut() {
+            Some((_, end, last_level)) if *last_level == level => *end = offset + c.len_utf8(),
+            _ => runs.push((offset, offset + c.len_utf8(), level)),
+        }
+    }
+    // Reverse the runs from the highest level down to the lowest odd level
+    for level in (1..=2).rev() {
+        let mut start = 0;
+        while start < runs.len() {
+            if runs[start].2 < level {
+                start += 1;
+                continue;
+            }
+            let end = runs[start..]
+                .iter()
+                .position(|(_, _, l)| *l < level)
+                .map_or(runs.len(), |len| start + len);
+            runs[start..end].reverse();
+            start = end;
+        }
+    }
+    runs.into_iter()
+        .map(|(start, end, level)| (&text[start..end], level))
+        .collect()
+}
+
+/// Reorder the characters from the logical order to the left-to-right visual order, with the
+/// simplified bidirectional algorithm of `bidi_levels`. The brackets of the right-to-left runs
+/// are mirrored. The glyphs are not shaped, so the joining forms of Arabic are not substituted,
+/// unless the `shaping` feature is enabled.
+fn visual_order(text: &str) -> Cow<'_, str> {
+    let runs = visual_runs(text);
+    if runs.iter().all(|(_, level)| *level == 0) {
+        return Cow::Borrowed(text);
+    }
+    let mut visual = String::with_capacity(text.len());
+    for (run, level) in runs {
+        if level % 2 == 1 {
+            visual.extend(run.chars().rev().map(mirror_char));
+        } else {
+            visual.push_str(run);
+        }
+    }
+    Cow::Owned(visual)
+}
+
+// The font, the fallback fonts and if the glyphs are hinted
 #[derive(Clone)]
-pub struct FontDataInternal(Font<'static>);
+pub struct FontDataInternal(LoadedFont, Vec<LoadedFont>, bool);
+
+impl FontDataInternal {
+    // The scale of the font size. The hinting snaps the x-height to whole pixels, so the tops
+    // of the lowercase letters are as sharp as the baseline.
+    fn scale(&self, size: f64) -> Scale {
+        let scale = Scale::uniform(size as f32);
+        if !self.2 {
+            return scale;
+        }
+        let x_height = self
+            .0
+            .glyph('x')
+            .scaled(scale)
+            .exact_bounding_box()
+            .map_or(0.0, |rect| -rect.min.y);
+        if x_height < 1.0 {
+            return scale;
+        }
+        Scale::uniform(size as f32 * x_height.round() / x_height)
+    }
+
+    /// Lay out the text like `Font::layout`, but the characters which are missing from the font
+    /// are taken from the first fallback font which has them, and the right-to-left text is
+    /// laid out in the visual order. The glyphs are paired with the ids of their fonts. The
+    /// hinted glyphs are placed at whole pixels.
+    fn layout(&self, scale: Scale, text: &str) -> Vec<(&LoadedFont, PositionedGlyph<'static>)> {
+        let mut glyphs = self.place_glyphs(scale, text);
+        if self.2 {
+            for (_, g) in glyphs.iter_mut() {
+                let position = g.position();
+                g.set_position(point(position.x.round(), position.y.round()));
+            }
+        }
+        glyphs
+    }
+
+    fn place_glyphs(
+        &self,
+        scale: Scale,
+        text: &str,
+    ) -> Vec<(&LoadedFont, PositionedGlyph<'static>)> {
+        #[cfg(feature = "shaping")]
+        {
+            if let Some(glyphs) = self.shaped_layout(scale, text) {
+                return glyphs;
+            }
+        }
+        let mut caret = 0.0;
+        let mut last: Option<(usize, GlyphId)> = None;
+        let mut glyphs = vec![];
+        for c in visual_order(text).chars() {
+            let (idx, font) = std::iter::once(&self.0)
+                .chain(self.1.iter())
+                .enumerate()
+                .find(|(_, font)| font.glyph(c).id().0 != 0)
+                .unwrap_or((0, &self.0));
+            let g = font.glyph(c).scaled(scale);
+            if let Some((last_idx, last_id)) = last {
+                // The kerning is only defined between the glyphs of the same font
+                if last_idx == idx {
+                    caret += font.pair_kerning(scale, last_id, g.id());
+                }
+            }
+            let advance = g.h_metrics().advance_width;
+            let g = g.positioned(point(caret, 0.0));
+            caret += advance;
+            last = Some((idx, g.id()));
+            glyphs.push((font, g));
+        }
+        glyphs
+    }
+
+    /// Lay out the text with the glyphs and the positions given by the shaper, thus the
+    /// ligatures and the joining forms are substituted and the marks are placed. Each run of
+    /// the same direction is shaped on its own, and the characters which the font doesn't have
+    /// are taken from the fallback fonts without shaping. Returns `None` when the shaper
+    /// can't parse the font.
+    #[cfg(feature = "shaping")]
+    fn shaped_layout(
+        &self,
+        scale: Scale,
+        text: &str,
+    ) -> Option<Vec<(&LoadedFont, PositionedGlyph<'static>)>> {
+        use rustybuzz::{Direction, UnicodeBuffer};
+
+        let face = rustybuzz::Face::from_slice(&self.0.data, self.0.index)?;
+        // The positions are in the font units, and the scale is the height of the glyphs
+        let v_metrics = self.0.v_metrics_unscaled();
+        let units = (
+            scale.x / (v_metrics.ascent - v_metrics.descent),
+            scale.y / (v_metrics.ascent - v_metrics.descent),
+        );
+
+        let mut caret = 0.0;
+        let mut glyphs = vec![];
+        for (run, level) in visual_runs(text) {
+            let mut buffer = UnicodeBuffer::new();
+            buffer.push_str(run);
+            if level % 2 == 1 {
+                buffer.set_direction(Direction::RightToLeft);
+            }
+            buffer.guess_segment_properties();
+            let shaped = rustybuzz::shape(&face, &[], buffer);
+            for (info, pos) in shaped.glyph_infos().iter().zip(shaped.glyph_positions()) {
+                if info.glyph_id == 0 {
+                    let c = run[info.cluster as usize..].chars().next()?;
+                    if let Some(font) = self.1.iter().find(|font| font.glyph(c).id().0 != 0) {
+                        let g = font.glyph(c).scaled(scale);
+                        let advance = g.h_metrics().advance_width;
+                        glyphs.push((font, g.positioned(point(caret, 0.0))));
+                        caret += advance;
+                        continue;
+                    }
+                }
+                let g = self.0.glyph(GlyphId(info.glyph_id)).scaled(scale);
+                let position = point(
+                    caret + pos.x_offset as f32 * units.0,
+                    -pos.y_offset as f32 * units.1,
+                );
+                glyphs.push((&self.0, g.positioned(position)));
+                caret += pos.x_advance as f32 * units.0;
+            }
+        }
+        Some(glyphs)
+    }
+
+    // Draw the pixels of the glyphs, the color glyphs have their colors when the color of the
+    // text is given
+    fn draw_glyphs<E, DrawFunc: FnMut(i32, i32, f32, Option<(u8, u8, u8)>) -> Result<(), E>>(
+        &self,
+        (base_x, base_y): (i32, i32),
+        size: f64,
+        text: &str,
+        foreground: Option<(u8, u8, u8)>,
+        mut draw: DrawFunc,
+    ) -> Result<(), E> {
+        let scale = self.scale(size);
+        for (font, g) in self.layout(scale, text) {
+            let raster = rasterize_glyph(font, scale, &g, foreground);
+            let position = g.position();
+            let x0 = raster.x0 + position.x.floor() as i32 + base_x;
+            let y0 = raster.y0 + position.y.floor() as i32 + base_y;
+            for (idx, &v) in raster.coverage.iter().enumerate() {
+                let (x, y) = ((idx % raster.width) as i32, (idx / raster.width) as i32);
+                draw(x0 + x, y0 + y, v, raster.colors.get(idx).copied())?;
+            }
+        }
+        Ok(())
+    }
+}
 
 impl FontData for FontDataInternal {
     type ErrorType = FontError;
 
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, FontError> {
-        Ok(FontDataInternal(load_font_data(family, style)?))
+        Ok(FontDataInternal(
+            load_font_data(family, style)?,
+            vec![],
+            false,
+        ))
+    }
+
+    fn add_fallback(&mut self, fallback: Self) {
+        self.1.push(fallback.0);
+        self.1.extend(fallback.1);
+    }
+
+    fn set_hinting(&mut self, hinting: bool) {
+        self.2 = hinting;
     }
 
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
-        let scale = Scale::uniform(size as f32);
+        let scale = self.scale(size);
 
         let (mut min_x, mut min_y) = (i32::MAX, i32::MAX);
         let (mut max_x, mut max_y) = (0, 0);
 
-        let font = &self.0;
-
-        for g in font.layout(text, scale, point(0.0, 0.0)) {
+        for (_, g) in self.layout(scale, text) {
             if let Some(rect) = g.pixel_bounding_box() {
                 min_x = min_x.min(rect.min.x);
                 min_y = min_y.min(rect.min.y);
@@ -159,28 +857,24 @@ impl FontData for FontDataInternal {
 
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
-        (base_x, base_y): (i32, i32),
+        pos: (i32, i32),
         size: f64,
         text: &str,
         mut draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::ErrorType> {
-        let scale = Scale::uniform(size as f32);
-        let mut result = Ok(());
-        let font = &self.0;
+        Ok(self.draw_glyphs(pos, size, text, None, |x, y, v, _| draw(x, y, v)))
+    }
 
-        for g in font.layout(text, scale, point(0.0, 0.0)) {
-            if let Some(rect) = g.pixel_bounding_box() {
-                let (x0, y0) = (rect.min.x, rect.min.y);
-                g.draw(|x, y, v| {
-                    let (x, y) = (x as i32 + x0, y as i32 + y0);
-                    result = draw(x + base_x, y + base_y, v);
-                });
-                if result.is_err() {
-                    break;
-                }
-            }
-        }
-        Ok(result)
+    #[cfg(feature = "color_font")]
+    fn draw_color<E, DrawFunc: FnMut(i32, i32, f32, Option<(u8, u8, u8)>) -> Result<(), E>>(
+        &self,
+        pos: (i32, i32),
+        size: f64,
+        text: &str,
+        color: (u8, u8, u8),
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType> {
+        Ok(self.draw_glyphs(pos, size, text, Some(color), draw))
     }
 }
 
@@ -189,6 +883,68 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn test_visual_order() {
+        assert!(matches!(visual_order("y = x^2"), Cow::Borrowed(_)));
+        assert_eq!(visual_order("שלום"), "םולש");
+        // The numbers and the Latin words keep their order in the right-to-left text
+        assert_eq!(visual_order("מחיר 42 (USD)"), "(USD) 42 ריחמ");
+        assert_eq!(visual_order("label: אב גד!"), "label: דג בא!");
+        assert_eq!(visual_order("אב 12"), "12 בא");
+    }
+
+    #[test]
+    fn test_visual_runs() {
+        assert_eq!(visual_runs("y = x^2"), vec![("y = x^2", 0)]);
+        assert_eq!(
+            visual_runs("label: אב גד!"),
+            vec![("label: ", 0), ("אב גד", 1), ("!", 0)]
+        );
+        assert_eq!(
+            visual_runs("מחיר 42 (USD)"),
+            vec![(")", 1), ("USD", 2), (" (", 1), ("42", 2), ("מחיר ", 1)]
+        );
+    }
+
+    #[cfg(feature = "shaping")]
+    #[test]
+    fn test_shaping() -> FontResult<()> {
+        let font = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal)?;
+        let text = "بيت";
+        if text.chars().any(|c| font.0.glyph(c).id().0 == 0) {
+            // The system font doesn't have the Arabic letters
+            return Ok(());
+        }
+        let scale = Scale::uniform(20.0);
+        let shaped: Vec<_> = font
+            .layout(scale, text)
+            .iter()
+            .map(|(_, g)| g.id())
+            .collect();
+        let unshaped: Vec<_> = visual_order(text)
+            .chars()
+            .map(|c| font.0.glyph(c).id())
+            .collect();
+        // The letters are replaced by their joining forms
+        assert_eq!(shaped.len(), unshaped.len());
+        assert_ne!(shaped, unshaped);
+
+        // The Latin text has the same glyphs and about the same advances as without shaping
+        let shaped = font.layout(scale, "Plot 42");
+        let caret =
+            |g: &PositionedGlyph| g.position().x + g.unpositioned().h_metrics().advance_width;
+        let width = caret(&shaped.last().unwrap().1);
+        for (c, (_, g)) in "Plot 42".chars().zip(shaped.iter()) {
+            assert_eq!(g.id(), font.0.glyph(c).id());
+        }
+        let expected: f32 = "Plot 42"
+            .chars()
+            .map(|c| font.0.glyph(c).scaled(scale).h_metrics().advance_width)
+            .sum();
+        assert!((width - expected).abs() < 1.0);
+        Ok(())
+    }
+
     #[test]
     fn test_font_cache() -> FontResult<()> {
         clear_font_cache()?;
@@ -206,4 +962,193 @@ mod test {
 
         return Ok(());
     }
+
+    fn system_font_bytes(properties: &Properties) -> Vec<u8> {
+        let handle = FONT_SOURCE
+            .with(|source| source.select_best_match(&[FamilyName::Serif], properties))
+            .unwrap();
+        match handle {
+            Handle::Path { path, .. } => std::fs::read(path).unwrap(),
+            Handle::Memory { bytes, .. } => bytes[..].to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_register_font() -> FontResult<()> {
+        let serif = load_font_data(FontFamily::Serif, FontStyle::Normal)?;
+        let bold = load_font_data(FontFamily::Serif, FontStyle::Bold)?;
+        let name = "plotters-registered-test-font";
+        register_font(name, system_font_bytes(&Properties::new()))?;
+        register_font_style(
+            name,
+            FontStyle::Bold,
+            system_font_bytes(Properties::new().weight(Weight::BOLD)),
+        )?;
+        assert!(register_font(name, vec![0u8; 16]).is_err());
+
+        let layout = |font: &Font| {
+            font.glyph('W')
+                .scaled(Scale::uniform(20.0))
+                .h_metrics()
+                .advance_width
+        };
+        let family = FontFamily::Name(name);
+        let registered = load_font_data(family, FontStyle::Normal)?;
+        assert_eq!(layout(&registered), layout(&serif));
+        // The closest weight is picked for the styles which are not registered
+        let semibold = load_font_data(family, FontStyle::Weight(600))?;
+        assert_eq!(layout(&semibold), layout(&bold));
+        Ok(())
+    }
+
+    #[test]
+    fn test_font_discovery() {
+        let families = list_font_families();
+        assert!(!families.is_empty());
+        let name = "plotters-discovery-test-font";
+        register_font(name, system_font_bytes(&Properties::new())).unwrap();
+        assert!(list_font_families().iter().any(|family| family == name));
+
+        // A missing family is reported instead of replaced by the default font
+        let missing = FontFamily::Name("plotters-missing-font-family");
+        match load_font_data(missing, FontStyle::Normal) {
+            Err(FontError::NoSuchFont(family, style)) => {
+                assert_eq!(family, "plotters-missing-font-family");
+                assert_eq!(style, "normal");
+            }
+            _ => panic!("The missing font family is loaded"),
+        }
+    }
+
+    #[test]
+    fn test_font_fallback() -> FontResult<()> {
+        let mut font = FontDataInternal::new(FontFamily::Serif, FontStyle::Normal)?;
+        let fallback = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal)?;
+        let symbol = '\u{26a0}';
+        if font.0.glyph(symbol).id().0 != 0 || fallback.0.glyph(symbol).id().0 == 0 {
+            // The system fonts can't show the fallback
+            return Ok(());
+        }
+        let text = format!("a{}", symbol);
+        let scale = Scale::uniform(20.0);
+        assert_eq!((font.layout(scale, &text)[1].1).id().0, 0);
+
+        font.add_fallback(fallback.clone());
+        let glyphs = font.layout(scale, &text);
+        assert_eq!(glyphs[1].1.id(), fallback.0.glyph(symbol).id());
+        assert_eq!(glyphs[0].1.id(), font.0.glyph('a').id());
+        Ok(())
+    }
+
+    #[cfg(feature = "color_font")]
+    #[test]
+    fn test_color_glyphs() -> FontResult<()> {
+        let family = FontFamily::Name("Noto Color Emoji");
+        let font = match FontDataInternal::new(family, FontStyle::Normal) {
+            Ok(font) => font,
+            // The system has no color emoji font
+            Err(_) => return Ok(()),
+        };
+        let render = |color: Option<(u8, u8, u8)>| {
+            let mut pixels = vec![];
+            let mut draw = |_, _, v, pixel_color| {
+                if v > 0.0 {
+                    pixels.push(pixel_color);
+                }
+                Ok::<(), ()>(())
+            };
+            match color {
+                Some(color) => font.draw_color((0, 0), 24.0, "\u{1f600}", color, &mut draw),
+                None => font.draw((0, 0), 24.0, "\u{1f600}", |x, y, v| draw(x, y, v, None)),
+            }
+            .unwrap()
+            .unwrap();
+            pixels
+        };
+        let colored = render(Some((0, 0, 0)));
+        assert!(!colored.is_empty());
+        assert!(colored.iter().all(Option::is_some));
+        // The color glyphs have no colors when they are drawn like the other glyphs
+        assert!(render(None).iter().all(Option::is_none));
+        Ok(())
+    }
+
+    #[test]
+    fn test_glyph_cache() -> FontResult<()> {
+        let font = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal)?;
+        let render = |text: &str| {
+            let mut pixels = vec![];
+            font.draw((10, 20), 18.0, text, |x, y, v| {
+                pixels.push((x, y, v.to_bits()));
+                Ok::<(), ()>(())
+            })
+            .unwrap()
+            .unwrap();
+            pixels
+        };
+
+        let first = render("12.5 0.25");
+        let before = glyph_cache_stats();
+        // The glyphs of the same text are found in the cache
+        assert_eq!(render("12.5 0.25"), first);
+        let after = glyph_cache_stats();
+        assert!(after.hits >= before.hits + 9);
+        assert!(after.entries > 0);
+
+        // The cached glyphs are the same as the ones rasterized at the closest quarter pixel
+        let scale = Scale::uniform(18.0);
+        let mut expected = vec![];
+        for (_, mut g) in font.layout(scale, "12.5 0.25") {
+            let position = g.position();
+            let x = (position.x * 4.0).round() / 4.0;
+            g.set_position(point(x, position.y));
+            if let Some(rect) = g.pixel_bounding_box() {
+                g.draw(|x, y, v| {
+                    expected.push((x as i32 + rect.min.x + 10, y as i32 + rect.min.y + 20, v));
+                });
+            }
+        }
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(x, y, v)| (x, y, v.to_bits()))
+            .collect();
+        assert_eq!(first, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_glyph_cache_eviction() -> FontResult<()> {
+        let font = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal)?.0;
+        let scale = Scale::uniform(18.0);
+        let mut cache = GlyphCache {
+            glyphs: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: 2,
+            hits: 0,
+            misses: 0,
+        };
+        let rasterize = |c: char, x: f32| {
+            let glyph = font.glyph(c).scaled(scale).positioned(point(x, 0.0));
+            let raster = rasterize_glyph(&font, scale, &glyph, None);
+            (glyph_key(&font, scale, &glyph, None), raster)
+        };
+
+        // The close sub-pixel offsets share the same rasterized glyph
+        assert!(rasterize('a', 10.3).0 == rasterize('a', 20.26).0);
+        assert!(rasterize('a', 10.3).0 != rasterize('a', 10.4).0);
+
+        // The oldest glyph is removed when the cache is full
+        for c in ['a', 'b', 'a', 'c'].iter() {
+            let (key, raster) = rasterize(*c, 0.0);
+            cache.insert(key, raster);
+        }
+        assert_eq!(cache.glyphs.len(), 2);
+        assert!(!cache.glyphs.contains_key(&rasterize('a', 0.0).0));
+        assert!(cache.glyphs.contains_key(&rasterize('b', 0.0).0));
+        assert!(cache.glyphs.contains_key(&rasterize('c', 0.0).0));
+        cache.evict(1);
+        assert!(cache.glyphs.contains_key(&rasterize('c', 0.0).0));
+        assert_eq!(cache.order.len(), 1);
+        Ok(())
+    }
 }
@@ -5,11 +5,16 @@ use web_sys::{window, HtmlElement};
 #[derive(Debug, Clone)]
 pub enum FontError {
     UnknownError,
+    /// The text is measured by the browser, which can't rasterize it
+    RasterizationNotSupported,
 }
 
 impl std::fmt::Display for FontError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match self {
+            FontError::RasterizationNotSupported => {
+                write!(fmt, "Unable to rasterize text with the browser fonts")
+            }
             _ => write!(fmt, "Unknown error"),
         }
     }
@@ -29,18 +34,33 @@ impl FontData for FontDataInternal {
         ))
     }
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
-        let window = window().unwrap();
-        let document = window.document().unwrap();
-        let body = document.body().unwrap();
-        let span = document.create_element("span").unwrap();
+        let document = window()
+            .and_then(|window| window.document())
+            .ok_or(FontError::UnknownError)?;
+        let body = document.body().ok_or(FontError::UnknownError)?;
+        let span = document
+            .create_element("span")
+            .map_err(|_| FontError::UnknownError)?;
         span.set_text_content(Some(text));
-        span.set_attribute("style", &format!("display: inline-block; font-family:{}; font-size: {}px; position: fixed; top: 100%", self.0, size)).unwrap();
+        span.set_attribute("style", &format!("display: inline-block; font-family:{}; font-size: {}px; position: fixed; top: 100%", self.0, size))
+            .map_err(|_| FontError::UnknownError)?;
         let span = span.into();
-        body.append_with_node_1(&span).unwrap();
-        let elem = JsCast::dyn_into::<HtmlElement>(span).unwrap();
+        body.append_with_node_1(&span)
+            .map_err(|_| FontError::UnknownError)?;
+        let elem = JsCast::dyn_into::<HtmlElement>(span).map_err(|_| FontError::UnknownError)?;
         let height = elem.offset_height() as i32;
         let width = elem.offset_width() as i32;
         elem.remove();
         Ok(((0, 0), (width, height)))
     }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        _pos: (i32, i32),
+        _size: f64,
+        _text: &str,
+        _draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType> {
+        Err(FontError::RasterizationNotSupported)
+    }
 }
@@ -0,0 +1,186 @@
+use super::color::{Color, RGBAColor};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// The shape of a gradient
+#[derive(Clone, Debug)]
+pub enum GradientKind {
+    /// The colors change along a direction, the angle is in degrees clockwise from the
+    /// left to right direction
+    Linear(f64),
+    /// The colors change from the center of the shape to its border
+    Radial,
+}
+
+/// A gradient fill, which is used as the fill of rectangles, polygons and area series by
+/// setting it on a shape style with `ShapeStyle::gradient`. The gradient is laid out over the
+/// bounding box of the shape. The backends which can't draw gradients fill the shape with the
+/// color of the first stop.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let fade = Gradient::linear(90.0).stop(0.0, &BLUE).stop(1.0, &BLUE.mix(0.0));
+/// let style = ShapeStyle::from(&BLUE).gradient(fade);
+/// let bar = Rectangle::new([(0, 0), (10, 100)], style);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    kind: GradientKind,
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl Gradient {
+    /// Create a linear gradient without stops
+    /// - `angle`: The direction of the gradient in degrees, 0 is left to right and 90 is
+    ///   top to bottom
+    pub fn linear(angle: f64) -> Self {
+        Self {
+            kind: GradientKind::Linear(angle),
+            stops: vec![],
+        }
+    }
+
+    /// Create a radial gradient without stops, from the center of the shape to its border
+    pub fn radial() -> Self {
+        Self {
+            kind: GradientKind::Radial,
+            stops: vec![],
+        }
+    }
+
+    /// Add a color stop to the gradient
+    /// - `offset`: The position of the stop in the range of 0 to 1
+    /// - `color`: The color at the stop
+    pub fn stop<C: Color>(mut self, offset: f64, color: &C) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+        let idx = self
+            .stops
+            .iter()
+            .position(|(o, _)| *o > offset)
+            .unwrap_or(self.stops.len());
+        self.stops.insert(idx, (offset, color.to_rgba()));
+        self
+    }
+
+    // Fade the colors of all the stops
+    pub(crate) fn mix(&self, value: f64) -> Self {
+        Self {
+            kind: self.kind.clone(),
+            stops: self.stops.iter().map(|(o, c)| (*o, c.mix(value))).collect(),
+        }
+    }
+
+    /// Get the shape of the gradient
+    pub fn kind(&self) -> &GradientKind {
+        &self.kind
+    }
+
+    /// Get the color stops of the gradient, sorted by the offset
+    pub fn stops(&self) -> &[(f64, RGBAColor)] {
+        &self.stops
+    }
+
+    /// Get the end points of a linear gradient, relative to the bounding box of the shape
+    pub fn linear_end_points(&self) -> ((f64, f64), (f64, f64)) {
+        let angle = match self.kind {
+            GradientKind::Linear(angle) => angle,
+            GradientKind::Radial => 0.0,
+        };
+        let (sin, cos) = angle.to_radians().sin_cos();
+        // Avoid the rounding errors like 0.49999999999999994 for the axis aligned angles
+        let round = |v: f64| (v * 1e6).round() / 1e6;
+        (
+            (round(0.5 - cos / 2.0), round(0.5 - sin / 2.0)),
+            (round(0.5 + cos / 2.0), round(0.5 + sin / 2.0)),
+        )
+    }
+
+    /// Get the color of the gradient at an offset, the colors beyond the first and the last
+    /// stops are the colors of the stops
+    pub fn color_at(&self, offset: f64) -> RGBAColor {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return RGBAColor(0, 0, 0, 0.0),
+        };
+        if offset <= first.0 {
+            return first.1.clone();
+        }
+        if offset >= last.0 {
+            return last.1.clone();
+        }
+        let idx = self
+            .stops
+            .iter()
+            .position(|(o, _)| *o > offset)
+            .unwrap_or(1);
+        let ((o0, c0), (o1, c1)) = (&self.stops[idx - 1], &self.stops[idx]);
+        let t = (offset - o0) / (o1 - o0);
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        RGBAColor(
+            mix(c0.0, c1.0),
+            mix(c0.1, c1.1),
+            mix(c0.2, c1.2),
+            c0.3 + (c1.3 - c0.3) * t,
+        )
+    }
+
+    /// Get the color of the gradient at a pixel of a shape
+    /// - `point`: The pixel
+    /// - `upper_left`, `bottom_right`: The bounding box of the shape
+    pub fn color_at_point(
+        &self,
+        (x, y): (i32, i32),
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+    ) -> RGBAColor {
+        let relative = |v: i32, min: i32, max: i32| {
+            if max > min {
+                f64::from(v - min) / f64::from(max - min)
+            } else {
+                0.5
+            }
+        };
+        let u = relative(x, upper_left.0, bottom_right.0) - 0.5;
+        let v = relative(y, upper_left.1, bottom_right.1) - 0.5;
+        let offset = match self.kind {
+            GradientKind::Linear(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                u * cos + v * sin + 0.5
+            }
+            GradientKind::Radial => u.hypot(v) * 2.0,
+        };
+        self.color_at(offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{BLUE, RED};
+
+    #[test]
+    fn test_gradient_colors() {
+        let gradient = Gradient::linear(0.0).stop(1.0, &BLUE).stop(0.0, &RED);
+        assert_eq!(gradient.stops()[0].1, RED.to_rgba());
+        assert_eq!(gradient.color_at(-1.0), RED.to_rgba());
+        assert_eq!(gradient.color_at(0.5), RGBAColor(128, 0, 128, 1.0));
+        assert_eq!(
+            gradient.color_at_point((10, 5), (0, 0), (10, 10)),
+            BLUE.to_rgba()
+        );
+
+        let vertical = Gradient::linear(90.0).stop(0.0, &RED).stop(1.0, &BLUE);
+        assert_eq!(
+            vertical.color_at_point((10, 0), (0, 0), (10, 10)),
+            RED.to_rgba()
+        );
+
+        let radial = Gradient::radial().stop(0.0, &RED).stop(1.0, &BLUE.mix(0.0));
+        assert_eq!(
+            radial.color_at_point((5, 5), (0, 0), (10, 10)),
+            RED.to_rgba()
+        );
+        assert_eq!(radial.color_at_point((5, 0), (0, 0), (10, 10)).3, 0.0);
+    }
+}
@@ -2,24 +2,40 @@
   The style for shapes and text, font, color, etc.
 */
 mod color;
+mod colormap;
 pub mod colors;
 mod font;
+mod gradient;
 mod palette;
+mod pattern;
 mod shape;
 mod size;
 mod text;
+mod theme;
 
 #[cfg(feature = "palette_ext")]
 mod palette_ext;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
-pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor, SimpleColor};
+pub use color::{Color, HSLColor, HSVColor, PaletteColor, RGBAColor, RGBColor, SimpleColor};
+pub use colormap::{ColorMap, Heat, Inferno, LinearColorMap, Magma, Plasma, Turbo, Viridis};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+#[cfg(any(test, feature = "testing"))]
+pub(crate) use font::DETERMINISTIC_LOCK;
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
 pub use font::{
-    FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
+    clear_glyph_cache, glyph_cache_stats, list_font_families, register_font, register_font_file,
+    register_font_style, set_glyph_cache_capacity, GlyphCacheStats,
 };
+pub use font::{
+    is_deterministic_rendering, set_deterministic_rendering, FontDesc, FontError, FontFamily,
+    FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
+};
+pub use gradient::{Gradient, GradientKind};
+pub use pattern::{Pattern, PatternKind};
 pub use shape::ShapeStyle;
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
 pub use text::{IntoTextStyle, TextStyle};
+pub use theme::Theme;
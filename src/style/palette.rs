@@ -1,7 +1,24 @@
-use super::color::PaletteColor;
+use super::color::{Color, PaletteColor, RGBColor};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
+/// The trait for the palettes, which are lists of colors picked by index. A custom palette is
+/// defined by implementing the trait with its colors:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// struct Brand;
+/// impl Palette for Brand {
+///     const COLORS: &'static [(u8, u8, u8)] = &[(0, 84, 147), (255, 147, 0)];
+/// }
+///
+/// // The colors are reused when the index is larger than the palette
+/// assert_eq!(Brand::pick(3).to_rgba(), RGBColor(255, 147, 0).to_rgba());
+/// ```
 pub trait Palette {
     const COLORS: &'static [(u8, u8, u8)];
+    /// Pick a color with the index, the colors are cycled, so it never panics
     fn pick(idx: usize) -> PaletteColor<Self>
     where
         Self: Sized,
@@ -10,6 +27,65 @@ pub trait Palette {
     }
 }
 
+/// A palette defined at runtime, for example from the brand colors in a configuration file.
+/// Unlike the palettes implementing `Palette`, it owns its colors.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let palette = CustomPalette::from_hex(&["#005493", "ff9300"]).unwrap();
+/// assert_eq!(palette.pick(2).to_rgba(), RGBColor(0, 84, 147).to_rgba());
+/// assert!(CustomPalette::from_hex(&["#00549"]).is_none());
+/// ```
+#[derive(Clone)]
+pub struct CustomPalette {
+    colors: Vec<(u8, u8, u8)>,
+}
+
+impl CustomPalette {
+    /// Create a palette from the colors
+    pub fn new<C: Color>(colors: &[C]) -> Self {
+        Self {
+            colors: colors.iter().map(Color::rgb).collect(),
+        }
+    }
+
+    /// Create a palette from hex color codes like `#ff9300`, the `#` is optional
+    /// - **returns** The palette, or `None` if any of the codes is malformed
+    pub fn from_hex<S: AsRef<str>>(codes: &[S]) -> Option<Self> {
+        let colors = codes
+            .iter()
+            .map(|code| {
+                let code = code.as_ref().trim_start_matches('#');
+                if code.len() != 6 || !code.is_ascii() {
+                    return None;
+                }
+                let channel = |at: usize| u8::from_str_radix(&code[at..at + 2], 16).ok();
+                Some((channel(0)?, channel(2)?, channel(4)?))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self { colors })
+    }
+
+    /// Pick a color with the index, the colors are cycled and an empty palette gives black
+    pub fn pick(&self, idx: usize) -> RGBColor {
+        let (r, g, b) = idx
+            .checked_rem(self.colors.len())
+            .map_or((0, 0, 0), |idx| self.colors[idx]);
+        RGBColor(r, g, b)
+    }
+
+    /// The number of colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if the palette has no color
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
 /// The palette of 99% accessibility
 pub struct Palette99;
 /// The palette of 99.99% accessibility
@@ -61,3 +137,27 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Empty;
+    impl Palette for Empty {
+        const COLORS: &'static [(u8, u8, u8)] = &[];
+    }
+
+    #[test]
+    fn test_pick_never_panics() {
+        assert_eq!(
+            Palette99::pick(usize::MAX).rgb(),
+            Palette99::pick(usize::MAX % 20).rgb()
+        );
+        assert_eq!(Empty::pick(5).rgb(), (0, 0, 0));
+        assert_eq!(CustomPalette::new::<RGBColor>(&[]).pick(5).rgb(), (0, 0, 0));
+
+        let palette = CustomPalette::new(&[RGBColor(1, 2, 3), RGBColor(4, 5, 6)]);
+        assert_eq!(palette.pick(usize::MAX).rgb(), (4, 5, 6));
+        assert_eq!(palette.len(), 2);
+    }
+}
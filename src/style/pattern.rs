@@ -0,0 +1,151 @@
+use super::color::{Color, RGBAColor};
+
+/// The shape of the marks of a pattern fill
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PatternKind {
+    /// Lines from the lower left to the upper right
+    DiagonalHatch,
+    /// Lines from the upper left to the lower right
+    BackDiagonalHatch,
+    /// Both diagonal hatches
+    CrossHatch,
+    /// Horizontal lines
+    HorizontalLines,
+    /// Vertical lines
+    VerticalLines,
+    /// A grid of dots
+    Dots,
+}
+
+/// A pattern fill, which draws marks in the color of the shape style instead of filling the
+/// whole shape, so the bar and area charts remain distinguishable when printed in grayscale.
+/// It's set on a shape style with `ShapeStyle::pattern`. The pattern is aligned to the pixel
+/// grid of the backend, so the patterns of adjacent shapes line up.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let hatch = Pattern::new(PatternKind::DiagonalHatch).spacing(6).background(&WHITE);
+/// let style = ShapeStyle::from(&BLACK).pattern(hatch);
+/// let bar = Rectangle::new([(0, 0), (10, 100)], style);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    kind: PatternKind,
+    spacing: u32,
+    width: u32,
+    background: Option<RGBAColor>,
+}
+
+impl Pattern {
+    /// Create a pattern with marks 8 pixels apart and 1 pixel wide, on a transparent
+    /// background
+    pub fn new(kind: PatternKind) -> Self {
+        Self {
+            kind,
+            spacing: 8,
+            width: 1,
+            background: None,
+        }
+    }
+
+    /// Set the distance between the marks in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing.max(2);
+        self
+    }
+
+    /// Set the width of the lines, or the radius of the dots, in pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Set the color between the marks, the background is transparent by default
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Get the shape of the marks
+    pub fn kind(&self) -> PatternKind {
+        self.kind
+    }
+
+    /// Get the distance between the marks in pixels
+    pub fn get_spacing(&self) -> u32 {
+        self.spacing
+    }
+
+    /// Get the width of the lines, or the radius of the dots, in pixels
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    // Fade the background, the marks are drawn in the color of the shape style
+    pub(crate) fn mix(&self, value: f64) -> Self {
+        Self {
+            background: self.background.as_ref().map(|c| c.mix(value)),
+            ..self.clone()
+        }
+    }
+
+    /// Get the color between the marks
+    pub fn get_background(&self) -> Option<&RGBAColor> {
+        self.background.as_ref()
+    }
+
+    /// Check if the pattern marks the pixel
+    pub fn covers(&self, (x, y): (i32, i32)) -> bool {
+        let (s, w) = (self.spacing as i32, self.width as i32);
+        let line = |v: i32| v.rem_euclid(s) < w;
+        match self.kind {
+            PatternKind::DiagonalHatch => line(x + y),
+            PatternKind::BackDiagonalHatch => line(x - y),
+            PatternKind::CrossHatch => line(x + y) || line(x - y),
+            PatternKind::HorizontalLines => line(y),
+            PatternKind::VerticalLines => line(x),
+            PatternKind::Dots => {
+                let (dx, dy) = (x.rem_euclid(s) - s / 2, y.rem_euclid(s) - s / 2);
+                dx * dx + dy * dy <= w * w
+            }
+        }
+    }
+
+    /// Get the color of a pixel, which is the mark color for the marked pixels, or the
+    /// background color, if any, for the others
+    pub fn color_at(&self, point: (i32, i32), color: &RGBAColor) -> Option<RGBAColor> {
+        if self.covers(point) {
+            Some(color.clone())
+        } else {
+            self.background.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{BLACK, WHITE};
+
+    #[test]
+    fn test_pattern_coverage() {
+        let hatch = Pattern::new(PatternKind::DiagonalHatch).spacing(4);
+        assert!(hatch.covers((0, 0)) && hatch.covers((3, 1)) && hatch.covers((-1, 1)));
+        assert!(!hatch.covers((1, 0)));
+
+        let lines = Pattern::new(PatternKind::HorizontalLines)
+            .spacing(5)
+            .width(2)
+            .background(&WHITE);
+        assert!(lines.covers((7, 6)) && !lines.covers((7, 7)));
+        assert_eq!(
+            lines.color_at((0, 2), &BLACK.to_rgba()),
+            Some(WHITE.to_rgba())
+        );
+
+        let dots = Pattern::new(PatternKind::Dots).spacing(10).width(2);
+        assert!(dots.covers((5, 5)) && dots.covers((15, 7)) && !dots.covers((0, 0)));
+        assert_eq!(dots.color_at((0, 0), &BLACK.to_rgba()), None);
+    }
+}
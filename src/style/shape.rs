@@ -1,30 +1,110 @@
 use super::color::{Color, RGBAColor};
+use super::gradient::Gradient;
+use super::pattern::Pattern;
 
-/// Style for any of shape
+/// Style for any of shape. A filled shape can be outlined with a different color, for
+/// example a white marker with a colored border. The stroke color is the outline of the
+/// rectangles, circles, ellipses, polygons, filled paths, pie slices and markers, and the
+/// color of the paths and the Bézier curves.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let style = WHITE.filled().stroke_color(&RED).stroke_width(2);
+/// let marker = Circle::new((0, 0), 5, style);
+/// ```
 #[derive(Clone)]
 pub struct ShapeStyle {
     pub color: RGBAColor,
     pub filled: bool,
     pub stroke_width: u32,
+    /// The color of the outline, if it's `None`, a filled shape has no outline and an
+    /// unfilled shape is outlined with `color`
+    pub stroke_color: Option<RGBAColor>,
+    /// The gradient the shape is filled with instead of `color`
+    pub gradient: Option<Gradient>,
+    /// The pattern the shape is filled with instead of the solid `color`
+    pub pattern: Option<Pattern>,
 }
 
 impl ShapeStyle {
     /// Make a filled shape style
     pub fn filled(&self) -> Self {
         Self {
-            color: self.color.to_rgba(),
             filled: true,
-            stroke_width: self.stroke_width,
+            ..self.clone()
         }
     }
 
     pub fn stroke_width(&self, width: u32) -> Self {
         Self {
-            color: self.color.to_rgba(),
-            filled: self.filled,
             stroke_width: width,
+            ..self.clone()
+        }
+    }
+
+    /// Set the color of the outline, which is independent from the fill color
+    pub fn stroke_color<C: Color>(&self, color: &C) -> Self {
+        Self {
+            stroke_color: Some(color.to_rgba()),
+            ..self.clone()
+        }
+    }
+
+    /// Fill the shape with a gradient. The color of the first stop is used by the backends
+    /// which can't draw gradients.
+    pub fn gradient(&self, gradient: Gradient) -> Self {
+        Self {
+            color: gradient
+                .stops()
+                .first()
+                .map_or_else(|| self.color.clone(), |(_, c)| c.clone()),
+            filled: true,
+            gradient: Some(gradient),
+            ..self.clone()
+        }
+    }
+
+    /// Fill the shape with a pattern drawn in the color of the style. The backends which
+    /// can't draw patterns fill the shape with the color.
+    pub fn pattern(&self, pattern: Pattern) -> Self {
+        Self {
+            filled: true,
+            pattern: Some(pattern),
+            ..self.clone()
         }
     }
+
+    /// Fade all the colors of the style by the opacity `value`, including the outline, the
+    /// gradient stops and the pattern background
+    pub fn mix(&self, value: f64) -> Self {
+        Self {
+            color: self.color.mix(value),
+            stroke_color: self.stroke_color.as_ref().map(|c| c.mix(value)),
+            gradient: self.gradient.as_ref().map(|g| g.mix(value)),
+            pattern: self.pattern.as_ref().map(|p| p.mix(value)),
+            ..self.clone()
+        }
+    }
+
+    /// Get the style used to draw the outline of a shape, or `None` if the shape isn't
+    /// outlined
+    pub fn outline_style(&self) -> Option<ShapeStyle> {
+        if self.filled && self.stroke_color.is_none() {
+            return None;
+        }
+        Some(ShapeStyle {
+            color: self
+                .stroke_color
+                .clone()
+                .unwrap_or_else(|| self.color.clone()),
+            filled: false,
+            stroke_width: self.stroke_width,
+            stroke_color: None,
+            gradient: None,
+            pattern: None,
+        })
+    }
 }
 
 impl<'a, T: Color> From<&'a T> for ShapeStyle {
@@ -33,6 +113,49 @@ impl<'a, T: Color> From<&'a T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            stroke_color: None,
+            gradient: None,
+            pattern: None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{BLUE, RED};
+
+    #[test]
+    fn test_outline_style() {
+        assert!(RED.filled().outline_style().is_none());
+        let outline = ShapeStyle::from(&RED)
+            .stroke_width(3)
+            .outline_style()
+            .unwrap();
+        assert_eq!((outline.color, outline.stroke_width), (RED.to_rgba(), 3));
+        let style = RED.filled().stroke_color(&BLUE);
+        assert!(style.filled);
+        assert_eq!(style.outline_style().unwrap().color, BLUE.to_rgba());
+    }
+
+    #[test]
+    fn test_mix_style() {
+        use crate::style::{Gradient, Pattern, PatternKind, WHITE};
+        let style = WHITE.filled().stroke_color(&RED).mix(0.2);
+        assert_eq!(style.color, WHITE.mix(0.2));
+        assert_eq!(style.stroke_color, Some(RED.mix(0.2)));
+        assert_eq!(style.outline_style().unwrap().color, RED.mix(0.2));
+
+        let style = ShapeStyle::from(&RED)
+            .gradient(Gradient::linear(0.0).stop(0.0, &RED).stop(1.0, &BLUE))
+            .mix(0.5);
+        assert_eq!(style.gradient.unwrap().stops()[1].1, BLUE.mix(0.5));
+        let style = ShapeStyle::from(&RED)
+            .pattern(Pattern::new(PatternKind::Dots).background(&BLUE))
+            .mix(0.5);
+        assert_eq!(
+            style.pattern.unwrap().get_background(),
+            Some(&BLUE.mix(0.5))
+        );
+    }
+}
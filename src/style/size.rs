@@ -30,7 +30,28 @@ impl HasDimension for (u32, u32) {
 }
 
 /// The trait that describes a size, it may be a relative size which the
-/// size is determined by the parent size, e.g., 10% of the parent width
+/// size is determined by the parent size, e.g., 10% of the parent width.
+/// The margins, the label areas, the tick marks and the font sizes accept it, so a layout
+/// keeps its proportions at any resolution:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// for &size in [(400, 300), (1600, 1200)].iter() {
+///     let mut buf = String::new();
+///     let root = SVGBackend::with_string(&mut buf, size).into_drawing_area();
+///     let label_area = (8).percent().min(20);
+///     let chart = ChartBuilder::on(&root)
+///         .caption("Resolution independent", ("sans-serif", (5).percent_height()))
+///         .margin((2).percent())
+///         .x_label_area_size(label_area)
+///         .y_label_area_size(label_area)
+///         .build_ranged(0..10, 0..10)
+///         .unwrap();
+///     let (w, _) = chart.plotting_area().dim_in_pixel();
+///     assert!(w > size.0 * 8 / 10);
+/// }
+/// ```
 pub trait SizeDesc {
     /// Convert the size into the number of pixels
     ///
@@ -39,15 +60,35 @@ pub trait SizeDesc {
     fn in_pixels<T: HasDimension>(&self, parent: &T) -> i32;
 }
 
-impl SizeDesc for i32 {
+// The sizes of any primitive number type are in pixels, so an integer literal never needs a
+// suffix, the sizes beyond the range of `i32` are saturated
+macro_rules! impl_pixel_size {
+    ($($t:ty),*) => {$(
+        impl SizeDesc for $t {
+            fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
+                (*self as i128).clamp(i128::from(i32::MIN), i128::from(i32::MAX)) as i32
+            }
+        }
+    )*};
+}
+
+impl_pixel_size!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl SizeDesc for f64 {
     fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self
+        self.round() as i32
     }
 }
 
-impl SizeDesc for u32 {
+impl SizeDesc for f32 {
     fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self as i32
+        self.round() as i32
+    }
+}
+
+impl<T: SizeDesc + ?Sized> SizeDesc for &T {
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        (**self).in_pixels(parent)
     }
 }
 
@@ -55,6 +96,7 @@ impl SizeDesc for u32 {
 ///     1. portion of height
 ///     2. portion of width
 ///     3. portion of the minimal of height and weight
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RelativeSize {
     /// Percentage height
     Height(f64),
@@ -121,6 +163,7 @@ pub trait AsRelative: Into<f64> {
 impl<T: Into<f64>> AsRelative for T {}
 
 /// The struct describes a relative size with upper bound and lower bound
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RelativeSizeWithBound {
     size: RelativeSize,
     min: Option<i32>,
@@ -176,5 +219,19 @@ mod test {
         let size = (10).percent();
         assert_eq!(size.in_pixels(&(100, 200)), 10);
         assert_eq!(size.in_pixels(&(400, 200)), 20);
+
+        assert_eq!(12.6.in_pixels(&(100, 200)), 13);
+    }
+
+    #[test]
+    fn test_any_number_is_a_size() {
+        let dim = (100, 200);
+        assert_eq!(5u8.in_pixels(&dim), 5);
+        assert_eq!((-5i64).in_pixels(&dim), -5);
+        assert_eq!(7usize.in_pixels(&dim), 7);
+        assert_eq!(2.5f32.in_pixels(&dim), 3);
+        assert_eq!(u64::MAX.in_pixels(&dim), i32::MAX);
+        let relative = (10).percent();
+        assert_eq!(SizeDesc::in_pixels(&&relative, &dim), 10);
     }
 }
@@ -1,7 +1,7 @@
 use super::color::{Color, RGBAColor};
 use super::font::{FontDesc, FontFamily, FontStyle, FontTransform};
 use super::size::{HasDimension, SizeDesc};
-use super::BLACK;
+use super::{ShapeStyle, BLACK};
 
 /// Text anchor attributes are used to properly position the text.
 ///
@@ -96,9 +96,35 @@ pub struct TextStyle<'a> {
     pub color: RGBAColor,
     /// The anchor point position
     pub pos: text_anchor::Pos,
+    /// The line height of the multi-line text, as a multiple of the font size
+    pub line_spacing: f64,
+    /// The extra space between the characters in pixels
+    pub letter_spacing: f64,
+    /// If the text is underlined
+    pub underline: bool,
+    /// If the text is struck through
+    pub strikethrough: bool,
+    /// The style of the box drawn behind the text
+    pub background: Option<ShapeStyle>,
+    /// The space between the text and the border of the background box in pixels
+    pub padding: u32,
 }
 
+/// The trait for the types which describe a text style, so the captions and the labels accept
+/// a text style, a font description, a font family name or a tuple of the family name, the
+/// size and optionally the font style. The size may be relative to the parent area.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let style = ("sans-serif", (5).percent_height(), "bold").into_text_style(&root);
+/// assert_eq!(style.font.get_size(), 10.0);
+/// assert_eq!(style.font.get_style().as_str(), "bold");
+/// ```
 pub trait IntoTextStyle<'a> {
+    /// Make the text style, the relative sizes are computed from the parent
     fn into_text_style<P: HasDimension>(self, parent: &P) -> TextStyle<'a>;
 }
 
@@ -114,6 +140,18 @@ impl<'a> IntoTextStyle<'a> for TextStyle<'a> {
     }
 }
 
+impl<'a> IntoTextStyle<'a> for &TextStyle<'a> {
+    fn into_text_style<P: HasDimension>(self, _: &P) -> TextStyle<'a> {
+        self.clone()
+    }
+}
+
+impl<'a> IntoTextStyle<'a> for &'a str {
+    fn into_text_style<P: HasDimension>(self, _: &P) -> TextStyle<'a> {
+        self.into()
+    }
+}
+
 impl<'a> IntoTextStyle<'a> for FontFamily<'a> {
     fn into_text_style<P: HasDimension>(self, _: &P) -> TextStyle<'a> {
         self.into()
@@ -132,13 +170,13 @@ impl<'a, T: SizeDesc> IntoTextStyle<'a> for (FontFamily<'a>, T) {
     }
 }
 
-impl<'a, T: SizeDesc> IntoTextStyle<'a> for (&'a str, T, FontStyle) {
+impl<'a, T: SizeDesc, S: Into<FontStyle>> IntoTextStyle<'a> for (&'a str, T, S) {
     fn into_text_style<P: HasDimension>(self, parent: &P) -> TextStyle<'a> {
         Into::<FontDesc>::into((self.0, self.1.in_pixels(parent), self.2)).into()
     }
 }
 
-impl<'a, T: SizeDesc> IntoTextStyle<'a> for (FontFamily<'a>, T, FontStyle) {
+impl<'a, T: SizeDesc, S: Into<FontStyle>> IntoTextStyle<'a> for (FontFamily<'a>, T, S) {
     fn into_text_style<P: HasDimension>(self, parent: &P) -> TextStyle<'a> {
         Into::<FontDesc>::into((self.0, self.1.in_pixels(parent), self.2)).into()
     }
@@ -157,9 +195,8 @@ impl<'a> TextStyle<'a> {
     /// ```
     pub fn color<C: Color>(&self, color: &'a C) -> Self {
         Self {
-            font: self.font.clone(),
             color: color.to_rgba(),
-            pos: self.pos,
+            ..self.clone()
         }
     }
 
@@ -176,8 +213,7 @@ impl<'a> TextStyle<'a> {
     pub fn transform(&self, trans: FontTransform) -> Self {
         Self {
             font: self.font.clone().transform(trans),
-            color: self.color.clone(),
-            pos: self.pos,
+            ..self.clone()
         }
     }
 
@@ -195,11 +231,82 @@ impl<'a> TextStyle<'a> {
     /// ```
     pub fn pos(&self, pos: text_anchor::Pos) -> Self {
         Self {
-            font: self.font.clone(),
-            color: self.color.clone(),
             pos,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the line height of the multi-line text, 1.25 times the font size by default.
+    ///
+    /// - `spacing`: The line height as a multiple of the font size
+    /// - **returns** The up-to-dated text style
+    pub fn line_spacing(&self, spacing: f64) -> Self {
+        Self {
+            line_spacing: spacing,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the extra space between the characters. The letter spacing, the decorations and
+    /// the background box are only applied to the text which isn't rotated.
+    ///
+    /// - `spacing`: The extra space in pixels
+    /// - **returns** The up-to-dated text style
+    pub fn letter_spacing(&self, spacing: f64) -> Self {
+        Self {
+            letter_spacing: spacing,
+            ..self.clone()
         }
     }
+
+    /// Underlines the text.
+    ///
+    /// - **returns** The up-to-dated text style
+    pub fn underline(&self) -> Self {
+        Self {
+            underline: true,
+            ..self.clone()
+        }
+    }
+
+    /// Strikes through the text.
+    ///
+    /// - **returns** The up-to-dated text style
+    pub fn strikethrough(&self) -> Self {
+        Self {
+            strikethrough: true,
+            ..self.clone()
+        }
+    }
+
+    /// Draws a box behind the text, which makes annotations readable on top of the data.
+    ///
+    /// - `style`: The style of the box
+    /// - `padding`: The space between the text and the border of the box in pixels
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font())
+    ///     .background(&WHITE.mix(0.8), 3)
+    ///     .underline();
+    /// ```
+    pub fn background<S: Into<ShapeStyle>>(&self, style: S, padding: u32) -> Self {
+        Self {
+            background: Some(style.into()),
+            padding,
+            ..self.clone()
+        }
+    }
+
+    /// Check if the style needs more than the plain text drawing of the backend
+    pub(crate) fn is_decorated(&self) -> bool {
+        self.letter_spacing != 0.0
+            || self.underline
+            || self.strikethrough
+            || self.background.is_some()
+    }
 }
 
 /// Make sure that we are able to automatically copy the `TextStyle`
@@ -215,6 +322,12 @@ impl<'a, T: Into<FontDesc<'a>>> From<T> for TextStyle<'a> {
             font: font.into(),
             color: BLACK.to_rgba(),
             pos: text_anchor::Pos::default(),
+            line_spacing: 1.25,
+            letter_spacing: 0.0,
+            underline: false,
+            strikethrough: false,
+            background: None,
+            padding: 0,
         }
     }
 }
@@ -0,0 +1,117 @@
+use super::color::{Color, RGBAColor, RGBColor};
+use super::palette::{CustomPalette, Palette, Palette99};
+use super::{FontFamily, BLACK, WHITE};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// A theme bundles the colors and the font of a chart, so all the charts of an application
+/// are restyled in one place. The theme is consumed by `ChartBuilder::theme`, which fills the
+/// background, and the mesh and the series labels of the chart use the theme for the styles
+/// which are not set explicitly.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buf = String::new();
+/// let root = SVGBackend::with_string(&mut buf, (300, 200)).into_drawing_area();
+/// let theme = Theme::dark();
+/// let mut chart = ChartBuilder::on(&root)
+///     .theme(&theme)
+///     .caption("Dark", ("sans-serif", 20))
+///     .build_ranged(0..10, 0..10)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &theme.series_color(0)))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Theme {
+    /// The color the chart area is filled with
+    pub background: RGBAColor,
+    /// The color of the caption, the labels and the axis descriptions
+    pub text_color: RGBAColor,
+    /// The color of the axes and the tick marks
+    pub axis_color: RGBAColor,
+    /// The color of the mesh lines at the labeled positions
+    pub bold_mesh_color: RGBAColor,
+    /// The color of the mesh lines between the labeled positions
+    pub light_mesh_color: RGBAColor,
+    /// The font family of the labels
+    pub font_family: FontFamily<'static>,
+    /// The colors of the data series
+    pub palette: CustomPalette,
+}
+
+impl Theme {
+    /// The light theme, which is the default look of the charts
+    pub fn light() -> Self {
+        Self {
+            background: WHITE.to_rgba(),
+            text_color: BLACK.to_rgba(),
+            axis_color: BLACK.to_rgba(),
+            bold_mesh_color: BLACK.mix(0.2),
+            light_mesh_color: BLACK.mix(0.1),
+            font_family: FontFamily::SansSerif,
+            palette: palette_of::<Palette99>(),
+        }
+    }
+
+    /// The dark theme, with light lines and text on a dark gray background
+    pub fn dark() -> Self {
+        Self {
+            background: RGBColor(33, 33, 36).to_rgba(),
+            text_color: RGBColor(220, 220, 220).to_rgba(),
+            axis_color: RGBColor(200, 200, 200).to_rgba(),
+            bold_mesh_color: WHITE.mix(0.2),
+            light_mesh_color: WHITE.mix(0.08),
+            font_family: FontFamily::SansSerif,
+            palette: CustomPalette::new(&[
+                RGBColor(100, 181, 246),
+                RGBColor(255, 183, 77),
+                RGBColor(129, 199, 132),
+                RGBColor(229, 115, 115),
+                RGBColor(186, 104, 200),
+                RGBColor(77, 208, 225),
+                RGBColor(255, 241, 118),
+                RGBColor(240, 98, 146),
+            ]),
+        }
+    }
+
+    /// Pick the color of a data series from the palette, the colors are cycled
+    pub fn series_color(&self, idx: usize) -> RGBColor {
+        self.palette.pick(idx)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+fn palette_of<P: Palette>() -> CustomPalette {
+    let colors: Vec<_> = P::COLORS
+        .iter()
+        .map(|&(r, g, b)| RGBColor(r, g, b))
+        .collect();
+    CustomPalette::new(&colors)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_theme_presets() {
+        let light = Theme::default();
+        assert_eq!(light.background, WHITE.to_rgba());
+        assert_eq!(light.series_color(0).rgb(), Palette99::COLORS[0]);
+
+        let dark = Theme::dark();
+        assert!(dark.background.rgb().0 < 128);
+        assert!(dark.text_color.rgb().0 > 128);
+        assert_eq!(dark.series_color(8).rgb(), dark.series_color(0).rgb());
+    }
+}
@@ -0,0 +1,430 @@
+/*!
+  The utilities of the golden image tests of the charts.
+
+  A golden image test renders a chart and compares it with an image committed to the
+  repository. `compare_images` gives the pixels which differ by more than a tolerance, and
+  `SnapshotTest` runs the whole test: the chart is drawn on a bitmap in the deterministic
+  rendering mode, see `style::set_deterministic_rendering`, and compared with the golden image.
+  When the `PLOTTERS_UPDATE_GOLDEN` environment variable is set, the golden image is written
+  instead, otherwise a missing golden image fails the test. When the images differ too much,
+  the test panics and writes the actual image and an image highlighting the differences next
+  to the golden one. The snapshots are drawn one at a time, since the deterministic rendering
+  mode is shared by all the threads.
+
+  The `chart_snapshot_test!` macro defines such a test:
+
+  ```rust,no_run
+  use plotters::prelude::*;
+
+  plotters::chart_snapshot_test!(
+      square_chart,
+      "tests/golden/square.png",
+      (300, 200),
+      |root| {
+          root.fill(&WHITE)?;
+          let mut chart = ChartBuilder::on(root).build_ranged(0.0..1.0, 0.0..1.0)?;
+          chart.draw_series(LineSeries::new((0..=10).map(|x| x as f64 / 10.0).map(|x| (x, x * x)), &RED))?;
+          Ok(())
+      },
+      max_changed_percent = 0.5
+  );
+  ```
+*/
+use std::env;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{MutexGuard, PoisonError};
+
+use crate::coord::Shift;
+use crate::drawing::{BitMapBackend, DrawingArea, IntoDrawingArea};
+use crate::error::PlottersError;
+use crate::style::{is_deterministic_rendering, set_deterministic_rendering, DETERMINISTIC_LOCK};
+
+/// The environment variable which makes the snapshot tests write the golden images
+pub const UPDATE_GOLDEN_VAR: &str = "PLOTTERS_UPDATE_GOLDEN";
+
+/// The difference between two RGB images of the same size
+#[derive(Clone, Debug)]
+pub struct ImageDiff {
+    size: (u32, u32),
+    changed_pixels: usize,
+    max_difference: u8,
+    diff_image: Vec<u8>,
+}
+
+impl ImageDiff {
+    /// The size of the images in pixels
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// The number of pixels which differ by more than the tolerance
+    pub fn changed_pixels(&self) -> usize {
+        self.changed_pixels
+    }
+
+    /// The percentage of the pixels which differ by more than the tolerance
+    pub fn changed_percent(&self) -> f64 {
+        let total = self.size.0 as usize * self.size.1 as usize;
+        if total == 0 {
+            return 0.0;
+        }
+        self.changed_pixels as f64 * 100.0 / total as f64
+    }
+
+    /// The largest difference of a color channel between the images
+    pub fn max_difference(&self) -> u8 {
+        self.max_difference
+    }
+
+    /// Check if no pixel differs by more than the tolerance
+    pub fn is_identical(&self) -> bool {
+        self.changed_pixels == 0
+    }
+
+    /// The RGB pixels of the image highlighting the differences, the changed pixels are red and
+    /// the others are the faded pixels of the actual image
+    pub fn diff_image(&self) -> &[u8] {
+        &self.diff_image
+    }
+
+    /// Save the image highlighting the differences
+    pub fn save_diff_image<P: AsRef<Path>>(&self, path: P) -> Result<(), PlottersError> {
+        save_rgb(path.as_ref(), &self.diff_image, self.size)
+    }
+}
+
+impl Display for ImageDiff {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(
+            fmt,
+            "{} of {}x{} pixels changed ({:.3}%), the largest difference is {}",
+            self.changed_pixels,
+            self.size.0,
+            self.size.1,
+            self.changed_percent(),
+            self.max_difference
+        )
+    }
+}
+
+/// Compare two RGB images of the same size pixel by pixel
+/// - `expected`: The pixels of the expected image, row by row
+/// - `actual`: The pixels of the actual image
+/// - `size`: The size of the images in pixels
+/// - `tolerance`: The largest difference of a color channel of a pixel which isn't a change
+/// - **returns**: The difference, or `None` if the buffers don't match the size
+pub fn compare_images(
+    expected: &[u8],
+    actual: &[u8],
+    size: (u32, u32),
+    tolerance: u8,
+) -> Option<ImageDiff> {
+    let len = size.0 as usize * size.1 as usize * 3;
+    if expected.len() != len || actual.len() != len {
+        return None;
+    }
+    let mut diff = ImageDiff {
+        size,
+        changed_pixels: 0,
+        max_difference: 0,
+        diff_image: Vec::with_capacity(len),
+    };
+    for (e, a) in expected.chunks(3).zip(actual.chunks(3)) {
+        let difference = e
+            .iter()
+            .zip(a)
+            .map(|(e, a)| e.abs_diff(*a))
+            .max()
+            .unwrap_or(0);
+        diff.max_difference = diff.max_difference.max(difference);
+        if difference > tolerance {
+            diff.changed_pixels += 1;
+            diff.diff_image.extend_from_slice(&[255, 0, 0]);
+        } else {
+            diff.diff_image
+                .extend(a.iter().map(|v| 255 - (255 - v) / 4));
+        }
+    }
+    Some(diff)
+}
+
+// Turns on the deterministic rendering mode until it's dropped, which restores the previous mode
+// even if drawing the chart panics, and keeps the other snapshots from drawing meanwhile
+struct DeterministicGuard {
+    previous: bool,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl DeterministicGuard {
+    fn new() -> Self {
+        let lock = DETERMINISTIC_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let previous = is_deterministic_rendering();
+        set_deterministic_rendering(true);
+        Self {
+            previous,
+            _lock: lock,
+        }
+    }
+}
+
+impl Drop for DeterministicGuard {
+    fn drop(&mut self) {
+        set_deterministic_rendering(self.previous);
+    }
+}
+
+fn save_rgb(path: &Path, pixels: &[u8], (w, h): (u32, u32)) -> Result<(), PlottersError> {
+    image::save_buffer(path, pixels, w, h, image::ColorType::Rgb8).map_err(|e| {
+        PlottersError::Backend {
+            source: Box::new(e),
+            context: Default::default(),
+        }
+    })
+}
+
+/// A golden image test of a chart, see the module documentation
+pub struct SnapshotTest {
+    golden: PathBuf,
+    size: (u32, u32),
+    tolerance: u8,
+    max_changed_percent: f64,
+    update_golden: bool,
+}
+
+impl SnapshotTest {
+    /// Create a test, by default no pixel may change
+    /// - `golden`: The path to the golden PNG image
+    /// - `size`: The size of the chart in pixels
+    pub fn new<P: AsRef<Path>>(golden: P, size: (u32, u32)) -> Self {
+        Self {
+            golden: golden.as_ref().to_path_buf(),
+            size,
+            tolerance: 0,
+            max_changed_percent: 0.0,
+            update_golden: env::var_os(UPDATE_GOLDEN_VAR).is_some(),
+        }
+    }
+
+    /// Set if the golden image is written rather than compared, by default it's written when
+    /// `PLOTTERS_UPDATE_GOLDEN` is set
+    pub fn update_golden(mut self, update: bool) -> Self {
+        self.update_golden = update;
+        self
+    }
+
+    /// Set the largest difference of a color channel of a pixel which isn't a change
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the largest percentage of the pixels which may change
+    pub fn max_changed_percent(mut self, percent: f64) -> Self {
+        self.max_changed_percent = percent;
+        self
+    }
+
+    // The path of the image next to the golden image, with the suffix added to its stem
+    fn sibling(&self, suffix: &str) -> PathBuf {
+        let stem = self
+            .golden
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.golden
+            .with_file_name(format!("{}.{}.png", stem, suffix))
+    }
+
+    /// Draw the chart and compare it with the golden image, the golden image is written
+    /// instead when `PLOTTERS_UPDATE_GOLDEN` is set, see `update_golden`
+    /// - `draw`: The function drawing the chart on the root drawing area
+    /// - **returns**: The difference, or the reason the test fails
+    pub fn check<F>(&self, draw: F) -> Result<ImageDiff, String>
+    where
+        F: FnOnce(&DrawingArea<BitMapBackend, Shift>) -> Result<(), PlottersError>,
+    {
+        let (w, h) = self.size;
+        let mut actual = vec![0; w as usize * h as usize * 3];
+        let result = {
+            let _guard = DeterministicGuard::new();
+            let root = BitMapBackend::with_buffer(&mut actual, self.size).into_drawing_area();
+            draw(&root).and_then(|_| root.present().map_err(PlottersError::from))
+        };
+        result.map_err(|e| format!("Failed to draw the chart: {}", e))?;
+
+        let golden = self.golden.display();
+        if self.update_golden {
+            if let Some(dir) = self.golden.parent() {
+                fs::create_dir_all(dir)
+                    .map_err(|e| format!("Failed to create {}: {}", golden, e))?;
+            }
+            save_rgb(&self.golden, &actual, self.size)
+                .map_err(|e| format!("Failed to write {}: {}", golden, e))?;
+            return Ok(compare_images(&actual, &actual, self.size, 0).unwrap());
+        }
+
+        if !self.golden.exists() {
+            return Err(format!(
+                "The golden image {} doesn't exist, set {} to write it",
+                golden, UPDATE_GOLDEN_VAR
+            ));
+        }
+        let expected = image::open(&self.golden)
+            .map_err(|e| format!("Failed to read {}: {}", golden, e))?
+            .to_rgb8();
+        if expected.dimensions() != self.size {
+            return Err(format!(
+                "The size of {} is {:?} rather than {:?}",
+                golden,
+                expected.dimensions(),
+                self.size
+            ));
+        }
+        let diff = compare_images(&expected, &actual, self.size, self.tolerance).unwrap();
+        if diff.changed_percent() > self.max_changed_percent {
+            let (actual_path, diff_path) = (self.sibling("actual"), self.sibling("diff"));
+            save_rgb(&actual_path, &actual, self.size).map_err(|e| e.to_string())?;
+            diff.save_diff_image(&diff_path)
+                .map_err(|e| e.to_string())?;
+            return Err(format!(
+                "The chart doesn't match {}: {}, see {} and {}, set {} to update the golden image",
+                golden,
+                diff,
+                actual_path.display(),
+                diff_path.display(),
+                UPDATE_GOLDEN_VAR
+            ));
+        }
+        Ok(diff)
+    }
+
+    /// Draw the chart and compare it with the golden image, panic if they don't match, see
+    /// `check`
+    pub fn run<F>(&self, draw: F)
+    where
+        F: FnOnce(&DrawingArea<BitMapBackend, Shift>) -> Result<(), PlottersError>,
+    {
+        if let Err(message) = self.check(draw) {
+            panic!("{}", message);
+        }
+    }
+}
+
+/// Define a golden image test of a chart, see the `testing` module.
+///
+/// `chart_snapshot_test!(name, golden_path, (width, height), |root| { ... })` defines the test
+/// function `name`, which draws the chart on `root` and compares it with the golden image. The
+/// closure returns `Result<(), PlottersError>`, so the drawing errors can be propagated with
+/// `?`. The options of `SnapshotTest` can be appended, like `tolerance = 2` or
+/// `max_changed_percent = 0.1`.
+#[macro_export]
+macro_rules! chart_snapshot_test {
+    ($name:ident, $golden:expr, $size:expr, $draw:expr $(, $option:ident = $value:expr)* $(,)?) => {
+        #[test]
+        fn $name() {
+            $crate::testing::SnapshotTest::new($golden, $size)
+                $(.$option($value))*
+                .run($draw);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_compare_images() {
+        let expected = vec![0, 0, 0, 100, 100, 100, 255, 255, 255, 10, 20, 30];
+        let actual = vec![0, 0, 0, 102, 100, 100, 255, 0, 255, 10, 20, 30];
+        assert!(compare_images(&expected, &actual[..9], (2, 2), 0).is_none());
+
+        let diff = compare_images(&expected, &actual, (2, 2), 2).unwrap();
+        assert_eq!(diff.changed_pixels(), 1);
+        assert_eq!(diff.changed_percent(), 25.0);
+        assert_eq!(diff.max_difference(), 255);
+        assert_eq!(&diff.diff_image()[6..9], &[255, 0, 0]);
+        assert_eq!(&diff.diff_image()[..3], &[192, 192, 192]);
+        assert_eq!(
+            compare_images(&expected, &actual, (2, 2), 1)
+                .unwrap()
+                .changed_pixels(),
+            2
+        );
+        assert!(compare_images(&expected, &expected, (2, 2), 0)
+            .unwrap()
+            .is_identical());
+    }
+
+    fn draw_square(root: &DrawingArea<BitMapBackend, Shift>, x: i32) -> Result<(), PlottersError> {
+        root.fill(&WHITE)?;
+        root.draw(&Rectangle::new([(x, 10), (x + 10, 20)], BLUE.filled()))?;
+        root.draw(&Text::new("42", (5, 5), ("sans-serif", 12)))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_test() {
+        let dir = std::env::temp_dir().join(format!("plotters-golden-{}", std::process::id()));
+        let golden = dir.join("square.png");
+        let test = SnapshotTest::new(&golden, (40, 30)).update_golden(false);
+
+        // The missing golden image fails the test unless it's written
+        let error = test.check(|root| draw_square(root, 20)).unwrap_err();
+        assert!(error.contains("doesn't exist"), "{}", error);
+        assert!(!golden.exists());
+        assert!(SnapshotTest::new(&golden, (40, 30))
+            .update_golden(true)
+            .check(|root| draw_square(root, 20))
+            .unwrap()
+            .is_identical());
+        assert!(golden.exists());
+        assert!(test
+            .check(|root| draw_square(root, 20))
+            .unwrap()
+            .is_identical());
+
+        // Moving the square changes the 2 columns at each side of it
+        let error = test.check(|root| draw_square(root, 22)).unwrap_err();
+        assert!(error.contains("44 of 40x30 pixels changed"), "{}", error);
+        assert!(dir.join("square.actual.png").exists());
+        assert!(dir.join("square.diff.png").exists());
+        let diff = test
+            .max_changed_percent(5.0)
+            .check(|root| draw_square(root, 22))
+            .unwrap();
+        assert_eq!(diff.changed_pixels(), 44);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_restores_mode() {
+        let test = SnapshotTest::new(std::env::temp_dir(), (40, 30)).update_golden(false);
+        let result = std::panic::catch_unwind(|| {
+            test.check(|_root| -> Result<(), PlottersError> {
+                assert!(is_deterministic_rendering());
+                panic!("Failed to draw")
+            })
+        });
+        assert!(result.is_err());
+        // The drawing panicked with the lock held
+        let _lock = DETERMINISTIC_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        assert!(!is_deterministic_rendering());
+    }
+
+    chart_snapshot_test!(
+        test_snapshot_macro,
+        std::env::temp_dir().join(format!("plotters-golden-macro-{}.png", std::process::id())),
+        (40, 30),
+        |root| draw_square(root, 5),
+        tolerance = 1,
+        update_golden = true,
+    );
+}